@@ -57,6 +57,7 @@ use deno_semver::jsr::JsrDepPackageReq;
 use deno_semver::package::PackageKind;
 use deno_telemetry::OtelConfig;
 use deno_telemetry::OtelConsoleConfig;
+use deno_telemetry::OtelIoSpansConfig;
 use deno_telemetry::OtelPropagators;
 use log::Level;
 use log::debug;
@@ -140,6 +141,10 @@ pub struct BenchFlags {
   pub files: FileFlags,
   pub filter: Option<String>,
   pub json: bool,
+  /// The `--json` output of an earlier run to compare the results with.
+  pub baseline: Option<String>,
+  /// Percent a bench may be slower than in the baseline.
+  pub regression_threshold: Option<u32>,
   pub no_run: bool,
   pub permit_no_files: bool,
   pub watch: Option<WatchFlags>,
@@ -512,6 +517,7 @@ pub struct TaskFlags {
   pub recursive: bool,
   pub filter: Option<String>,
   pub eval: bool,
+  pub parallel: bool,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -523,6 +529,42 @@ pub enum TestReporterConfig {
   Tap,
 }
 
+/// The part of the tests `deno test --shard=<INDEX>/<COUNT>` runs, with a
+/// 1-based index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TestShard {
+  pub index: u32,
+  pub count: u32,
+}
+
+impl std::str::FromStr for TestShard {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let shard = s.split_once('/').and_then(|(index, count)| {
+      Some(TestShard {
+        index: index.parse().ok()?,
+        count: count.parse().ok()?,
+      })
+    });
+    match shard {
+      Some(shard) if shard.index >= 1 && shard.index <= shard.count => {
+        Ok(shard)
+      }
+      _ => Err(
+        "expected <INDEX>/<COUNT>, with an index from 1 to the count"
+          .to_string(),
+      ),
+    }
+  }
+}
+
+impl std::fmt::Display for TestShard {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}/{}", self.index, self.count)
+  }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct TestFlags {
   pub doc: bool,
@@ -536,6 +578,8 @@ pub struct TestFlags {
   pub permit_no_files: bool,
   pub filter: Option<String>,
   pub shuffle: Option<u64>,
+  pub shard: Option<TestShard>,
+  pub update_snapshots: bool,
   pub trace_leaks: bool,
   pub watch: Option<WatchFlagsWithPaths>,
   pub reporter: TestReporterConfig,
@@ -575,6 +619,16 @@ pub struct CleanFlags {
   pub dry_run: bool,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PermissionsFlags {
+  List,
+  /// Revokes the permissions of the given scripts, or of all scripts when
+  /// empty.
+  Revoke {
+    scripts: Vec<String>,
+  },
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BundleFlags {
   pub entrypoints: Vec<String>,
@@ -623,6 +677,7 @@ pub enum DenoSubcommand {
   Task(TaskFlags),
   Test(TestFlags),
   Outdated(OutdatedFlags),
+  Permissions(PermissionsFlags),
   Types,
   Upgrade(UpgradeFlags),
   Vendor,
@@ -861,13 +916,27 @@ pub struct Flags {
   pub reload: bool,
   pub seed: Option<u64>,
   pub trace_ops: Option<Vec<String>>,
+  pub trace_ops_output: Option<String>,
+  /// `--trace-leaks` of `deno run`, `deno test` has its own.
+  pub trace_leaks: bool,
+  /// `--cpu-prof` of `deno run`, with the file to write the CPU profile to,
+  /// or an empty string for a generated name.
+  pub cpu_prof: Option<String>,
+  /// `--heap-prof` of `deno run`, like `cpu_prof`.
+  pub heap_prof: Option<String>,
   pub unstable_config: UnstableConfig,
   pub unsafely_ignore_certificate_errors: Option<Vec<String>>,
+  pub unsafely_log_tls_keys: bool,
   pub v8_flags: Vec<String>,
   pub code_cache_enabled: bool,
   pub permissions: PermissionFlags,
   pub allow_scripts: PackagesAllowedScripts,
   pub permission_set: Option<String>,
+  pub permission_audit: Option<String>,
+  pub remember_permissions: bool,
+  pub landlock: bool,
+  pub seccomp_denylist: bool,
+  pub seccomp_profile: Option<String>,
   pub eszip: bool,
   pub node_conditions: Vec<String>,
   pub preload: Vec<String>,
@@ -1197,6 +1266,44 @@ impl Flags {
       HashSet::default()
     };
 
+    let mut io_spans = OtelIoSpansConfig::default();
+    if let Ok(kinds) = std::env::var("OTEL_DENO_IO_SPANS") {
+      for kind in kinds.split(',') {
+        match kind.trim() {
+          "fs" => io_spans.fs = true,
+          "net" => io_spans.net = true,
+          "" => {}
+          _ => {
+            log::warn!(
+              "'OTEL_DENO_IO_SPANS' env var value not recognized, only a comma separated list of 'fs' and 'net' is accepted"
+            );
+          }
+        }
+      }
+    }
+    if let Ok(ratio) = std::env::var("OTEL_DENO_IO_SPANS_SAMPLE_RATIO") {
+      match f64::from_str(&ratio) {
+        Ok(ratio) if (0.0..=1.0).contains(&ratio) => {
+          io_spans.sample_ratio = ratio
+        }
+        _ => {
+          log::warn!(
+            "'OTEL_DENO_IO_SPANS_SAMPLE_RATIO' env var value not recognized, only numbers from 0 to 1 are accepted"
+          );
+        }
+      }
+    }
+    if let Ok(max) = std::env::var("OTEL_DENO_IO_SPANS_MAX_PER_SECOND") {
+      match u32::from_str(&max) {
+        Ok(max) => io_spans.max_per_second = max,
+        Err(_) => {
+          log::warn!(
+            "'OTEL_DENO_IO_SPANS_MAX_PER_SECOND' env var value not recognized, only integers are accepted"
+          );
+        }
+      }
+    }
+
     OtelConfig {
       tracing_enabled: !disabled
         && otel_var("OTEL_DENO_TRACING").unwrap_or(default),
@@ -1231,6 +1338,7 @@ impl Flags {
         })
         .ok()
         .flatten(),
+      io_spans,
     }
   }
 
@@ -1439,6 +1547,16 @@ static ENV_VARS: &[EnvVar] = &[
     description: "Controls whether Deno.openKv() API should use disk based or in-memory\ndatabase.",
     example: None,
   },
+  EnvVar {
+    name: "DENO_LOG",
+    description: "Enable internal logs, at a level or with a list of module=level filters",
+    example: Some(r#"(e.g. "debug" or "info,deno_runtime::worker=debug")"#),
+  },
+  EnvVar {
+    name: "DENO_LOG_FORMAT",
+    description: "Write internal logs as \"text\" or as newline delimited \"json\"",
+    example: Some(r#"(defaults to "text")"#),
+  },
   EnvVar {
     name: "DENO_EMIT_CACHE_MODE",
     description: "Control if the transpiled sources should be cached.",
@@ -1513,6 +1631,11 @@ static ENV_VARS: &[EnvVar] = &[
     description: "URL to use for the npm registry.",
     example: None,
   },
+  EnvVar {
+    name: "SSLKEYLOGFILE",
+    description: "File to log TLS session secrets to when --unsafely-log-tls-keys is set.",
+    example: None,
+  },
   EnvVar {
     name: "DENO_TRUST_PROXY_HEADERS",
     description: "If specified, removes X-deno-client-address header when serving HTTP.",
@@ -1605,6 +1728,7 @@ static DENO_HELP: &str = cstr!(
     <g>info</>         Show info about cache or info related to source file
     <g>jupyter</>      Deno kernel for Jupyter notebooks
     <g>lint</>         Lint source files
+    <g>permissions</>  List and revoke permissions remembered with --remember-permissions
     <g>init</>         Initialize a new project
     <g>test</>         Run tests
                   <p(245)>deno test  |  deno test test.ts</>
@@ -1830,6 +1954,7 @@ pub fn flags_from_vec_with_initial_cwd(
         "lint" => lint_parse(&mut flags, &mut m)?,
         "lsp" => lsp_parse(&mut flags, &mut m),
         "outdated" => outdated_parse(&mut flags, &mut m, false)?,
+        "permissions" => permissions_parse(&mut flags, &mut m),
         "repl" => repl_parse(&mut flags, &mut m)?,
         "run" => run_parse(&mut flags, &mut m, app, false)?,
         "serve" => serve_parse(&mut flags, &mut m, app)?,
@@ -2098,6 +2223,7 @@ pub fn clap_root() -> Command {
         .subcommand(outdated_subcommand())
         .subcommand(lsp_subcommand())
         .subcommand(lint_subcommand())
+        .subcommand(permissions_subcommand())
         .subcommand(publish_subcommand())
         .subcommand(repl_subcommand())
         .subcommand(task_subcommand())
@@ -2335,6 +2461,23 @@ If you specify a directory instead of a file, the path is expanded to all contai
           .action(ArgAction::SetTrue)
           .help("UNSTABLE: Output benchmark result in JSON format"),
       )
+      .arg(
+        Arg::new("baseline")
+          .long("baseline")
+          .value_name("FILE")
+          .require_equals(true)
+          .help("Compare the results with the output of an earlier run with --json, failing if a bench got slower than --regression-threshold")
+          .value_hint(ValueHint::FilePath),
+      )
+      .arg(
+        Arg::new("regression-threshold")
+          .long("regression-threshold")
+          .value_name("PERCENT")
+          .require_equals(true)
+          .requires("baseline")
+          .value_parser(value_parser!(u32))
+          .help("Percent a bench may be slower than in the --baseline before failing [default: 10]"),
+      )
       .arg(
         Arg::new("ignore")
           .long("ignore")
@@ -2580,6 +2723,42 @@ fn clean_subcommand() -> Command {
   })
 }
 
+fn permissions_subcommand() -> Command {
+  command(
+    "permissions",
+    cstr!("List and revoke the permissions remembered for scripts of the current project.
+
+Answers to permission prompts are remembered when running with <c>--remember-permissions</>,
+until the script changes.
+  <p(245)>deno permissions list</>
+  <p(245)>deno permissions revoke main.ts</>
+  <p(245)>deno permissions revoke --all</>"),
+    UnstableArgsConfig::None,
+  )
+  .defer(|cmd| {
+    cmd
+      .subcommand_required(true)
+      .subcommand(Command::new("list").about("List remembered permissions"))
+      .subcommand(
+        Command::new("revoke")
+          .about("Revoke remembered permissions")
+          .arg(
+            Arg::new("scripts")
+              .num_args(1..)
+              .value_hint(ValueHint::FilePath)
+              .required_unless_present("all"),
+          )
+          .arg(
+            Arg::new("all")
+              .long("all")
+              .help("Revoke the permissions of all scripts in the project")
+              .action(ArgAction::SetTrue)
+              .conflicts_with("scripts"),
+          ),
+      )
+  })
+}
+
 fn check_subcommand() -> Command {
   command("check",
       cstr!("Download and type-check without execution.
@@ -3874,6 +4053,9 @@ fn run_args(command: Command, top_level: bool) -> Command {
     .arg(no_code_cache_arg())
     .arg(coverage_arg())
     .arg(tunnel_arg())
+    .arg(trace_leaks_arg())
+    .arg(cpu_prof_arg())
+    .arg(heap_prof_arg())
 }
 
 fn run_subcommand() -> Command {
@@ -3964,7 +4146,10 @@ List all available tasks (from config files in the current and ancestor director
   <p(245)>deno task</>
 
 Evaluate a task from string:
-  <p(245)>deno task --eval \"echo $(pwd)\"</>"
+  <p(245)>deno task --eval \"echo $(pwd)\"</>
+
+Run the tasks matching a wildcard at the same time:
+  <p(245)>deno task --parallel \"dev:*\"</>"
     ),
     UnstableArgsConfig::ResolutionAndRuntime,
   )
@@ -4003,6 +4188,12 @@ Evaluate a task from string:
             "Evaluate the passed value as if it was a task in a configuration file",
           ).action(ArgAction::SetTrue)
       )
+      .arg(
+        Arg::new("parallel")
+          .long("parallel")
+          .help("Run the matched tasks and their dependencies all at once, prefixing each line of output with the name of its task. Once a task fails, the others are stopped")
+          .action(ArgAction::SetTrue),
+      )
       .arg(node_modules_dir_arg())
       .arg(tunnel_arg())
   })
@@ -4143,6 +4334,22 @@ or <c>**/__tests__/**</>:
           .value_parser(value_parser!(u64))
           .help_heading(TEST_HEADING),
       )
+      .arg(
+        Arg::new("shard")
+          .long("shard")
+          .value_name("INDEX/COUNT")
+          .help("Split the tests into COUNT shards and only run shard INDEX, starting from 1, e.g. to spread them across CI machines. Tests are assigned to shards by a hash of their file and name")
+          .require_equals(true)
+          .value_parser(|s: &str| s.parse::<TestShard>())
+          .help_heading(TEST_HEADING),
+      )
+      .arg(
+        Arg::new("update-snapshots")
+          .long("update-snapshots")
+          .help("Write the values asserted with t.assertSnapshot() to the snapshot files instead of comparing them")
+          .action(ArgAction::SetTrue)
+          .help_heading(TEST_HEADING),
+      )
       .arg(
         Arg::new("coverage")
           .long("coverage")
@@ -4464,9 +4671,9 @@ fn permission_args(app: Command, requires: Option<&'static str>) -> Command {
   <g>--no-prompt</>                               Always throw if required permission wasn't passed.
                                              <p(245)>Can also be set via the DENO_NO_PROMPT environment variable.</>
   <g>-R, --allow-read[=<<PATH>...]</>             Allow file system read access. Optionally specify allowed paths.
-                                             <p(245)>--allow-read  |  --allow-read="/etc,/var/log.txt"</>
+                                             <p(245)>--allow-read  |  --allow-read="/etc,/var/log.txt"  |  --allow-read="./src/**/*.ts"</>
   <g>-W, --allow-write[=<<PATH>...]</>            Allow file system write access. Optionally specify allowed paths.
-                                             <p(245)>--allow-write  |  --allow-write="/etc,/var/log.txt"</>
+                                             <p(245)>--allow-write  |  --allow-write="/etc,/var/log.txt"  |  --allow-write="./src/**/*.ts"</>
   <g>-I, --allow-import[=<<IP_OR_HOSTNAME>...]</> Allow importing from remote hosts. Optionally specify allowed IP addresses and host names, with ports as necessary.
                                             Default value: <p(245)>deno.land:443,jsr.io:443,esm.sh:443,cdn.jsdelivr.net:443,raw.githubusercontent.com:443,gist.githubusercontent.com:443</>
                                              <p(245)>--allow-import  |  --allow-import="example.com,github.com"</>
@@ -4500,6 +4707,15 @@ fn permission_args(app: Command, requires: Option<&'static str>) -> Command {
                                              <p(245)>--ignore-env  |  --ignore-env="PORT,HOME,PATH"</>
       <g>--ignore-read[=<<PATH>...]</>            Ignore file system read access with a `NotFound` error. Optionally specify ignored paths.
                                              <p(245)>--ignore-read  |  --ignore-read="/etc,/var/log.txt"</>
+      <g>--permission-audit=<<FILE></>             Write a JSONL record of every permission check and its result to a file.
+                                             <p(245)>--permission-audit=./audit.jsonl</>
+      <g>--remember-permissions</>                Remember permissions granted at prompts for the next runs of an unchanged script.
+                                             <p(245)>deno permissions list  |  deno permissions revoke main.ts</>
+      <g>--landlock</>                            Also enforce read and write permissions in the kernel with Landlock, on Linux.
+                                             <p(245)>--landlock --allow-read=./data --allow-write=/tmp</>
+      <g>--seccomp-denylist</>                    Deny system calls Deno never makes, such as mount and ptrace, with seccomp, on Linux.
+      <g>--seccomp-profile=<<FILE></>             Restrict the system calls of the process with a seccomp profile, on Linux.
+                                             <p(245)>--seccomp-profile=./profile.json</>
       <g>DENO_TRACE_PERMISSIONS</>                Environmental variable to enable stack traces in permission prompts.
                                              <p(245)>DENO_TRACE_PERMISSIONS=1 deno run main.ts</>
       <g>DENO_AUDIT_PERMISSIONS</>               Environmental variable to generate a JSONL file with all permissions accesses.
@@ -4791,6 +5007,76 @@ fn permission_args(app: Command, requires: Option<&'static str>) -> Command {
         arg
       }
     )
+    .arg(
+      {
+        let mut arg = Arg::new("permission-audit")
+          .long("permission-audit")
+          .require_equals(true)
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath)
+          .hide(true)
+          .long_help("false");
+        if let Some(requires) = requires {
+          arg = arg.requires(requires)
+        }
+        arg
+      }
+    )
+    .arg(
+      {
+        let mut arg = Arg::new("remember-permissions")
+          .long("remember-permissions")
+          .action(ArgAction::SetTrue)
+          .hide(true)
+          .long_help("false");
+        if let Some(requires) = requires {
+          arg = arg.requires(requires)
+        }
+        arg
+      }
+    )
+    .arg(
+      {
+        let mut arg = Arg::new("landlock")
+          .long("landlock")
+          .action(ArgAction::SetTrue)
+          .hide(true)
+          .long_help("false");
+        if let Some(requires) = requires {
+          arg = arg.requires(requires)
+        }
+        arg
+      }
+    )
+    .arg(
+      {
+        let mut arg = Arg::new("seccomp-denylist")
+          .long("seccomp-denylist")
+          .action(ArgAction::SetTrue)
+          .conflicts_with("seccomp-profile")
+          .hide(true)
+          .long_help("false");
+        if let Some(requires) = requires {
+          arg = arg.requires(requires)
+        }
+        arg
+      }
+    )
+    .arg(
+      {
+        let mut arg = Arg::new("seccomp-profile")
+          .long("seccomp-profile")
+          .require_equals(true)
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath)
+          .hide(true)
+          .long_help("false");
+        if let Some(requires) = requires {
+          arg = arg.requires(requires)
+        }
+        arg
+      }
+    )
     .arg(
       {
         let mut arg = allow_import_arg().hide(true);
@@ -4867,6 +5153,8 @@ fn runtime_misc_args(app: Command) -> Command {
     .arg(seed_arg())
     .arg(enable_testing_features_arg())
     .arg(trace_ops_arg())
+    .arg(trace_ops_output_arg())
+    .arg(unsafely_log_tls_keys_arg())
     .arg(eszip_arg())
     .arg(preload_arg())
     .arg(require_arg())
@@ -5086,14 +5374,38 @@ fn enable_testing_features_arg() -> Arg {
 fn trace_ops_arg() -> Arg {
   Arg::new("trace-ops")
     .long("trace-ops")
+    .alias("strace-ops")
     .num_args(0..)
     .use_value_delimiter(true)
     .require_equals(true)
     .value_name("OPS")
-    .help("Trace low-level op calls")
+    .help(
+      "Trace low-level op calls, optionally only of ops matching the given names or globs, or not matching the ones starting with '-'",
+    )
+    .hide(true)
+}
+
+fn trace_ops_output_arg() -> Arg {
+  Arg::new("trace-ops-output")
+    .long("trace-ops-output")
+    .require_equals(true)
+    .requires("trace-ops")
+    .value_name("FILE")
+    .value_hint(ValueHint::FilePath)
+    .help("Write the trace of --trace-ops to a file as newline delimited JSON")
     .hide(true)
 }
 
+fn unsafely_log_tls_keys_arg() -> Arg {
+  Arg::new("unsafely-log-tls-keys")
+    .long("unsafely-log-tls-keys")
+    .action(ArgAction::SetTrue)
+    .help(
+      "Log TLS session secrets to the file named by the SSLKEYLOGFILE environment variable, so the traffic can be decrypted for debugging",
+    )
+    .help_heading(DEBUGGING_HEADING)
+}
+
 fn v8_flags_arg() -> Arg {
   Arg::new("v8-flags")
     .long("v8-flags")
@@ -5230,6 +5542,37 @@ fn no_check_arg() -> Arg {
     .help_heading(TYPE_CHECKING_HEADING)
 }
 
+fn trace_leaks_arg() -> Arg {
+  Arg::new("trace-leaks")
+    .long("trace-leaks")
+    .help("Record where resources are created and report the files, sockets and other resources still open on exit, with the stack they were created from")
+    .action(ArgAction::SetTrue)
+}
+
+fn cpu_prof_arg() -> Arg {
+  Arg::new("cpu-prof")
+    .long("cpu-prof")
+    .value_name("FILE")
+    .num_args(0..=1)
+    .require_equals(true)
+    .default_missing_value("")
+    .help(cstr!("Write a V8 CPU profile to FILE on exit, or on SIGUSR2, which can be loaded in Chrome DevTools. If FILE is not specified, a name like 'CPU.20250101.120000.1234.cpuprofile' is used.
+  <p(245)>Each profile written on SIGUSR2 gets a sequence number before the extension.</>"))
+    .value_hint(ValueHint::FilePath)
+}
+
+fn heap_prof_arg() -> Arg {
+  Arg::new("heap-prof")
+    .long("heap-prof")
+    .value_name("FILE")
+    .num_args(0..=1)
+    .require_equals(true)
+    .default_missing_value("")
+    .help(cstr!("Write a V8 heap snapshot to FILE on exit, or on SIGUSR2, which can be loaded in Chrome DevTools. If FILE is not specified, a name like 'Heap.20250101.120000.1234.heapsnapshot' is used.
+  <p(245)>Each snapshot written on SIGUSR2 gets a sequence number before the extension.</>"))
+    .value_hint(ValueHint::FilePath)
+}
+
 fn tunnel_arg() -> Arg {
   Arg::new("tunnel")
     .long("tunnel")
@@ -5655,6 +5998,8 @@ fn bench_parse(
   flags.permissions.no_prompt = true;
 
   let json = matches.get_flag("json");
+  let baseline = matches.remove_one::<String>("baseline");
+  let regression_threshold = matches.remove_one::<u32>("regression-threshold");
   let ignore = match matches.remove_many::<String>("ignore") {
     Some(f) => f
       .flat_map(flat_escape_split_commas)
@@ -5681,6 +6026,8 @@ fn bench_parse(
     files: FileFlags { include, ignore },
     filter,
     json,
+    baseline,
+    regression_threshold,
     no_run,
     permit_no_files: permit_no_files_parse(matches),
     watch: watch_arg_parse(matches)?,
@@ -5775,6 +6122,19 @@ fn clean_parse(flags: &mut Flags, matches: &mut ArgMatches) {
   flags.subcommand = DenoSubcommand::Clean(clean_flags);
 }
 
+fn permissions_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let permissions_flags = match matches.remove_subcommand() {
+    Some((name, mut matches)) if name == "revoke" => PermissionsFlags::Revoke {
+      scripts: matches
+        .remove_many::<String>("scripts")
+        .map(|scripts| scripts.collect())
+        .unwrap_or_default(),
+    },
+    _ => PermissionsFlags::List,
+  };
+  flags.subcommand = DenoSubcommand::Permissions(permissions_flags);
+}
+
 fn compile_parse(
   flags: &mut Flags,
   matches: &mut ArgMatches,
@@ -6459,6 +6819,9 @@ fn run_parse(
   ext_arg_parse(flags, matches);
 
   flags.tunnel = matches.get_flag("tunnel");
+  flags.trace_leaks = matches.get_flag("trace-leaks");
+  flags.cpu_prof = matches.remove_one::<String>("cpu-prof");
+  flags.heap_prof = matches.remove_one::<String>("heap-prof");
   flags.code_cache_enabled = !matches.get_flag("no-code-cache");
   let coverage_dir = matches.remove_one::<String>("coverage");
 
@@ -6592,6 +6955,7 @@ fn task_parse(
     recursive,
     filter,
     eval: matches.get_flag("eval"),
+    parallel: matches.get_flag("parallel"),
   };
 
   match matches.remove_subcommand() {
@@ -6755,6 +7119,8 @@ fn test_parse(
     None
   };
 
+  let shard = matches.remove_one::<TestShard>("shard");
+
   if let Some(script_arg) = matches.remove_many::<String>("script_arg") {
     flags.argv.extend(script_arg);
   }
@@ -6795,6 +7161,8 @@ fn test_parse(
     files: FileFlags { include, ignore },
     filter,
     shuffle,
+    shard,
+    update_snapshots: matches.get_flag("update-snapshots"),
     permit_no_files: permit_no_files_parse(matches),
     parallel: matches.get_flag("parallel"),
     trace_leaks,
@@ -7092,6 +7460,26 @@ fn permission_args_parse(
     flags.permissions.no_prompt = true;
   }
 
+  if let Some(path) = matches.remove_one::<String>("permission-audit") {
+    flags.permission_audit = Some(path);
+  }
+
+  if matches.get_flag("remember-permissions") {
+    flags.remember_permissions = true;
+  }
+
+  if matches.get_flag("landlock") {
+    flags.landlock = true;
+  }
+
+  if matches.get_flag("seccomp-denylist") {
+    flags.seccomp_denylist = true;
+  }
+
+  if let Some(profile) = matches.remove_one::<String>("seccomp-profile") {
+    flags.seccomp_profile = Some(profile);
+  }
+
   Ok(())
 }
 
@@ -7147,6 +7535,7 @@ fn runtime_args_parse(
   enable_testing_features_arg_parse(flags, matches);
   env_file_arg_parse(flags, matches);
   trace_ops_parse(flags, matches);
+  unsafely_log_tls_keys_parse(flags, matches);
   eszip_arg_parse(flags, matches);
   preload_arg_parse(flags, matches);
   require_arg_parse(flags, matches);
@@ -7229,6 +7618,13 @@ fn trace_ops_parse(flags: &mut Flags, matches: &mut ArgMatches) {
   if let Some(patterns) = matches.remove_many::<String>("trace-ops") {
     flags.trace_ops = Some(patterns.collect());
   }
+  flags.trace_ops_output = matches.remove_one::<String>("trace-ops-output");
+}
+
+fn unsafely_log_tls_keys_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  if matches.get_flag("unsafely-log-tls-keys") {
+    flags.unsafely_log_tls_keys = true;
+  }
 }
 
 fn cached_only_arg_parse(flags: &mut Flags, matches: &mut ArgMatches) {
@@ -8192,6 +8588,87 @@ mod tests {
     );
   }
 
+  #[test]
+  fn permission_audit() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--permission-audit=audit.jsonl",
+      "gist.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        permission_audit: Some("audit.jsonl".to_string()),
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn landlock() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--landlock",
+      "--allow-read=./data",
+      "gist.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        permissions: PermissionFlags {
+          allow_read: Some(svec!["./data"]),
+          ..Default::default()
+        },
+        landlock: true,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn seccomp_profile() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--seccomp-profile=profile.json",
+      "gist.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
+        seccomp_profile: Some("profile.json".to_string()),
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "run", "--seccomp-denylist", "gist.ts"]);
+    assert!(r.unwrap().seccomp_denylist);
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--seccomp-denylist",
+      "--seccomp-profile=profile.json",
+      "gist.ts"
+    ]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn allow_read() {
     let r = flags_from_vec(svec!["deno", "run", "--allow-read", "gist.ts"]);
@@ -9310,6 +9787,65 @@ mod tests {
     );
   }
 
+  #[test]
+  fn run_trace_ops_output() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--strace-ops=op_fs_*",
+      "--trace-ops-output=trace.ndjson",
+      "script.ts"
+    ]);
+    let flags = r.unwrap();
+    assert_eq!(flags.trace_ops, Some(svec!["op_fs_*"]));
+    assert_eq!(flags.trace_ops_output, Some("trace.ndjson".to_string()));
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--trace-ops-output=trace.ndjson",
+      "script.ts"
+    ]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn run_trace_leaks() {
+    let r = flags_from_vec(svec!["deno", "run", "--trace-leaks", "script.ts"]);
+    let flags = r.unwrap();
+    assert!(flags.trace_leaks);
+    assert_eq!(
+      flags.subcommand,
+      DenoSubcommand::Run(RunFlags::new_default("script.ts".to_string()))
+    );
+
+    let r = flags_from_vec(svec!["deno", "run", "script.ts"]);
+    assert!(!r.unwrap().trace_leaks);
+  }
+
+  #[test]
+  fn run_cpu_prof_heap_prof() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--cpu-prof",
+      "--heap-prof=app.heapsnapshot",
+      "script.ts"
+    ]);
+    let flags = r.unwrap();
+    assert_eq!(flags.cpu_prof.as_deref(), Some(""));
+    assert_eq!(flags.heap_prof.as_deref(), Some("app.heapsnapshot"));
+    assert_eq!(
+      flags.subcommand,
+      DenoSubcommand::Run(RunFlags::new_default("script.ts".to_string()))
+    );
+
+    let r = flags_from_vec(svec!["deno", "run", "script.ts"]);
+    let flags = r.unwrap();
+    assert_eq!(flags.cpu_prof, None);
+    assert_eq!(flags.heap_prof, None);
+  }
+
   #[test]
   fn repl_with_flags() {
     #[rustfmt::skip]
@@ -10257,6 +10793,27 @@ mod tests {
     );
   }
 
+  #[test]
+  fn run_unsafely_log_tls_keys() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--unsafely-log-tls-keys",
+      "script.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string(),
+        )),
+        unsafely_log_tls_keys: true,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+  }
+
   #[test]
   fn run_seed_with_v8_flags() {
     let r = flags_from_vec(svec![
@@ -11001,6 +11558,8 @@ mod tests {
             ignore: vec![],
           },
           shuffle: None,
+          shard: None,
+          update_snapshots: false,
           parallel: false,
           trace_leaks: true,
           coverage_dir: Some("cov".to_string()),
@@ -11104,6 +11663,8 @@ mod tests {
           filter: None,
           permit_no_files: false,
           shuffle: None,
+          shard: None,
+          update_snapshots: false,
           files: FileFlags {
             include: vec![],
             ignore: vec![],
@@ -11148,6 +11709,8 @@ mod tests {
           filter: None,
           permit_no_files: false,
           shuffle: None,
+          shard: None,
+          update_snapshots: false,
           files: FileFlags {
             include: vec![],
             ignore: vec![],
@@ -11273,6 +11836,29 @@ mod tests {
     assert!(r.is_err());
   }
 
+  #[test]
+  fn test_shard() {
+    let r = flags_from_vec(svec!["deno", "test", "--shard=2/3"]);
+    let DenoSubcommand::Test(test_flags) = r.unwrap().subcommand else {
+      panic!("expected test subcommand");
+    };
+    assert_eq!(test_flags.shard, Some(TestShard { index: 2, count: 3 }));
+
+    for shard in ["0/3", "4/3", "1", "a/b"] {
+      let r = flags_from_vec(svec!["deno", "test", format!("--shard={shard}")]);
+      assert!(r.is_err(), "{shard}");
+    }
+  }
+
+  #[test]
+  fn test_update_snapshots() {
+    let r = flags_from_vec(svec!["deno", "test", "--update-snapshots"]);
+    let DenoSubcommand::Test(test_flags) = r.unwrap().subcommand else {
+      panic!("expected test subcommand");
+    };
+    assert!(test_flags.update_snapshots);
+  }
+
   #[test]
   fn test_shuffle() {
     let r = flags_from_vec(svec!["deno", "test", "--shuffle=1"]);
@@ -11286,6 +11872,8 @@ mod tests {
           filter: None,
           permit_no_files: false,
           shuffle: Some(1),
+          shard: None,
+          update_snapshots: false,
           files: FileFlags {
             include: vec![],
             ignore: vec![],
@@ -11323,6 +11911,8 @@ mod tests {
           filter: None,
           permit_no_files: false,
           shuffle: None,
+          shard: None,
+          update_snapshots: false,
           files: FileFlags {
             include: vec![],
             ignore: vec![],
@@ -11359,6 +11949,8 @@ mod tests {
           filter: None,
           permit_no_files: false,
           shuffle: None,
+          shard: None,
+          update_snapshots: false,
           files: FileFlags {
             include: vec!["./".to_string()],
             ignore: vec![],
@@ -11397,6 +11989,8 @@ mod tests {
           filter: None,
           permit_no_files: false,
           shuffle: None,
+          shard: None,
+          update_snapshots: false,
           files: FileFlags {
             include: vec![],
             ignore: vec![],
@@ -12259,6 +12853,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         argv: svec!["hello", "world"],
         ..Flags::default()
@@ -12276,6 +12871,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12292,6 +12888,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12308,6 +12905,7 @@ mod tests {
           recursive: false,
           filter: Some("*".to_string()),
           eval: false,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12324,6 +12922,7 @@ mod tests {
           recursive: true,
           filter: Some("*".to_string()),
           eval: false,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12340,6 +12939,7 @@ mod tests {
           recursive: true,
           filter: Some("*".to_string()),
           eval: false,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12356,6 +12956,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: true,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12387,6 +12988,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         argv: svec!["--", "hello", "world"],
         config_flag: ConfigFlag::Path("deno.json".to_owned()),
@@ -12407,6 +13009,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         argv: svec!["--", "hello", "world"],
         ..Flags::default()
@@ -12428,6 +13031,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         argv: svec!["--"],
         ..Flags::default()
@@ -12435,6 +13039,26 @@ mod tests {
     );
   }
 
+  #[test]
+  fn task_parallel() {
+    let r = flags_from_vec(svec!["deno", "task", "--parallel", "dev:*"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Task(TaskFlags {
+          cwd: None,
+          task: Some("dev:*".to_string()),
+          is_run: false,
+          recursive: false,
+          filter: None,
+          eval: false,
+          parallel: true,
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
   #[test]
   fn task_following_arg() {
     let r = flags_from_vec(svec!["deno", "task", "build", "-1", "--test"]);
@@ -12448,6 +13072,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         argv: svec!["-1", "--test"],
         ..Flags::default()
@@ -12468,6 +13093,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         argv: svec!["--test"],
         ..Flags::default()
@@ -12489,6 +13115,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         log_level: Some(log::Level::Error),
         ..Flags::default()
@@ -12509,6 +13136,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         ..Flags::default()
       }
@@ -12528,6 +13156,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
         ..Flags::default()
@@ -12548,6 +13177,7 @@ mod tests {
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         }),
         config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
         ..Flags::default()
@@ -12590,6 +13220,8 @@ mod tests {
         subcommand: DenoSubcommand::Bench(BenchFlags {
           filter: Some("- foo".to_string()),
           json: true,
+          baseline: None,
+          regression_threshold: None,
           no_run: true,
           files: FileFlags {
             include: vec!["dir1/".to_string(), "dir2/".to_string()],
@@ -12622,6 +13254,8 @@ mod tests {
         subcommand: DenoSubcommand::Bench(BenchFlags {
           filter: None,
           json: false,
+          baseline: None,
+          regression_threshold: None,
           no_run: false,
           files: FileFlags {
             include: vec![],
@@ -12649,6 +13283,8 @@ mod tests {
         subcommand: DenoSubcommand::Bench(BenchFlags {
           filter: None,
           json: false,
+          baseline: None,
+          regression_threshold: None,
           no_run: false,
           files: FileFlags {
             include: vec![],
@@ -12667,6 +13303,26 @@ mod tests {
     );
   }
 
+  #[test]
+  fn bench_baseline() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "bench",
+      "--baseline=main.json",
+      "--regression-threshold=5"
+    ]);
+    let flags = r.unwrap();
+    let DenoSubcommand::Bench(bench_flags) = flags.subcommand else {
+      panic!("expected bench subcommand");
+    };
+    assert_eq!(bench_flags.baseline.as_deref(), Some("main.json"));
+    assert_eq!(bench_flags.regression_threshold, Some(5));
+
+    // the threshold only applies to a baseline
+    let r = flags_from_vec(svec!["deno", "bench", "--regression-threshold=5"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn run_with_check() {
     let r = flags_from_vec(svec!["deno", "run", "--check", "script.ts",]);
@@ -13901,6 +14557,40 @@ Usage: deno repl [OPTIONS] [-- [ARGS]...]\n"
     }
   }
 
+  #[test]
+  fn permissions_subcommand() {
+    let cases = [
+      (svec!["deno", "permissions", "list"], PermissionsFlags::List),
+      (
+        svec!["deno", "permissions", "revoke", "a.ts", "b.ts"],
+        PermissionsFlags::Revoke {
+          scripts: svec!["a.ts", "b.ts"],
+        },
+      ),
+      (
+        svec!["deno", "permissions", "revoke", "--all"],
+        PermissionsFlags::Revoke { scripts: vec![] },
+      ),
+    ];
+    for (args, expected) in cases {
+      let r = flags_from_vec(args);
+      assert_eq!(
+        r.unwrap(),
+        Flags {
+          subcommand: DenoSubcommand::Permissions(expected),
+          ..Flags::default()
+        }
+      );
+    }
+
+    let r = flags_from_vec(svec!["deno", "permissions", "revoke"]);
+    assert!(r.is_err());
+
+    let r =
+      flags_from_vec(svec!["deno", "run", "--remember-permissions", "main.ts"]);
+    assert!(r.unwrap().remember_permissions);
+  }
+
   #[test]
   fn clean_subcommand() {
     let cases = [
@@ -53,6 +53,7 @@ use deno_runtime::deno_permissions::AllowRunDescriptor;
 use deno_runtime::deno_permissions::PathDescriptor;
 use deno_runtime::deno_permissions::PermissionsOptions;
 use deno_runtime::inspector_server::InspectorServer;
+use deno_runtime::profiler::ProfilerOptions;
 use deno_semver::StackString;
 use deno_semver::npm::NpmPackageReqReference;
 use deno_telemetry::OtelConfig;
@@ -84,6 +85,8 @@ pub fn jsr_api_url() -> &'static Url {
 pub struct WorkspaceBenchOptions {
   pub filter: Option<String>,
   pub json: bool,
+  pub baseline: Option<PathBuf>,
+  pub regression_threshold: u32,
   pub no_run: bool,
   pub permit_no_files: bool,
 }
@@ -93,6 +96,8 @@ impl WorkspaceBenchOptions {
     Self {
       filter: bench_flags.filter.clone(),
       json: bench_flags.json,
+      baseline: bench_flags.baseline.as_ref().map(PathBuf::from),
+      regression_threshold: bench_flags.regression_threshold.unwrap_or(10),
       no_run: bench_flags.no_run,
       permit_no_files: bench_flags.permit_no_files,
     }
@@ -202,6 +207,8 @@ pub struct WorkspaceTestOptions {
   pub permit_no_files: bool,
   pub filter: Option<String>,
   pub shuffle: Option<u64>,
+  pub shard: Option<TestShard>,
+  pub update_snapshots: bool,
   pub concurrent_jobs: NonZeroUsize,
   pub trace_leaks: bool,
   pub reporter: TestReporterConfig,
@@ -219,6 +226,8 @@ impl WorkspaceTestOptions {
       filter: test_flags.filter.clone(),
       no_run: test_flags.no_run,
       shuffle: test_flags.shuffle,
+      shard: test_flags.shard,
+      update_snapshots: test_flags.update_snapshots,
       trace_leaks: test_flags.trace_leaks,
       reporter: test_flags.reporter,
       junit_path: test_flags.junit_path.clone(),
@@ -1046,6 +1055,13 @@ impl CliOptions {
     &self.flags.location
   }
 
+  /// Whether permissions granted at prompts are remembered for the next
+  /// runs of the script, which is only supported by `deno run`.
+  pub fn remember_permissions(&self) -> bool {
+    self.flags.remember_permissions
+      && matches!(self.flags.subcommand, DenoSubcommand::Run(_))
+  }
+
   pub fn no_remote(&self) -> bool {
     self.flags.no_remote
   }
@@ -1216,6 +1232,40 @@ impl CliOptions {
     &self.flags.trace_ops
   }
 
+  pub fn trace_leaks(&self) -> bool {
+    self.flags.trace_leaks
+  }
+
+  /// Where `--cpu-prof` and `--heap-prof` write their profiles.
+  pub fn profiler_options(&self) -> ProfilerOptions {
+    // like Node, e.g. `CPU.20250101.120000.1234.cpuprofile`
+    let resolve = |path: &Option<String>, prefix: &str, ext: &str| {
+      path.as_ref().map(|path| {
+        if path.is_empty() {
+          self.initial_cwd.join(format!(
+            "{prefix}.{}.{}.{ext}",
+            chrono::Local::now().format("%Y%m%d.%H%M%S"),
+            std::process::id()
+          ))
+        } else {
+          self.initial_cwd.join(path)
+        }
+      })
+    };
+    ProfilerOptions {
+      cpu_prof: resolve(&self.flags.cpu_prof, "CPU", "cpuprofile"),
+      heap_prof: resolve(&self.flags.heap_prof, "Heap", "heapsnapshot"),
+    }
+  }
+
+  pub fn trace_ops_output(&self) -> Option<PathBuf> {
+    self
+      .flags
+      .trace_ops_output
+      .as_ref()
+      .map(|path| self.initial_cwd().join(path))
+  }
+
   pub fn take_binary_npm_command_name(&self) -> Option<String> {
     match self.sub_command() {
       DenoSubcommand::Run(flags) => {
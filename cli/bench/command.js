@@ -9,3 +9,23 @@ Deno.bench("cat 128kb", async () => {
     args: ["./cli/bench/testdata/128k.bin"],
   }).output();
 });
+
+// `deno task`-style workloads spawn many short-lived processes, sequentially
+// and in parallel. Plain spawns take the `posix_spawn` path, detached ones
+// fork and exec (see `create_command` in ext/process/lib.rs).
+Deno.bench("spawn true x100", async () => {
+  for (let i = 0; i < 100; i++) {
+    await new Deno.Command("true").output();
+  }
+});
+
+Deno.bench("spawn true x100 parallel", async () => {
+  await Promise.all(
+    Array.from({ length: 100 }, () => new Deno.Command("true").output()),
+  );
+});
+
+Deno.bench("spawn true detached", async () => {
+  await new Deno.Command("true", { detached: true, stdout: "null" }).spawn()
+    .status;
+});
@@ -0,0 +1,45 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+// deno-lint-ignore-file no-console
+
+// Measures the per-call cost of the synchronous file ops for small buffers,
+// where the op overhead is in the same range as the syscall itself:
+//
+//   deno run -A cli/bench/fs_read_write.js [rounds] [calls]
+
+let [total, count] = Deno.args;
+total = total ? parseInt(total, 0) : 5;
+count = count ? parseInt(count, 10) : 200000;
+
+const path = Deno.makeTempFileSync();
+const file = Deno.openSync(path, { read: true, write: true });
+file.writeSync(new Uint8Array(64 * 1024));
+
+function bench(name, fun) {
+  // warm up, so that the fast calls are used once the caller is optimized
+  for (let i = 0; i < 10000; i++) fun();
+  for (let round = 0; round < total; round++) {
+    const start = performance.now();
+    for (let i = 0; i < count; i++) fun();
+    const elapsed = performance.now() - start;
+    const nsPerCall = Math.round(elapsed * 1e6 / count);
+    const rate = Math.floor(count / (elapsed / 1000));
+    console.log(`${name}: ${nsPerCall} ns/call, rate ${rate}`);
+  }
+}
+
+bench("seekSync", () => file.seekSync(0, Deno.SeekMode.Start));
+
+for (const size of [1024, 4096]) {
+  const buf = new Uint8Array(size);
+  bench(`readSync ${size}B`, () => {
+    file.seekSync(0, Deno.SeekMode.Start);
+    file.readSync(buf);
+  });
+  bench(`writeSync ${size}B`, () => {
+    file.seekSync(0, Deno.SeekMode.Start);
+    file.writeSync(buf);
+  });
+}
+
+file.close();
+Deno.removeSync(path);
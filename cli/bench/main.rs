@@ -118,6 +118,15 @@ const EXEC_TIME_BENCHMARKS: &[(&str, &[&str], Option<i32>)] = &[
     &["run", "tests/testdata/benches/response_string_perf.js"],
     None,
   ),
+  (
+    "stat_small_files",
+    &[
+      "run",
+      "--allow-read=tests/testdata/benches",
+      "tests/testdata/benches/stat_small_files_perf.js",
+    ],
+    None,
+  ),
   // TODO(bartlomieju): temporarily disabled, because we can't upgrade `tests/util/std` submodule
   // due to needing it to be published.
   // (
@@ -55,6 +55,7 @@ use deno_runtime::deno_tls::rustls::RootCertStore;
 use deno_runtime::deno_web::BlobStore;
 use deno_runtime::inspector_server::InspectorServer;
 use deno_runtime::permissions::RuntimePermissionDescriptorParser;
+use deno_runtime::trace_ops::TraceOpsOutput;
 use node_resolver::NodeConditionOptions;
 use node_resolver::NodeResolverOptions;
 use node_resolver::cache::NodeResolutionThreadLocalCache;
@@ -74,6 +75,7 @@ use crate::cache::DenoDir;
 use crate::cache::GlobalHttpCache;
 use crate::cache::ModuleInfoCache;
 use crate::cache::SqliteNodeAnalysisCache;
+use crate::colors;
 use crate::file_fetcher::CliFileFetcher;
 use crate::file_fetcher::CreateCliFileFetcherOptions;
 use crate::file_fetcher::TextDecodedFile;
@@ -103,6 +105,7 @@ use crate::standalone::binary::DenoCompileBinaryWriter;
 use crate::sys::CliSys;
 use crate::tools::installer::BinNameResolver;
 use crate::tools::lint::LintRuleProvider;
+use crate::tools::permissions::PermissionGrantStore;
 use crate::tools::run::hmr::HmrRunnerState;
 use crate::tsc::TypeCheckingCjsTracker;
 use crate::type_checker::TypeChecker;
@@ -311,6 +314,7 @@ struct CliFactoryServices {
   module_info_cache: Deferred<Arc<ModuleInfoCache>>,
   module_load_preparer: Deferred<Arc<ModuleLoadPreparer>>,
   npm_installer_factory: Deferred<CliNpmInstallerFactory>,
+  permission_grant_store: Deferred<Option<Arc<PermissionGrantStore>>>,
   permission_desc_parser:
     Deferred<Arc<RuntimePermissionDescriptorParser<CliSys>>>,
   resolver_factory: Deferred<Arc<CliResolverFactory>>,
@@ -864,6 +868,7 @@ impl CliFactory {
             self.cli_options()?.clone(),
             self.module_load_preparer().await?.clone(),
             self.root_permissions_container()?.clone(),
+            self.permission_grant_store()?.clone(),
           )))
         }
         .boxed_local(),
@@ -959,15 +964,48 @@ impl CliFactory {
       .root_permissions_container
       .get_or_try_init(|| {
         let desc_parser = self.permission_desc_parser()?.clone();
-        let permissions = Permissions::from_options(
-          desc_parser.as_ref(),
-          &self.cli_options()?.permissions_options()?,
-        )?;
+        let mut options = self.cli_options()?.permissions_options()?;
+        if let Some(store) = self.permission_grant_store()? {
+          store.apply(&mut options);
+          store.clone().record_prompt_grants();
+        }
+        let permissions =
+          Permissions::from_options(desc_parser.as_ref(), &options)?;
 
         Ok(PermissionsContainer::new(desc_parser, permissions))
       })
   }
 
+  /// The permissions remembered for the main module with
+  /// `--remember-permissions`, which only applies to local scripts.
+  fn permission_grant_store(
+    &self,
+  ) -> Result<&Option<Arc<PermissionGrantStore>>, AnyError> {
+    self.services.permission_grant_store.get_or_try_init(|| {
+      let cli_options = self.cli_options()?;
+      if !cli_options.remember_permissions() {
+        return Ok(None);
+      }
+      let main_module = cli_options.resolve_main_module()?;
+      let Ok(path) = deno_path_util::url_to_file_path(main_module) else {
+        log::warn!(
+          "{} --remember-permissions only applies to local scripts.",
+          colors::yellow("Warning")
+        );
+        return Ok(None);
+      };
+      let source = std::fs::read(&path)
+        .with_context(|| format!("Failed reading '{}'.", path.display()))?;
+      let store = PermissionGrantStore::load(
+        &self.deno_dir()?.permission_grants_folder_path(),
+        &cli_options.workspace().root_dir_path(),
+        main_module,
+        &source,
+      )?;
+      Ok(Some(Arc::new(store)))
+    })
+  }
+
   fn workspace_external_import_map_loader(
     &self,
   ) -> Result<&Arc<WorkspaceExternalImportMapLoader<CliSys>>, AnyError> {
@@ -1119,6 +1157,15 @@ impl CliFactory {
       inspect_brk: cli_options.inspect_brk().is_some(),
       inspect_wait: cli_options.inspect_wait().is_some(),
       trace_ops: cli_options.trace_ops().clone(),
+      trace_ops_output: cli_options
+        .trace_ops_output()
+        .map(|path| {
+          TraceOpsOutput::create(&path).with_context(|| {
+            format!("Failed creating trace file '{}'.", path.display())
+          })
+        })
+        .transpose()?,
+      trace_leaks: cli_options.trace_leaks(),
       is_standalone: false,
       auto_serve: std::env::var("DENO_AUTO_SERVE").is_ok(),
       is_inspecting: cli_options.is_inspecting(),
@@ -1170,6 +1217,7 @@ impl CliFactory {
       needs_test_modules: cli_options.sub_command().needs_test(),
       create_hmr_runner,
       maybe_coverage_dir,
+      profiler_options: cli_options.profiler_options(),
       default_npm_caching_strategy: cli_options.default_npm_caching_strategy(),
       maybe_initial_cwd: Some(Arc::new(initial_cwd)),
     })
@@ -101,6 +101,7 @@ pub fn create_cli_file_fetcher(
     PermissionedFileFetcherOptions {
       allow_remote: options.allow_remote,
       cache_setting: options.cache_setting,
+      virtual_module_provider: None,
     },
   )
 }
@@ -250,6 +251,9 @@ mod tests {
   use deno_core::resolve_url;
   use deno_resolver::file_fetcher::FetchErrorKind;
   use deno_resolver::file_fetcher::FetchPermissionsOptionRef;
+  use deno_resolver::file_fetcher::VirtualModule;
+  use deno_resolver::file_fetcher::VirtualModuleProvider;
+  use deno_resolver::file_fetcher::VirtualModuleProviderRc;
   use deno_resolver::loader::MemoryFilesRc;
   use deno_runtime::deno_web::Blob;
   use deno_runtime::deno_web::InMemoryBlobPart;
@@ -452,6 +456,81 @@ mod tests {
     assert_eq!(file.specifier, specifier);
   }
 
+  #[derive(Debug, Default)]
+  struct TestVirtualModuleProvider {
+    loads: std::sync::atomic::AtomicUsize,
+  }
+
+  impl VirtualModuleProvider for TestVirtualModuleProvider {
+    fn handles(&self, specifier: &Url) -> bool {
+      specifier.scheme() == "db"
+    }
+
+    fn load(
+      &self,
+      specifier: &Url,
+    ) -> deno_core::futures::future::LocalBoxFuture<
+      'static,
+      Result<Option<VirtualModule>, deno_error::JsErrorBox>,
+    > {
+      self.loads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+      let module = (specifier.path() == "mod").then(|| VirtualModule {
+        source: b"export const a = 1;\n".as_slice().into(),
+        maybe_headers: Some(HashMap::from([(
+          "content-type".to_string(),
+          "application/typescript".to_string(),
+        )])),
+        cache: true,
+      });
+      Box::pin(std::future::ready(Ok(module)))
+    }
+  }
+
+  #[tokio::test]
+  async fn test_fetch_virtual_module() {
+    let temp_dir = TempDir::new();
+    let provider = Arc::new(TestVirtualModuleProvider::default());
+    let virtual_module_provider: VirtualModuleProviderRc = provider.clone();
+    let file_fetcher = CliFileFetcher::new(
+      BlobStoreAdapter(Default::default()),
+      Arc::new(GlobalOrLocalHttpCache::Global(Arc::new(
+        GlobalHttpCache::new(
+          CliSys::default(),
+          temp_dir.path().join("remote").to_path_buf(),
+        ),
+      ))),
+      HttpClientAdapter {
+        http_client_provider: Arc::new(HttpClientProvider::new(None, None)),
+        download_log_level: log::Level::Info,
+        progress_bar: None,
+      },
+      MemoryFilesRc::default(),
+      CliSys::default(),
+      PermissionedFileFetcherOptions {
+        allow_remote: false,
+        cache_setting: CacheSetting::Use,
+        virtual_module_provider: Some(virtual_module_provider),
+      },
+    );
+    let specifier = resolve_url("db:mod").unwrap();
+    for _ in 0..2 {
+      let file = file_fetcher
+        .fetch_bypass_permissions(&specifier)
+        .await
+        .unwrap();
+      let file = TextDecodedFile::decode(file).unwrap();
+      assert_eq!(&*file.source, "export const a = 1;\n");
+      assert_eq!(file.media_type, MediaType::TypeScript);
+    }
+    // cached in memory after the first load
+    assert_eq!(provider.loads.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+    let result = file_fetcher
+      .fetch_bypass_permissions(&resolve_url("db:missing").unwrap())
+      .await;
+    assert!(result.is_err());
+  }
+
   #[tokio::test]
   async fn test_fetch_complex() {
     let _http_server_guard = test_util::http_server();
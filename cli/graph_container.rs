@@ -14,6 +14,7 @@ use deno_runtime::deno_permissions::PermissionsContainer;
 use crate::args::CliOptions;
 use crate::module_loader::ModuleLoadPreparer;
 use crate::module_loader::PrepareModuleLoadOptions;
+use crate::tools::permissions::PermissionGrantStore;
 use crate::util::fs::collect_specifiers;
 use crate::util::path::is_script_ext;
 
@@ -47,6 +48,7 @@ pub struct MainModuleGraphContainer {
   cli_options: Arc<CliOptions>,
   module_load_preparer: Arc<ModuleLoadPreparer>,
   root_permissions: PermissionsContainer,
+  permission_grant_store: Option<Arc<PermissionGrantStore>>,
 }
 
 #[derive(Default, Debug)]
@@ -65,6 +67,7 @@ impl MainModuleGraphContainer {
     cli_options: Arc<CliOptions>,
     module_load_preparer: Arc<ModuleLoadPreparer>,
     root_permissions: PermissionsContainer,
+    permission_grant_store: Option<Arc<PermissionGrantStore>>,
   ) -> Self {
     Self {
       update_queue: Default::default(),
@@ -74,6 +77,7 @@ impl MainModuleGraphContainer {
       cli_options,
       module_load_preparer,
       root_permissions,
+      permission_grant_store,
     }
   }
 
@@ -156,6 +160,7 @@ impl ModuleGraphContainer for MainModuleGraphContainer {
       permit,
       inner: self.inner.clone(),
       graph: (**self.inner.read()).clone(),
+      permission_grant_store: self.permission_grant_store.clone(),
     }
   }
 
@@ -171,6 +176,7 @@ pub struct MainModuleGraphUpdatePermit<'a> {
   permit: deno_core::unsync::sync::TaskQueuePermit<'a>,
   inner: Arc<RwLock<Arc<ModuleGraph>>>,
   graph: ModuleGraph,
+  permission_grant_store: Option<Arc<PermissionGrantStore>>,
 }
 
 impl ModuleGraphUpdatePermit for MainModuleGraphUpdatePermit<'_> {
@@ -179,6 +185,9 @@ impl ModuleGraphUpdatePermit for MainModuleGraphUpdatePermit<'_> {
   }
 
   fn commit(self) {
+    if let Some(store) = &self.permission_grant_store {
+      store.set_module_graph(&self.graph);
+    }
     *self.inner.write() = Arc::new(self.graph);
     drop(self.permit); // explicit drop for clarity
   }
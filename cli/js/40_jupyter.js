@@ -510,18 +510,249 @@ function enableJupyter() {
     if (options.update) {
       messageType = "update_display_data";
     }
+    let displayId = options.display_id;
+    if (displayId === true) {
+      displayId = crypto.randomUUID();
+    }
     let transient = { __proto__: null };
-    if (options.display_id) {
-      transient = { display_id: options.display_id };
+    if (displayId) {
+      transient = { display_id: displayId };
     }
     await broadcast(messageType, {
       data: bundle,
       metadata: {},
       transient,
     });
+    if (displayId) {
+      return new DisplayHandle(displayId);
+    }
     return;
   }
 
+  /**
+   * A display that can be updated in place while a cell is still running,
+   * like a progress bar.
+   */
+  class DisplayHandle {
+    #displayId;
+
+    constructor(displayId) {
+      this.#displayId = displayId;
+    }
+
+    get displayId() {
+      return this.#displayId;
+    }
+
+    async update(obj, options = { raw: false }) {
+      await display(obj, {
+        raw: options.raw,
+        update: true,
+        display_id: this.#displayId,
+      });
+    }
+  }
+
+  /**
+   * Clears the output of the current cell, or with `wait` once new output
+   * is displayed, which avoids flickering when it is redrawn.
+   */
+  async function clearOutput({ wait = false } = {}) {
+    await broadcast("clear_output", { wait });
+  }
+
+  /** @type {Map<string, (comm: Comm, message: object) => unknown>} */
+  const commTargets = new Map();
+  /** @type {Map<string, Comm>} */
+  const comms = new Map();
+  let markCommClosed;
+
+  /**
+   * A comm, a channel for custom messages between the kernel and the
+   * frontend, like for widgets.
+   */
+  class Comm {
+    #id;
+    #targetName;
+    #closed = false;
+    onmessage = null;
+    onclose = null;
+
+    constructor(id, targetName) {
+      this.#id = id;
+      this.#targetName = targetName;
+    }
+
+    get id() {
+      return this.#id;
+    }
+
+    get targetName() {
+      return this.#targetName;
+    }
+
+    get closed() {
+      return this.#closed;
+    }
+
+    async send(data, extra) {
+      if (this.#closed) {
+        throw new Error(`Comm ${this.#id} is closed`);
+      }
+      await broadcast("comm_msg", { comm_id: this.#id, data }, extra);
+    }
+
+    async close(data = {}, extra) {
+      if (this.#closed) {
+        return;
+      }
+      this.#closed = true;
+      comms.delete(this.#id);
+      await broadcast("comm_close", { comm_id: this.#id, data }, extra);
+    }
+
+    static {
+      markCommClosed = (comm) => {
+        comm.#closed = true;
+        comms.delete(comm.#id);
+      };
+    }
+  }
+
+  /**
+   * Registers the callback that is called when the frontend opens a comm for
+   * the target.
+   */
+  function registerCommTarget(targetName, callback) {
+    if (typeof callback !== "function") {
+      throw new TypeError("The comm target callback must be a function");
+    }
+    commTargets.set(targetName, callback);
+  }
+
+  /** Opens a comm to a target of the frontend. */
+  async function openComm(targetName, data = {}, extra) {
+    const comm = new Comm(crypto.randomUUID(), targetName);
+    comms.set(comm.id, comm);
+    await broadcast("comm_open", {
+      comm_id: comm.id,
+      target_name: targetName,
+      data,
+    }, extra);
+    return comm;
+  }
+
+  /** Dispatches a comm message that the frontend sent. */
+  async function handleCommMessage(msgType, content, metadata) {
+    const message = { data: content.data ?? {}, metadata: metadata ?? {} };
+    try {
+      if (msgType === "comm_open") {
+        const callback = commTargets.get(content.target_name);
+        if (callback === undefined) {
+          await broadcast("comm_close", { comm_id: content.comm_id, data: {} });
+          return;
+        }
+        const comm = new Comm(content.comm_id, content.target_name);
+        comms.set(comm.id, comm);
+        await callback(comm, message);
+      } else if (msgType === "comm_msg") {
+        await comms.get(content.comm_id)?.onmessage?.(message);
+      } else if (msgType === "comm_close") {
+        const comm = comms.get(content.comm_id);
+        if (comm !== undefined) {
+          markCommClosed(comm);
+          await comm.onclose?.(message);
+        }
+      }
+    } catch (err) {
+      // deno-lint-ignore no-console
+      console.error(err);
+    }
+  }
+
+  internals.jupyter.handleCommMessage = handleCommMessage;
+
+  const WIDGET_PROTOCOL_VERSION = "2.1.0";
+
+  /**
+   * A widget of `@jupyter-widgets`, with its model state kept in sync with
+   * the frontend.
+   */
+  class Widget {
+    #comm;
+    #state;
+    onchange = null;
+
+    constructor(comm, state) {
+      this.#comm = comm;
+      this.#state = state;
+      comm.onmessage = (message) => this.#onMessage(message);
+    }
+
+    get id() {
+      return this.#comm.id;
+    }
+
+    get state() {
+      return { ...this.#state };
+    }
+
+    get(key) {
+      return this.#state[key];
+    }
+
+    async set(changes) {
+      Object.assign(this.#state, changes);
+      await this.#comm.send({
+        method: "update",
+        state: changes,
+        buffer_paths: [],
+      });
+    }
+
+    async close() {
+      await this.#comm.close();
+    }
+
+    async #onMessage({ data }) {
+      if (data.method === "update") {
+        Object.assign(this.#state, data.state);
+        await this.onchange?.(data.state);
+      } else if (data.method === "request_state") {
+        await this.#comm.send({
+          method: "update",
+          state: this.#state,
+          buffer_paths: [],
+        });
+      }
+    }
+
+    [$display]() {
+      const [versionMajor, versionMinor] = WIDGET_PROTOCOL_VERSION.split(".");
+      return {
+        "application/vnd.jupyter.widget-view+json": {
+          model_id: this.#comm.id,
+          version_major: Number(versionMajor),
+          version_minor: Number(versionMinor),
+        },
+        "text/plain": `${this.#state._model_name ?? "Widget"}()`,
+      };
+    }
+  }
+
+  /**
+   * Creates a widget from its model state, like `_model_name`,
+   * `_model_module` and the values of the model. Displaying it renders the
+   * widget's view.
+   */
+  async function createWidget(state) {
+    const comm = await openComm("jupyter.widget", {
+      state,
+      buffer_paths: [],
+    }, { metadata: { version: WIDGET_PROTOCOL_VERSION } });
+    return new Widget(comm, { ...state });
+  }
+
   /**
    * Prompt for user confirmation (in Jupyter Notebook context)
    * Override confirm and prompt because they depend on a tty
@@ -563,7 +794,11 @@ function enableJupyter() {
   globalThis.prompt = prompt;
   globalThis.Deno.jupyter = {
     broadcast,
+    clearOutput,
+    createWidget,
     display,
+    openComm,
+    registerCommTarget,
     format,
     md,
     html,
@@ -10,6 +10,7 @@ import {
 import { core, internals } from "ext:core/mod.js";
 
 const {
+  op_lint_get_binding,
   op_lint_get_source,
   op_lint_report,
   op_lint_create_serialized_ast,
@@ -263,6 +264,14 @@ export class SourceCode {
     return ancestors;
   }
 
+  /**
+   * @param {Deno.lint.Identifier} node
+   * @returns {Deno.lint.Binding | null}
+   */
+  getBinding(node) {
+    return op_lint_get_binding(node.range[0]);
+  }
+
   /**
    * @returns {Array<Deno.lint.LineComment | Deno.lint.BlockComment>}
    */
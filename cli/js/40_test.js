@@ -8,6 +8,7 @@ const {
   op_register_test_step,
   op_register_test,
   op_register_test_hook,
+  op_test_assert_snapshot,
   op_test_event_step_result_failed,
   op_test_event_step_result_ignored,
   op_test_event_step_result_ok,
@@ -22,12 +23,19 @@ const {
   Map,
   MapPrototypeGet,
   MapPrototypeSet,
+  NumberIsFinite,
   SafeArrayIterator,
+  StringPrototypeReplaceAll,
   SymbolToStringTag,
   TypeError,
 } = primordials;
 
 import { setExitHandler } from "ext:deno_os/30_os.js";
+import {
+  installVirtualClock,
+  uninstallVirtualClock,
+} from "ext:deno_web/02_timers.js";
+import { now as performanceNow } from "ext:deno_web/15_performance.js";
 
 // Capture `Deno` global so that users deleting or mangling it, won't
 // have impact on our sanitizers.
@@ -46,6 +54,7 @@ const DenoNs = globalThis.Deno;
  *   sanitizeResources: boolean,
  *   sanitizeExit: boolean,
  *   permissions: PermissionOptions,
+ *   virtualTime: boolean | { now?: number },
  * }} TestDescription
  *
  * @typedef {{
@@ -115,6 +124,26 @@ function assertExit(fn, isTest) {
   };
 }
 
+// Drive the timers and `performance.now()` of the test by a virtual clock,
+// which the test advances through `t.clock`.
+function withVirtualTime(fn, desc) {
+  return async function applyVirtualTime(...params) {
+    const now = desc.virtualTime.now ?? DateNow();
+    if (typeof now !== "number" || !NumberIsFinite(now)) {
+      throw new TypeError(
+        "The 'virtualTime.now' option must be a number of milliseconds",
+      );
+    }
+    desc.clock = installVirtualClock(now, performanceNow());
+    try {
+      return await fn(...new SafeArrayIterator(params));
+    } finally {
+      uninstallVirtualClock();
+      desc.clock = undefined;
+    }
+  };
+}
+
 function wrapOuter(fn, desc) {
   return async function outerWrapped() {
     try {
@@ -218,6 +247,7 @@ function testInner(
     sanitizeResources: true,
     sanitizeExit: true,
     permissions: null,
+    virtualTime: false,
   };
 
   if (typeof nameOrFnOrOptions === "string") {
@@ -381,6 +411,28 @@ function getFullName(desc) {
   return desc.name;
 }
 
+/** The name of the snapshots of a test or step, before they are numbered. */
+function getSnapshotName(desc) {
+  if ("parent" in desc) {
+    return `${getSnapshotName(desc.parent)} > ${desc.name}`;
+  }
+  return desc.name;
+}
+
+function serializeSnapshot(value) {
+  const text = DenoNs.inspect(value, {
+    depth: Infinity,
+    sorted: true,
+    trailingComma: true,
+    compact: false,
+    iterableLimit: Infinity,
+    strAbbreviateSize: Infinity,
+    breakLength: Infinity,
+    escapeSequences: false,
+  });
+  return StringPrototypeReplaceAll(text, "\r", "\\r");
+}
+
 function usesSanitizer(desc) {
   return desc.sanitizeResources || desc.sanitizeOps || desc.sanitizeExit;
 }
@@ -430,6 +482,22 @@ function createTestContext(desc) {
      * File Uri of the test code.
      */
     origin: desc.origin,
+    /**
+     * The virtual clock of a test with `virtualTime` set.
+     */
+    get clock() {
+      let rootDesc = desc;
+      while ("parent" in rootDesc) {
+        rootDesc = rootDesc.parent;
+      }
+      if (rootDesc.clock === undefined) {
+        throw new TypeError(
+          "The test does not use virtual time, set 'virtualTime: true' in " +
+            "its definition",
+        );
+      }
+      return rootDesc.clock;
+    },
     /**
      * @param nameOrFnOrOptions {string | TestStepDefinition | ((t: TestContext) => void | Promise<void>)}
      * @param maybeFn {((t: TestContext) => void | Promise<void>) | undefined}
@@ -514,6 +582,19 @@ function createTestContext(desc) {
       stepReportResult(stepDesc, result, elapsed);
       return result == "ok";
     },
+    /**
+     * @param actual {unknown}
+     * @param options {AssertSnapshotOptions | undefined}
+     */
+    assertSnapshot(actual, options = { __proto__: null }) {
+      const name = options.name ?? getSnapshotName(desc);
+      const serializer = options.serializer ?? serializeSnapshot;
+      const serialized = serializer(actual);
+      if (typeof serialized !== "string") {
+        throw new TypeError("The snapshot serializer must return a string");
+      }
+      op_test_assert_snapshot(name, serialized);
+    },
   };
 }
 
@@ -529,6 +610,9 @@ function wrapTest(desc) {
   if (desc.sanitizeExit) {
     testFn = assertExit(testFn, true);
   }
+  if (!("parent" in desc) && desc.virtualTime) {
+    testFn = withVirtualTime(testFn, desc);
+  }
   if (!("parent" in desc) && desc.permissions) {
     testFn = withPermissions(testFn, desc.permissions);
   }
@@ -88,6 +88,7 @@ pub struct Metadata {
   pub entrypoint_key: String,
   pub preload_modules: Vec<String>,
   pub require_modules: Vec<String>,
+  pub include_paths: Vec<String>,
   pub node_modules: Option<NodeModules>,
   pub unstable_config: UnstableConfig,
   pub otel_config: OtelConfig,
@@ -57,6 +57,41 @@ impl log::Log for CliLogger {
   }
 }
 
+/// How `DENO_LOG_FORMAT` says logs are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogFormat {
+  Text,
+  /// One JSON object per line, for tools collecting the logs.
+  Json,
+}
+
+impl LogFormat {
+  fn from_env() -> Self {
+    match std::env::var("DENO_LOG_FORMAT").as_deref() {
+      Ok("json" | "ndjson") => LogFormat::Json,
+      _ => LogFormat::Text,
+    }
+  }
+}
+
+fn format_json_record(
+  buf: &mut impl Write,
+  record: &log::Record,
+) -> std::io::Result<()> {
+  let time = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default();
+  let line = serde_json::json!({
+    "time": time,
+    "level": record.level().as_str(),
+    "target": record.target(),
+    "line": record.line(),
+    "message": record.args().to_string(),
+  });
+  writeln!(buf, "{line}")
+}
+
 pub struct InitLoggingOptions {
   pub on_log_start: fn(),
   pub on_log_end: fn(),
@@ -68,9 +103,12 @@ static LOGGER: OnceLock<CliLogger> = OnceLock::new();
 
 pub fn init(options: InitLoggingOptions) {
   let log_level = options.maybe_level.unwrap_or(log::Level::Info);
+  let log_format = LogFormat::from_env();
   let logger = env_logger::Builder::from_env(
     env_logger::Env::new()
-      // Use `DENO_LOG` and `DENO_LOG_STYLE` instead of `RUST_` prefix
+      // Use `DENO_LOG` and `DENO_LOG_STYLE` instead of `RUST_` prefix. Like
+      // `RUST_LOG`, it takes a level or a list of `module=level` filters,
+      // e.g. `DENO_LOG=info,deno_runtime::worker=debug`.
       .filter_or("DENO_LOG", log_level.to_level_filter().to_string())
       .write_style("DENO_LOG_STYLE"),
   )
@@ -101,12 +139,17 @@ pub fn init(options: InitLoggingOptions) {
   .filter_module("editpe", log::LevelFilter::Error)
   // too verbose
   .filter_module("cranelift_codegen", log::LevelFilter::Off)
-  .write_style(if deno_terminal::colors::use_color() {
-    env_logger::WriteStyle::Always
-  } else {
-    env_logger::WriteStyle::Never
-  })
-  .format(|buf, record| {
+  .write_style(
+    if log_format == LogFormat::Text && deno_terminal::colors::use_color() {
+      env_logger::WriteStyle::Always
+    } else {
+      env_logger::WriteStyle::Never
+    },
+  )
+  .format(move |buf, record| {
+    if log_format == LogFormat::Json {
+      return format_json_record(buf, record);
+    }
     let mut target = record.target().to_string();
     if let Some(line_no) = record.line() {
       target.push(':');
@@ -153,3 +196,30 @@ pub fn init(options: InitLoggingOptions) {
   let _ = log::set_logger(cli_logger);
   log::set_max_level(cli_logger.filter());
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn formats_json_records() {
+    let mut buf = Vec::new();
+    format_json_record(
+      &mut buf,
+      &log::Record::builder()
+        .args(format_args!("spawned {} threads", 4))
+        .level(log::Level::Debug)
+        .target("deno_runtime::worker")
+        .line(Some(12))
+        .build(),
+    )
+    .unwrap();
+    assert_eq!(buf.last(), Some(&b'\n'));
+    let record: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    assert_eq!(record["level"], "DEBUG");
+    assert_eq!(record["target"], "deno_runtime::worker");
+    assert_eq!(record["line"], 12);
+    assert_eq!(record["message"], "spawned 4 threads");
+    assert!(record["time"].as_u64().unwrap() > 0);
+  }
+}
@@ -43,6 +43,7 @@ use deno_runtime::fmt_errors::format_js_error;
 use deno_runtime::inspector_server::InspectorServer;
 use deno_runtime::inspector_server::MainInspectorSessionChannel;
 use deno_runtime::ops::worker_host::CreateWebWorkerCb;
+use deno_runtime::trace_ops::TraceOpsOutput;
 use deno_runtime::web_worker::WebWorker;
 use deno_runtime::web_worker::WebWorkerOptions;
 use deno_runtime::web_worker::WebWorkerServiceOptions;
@@ -334,6 +335,8 @@ pub struct LibMainWorkerOptions {
   pub inspect_brk: bool,
   pub inspect_wait: bool,
   pub trace_ops: Option<Vec<String>>,
+  pub trace_ops_output: Option<TraceOpsOutput>,
+  pub trace_leaks: bool,
   pub is_inspecting: bool,
   /// If this is a `deno compile`-ed executable.
   pub is_standalone: bool,
@@ -516,12 +519,15 @@ impl<TSys: DenoLibSys> LibWorkerFactorySharedState<TSys> {
         worker_type: args.worker_type,
         stdio: stdio.clone(),
         cache_storage_dir,
+        create_cache: None,
         trace_ops: shared.options.trace_ops.clone(),
+        trace_ops_output: shared.options.trace_ops_output.clone(),
         close_on_idle: args.close_on_idle,
         maybe_worker_metadata: args.maybe_worker_metadata,
         maybe_coverage_dir: shared.maybe_coverage_dir.clone(),
         enable_raw_imports: shared.options.enable_raw_imports,
         enable_stack_trace_arg_in_ops: has_trace_permissions_enabled(),
+        limits: args.limits,
       };
 
       WebWorker::bootstrap_from_options(services, options)
@@ -717,7 +723,10 @@ impl<TSys: DenoLibSys> LibMainWorkerFactory<TSys> {
       should_break_on_first_statement: shared.options.inspect_brk,
       should_wait_for_inspector_session: shared.options.inspect_wait,
       trace_ops: shared.options.trace_ops.clone(),
+      trace_ops_output: shared.options.trace_ops_output.clone(),
+      trace_leaks: shared.options.trace_leaks,
       cache_storage_dir,
+      create_cache: None,
       origin_storage_dir,
       stdio,
       skip_op_registration: shared.options.skip_op_registration,
@@ -359,6 +359,19 @@ impl Default for TestingSettings {
   }
 }
 
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedCodeSettings {
+  /// Flag for reporting the files and exports of the workspace which aren't
+  /// reachable from its entrypoints.
+  #[serde(default)]
+  pub enable: bool,
+  /// Paths of entrypoints, using the root_uri as a base, in addition to the
+  /// `exports` of the workspace's packages and its test and bench modules.
+  #[serde(default)]
+  pub entrypoints: Vec<String>,
+}
+
 fn default_to_true() -> bool {
   true
 }
@@ -599,6 +612,10 @@ pub struct WorkspaceSettings {
   #[serde(default)]
   pub unstable: SafeValue<Vec<String>>,
 
+  /// Unused code analysis settings for the workspace.
+  #[serde(default)]
+  pub unused_code: UnusedCodeSettings,
+
   #[serde(default)]
   pub javascript: LanguageWorkspaceSettings,
 
@@ -631,6 +648,7 @@ impl Default for WorkspaceSettings {
       tls_certificate: None,
       unsafely_ignore_certificate_errors: None,
       unstable: Default::default(),
+      unused_code: Default::default(),
       javascript: Default::default(),
       typescript: Default::default(),
       tracing: Default::default(),
@@ -2167,6 +2185,10 @@ mod tests {
         tls_certificate: None,
         unsafely_ignore_certificate_errors: None,
         unstable: Default::default(),
+        unused_code: UnusedCodeSettings {
+          enable: false,
+          entrypoints: vec![],
+        },
         javascript: LanguageWorkspaceSettings {
           inlay_hints: InlayHintsSettings {
             parameter_names: InlayHintsParamNamesOptions {
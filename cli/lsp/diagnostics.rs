@@ -53,6 +53,7 @@ use crate::lsp::documents::OpenDocument;
 use crate::lsp::language_server::OnceCellMap;
 use crate::lsp::lint::LspLinter;
 use crate::lsp::logging::lsp_warn;
+use crate::lsp::unused_code::UnusedCode;
 use crate::lsp::urls::uri_to_url;
 use crate::sys::CliSys;
 use crate::tsc::DiagnosticCategory;
@@ -236,6 +237,14 @@ impl DiagnosticsServer {
             if should_send_batch_notifications {
               client.send_diagnostic_batch_start_notification();
             }
+            let unused_code = tokio::task::spawn_blocking({
+              let snapshot = snapshot.clone();
+              let token = token.clone();
+              move || UnusedCode::analyze(&snapshot, &token)
+            })
+            .await
+            .map(Arc::new)
+            .unwrap_or_default();
             let open_docs =
               snapshot.document_modules.documents.open_docs().cloned();
             deno_core::futures::stream::iter(open_docs.map(|document| {
@@ -243,6 +252,7 @@ impl DiagnosticsServer {
               let ts_server = ts_server.clone();
               let ambient_modules_regex_cache =
                 ambient_modules_regex_cache.clone();
+              let unused_code = unused_code.clone();
               let token = token.clone();
               AbortOnDropHandle::new(tokio::task::spawn(async move {
                 let diagnostics = generate_document_diagnostics(
@@ -250,6 +260,7 @@ impl DiagnosticsServer {
                   &snapshot,
                   &ts_server,
                   &ambient_modules_regex_cache,
+                  &unused_code,
                   &token,
                 )
                 .await
@@ -493,6 +504,12 @@ struct DiagnosticDataNoLocal {
   pub message: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticDataRange {
+  pub range: lsp::Range,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct DiagnosticDataImportMapRemap {
@@ -528,6 +545,14 @@ pub enum DenoDiagnostic {
   UnknownNodeSpecifier(ModuleSpecifier),
   /// Bare specifier is used for `node:` specifier
   BareNodeSpecifier(String),
+  /// A file which isn't reachable from the entrypoints of the workspace.
+  UnusedFile,
+  /// An export which no module of the workspace imports, with the range of
+  /// its `export` keyword when it can be removed.
+  UnusedExport {
+    name: String,
+    keyword_range: Option<lsp::Range>,
+  },
 }
 
 impl DenoDiagnostic {
@@ -568,6 +593,8 @@ impl DenoDiagnostic {
       }
       Self::UnknownNodeSpecifier(_) => "resolver-error",
       Self::BareNodeSpecifier(_) => "import-node-prefix-missing",
+      Self::UnusedFile => "unused-file",
+      Self::UnusedExport { .. } => "unused-export",
     }
   }
 
@@ -724,6 +751,46 @@ impl DenoDiagnostic {
             ..Default::default()
           }
         }
+        "unused-file" => lsp::CodeAction {
+          title: "Delete this unused file.".to_string(),
+          kind: Some(lsp::CodeActionKind::QUICKFIX),
+          diagnostics: Some(vec![diagnostic.clone()]),
+          edit: Some(lsp::WorkspaceEdit {
+            document_changes: Some(lsp::DocumentChanges::Operations(vec![
+              lsp::DocumentChangeOperation::Op(lsp::ResourceOp::Delete(
+                lsp::DeleteFile {
+                  uri: uri.clone(),
+                  options: None,
+                },
+              )),
+            ])),
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+        "unused-export" => {
+          let data = diagnostic
+            .data
+            .clone()
+            .ok_or_else(|| anyhow!("Diagnostic is missing data"))?;
+          let data: DiagnosticDataRange = serde_json::from_value(data)?;
+          lsp::CodeAction {
+            title: "Remove the export keyword.".to_string(),
+            kind: Some(lsp::CodeActionKind::QUICKFIX),
+            diagnostics: Some(vec![diagnostic.clone()]),
+            edit: Some(lsp::WorkspaceEdit {
+              changes: Some(HashMap::from([(
+                uri.clone(),
+                vec![lsp::TextEdit {
+                  new_text: "".to_string(),
+                  range: data.range,
+                }],
+              )])),
+              ..Default::default()
+            }),
+            ..Default::default()
+          }
+        }
         _ => {
           return Err(anyhow!(
             "Unsupported diagnostic code (\"{}\") provided.",
@@ -748,8 +815,9 @@ impl DenoDiagnostic {
         | "not-installed-npm"
         | "no-attribute-type"
         | "redirect"
-        | "import-node-prefix-missing" => true,
-        "no-local" => diagnostic.data.is_some(),
+        | "import-node-prefix-missing"
+        | "unused-file" => true,
+        "no-local" | "unused-export" => diagnostic.data.is_some(),
         _ => false,
       }
     } else {
@@ -807,7 +875,11 @@ impl DenoDiagnostic {
       )},
       Self::UnknownNodeSpecifier(specifier) => (lsp::DiagnosticSeverity::ERROR, format!("No such built-in module: node:{}", specifier.path()), None),
       Self::BareNodeSpecifier(specifier) => (lsp::DiagnosticSeverity::WARNING, format!("\"{0}\" is resolved to \"node:{0}\". If you want to use a built-in Node module, add a \"node:\" prefix.", specifier), Some(json!({ "specifier": specifier }))),
+      Self::UnusedFile => (lsp::DiagnosticSeverity::HINT, "This file isn't reachable from the entrypoints of the workspace.".to_string(), None),
+      Self::UnusedExport { name, keyword_range } => (lsp::DiagnosticSeverity::HINT, format!("\"{name}\" is exported but never imported in the workspace."), keyword_range.map(|range| json!({ "range": range }))),
     };
+    let tags = matches!(self, Self::UnusedFile | Self::UnusedExport { .. })
+      .then(|| vec![lsp::DiagnosticTag::UNNECESSARY]);
     lsp::Diagnostic {
       range: *range,
       severity: Some(severity),
@@ -815,6 +887,7 @@ impl DenoDiagnostic {
       source: Some(DiagnosticSource::Deno.as_lsp_source().to_string()),
       message,
       data,
+      tags,
       ..Default::default()
     }
   }
@@ -1218,6 +1291,7 @@ async fn generate_document_diagnostics(
     (CompilerOptionsKey, Option<Arc<Uri>>),
     Option<regex::Regex>,
   >,
+  unused_code: &UnusedCode,
   token: &CancellationToken,
 ) -> Result<Vec<lsp::Diagnostic>, AnyError> {
   if !document.is_diagnosable() {
@@ -1253,6 +1327,7 @@ async fn generate_document_diagnostics(
     snapshot,
     ts_server,
     ambient_modules_regex_cache,
+    unused_code,
     token,
   )
   .await
@@ -1266,6 +1341,7 @@ pub async fn generate_module_diagnostics(
     (CompilerOptionsKey, Option<Arc<Uri>>),
     Option<regex::Regex>,
   >,
+  unused_code: &UnusedCode,
   token: &CancellationToken,
 ) -> Result<Vec<lsp::Diagnostic>, AnyError> {
   let deps_handle = tokio::task::spawn_blocking({
@@ -1396,6 +1472,8 @@ pub async fn generate_module_diagnostics(
     })
     .unwrap_or_default();
   diagnostics.extend(lint_diagnostics);
+  diagnostics
+    .extend(unused_code.diagnostics(&module.specifier).iter().cloned());
 
   Ok(diagnostics)
 }
@@ -116,6 +116,7 @@ use crate::lsp::diagnostics::generate_module_diagnostics;
 use crate::lsp::lint::LspLinterResolver;
 use crate::lsp::logging::init_log_file;
 use crate::lsp::tsc::file_text_changes_to_workspace_edit;
+use crate::lsp::unused_code::UnusedCode;
 use crate::sys::CliSys;
 use crate::tools::fmt::format_file;
 use crate::tools::fmt::format_parsed_source;
@@ -317,6 +318,8 @@ pub struct Inner {
   task_queue: LanguageServerTaskQueue,
   ts_fixable_diagnostics: tokio::sync::OnceCell<Vec<String>>,
   pub ts_server: Arc<TsServer>,
+  /// The workspace-wide unused code analysis of the current project version.
+  unused_code: OnceCell<Arc<UnusedCode>>,
   workspace_files: Arc<IndexSet<PathBuf>>,
   /// Set to `self.config.settings.enable_settings_hash()` after
   /// refreshing `self.workspace_files`.
@@ -611,6 +614,7 @@ impl Inner {
       resolver: Default::default(),
       ts_fixable_diagnostics: Default::default(),
       ts_server,
+      unused_code: Default::default(),
       workspace_files: Default::default(),
       workspace_files_hash: 0,
       _tracing: Default::default(),
@@ -3206,11 +3210,13 @@ impl Inner {
       .clone();
     diagnostics_cell
       .get_or_try_init(async || {
+        let unused_code = self.unused_code().await;
         let diagnostics = generate_module_diagnostics(
           module,
           &self.snapshot(),
           &self.ts_server,
           &self.ambient_modules_regex_cache,
+          &unused_code,
           token,
         )
         .await
@@ -3231,6 +3237,25 @@ impl Inner {
       .cloned()
   }
 
+  async fn unused_code(&self) -> Arc<UnusedCode> {
+    self
+      .unused_code
+      .get_or_init(async || {
+        let snapshot = self.snapshot();
+        // shared by the requests of the project version, so not cancelled
+        // with any of them
+        let token = CancellationToken::new();
+        tokio::task::spawn_blocking(move || {
+          UnusedCode::analyze(&snapshot, &token)
+        })
+        .await
+        .map(Arc::new)
+        .unwrap_or_default()
+      })
+      .await
+      .clone()
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub async fn goto_implementation(
     &self,
@@ -4065,6 +4090,7 @@ impl Inner {
   ) {
     self.ambient_modules_regex_cache.clear();
     self.diagnostics_cache.clear();
+    self.unused_code = Default::default();
     self.project_version += 1; // increment before getting the snapshot
     self.ts_server.project_changed(
       self.snapshot(),
@@ -37,6 +37,7 @@ mod testing;
 mod text;
 mod trace;
 mod tsc;
+mod unused_code;
 mod urls;
 
 pub async fn start() -> Result<(), AnyError> {
@@ -316,6 +316,7 @@ pub fn get_repl_workspace_settings() -> WorkspaceSettings {
     tls_certificate: None,
     unsafely_ignore_certificate_errors: None,
     unstable: Default::default(),
+    unused_code: Default::default(),
     suggest: DenoCompletionSettings {
       imports: ImportCompletionSettings {
         auto_discover: false,
@@ -330,6 +330,8 @@ impl TestRun {
             test::TestSpecifierOptions {
               filter,
               shuffle: None,
+              shard: None,
+              update_snapshots: false,
               trace_leaks: false,
             },
           ))
@@ -0,0 +1,452 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Workspace-wide unused code analysis, enabled with the
+//! `deno.unusedCode.enable` setting. The modules of the workspace are
+//! walked from its entrypoints, which are the `deno.unusedCode.entrypoints`
+//! paths, the `exports` of its packages and its test and bench modules.
+//! Files that aren't reached are reported, as are exports of reached modules
+//! that no module imports.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use deno_ast::MediaType;
+use deno_ast::ParsedSource;
+use deno_ast::ProgramRef;
+use deno_ast::SourceRange;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::swc::ast::Decl;
+use deno_ast::swc::ast::ExportSpecifier;
+use deno_ast::swc::ast::ImportSpecifier;
+use deno_ast::swc::ast::ModuleDecl;
+use deno_ast::swc::ast::ModuleExportName;
+use deno_ast::swc::ast::ModuleItem;
+use deno_ast::swc::ast::Pat;
+use deno_ast::swc::ast::TsModuleName;
+use deno_core::url::Url;
+use deno_path_util::url_to_file_path;
+use tokio_util::sync::CancellationToken;
+use tower_lsp::lsp_types as lsp;
+
+use super::analysis::source_range_to_lsp_range;
+use super::diagnostics::DenoDiagnostic;
+use super::documents::DocumentModule;
+use super::language_server::StateSnapshot;
+
+/// The names a module imports from another, or `None` when it may use all
+/// of them, like with a namespace or dynamic import.
+type ImportedNames = Option<HashSet<String>>;
+
+struct Export {
+  name: String,
+  range: lsp::Range,
+  /// The range of the `export` keyword, when removing it leaves a valid
+  /// declaration.
+  keyword_range: Option<lsp::Range>,
+}
+
+#[derive(Default)]
+struct ModuleInfo {
+  exports: Vec<Export>,
+  /// The names imported with each specifier text, from static imports and
+  /// re-exports.
+  imports: HashMap<String, ImportedNames>,
+}
+
+fn export_name(name: &ModuleExportName) -> Option<String> {
+  match name {
+    ModuleExportName::Ident(ident) => Some(ident.sym.to_string()),
+    ModuleExportName::Str(_) => None,
+  }
+}
+
+fn add_imported_name(
+  imports: &mut HashMap<String, ImportedNames>,
+  specifier: &str,
+  name: Option<String>,
+) {
+  let entry = imports
+    .entry(specifier.to_string())
+    .or_insert_with(|| Some(HashSet::new()));
+  match (entry.as_mut(), name) {
+    (Some(names), Some(name)) => {
+      names.insert(name);
+    }
+    _ => *entry = None,
+  }
+}
+
+fn analyze_module(parsed_source: &ParsedSource) -> ModuleInfo {
+  let mut info = ModuleInfo::default();
+  let ProgramRef::Module(module) = parsed_source.program_ref() else {
+    return info;
+  };
+  let text_info = parsed_source.text_info_lazy();
+  let to_lsp_range =
+    |range: SourceRange| source_range_to_lsp_range(&range, text_info);
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(decl) = item else {
+      continue;
+    };
+    match decl {
+      ModuleDecl::Import(import) => {
+        let specifier = import.src.value.to_string_lossy();
+        if import.specifiers.is_empty() {
+          // a side effect import only makes the module reachable
+          info
+            .imports
+            .entry(specifier.to_string())
+            .or_insert_with(|| Some(HashSet::new()));
+        }
+        for import_specifier in &import.specifiers {
+          let name = match import_specifier {
+            ImportSpecifier::Named(named) => match &named.imported {
+              Some(imported) => export_name(imported),
+              None => Some(named.local.sym.to_string()),
+            },
+            ImportSpecifier::Default(_) => Some("default".to_string()),
+            ImportSpecifier::Namespace(_) => None,
+          };
+          add_imported_name(&mut info.imports, &specifier, name);
+        }
+      }
+      ModuleDecl::ExportDecl(export) => {
+        let keyword_range =
+          to_lsp_range(SourceRange::new(export.start(), export.decl.start()));
+        let mut add = |ident: &deno_ast::swc::ast::Ident| {
+          info.exports.push(Export {
+            name: ident.sym.to_string(),
+            range: to_lsp_range(ident.range()),
+            keyword_range: Some(keyword_range),
+          });
+        };
+        match &export.decl {
+          Decl::Class(class) => add(&class.ident),
+          Decl::Fn(func) => add(&func.ident),
+          Decl::Var(var) => {
+            for declarator in &var.decls {
+              if let Pat::Ident(binding) = &declarator.name {
+                add(&binding.id);
+              }
+            }
+          }
+          Decl::TsInterface(interface) => add(&interface.id),
+          Decl::TsTypeAlias(alias) => add(&alias.id),
+          Decl::TsEnum(ts_enum) => add(&ts_enum.id),
+          Decl::TsModule(ts_module) => {
+            if let TsModuleName::Ident(ident) = &ts_module.id {
+              add(ident);
+            }
+          }
+          Decl::Using(_) => {}
+        }
+      }
+      ModuleDecl::ExportDefaultDecl(export) => {
+        info.exports.push(Export {
+          name: "default".to_string(),
+          range: to_lsp_range(export.range()),
+          keyword_range: None,
+        });
+      }
+      ModuleDecl::ExportDefaultExpr(export) => {
+        info.exports.push(Export {
+          name: "default".to_string(),
+          range: to_lsp_range(export.range()),
+          keyword_range: None,
+        });
+      }
+      ModuleDecl::ExportNamed(export) => {
+        let src = export.src.as_ref().map(|src| src.value.to_string_lossy());
+        for export_specifier in &export.specifiers {
+          let (exported, imported, range) = match export_specifier {
+            ExportSpecifier::Named(named) => (
+              export_name(named.exported.as_ref().unwrap_or(&named.orig)),
+              export_name(&named.orig),
+              named.range(),
+            ),
+            ExportSpecifier::Default(default) => (
+              Some(default.exported.sym.to_string()),
+              Some("default".to_string()),
+              default.range(),
+            ),
+            ExportSpecifier::Namespace(namespace) => {
+              (export_name(&namespace.name), None, namespace.range())
+            }
+          };
+          if let Some(exported) = exported {
+            info.exports.push(Export {
+              name: exported,
+              range: to_lsp_range(range),
+              keyword_range: None,
+            });
+          }
+          if let Some(src) = &src {
+            add_imported_name(&mut info.imports, src, imported);
+          }
+        }
+      }
+      ModuleDecl::ExportAll(export) => {
+        let specifier = export.src.value.to_string_lossy();
+        add_imported_name(&mut info.imports, &specifier, None);
+      }
+      ModuleDecl::TsImportEquals(_)
+      | ModuleDecl::TsExportAssignment(_)
+      | ModuleDecl::TsNamespaceExport(_) => {}
+    }
+  }
+  info
+}
+
+fn parse_module(module: &DocumentModule) -> Option<ParsedSource> {
+  if let Some(open_data) = &module.open_data {
+    return open_data.parsed_source.clone()?.ok();
+  }
+  deno_ast::parse_program(deno_ast::ParseParams {
+    specifier: module.specifier.as_ref().clone(),
+    text: module.text.to_arc(),
+    media_type: module.media_type,
+    capture_tokens: false,
+    scope_analysis: false,
+    maybe_syntax: None,
+  })
+  .ok()
+}
+
+fn is_test_or_bench_module(specifier: &Url) -> bool {
+  let Ok(path) = url_to_file_path(specifier) else {
+    return false;
+  };
+  crate::tools::test::is_supported_test_path(&path)
+    || crate::tools::bench::has_supported_bench_path_name(&path)
+}
+
+/// The result of the analysis, as diagnostics by module.
+#[derive(Debug, Default)]
+pub struct UnusedCode {
+  diagnostics: HashMap<Url, Vec<lsp::Diagnostic>>,
+}
+
+impl UnusedCode {
+  pub fn analyze(snapshot: &StateSnapshot, token: &CancellationToken) -> Self {
+    let config = &snapshot.config;
+    let enabled = |specifier: &Url| {
+      config
+        .workspace_settings_for_specifier(specifier)
+        .unused_code
+        .enable
+    };
+    let modules = snapshot
+      .document_modules
+      .workspace_file_modules_by_scope()
+      .into_values()
+      .flatten()
+      .filter(|module| {
+        module.specifier.scheme() == "file"
+          && module.is_diagnosable()
+          && !snapshot.resolver.in_node_modules(&module.specifier)
+      })
+      .map(|module| (module.specifier.as_ref().clone(), module))
+      .collect::<HashMap<Url, Arc<DocumentModule>>>();
+    if !modules.keys().any(enabled) {
+      return Self::default();
+    }
+
+    let mut entrypoints = HashSet::new();
+    for (folder, settings) in &config.settings.by_workspace_folder {
+      let settings = settings.as_deref().unwrap_or(&config.settings.unscoped);
+      for path in &settings.unused_code.entrypoints {
+        if let Ok(url) = folder.join(path) {
+          entrypoints.insert(url);
+        }
+      }
+    }
+    for data in config.tree.data_by_scope().values() {
+      if let Some(deno_json) = data.maybe_deno_json()
+        && let Ok(exports) = deno_json.resolve_export_value_urls()
+      {
+        entrypoints.extend(exports);
+      }
+    }
+    entrypoints.extend(
+      modules
+        .keys()
+        .filter(|specifier| is_test_or_bench_module(specifier))
+        .cloned(),
+    );
+
+    let mut infos = HashMap::with_capacity(modules.len());
+    let mut used = HashMap::<Url, ImportedNames>::new();
+    let mut reached = HashSet::new();
+    let mut queue = entrypoints
+      .iter()
+      .filter(|specifier| modules.contains_key(*specifier))
+      .cloned()
+      .collect::<VecDeque<_>>();
+    reached.extend(queue.iter().cloned());
+    while let Some(specifier) = queue.pop_front() {
+      if token.is_cancelled() {
+        return Self::default();
+      }
+      let module = &modules[&specifier];
+      let info = parse_module(module)
+        .map(|parsed_source| analyze_module(&parsed_source))
+        .unwrap_or_default();
+      let dependencies = module.dependencies.iter().map(|(key, dependency)| {
+        let names = match info.imports.get(key) {
+          Some(names) if !dependency.is_dynamic => names.clone(),
+          _ => None,
+        };
+        let specifiers = [
+          dependency.maybe_code.maybe_specifier(),
+          dependency.maybe_type.maybe_specifier(),
+        ];
+        (specifiers, names)
+      });
+      let types_dependency = module
+        .types_dependency
+        .iter()
+        .map(|types| ([types.dependency.maybe_specifier(), None], None));
+      for (specifiers, names) in dependencies.chain(types_dependency) {
+        for dependency in specifiers.into_iter().flatten() {
+          if !modules.contains_key(dependency) {
+            continue;
+          }
+          let entry = used
+            .entry(dependency.clone())
+            .or_insert_with(|| Some(HashSet::new()));
+          match (entry.as_mut(), &names) {
+            (Some(used), Some(names)) => used.extend(names.iter().cloned()),
+            _ => *entry = None,
+          }
+          if reached.insert(dependency.clone()) {
+            queue.push_back(dependency.clone());
+          }
+        }
+      }
+      infos.insert(specifier, info);
+    }
+
+    let mut diagnostics = HashMap::new();
+    for specifier in modules.keys() {
+      if !enabled(specifier) || entrypoints.contains(specifier) {
+        continue;
+      }
+      let Some(info) = infos.get(specifier) else {
+        let media_type = modules[specifier].media_type;
+        if !matches!(
+          media_type,
+          MediaType::Dts | MediaType::Dmts | MediaType::Dcts
+        ) {
+          diagnostics.insert(
+            specifier.clone(),
+            vec![
+              DenoDiagnostic::UnusedFile
+                .to_lsp_diagnostic(&lsp::Range::default()),
+            ],
+          );
+        }
+        continue;
+      };
+      let used = used.get(specifier);
+      let unused_exports = info
+        .exports
+        .iter()
+        .filter(|export| match used {
+          Some(Some(names)) => !names.contains(&export.name),
+          Some(None) => false,
+          None => true,
+        })
+        .map(|export| {
+          DenoDiagnostic::UnusedExport {
+            name: export.name.clone(),
+            keyword_range: export.keyword_range,
+          }
+          .to_lsp_diagnostic(&export.range)
+        })
+        .collect::<Vec<_>>();
+      if !unused_exports.is_empty() {
+        diagnostics.insert(specifier.clone(), unused_exports);
+      }
+    }
+    Self { diagnostics }
+  }
+
+  pub fn diagnostics(&self, specifier: &Url) -> &[lsp::Diagnostic] {
+    self
+      .diagnostics
+      .get(specifier)
+      .map(|d| d.as_slice())
+      .unwrap_or_default()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use deno_ast::ModuleSpecifier;
+
+  use super::*;
+
+  #[test]
+  fn collects_exports_and_imports() {
+    let parsed_source = deno_ast::parse_program(deno_ast::ParseParams {
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      text: r#"import { a, b as c } from "./a.ts";
+import d from "./d.ts";
+import * as e from "./e.ts";
+import "./f.ts";
+export { g } from "./g.ts";
+export * from "./h.ts";
+export const i = 1, [j] = [2];
+export function k() {}
+export default class {}
+"#
+      .into(),
+      media_type: MediaType::TypeScript,
+      capture_tokens: false,
+      scope_analysis: false,
+      maybe_syntax: None,
+    })
+    .unwrap();
+    let info = analyze_module(&parsed_source);
+    let names = |specifier: &str| {
+      info.imports[specifier].as_ref().map(|names| {
+        let mut names = names.iter().cloned().collect::<Vec<_>>();
+        names.sort();
+        names
+      })
+    };
+    assert_eq!(
+      names("./a.ts"),
+      Some(vec!["a".to_string(), "b".to_string()])
+    );
+    assert_eq!(names("./d.ts"), Some(vec!["default".to_string()]));
+    assert_eq!(names("./e.ts"), None);
+    assert_eq!(names("./f.ts"), Some(vec![]));
+    assert_eq!(names("./g.ts"), Some(vec!["g".to_string()]));
+    assert_eq!(names("./h.ts"), None);
+    let exports = info
+      .exports
+      .iter()
+      .map(|e| (e.name.as_str(), e.keyword_range.is_some()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      exports,
+      vec![("g", false), ("i", true), ("k", true), ("default", false)]
+    );
+    assert_eq!(
+      info.exports[2].keyword_range,
+      Some(lsp::Range {
+        start: lsp::Position {
+          line: 7,
+          character: 0,
+        },
+        end: lsp::Position {
+          line: 7,
+          character: 7,
+        },
+      })
+    );
+  }
+}
@@ -269,6 +269,7 @@ async fn run_subcommand(
           recursive: false,
           filter: None,
           eval: false,
+          parallel: false,
         };
         let mut flags = flags.deref().clone();
         flags.subcommand = DenoSubcommand::Task(task_flags.clone());
@@ -370,6 +371,7 @@ async fn run_subcommand(
                   recursive: false,
                   filter: None,
                   eval: false,
+                  parallel: false,
                 };
                 new_flags.subcommand = DenoSubcommand::Task(task_flags.clone());
                 let result = tools::task::execute_script(
@@ -440,6 +442,11 @@ async fn run_subcommand(
         }
       })
     }
+    DenoSubcommand::Permissions(permissions_flags) => {
+      spawn_subcommand(async move {
+        tools::permissions::permissions(flags, permissions_flags).await
+      })
+    }
     DenoSubcommand::Types => spawn_subcommand(async move {
       let types = tsc::get_types_declaration_file_text();
       display::write_to_stdout_ignore_sigpipe(types.as_bytes())
@@ -674,6 +681,19 @@ pub fn main() {
       Err(err) => return (Err(err), initial_cwd),
     };
 
+    if let Err(err) = maybe_install_seccomp_profile(&flags) {
+      return (Err(err), initial_cwd);
+    }
+
+    // before V8 starts its threads, which would not be restricted
+    if let Err(err) = util::landlock::maybe_restrict_fs(
+      &flags,
+      initial_cwd.as_deref(),
+      waited_unconfigured_runtime.is_some(),
+    ) {
+      return (Err(err), initial_cwd);
+    }
+
     if waited_unconfigured_runtime.is_none() {
       init_v8(&flags);
     }
@@ -782,7 +802,11 @@ async fn resolve_flags_and_init(
     );
   }
 
-  if let Ok(audit_path) = std::env::var("DENO_AUDIT_PERMISSIONS") {
+  let audit_path = flags
+    .permission_audit
+    .clone()
+    .or_else(|| std::env::var("DENO_AUDIT_PERMISSIONS").ok());
+  if let Some(audit_path) = audit_path {
     let audit_file = deno_runtime::deno_permissions::AUDIT_FILE.set(
       deno_core::parking_lot::Mutex::new(std::fs::File::create(audit_path)?),
     );
@@ -791,9 +815,31 @@ async fn resolve_flags_and_init(
     }
   }
 
+  if flags.unsafely_log_tls_keys {
+    deno_runtime::deno_tls::enable_ssl_key_log();
+  }
+
   Ok(flags)
 }
 
+/// Installs the seccomp profile given with `--seccomp-profile`, or the
+/// denylist of `--seccomp-denylist`.
+fn maybe_install_seccomp_profile(flags: &Flags) -> Result<(), AnyError> {
+  use deno_runtime::seccomp::SeccompProfile;
+
+  let profile = if let Some(path) = &flags.seccomp_profile {
+    let text = std::fs::read_to_string(path)
+      .with_context(|| format!("Failed reading seccomp profile '{path}'."))?;
+    SeccompProfile::from_json(&text)?
+  } else if flags.seccomp_denylist {
+    SeccompProfile::denylist()
+  } else {
+    return Ok(());
+  };
+  profile.install()?;
+  Ok(())
+}
+
 fn init_v8(flags: &Flags) {
   let default_v8_flags = match flags.subcommand {
     DenoSubcommand::Lsp => vec![
@@ -3,6 +3,7 @@
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
 use deno_ast::ParseDiagnostic;
+use deno_ast::ParsedSource;
 use deno_ast::SourceRange;
 use deno_ast::SourceTextInfo;
 use deno_ast::SourceTextProvider;
@@ -26,6 +27,7 @@ deno_core::extension!(
     op_lint_create_serialized_ast,
     op_lint_report,
     op_lint_get_source,
+    op_lint_get_binding,
     op_is_cancelled
   ],
   options = {
@@ -45,7 +47,11 @@ deno_core::extension!(
 
 deno_core::extension!(
   deno_lint_ext_for_test,
-  ops = [op_lint_create_serialized_ast, op_is_cancelled],
+  ops = [
+    op_lint_create_serialized_ast,
+    op_lint_get_binding,
+    op_is_cancelled
+  ],
   state = |state| {
     state.put(LintPluginContainer::default());
   },
@@ -58,23 +64,42 @@ pub struct LintPluginContainer {
   pub utf_16_map: Option<Utf16Map>,
   pub specifier: Option<ModuleSpecifier>,
   pub token: CancellationToken,
+  parsed_source: Option<ParsedSource>,
+  /// Analyzed once a plugin asks for a binding of the file.
+  bindings: Option<lint::ScopeBindings>,
 }
 
 impl LintPluginContainer {
   pub fn set_info_for_file(
     &mut self,
     specifier: ModuleSpecifier,
-    source_text_info: SourceTextInfo,
+    parsed_source: ParsedSource,
     utf16_map: Utf16Map,
     maybe_token: Option<CancellationToken>,
   ) {
     self.specifier = Some(specifier);
     self.utf_16_map = Some(utf16_map);
-    self.source_text_info = Some(source_text_info);
+    self.source_text_info = Some(parsed_source.text_info_lazy().clone());
+    self.set_parsed_source(parsed_source);
     self.diagnostics.clear();
     self.token = maybe_token.unwrap_or_default();
   }
 
+  fn set_parsed_source(&mut self, parsed_source: ParsedSource) {
+    self.parsed_source = Some(parsed_source);
+    self.bindings = None;
+  }
+
+  fn binding(&mut self, start_utf16: u32) -> Option<lint::Binding> {
+    if self.bindings.is_none() {
+      let parsed_source = self.parsed_source.as_ref()?;
+      let utf16_map = Utf16Map::new(parsed_source.text().as_ref());
+      self.bindings =
+        Some(lint::ScopeBindings::analyze(parsed_source, &utf16_map));
+    }
+    self.bindings.as_ref()?.get(start_utf16)
+  }
+
   fn report(
     &mut self,
     id: String,
@@ -207,6 +232,7 @@ pub enum LintError {
 #[buffer]
 #[allow(clippy::result_large_err)]
 fn op_lint_create_serialized_ast(
+  state: &mut OpState,
   #[string] file_name: &str,
   #[string] source: String,
 ) -> Result<Vec<u8>, LintError> {
@@ -220,11 +246,15 @@ fn op_lint_create_serialized_ast(
     text: file_text.into(),
     media_type,
     capture_tokens: false,
-    scope_analysis: false,
+    scope_analysis: true,
     maybe_syntax: None,
   })?;
   let utf16_map = Utf16Map::new(parsed_source.text().as_ref());
-  Ok(lint::serialize_ast_to_buffer(&parsed_source, &utf16_map))
+  let buffer = lint::serialize_ast_to_buffer(&parsed_source, &utf16_map);
+  state
+    .borrow_mut::<LintPluginContainer>()
+    .set_parsed_source(parsed_source);
+  Ok(buffer)
 }
 
 #[derive(serde::Deserialize)]
@@ -272,3 +302,13 @@ fn op_lint_get_source(state: &mut OpState) -> String {
     .text_str()
     .to_string()
 }
+
+#[op2]
+#[serde]
+fn op_lint_get_binding(
+  state: &mut OpState,
+  #[smi] start_utf16: u32,
+) -> Option<lint::Binding> {
+  let container = state.borrow_mut::<LintPluginContainer>();
+  container.binding(start_utf16)
+}
@@ -12,6 +12,8 @@ use deno_runtime::deno_permissions::ChildPermissionsArg;
 use deno_runtime::deno_permissions::PermissionsContainer;
 use uuid::Uuid;
 
+use crate::tools::test::SnapshotError;
+use crate::tools::test::SnapshotStore;
 use crate::tools::test::TestContainer;
 use crate::tools::test::TestDescription;
 use crate::tools::test::TestEvent;
@@ -33,6 +35,7 @@ deno_core::extension!(deno_test,
     op_test_event_step_result_ok,
     op_test_event_step_result_ignored,
     op_test_event_step_result_failed,
+    op_test_assert_snapshot,
   ],
   options = {
     sender: TestEventSender,
@@ -40,6 +43,7 @@ deno_core::extension!(deno_test,
   state = |state, options| {
     state.put(options.sender);
     state.put(TestContainer::default());
+    state.put(SnapshotStore::default());
   },
 );
 
@@ -233,3 +237,19 @@ fn op_test_event_step_result_failed(
     ))
     .ok();
 }
+
+#[op2]
+fn op_test_assert_snapshot(
+  state: &mut OpState,
+  #[string] name: String,
+  #[string] actual: String,
+) -> Result<(), SnapshotError> {
+  let specifier = state.borrow::<ModuleSpecifier>().clone();
+  let permissions = state.borrow::<PermissionsContainer>().clone();
+  state.borrow_mut::<SnapshotStore>().assert(
+    &specifier,
+    &name,
+    actual,
+    &permissions,
+  )
+}
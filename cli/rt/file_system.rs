@@ -27,6 +27,7 @@ use deno_lib::standalone::virtual_fs::VirtualFile;
 use deno_runtime::deno_fs::FileSystem;
 use deno_runtime::deno_fs::FsDirEntry;
 use deno_runtime::deno_fs::FsFileType;
+use deno_runtime::deno_fs::FsRawDirEntry;
 use deno_runtime::deno_fs::OpenOptions;
 use deno_runtime::deno_fs::RealFs;
 use deno_runtime::deno_io;
@@ -107,6 +108,10 @@ impl DenoRtSys {
 
 #[async_trait::async_trait(?Send)]
 impl FileSystem for DenoRtSys {
+  fn backend_name(&self) -> &'static str {
+    "compile"
+  }
+
   fn cwd(&self) -> FsResult<PathBuf> {
     RealFs.cwd()
   }
@@ -269,6 +274,15 @@ impl FileSystem for DenoRtSys {
     RealFs.remove_async(path, recursive).await
   }
 
+  fn trash_sync(&self, path: &CheckedPath) -> FsResult<()> {
+    self.error_if_in_vfs(path)?;
+    RealFs.trash_sync(path)
+  }
+  async fn trash_async(&self, path: CheckedPathBuf) -> FsResult<()> {
+    self.error_if_in_vfs(&path)?;
+    RealFs.trash_async(path).await
+  }
+
   fn copy_file_sync(
     &self,
     oldpath: &CheckedPath,
@@ -384,6 +398,29 @@ impl FileSystem for DenoRtSys {
     }
   }
 
+  fn read_dir_raw_sync(
+    &self,
+    path: &CheckedPath,
+  ) -> FsResult<Vec<FsRawDirEntry>> {
+    if self.0.is_path_within(path) {
+      let entries = self.0.read_dir(path)?;
+      Ok(entries.into_iter().map(FsRawDirEntry::from).collect())
+    } else {
+      RealFs.read_dir_raw_sync(path)
+    }
+  }
+  async fn read_dir_raw_async(
+    &self,
+    path: CheckedPathBuf,
+  ) -> FsResult<Vec<FsRawDirEntry>> {
+    if self.0.is_path_within(&path) {
+      let entries = self.0.read_dir(&path)?;
+      Ok(entries.into_iter().map(FsRawDirEntry::from).collect())
+    } else {
+      RealFs.read_dir_raw_async(path).await
+    }
+  }
+
   fn rename_sync(
     &self,
     oldpath: &CheckedPath,
@@ -78,6 +78,8 @@ use deno_runtime::deno_permissions::PermissionsContainer;
 use deno_runtime::deno_tls::RootCertStoreProvider;
 use deno_runtime::deno_tls::rustls::RootCertStore;
 use deno_runtime::deno_web::BlobStore;
+use deno_runtime::ops::runtime::EmbeddedFiles;
+use deno_runtime::ops::runtime::deno_embedded_files;
 use deno_runtime::permissions::RuntimePermissionDescriptorParser;
 use deno_semver::npm::NpmPackageReqReference;
 use node_resolver::DenoIsBuiltInNodeModuleChecker;
@@ -1041,6 +1043,8 @@ pub async fn run(
     inspect_brk: false,
     inspect_wait: false,
     trace_ops: None,
+    trace_ops_output: None,
+    trace_leaks: false,
     is_inspecting: false,
     is_standalone: true,
     auto_serve: false,
@@ -1115,18 +1119,61 @@ pub async fn run(
     .map(|key| root_dir_url.join(key).unwrap())
     .collect::<Vec<_>>();
 
-  let mut worker = worker_factory.create_main_worker(
+  let embedded_files =
+    collect_embedded_files(&vfs, &root_dir_url, &metadata.include_paths);
+  let mut worker = worker_factory.create_custom_worker(
     WorkerExecutionMode::Run,
-    permissions,
     main_module,
     preload_modules,
     require_modules,
+    permissions,
+    vec![deno_embedded_files::init(EmbeddedFiles(Arc::new(
+      embedded_files,
+    )))],
+    Default::default(),
+    None,
   )?;
 
   let exit_code = worker.run().await?;
   Ok(exit_code)
 }
 
+/// The paths of the files in the vfs at the `--include` paths, which are
+/// listed by `Deno.embeddedFiles()`.
+fn collect_embedded_files(
+  vfs: &FileBackedVfs,
+  root_dir_url: &Url,
+  include_paths: &[String],
+) -> Vec<String> {
+  let mut pending = include_paths
+    .iter()
+    .filter_map(|key| {
+      deno_path_util::url_to_file_path(&root_dir_url.join(key).ok()?).ok()
+    })
+    .collect::<Vec<_>>();
+  let mut files = Vec::new();
+  while let Some(path) = pending.pop() {
+    let Ok(metadata) = vfs.lstat(&path) else {
+      continue;
+    };
+    match metadata.file_type {
+      sys_traits::FileType::File => {
+        files.push(path.to_string_lossy().into_owned());
+      }
+      sys_traits::FileType::Dir => {
+        if let Ok(entries) = vfs.read_dir(&path) {
+          pending
+            .extend(entries.into_iter().map(|entry| path.join(entry.name)));
+        }
+      }
+      _ => {}
+    }
+  }
+  files.sort();
+  files.dedup();
+  files
+}
+
 fn create_default_npmrc() -> Arc<ResolvedNpmRc> {
   // this is fine because multiple registries are combined into
   // one when compiling the binary
@@ -768,6 +768,10 @@ impl<'a> DenoCompileBinaryWriter<'a> {
       entrypoint_key: root_dir_url.specifier_key(entrypoint).into_owned(),
       preload_modules,
       require_modules,
+      include_paths: include_paths
+        .iter()
+        .map(|path| root_dir_url.specifier_key(path).into_owned())
+        .collect(),
       workspace_resolver: SerializedWorkspaceResolver {
         import_map: self.workspace_resolver.maybe_import_map().map(|i| {
           SerializedWorkspaceResolverImportMap {
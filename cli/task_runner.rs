@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::ffi::OsString;
+use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
@@ -38,20 +39,89 @@ pub fn get_script_with_args(script: &str, argv: &[String]) -> String {
   script.trim().to_owned()
 }
 
-pub struct TaskStdio(Option<ShellPipeReader>, ShellPipeWriter);
+enum TaskOutput {
+  Inherit,
+  /// Collected into the `TaskResult`.
+  Piped(ShellPipeReader),
+  /// Forwarded to stdout or stderr a line at a time, after a prefix.
+  Prefixed {
+    reader: ShellPipeReader,
+    prefix: String,
+    stderr: bool,
+  },
+}
+
+pub struct TaskStdio(TaskOutput, ShellPipeWriter);
 
 impl TaskStdio {
   pub fn stdout() -> Self {
-    Self(None, ShellPipeWriter::stdout())
+    Self(TaskOutput::Inherit, ShellPipeWriter::stdout())
   }
 
   pub fn stderr() -> Self {
-    Self(None, ShellPipeWriter::stderr())
+    Self(TaskOutput::Inherit, ShellPipeWriter::stderr())
   }
 
   pub fn piped() -> Self {
     let (r, w) = deno_task_shell::pipe();
-    Self(Some(r), w)
+    Self(TaskOutput::Piped(r), w)
+  }
+
+  /// Prefixes every line written to stdout, or stderr, so that the output of
+  /// tasks that run at the same time can be told apart.
+  pub fn prefixed(prefix: String, stderr: bool) -> Self {
+    let (reader, w) = deno_task_shell::pipe();
+    Self(
+      TaskOutput::Prefixed {
+        reader,
+        prefix,
+        stderr,
+      },
+      w,
+    )
+  }
+}
+
+/// Writes whole lines, each after a prefix. A line is written at once, so
+/// lines of different tasks interleave without mixing.
+struct PrefixedWriter {
+  prefix: String,
+  stderr: bool,
+  line: Vec<u8>,
+}
+
+impl PrefixedWriter {
+  fn write_line(&mut self) -> std::io::Result<()> {
+    let mut out = Vec::with_capacity(self.prefix.len() + self.line.len());
+    out.extend_from_slice(self.prefix.as_bytes());
+    out.append(&mut self.line);
+    if self.stderr {
+      std::io::stderr().lock().write_all(&out)
+    } else {
+      std::io::stdout().lock().write_all(&out)
+    }
+  }
+}
+
+impl Write for PrefixedWriter {
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    let mut rest = buf;
+    while let Some(index) = rest.iter().position(|b| *b == b'\n') {
+      self.line.extend_from_slice(&rest[..=index]);
+      self.write_line()?;
+      rest = &rest[index + 1..];
+    }
+    self.line.extend_from_slice(rest);
+    Ok(buf.len())
+  }
+
+  /// Writes the last line when it doesn't end with a new line.
+  fn flush(&mut self) -> std::io::Result<()> {
+    if !self.line.is_empty() {
+      self.line.push(b'\n');
+      self.write_line()?;
+    }
+    Ok(())
   }
 }
 
@@ -115,16 +185,37 @@ pub async fn run_task(
     TaskStdio(stderr_read, stderr_write),
   ) = (stdio.stdout, stdio.stderr);
 
-  fn read(reader: ShellPipeReader) -> JoinHandle<Result<Vec<u8>, AnyError>> {
-    tokio::task::spawn_blocking(move || {
-      let mut buf = Vec::new();
-      reader.pipe_to(&mut buf)?;
-      Ok(buf)
-    })
+  fn read(
+    output: TaskOutput,
+  ) -> Option<JoinHandle<Result<Option<Vec<u8>>, AnyError>>> {
+    match output {
+      TaskOutput::Inherit => None,
+      TaskOutput::Piped(reader) => {
+        Some(tokio::task::spawn_blocking(move || {
+          let mut buf = Vec::new();
+          reader.pipe_to(&mut buf)?;
+          Ok(Some(buf))
+        }))
+      }
+      TaskOutput::Prefixed {
+        reader,
+        prefix,
+        stderr,
+      } => Some(tokio::task::spawn_blocking(move || {
+        let mut writer = PrefixedWriter {
+          prefix,
+          stderr,
+          line: Vec::new(),
+        };
+        reader.pipe_to(&mut writer)?;
+        writer.flush()?;
+        Ok(None)
+      })),
+    }
   }
 
-  let stdout = stdout_read.map(read);
-  let stderr = stderr_read.map(read);
+  let stdout = read(stdout_read);
+  let stderr = read(stderr_read);
 
   let local = LocalSet::new();
   let future = async move {
@@ -138,15 +229,13 @@ pub async fn run_task(
     .await;
     Ok::<_, AnyError>(TaskResult {
       exit_code,
-      stdout: if let Some(stdout) = stdout {
-        Some(stdout.await??)
-      } else {
-        None
+      stdout: match stdout {
+        Some(stdout) => stdout.await??,
+        None => None,
       },
-      stderr: if let Some(stderr) = stderr {
-        Some(stderr.await??)
-      } else {
-        None
+      stderr: match stderr {
+        Some(stderr) => stderr.await??,
+        None => None,
       },
     })
   };
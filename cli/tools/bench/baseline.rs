@@ -0,0 +1,231 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Comparison of bench results with a baseline, the `--json` output of an
+//! earlier run, for `deno bench --baseline`. Benches are matched by their
+//! origin, group and name, or by group and name alone when the origin
+//! differs, like when the baseline was recorded in another checkout.
+
+use std::path::Path;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Deserialize;
+
+use super::BenchDescription;
+use super::BenchStats;
+use crate::colors;
+
+#[derive(Debug, Deserialize)]
+struct BaselineOutput {
+  benches: Vec<BaselineBench>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BaselineBench {
+  origin: String,
+  group: Option<String>,
+  name: String,
+  results: Vec<BaselineResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum BaselineResult {
+  Ok(BaselineStats),
+  Failed(serde_json::Value),
+}
+
+#[derive(Debug, Deserialize)]
+struct BaselineStats {
+  avg: f64,
+}
+
+#[derive(Debug)]
+pub struct Baseline {
+  benches: Vec<BaselineBench>,
+}
+
+/// A bench compared with its result in the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchComparison {
+  pub name: String,
+  pub baseline_avg: f64,
+  pub avg: f64,
+  /// Percent the bench got slower, or faster when negative.
+  pub change: f64,
+  pub regressed: bool,
+}
+
+impl Baseline {
+  #[allow(clippy::disallowed_methods)]
+  pub fn load(path: &Path) -> Result<Self, AnyError> {
+    let text = std::fs::read_to_string(path)
+      .with_context(|| format!("Failed reading baseline {}", path.display()))?;
+    Self::parse(&text)
+      .with_context(|| format!("Failed parsing baseline {}", path.display()))
+  }
+
+  fn parse(text: &str) -> Result<Self, AnyError> {
+    let output: BaselineOutput = serde_json::from_str(text)?;
+    Ok(Self {
+      benches: output.benches,
+    })
+  }
+
+  /// The average time of the bench in the baseline.
+  fn avg(&self, desc: &BenchDescription) -> Option<f64> {
+    let same_name = |bench: &&BaselineBench| {
+      bench.group == desc.group && bench.name == desc.name
+    };
+    let bench = match self
+      .benches
+      .iter()
+      .find(|bench| bench.origin == desc.origin && same_name(bench))
+    {
+      Some(bench) => bench,
+      None => {
+        let mut benches = self.benches.iter().filter(same_name);
+        let bench = benches.next()?;
+        if benches.next().is_some() {
+          return None;
+        }
+        bench
+      }
+    };
+    bench.results.iter().find_map(|result| match result {
+      BaselineResult::Ok(stats) => Some(stats.avg),
+      BaselineResult::Failed(_) => None,
+    })
+  }
+
+  /// Compares the measurements with the baseline. Benches missing from the
+  /// baseline are left out.
+  pub fn compare(
+    &self,
+    measurements: &[(BenchDescription, BenchStats)],
+    threshold: u32,
+  ) -> Vec<BenchComparison> {
+    measurements
+      .iter()
+      .filter_map(|(desc, stats)| {
+        let baseline_avg = self.avg(desc)?;
+        let change = if baseline_avg > 0.0 {
+          (stats.avg - baseline_avg) / baseline_avg * 100.0
+        } else {
+          0.0
+        };
+        Some(BenchComparison {
+          name: desc.name.clone(),
+          baseline_avg,
+          avg: stats.avg,
+          change,
+          regressed: change > threshold as f64,
+        })
+      })
+      .collect()
+  }
+}
+
+/// Prints the comparisons to stderr, so that they don't mix with the
+/// `--json` output.
+#[allow(clippy::print_stderr)]
+pub fn report_comparisons(comparisons: &[BenchComparison]) {
+  if comparisons.is_empty() {
+    return;
+  }
+  eprintln!("\n{}", colors::bold("Compared with baseline:"));
+  for comparison in comparisons {
+    let change = format!("{:+.1}%", comparison.change);
+    let change = if comparison.regressed {
+      colors::red_bold(change).to_string()
+    } else if comparison.change < 0.0 {
+      colors::green(change).to_string()
+    } else {
+      colors::gray(change).to_string()
+    };
+    eprintln!(
+      "  {} {} ({} -> {})",
+      comparison.name,
+      change,
+      super::mitata::fmt_duration(comparison.baseline_avg),
+      super::mitata::fmt_duration(comparison.avg),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn desc(origin: &str, name: &str) -> BenchDescription {
+    BenchDescription {
+      id: 0,
+      name: name.to_string(),
+      origin: origin.to_string(),
+      baseline: false,
+      group: None,
+      ignore: false,
+      only: false,
+      warmup: false,
+    }
+  }
+
+  fn stats(avg: f64) -> BenchStats {
+    BenchStats {
+      n: 100,
+      min: avg,
+      max: avg,
+      avg,
+      p75: avg,
+      p99: avg,
+      p995: avg,
+      p999: avg,
+      high_precision: true,
+      used_explicit_timers: false,
+    }
+  }
+
+  #[test]
+  fn compares_with_baseline() {
+    let baseline = Baseline::parse(
+      r#"{
+        "version": 1,
+        "runtime": "Deno/2.0.0 x86_64-unknown-linux-gnu",
+        "cpu": "cpu",
+        "benches": [
+          {
+            "origin": "file:///ci/a_bench.ts",
+            "group": null,
+            "name": "parse",
+            "baseline": false,
+            "results": [{ "ok": { "avg": 100.0 } }]
+          },
+          {
+            "origin": "file:///ci/a_bench.ts",
+            "group": null,
+            "name": "read",
+            "baseline": false,
+            "results": [{ "ok": { "avg": 200.0 } }]
+          }
+        ]
+      }"#,
+    )
+    .unwrap();
+    let comparisons = baseline.compare(
+      &[
+        // a different checkout, matched by name
+        (desc("file:///home/a_bench.ts", "parse"), stats(115.0)),
+        (desc("file:///ci/a_bench.ts", "read"), stats(180.0)),
+        (desc("file:///ci/a_bench.ts", "new"), stats(1.0)),
+      ],
+      10,
+    );
+    assert_eq!(comparisons.len(), 2);
+    assert_eq!(comparisons[0].name, "parse");
+    assert!((comparisons[0].change - 15.0).abs() < 1e-9);
+    assert!(comparisons[0].regressed);
+    assert!((comparisons[1].change + 10.0).abs() < 1e-9);
+    assert!(!comparisons[1].regressed);
+  }
+}
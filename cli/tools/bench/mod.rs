@@ -37,6 +37,7 @@ use tokio::sync::mpsc::unbounded_channel;
 use crate::args::BenchFlags;
 use crate::args::CliOptions;
 use crate::args::Flags;
+use crate::args::WorkspaceBenchOptions;
 use crate::colors;
 use crate::display::write_json_to_stdout;
 use crate::factory::CliFactory;
@@ -54,9 +55,12 @@ use crate::util::path::matches_pattern_or_exact_path;
 use crate::worker::CliMainWorkerFactory;
 use crate::worker::CreateCustomWorkerError;
 
+mod baseline;
 mod mitata;
 mod reporters;
 
+use baseline::Baseline;
+
 use reporters::BenchReporter;
 use reporters::ConsoleReporter;
 use reporters::JsonReporter;
@@ -66,6 +70,8 @@ struct BenchSpecifierOptions {
   filter: TestFilter,
   json: bool,
   log_level: Option<log::Level>,
+  baseline: Option<Arc<Baseline>>,
+  regression_threshold: u32,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
@@ -417,6 +423,25 @@ async fn bench_specifiers(
         return Err(anyhow!("Bench failed"));
       }
 
+      if let Some(baseline) = &options.baseline {
+        let comparisons =
+          baseline.compare(&report.measurements, options.regression_threshold);
+        baseline::report_comparisons(&comparisons);
+        let regressed = comparisons.iter().filter(|c| c.regressed).count();
+        if regressed > 0 {
+          return Err(anyhow!(
+            "Bench failed because {} {} more than {}% slower than the baseline",
+            regressed,
+            if regressed == 1 {
+              "bench was"
+            } else {
+              "benches were"
+            },
+            options.regression_threshold,
+          ));
+        }
+      }
+
       Ok(())
     })
   };
@@ -447,7 +472,7 @@ fn is_supported_bench_path(entry: WalkEntry) -> bool {
   }
 }
 
-fn has_supported_bench_path_name(path: &Path) -> bool {
+pub(crate) fn has_supported_bench_path_name(path: &Path) -> bool {
   if let Some(name) = path.file_stem() {
     let basename = name.to_string_lossy();
     basename.ends_with("_bench")
@@ -458,6 +483,16 @@ fn has_supported_bench_path_name(path: &Path) -> bool {
   }
 }
 
+fn load_baseline(
+  options: &WorkspaceBenchOptions,
+) -> Result<Option<Arc<Baseline>>, AnyError> {
+  options
+    .baseline
+    .as_deref()
+    .map(|path| Baseline::load(path).map(Arc::new))
+    .transpose()
+}
+
 pub async fn run_benchmarks(
   flags: Arc<Flags>,
   bench_flags: BenchFlags,
@@ -505,6 +540,7 @@ pub async fn run_benchmarks(
     return Ok(());
   }
 
+  let baseline = load_baseline(&workspace_bench_options)?;
   let preload_modules = cli_options.preload_modules()?;
   let require_modules = cli_options.require_modules()?;
   let log_level = cli_options.log_level();
@@ -521,6 +557,8 @@ pub async fn run_benchmarks(
       filter: TestFilter::from_flag(&workspace_bench_options.filter),
       json: workspace_bench_options.json,
       log_level,
+      baseline,
+      regression_threshold: workspace_bench_options.regression_threshold,
     },
   )
   .await?;
@@ -644,6 +682,7 @@ pub async fn run_benchmarks_with_watch(
           return Ok(());
         }
 
+        let baseline = load_baseline(&workspace_bench_options)?;
         let log_level = cli_options.log_level();
         let preload_modules = cli_options.preload_modules()?;
         let require_modules = cli_options.require_modules()?;
@@ -658,6 +697,8 @@ pub async fn run_benchmarks_with_watch(
             filter: TestFilter::from_flag(&workspace_bench_options.filter),
             json: workspace_bench_options.json,
             log_level,
+            baseline,
+            regression_threshold: workspace_bench_options.regression_threshold,
           },
         )
         .await?;
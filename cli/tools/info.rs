@@ -250,6 +250,7 @@ fn print_cache_info(
       "registryCache": registry_cache,
       "originStorage": origin_dir,
       "webCacheStorage": web_cache_dir,
+      "buildFeatures": deno_runtime::build_features(),
     });
 
     if location.is_some() {
@@ -19,6 +19,7 @@ use deno_runtime::deno_permissions::PermissionsContainer;
 use deno_terminal::colors;
 use jupyter_protocol::messaging::StreamContent;
 use jupyter_runtime::ConnectionInfo;
+use jupyter_runtime::JupyterMessage;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
@@ -170,6 +171,7 @@ pub async fn kernel(
 
   let (tx1, rx1) = mpsc::unbounded_channel();
   let (tx2, rx2) = mpsc::unbounded_channel();
+  let (comm_tx, comm_rx) = mpsc::unbounded_channel();
   let (startup_data_tx, startup_data_rx) =
     oneshot::channel::<server::StartupData>();
 
@@ -177,8 +179,13 @@ pub async fn kernel(
     repl_session,
     rx: rx1,
     tx: tx2,
+    comm_rx,
+  };
+  let repl_session_proxy_channels = JupyterReplProxy {
+    tx: tx1,
+    rx: rx2,
+    comm_tx,
   };
-  let repl_session_proxy_channels = JupyterReplProxy { tx: tx1, rx: rx2 };
 
   let join_handle = std::thread::spawn(move || {
     let fut = server::JupyterServer::start(
@@ -255,9 +262,17 @@ pub enum JupyterReplResponse {
 pub struct JupyterReplProxy {
   tx: mpsc::UnboundedSender<JupyterReplRequest>,
   rx: mpsc::UnboundedReceiver<JupyterReplResponse>,
+  /// Comm messages from the frontend, which don't get a reply.
+  comm_tx: mpsc::UnboundedSender<JupyterMessage>,
 }
 
 impl JupyterReplProxy {
+  /// Passes a `comm_open`, `comm_msg` or `comm_close` message on to the comms
+  /// of `Deno.jupyter`.
+  pub fn send_comm_message(&self, message: JupyterMessage) {
+    let _ = self.comm_tx.send(message);
+  }
+
   pub async fn lsp_completions(
     &mut self,
     line_text: String,
@@ -366,6 +381,7 @@ pub struct JupyterReplSession {
   repl_session: repl::ReplSession,
   rx: mpsc::UnboundedReceiver<JupyterReplRequest>,
   tx: mpsc::UnboundedSender<JupyterReplResponse>,
+  comm_rx: mpsc::UnboundedReceiver<JupyterMessage>,
 }
 
 impl JupyterReplSession {
@@ -384,6 +400,13 @@ impl JupyterReplSession {
           }
           poll_worker = true;
         },
+        maybe_message = self.comm_rx.recv() => {
+          let Some(msg) = maybe_message else {
+            break;
+          };
+          self.handle_comm_message(msg).await;
+          poll_worker = true;
+        },
         _ = self.repl_session.run_event_loop(), if poll_worker => {
           poll_worker = false;
         }
@@ -440,6 +463,37 @@ impl JupyterReplSession {
     self.tx.send(resp).map_err(|e| e.into())
   }
 
+  async fn handle_comm_message(&mut self, msg: JupyterMessage) {
+    let content = match serde_json::to_value(&msg.content) {
+      Ok(content) => content,
+      Err(err) => {
+        log::error!("Failed to serialize comm message: {}", err);
+        return;
+      }
+    };
+    let response = self
+      .repl_session
+      .post_message_with_event_loop(
+        "Runtime.callFunctionOn",
+        Some(json!({
+          "functionDeclaration": r#"async function (msg_type, content, metadata) {
+          await Deno[Deno.internal].jupyter.handleCommMessage(msg_type, content, metadata);
+    }"#,
+          "arguments": [
+            { "value": msg.message_type() },
+            { "value": content },
+            { "value": msg.metadata },
+          ],
+          "executionContextId": self.repl_session.context_id,
+          "awaitPromise": true,
+        })),
+      )
+      .await;
+    if let Some(exception) = response.get("exceptionDetails") {
+      log::error!("Failed to handle comm message: {}", exception);
+    }
+  }
+
   pub async fn lsp_completions(
     &mut self,
     line_text: &str,
@@ -423,16 +423,12 @@ impl JupyterServer {
       JupyterMessageContent::KernelInfoRequest(_) => {
         connection.send(kernel_info().as_child_of(parent)).await?;
       }
-      JupyterMessageContent::CommOpen(comm) => {
-        connection
-          .send(
-            messaging::CommClose {
-              comm_id: comm.comm_id,
-              data: Default::default(),
-            }
-            .as_child_of(parent),
-          )
-          .await?;
+      JupyterMessageContent::CommOpen(_)
+      | JupyterMessageContent::CommMsg(_)
+      | JupyterMessageContent::CommClose(_) => {
+        // comms are handled by `Deno.jupyter`, which closes the ones opened
+        // for a target that wasn't registered
+        self.repl_session_proxy.send_comm_message(parent.clone());
       }
       JupyterMessageContent::HistoryRequest(_req) => {
         connection
@@ -462,10 +458,6 @@ impl JupyterServer {
           )
           .await?;
       }
-      JupyterMessageContent::CommMsg(_)
-      | JupyterMessageContent::CommClose(_) => {
-        // Do nothing with regular comm messages
-      }
       // Any unknown message type is ignored
       _ => {
         log::error!(
@@ -388,7 +388,6 @@ fn run_plugins(
   file_path: PathBuf,
   maybe_token: Option<CancellationToken>,
 ) -> Result<ExternalLinterResult, AnyError> {
-  let source_text_info = parsed_source.text_info_lazy().clone();
   let plugin_info = plugin_runner
     .get_plugin_rules()
     .into_iter()
@@ -404,7 +403,7 @@ fn run_plugins(
       &plugin_runner,
       &file_path,
       serialized_ast,
-      source_text_info,
+      parsed_source,
       utf16_map,
       maybe_token,
     )
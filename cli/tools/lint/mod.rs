@@ -61,6 +61,7 @@ mod linter;
 mod plugins;
 mod reporters;
 mod rules;
+mod scopes;
 
 // TODO(bartlomieju): remove once we wire plugins through the CLI linter
 pub use ast_buffer::serialize_ast_to_buffer;
@@ -72,6 +73,8 @@ pub use plugins::create_runner_and_load_plugins;
 pub use rules::ConfiguredRules;
 pub use rules::LintRuleProvider;
 pub use rules::collect_no_slow_type_diagnostics;
+pub use scopes::Binding;
+pub use scopes::ScopeBindings;
 
 const JSON_SCHEMA_VERSION: u8 = 1;
 
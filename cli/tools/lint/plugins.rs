@@ -8,7 +8,6 @@ use std::sync::Arc;
 use ::tokio_util::sync::CancellationToken;
 use deno_ast::ModuleSpecifier;
 use deno_ast::ParsedSource;
-use deno_ast::SourceTextInfo;
 use deno_core::PollEventLoopOptions;
 use deno_core::anyhow::bail;
 use deno_core::error::AnyError;
@@ -44,7 +43,7 @@ pub enum PluginHostRequest {
   Run {
     serialized_ast: Vec<u8>,
     file_path: PathBuf,
-    source_text_info: SourceTextInfo,
+    parsed_source: ParsedSource,
     utf16_map: Utf16Map,
     maybe_token: Option<CancellationToken>,
     tx: oneshot::Sender<PluginHostResponse>,
@@ -276,7 +275,7 @@ impl PluginHost {
         PluginHostRequest::Run {
           serialized_ast,
           file_path,
-          source_text_info,
+          parsed_source,
           utf16_map,
           maybe_token,
           tx,
@@ -285,7 +284,7 @@ impl PluginHost {
           let r = match self.run_plugins(
             &file_path,
             serialized_ast,
-            source_text_info,
+            parsed_source,
             utf16_map,
             maybe_token,
           ) {
@@ -315,7 +314,7 @@ impl PluginHost {
     &mut self,
     file_path: &Path,
     serialized_ast: Vec<u8>,
-    source_text_info: SourceTextInfo,
+    parsed_source: ParsedSource,
     utf16_map: Utf16Map,
     maybe_token: Option<CancellationToken>,
   ) -> Result<(), AnyError> {
@@ -325,7 +324,7 @@ impl PluginHost {
       let container = state.borrow_mut::<LintPluginContainer>();
       container.set_info_for_file(
         url_from_file_path(file_path)?,
-        source_text_info,
+        parsed_source,
         utf16_map,
         maybe_token,
       );
@@ -481,7 +480,7 @@ impl PluginHostProxy {
     &self,
     specifier: &Path,
     serialized_ast: Vec<u8>,
-    source_text_info: SourceTextInfo,
+    parsed_source: ParsedSource,
     utf16_map: Utf16Map,
     maybe_token: Option<CancellationToken>,
   ) -> Result<Vec<LintDiagnostic>, AnyError> {
@@ -491,7 +490,7 @@ impl PluginHostProxy {
       .send(PluginHostRequest::Run {
         serialized_ast,
         file_path: specifier.to_path_buf(),
-        source_text_info,
+        parsed_source,
         utf16_map,
         maybe_token,
         tx,
@@ -533,7 +532,7 @@ pub async fn run_rules_for_ast(
   host_proxy: &PluginHostProxy,
   specifier: &Path,
   serialized_ast: Vec<u8>,
-  source_text_info: SourceTextInfo,
+  parsed_source: ParsedSource,
   utf16_map: Utf16Map,
   maybe_token: Option<CancellationToken>,
 ) -> Result<Vec<LintDiagnostic>, AnyError> {
@@ -541,7 +540,7 @@ pub async fn run_rules_for_ast(
     .run_rules(
       specifier,
       serialized_ast,
-      source_text_info,
+      parsed_source,
       utf16_map,
       maybe_token,
     )
@@ -0,0 +1,320 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Scope analysis for lint plugins, behind `context.sourceCode.getBinding()`.
+//! The resolver of `deno_ast` gives identifiers that refer to the same
+//! binding the same syntax context, so each identifier is mapped to the
+//! declaration with its symbol and context.
+
+use std::collections::HashMap;
+
+use deno_ast::ParsedSource;
+use deno_ast::swc::ast::ArrowExpr;
+use deno_ast::swc::ast::AssignPat;
+use deno_ast::swc::ast::BindingIdent;
+use deno_ast::swc::ast::CatchClause;
+use deno_ast::swc::ast::ClassDecl;
+use deno_ast::swc::ast::ClassExpr;
+use deno_ast::swc::ast::FnDecl;
+use deno_ast::swc::ast::FnExpr;
+use deno_ast::swc::ast::Id;
+use deno_ast::swc::ast::Ident;
+use deno_ast::swc::ast::ImportDefaultSpecifier;
+use deno_ast::swc::ast::ImportNamedSpecifier;
+use deno_ast::swc::ast::ImportStarAsSpecifier;
+use deno_ast::swc::ast::Param;
+use deno_ast::swc::ast::SetterProp;
+use deno_ast::swc::ast::TsEnumDecl;
+use deno_ast::swc::ast::TsInterfaceDecl;
+use deno_ast::swc::ast::TsModuleDecl;
+use deno_ast::swc::ast::TsModuleName;
+use deno_ast::swc::ast::TsParamProp;
+use deno_ast::swc::ast::TsTypeAliasDecl;
+use deno_ast::swc::ast::UsingDecl;
+use deno_ast::swc::ast::VarDecl;
+use deno_ast::swc::ast::VarDeclKind;
+use deno_ast::swc::common::Span;
+use deno_ast::swc::ecma_visit::Visit;
+use deno_ast::swc::ecma_visit::VisitWith;
+use serde::Serialize;
+
+use crate::util::text_encoding::Utf16Map;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BindingKind {
+  Var,
+  Let,
+  Const,
+  Using,
+  Function,
+  Class,
+  Param,
+  CatchParam,
+  Import,
+  Enum,
+  Type,
+  Namespace,
+}
+
+/// The declaration an identifier refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Binding {
+  pub kind: BindingKind,
+  /// The UTF-16 range of the identifier of the declaration.
+  pub range: (u32, u32),
+}
+
+#[derive(Debug, Default)]
+pub struct ScopeBindings {
+  /// Bindings by the UTF-16 start of the identifiers referring to them.
+  by_start: HashMap<u32, Binding>,
+}
+
+impl ScopeBindings {
+  pub fn analyze(parsed_source: &ParsedSource, utf16_map: &Utf16Map) -> Self {
+    let program = parsed_source.program_ref();
+    let mut decls = DeclCollector {
+      utf16_map,
+      kind: None,
+      decls: HashMap::new(),
+    };
+    program.visit_with(&mut decls);
+    let mut refs = RefCollector {
+      utf16_map,
+      decls: &decls.decls,
+      by_start: HashMap::new(),
+    };
+    program.visit_with(&mut refs);
+    Self {
+      by_start: refs.by_start,
+    }
+  }
+
+  /// The binding of the identifier starting at the UTF-16 offset, or `None`
+  /// for globals and identifiers that aren't bound, like property names.
+  pub fn get(&self, start_utf16: u32) -> Option<Binding> {
+    self.by_start.get(&start_utf16).copied()
+  }
+}
+
+fn utf16_range(utf16_map: &Utf16Map, span: Span) -> Option<(u32, u32)> {
+  let start = utf16_map.utf8_to_utf16_offset((span.lo.0 - 1).into())?;
+  let end = utf16_map.utf8_to_utf16_offset((span.hi.0 - 1).into())?;
+  Some((start.into(), end.into()))
+}
+
+struct DeclCollector<'a> {
+  utf16_map: &'a Utf16Map,
+  /// The kind of the bindings of the pattern being visited.
+  kind: Option<BindingKind>,
+  decls: HashMap<Id, Binding>,
+}
+
+impl DeclCollector<'_> {
+  fn add(&mut self, ident: &Ident, kind: BindingKind) {
+    let Some(range) = utf16_range(self.utf16_map, ident.span) else {
+      return;
+    };
+    // the first declaration wins, like for `var` redeclarations and
+    // function overloads
+    self
+      .decls
+      .entry(ident.to_id())
+      .or_insert(Binding { kind, range });
+  }
+
+  fn visit_pat_with<N: VisitWith<Self>>(&mut self, n: &N, kind: BindingKind) {
+    let prev = self.kind.replace(kind);
+    n.visit_with(self);
+    self.kind = prev;
+  }
+
+  fn visit_expr_with<N: VisitWith<Self>>(&mut self, n: &N) {
+    let prev = self.kind.take();
+    n.visit_with(self);
+    self.kind = prev;
+  }
+}
+
+impl Visit for DeclCollector<'_> {
+  fn visit_binding_ident(&mut self, n: &BindingIdent) {
+    if let Some(kind) = self.kind {
+      self.add(&n.id, kind);
+    }
+  }
+
+  fn visit_assign_pat(&mut self, n: &AssignPat) {
+    n.left.visit_with(self);
+    self.visit_expr_with(&n.right);
+  }
+
+  fn visit_var_decl(&mut self, n: &VarDecl) {
+    let kind = match n.kind {
+      VarDeclKind::Var => BindingKind::Var,
+      VarDeclKind::Let => BindingKind::Let,
+      VarDeclKind::Const => BindingKind::Const,
+    };
+    for decl in &n.decls {
+      self.visit_pat_with(&decl.name, kind);
+      self.visit_expr_with(&decl.init);
+    }
+  }
+
+  fn visit_using_decl(&mut self, n: &UsingDecl) {
+    for decl in &n.decls {
+      self.visit_pat_with(&decl.name, BindingKind::Using);
+      self.visit_expr_with(&decl.init);
+    }
+  }
+
+  fn visit_fn_decl(&mut self, n: &FnDecl) {
+    self.add(&n.ident, BindingKind::Function);
+    self.visit_expr_with(&n.function);
+  }
+
+  fn visit_fn_expr(&mut self, n: &FnExpr) {
+    if let Some(ident) = &n.ident {
+      self.add(ident, BindingKind::Function);
+    }
+    self.visit_expr_with(&n.function);
+  }
+
+  fn visit_class_decl(&mut self, n: &ClassDecl) {
+    self.add(&n.ident, BindingKind::Class);
+    self.visit_expr_with(&n.class);
+  }
+
+  fn visit_class_expr(&mut self, n: &ClassExpr) {
+    if let Some(ident) = &n.ident {
+      self.add(ident, BindingKind::Class);
+    }
+    self.visit_expr_with(&n.class);
+  }
+
+  fn visit_param(&mut self, n: &Param) {
+    self.visit_pat_with(&n.pat, BindingKind::Param);
+  }
+
+  fn visit_ts_param_prop(&mut self, n: &TsParamProp) {
+    self.visit_pat_with(&n.param, BindingKind::Param);
+  }
+
+  fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+    self.visit_pat_with(&n.params, BindingKind::Param);
+    self.visit_expr_with(&n.body);
+  }
+
+  fn visit_setter_prop(&mut self, n: &SetterProp) {
+    self.visit_pat_with(&n.param, BindingKind::Param);
+    self.visit_expr_with(&n.body);
+  }
+
+  fn visit_catch_clause(&mut self, n: &CatchClause) {
+    self.visit_pat_with(&n.param, BindingKind::CatchParam);
+    self.visit_expr_with(&n.body);
+  }
+
+  fn visit_import_named_specifier(&mut self, n: &ImportNamedSpecifier) {
+    self.add(&n.local, BindingKind::Import);
+  }
+
+  fn visit_import_default_specifier(&mut self, n: &ImportDefaultSpecifier) {
+    self.add(&n.local, BindingKind::Import);
+  }
+
+  fn visit_import_star_as_specifier(&mut self, n: &ImportStarAsSpecifier) {
+    self.add(&n.local, BindingKind::Import);
+  }
+
+  fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+    self.add(&n.id, BindingKind::Enum);
+    self.visit_expr_with(&n.members);
+  }
+
+  fn visit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) {
+    self.add(&n.id, BindingKind::Type);
+    self.visit_expr_with(&n.body);
+  }
+
+  fn visit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl) {
+    self.add(&n.id, BindingKind::Type);
+    self.visit_expr_with(&n.type_ann);
+  }
+
+  fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+    if let TsModuleName::Ident(ident) = &n.id {
+      self.add(ident, BindingKind::Namespace);
+    }
+    self.visit_expr_with(&n.body);
+  }
+}
+
+struct RefCollector<'a> {
+  utf16_map: &'a Utf16Map,
+  decls: &'a HashMap<Id, Binding>,
+  by_start: HashMap<u32, Binding>,
+}
+
+impl Visit for RefCollector<'_> {
+  fn visit_ident(&mut self, n: &Ident) {
+    if let Some(binding) = self.decls.get(&n.to_id())
+      && let Some((start, _)) = utf16_range(self.utf16_map, n.span)
+    {
+      self.by_start.insert(start, *binding);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use deno_ast::MediaType;
+  use deno_ast::ModuleSpecifier;
+
+  use super::*;
+
+  fn analyze(text: &str) -> ScopeBindings {
+    let parsed_source = deno_ast::parse_program(deno_ast::ParseParams {
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      text: text.into(),
+      media_type: MediaType::TypeScript,
+      capture_tokens: false,
+      scope_analysis: true,
+      maybe_syntax: None,
+    })
+    .unwrap();
+    let utf16_map = Utf16Map::new(parsed_source.text().as_ref());
+    ScopeBindings::analyze(&parsed_source, &utf16_map)
+  }
+
+  #[test]
+  fn resolves_bindings() {
+    let text = "import { a } from './a.ts';\n\
+      const b = (c: number) => { let a = c; return a; };\n\
+      function d() { return a + b + e; }\n";
+    let bindings = analyze(text);
+    let binding = |needle: &str| {
+      let start = text.find(needle).unwrap() as u32;
+      bindings.get(start)
+    };
+    let import = Binding {
+      kind: BindingKind::Import,
+      range: (9, 10),
+    };
+    assert_eq!(binding("a }"), Some(import));
+    assert_eq!(binding("a + b"), Some(import));
+    // shadowed
+    let local_start = text.find("a = c").unwrap() as u32;
+    assert_eq!(
+      binding("a; }"),
+      Some(Binding {
+        kind: BindingKind::Let,
+        range: (local_start, local_start + 1),
+      })
+    );
+    assert_eq!(binding("c;").map(|b| b.kind), Some(BindingKind::Param));
+    assert_eq!(binding("b + e").map(|b| b.kind), Some(BindingKind::Const));
+    assert_eq!(binding("d()").map(|b| b.kind), Some(BindingKind::Function));
+    // a global
+    assert_eq!(binding("e;"), None);
+  }
+}
@@ -14,6 +14,7 @@ pub mod init;
 pub mod installer;
 pub mod jupyter;
 pub mod lint;
+pub mod permissions;
 pub mod pm;
 pub mod publish;
 pub mod repl;
@@ -0,0 +1,355 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Permissions granted at prompts are remembered across runs of a script
+//! with `--remember-permissions`. Grants are stored per project in the
+//! `DENO_DIR`, keyed by a hash of the script's source, along with hashes of
+//! the local modules it imported, so that they no longer apply once the
+//! script or one of those modules changes. `deno permissions` lists and
+//! revokes them.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::parking_lot::Mutex;
+use deno_core::serde_json;
+use deno_core::url::Url;
+use deno_graph::ModuleGraph;
+use deno_path_util::fs::atomic_write_file_with_retries;
+use deno_runtime::deno_permissions::PermissionsOptions;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+
+use crate::args::Flags;
+use crate::args::PermissionsFlags;
+use crate::colors;
+use crate::factory::CliFactory;
+use crate::sys::CliSys;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GrantsFile {
+  version: u32,
+  /// Grants by the hash of the script they were given to.
+  scripts: BTreeMap<String, ScriptGrants>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct ScriptGrants {
+  specifier: String,
+  /// Values granted by flag name. An empty set grants all of the kind.
+  permissions: BTreeMap<String, BTreeSet<String>>,
+  /// Hashes of the local modules in the module graph when the permissions
+  /// were last granted, by specifier.
+  #[serde(default)]
+  modules: BTreeMap<String, String>,
+}
+
+impl ScriptGrants {
+  fn insert(&mut self, flag_name: &str, value: Option<&str>) -> bool {
+    match (self.permissions.get_mut(flag_name), value) {
+      // all of the kind are granted already
+      (Some(values), _) if values.is_empty() => false,
+      (Some(values), Some(value)) => values.insert(value.to_string()),
+      (None, Some(value)) => {
+        self
+          .permissions
+          .insert(flag_name.to_string(), BTreeSet::from([value.to_string()]));
+        true
+      }
+      (_, None) => {
+        self
+          .permissions
+          .insert(flag_name.to_string(), BTreeSet::new());
+        true
+      }
+    }
+  }
+}
+
+fn hash_module(specifier: &Url) -> Option<String> {
+  let path = deno_path_util::url_to_file_path(specifier).ok()?;
+  let source = std::fs::read(path).ok()?;
+  Some(faster_hex::hex_string(&sha2::Sha256::digest(source)))
+}
+
+fn grants_file_path(grants_dir: &Path, project_dir: &Path) -> PathBuf {
+  let hash = sha2::Sha256::digest(project_dir.to_string_lossy().as_bytes());
+  grants_dir.join(format!("{}.json", faster_hex::hex_string(&hash)))
+}
+
+fn read_grants_file(path: &Path) -> Result<GrantsFile, AnyError> {
+  match std::fs::read_to_string(path) {
+    Ok(text) => serde_json::from_str(&text)
+      .with_context(|| format!("Failed parsing '{}'.", path.display())),
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+      Ok(GrantsFile::default())
+    }
+    Err(err) => Err(err.into()),
+  }
+}
+
+fn write_grants_file(path: &Path, file: &GrantsFile) -> Result<(), AnyError> {
+  if file.scripts.is_empty() {
+    return match std::fs::remove_file(path) {
+      Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+      _ => Ok(()),
+    };
+  }
+  let text = serde_json::to_string_pretty(&GrantsFile {
+    version: 1,
+    scripts: file.scripts.clone(),
+  })?;
+  atomic_write_file_with_retries(
+    &CliSys::default(),
+    path,
+    text.as_bytes(),
+    crate::cache::CACHE_PERM,
+  )
+  .with_context(|| format!("Failed writing '{}'.", path.display()))
+}
+
+/// The remembered grants of one script in a project.
+pub struct PermissionGrantStore {
+  path: PathBuf,
+  script_hash: String,
+  grants: Mutex<ScriptGrants>,
+  /// The local modules of the main module graph.
+  local_modules: Mutex<Vec<Url>>,
+}
+
+impl PermissionGrantStore {
+  pub fn load(
+    grants_dir: &Path,
+    project_dir: &Path,
+    main_module: &Url,
+    source: &[u8],
+  ) -> Result<Self, AnyError> {
+    let path = grants_file_path(grants_dir, project_dir);
+    let script_hash = faster_hex::hex_string(&sha2::Sha256::digest(source));
+    let mut grants = read_grants_file(&path)?
+      .scripts
+      .remove(&script_hash)
+      .filter(|grants| {
+        grants.modules.iter().all(|(specifier, hash)| {
+          Url::parse(specifier)
+            .ok()
+            .and_then(|specifier| hash_module(&specifier))
+            .is_some_and(|current| current == *hash)
+        })
+      })
+      .unwrap_or_default();
+    grants.specifier = main_module.to_string();
+    Ok(Self {
+      path,
+      script_hash,
+      grants: Mutex::new(grants),
+      local_modules: Default::default(),
+    })
+  }
+
+  /// Updates the local modules whose hashes are stored with the grants.
+  pub fn set_module_graph(&self, graph: &ModuleGraph) {
+    *self.local_modules.lock() = graph
+      .modules()
+      .map(|module| module.specifier())
+      .filter(|specifier| specifier.scheme() == "file")
+      .cloned()
+      .collect();
+  }
+
+  /// Adds the remembered grants to the ones given with flags.
+  pub fn apply(&self, options: &mut PermissionsOptions) {
+    let grants = self.grants.lock();
+    for (flag_name, values) in &grants.permissions {
+      let allow = match flag_name.as_str() {
+        "env" => &mut options.allow_env,
+        "net" => &mut options.allow_net,
+        "ffi" => &mut options.allow_ffi,
+        "read" => &mut options.allow_read,
+        "run" => &mut options.allow_run,
+        "sys" => &mut options.allow_sys,
+        "write" => &mut options.allow_write,
+        "import" => &mut options.allow_import,
+        _ => continue,
+      };
+      match allow {
+        // all are granted already
+        Some(existing) if existing.is_empty() => {}
+        _ if values.is_empty() => *allow = Some(Vec::new()),
+        Some(existing) => existing.extend(values.iter().cloned()),
+        None => *allow = Some(values.iter().cloned().collect()),
+      }
+    }
+  }
+
+  fn remember(
+    &self,
+    flag_name: &str,
+    value: Option<&str>,
+  ) -> Result<(), AnyError> {
+    let mut grants = self.grants.lock();
+    if !grants.insert(flag_name, value) {
+      return Ok(());
+    }
+    grants.modules = self
+      .local_modules
+      .lock()
+      .iter()
+      .filter_map(|specifier| {
+        Some((specifier.to_string(), hash_module(specifier)?))
+      })
+      .collect();
+    let mut file = read_grants_file(&self.path)?;
+    file
+      .scripts
+      .insert(self.script_hash.clone(), grants.clone());
+    write_grants_file(&self.path, &file)
+  }
+
+  /// Remembers permissions granted at prompts from now on.
+  pub fn record_prompt_grants(self: Arc<Self>) {
+    deno_runtime::deno_permissions::prompter::set_prompt_grant_callback(
+      Box::new(move |flag_name, value| {
+        if let Err(err) = self.remember(flag_name, value) {
+          log::warn!(
+            "{} Failed remembering permission: {:#}",
+            colors::yellow("Warning"),
+            err
+          );
+        }
+      }),
+    );
+  }
+}
+
+pub async fn permissions(
+  flags: Arc<Flags>,
+  permissions_flags: PermissionsFlags,
+) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  let cli_options = factory.cli_options()?;
+  let grants_dir = factory.deno_dir()?.permission_grants_folder_path();
+  let path =
+    grants_file_path(&grants_dir, &cli_options.workspace().root_dir_path());
+  let mut file = read_grants_file(&path)?;
+
+  match permissions_flags {
+    PermissionsFlags::List => {
+      if file.scripts.is_empty() {
+        log::info!("No remembered permissions in this project.");
+        return Ok(());
+      }
+      for (hash, grants) in &file.scripts {
+        log::info!(
+          "{} {}",
+          colors::green(&grants.specifier),
+          colors::gray(format!("({})", &hash[..12]))
+        );
+        for (flag_name, values) in &grants.permissions {
+          if values.is_empty() {
+            log::info!("  --allow-{}", flag_name);
+          } else {
+            let values = values.iter().cloned().collect::<Vec<_>>();
+            log::info!("  --allow-{}={}", flag_name, values.join(","));
+          }
+        }
+      }
+    }
+    PermissionsFlags::Revoke { scripts } => {
+      let before = file.scripts.len();
+      if scripts.is_empty() {
+        file.scripts.clear();
+      } else {
+        let specifiers = scripts
+          .iter()
+          .map(|script| {
+            deno_path_util::resolve_url_or_path(
+              script,
+              cli_options.initial_cwd(),
+            )
+            .map(|url| url.to_string())
+          })
+          .collect::<Result<BTreeSet<_>, _>>()?;
+        file
+          .scripts
+          .retain(|_, grants| !specifiers.contains(&grants.specifier));
+      }
+      let revoked = before - file.scripts.len();
+      write_grants_file(&path, &file)?;
+      log::info!(
+        "Revoked remembered permissions of {} script{}.",
+        revoked,
+        if revoked == 1 { "" } else { "s" }
+      );
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn inserts_grants() {
+    let mut grants = ScriptGrants::default();
+    assert!(grants.insert("read", Some("/a")));
+    assert!(!grants.insert("read", Some("/a")));
+    assert!(grants.insert("read", Some("/b")));
+    assert!(grants.insert("net", None));
+    assert!(!grants.insert("net", None));
+
+    let mut options = PermissionsOptions {
+      allow_read: Some(vec!["/c".to_string()]),
+      allow_env: Some(vec![]),
+      ..Default::default()
+    };
+    grants.insert("env", Some("HOME"));
+    let store = PermissionGrantStore {
+      path: PathBuf::new(),
+      script_hash: String::new(),
+      grants: Mutex::new(grants),
+      local_modules: Default::default(),
+    };
+    store.apply(&mut options);
+    assert_eq!(
+      options.allow_read,
+      Some(vec!["/c".to_string(), "/a".to_string(), "/b".to_string()])
+    );
+    assert_eq!(options.allow_net, Some(vec![]));
+    assert_eq!(options.allow_env, Some(vec![]));
+  }
+
+  #[test]
+  fn discards_grants_when_local_module_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let main_path = dir.path().join("main.ts");
+    let dep_path = dir.path().join("dep.ts");
+    std::fs::write(&main_path, "import './dep.ts';").unwrap();
+    std::fs::write(&dep_path, "export {};").unwrap();
+    let main_module = Url::from_file_path(&main_path).unwrap();
+    let dep_module = Url::from_file_path(&dep_path).unwrap();
+    let load = || {
+      PermissionGrantStore::load(
+        dir.path(),
+        dir.path(),
+        &main_module,
+        b"import './dep.ts';",
+      )
+      .unwrap()
+    };
+
+    let store = load();
+    *store.local_modules.lock() = vec![main_module.clone(), dep_module];
+    store.remember("read", None).unwrap();
+    assert_eq!(load().grants.lock().permissions.len(), 1);
+
+    std::fs::write(&dep_path, "Deno.readTextFileSync('/etc/passwd');").unwrap();
+    assert!(load().grants.lock().permissions.is_empty());
+  }
+}
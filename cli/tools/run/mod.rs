@@ -23,6 +23,8 @@ use crate::args::Flags;
 use crate::args::RunFlags;
 use crate::args::WatchFlagsWithPaths;
 use crate::factory::CliFactory;
+use crate::tools::test::fmt::pretty_resource_name;
+use crate::tools::test::fmt::resource_close_hint;
 use crate::util;
 use crate::util::file_watcher::WatcherRestartMode;
 use crate::util::watch_env_tracker::WatchEnvTracker;
@@ -113,13 +115,50 @@ pub async fn run_script(
     .await
     .inspect_err(|e| deno_telemetry::report_event("boot_failure", e))?;
 
+  if cli_options.trace_leaks() {
+    // `Deno.exit()` and signals end the process without returning here
+    deno_signals::before_exit(report_open_resources);
+  }
   let exit_code = worker
     .run()
     .await
     .inspect_err(|e| deno_telemetry::report_event("uncaught_exception", e))?;
+  if cli_options.trace_leaks() {
+    report_open_resources();
+  }
   Ok(exit_code)
 }
 
+/// Reports the resources left open with `--trace-leaks`.
+fn report_open_resources() {
+  let open = deno_runtime::resource_leaks::take_open_resources()
+    .into_iter()
+    .filter(|(_, origin)| {
+      !matches!(origin.name.as_str(), "stdin" | "stdout" | "stderr")
+    })
+    .collect::<Vec<_>>();
+  if open.is_empty() {
+    return;
+  }
+  log::warn!(
+    "{} {} resource{} still open on exit:",
+    crate::colors::yellow("Warning"),
+    open.len(),
+    if open.len() == 1 { " was" } else { "s were" }
+  );
+  for (rid, origin) in open {
+    let (name, action, _) = pretty_resource_name(&origin.name);
+    log::warn!(
+      "  {name} (rid {rid}) {action} by {}. {}",
+      origin.op,
+      resource_close_hint(&origin.name)
+    );
+    for frame in origin.stack {
+      log::warn!("    {}", crate::colors::gray(format!("at {frame}")));
+    }
+  }
+}
+
 pub async fn run_from_stdin(
   flags: Arc<Flags>,
   unconfigured_runtime: Option<deno_runtime::UnconfiguredRuntime>,
@@ -202,7 +202,12 @@ pub async fn execute_script(
     env_vars,
     cli_options,
     maybe_lockfile,
-    concurrency: no_of_concurrent_tasks.into(),
+    // long running tasks, like dev servers, must not wait for a free slot
+    concurrency: if task_flags.parallel {
+      usize::MAX
+    } else {
+      no_of_concurrent_tasks.into()
+    },
   };
 
   let kill_signal = KillSignal::default();
@@ -398,7 +403,7 @@ impl<'a> TaskRunner<'a> {
 
     let mut context = PendingTasksContext {
       completed: HashSet::with_capacity(tasks.len()),
-      running: HashSet::with_capacity(self.concurrency),
+      running: HashSet::with_capacity(self.concurrency.min(tasks.len())),
       tasks: &tasks,
     };
 
@@ -424,6 +429,11 @@ impl<'a> TaskRunner<'a> {
 
       let (exit_code, name) = result?;
       if exit_code > 0 {
+        if self.task_flags.parallel {
+          // stop the other tasks, and let them write the rest of their output
+          kill_signal.send(deno_task_shell::SignalKind::SIGTERM);
+          while queue.next().await.is_some() {}
+        }
         return Ok(exit_code);
       }
 
@@ -561,7 +571,13 @@ impl<'a> TaskRunner<'a> {
         init_cwd: self.cli_options.initial_cwd(),
         argv,
         root_node_modules_dir: self.npm_resolver.root_node_modules_path(),
-        stdio: None,
+        stdio: self.task_flags.parallel.then(|| {
+          let prefix = task_prefix(task_name);
+          task_runner::TaskIo {
+            stdout: task_runner::TaskStdio::prefixed(prefix.clone(), false),
+            stderr: task_runner::TaskStdio::prefixed(prefix, true),
+          }
+        }),
         kill_signal,
       })
       .await?
@@ -604,6 +620,22 @@ impl<'a> TaskRunner<'a> {
   }
 }
 
+/// The prefix of the output of a task with `--parallel`, colored by the
+/// name, so that a task keeps its color between runs.
+fn task_prefix(task_name: &str) -> String {
+  let name = format!("[{task_name}]");
+  let color = task_name
+    .bytes()
+    .fold(0usize, |hash, b| hash.wrapping_add(b as usize));
+  let name = match color % 4 {
+    0 => colors::cyan(name).to_string(),
+    1 => colors::magenta(name).to_string(),
+    2 => colors::yellow(name).to_string(),
+    _ => colors::green(name).to_string(),
+  };
+  format!("{name} ")
+}
+
 #[derive(Debug)]
 enum TaskError {
   NotFound(String),
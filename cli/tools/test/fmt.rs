@@ -242,7 +242,7 @@ fn format_sanitizer_accum_item(
   }
 }
 
-fn pretty_resource_name(
+pub fn pretty_resource_name(
   name: &str,
 ) -> (Cow<'static, str>, &'static str, &'static str) {
   let (name, action1, action2) = match name {
@@ -281,7 +281,7 @@ fn pretty_resource_name(
   (name.into(), action1, action2)
 }
 
-fn resource_close_hint(name: &str) -> &'static str {
+pub fn resource_close_hint(name: &str) -> &'static str {
   match name {
     "fsFile" => "Close the file handle by calling `file.close()`.",
     "fetchRequest" => {
@@ -46,6 +46,7 @@ use deno_core::unsync::spawn_blocking;
 use deno_core::url::Url;
 use deno_core::v8;
 use deno_error::JsErrorBox;
+use deno_lib::util::hash::FastInsecureHasher;
 use deno_npm_installer::graph::NpmCachingStrategy;
 use deno_runtime::WorkerExecutionMode;
 use deno_runtime::coverage::CoverageCollector;
@@ -70,6 +71,7 @@ use crate::args::CliOptions;
 use crate::args::Flags;
 use crate::args::TestFlags;
 use crate::args::TestReporterConfig;
+use crate::args::TestShard;
 use crate::colors;
 use crate::display;
 use crate::factory::CliFactory;
@@ -92,6 +94,7 @@ mod channel;
 pub mod fmt;
 pub mod reporters;
 mod sanitizers;
+mod snapshot;
 
 pub use channel::TestEventReceiver;
 pub use channel::TestEventSender;
@@ -106,6 +109,8 @@ use reporters::JunitTestReporter;
 use reporters::PrettyTestReporter;
 use reporters::TapTestReporter;
 use reporters::TestReporter;
+pub use snapshot::SnapshotError;
+pub use snapshot::SnapshotStore;
 
 use crate::tools::coverage::cover_files;
 use crate::tools::coverage::reporter;
@@ -193,6 +198,30 @@ impl TestFilter {
   }
 }
 
+/// Selects the tests of one shard of `deno test --shard`. Tests are assigned
+/// by a hash of their module, relative to the working directory, and their
+/// name, so every machine that runs a shard agrees on which tests it has.
+#[derive(Clone, Debug)]
+pub struct TestShardFilter {
+  pub shard: TestShard,
+  pub cwd: Url,
+}
+
+impl TestShardFilter {
+  pub fn includes(&self, specifier: &Url, name: &str) -> bool {
+    let origin = self
+      .cwd
+      .make_relative(specifier)
+      .unwrap_or_else(|| specifier.to_string());
+    let hash = FastInsecureHasher::new_without_deno_version()
+      .write_str(&origin)
+      .write_u8(0)
+      .write_str(name)
+      .finish();
+    hash % self.shard.count as u64 == (self.shard.index - 1) as u64
+  }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct TestLocation {
@@ -559,6 +588,8 @@ pub struct TestSummary {
   pub measured: usize,
   pub failures: Vec<(TestFailureDescription, TestFailure)>,
   pub uncaught_errors: Vec<(String, Box<JsError>)>,
+  #[serde(skip)]
+  pub shard: Option<TestShard>,
 }
 
 #[derive(Debug, Clone)]
@@ -578,6 +609,8 @@ struct TestSpecifiersOptions {
 pub struct TestSpecifierOptions {
   pub shuffle: Option<u64>,
   pub filter: TestFilter,
+  pub shard: Option<TestShardFilter>,
+  pub update_snapshots: bool,
   pub trace_leaks: bool,
 }
 
@@ -595,6 +628,7 @@ impl TestSummary {
       measured: 0,
       failures: Vec::new(),
       uncaught_errors: Vec::new(),
+      shard: None,
     }
   }
 
@@ -605,24 +639,28 @@ impl TestSummary {
 
 fn get_test_reporter(options: &TestSpecifiersOptions) -> Box<dyn TestReporter> {
   let parallel = options.concurrent_jobs.get() > 1;
+  let shard = options.specifier.shard.as_ref().map(|shard| shard.shard);
   let failure_format_options = TestFailureFormatOptions {
     hide_stacktraces: options.hide_stacktraces,
     strip_ascii_color: false,
     initial_cwd: Some(options.cwd.clone()),
   };
   let reporter: Box<dyn TestReporter> = match &options.reporter {
-    TestReporterConfig::Dot => Box::new(DotTestReporter::new(
-      options.cwd.clone(),
-      failure_format_options,
-    )),
-    TestReporterConfig::Pretty => Box::new(PrettyTestReporter::new(
-      parallel,
-      options.log_level != Some(Level::Error),
-      options.filter,
-      false,
-      options.cwd.clone(),
-      failure_format_options,
-    )),
+    TestReporterConfig::Dot => Box::new(
+      DotTestReporter::new(options.cwd.clone(), failure_format_options)
+        .with_shard(shard),
+    ),
+    TestReporterConfig::Pretty => Box::new(
+      PrettyTestReporter::new(
+        parallel,
+        options.log_level != Some(Level::Error),
+        options.filter,
+        false,
+        options.cwd.clone(),
+        failure_format_options,
+      )
+      .with_shard(shard),
+    ),
     TestReporterConfig::Junit => Box::new(JunitTestReporter::new(
       options.cwd.clone(),
       "-".to_string(),
@@ -686,6 +724,13 @@ async fn configure_main_worker(
     )
     .await?;
   let coverage_collector = worker.maybe_setup_coverage_collector();
+  if options.update_snapshots {
+    worker
+      .op_state()
+      .borrow_mut()
+      .borrow_mut::<SnapshotStore>()
+      .update = true;
+  }
   if options.trace_leaks {
     worker
       .execute_script_static(
@@ -877,6 +922,9 @@ pub enum RunTestsForWorkerErr {
   #[class(inherit)]
   #[error(transparent)]
   SerdeV8(#[from] serde_v8::Error),
+  #[class(inherit)]
+  #[error(transparent)]
+  Snapshot(#[from] SnapshotError),
 }
 
 async fn slow_test_watchdog(event_tracker: TestEventTracker, test_id: usize) {
@@ -931,9 +979,11 @@ pub async fn run_tests_for_worker(
 }
 
 fn compute_tests_to_run(
+  specifier: &ModuleSpecifier,
   descs: &TestDescriptions,
   test_functions: Vec<v8::Global<v8::Function>>,
   filter: TestFilter,
+  shard: Option<&TestShardFilter>,
 ) -> (Vec<(&TestDescription, v8::Global<v8::Function>)>, bool) {
   let mut tests_to_run = Vec::with_capacity(descs.len());
   let mut used_only = false;
@@ -941,6 +991,11 @@ fn compute_tests_to_run(
     if !filter.includes(&d.name) {
       continue;
     }
+    if let Some(shard) = shard
+      && !shard.includes(specifier, &d.name)
+    {
+      continue;
+    }
 
     // If we've seen an "only: true" test, the remaining tests must be "only: true" to be added
     if used_only && !d.only {
@@ -994,8 +1049,13 @@ async fn run_tests_for_worker_inner(
 ) -> Result<(), RunTestsForWorkerErr> {
   let unfiltered = descs.len();
 
-  let (mut tests_to_run, used_only) =
-    compute_tests_to_run(&descs, test_functions, options.filter.clone());
+  let (mut tests_to_run, used_only) = compute_tests_to_run(
+    specifier,
+    &descs,
+    test_functions,
+    options.filter.clone(),
+    options.shard.as_ref(),
+  );
 
   if let Some(seed) = options.shuffle {
     tests_to_run.shuffle(&mut SmallRng::seed_from_u64(seed));
@@ -1155,6 +1215,13 @@ async fn run_tests_for_worker_inner(
     }
   }
 
+  worker
+    .js_runtime
+    .op_state()
+    .borrow_mut()
+    .borrow_mut::<SnapshotStore>()
+    .write()?;
+
   event_tracker.completed()?;
 
   // Execute afterAll hooks (LIFO order)
@@ -1599,6 +1666,14 @@ pub async fn run_tests(
   let preload_modules = cli_options.preload_modules()?;
   let require_modules = cli_options.require_modules()?;
 
+  let cwd =
+    Url::from_directory_path(cli_options.initial_cwd()).map_err(|_| {
+      anyhow!(
+        "Unable to construct URL from the path of cwd: {}",
+        cli_options.initial_cwd().to_string_lossy(),
+      )
+    })?;
+
   // Run tests
   test_specifiers(
     worker_factory,
@@ -1608,14 +1683,7 @@ pub async fn run_tests(
     preload_modules,
     require_modules,
     TestSpecifiersOptions {
-      cwd: Url::from_directory_path(cli_options.initial_cwd()).map_err(
-        |_| {
-          anyhow!(
-            "Unable to construct URL from the path of cwd: {}",
-            cli_options.initial_cwd().to_string_lossy(),
-          )
-        },
-      )?,
+      cwd: cwd.clone(),
       concurrent_jobs: workspace_test_options.concurrent_jobs,
       fail_fast: workspace_test_options.fail_fast,
       log_level,
@@ -1626,6 +1694,10 @@ pub async fn run_tests(
       specifier: TestSpecifierOptions {
         filter: TestFilter::from_flag(&workspace_test_options.filter),
         shuffle: workspace_test_options.shuffle,
+        shard: workspace_test_options
+          .shard
+          .map(|shard| TestShardFilter { shard, cwd }),
+        update_snapshots: workspace_test_options.update_snapshots,
         trace_leaks: workspace_test_options.trace_leaks,
       },
     },
@@ -1813,6 +1885,15 @@ pub async fn run_tests_with_watch(
         let preload_modules = cli_options.preload_modules()?;
         let require_modules = cli_options.require_modules()?;
 
+        let cwd = Url::from_directory_path(cli_options.initial_cwd()).map_err(
+          |_| {
+            anyhow!(
+              "Unable to construct URL from the path of cwd: {}",
+              cli_options.initial_cwd().to_string_lossy(),
+            )
+          },
+        )?;
+
         test_specifiers(
           worker_factory,
           &cli_options,
@@ -1821,14 +1902,7 @@ pub async fn run_tests_with_watch(
           preload_modules,
           require_modules,
           TestSpecifiersOptions {
-            cwd: Url::from_directory_path(cli_options.initial_cwd()).map_err(
-              |_| {
-                anyhow!(
-                  "Unable to construct URL from the path of cwd: {}",
-                  cli_options.initial_cwd().to_string_lossy(),
-                )
-              },
-            )?,
+            cwd: cwd.clone(),
             concurrent_jobs: workspace_test_options.concurrent_jobs,
             fail_fast: workspace_test_options.fail_fast,
             log_level,
@@ -1839,6 +1913,10 @@ pub async fn run_tests_with_watch(
             specifier: TestSpecifierOptions {
               filter: TestFilter::from_flag(&workspace_test_options.filter),
               shuffle: workspace_test_options.shuffle,
+              shard: workspace_test_options
+                .shard
+                .map(|shard| TestShardFilter { shard, cwd }),
+              update_snapshots: workspace_test_options.update_snapshots,
               trace_leaks: workspace_test_options.trace_leaks,
             },
           },
@@ -220,6 +220,10 @@ pub(super) fn report_summary(
     write!(summary_result, " | {} filtered out", summary.filtered_out).ok();
   };
 
+  if let Some(shard) = summary.shard {
+    write!(summary_result, " | shard {shard}").ok();
+  }
+
   writeln!(
     writer,
     "\n{} | {} {}",
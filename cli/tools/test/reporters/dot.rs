@@ -34,6 +34,11 @@ impl DotTestReporter {
     }
   }
 
+  pub fn with_shard(mut self, shard: Option<TestShard>) -> Self {
+    self.summary.shard = shard;
+    self
+  }
+
   fn print_status(&mut self, status: String) {
     // Non-TTY console prints every result on a separate line.
     if self.width == 0 {
@@ -55,6 +55,11 @@ impl PrettyTestReporter {
     Self { writer, ..self }
   }
 
+  pub fn with_shard(mut self, shard: Option<TestShard>) -> Self {
+    self.summary.shard = shard;
+    self
+  }
+
   fn force_report_wait(&mut self, description: &TestDescription) {
     if !self.in_new_line {
       writeln!(&mut self.writer).ok();
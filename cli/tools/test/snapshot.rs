@@ -0,0 +1,245 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Snapshot testing with `t.assertSnapshot()`. The snapshots of a test
+//! module are kept in `__snapshots__/<module>.snap` next to it, in the
+//! format of `@std/testing/snapshot`, and are only written by the test
+//! runner when `deno test --update-snapshots` is given.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::PathBuf;
+
+use deno_core::url::Url;
+use deno_runtime::deno_permissions::OpenAccessKind;
+use deno_runtime::deno_permissions::PermissionCheckError;
+use deno_runtime::deno_permissions::PermissionsContainer;
+use indexmap::IndexMap;
+
+#[derive(Debug, thiserror::Error, deno_error::JsError)]
+pub enum SnapshotError {
+  #[class(type)]
+  #[error("Snapshots are only supported for local test modules, not {0}")]
+  NotLocal(Url),
+  #[class(generic)]
+  #[error("Failed reading snapshots from {}: {source}", path.display())]
+  Read { path: PathBuf, source: io::Error },
+  #[class(generic)]
+  #[error("Failed parsing snapshots in {}", path.display())]
+  Parse { path: PathBuf },
+  #[class(generic)]
+  #[error(
+    "Missing snapshot: {0}\n\nRun `deno test --update-snapshots` to create it."
+  )]
+  Missing(String),
+  #[class(generic)]
+  #[error(
+    "Snapshot does not match: {name}\n\n{diff}\nRun `deno test --update-snapshots` to update it."
+  )]
+  Mismatch { name: String, diff: String },
+  #[class(inherit)]
+  #[error(transparent)]
+  Permission(#[from] PermissionCheckError),
+  #[class(generic)]
+  #[error("Failed writing snapshots to {}: {source}", path.display())]
+  Write { path: PathBuf, source: io::Error },
+}
+
+struct SnapshotFile {
+  path: PathBuf,
+  snapshots: IndexMap<String, String>,
+  changed: bool,
+}
+
+/// The snapshots of the test module of a worker.
+#[derive(Default)]
+pub struct SnapshotStore {
+  pub update: bool,
+  file: Option<SnapshotFile>,
+  /// How many times each name was asserted, to number the snapshots.
+  counts: HashMap<String, usize>,
+}
+
+impl SnapshotStore {
+  #[allow(clippy::disallowed_methods)]
+  fn file(
+    &mut self,
+    specifier: &Url,
+  ) -> Result<&mut SnapshotFile, SnapshotError> {
+    if self.file.is_none() {
+      let path = snapshot_path(specifier)?;
+      let snapshots = match std::fs::read_to_string(&path) {
+        Ok(text) => parse(&text)
+          .ok_or_else(|| SnapshotError::Parse { path: path.clone() })?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => IndexMap::new(),
+        Err(source) => return Err(SnapshotError::Read { path, source }),
+      };
+      self.file = Some(SnapshotFile {
+        path,
+        snapshots,
+        changed: false,
+      });
+    }
+    Ok(self.file.as_mut().unwrap())
+  }
+
+  /// Compares a serialized value with its snapshot, or records it when
+  /// updating, which needs write permission to the snapshot file.
+  pub fn assert(
+    &mut self,
+    specifier: &Url,
+    name: &str,
+    actual: String,
+    permissions: &PermissionsContainer,
+  ) -> Result<(), SnapshotError> {
+    let count = self.counts.entry(name.to_string()).or_default();
+    *count += 1;
+    let name = format!("{name} {count}");
+    let update = self.update;
+    let file = self.file(specifier)?;
+    let expected = file.snapshots.get(&name);
+    if expected == Some(&actual) {
+      return Ok(());
+    }
+    if !update {
+      return Err(match expected {
+        Some(expected) => SnapshotError::Mismatch {
+          diff: deno_resolver::display::diff(expected, &actual),
+          name,
+        },
+        None => SnapshotError::Missing(name),
+      });
+    }
+    let _ = permissions.check_open(
+      Cow::Borrowed(&file.path),
+      OpenAccessKind::Write,
+      Some("TestContext.assertSnapshot()"),
+    )?;
+    file.snapshots.insert(name, actual);
+    file.changed = true;
+    Ok(())
+  }
+
+  /// Writes the snapshots that were added or changed while updating.
+  #[allow(clippy::disallowed_methods)]
+  pub fn write(&mut self) -> Result<(), SnapshotError> {
+    let Some(file) = self.file.as_mut().filter(|file| file.changed) else {
+      return Ok(());
+    };
+    let write = |file: &SnapshotFile| {
+      if let Some(dir) = file.path.parent() {
+        std::fs::create_dir_all(dir)?;
+      }
+      std::fs::write(&file.path, serialize(&file.snapshots))
+    };
+    write(file).map_err(|source| SnapshotError::Write {
+      path: file.path.clone(),
+      source,
+    })?;
+    file.changed = false;
+    Ok(())
+  }
+}
+
+fn snapshot_path(specifier: &Url) -> Result<PathBuf, SnapshotError> {
+  let path = specifier
+    .to_file_path()
+    .map_err(|_| SnapshotError::NotLocal(specifier.clone()))?;
+  let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+    return Err(SnapshotError::NotLocal(specifier.clone()));
+  };
+  Ok(
+    dir
+      .join("__snapshots__")
+      .join(format!("{}.snap", file_name.to_string_lossy())),
+  )
+}
+
+fn escape(text: &str) -> String {
+  text
+    .replace('\\', "\\\\")
+    .replace('`', "\\`")
+    .replace('$', "\\$")
+}
+
+fn serialize(snapshots: &IndexMap<String, String>) -> String {
+  let mut out = String::from("export const snapshot = {};\n");
+  for (name, value) in snapshots {
+    // values spanning lines start and end on a line of their own
+    let value = if value.contains('\n') {
+      Cow::Owned(format!("\n{value}\n"))
+    } else {
+      Cow::Borrowed(value)
+    };
+    write!(
+      out,
+      "\nsnapshot[`{}`] = `{}`;\n",
+      escape(name),
+      escape(&value)
+    )
+    .unwrap();
+  }
+  out
+}
+
+/// Reads a template literal up to its closing backtick, returning its
+/// unescaped content and the text after it.
+fn read_template(text: &str) -> Option<(String, &str)> {
+  let mut value = String::new();
+  let mut chars = text.char_indices();
+  while let Some((i, c)) = chars.next() {
+    match c {
+      '`' => return Some((value, &text[i + 1..])),
+      '\\' => value.push(chars.next()?.1),
+      c => value.push(c),
+    }
+  }
+  None
+}
+
+fn parse(text: &str) -> Option<IndexMap<String, String>> {
+  const START: &str = "snapshot[`";
+  let mut snapshots = IndexMap::new();
+  let mut rest = text;
+  while let Some(start) = rest.find(START) {
+    let (name, after) = read_template(&rest[start + START.len()..])?;
+    let (value, after) = read_template(after.strip_prefix("] = `")?)?;
+    let value =
+      if value.len() > 1 && value.starts_with('\n') && value.ends_with('\n') {
+        value[1..value.len() - 1].to_string()
+      } else {
+        value
+      };
+    snapshots.insert(name, value);
+    rest = after;
+  }
+  Some(snapshots)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn serializes_snapshots() {
+    let mut snapshots = IndexMap::new();
+    snapshots.insert("object 1".to_string(), "{\n  a: `${1}`,\n}".to_string());
+    snapshots.insert("a > b 1".to_string(), "\"a\\b\"".to_string());
+    let text = serialize(&snapshots);
+    assert_eq!(
+      text,
+      r#"export const snapshot = {};
+
+snapshot[`object 1`] = `
+{
+  a: \`\${1}\`,
+}
+`;
+
+snapshot[`a > b 1`] = `"a\\b"`;
+"#
+    );
+    assert_eq!(parse(&text), Some(snapshots));
+  }
+}
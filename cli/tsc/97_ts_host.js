@@ -26,7 +26,11 @@ function spanned(name, f) {
 /** @type {ReadonlySet<string>} */
 const unstableDenoProps = new Set([
   "AtomicOperation",
+  "ConnectDnsOptions",
+  "ConnectProxy",
   "DatagramConn",
+  "IcmpPingOptions",
+  "IcmpPingReply",
   "Kv",
   "KvListIterator",
   "KvU64",
@@ -34,6 +38,10 @@ const unstableDenoProps = new Set([
   "UnixListenOptions",
   "listen",
   "listenDatagram",
+  "ListenSystemdOptions",
+  "listenSystemd",
+  "notifySystemd",
+  "icmpPing",
   "openKv",
   "connectQuic",
   "listenQuic",
@@ -42,6 +50,23 @@ const unstableDenoProps = new Set([
   "QuicListener",
   "QuicReceiveStream",
   "QuicSendStream",
+  "ReadWithHandlesResult",
+  "ResolvingTlsListener",
+  "ServeDirOptions",
+  "ServeLimits",
+  "ServeListenerOptions",
+  "ServeMetricsSnapshot",
+  "ServeShutdownOptions",
+  "ServeShutdownResult",
+  "ServerSentEvent",
+  "ServerSentEvents",
+  "ServerSentEventsOptions",
+  "TlsCertificateResolver",
+  "TransferableHandle",
+  "UnixCredentials",
+  "serveDir",
+  "serveFile",
+  "serverSentEvents",
 ]);
 const unstableMsgSuggestion =
   "If not, try changing the 'lib' compiler option to include 'deno.unstable' " +
@@ -101,6 +101,26 @@ interface Performance {
   ): PerformanceMeasure;
 }
 
+/**
+ * @category Crypto
+ */
+interface SubtleCrypto {
+  /**
+   * Computes the digest of a stream or of the rest of a file, which are read
+   * in chunks instead of being buffered in full.
+   *
+   * @example
+   * ```ts
+   * using file = await Deno.open("./large.bin");
+   * await crypto.subtle.digest("SHA-256", file);
+   * ```
+   */
+  digest(
+    algorithm: AlgorithmIdentifier,
+    data: ReadableStream<BufferSource> | Deno.FsFile,
+  ): Promise<ArrayBuffer>;
+}
+
 /**
  * Options which are used in conjunction with `performance.mark`. Check out the
  * MDN
@@ -317,6 +337,40 @@ declare namespace Deno {
      *
      * @category Errors */
     export class NotADirectory extends Error {}
+    /**
+     * Raised when trying to rename or link a path across file systems
+     * or mount points.
+     *
+     * @category Errors */
+    export class CrossDevice extends Error {}
+    /**
+     * Raised when trying to write to an executable file that is currently
+     * being run, or execute a file that is open for writing.
+     *
+     * @category Errors */
+    export class TextFileBusy extends Error {}
+    /**
+     * Raised when a file would grow beyond the maximum size supported by
+     * the file system or the process limits.
+     *
+     * @category Errors */
+    export class FileTooLarge extends Error {}
+    /**
+     * Raised when trying to remove or replace a directory that still has
+     * entries.
+     *
+     * @category Errors */
+    export class DirectoryNotEmpty extends Error {}
+    /**
+     * Raised when trying to modify a path on a read-only file system.
+     *
+     * @category Errors */
+    export class ReadOnlyFileSystem extends Error {}
+    /**
+     * Raised when the underlying storage device has no space left.
+     *
+     * @category Errors */
+    export class StorageFull extends Error {}
 
     /**
      * Raised when trying to perform an operation while the relevant Deno
@@ -451,6 +505,10 @@ declare namespace Deno {
    *
    * This is similar to the `free` command in Linux
    *
+   * On Linux, when the process runs in a cgroup with a memory limit below
+   * the installed memory, such as in a container, the limit is reported as
+   * the total and the free and available memory are capped by it.
+   *
    * ```ts
    * console.log(Deno.systemMemoryInfo());
    * ```
@@ -702,6 +760,26 @@ declare namespace Deno {
     /** If the current test is a step of another test, the parent test context
      * will be set here. */
     parent?: TestContext;
+    /** The virtual clock driving the timers and `performance.now()` of a
+     * test that sets `virtualTime`, shared with its steps. Throws when the
+     * test does not use virtual time.
+     *
+     * ```ts
+     * Deno.test({
+     *   name: "debounce",
+     *   virtualTime: true,
+     *   fn(t) {
+     *     let calls = 0;
+     *     setTimeout(() => calls++, 1000);
+     *     t.clock.tick(999);
+     *     // calls === 0
+     *     t.clock.tick(1);
+     *     // calls === 1
+     *   },
+     * });
+     * ```
+     */
+    readonly clock: TestClock;
 
     /** Run a sub step of the parent test or step. Returns a promise
      * that resolves to a boolean signifying if the step completed successfully.
@@ -774,6 +852,56 @@ declare namespace Deno {
      * ```
      */
     step(fn: (t: TestContext) => void | Promise<void>): Promise<boolean>;
+
+    /** Assert that a value matches its snapshot, which is kept in a
+     * `__snapshots__/<module>.snap` file next to the test module. Snapshots
+     * are named after the test and its steps and numbered in the order they
+     * are asserted.
+     *
+     * Run `deno test --update-snapshots` to create or update the snapshots,
+     * which needs write permission to the snapshot file.
+     *
+     * ```ts
+     * Deno.test("render", (t) => {
+     *   t.assertSnapshot({ title: "Home", links: ["/about"] });
+     * });
+     * ```
+     */
+    assertSnapshot(actual: unknown, options?: AssertSnapshotOptions): void;
+  }
+
+  /** A virtual clock of a test, see {@linkcode TestDefinition.virtualTime}.
+   *
+   * Time only moves forward when the clock is advanced, and timers fire
+   * synchronously while it is, in the order they are due.
+   *
+   * @category Testing */
+  export interface TestClock {
+    /** The current virtual time, in milliseconds since the epoch. */
+    readonly now: number;
+    /** The number of timers that have not fired or been cleared yet. */
+    readonly pending: number;
+    /** Moves the time forward by `ms` milliseconds, firing the timers that
+     * become due. */
+    tick(ms: number): void;
+    /** Like {@linkcode TestClock.tick}, but lets pending promises settle
+     * after each timer fired and before the first one, so that timers
+     * scheduled after an `await` fire in the same call. */
+    tickAsync(ms: number): Promise<void>;
+    /** Fires timers until none are left, including the ones scheduled while
+     * running them. Throws if they never run out, like with an interval. */
+    runAll(): void;
+  }
+
+  /** @category Testing */
+  export interface AssertSnapshotOptions {
+    /** The name of the snapshot, instead of the name of the test and its
+     * steps. */
+    name?: string;
+    /** Turns the value into the text that is stored in the snapshot.
+     * Defaults to {@linkcode Deno.inspect} with options that show the whole
+     * value, sorted. */
+    serializer?: (value: unknown) => string;
   }
 
   /** @category Testing */
@@ -852,6 +980,16 @@ declare namespace Deno {
      *
      * @default {"inherit"} */
     permissions?: PermissionOptions;
+    /** Drive `setTimeout()`, `setInterval()` and `performance.now()` by a
+     * virtual clock while the test runs, which is advanced through
+     * {@linkcode TestContext.clock}. The clock starts at the current time, or
+     * at `now` milliseconds since the epoch. Timers that did not fire when the
+     * test finishes are dropped, and timers created before the test keep
+     * using the real time. `Date` is not affected and keeps using the real
+     * time.
+     *
+     * @default {false} */
+    virtualTime?: boolean | { now?: number };
   }
 
   /** Register a test which will be run when `deno test` is used on the command
@@ -1667,6 +1805,22 @@ declare namespace Deno {
    */
   export function execPath(): string;
 
+  /**
+   * Returns the paths of the files embedded in a binary created by
+   * `deno compile` with the `--include` flag. The files are read-only and
+   * can be read with the file system APIs. Outside of such a binary an
+   * empty array is returned.
+   *
+   * ```ts
+   * for (const path of Deno.embeddedFiles()) {
+   *   console.log(path, Deno.readTextFileSync(path).length);
+   * }
+   * ```
+   *
+   * @category Runtime
+   */
+  export function embeddedFiles(): string[];
+
   /**
    * Change the current working directory to the specified path.
    *
@@ -1773,7 +1927,7 @@ declare namespace Deno {
    * @category File System
    */
   export function open(
-    path: string | URL,
+    path: string | URL | Uint8Array,
     options?: OpenOptions,
   ): Promise<FsFile>;
 
@@ -1803,7 +1957,10 @@ declare namespace Deno {
    * @tags allow-read, allow-write
    * @category File System
    */
-  export function openSync(path: string | URL, options?: OpenOptions): FsFile;
+  export function openSync(
+    path: string | URL | Uint8Array,
+    options?: OpenOptions,
+  ): FsFile;
 
   /** Creates a file if none exists or truncates an existing file and resolves to
    *  an instance of {@linkcode Deno.FsFile}.
@@ -1833,6 +1990,24 @@ declare namespace Deno {
    */
   export function createSync(path: string | URL): FsFile;
 
+  /** Options for {@linkcode Deno.FsFile.setBuffering}.
+   *
+   * @category File System
+   */
+  export interface FsFileBufferingOptions {
+    /** The size of the buffer in bytes, at most 64 MiB. Writes that are at
+     * least as large are written directly.
+     *
+     * @default {65536} */
+    bytes?: number;
+    /** If set, the buffer is written this many milliseconds after the first
+     * write that was buffered, so that data doesn't stay in memory for long
+     * when the writes are rare.
+     *
+     * @default {0} */
+    flushMs?: number;
+  }
+
   /** The Deno abstraction for reading and writing files.
    *
    * This is the most straight forward way of handling files within Deno and is
@@ -1932,7 +2107,7 @@ declare namespace Deno {
      * const text = new TextDecoder().decode(buf); // "hello w"
      * ```
      */
-    truncate(len?: number): Promise<void>;
+    truncate(len?: number | bigint): Promise<void>;
     /** Synchronously truncates (or extends) the file to reach the specified
      * `len`. If `len` is not specified, then the entire file contents are
      * truncated.
@@ -1955,7 +2130,7 @@ declare namespace Deno {
      * const text = new TextDecoder().decode(buf); // "hello w"
      * ```
      */
-    truncateSync(len?: number): void;
+    truncateSync(len?: number | bigint): void;
     /** Read the file into an array buffer (`p`).
      *
      * Resolves to either the number of bytes read during the operation or EOF
@@ -2032,8 +2207,16 @@ declare namespace Deno {
      * // Seek backwards 2 bytes from the end of the file
      * console.log(await file.seek(-2, Deno.SeekMode.End)); // "9" (i.e. 11-2)
      * ```
+     *
+     * When `offset` is a `bigint` the position is resolved as a `bigint` too,
+     * so offsets past `Number.MAX_SAFE_INTEGER` are represented exactly.
      */
-    seek(offset: number | bigint, whence: SeekMode): Promise<number>;
+    seek(offset: number, whence: SeekMode): Promise<number>;
+    seek(offset: bigint, whence: SeekMode): Promise<bigint>;
+    seek(
+      offset: number | bigint,
+      whence: SeekMode,
+    ): Promise<number | bigint>;
     /** Synchronously seek to the given `offset` under mode given by `whence`.
      * The new position within the resource (bytes from the start) is returned.
      *
@@ -2069,8 +2252,13 @@ declare namespace Deno {
      * // Seek backwards 2 bytes from the end of the file
      * console.log(file.seekSync(-2, Deno.SeekMode.End)); // "9" (i.e. 11-2)
      * ```
+     *
+     * When `offset` is a `bigint` the position is returned as a `bigint` too,
+     * so offsets past `Number.MAX_SAFE_INTEGER` are represented exactly.
      */
-    seekSync(offset: number | bigint, whence: SeekMode): number;
+    seekSync(offset: number, whence: SeekMode): number;
+    seekSync(offset: bigint, whence: SeekMode): bigint;
+    seekSync(offset: number | bigint, whence: SeekMode): number | bigint;
     /** Resolves to a {@linkcode Deno.FileInfo} for the file.
      *
      * ```ts
@@ -2161,6 +2349,40 @@ declare namespace Deno {
      * @category I/O
      */
     syncDataSync(): void;
+    /**
+     * Coalesces small writes to the file in a buffer, which is written once
+     * it is full, after `flushMs` if set, on {@linkcode Deno.FsFile.flushSync},
+     * before any other operation on the file, and when the file is closed or
+     * the process exits. This saves a system call per write for loggers and
+     * other code that writes a little at a time. Pass `null` to write the
+     * buffer and stop buffering.
+     *
+     * Buffered writes report all of their data as written. An error writing
+     * the buffer in the background is thrown by the next write or flush.
+     *
+     * ```ts
+     * using file = Deno.openSync(
+     *   "app.log",
+     *   { write: true, create: true, append: true },
+     * );
+     * file.setBuffering({ bytes: 16 * 1024, flushMs: 100 });
+     * const encoder = new TextEncoder();
+     * for (let i = 0; i < 1000; i++) {
+     *   file.writeSync(encoder.encode(`line ${i}\n`));
+     * }
+     * file.flushSync();
+     * ```
+     *
+     * @category I/O
+     */
+    setBuffering(options: FsFileBufferingOptions | null): void;
+    /**
+     * Writes the data buffered since {@linkcode Deno.FsFile.setBuffering} to
+     * the file. Does nothing if buffering isn't enabled.
+     *
+     * @category I/O
+     */
+    flushSync(): void;
     /**
      * Changes the access (`atime`) and modification (`mtime`) times of the
      * file stream resource. Given times are either in seconds (UNIX epoch
@@ -2260,6 +2482,27 @@ declare namespace Deno {
     rows: number;
   };
 
+  /** Returns an async iterator of the size of the console, yielding each time
+   * the terminal is resized.
+   *
+   * ```ts
+   * for await (const { columns, rows } of Deno.consoleSizeChanges()) {
+   *   console.log(`resized to ${columns}x${rows}`);
+   * }
+   * ```
+   *
+   * Resizes that happen while the previous size is not consumed yet are
+   * coalesced, so only the latest size is yielded. Breaking out of the loop
+   * stops listening for changes. On Unix the changes are reported by
+   * `SIGWINCH`, on Windows the size is polled.
+   *
+   * @category I/O
+   */
+  export function consoleSizeChanges(): AsyncIterableIterator<{
+    columns: number;
+    rows: number;
+  }>;
+
   /** @category I/O */
   export interface SetRawOptions {
     /**
@@ -2268,7 +2511,18 @@ declare namespace Deno {
      * mode, this option is ignored. This functionality currently only works on
      * Linux and Mac OS.
      */
-    cbreak: boolean;
+    cbreak?: boolean;
+    /**
+     * The minimum number of bytes a read waits for in raw mode (`VMIN`),
+     * between 0 and 255. Defaults to `1`. Only supported on Linux and Mac OS.
+     */
+    min?: number;
+    /**
+     * The time in tenths of a second a read waits for input in raw mode
+     * (`VTIME`), between 0 and 255. Defaults to `0`, which waits
+     * indefinitely. Only supported on Linux and Mac OS.
+     */
+    time?: number;
   }
 
   /** A reference to `stdin` which can be used to read directly from `stdin`.
@@ -2433,6 +2687,19 @@ declare namespace Deno {
      * @category I/O
      */
     isTerminal(): boolean;
+    /**
+     * Turns bracketed paste mode of the terminal on or off. While it is on,
+     * pasted text is wrapped in `\x1b[200~` and `\x1b[201~`, so it can be
+     * told apart from typed input when `stdin` is in raw mode. It is turned
+     * off again when the process exits.
+     *
+     * ```ts
+     * Deno.stdout.setBracketedPaste(true);
+     * ```
+     *
+     * @category I/O
+     */
+    setBracketedPaste(enabled: boolean): void;
   };
   /** A reference to `stderr` which can be used to write directly to `stderr`.
    * It implements the Deno specific
@@ -2856,6 +3123,17 @@ declare namespace Deno {
      *
      * @default {false} */
     recursive?: boolean;
+    /** If set to `true`, path is moved to the platform trash (the
+     * freedesktop.org Trash on Linux, `~/.Trash` on macOS, the Recycle Bin
+     * on Windows) instead of being deleted. Directories are always moved
+     * together with their contents, regardless of `recursive`.
+     *
+     * Since the trash is outside of the removed path and is found through
+     * the `HOME` and `XDG_DATA_HOME` environment variables, this requires
+     * write access to everything and env access to those variables.
+     *
+     * @default {false} */
+    trash?: boolean;
   }
 
   /** Removes the named file or directory.
@@ -3014,6 +3292,38 @@ declare namespace Deno {
    */
   export function readFileSync(path: string | URL): Uint8Array<ArrayBuffer>;
 
+  /** Options for {@linkcode Deno.fileFromPath}.
+   *
+   * @category File System
+   */
+  export interface FileFromPathOptions {
+    /** The MIME type of the file. Defaults to an empty string. */
+    type?: string;
+  }
+
+  /** Creates a `File` of a file on disk, whose contents are read from disk
+   * when they are needed instead of being loaded into memory. Returning it
+   * from a `Deno.serve()` handler sends the file straight from disk.
+   *
+   * The `name` is the last segment of the path and `lastModified` is its
+   * modification time. Like in browsers, the file is expected to not change
+   * while the `File` is in use.
+   *
+   * ```ts
+   * const file = await Deno.fileFromPath("./video.mp4", { type: "video/mp4" });
+   * Deno.serve(() => new Response(file));
+   * ```
+   *
+   * Requires `allow-read` permission.
+   *
+   * @tags allow-read
+   * @category File System
+   */
+  export function fileFromPath(
+    path: string | URL,
+    options?: FileFromPathOptions,
+  ): Promise<File>;
+
   /** Provides information about a file and is returned by
    * {@linkcode Deno.stat}, {@linkcode Deno.lstat}, {@linkcode Deno.statSync},
    * and {@linkcode Deno.lstatSync} or from calling `stat()` and `statSync()`
@@ -3160,6 +3470,17 @@ declare namespace Deno {
     isSymlink: boolean;
   }
 
+  /**
+   * Like {@linkcode Deno.DirEntry}, but with the file name as the OS-native
+   * bytes. Returned when the directory is given as a `Uint8Array`.
+   *
+   * @category File System */
+  export interface RawDirEntry extends Omit<DirEntry, "name"> {
+    /** The file name of the entry as OS-native bytes, which need not be
+     * valid UTF-8. */
+    name: Uint8Array;
+  }
+
   /** Reads the directory given by `path` and returns an async iterable of
    * {@linkcode Deno.DirEntry}. The order of entries is not guaranteed.
    *
@@ -3177,6 +3498,23 @@ declare namespace Deno {
    * @category File System
    */
   export function readDir(path: string | URL): AsyncIterable<DirEntry>;
+  /** Reads the directory given as OS-native bytes and returns an async
+   * iterable of {@linkcode Deno.RawDirEntry}, preserving file names that are
+   * not valid UTF-8.
+   *
+   * ```ts
+   * const dir = new TextEncoder().encode("/");
+   * for await (const dirEntry of Deno.readDir(dir)) {
+   *   console.log(dirEntry.name); // Uint8Array
+   * }
+   * ```
+   *
+   * Requires `allow-read` permission.
+   *
+   * @tags allow-read
+   * @category File System
+   */
+  export function readDir(path: Uint8Array): AsyncIterable<RawDirEntry>;
 
   /** Synchronously reads the directory given by `path` and returns an iterable
    * of {@linkcode Deno.DirEntry}. The order of entries is not guaranteed.
@@ -3195,6 +3533,18 @@ declare namespace Deno {
    * @category File System
    */
   export function readDirSync(path: string | URL): IteratorObject<DirEntry>;
+  /** Synchronously reads the directory given as OS-native bytes and returns
+   * an iterable of {@linkcode Deno.RawDirEntry}, preserving file names that
+   * are not valid UTF-8.
+   *
+   * Requires `allow-read` permission.
+   *
+   * @tags allow-read
+   * @category File System
+   */
+  export function readDirSync(
+    path: Uint8Array,
+  ): IteratorObject<RawDirEntry>;
 
   /** Copies the contents and permissions of one file to another specified path,
    * by default creating a new file if needed, else overwriting. Fails if target
@@ -3284,7 +3634,7 @@ declare namespace Deno {
    * @tags allow-read
    * @category File System
    */
-  export function lstat(path: string | URL): Promise<FileInfo>;
+  export function lstat(path: string | URL | Uint8Array): Promise<FileInfo>;
 
   /** Synchronously returns a {@linkcode Deno.FileInfo} for the specified
    * `path`. If `path` is a symlink, information for the symlink will be
@@ -3301,7 +3651,7 @@ declare namespace Deno {
    * @tags allow-read
    * @category File System
    */
-  export function lstatSync(path: string | URL): FileInfo;
+  export function lstatSync(path: string | URL | Uint8Array): FileInfo;
 
   /** Resolves to a {@linkcode Deno.FileInfo} for the specified `path`. Will
    * always follow symlinks.
@@ -3317,7 +3667,7 @@ declare namespace Deno {
    * @tags allow-read
    * @category File System
    */
-  export function stat(path: string | URL): Promise<FileInfo>;
+  export function stat(path: string | URL | Uint8Array): Promise<FileInfo>;
 
   /** Synchronously returns a {@linkcode Deno.FileInfo} for the specified
    * `path`. Will always follow symlinks.
@@ -3333,7 +3683,7 @@ declare namespace Deno {
    * @tags allow-read
    * @category File System
    */
-  export function statSync(path: string | URL): FileInfo;
+  export function statSync(path: string | URL | Uint8Array): FileInfo;
 
   /** Options for writing to a file.
    *
@@ -3474,7 +3824,10 @@ declare namespace Deno {
    * @tags allow-write
    * @category File System
    */
-  export function truncate(name: string, len?: number): Promise<void>;
+  export function truncate(
+    name: string,
+    len?: number | bigint,
+  ): Promise<void>;
 
   /** Synchronously truncates (or extends) the specified file, to reach the
    * specified `len`. If `len` is not specified then the entire file contents
@@ -3501,7 +3854,7 @@ declare namespace Deno {
    * @tags allow-write
    * @category File System
    */
-  export function truncateSync(name: string, len?: number): void;
+  export function truncateSync(name: string, len?: number | bigint): void;
 
   /**
    * Additional information for FsEvent objects with the "other" kind.
@@ -3603,6 +3956,9 @@ declare namespace Deno {
   /** Operating signals which can be listened for or sent to sub-processes. What
    * signals and what their standard behaviors are OS dependent.
    *
+   * On Linux, real-time signals are named relative to `SIGRTMIN` and
+   * `SIGRTMAX`, like `"SIGRTMIN+3"` or `"SIGRTMAX-1"`.
+   *
    * @category Runtime */
   export type Signal =
     | "SIGABRT"
@@ -3640,7 +3996,32 @@ declare namespace Deno {
     | "SIGVTALRM"
     | "SIGWINCH"
     | "SIGXCPU"
-    | "SIGXFSZ";
+    | "SIGXFSZ"
+    | "SIGRTMIN"
+    | "SIGRTMAX"
+    | `SIGRTMIN+${number}`
+    | `SIGRTMAX-${number}`;
+
+  /** Details of a signal passed to the listeners registered with
+   * {@linkcode Deno.addSignalListener}. Fields the platform doesn't provide
+   * are `null`; the sender and value are only available on Linux.
+   *
+   * @category Runtime */
+  export interface SignalInfo {
+    /** The signal that was delivered. */
+    signal: Signal;
+    /** The process that sent the signal, if it was sent by a process. */
+    pid: number | null;
+    /** The real user ID of the process that sent the signal. */
+    uid: number | null;
+    /** The `si_code` of the signal, which tells how it was sent, e.g. `0`
+     * for `kill` and `-1` for `sigqueue`. Always `null` on Windows. */
+    code: number | null;
+    /** The value queued with the signal, see {@linkcode Deno.kill}. Signals
+     * aren't queued up for the listeners: when a signal is sent again before
+     * the listeners ran, only the value sent last is delivered. */
+    value: number | null;
+  }
 
   /** Registers the given function as a listener of the given signal event.
    *
@@ -3653,12 +4034,19 @@ declare namespace Deno {
    * );
    * ```
    *
+   * The handler is passed the {@linkcode Deno.SignalInfo} of the signal.
+   * Signals delivered while the previous one is still being handled may be
+   * coalesced into one call.
+   *
    * _Note_: On Windows only `"SIGINT"` (CTRL+C) and `"SIGBREAK"` (CTRL+Break)
    * are supported.
    *
    * @category Runtime
    */
-  export function addSignalListener(signal: Signal, handler: () => void): void;
+  export function addSignalListener(
+    signal: Signal,
+    handler: (info: SignalInfo) => void,
+  ): void;
 
   /** Removes the given signal listener that has been registered with
    * {@linkcode Deno.addSignalListener}.
@@ -3678,7 +4066,7 @@ declare namespace Deno {
    */
   export function removeSignalListener(
     signal: Signal,
-    handler: () => void,
+    handler: (info: SignalInfo) => void,
   ): void;
 
   /** Create a child process.
@@ -3786,6 +4174,9 @@ declare namespace Deno {
     get stdin(): WritableStream<Uint8Array<ArrayBufferLike>>;
     get stdout(): SubprocessReadableStream;
     get stderr(): SubprocessReadableStream;
+    /** The pseudo-terminal the child is attached to, when spawned with the
+     * `pty` option. */
+    get pty(): ChildPty;
     readonly pid: number;
     /** Get the status of the child. */
     readonly status: Promise<CommandStatus>;
@@ -3811,6 +4202,23 @@ declare namespace Deno {
     [Symbol.asyncDispose](): Promise<void>;
   }
 
+  /**
+   * The pseudo-terminal of a child process spawned with the `pty` option of
+   * {@linkcode Deno.CommandOptions}.
+   *
+   * @category Subprocess
+   */
+  export interface ChildPty {
+    /** The output of the child. The stream ends once the child closed the
+     * terminal, usually when it exits. */
+    readonly readable: SubprocessReadableStream;
+    /** The input of the child, as typed into the terminal. */
+    readonly writable: WritableStream<Uint8Array<ArrayBufferLike>>;
+    /** Changes the size of the terminal, which sends `SIGWINCH` to the
+     * child. */
+    resize(rows: number, cols: number): void;
+  }
+
   /**
    * The interface for stdout and stderr streams for child process returned from
    * {@linkcode Deno.Command.spawn}.
@@ -3873,6 +4281,25 @@ declare namespace Deno {
     uid?: number;
     /** Similar to `uid`, but sets the group ID of the child process. */
     gid?: number;
+    /** Sets the supplementary group IDs of the child process, instead of
+     * clearing them when `uid` drops root privileges.
+     *
+     * Requires `allow-run` permission for all programs. Not supported on
+     * Windows. */
+    groups?: number[];
+    /** Changes the root directory of the child process before it runs, which
+     * usually requires root privileges. The program and `cwd` are looked up
+     * within the new root.
+     *
+     * Requires `allow-run` permission for all programs. Not supported on
+     * Windows. */
+    chroot?: string | URL;
+    /** The path of a cgroup, such as `/sys/fs/cgroup/my-service`, to place
+     * the child process in before it runs.
+     *
+     * Requires `allow-run` permission for all programs. Only supported on
+     * Linux. */
+    cgroup?: string | URL;
     /**
      * An {@linkcode AbortSignal} that allows closing the process using the
      * corresponding {@linkcode AbortController} by sending the process a
@@ -3917,6 +4344,18 @@ declare namespace Deno {
      * @default {false}
      */
     detached?: boolean;
+
+    /** Allocate a pseudo-terminal with the given size and attach the spawned
+     * process to it, as its stdin, stdout, stderr and controlling terminal.
+     * This lets interactive programs, which check whether they run in a
+     * terminal, be driven through {@linkcode Deno.ChildProcess.pty}.
+     *
+     * Can't be combined with `stdin`, `stdout` or `stderr`, and is only
+     * supported with {@linkcode Deno.Command.spawn}.
+     *
+     * Not supported on Windows.
+     */
+    pty?: { rows: number; cols: number };
   }
 
   /**
@@ -4161,7 +4600,8 @@ declare namespace Deno {
       | "homedir"
       | "statfs"
       | "getPriority"
-      | "setPriority";
+      | "setPriority"
+      | "icmpPing";
   }
 
   /** The permission descriptor for the `allow-ffi` and `deny-ffi` permissions, which controls
@@ -4261,6 +4701,18 @@ declare namespace Deno {
     ): void;
   }
 
+  /** Options for {@linkcode Permissions.request}.
+   *
+   * @category Permissions */
+  export interface PermissionRequestOptions {
+    /** Milliseconds after which the permission, when granted at the prompt,
+     * is revoked again. Workers created while the grant is active inherit it
+     * with the same expiry.
+     *
+     * This has no effect when the permission is granted already. */
+    expiresIn?: number;
+  }
+
   /**
    * Deno's permission management API.
    *
@@ -4312,6 +4764,8 @@ declare namespace Deno {
     querySync(desc: PermissionDescriptor): PermissionStatus;
 
     /** Revokes a permission, and resolves to the state of the permission.
+     *
+     * Note, workers that were already created keep the permission.
      *
      * ```ts
      * import { assert } from "jsr:@std/assert";
@@ -4323,6 +4777,8 @@ declare namespace Deno {
     revoke(desc: PermissionDescriptor): Promise<PermissionStatus>;
 
     /** Revokes a permission, and returns the state of the permission.
+     *
+     * Note, workers that were already created keep the permission.
      *
      * ```ts
      * import { assert } from "jsr:@std/assert";
@@ -4346,8 +4802,17 @@ declare namespace Deno {
      *   console.log("'env' permission is denied.");
      * }
      * ```
+     *
+     * A grant can be limited to a setup phase with `expiresIn`:
+     *
+     * ```ts
+     * await Deno.permissions.request({ name: "net" }, { expiresIn: 5_000 });
+     * ```
      */
-    request(desc: PermissionDescriptor): Promise<PermissionStatus>;
+    request(
+      desc: PermissionDescriptor,
+      options?: PermissionRequestOptions,
+    ): Promise<PermissionStatus>;
 
     /** Requests the permission, and returns the state of the permission.
      *
@@ -4363,7 +4828,10 @@ declare namespace Deno {
      * }
      * ```
      */
-    requestSync(desc: PermissionDescriptor): PermissionStatus;
+    requestSync(
+      desc: PermissionDescriptor,
+      options?: PermissionRequestOptions,
+    ): PermissionStatus;
   }
 
   /** Deno's permission management API.
@@ -4471,6 +4939,18 @@ declare namespace Deno {
       | "solaris"
       | "illumos";
     standalone: boolean;
+    /** The optional parts of the runtime that this build of Deno includes,
+     * such as `"pty"` or `"seccomp"`, which are only built for some
+     * platforms. Use it to feature-detect, instead of calling an API to see
+     * if it throws.
+     *
+     * ```ts
+     * if (Deno.build.features.includes("pty")) {
+     *   new Deno.Command("bash", { pty: { rows: 24, cols: 80 } }).spawn();
+     * }
+     * ```
+     */
+    features: readonly string[];
     /** The computer vendor that the Deno CLI was built for. */
     vendor: string;
     /** Optional environment flags that were set for this build of Deno CLI. */
@@ -4738,12 +5218,21 @@ declare namespace Deno {
    *
    * As a special case, a signal of 0 can be used to test for the existence of a process.
    *
+   * On Linux, an integer `value` can be queued with the signal with
+   * `sigqueue`, which is passed to the listeners of the receiving process as
+   * {@linkcode Deno.SignalInfo.value}. Values sent in quick succession may be
+   * lost, as only the latest delivery of each signal is passed on.
+   *
    * Requires `allow-run` permission.
    *
    * @tags allow-run
    * @category Subprocess
    */
-  export function kill(pid: number, signo?: Signal | number): void;
+  export function kill(
+    pid: number,
+    signo?: Signal | number,
+    options?: { value?: number },
+  ): void;
 
   /** The type of the resource record to resolve via DNS using
    * {@linkcode Deno.resolveDns}.
@@ -5266,6 +5755,17 @@ declare namespace Deno {
      *
      * @default {511} */
     tcpBacklog?: number;
+
+    /** Enable TCP Fast Open. See {@linkcode Deno.TcpListenOptions.tcpFastOpen}.
+     *
+     * @default {false} */
+    tcpFastOpen?: boolean | number;
+
+    /** Only accept connections once the client sent data. See
+     * {@linkcode Deno.TcpListenOptions.deferAccept}.
+     *
+     * @default {false} */
+    deferAccept?: boolean | number;
   }
 
   /**
@@ -6105,6 +6605,28 @@ declare namespace Deno {
     parameters: Parameters;
     /** The current result of the callback. */
     result: Result;
+    /** When true or given queue options, calls from foreign threads are
+     * queued to run on the event loop and return to the caller immediately,
+     * instead of blocking the calling thread until the callback has run.
+     * Nonblocking callbacks must return `"void"`.
+     *
+     * The arguments are copied when queued, but the memory that pointer
+     * arguments point to is not, so it must outlive the call.
+     */
+    nonblocking?: boolean | UnsafeCallbackQueueOptions;
+  }
+
+  /** The queue of calls from foreign threads of a nonblocking
+   * {@linkcode UnsafeCallback}.
+   *
+   * @category FFI
+   */
+  export interface UnsafeCallbackQueueOptions {
+    /** The number of calls that may be queued. Defaults to 1024. */
+    queueSize?: number;
+    /** Which call to drop when the queue is full: the oldest queued call or
+     * the new one. Defaults to `"oldest"`. */
+    drop?: "oldest" | "newest";
   }
 
   /** An unsafe callback function.
@@ -6300,9 +6822,42 @@ declare namespace Deno {
     /** Close the HTTP client. */
     close(): void;
 
+    /** The connections the client currently keeps open, by origin. Origins
+     * without open connections or requests in flight are left out.
+     *
+     * ```ts
+     * using client = Deno.createHttpClient({});
+     * await (await fetch("https://deno.land", { client })).body?.cancel();
+     * console.log(client.poolStats());
+     * // [{ origin: "https://deno.land", connections: 1, idleConnections: 1,
+     * //    activeRequests: 0 }]
+     * ```
+     */
+    poolStats(): HttpClientPoolStats[];
+
     [Symbol.dispose](): void;
   }
 
+  /**
+   * The connections of a {@linkcode Deno.HttpClient} to one origin, as
+   * returned by {@linkcode Deno.HttpClient.poolStats}.
+   *
+   * @category Fetch
+   */
+  export interface HttpClientPoolStats {
+    /** The origin, like `"https://deno.land"`. */
+    origin: string;
+    /** The number of connections open to the origin. */
+    connections: number;
+    /** The number of open connections without a request in flight. HTTP/2
+     * connections can carry several requests at once, so this is a lower
+     * bound for them. */
+    idleConnections: number;
+    /** The number of requests whose response body has not been consumed or
+     * cancelled yet. */
+    activeRequests: number;
+  }
+
   /**
    * The options used when creating a {@linkcode Deno.HttpClient}.
    *
@@ -6316,6 +6871,19 @@ declare namespace Deno {
     caCerts?: string[];
     /** An alternative transport (a proxy) to use for new connections. */
     proxy?: Proxy;
+    /** Send all requests to this Unix domain socket, whatever the host of
+     * the URL. A shorthand for a `proxy` with the `"unix"` transport, so it
+     * cannot be combined with `proxy`.
+     *
+     * ```ts
+     * const client = Deno.createHttpClient({
+     *   unixSocketPath: "/var/run/docker.sock",
+     * });
+     * const res = await fetch("http://localhost/containers/json", { client });
+     * ```
+     *
+     * *Not supported on Windows.* */
+    unixSocketPath?: string;
     /** Sets the maximum number of idle connections per host allowed in the pool. */
     poolMaxIdlePerHost?: number;
     /** Set an optional timeout for idle sockets being kept-alive.
@@ -6332,6 +6900,25 @@ declare namespace Deno {
      * @default {true}
      */
     http2?: boolean;
+    /** Use HTTP/2 on cleartext `http:` connections without negotiating it
+     * first (h2c with prior knowledge), as gRPC servers without TLS expect.
+     * `https:` connections still negotiate the protocol. Connections through
+     * a proxy are not affected.
+     *
+     * @default {false}
+     */
+    http2PriorKnowledge?: boolean;
+    /** Send HTTP/2 `PING` frames at this interval, in milliseconds, to
+     * detect connections that died without being closed, including idle
+     * connections in the pool. */
+    http2KeepAliveInterval?: number;
+    /** How long to wait for the acknowledgement of an HTTP/2 `PING`, in
+     * milliseconds, before the connection is closed. Only used together
+     * with `http2KeepAliveInterval`.
+     *
+     * @default {20000}
+     */
+    http2KeepAliveTimeout?: number;
     /** Whether setting the host header is allowed or not.
      *
      * @default {false}
@@ -6339,6 +6926,24 @@ declare namespace Deno {
     allowHost?: boolean;
     /** Sets the local address where the socket will connect from. */
     localAddress?: string;
+    /** The DNS server to query for the addresses of request hosts, instead
+     * of the system resolver. Answers are cached for as long as their TTL
+     * allows.
+     *
+     * Requires `allow-net` permission for the name server. */
+    nameServer?: {
+      /** The IP address of the name server. */
+      ipAddr: string;
+      /** The port number the query will be sent to.
+       *
+       * @default {53} */
+      port?: number;
+    };
+    /** Compress the bodies of requests sent with this client using the
+     * given encoding, and set their `Content-Encoding` header. Requests
+     * whose `Content-Encoding` header is already set are sent unchanged.
+     * The server must be able to decode the body. */
+    compressRequestBody?: "gzip" | "br" | "zstd";
   }
 
   /**
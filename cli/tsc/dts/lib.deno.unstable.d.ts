@@ -240,6 +240,167 @@ declare namespace Deno {
     setLoopback: (loopback: boolean) => Promise<void>;
   }
 
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Credentials of the process on the other end of a unix socket.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface UnixCredentials {
+    uid: number;
+    gid: number;
+    /** `null` on platforms which do not report the peer's process id. */
+    pid: number | null;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * A resource whose underlying descriptor can be sent over a unix socket.
+   *
+   * @category Network
+   * @experimental
+   */
+  export type TransferableHandle =
+    | FsFile
+    | TcpConn
+    | UnixConn
+    | TcpListener
+    | UnixListener;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ReadWithHandlesResult {
+    /** The number of bytes read, or `null` at the end of the stream. */
+    nread: number | null;
+    /** Handles which arrived together with the data. */
+    handles: TransferableHandle[];
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface UnixConn {
+    /** Returns the credentials of the peer process, as of the time the
+     * connection was established. */
+    peerCredentials(): UnixCredentials;
+    /** Writes `p` with the descriptors of `handles` attached (`SCM_RIGHTS`).
+     * The peer receives its own copies, the handles stay open here. `p` must
+     * not be empty when handles are sent. */
+    writeWithHandles(
+      p: Uint8Array,
+      handles: TransferableHandle[],
+    ): Promise<number>;
+    /** Reads into `p` and returns any handles which were sent along with the
+     * data. Received sockets other than TCP or unix stream sockets are
+     * closed. Rejects if more handles were sent than fit in one message,
+     * in which case the handles of the message are closed.
+     *
+     * On platforms other than Linux and Android the received descriptors are
+     * only marked close-on-exec after they arrived, so a subprocess spawned
+     * at the same time by another worker can inherit them. */
+    readWithHandles(p: Uint8Array): Promise<ReadWithHandlesResult>;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * A proxy to tunnel a TCP connection through. `http://` proxies are sent a
+   * `CONNECT` request, `socks5://` proxies are given the resolved address of
+   * the destination and `socks5h://` proxies resolve the host name
+   * themselves.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ConnectProxy {
+    /** The URL of the proxy server. Credentials in the URL are used unless
+     * `basicAuth` is given. */
+    url: string;
+    /** The credentials to authenticate with against the proxy server. */
+    basicAuth?: BasicAuth;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options to resolve the host name of a connection with something other
+   * than the system resolver. Only one of them is used, `lookup` taking
+   * precedence.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ConnectDnsOptions {
+    /** The DNS server to query for the addresses of the host name. Answers
+     * are cached for as long as their TTL allows.
+     *
+     * Requires `allow-net` permission for the name server. */
+    nameServer?: {
+      /** The IP address of the name server. */
+      ipAddr: string;
+      /** The port number the query will be sent to.
+       *
+       * @default {53} */
+      port?: number;
+    };
+    /** Looks up the IP addresses of `hostname` instead of querying DNS. The
+     * addresses are tried like the ones of a DNS answer.
+     *
+     * Requires `allow-net` permission for the returned addresses as well as
+     * the host name.
+     *
+     * ```ts
+     * const conn = await Deno.connect({
+     *   hostname: "api.internal",
+     *   port: 80,
+     *   lookup: () => ["10.0.0.12"],
+     * });
+     * ```
+     */
+    lookup?: (hostname: string) => string[] | Promise<string[]>;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ConnectOptions extends ConnectDnsOptions {
+    /** Connect through a proxy instead of directly. The `remoteAddr` of the
+     * connection is the address of the proxy.
+     *
+     * Requires `allow-net` permission for the proxy as well as the
+     * destination.
+     *
+     * ```ts
+     * const conn = await Deno.connect({
+     *   hostname: "example.com",
+     *   port: 80,
+     *   proxy: { url: "socks5h://127.0.0.1:9050" },
+     * });
+     * ```
+     */
+    proxy?: ConnectProxy;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ConnectTlsOptions extends ConnectDnsOptions {
+    /** Connect through a proxy instead of directly. The TLS session is
+     * negotiated with the destination through the tunnel.
+     *
+     * Requires `allow-net` permission for the proxy as well as the
+     * destination. */
+    proxy?: ConnectProxy;
+  }
+
   /** **UNSTABLE**: New API, yet to be vetted.
    *
    * A generic transport listener for message-oriented protocols.
@@ -249,18 +410,26 @@ declare namespace Deno {
    */
   export interface DatagramConn
     extends AsyncIterable<[Uint8Array<ArrayBuffer>, Addr]> {
-    /** Joins an IPv4 multicast group. */
+    /** Joins an IPv4 multicast group.
+     *
+     * Requires `allow-net` permission for the group address. */
     joinMulticastV4(
       address: string,
       networkInterface: string,
     ): Promise<MulticastV4Membership>;
 
-    /** Joins an IPv6 multicast group. */
+    /** Joins an IPv6 multicast group.
+     *
+     * Requires `allow-net` permission for the group address. */
     joinMulticastV6(
       address: string,
       networkInterface: number,
     ): Promise<MulticastV6Membership>;
 
+    /** Enables or disables sending to broadcast addresses. Sockets created
+     * by `Deno.listenDatagram()` start with broadcast enabled. */
+    setBroadcast(broadcast: boolean): Promise<void>;
+
     /** Waits for and resolves to the next message to the instance.
      *
      * Messages are received in the format of a tuple containing the data array
@@ -273,6 +442,11 @@ declare namespace Deno {
     /** Close closes the socket. Any pending message promises will be rejected
      * with errors. */
     close(): void;
+    /** Set the buffer sizes of a UDP socket. Other options throw a
+     * `NotSupported` error. */
+    setOption(options: Pick<SocketOptions, "recvBuffer" | "sendBuffer">): void;
+    /** Read back the buffer sizes of a UDP socket. */
+    getOption(): Pick<SocketOptions, "recvBuffer" | "sendBuffer">;
     /** Return the address of the instance. */
     readonly addr: Addr;
     [Symbol.asyncIterator](): AsyncIterableIterator<
@@ -387,6 +561,658 @@ declare namespace Deno {
    */
   export function openKv(path?: string): Promise<Kv>;
 
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Responds to `req` with the file at `filePath`.
+   *
+   * `ETag`, `Last-Modified`, `Content-Type` and `Content-Length` headers are
+   * set from the file, conditional requests are answered with
+   * `304 Not Modified`, and a single `Range` is answered with
+   * `206 Partial Content`. The body is streamed to the connection without
+   * passing through JavaScript when the response is returned from a
+   * {@linkcode Deno.serve} handler.
+   *
+   * Missing files and directories result in a `404 Not Found` response.
+   *
+   * ```ts
+   * Deno.serve((req) => Deno.serveFile(req, "./static/index.html"));
+   * ```
+   *
+   * @tags allow-read
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serveFile(req: Request, filePath: string): Promise<Response>;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for {@linkcode Deno.serveDir}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeDirOptions {
+    /** The directory files are served from.
+     *
+     * @default {"."} */
+    fsRoot?: string;
+    /** The URL path files are served under. Requests outside of it get a
+     * `404 Not Found` response.
+     *
+     * @default {""} */
+    urlRoot?: string;
+    /** Serve `index.html` for requests to a directory.
+     *
+     * @default {true} */
+    showIndex?: boolean;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Responds to `req` with the file its URL path maps to below
+   * `options.fsRoot`, using {@linkcode Deno.serveFile}. Paths cannot escape
+   * the root, and methods other than `GET` and `HEAD` get a
+   * `405 Method Not Allowed` response.
+   *
+   * ```ts
+   * Deno.serve((req) => Deno.serveDir(req, { fsRoot: "./public" }));
+   * ```
+   *
+   * @tags allow-read
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serveDir(
+    req: Request,
+    options?: ServeDirOptions,
+  ): Promise<Response>;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * An event sent with {@linkcode ServerSentEvents.send}. Multi-line `data`
+   * and `comment` values are split into one field per line.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServerSentEvent {
+    /** The payload of the event. */
+    data?: string;
+    /** The event type. Clients dispatch events without one as `message`. */
+    event?: string;
+    /** The event ID, sent back by reconnecting clients in the
+     * `Last-Event-ID` header. */
+    id?: string;
+    /** The reconnection delay for the client, in milliseconds. */
+    retry?: number;
+    /** A comment, which clients ignore. */
+    comment?: string;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for {@linkcode Deno.serverSentEvents}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServerSentEventsOptions {
+    /** How often, in milliseconds, a `:keep-alive` comment is sent so that
+     * idle connections are not closed by proxies. `false` disables it.
+     *
+     * @default {15000} */
+    keepAliveInterval?: number | false;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * An event stream created with {@linkcode Deno.serverSentEvents}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServerSentEvents {
+    /** The `text/event-stream` response to return from the handler. */
+    readonly response: Response;
+    /** The `Last-Event-ID` header of the request, if the client is
+     * reconnecting. */
+    readonly lastEventId: string | null;
+    /** Resolves when the stream was closed, by either side. */
+    readonly closed: Promise<void>;
+    readonly isClosed: boolean;
+    /** Sends an event. Each event is written to the connection as soon as it
+     * is sent. Throws if the stream is closed. */
+    send(event: ServerSentEvent): void;
+    /** Ends the response. */
+    close(): void;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Creates a Server-Sent Events stream in response to `req`. The stream is
+   * closed when the client disconnects.
+   *
+   * ```ts
+   * Deno.serve((req) => {
+   *   const events = Deno.serverSentEvents(req);
+   *   let n = Number(events.lastEventId ?? 0);
+   *   const timer = setInterval(() => {
+   *     n++;
+   *     events.send({ id: String(n), data: `tick ${n}` });
+   *   }, 1000);
+   *   events.closed.then(() => clearInterval(timer));
+   *   return events.response;
+   * });
+   * ```
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serverSentEvents(
+    req: Request,
+    options?: ServerSentEventsOptions,
+  ): ServerSentEvents;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for serving HTTP on a listener which already exists, such as one
+   * returned by {@linkcode Deno.listenSystemd}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeListenerOptions<
+    Addr extends Deno.NetAddr | Deno.UnixAddr = Deno.NetAddr | Deno.UnixAddr,
+  > extends ServeOptions<Addr> {
+    /** The listener to accept connections on. It is closed when the server
+     * shuts down. */
+    listener: Listener<Conn<Addr>, Addr>;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Serves HTTP requests on an existing listener.
+   *
+   * ```ts
+   * const [listener] = Deno.listenSystemd() as Deno.TcpListener[];
+   * Deno.serve({ listener }, (_req) => new Response("Hello, world"));
+   * ```
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serve<Addr extends Deno.NetAddr | Deno.UnixAddr>(
+    options: ServeListenerOptions<Addr>,
+    handler: ServeHandler<Addr>,
+  ): HttpServer<Addr>;
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Serves HTTP requests on an existing listener.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serve<Addr extends Deno.NetAddr | Deno.UnixAddr>(
+    options: ServeListenerOptions<Addr> & ServeInit<Addr>,
+  ): HttpServer<Addr>;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Picks the certificate of a TLS server by the server name the client asks
+   * for (SNI), so one listener can serve many domains.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface TlsCertificateResolver {
+    /** Called with the server name of a handshake, or `""` if the client did
+     * not send one. The result is reused for later handshakes with the same
+     * server name until the certificate cache is cleared. A rejected promise
+     * fails the handshake. */
+    resolveCertificate(
+      serverName: string,
+    ): TlsCertifiedKeyPem | Promise<TlsCertifiedKeyPem>;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * A TLS listener whose certificates are picked by
+   * {@linkcode TlsCertificateResolver.resolveCertificate}.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ResolvingTlsListener extends Listener<TlsConn, NetAddr> {
+    /** Forgets the resolved certificates, so new handshakes ask
+     * `resolveCertificate` again. Use this to rotate certificates without
+     * restarting the listener. Open connections are not affected. */
+    clearCertificateCache(): void;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Listen for TLS connections and pick the certificate of each one by its
+   * server name.
+   *
+   * ```ts
+   * const certs = {
+   *   "a.example.com": { cert: "...", key: "..." },
+   *   "b.example.com": { cert: "...", key: "..." },
+   * };
+   * const listener = Deno.listenTls({
+   *   port: 443,
+   *   resolveCertificate: (serverName) => certs[serverName],
+   * });
+   * ```
+   *
+   * Requires `allow-net` permission.
+   *
+   * @tags allow-net
+   * @category Network
+   * @experimental
+   */
+  export function listenTls(
+    options: ListenTlsOptions & TlsCertificateResolver,
+  ): ResolvingTlsListener;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface HttpServer<Addr extends Deno.Addr = Deno.Addr> {
+    /** Forgets the certificates resolved by `resolveCertificate`, so new
+     * handshakes ask for them again. Does nothing for servers with a fixed
+     * certificate or without TLS. */
+    clearCertificateCache(): void;
+
+    /** A snapshot of the server's metrics, or `null` if it was started
+     * without the `metrics` option or has been shut down. */
+    metrics(): ServeMetricsSnapshot | null;
+
+    /** Gracefully close the server, giving pending requests at most
+     * `gracePeriodMs` to finish. No more new connections are accepted, idle
+     * connections are closed and HTTP/2 clients are sent `GOAWAY`. Whatever
+     * is still open once the grace period is over is aborted.
+     *
+     * ```ts
+     * const server = Deno.serve((_req) => new Response("Hello, world"));
+     * Deno.addSignalListener("SIGTERM", async () => {
+     *   const { droppedConnections } = await server.shutdown({
+     *     gracePeriodMs: 10_000,
+     *   });
+     *   console.log(`Aborted ${droppedConnections} connections`);
+     * });
+     * ```
+     */
+    shutdown(options: ServeShutdownOptions): Promise<ServeShutdownResult>;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Limits enforced on the connections and requests of a server, to protect
+   * it from misbehaving clients without a proxy in front of it.
+   *
+   * ```ts
+   * Deno.serve({
+   *   limits: {
+   *     maxHeaderSize: 16 * 1024,
+   *     maxBodySize: 1024 * 1024,
+   *     headerReadTimeoutMs: 10_000,
+   *     bodyReadTimeoutMs: 30_000,
+   *     http2MaxConcurrentStreams: 100,
+   *   },
+   * }, (_req) => new Response("Hello, world"));
+   * ```
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeLimits {
+    /** The most bytes the head of an HTTP/1 request, or the header list of
+     * an HTTP/2 request, may take. Larger requests are answered with
+     * `431 Request Header Fields Too Large`. Must be at least 8192, smaller
+     * values throw a `RangeError`. */
+    maxHeaderSize?: number;
+    /** The most bytes a request body may take. Requests that declare a
+     * larger `Content-Length` are answered with `413 Content Too Large`
+     * without calling the handler. Reading a longer body of unknown length
+     * fails once the limit is reached. */
+    maxBodySize?: number;
+    /** How long a client may take to send the head of an HTTP/1 request
+     * before its connection is closed. The connection is closed without
+     * sending a `408 Request Timeout` response. */
+    headerReadTimeoutMs?: number;
+    /** How long a read of the request body may wait for more data from the
+     * client. Reads that wait longer fail, and the handler decides what to
+     * respond, e.g. with `408 Request Timeout`. */
+    bodyReadTimeoutMs?: number;
+    /** The most streams an HTTP/2 client may have open at once. */
+    http2MaxConcurrentStreams?: number;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeOptions<Addr extends Deno.Addr = Deno.Addr> {
+    /** Limits enforced on the connections and requests of the server. */
+    limits?: ServeLimits;
+    /** Collect metrics about the server, readable with
+     * {@linkcode HttpServer.metrics}. With a `path`, `GET` requests for it
+     * are answered with the metrics in the Prometheus text exposition format
+     * instead of being passed to the handler.
+     *
+     * ```ts
+     * Deno.serve({
+     *   metrics: { path: "/metrics" },
+     * }, (_req) => new Response("Hello, world"));
+     * ```
+     *
+     * @default {false}
+     */
+    metrics?: boolean | { path?: string };
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * The metrics of a server since it started, as returned by
+   * {@linkcode HttpServer.metrics}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeMetricsSnapshot {
+    /** The number of responses sent. */
+    requests: number;
+    /** The number of responses sent, by the first digit of their status. */
+    responses: Record<"1xx" | "2xx" | "3xx" | "4xx" | "5xx", number>;
+    /** The number of connections currently open. */
+    activeConnections: number;
+    /** The number of request body bytes read by handlers. */
+    requestBodyBytes: number;
+    /** The number of response body bytes sent. */
+    responseBodyBytes: number;
+    /** A histogram of the time, in seconds, from receiving a request until
+     * the head of its response was ready. Each bucket counts the requests
+     * that took at most `le` seconds, including the ones of smaller
+     * buckets. */
+    requestDurationBuckets: { le: number; count: number }[];
+    /** The sum of all request durations, in seconds. */
+    requestDurationSum: number;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for {@linkcode HttpServer.shutdown}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeShutdownOptions {
+    /** How long pending requests may take to finish before their
+     * connections are aborted. Without it the server waits for as long as
+     * the requests take. */
+    gracePeriodMs?: number;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * The outcome of {@linkcode HttpServer.shutdown}.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export interface ServeShutdownResult {
+    /** The number of connections which were still open when they were
+     * aborted. */
+    droppedConnections: number;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Serves HTTPS requests and picks the certificate of each connection by
+   * its server name.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serve(
+    options: ServeTcpOptions & TlsCertificateResolver,
+    handler: ServeHandler<Deno.NetAddr>,
+  ): HttpServer<Deno.NetAddr>;
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Serves HTTPS requests and picks the certificate of each connection by
+   * its server name.
+   *
+   * @category HTTP Server
+   * @experimental
+   */
+  export function serve(
+    options:
+      & ServeTcpOptions
+      & TlsCertificateResolver
+      & ServeInit<Deno.NetAddr>,
+  ): HttpServer<Deno.NetAddr>;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for {@linkcode Deno.listenSystemd}.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface ListenSystemdOptions {
+    /** Only adopt the sockets with this name, as set by `FileDescriptorName=`
+     * in the socket unit. Sockets without a name are called `"unknown"`. */
+    name?: string;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Adopts the sockets systemd passed to this process through socket
+   * activation (`LISTEN_FDS`). Listening sockets are returned as listeners,
+   * connected sockets (`Accept=yes`) as connections and other files, such as
+   * FIFOs, as {@linkcode Deno.FsFile}. Each socket is only returned once per
+   * process, and an empty array is returned when the process was not socket
+   * activated. The `LISTEN_PID`, `LISTEN_FDS` and `LISTEN_FDNAMES`
+   * environment variables are removed on the first call, so that
+   * subprocesses don't adopt the same sockets.
+   *
+   * ```ts
+   * const listeners = Deno.listenSystemd({ name: "http" });
+   * const listener = listeners[0] as Deno.TcpListener;
+   * Deno.serve({ listener }, (_req) => new Response("Hello, world"));
+   * ```
+   *
+   * Requires `allow-env` permission for `LISTEN_PID`, `LISTEN_FDS` and
+   * `LISTEN_FDNAMES`, and the permissions `Deno.listen()` would need to bind
+   * to the sockets' addresses.
+   *
+   * @tags allow-env, allow-net, allow-read, allow-write
+   * @category Network
+   * @experimental
+   */
+  export function listenSystemd(
+    options?: ListenSystemdOptions,
+  ): TransferableHandle[];
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Sends a status update to the service manager through `NOTIFY_SOCKET`,
+   * like `sd_notify()`. Returns `false` when the process was not started
+   * with a notify socket.
+   *
+   * ```ts
+   * Deno.serve({
+   *   onListen() {
+   *     Deno.notifySystemd("READY=1");
+   *   },
+   * }, (_req) => new Response("Hello, world"));
+   *
+   * // with `WatchdogSec=` set in the service unit
+   * setInterval(() => Deno.notifySystemd("WATCHDOG=1"), 10_000);
+   * ```
+   *
+   * Requires `allow-env` permission for `NOTIFY_SOCKET`, and `allow-write`
+   * permission for the socket path unless it is an abstract socket.
+   *
+   * @tags allow-env, allow-write
+   * @category Network
+   * @experimental
+   */
+  export function notifySystemd(state: string): boolean;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * The CPU and memory limits of the cgroup the process runs in, as returned
+   * by {@linkcode Deno.cgroupLimits}.
+   *
+   * @category Runtime
+   * @experimental
+   */
+  export interface CgroupLimits {
+    /** The cgroup version, `1` or `2`. */
+    version: number;
+    /** The CPUs the cgroup may use, as its quota divided by its period, e.g.
+     * `1.5`, or `null` without a limit. */
+    cpuQuota: number | null;
+    /** Bytes of memory the cgroup may use, or `null` without a limit. */
+    memoryMax: number | null;
+    /** Bytes of memory the cgroup currently uses. */
+    memoryCurrent: number | null;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Returns the limits of the cgroup the process runs in, such as the limits
+   * of a container, or `null` when it isn't in a cgroup or the platform
+   * doesn't have cgroups. Limits of parent cgroups are taken into account.
+   *
+   * ```ts
+   * const limits = Deno.cgroupLimits();
+   * const cpus = limits?.cpuQuota ?? navigator.hardwareConcurrency;
+   * ```
+   *
+   * Requires `allow-sys` permission for `systemMemoryInfo`.
+   *
+   * @tags allow-sys
+   * @category Runtime
+   * @experimental
+   */
+  export function cgroupLimits(): CgroupLimits | null;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Memory of the process allocated outside of the V8 heap, such as by the
+   * Rust side of the runtime, as returned by
+   * {@linkcode Deno.memoryUsage} with `detailed: true`.
+   *
+   * @category Runtime
+   * @experimental
+   */
+  export interface DetailedMemoryUsage extends MemoryUsage {
+    /** Statistics of the system allocator, or `null` when it doesn't report
+     * any. Only reported on Linux with glibc. */
+    allocator: {
+      /** Bytes in use. */
+      allocated: number;
+      /** Bytes the allocator holds on to but that are not in use. */
+      free: number;
+      /** Bytes in use in regions mapped separately for large allocations. */
+      mapped: number;
+    } | null;
+    /** The number of open resources of each type, such as `fsFile` or
+     * `tcpStream`. */
+    resources: Record<string, number>;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Returns the memory usage of the process like
+   * {@linkcode Deno.memoryUsage}, together with the memory of the system
+   * allocator and the open resources by type. This is slower than
+   * {@linkcode Deno.memoryUsage} without options.
+   *
+   * ```ts
+   * const { allocator, resources } = Deno.memoryUsage({ detailed: true });
+   * console.log(allocator?.allocated, resources.tcpStream ?? 0);
+   * ```
+   *
+   * @category Runtime
+   * @experimental
+   */
+  export function memoryUsage(
+    options: { detailed: true },
+  ): DetailedMemoryUsage;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for {@linkcode Deno.icmpPing}.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface IcmpPingOptions {
+    /** Milliseconds to wait for the reply before rejecting with
+     * {@linkcode Deno.errors.TimedOut}.
+     *
+     * @default {5000} */
+    timeout?: number;
+    /** The TTL, or hop limit for IPv6 addresses, of the echo request. */
+    ttl?: number;
+    /** Bytes of payload carried by the echo request.
+     *
+     * @default {56} */
+    payloadSize?: number;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * The reply to an echo request sent with {@linkcode Deno.icmpPing}.
+   *
+   * @category Network
+   * @experimental
+   */
+  export interface IcmpPingReply {
+    /** The address the echo request was sent to. */
+    address: string;
+    sequence: number;
+    /** Round-trip time in milliseconds. */
+    time: number;
+    /** The TTL of the reply. Only known when `privileged` is `true` or on
+     * macOS. */
+    ttl: number | null;
+    /** Whether the echo request was sent on a raw socket, which requires
+     * `CAP_NET_RAW` or root on Unix and an administrator on Windows. On
+     * Linux, Android and macOS unprivileged ICMP sockets are used otherwise,
+     * which on Linux are subject to the `net.ipv4.ping_group_range` sysctl. */
+    privileged: boolean;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Sends an ICMP echo request to `hostname` and resolves with the reply.
+   *
+   * ```ts
+   * const { time } = await Deno.icmpPing("example.com");
+   * console.log(`${time.toFixed(1)} ms`);
+   * ```
+   *
+   * Requires `allow-sys` permission for `icmpPing`, and `allow-net`
+   * permission for `hostname`.
+   *
+   * @tags allow-net, allow-sys
+   * @category Network
+   * @experimental
+   */
+  export function icmpPing(
+    hostname: string,
+    options?: IcmpPingOptions,
+  ): Promise<IcmpPingReply>;
+
   /** **UNSTABLE**: New API, yet to be vetted.
    *
    * CronScheduleExpression is used as the type of `minute`, `hour`,
@@ -1162,6 +1988,32 @@ declare namespace Deno {
       options?: { raw?: boolean },
     ): ReadableStream<{ [K in keyof T]: KvEntryMaybe<T[K]> }>;
 
+    /**
+     * Copy a point-in-time snapshot of the database to a new file at
+     * `destination`, which can be opened with {@linkcode Deno.openKv} or
+     * passed to {@linkcode Deno.Kv.restore}. The snapshot is consistent even
+     * while the database is being written to.
+     *
+     * Only databases stored in a local file can be backed up. This requires
+     * write permission for `destination`.
+     *
+     * ```ts
+     * const db = await Deno.openKv("./data.sqlite3");
+     * await db.backup("./backup.sqlite3");
+     * ```
+     */
+    backup(destination: string | URL): Promise<void>;
+
+    /**
+     * Replace the contents of the database with the backup in `source`, as
+     * created by {@linkcode Deno.Kv.backup}. Other connections to the
+     * database see either the old or the restored contents.
+     *
+     * Only databases stored in a local file can be restored. This requires
+     * read permission for `source`.
+     */
+    restore(source: string | URL): Promise<void>;
+
     /**
      * Close the database connection. This will prevent any further operations
      * from being performed on the database, and interrupt any in-flight
@@ -1212,7 +2064,34 @@ declare namespace Deno {
     export interface DisplayOptions {
       raw?: boolean;
       update?: boolean;
-      display_id?: string;
+      /** The id of the display, used to update it later. Pass `true` to
+       * generate one. */
+      display_id?: string | true;
+    }
+
+    /**
+     * A display that can be updated in place, returned by
+     * {@linkcode Deno.jupyter.display} when it is given a `display_id`.
+     *
+     * ```ts
+     * const progress = await Deno.jupyter.display(
+     *   Deno.jupyter.md`Starting...`,
+     *   { display_id: true },
+     * );
+     * for (let i = 1; i <= 10; i++) {
+     *   await new Promise((resolve) => setTimeout(resolve, 100));
+     *   await progress.update(Deno.jupyter.md`${i * 10}%`);
+     * }
+     * ```
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export interface DisplayHandle {
+      readonly displayId: string;
+      /** Replaces the displayed object, while the cell is still running or
+       * after it finished. */
+      update(obj: unknown, options?: { raw?: boolean }): Promise<void>;
     }
 
     /**
@@ -1272,6 +2151,20 @@ declare namespace Deno {
       [$display]: () => MediaBundle | Promise<MediaBundle>;
     }
 
+    /**
+     * Display function for Jupyter Deno Kernel.
+     * Mimics the behavior of IPython's `display(obj, raw=True)` function to allow
+     * asynchronous displaying of objects in Jupyter.
+     *
+     * @param obj - The object to be displayed
+     * @param options - Display options with a default { raw: true }
+     * @category Jupyter
+     * @experimental
+     */
+    export function display(
+      obj: unknown,
+      options: DisplayOptions & { display_id: string | true },
+    ): Promise<DisplayHandle>;
     /**
      * Display function for Jupyter Deno Kernel.
      * Mimics the behavior of IPython's `display(obj, raw=True)` function to allow
@@ -1287,6 +2180,153 @@ declare namespace Deno {
       options?: DisplayOptions,
     ): Promise<void>;
 
+    /**
+     * Clears the output of the current cell. With `wait`, it is cleared once
+     * new output is displayed instead, which avoids flickering when the
+     * output is redrawn.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export function clearOutput(options?: { wait?: boolean }): Promise<void>;
+
+    /**
+     * A message received on a {@linkcode Comm}.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export interface CommMessage {
+      data: Record<string, unknown>;
+      metadata: Record<string, unknown>;
+    }
+
+    /**
+     * A comm, a channel for custom messages between the kernel and the
+     * frontend that is used by widgets.
+     *
+     * Messages from the frontend are handled between cells, while a cell is
+     * running they wait until it finished.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export interface Comm {
+      readonly id: string;
+      readonly targetName: string;
+      /** Whether the kernel or the frontend closed the comm. */
+      readonly closed: boolean;
+      /** Called with the messages the frontend sends. */
+      onmessage:
+        | ((message: CommMessage) => void | Promise<void>)
+        | null;
+      /** Called when the frontend closes the comm. */
+      onclose:
+        | ((message: CommMessage) => void | Promise<void>)
+        | null;
+      /** Sends a message to the frontend. */
+      send(
+        data: Record<string, unknown>,
+        extra?: {
+          metadata?: Record<string, unknown>;
+          buffers?: Uint8Array[];
+        },
+      ): Promise<void>;
+      /** Closes the comm. */
+      close(
+        data?: Record<string, unknown>,
+        extra?: {
+          metadata?: Record<string, unknown>;
+          buffers?: Uint8Array[];
+        },
+      ): Promise<void>;
+    }
+
+    /**
+     * Registers the function that is called when the frontend opens a comm
+     * for the target. Comms opened for targets that were not registered are
+     * closed right away.
+     *
+     * ```ts
+     * Deno.jupyter.registerCommTarget("echo", (comm) => {
+     *   comm.onmessage = ({ data }) => comm.send(data);
+     * });
+     * ```
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export function registerCommTarget(
+      targetName: string,
+      callback: (comm: Comm, message: CommMessage) => void | Promise<void>,
+    ): void;
+
+    /**
+     * Opens a comm to a target of the frontend.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export function openComm(
+      targetName: string,
+      data?: Record<string, unknown>,
+      extra?: {
+        metadata?: Record<string, unknown>;
+        buffers?: Uint8Array[];
+      },
+    ): Promise<Comm>;
+
+    /**
+     * A widget of `@jupyter-widgets`, created with
+     * {@linkcode Deno.jupyter.createWidget}.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export interface Widget extends Displayable {
+      /** The id of the widget's model. */
+      readonly id: string;
+      /** A copy of the current state of the widget's model. */
+      readonly state: Record<string, unknown>;
+      /** Called with the changed state when the widget is changed in the
+       * frontend, like when a slider is moved. */
+      onchange:
+        | ((changes: Record<string, unknown>) => void | Promise<void>)
+        | null;
+      get(key: string): unknown;
+      /** Changes the state of the widget and updates it in the frontend. */
+      set(changes: Record<string, unknown>): Promise<void>;
+      close(): Promise<void>;
+    }
+
+    /**
+     * Creates a widget of `@jupyter-widgets` from the state of its model.
+     * Displaying the widget renders its view, which needs the widgets
+     * extension to be installed in the frontend.
+     *
+     * ```ts
+     * const slider = await Deno.jupyter.createWidget({
+     *   _model_name: "IntSliderModel",
+     *   _model_module: "@jupyter-widgets/controls",
+     *   _model_module_version: "2.0.0",
+     *   _view_name: "IntSliderView",
+     *   _view_module: "@jupyter-widgets/controls",
+     *   _view_module_version: "2.0.0",
+     *   value: 5,
+     *   min: 0,
+     *   max: 10,
+     * });
+     * slider.onchange = ({ value }) => console.log(value);
+     * slider;
+     * ```
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export function createWidget(
+      state: Record<string, unknown>,
+    ): Promise<Widget>;
+
     /**
      * Show Markdown in Jupyter frontends with a tagged template function.
      *
@@ -1472,6 +2512,36 @@ declare namespace Deno {
       fix?(fixer: Fixer): Fix | Iterable<Fix>;
     }
 
+    /**
+     * @category Linter
+     * @experimental
+     */
+    export type BindingKind =
+      | "var"
+      | "let"
+      | "const"
+      | "using"
+      | "function"
+      | "class"
+      | "param"
+      | "catchParam"
+      | "import"
+      | "enum"
+      | "type"
+      | "namespace";
+
+    /**
+     * The declaration an identifier refers to.
+     *
+     * @category Linter
+     * @experimental
+     */
+    export interface Binding {
+      kind: BindingKind;
+      /** The range of the identifier of the declaration. */
+      range: Range;
+    }
+
     /**
      * @category Linter
      * @experimental
@@ -1488,6 +2558,13 @@ declare namespace Deno {
        */
       getAncestors(node: Node): Node[];
 
+      /**
+       * Get the declaration an identifier refers to, from the scope
+       * analysis of the file. Returns `null` for globals, and for
+       * identifiers that aren't bound, like property names.
+       */
+      getBinding(node: Identifier): Binding | null;
+
       /**
        * Get all comments inside the source.
        */
@@ -4601,6 +5678,32 @@ interface WorkerOptions {
   deno?: {
     /** Set to `"none"` to disable all the permissions in the worker. */
     permissions?: Deno.PermissionOptions;
+    /** Limits of the resources the worker may use. A worker exceeding one
+     * of them is terminated, and an `error` event is dispatched on the
+     * `Worker` object.
+     *
+     * ```ts
+     * const worker = new Worker(
+     *   new URL("plugin.ts", import.meta.url).href,
+     *   { type: "module", deno: { limits: { heapMb: 64, cpuMs: 1000 } } },
+     * );
+     * worker.onerror = (e) => {
+     *   e.preventDefault();
+     *   console.log(e.message);
+     * };
+     * ```
+     */
+    limits?: {
+      /** Megabytes the heap of the worker may grow to. */
+      heapMb?: number;
+      /** Milliseconds of CPU time the worker may use. Only supported on
+       * Linux. */
+      cpuMs?: number;
+      /** Resources, such as files and sockets, the worker may have open at
+       * the same time, not counting its stdio. Open resources are counted
+       * periodically while the event loop of the worker runs. */
+      maxOpenFds?: number;
+    };
   };
 }
 
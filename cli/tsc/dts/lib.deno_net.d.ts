@@ -144,6 +144,21 @@ declare namespace Deno {
     /** Shuts down (`shutdown(2)`) the write side of the connection. Most
      * callers should just use `close()`. */
     closeWrite(): Promise<void>;
+    /** Limits how fast data is read from and written to the connection, in
+     * bytes per second, with a token bucket that allows bursts of up to one
+     * second worth of data. The limits apply to `read()`, `write()` and the
+     * `readable` and `writable` streams, and to the plaintext of TLS
+     * connections. A limit left out is removed, so `setRateLimit({})`
+     * removes both.
+     *
+     * ```ts
+     * const conn = await Deno.connect({ hostname: "example.com", port: 80 });
+     * conn.setRateLimit({ writeBps: 64 * 1024 });
+     * ```
+     *
+     * @experimental **UNSTABLE**: New API, yet to be vetted.
+     */
+    setRateLimit(options: { readBps?: number; writeBps?: number }): void;
 
     /** Make the connection block the event loop from finishing.
      *
@@ -165,6 +180,12 @@ declare namespace Deno {
      * If no ALPN protocol selected, returns `null`.
      */
     alpnProtocol: string | null;
+    /**
+     * The certificate chain the peer presented, in PEM format and starting
+     * with the peer's own certificate. Empty if the peer did not send one,
+     * like clients usually do not.
+     */
+    peerCertificates: string[];
   }
 
   /** @category Network */
@@ -207,6 +228,23 @@ declare namespace Deno {
 
   /** @category Network */
   export interface TcpListenOptions extends ListenOptions {
+    /** Enable TCP Fast Open, which lets clients that connected before send
+     * data in the SYN packet, saving a round trip. A number sets the maximum
+     * number of pending TCP Fast Open requests, `true` uses 256.
+     *
+     * Only supported on Linux and Android, and ignored elsewhere.
+     *
+     * @default {false} */
+    tcpFastOpen?: boolean | number;
+    /** Only accept connections once the client sent data, so that idle
+     * connections are not handed to the program. A number sets how many
+     * seconds to wait for the data before accepting the connection anyway,
+     * `true` uses 1.
+     *
+     * Only supported on Linux and Android, and ignored elsewhere.
+     *
+     * @default {false} */
+    deferAccept?: boolean | number;
   }
 
   /** Listen announces on the local transport address.
@@ -355,6 +393,13 @@ declare namespace Deno {
     transport?: "tcp";
     /** An {@linkcode AbortSignal} to close the tcp connection. */
     signal?: AbortSignal;
+    /** When the host name resolves to several addresses, the time in
+     * milliseconds a connection attempt gets before the next address is
+     * tried in parallel. Attempts alternate between IPv6 and IPv4, so a
+     * broken address family does not stall the connection (RFC 8305).
+     *
+     * @default {250} */
+    happyEyeballsDelay?: number;
   }
 
   /**
@@ -375,6 +420,27 @@ declare namespace Deno {
    */
   export function connect(options: ConnectOptions): Promise<TcpConn>;
 
+  /** Socket level options of a connection. Options a socket does not
+   * support, like `noDelay` on a Unix socket, throw a `NotSupported` error.
+   *
+   * @category Network */
+  export interface SocketOptions {
+    /** Disable Nagle's algorithm. TCP only. */
+    noDelay?: boolean;
+    /** Send keep-alive probes on idle connections. TCP only. */
+    keepAlive?: boolean;
+    /** Milliseconds a connection is idle before keep-alive probes are sent.
+     * TCP only. */
+    keepAliveIdle?: number;
+    /** Size in bytes of the kernel receive buffer (`SO_RCVBUF`). */
+    recvBuffer?: number;
+    /** Size in bytes of the kernel send buffer (`SO_SNDBUF`). */
+    sendBuffer?: number;
+    /** Milliseconds `close()` waits for unsent data (`SO_LINGER`), or `null`
+     * to not wait. Not supported on datagram sockets. */
+    linger?: number | null;
+  }
+
   /** @category Network */
   export interface TcpConn extends Conn<NetAddr> {
     /**
@@ -385,6 +451,17 @@ declare namespace Deno {
     setNoDelay(noDelay?: boolean): void;
     /** Enable/disable keep-alive functionality. */
     setKeepAlive(keepAlive?: boolean): void;
+    /** Set socket options. Options which are left out keep their value.
+     *
+     * ```ts
+     * const conn = await Deno.connect({ port: 80 });
+     * conn.setOption({ noDelay: true, keepAlive: true, keepAliveIdle: 30_000 });
+     * ```
+     */
+    setOption(options: SocketOptions): void;
+    /** Read back the current socket options. `keepAliveIdle` is not
+     * reported on Windows. */
+    getOption(): SocketOptions;
   }
 
   /** @category Network */
@@ -394,7 +471,12 @@ declare namespace Deno {
   }
 
   /** @category Network */
-  export interface UnixConn extends Conn<UnixAddr> {}
+  export interface UnixConn extends Conn<UnixAddr> {
+    /** Set socket options. Options which are left out keep their value. */
+    setOption(options: SocketOptions): void;
+    /** Read back the current socket options. */
+    getOption(): Omit<SocketOptions, "noDelay" | "keepAlive" | "keepAliveIdle">;
+  }
 
   /** Connects to the hostname (default is "127.0.0.1") and port on the named
    * transport (default is "tcp"), and resolves to the connection (`Conn`).
@@ -558,6 +640,9 @@ declare namespace Deno {
    * const tlsConn = await Deno.startTls(conn, { caCerts: [caCert], hostname: "localhost" });
    * ```
    *
+   * Mutual TLS (mTLS or client certificates) are supported by providing a
+   * `key` and `cert` in the options as PEM-encoded strings.
+   *
    * Requires `allow-net` permission.
    *
    * @tags allow-net
@@ -565,7 +650,7 @@ declare namespace Deno {
    */
   export function startTls(
     conn: TcpConn,
-    options?: StartTlsOptions,
+    options?: StartTlsOptions | (StartTlsOptions & TlsCertifiedKeyPem),
   ): Promise<TlsConn>;
 
   /**
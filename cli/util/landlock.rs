@@ -0,0 +1,421 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Kernel enforcement of `--allow-read` and `--allow-write` with Landlock,
+//! enabled with `--landlock`. This confines code that Deno's own checks do
+//! not see, such as FFI libraries and subprocesses, to the allowed paths.
+//!
+//! A Landlock ruleset only restricts the thread that installs it and the
+//! threads and processes it starts later, so installing it fails once the
+//! process has other threads. Besides the allowed paths, the ruleset allows
+//! what Deno itself needs to run: reading executables, libraries and the
+//! system files for TLS and name resolution, the directory of the main
+//! module of `deno run` (or the working directory for other subcommands),
+//! the config files of the working directory and its ancestors, and
+//! reading and writing the `DENO_DIR`, the lockfile and the `node_modules`
+//! directory. Denied paths within allowed ones are only enforced by Deno.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+#[cfg(target_os = "linux")]
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_path_util::normalize_path;
+
+use crate::args::ConfigFlag;
+use crate::args::DenoSubcommand;
+use crate::args::Flags;
+use crate::colors;
+use crate::sys::CliSys;
+
+/// Read by Deno, the dynamic loader and most subprocesses.
+#[cfg(target_os = "linux")]
+const SYSTEM_READ_PATHS: &[&str] = &[
+  "/bin",
+  "/dev/urandom",
+  "/etc/hosts",
+  "/etc/ld.so.cache",
+  "/etc/localtime",
+  "/etc/nsswitch.conf",
+  "/etc/pki",
+  "/etc/resolv.conf",
+  "/etc/ssl",
+  "/lib",
+  "/lib64",
+  "/proc/self",
+  "/sbin",
+  // the number of CPUs available to the process
+  "/sys/devices/system/cpu",
+  "/sys/fs/cgroup",
+  "/usr",
+];
+#[cfg(target_os = "linux")]
+const SYSTEM_WRITE_PATHS: &[&str] = &["/dev/null", "/dev/tty"];
+
+/// Files looked up in the working directory and its ancestors.
+const CONFIG_FILE_NAMES: &[&str] = &["deno.json", "deno.jsonc", "package.json"];
+
+#[derive(Debug)]
+struct Rules {
+  read: Vec<PathBuf>,
+  write: Vec<PathBuf>,
+}
+
+/// The path a flag value grants access beneath, which for a glob pattern is
+/// its literal prefix.
+fn flag_path(cwd: &Path, value: &str) -> PathBuf {
+  let path = Path::new(value);
+  let literal = path
+    .components()
+    .take_while(|c| {
+      !deno_runtime::deno_permissions::is_glob_component(c.as_os_str())
+    })
+    .collect::<PathBuf>();
+  normalize_path(cwd.join(literal))
+}
+
+fn rules(flags: &Flags, cwd: &Path, deno_dir: Option<PathBuf>) -> Rules {
+  let permissions = &flags.permissions;
+  let allowed = |list: &Option<Vec<String>>| match list {
+    _ if permissions.allow_all => vec![PathBuf::from("/")],
+    Some(list) if list.is_empty() => vec![PathBuf::from("/")],
+    Some(list) => list.iter().map(|value| flag_path(cwd, value)).collect(),
+    None => Vec::new(),
+  };
+  let mut rules = Rules {
+    read: allowed(&permissions.allow_read),
+    write: allowed(&permissions.allow_write),
+  };
+  match &flags.subcommand {
+    DenoSubcommand::Run(run_flags) => {
+      if let Some(dir) =
+        deno_path_util::resolve_url_or_path(&run_flags.script, cwd)
+          .ok()
+          .and_then(|url| deno_path_util::url_to_file_path(&url).ok())
+          .and_then(|path| path.parent().map(Path::to_path_buf))
+      {
+        rules.read.push(dir);
+      }
+    }
+    _ => rules.read.push(cwd.to_path_buf()),
+  }
+  if let ConfigFlag::Path(path) = &flags.config_flag {
+    rules.read.push(normalize_path(cwd.join(path)));
+  }
+  for dir in cwd.ancestors() {
+    rules
+      .read
+      .extend(CONFIG_FILE_NAMES.iter().map(|name| dir.join(name)));
+  }
+  for path in deno_dir
+    .into_iter()
+    .chain([cwd.join("deno.lock"), cwd.join("node_modules")])
+  {
+    rules.read.push(path.clone());
+    rules.write.push(path);
+  }
+  rules
+}
+
+/// Restricts the process to the file system paths allowed by the flags, when
+/// `--landlock` is given.
+pub fn maybe_restrict_fs(
+  flags: &Flags,
+  initial_cwd: Option<&Path>,
+  runtime_started: bool,
+) -> Result<(), AnyError> {
+  if !flags.landlock {
+    return Ok(());
+  }
+  if runtime_started {
+    deno_core::anyhow::bail!(
+      "--landlock can not be used with a runtime started ahead of time."
+    );
+  }
+  if !cfg!(target_os = "linux") {
+    log::warn!(
+      "{} --landlock is only supported on Linux, file system permissions are only enforced by Deno.",
+      colors::yellow("Warning")
+    );
+    return Ok(());
+  }
+  let Some(cwd) = initial_cwd else {
+    return Ok(());
+  };
+  let deno_dir = deno_cache_dir::resolve_deno_dir(
+    &CliSys::default(),
+    flags.internal.cache_path.clone(),
+  )
+  .ok();
+  let rules = rules(flags, cwd, deno_dir);
+
+  #[cfg(target_os = "linux")]
+  match linux::restrict(&rules)
+    .context("Failed installing Landlock ruleset.")?
+  {
+    Some(abi) => log::debug!("Installed Landlock ruleset, ABI version {abi}"),
+    None => log::warn!(
+      "{} This kernel does not support Landlock, file system permissions are only enforced by Deno.",
+      colors::yellow("Warning")
+    ),
+  }
+  #[cfg(not(target_os = "linux"))]
+  let _ = rules;
+  Ok(())
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+  use std::fs::File;
+  use std::io;
+  use std::os::fd::AsRawFd;
+  use std::os::fd::FromRawFd;
+  use std::os::fd::OwnedFd;
+  use std::os::unix::fs::OpenOptionsExt;
+  use std::path::Path;
+
+  use super::Rules;
+  use super::SYSTEM_READ_PATHS;
+  use super::SYSTEM_WRITE_PATHS;
+
+  const CREATE_RULESET_VERSION: u32 = 1 << 0;
+  const RULE_PATH_BENEATH: libc::c_int = 1;
+
+  const ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+  const ACCESS_FS_READ_FILE: u64 = 1 << 2;
+  const ACCESS_FS_READ_DIR: u64 = 1 << 3;
+  const ACCESS_FS_REMOVE_DIR: u64 = 1 << 4;
+  const ACCESS_FS_REMOVE_FILE: u64 = 1 << 5;
+  const ACCESS_FS_MAKE_CHAR: u64 = 1 << 6;
+  const ACCESS_FS_MAKE_DIR: u64 = 1 << 7;
+  const ACCESS_FS_MAKE_REG: u64 = 1 << 8;
+  const ACCESS_FS_MAKE_SOCK: u64 = 1 << 9;
+  const ACCESS_FS_MAKE_FIFO: u64 = 1 << 10;
+  const ACCESS_FS_MAKE_BLOCK: u64 = 1 << 11;
+  const ACCESS_FS_MAKE_SYM: u64 = 1 << 12;
+  /// ABI version 2 and later.
+  const ACCESS_FS_REFER: u64 = 1 << 13;
+  /// ABI version 3 and later.
+  const ACCESS_FS_TRUNCATE: u64 = 1 << 14;
+
+  const ACCESS_READ: u64 = ACCESS_FS_READ_FILE | ACCESS_FS_READ_DIR;
+  const ACCESS_WRITE_V1: u64 = ACCESS_FS_WRITE_FILE
+    | ACCESS_FS_REMOVE_DIR
+    | ACCESS_FS_REMOVE_FILE
+    | ACCESS_FS_MAKE_CHAR
+    | ACCESS_FS_MAKE_DIR
+    | ACCESS_FS_MAKE_REG
+    | ACCESS_FS_MAKE_SOCK
+    | ACCESS_FS_MAKE_FIFO
+    | ACCESS_FS_MAKE_BLOCK
+    | ACCESS_FS_MAKE_SYM;
+  /// The rights which apply to files rather than directories.
+  const ACCESS_FILE: u64 =
+    ACCESS_FS_READ_FILE | ACCESS_FS_WRITE_FILE | ACCESS_FS_TRUNCATE;
+
+  #[repr(C)]
+  struct RulesetAttr {
+    handled_access_fs: u64,
+  }
+
+  #[repr(C, packed)]
+  struct PathBeneathAttr {
+    allowed_access: u64,
+    parent_fd: i32,
+  }
+
+  /// The Landlock ABI version of the kernel, or `None` without Landlock.
+  fn abi_version() -> Option<i64> {
+    // SAFETY: querying the version takes no attributes
+    let version = unsafe {
+      libc::syscall(
+        libc::SYS_landlock_create_ruleset,
+        std::ptr::null::<RulesetAttr>(),
+        0,
+        CREATE_RULESET_VERSION,
+      )
+    };
+    (version > 0).then_some(version)
+  }
+
+  fn add_rule(
+    ruleset: &OwnedFd,
+    path: &Path,
+    access: u64,
+    handled: u64,
+  ) -> io::Result<()> {
+    let file = match File::options()
+      .read(true)
+      .custom_flags(libc::O_PATH | libc::O_CLOEXEC)
+      .open(path)
+    {
+      Ok(file) => file,
+      // rules can only be added for existing paths
+      Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+      Err(err) => return Err(err),
+    };
+    let mut allowed_access = access & handled;
+    if !file.metadata()?.is_dir() {
+      allowed_access &= ACCESS_FILE;
+    }
+    let attr = PathBeneathAttr {
+      allowed_access,
+      parent_fd: file.as_raw_fd(),
+    };
+    // SAFETY: `attr` outlives the call and both fds are open
+    let result = unsafe {
+      libc::syscall(
+        libc::SYS_landlock_add_rule,
+        ruleset.as_raw_fd(),
+        RULE_PATH_BENEATH,
+        &attr as *const PathBeneathAttr,
+        0,
+      )
+    };
+    if result != 0 {
+      return Err(io::Error::last_os_error());
+    }
+    Ok(())
+  }
+
+  /// Whether the calling thread is the only thread of the process.
+  fn is_single_threaded() -> io::Result<bool> {
+    Ok(std::fs::read_dir("/proc/self/task")?.take(2).count() == 1)
+  }
+
+  /// Installs the ruleset and returns the ABI version it was installed
+  /// with, or `None` when the kernel lacks Landlock.
+  pub fn restrict(rules: &Rules) -> io::Result<Option<i64>> {
+    let Some(abi) = abi_version() else {
+      return Ok(None);
+    };
+    // other threads would not be restricted
+    if !is_single_threaded()? {
+      return Err(io::Error::other(
+        "the process already started other threads",
+      ));
+    }
+    let mut access_write = ACCESS_WRITE_V1;
+    if abi >= 2 {
+      access_write |= ACCESS_FS_REFER;
+    }
+    if abi >= 3 {
+      access_write |= ACCESS_FS_TRUNCATE;
+    }
+    let handled = ACCESS_READ | access_write;
+    let attr = RulesetAttr {
+      handled_access_fs: handled,
+    };
+    // SAFETY: `attr` outlives the call
+    let fd = unsafe {
+      libc::syscall(
+        libc::SYS_landlock_create_ruleset,
+        &attr as *const RulesetAttr,
+        std::mem::size_of::<RulesetAttr>(),
+        0,
+      )
+    };
+    if fd < 0 {
+      return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the syscall returned a new fd which nothing else owns
+    let ruleset = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+
+    let read = rules
+      .read
+      .iter()
+      .map(|p| p.as_path())
+      .chain(SYSTEM_READ_PATHS.iter().map(Path::new));
+    for path in read {
+      add_rule(&ruleset, path, ACCESS_READ, handled)?;
+    }
+    let write = rules
+      .write
+      .iter()
+      .map(|p| p.as_path())
+      .chain(SYSTEM_WRITE_PATHS.iter().map(Path::new));
+    for path in write {
+      add_rule(&ruleset, path, ACCESS_READ | access_write, handled)?;
+    }
+
+    // SAFETY: plain prctl call, required to restrict an unprivileged process
+    if unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) } != 0 {
+      return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the ruleset fd is open
+    let result = unsafe {
+      libc::syscall(libc::SYS_landlock_restrict_self, ruleset.as_raw_fd(), 0)
+    };
+    if result != 0 {
+      return Err(io::Error::last_os_error());
+    }
+    Ok(Some(abi))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::args::PermissionFlags;
+  use crate::args::RunFlags;
+
+  #[test]
+  fn rules_from_flags() {
+    let cwd = PathBuf::from("/project");
+    let flags = Flags {
+      permissions: PermissionFlags {
+        allow_read: Some(vec!["./data".into(), "src/**/*.ts".into()]),
+        allow_write: Some(vec![]),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let granted = rules(&flags, &cwd, Some(PathBuf::from("/deno_dir")));
+    assert_eq!(
+      granted.read,
+      [
+        "/project/data",
+        "/project/src",
+        "/project",
+        "/project/deno.json",
+        "/project/deno.jsonc",
+        "/project/package.json",
+        "/deno.json",
+        "/deno.jsonc",
+        "/package.json",
+        "/deno_dir",
+        "/project/deno.lock",
+        "/project/node_modules",
+      ]
+      .map(PathBuf::from)
+    );
+    assert_eq!(
+      granted.write,
+      [
+        "/",
+        "/deno_dir",
+        "/project/deno.lock",
+        "/project/node_modules"
+      ]
+      .map(PathBuf::from)
+    );
+
+    let granted = rules(&Flags::default(), &cwd, None);
+    assert_eq!(granted.read[0], cwd);
+    assert_eq!(granted.write.len(), 2);
+
+    let flags = Flags {
+      subcommand: DenoSubcommand::Run(RunFlags::new_default(
+        "scripts/main.ts".to_string(),
+      )),
+      permissions: PermissionFlags {
+        allow_read: Some(vec!["data[0-9]".into()]),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let granted = rules(&flags, &cwd, None);
+    assert_eq!(granted.read[0], cwd);
+    assert_eq!(granted.read[1], cwd.join("scripts"));
+  }
+}
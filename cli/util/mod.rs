@@ -8,6 +8,7 @@ pub mod draw_thread;
 pub mod extract;
 pub mod file_watcher;
 pub mod fs;
+pub mod landlock;
 pub mod path;
 pub mod progress_bar;
 pub mod retry;
@@ -21,6 +21,8 @@ use deno_npm_installer::graph::NpmCachingStrategy;
 use deno_runtime::WorkerExecutionMode;
 use deno_runtime::coverage::CoverageCollector;
 use deno_runtime::deno_permissions::PermissionsContainer;
+use deno_runtime::profiler::Profiler;
+use deno_runtime::profiler::ProfilerOptions;
 use deno_runtime::worker::MainWorker;
 use deno_semver::npm::NpmPackageReqReference;
 use sys_traits::EnvCurrentDir;
@@ -41,6 +43,7 @@ pub type CreateHmrRunnerCb = Box<dyn Fn() -> HmrRunnerState + Send + Sync>;
 pub struct CliMainWorkerOptions {
   pub create_hmr_runner: Option<CreateHmrRunnerCb>,
   pub maybe_coverage_dir: Option<PathBuf>,
+  pub profiler_options: ProfilerOptions,
   pub default_npm_caching_strategy: NpmCachingStrategy,
   pub needs_test_modules: bool,
   pub maybe_initial_cwd: Option<Arc<ModuleSpecifier>>,
@@ -50,6 +53,7 @@ pub struct CliMainWorkerOptions {
 struct SharedState {
   pub create_hmr_runner: Option<CreateHmrRunnerCb>,
   pub maybe_coverage_dir: Option<PathBuf>,
+  pub profiler_options: ProfilerOptions,
   pub maybe_file_watcher_communicator: Option<Arc<WatcherCommunicator>>,
   pub maybe_initial_cwd: Option<Arc<ModuleSpecifier>>,
 }
@@ -73,6 +77,7 @@ impl CliMainWorker {
   pub async fn run(&mut self) -> Result<i32, CoreError> {
     let mut maybe_coverage_collector = self.maybe_setup_coverage_collector();
     let mut maybe_hmr_runner = self.maybe_setup_hmr_runner();
+    let mut maybe_profiler = self.maybe_setup_profiler();
 
     // WARNING: Remember to update cli/lib/worker.rs to align with
     // changes made here so that they affect deno_compile as well.
@@ -109,10 +114,18 @@ impl CliMainWorker {
         }
       } else {
         // TODO(bartlomieju): this might not be needed anymore
-        self
+        let event_loop_future = self
           .worker
           .run_event_loop(maybe_coverage_collector.is_none())
-          .await?;
+          .boxed_local();
+        if let Some(profiler) = maybe_profiler.as_mut() {
+          select! {
+            _ = profiler.write_on_signal() => unreachable!(),
+            result = event_loop_future => result?,
+          }
+        } else {
+          event_loop_future.await?;
+        }
       }
 
       let web_continue = self.worker.dispatch_beforeunload_event()?;
@@ -130,6 +143,9 @@ impl CliMainWorker {
     if let Some(coverage_collector) = maybe_coverage_collector.as_mut() {
       coverage_collector.stop_collecting()?;
     }
+    if let Some(profiler) = maybe_profiler.as_mut() {
+      profiler.stop();
+    }
     if let Some(hmr_runner) = maybe_hmr_runner.as_mut() {
       hmr_runner.stop();
     }
@@ -242,6 +258,17 @@ impl CliMainWorker {
     Some(coverage_collector)
   }
 
+  pub fn maybe_setup_profiler(&mut self) -> Option<Profiler> {
+    let options = &self.shared.profiler_options;
+    if options.cpu_prof.is_none() && options.heap_prof.is_none() {
+      return None;
+    }
+    let mut profiler = Profiler::new(self.worker.js_runtime(), options.clone());
+    profiler.start();
+
+    Some(profiler)
+  }
+
   pub fn execute_script_static(
     &mut self,
     name: &'static str,
@@ -315,6 +342,7 @@ impl CliMainWorkerFactory {
       shared: Arc::new(SharedState {
         create_hmr_runner: options.create_hmr_runner,
         maybe_coverage_dir: options.maybe_coverage_dir,
+        profiler_options: options.profiler_options,
         maybe_file_watcher_communicator,
         maybe_initial_cwd: options.maybe_initial_cwd,
       }),
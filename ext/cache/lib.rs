@@ -189,10 +189,43 @@ pub trait Cache: Clone + 'static {
   ) -> Result<bool, CacheError>;
 }
 
+/// A storage backend for the Cache API other than the built-in ones, such
+/// as an in-memory or remote store provided by an embedder through
+/// [`CreateCache`].
+#[async_trait(?Send)]
+pub trait CacheBackend {
+  async fn storage_open(&self, cache_name: String) -> Result<i64, CacheError>;
+  async fn storage_has(&self, cache_name: String) -> Result<bool, CacheError>;
+  async fn storage_delete(
+    &self,
+    cache_name: String,
+  ) -> Result<bool, CacheError>;
+
+  /// Put a resource into the cache.
+  async fn put(
+    &self,
+    request_response: CachePutRequest,
+    resource: Option<Rc<dyn Resource>>,
+  ) -> Result<(), CacheError>;
+
+  async fn r#match(
+    &self,
+    request: CacheMatchRequest,
+  ) -> Result<
+    Option<(CacheMatchResponseMeta, Option<CacheResponseResource>)>,
+    CacheError,
+  >;
+  async fn delete(
+    &self,
+    request: CacheDeleteRequest,
+  ) -> Result<bool, CacheError>;
+}
+
 #[derive(Clone)]
 pub enum CacheImpl {
   Sqlite(SqliteBackedCache),
   Lsc(LscBackend),
+  Custom(Rc<dyn CacheBackend>),
 }
 
 #[async_trait(?Send)]
@@ -203,6 +236,7 @@ impl Cache for CacheImpl {
     match self {
       Self::Sqlite(cache) => cache.storage_open(cache_name).await,
       Self::Lsc(cache) => cache.storage_open(cache_name).await,
+      Self::Custom(cache) => cache.storage_open(cache_name).await,
     }
   }
 
@@ -210,6 +244,7 @@ impl Cache for CacheImpl {
     match self {
       Self::Sqlite(cache) => cache.storage_has(cache_name).await,
       Self::Lsc(cache) => cache.storage_has(cache_name).await,
+      Self::Custom(cache) => cache.storage_has(cache_name).await,
     }
   }
 
@@ -220,6 +255,7 @@ impl Cache for CacheImpl {
     match self {
       Self::Sqlite(cache) => cache.storage_delete(cache_name).await,
       Self::Lsc(cache) => cache.storage_delete(cache_name).await,
+      Self::Custom(cache) => cache.storage_delete(cache_name).await,
     }
   }
 
@@ -231,6 +267,7 @@ impl Cache for CacheImpl {
     match self {
       Self::Sqlite(cache) => cache.put(request_response, resource).await,
       Self::Lsc(cache) => cache.put(request_response, resource).await,
+      Self::Custom(cache) => cache.put(request_response, resource).await,
     }
   }
 
@@ -244,6 +281,7 @@ impl Cache for CacheImpl {
     match self {
       Self::Sqlite(cache) => cache.r#match(request).await,
       Self::Lsc(cache) => cache.r#match(request).await,
+      Self::Custom(cache) => cache.r#match(request).await,
     }
   }
 
@@ -254,13 +292,14 @@ impl Cache for CacheImpl {
     match self {
       Self::Sqlite(cache) => cache.delete(request).await,
       Self::Lsc(cache) => cache.delete(request).await,
+      Self::Custom(cache) => cache.delete(request).await,
     }
   }
 }
 
 pub enum CacheResponseResource {
   Sqlite(AsyncRefCell<tokio::fs::File>),
-  Lsc(AsyncRefCell<Pin<Box<dyn AsyncRead>>>),
+  Reader(AsyncRefCell<Pin<Box<dyn AsyncRead>>>),
 }
 
 impl CacheResponseResource {
@@ -271,7 +310,12 @@ impl CacheResponseResource {
   fn lsc(
     body: impl Stream<Item = Result<Bytes, std::io::Error>> + 'static,
   ) -> Self {
-    Self::Lsc(AsyncRefCell::new(Box::pin(StreamReader::new(body))))
+    Self::reader(StreamReader::new(body))
+  }
+
+  /// A response body read from `reader`, for [`CacheBackend`]s.
+  pub fn reader(reader: impl AsyncRead + 'static) -> Self {
+    Self::Reader(AsyncRefCell::new(Box::pin(reader)))
   }
 
   async fn read(
@@ -287,9 +331,9 @@ impl CacheResponseResource {
         let mut file = resource.borrow_mut().await;
         file.read(data).await?
       }
-      CacheResponseResource::Lsc(_) => {
+      CacheResponseResource::Reader(_) => {
         let resource = deno_core::RcRef::map(&self, |r| match r {
-          Self::Lsc(r) => r,
+          Self::Reader(r) => r,
           _ => unreachable!(),
         });
         let mut file = resource.borrow_mut().await;
@@ -34,6 +34,9 @@ use crate::vary_header_matches;
 pub struct SqliteBackedCache {
   pub connection: Arc<Mutex<Connection>>,
   pub cache_storage_dir: PathBuf,
+  /// The total size of the response bodies in bytes, past which the least
+  /// recently used responses are evicted.
+  pub max_size: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -55,6 +58,16 @@ impl SqliteBackedCache {
         Mode::Disk
       }
     };
+    let max_size = match std::env::var("DENO_CACHE_MAX_SIZE") {
+      Ok(value) => match value.parse::<u64>() {
+        Ok(max_size) => Some(max_size),
+        Err(_) => {
+          log::warn!("Invalid DENO_CACHE_MAX_SIZE value, ignoring it");
+          None
+        }
+      },
+      Err(_) => None,
+    };
 
     let connection = if matches!(mode, Mode::InMemory) {
       rusqlite::Connection::open_in_memory()
@@ -107,11 +120,49 @@ impl SqliteBackedCache {
                 )",
           (),
         )?;
+    // added after the table, so databases created before have to be migrated
+    add_column_if_missing(
+      &connection,
+      "response_body_size",
+      "INTEGER NOT NULL DEFAULT 0",
+    )?;
+    add_column_if_missing(
+      &connection,
+      "last_accessed_at",
+      "INTEGER NOT NULL DEFAULT 0",
+    )?;
     Ok(SqliteBackedCache {
       connection: Arc::new(Mutex::new(connection)),
       cache_storage_dir,
+      max_size,
     })
   }
+
+  /// Evicts the least recently used responses once their bodies take up
+  /// more than `max_size` bytes, overriding `DENO_CACHE_MAX_SIZE`.
+  pub fn with_max_size(mut self, max_size: Option<u64>) -> Self {
+    self.max_size = max_size;
+    self
+  }
+}
+
+fn add_column_if_missing(
+  connection: &Connection,
+  column: &str,
+  definition: &str,
+) -> Result<(), rusqlite::Error> {
+  let exists = connection
+    .prepare("SELECT 1 FROM pragma_table_info('request_response_list') WHERE name = ?1")?
+    .exists([column])?;
+  if !exists {
+    connection.execute(
+      &format!(
+        "ALTER TABLE request_response_list ADD COLUMN {column} {definition}"
+      ),
+      (),
+    )?;
+  }
+  Ok(())
 }
 
 impl SqliteBackedCache {
@@ -219,6 +270,7 @@ impl SqliteBackedCache {
       let response_path = responses_dir.join(&body_key);
       let mut file = tokio::fs::File::create(response_path).await?;
       let mut buf = BufMutView::new(64 * 1024);
+      let mut body_size = 0u64;
       loop {
         let (size, buf2) = resource
           .clone()
@@ -229,6 +281,7 @@ impl SqliteBackedCache {
           break;
         }
         buf = buf2;
+        body_size += size as u64;
 
         // Use poll_write to avoid holding a slice across await points
         poll_fn(|cx| Pin::new(&mut file).poll_write(cx, &buf[..size])).await?;
@@ -238,10 +291,17 @@ impl SqliteBackedCache {
       file.sync_all().await?;
 
       assert_eq!(
-        insert_cache_asset(db, request_response, Some(body_key.clone()),)
-          .await?,
+        insert_cache_asset(
+          db,
+          request_response.clone(),
+          Some((body_key.clone(), body_size))
+        )
+        .await?,
         Some(body_key)
       );
+      if let Some(max_size) = self.max_size {
+        self.evict(max_size, request_response).await?;
+      }
     } else {
       assert!(
         insert_cache_asset(db, request_response, None)
@@ -252,6 +312,60 @@ impl SqliteBackedCache {
     Ok(())
   }
 
+  /// Deletes the least recently used responses, other than the one just
+  /// put, until the bodies take up at most `max_size` bytes.
+  async fn evict(
+    &self,
+    max_size: u64,
+    put: CachePutRequest,
+  ) -> Result<(), CacheError> {
+    let db = self.connection.clone();
+    let cache_storage_dir = self.cache_storage_dir.clone();
+    spawn_blocking(move || {
+      let evicted = {
+        let db = db.lock();
+        let mut total: u64 = db.query_row(
+          "SELECT COALESCE(SUM(response_body_size), 0) FROM request_response_list",
+          (),
+          |row| row.get(0),
+        )?;
+        let mut evicted = Vec::new();
+        while total > max_size {
+          let Some((cache_id, body_key, size)) = db
+            .query_row(
+              "DELETE FROM request_response_list WHERE id = (
+                 SELECT id FROM request_response_list
+                 WHERE NOT (cache_id = ?1 AND request_url = ?2)
+                 ORDER BY last_accessed_at, id LIMIT 1
+               )
+               RETURNING cache_id, response_body_key, response_body_size",
+              (put.cache_id, &put.request_url),
+              |row| {
+                let cache_id: i64 = row.get(0)?;
+                let body_key: Option<String> = row.get(1)?;
+                let size: u64 = row.get(2)?;
+                Ok((cache_id, body_key, size))
+              },
+            )
+            .optional()?
+          else {
+            break;
+          };
+          total = total.saturating_sub(size);
+          if let Some(body_key) = body_key {
+            evicted.push((cache_id, body_key));
+          }
+        }
+        evicted
+      };
+      for (cache_id, body_key) in evicted {
+        remove_response_body(&cache_storage_dir, cache_id, &body_key);
+      }
+      Ok::<(), CacheError>(())
+    })
+    .await?
+  }
+
   pub async fn r#match(
     &self,
     request: CacheMatchRequest,
@@ -261,6 +375,8 @@ impl SqliteBackedCache {
   > {
     let db = self.connection.clone();
     let cache_storage_dir = self.cache_storage_dir.clone();
+    // the access time is only needed to evict the least recently used
+    let track_access = self.max_size.is_some();
     let (query_result, request) = spawn_blocking(move || {
       let db = db.lock();
       let result = db.query_row(
@@ -285,8 +401,19 @@ impl SqliteBackedCache {
           ))
         },
       );
+      let result = result.optional()?;
+      if track_access && result.is_some() {
+        db.execute(
+          &format!(
+            "UPDATE request_response_list SET last_accessed_at = {}
+               WHERE cache_id = ?1 AND request_url = ?2",
+            last_accessed_at(3)
+          ),
+          (request.cache_id, &request.request_url, now_millis()),
+        )?;
+      }
       // Return ownership of request to the caller
-      result.optional().map(|x| (x, request))
+      Ok::<_, rusqlite::Error>((result, request))
     })
     .await??;
 
@@ -337,14 +464,25 @@ impl SqliteBackedCache {
     request: CacheDeleteRequest,
   ) -> Result<bool, CacheError> {
     let db = self.connection.clone();
+    let cache_storage_dir = self.cache_storage_dir.clone();
     spawn_blocking(move || {
-      // TODO(@satyarohith): remove the response body from disk if one exists
-      let db = db.lock();
-      let rows_effected = db.execute(
-        "DELETE FROM request_response_list WHERE cache_id = ?1 AND request_url = ?2",
-        (request.cache_id, &request.request_url),
-      )?;
-      Ok::<bool, CacheError>(rows_effected > 0)
+      let deleted = {
+        let db = db.lock();
+        db.query_row(
+          "DELETE FROM request_response_list WHERE cache_id = ?1 AND request_url = ?2
+             RETURNING response_body_key",
+          (request.cache_id, &request.request_url),
+          |row| {
+            let response_body_key: Option<String> = row.get(0)?;
+            Ok(response_body_key)
+          },
+        )
+        .optional()?
+      };
+      if let Some(Some(body_key)) = &deleted {
+        remove_response_body(&cache_storage_dir, request.cache_id, body_key);
+      }
+      Ok::<bool, CacheError>(deleted.is_some())
     })
     .await?
   }
@@ -353,17 +491,22 @@ impl SqliteBackedCache {
 async fn insert_cache_asset(
   db: Arc<Mutex<Connection>>,
   put: CachePutRequest,
-  response_body_key: Option<String>,
+  response_body: Option<(String, u64)>,
 ) -> Result<Option<String>, CacheError> {
+  let (response_body_key, response_body_size) = match response_body {
+    Some((key, size)) => (Some(key), size),
+    None => (None, 0),
+  };
   spawn_blocking(move || {
     let maybe_response_body = {
       let db = db.lock();
       db.query_row(
-        "INSERT OR REPLACE INTO request_response_list
+        &format!("INSERT OR REPLACE INTO request_response_list
              (cache_id, request_url, request_headers, response_headers,
-              response_body_key, response_status, response_status_text, last_inserted_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
-             RETURNING response_body_key",
+              response_body_key, response_status, response_status_text, last_inserted_at,
+              response_body_size, last_accessed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, {})
+             RETURNING response_body_key", last_accessed_at(10)),
         (
           put.cache_id,
           put.request_url,
@@ -373,6 +516,8 @@ async fn insert_cache_asset(
           put.response_status,
           put.response_status_text,
           SystemTime::now().duration_since(UNIX_EPOCH).expect("SystemTime is before unix epoch").as_secs(),
+          response_body_size,
+          now_millis(),
         ),
         |row| {
           let response_body_key: Option<String> = row.get(0)?;
@@ -384,6 +529,34 @@ async fn insert_cache_asset(
   }).await?
 }
 
+/// The access time in milliseconds bound to the parameter, moved past the
+/// latest one so that accesses in the same millisecond stay ordered.
+fn last_accessed_at(param: usize) -> String {
+  format!(
+    "MAX(?{param}, (SELECT COALESCE(MAX(last_accessed_at), 0) + 1 FROM request_response_list))"
+  )
+}
+
+fn now_millis() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .expect("SystemTime is before unix epoch")
+    .as_millis() as u64
+}
+
+/// Best effort, as the body may still be read from.
+fn remove_response_body(
+  cache_storage_dir: &std::path::Path,
+  cache_id: i64,
+  body_key: &str,
+) {
+  let path =
+    get_responses_dir(cache_storage_dir.to_path_buf(), cache_id).join(body_key);
+  if let Err(err) = std::fs::remove_file(&path) {
+    log::debug!("Failed to remove cached response {}: {err}", path.display());
+  }
+}
+
 #[inline]
 fn get_responses_dir(cache_storage_dir: PathBuf, cache_id: i64) -> PathBuf {
   cache_storage_dir
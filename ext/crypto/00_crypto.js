@@ -11,6 +11,7 @@ const {
   isArrayBuffer,
   isTypedArray,
   isDataView,
+  internalRidSymbol,
 } = core;
 import {
   op_crypto_base64url_decode,
@@ -18,6 +19,9 @@ import {
   op_crypto_decrypt,
   op_crypto_derive_bits,
   op_crypto_derive_bits_x25519,
+  op_crypto_digest_finish,
+  op_crypto_digest_new,
+  op_crypto_digest_update,
   op_crypto_derive_bits_x448,
   op_crypto_encrypt,
   op_crypto_export_key,
@@ -31,6 +35,7 @@ import {
   op_crypto_generate_key,
   op_crypto_generate_x25519_keypair,
   op_crypto_generate_x448_keypair,
+  op_crypto_hash_file,
   op_crypto_get_random_values,
   op_crypto_import_key,
   op_crypto_import_pkcs8_ed25519,
@@ -67,6 +72,7 @@ const {
   ObjectAssign,
   ObjectHasOwn,
   ObjectPrototypeIsPrototypeOf,
+  PromisePrototypeCatch,
   SafeArrayIterator,
   SafeWeakMap,
   StringFromCharCode,
@@ -90,6 +96,10 @@ const {
 import * as webidl from "ext:deno_webidl/00_webidl.js";
 import { createFilteredInspectProxy } from "ext:deno_web/01_console.js";
 import { DOMException } from "ext:deno_web/01_dom_exception.js";
+import {
+  ReadableStreamDefaultReader,
+  ReadableStreamPrototype,
+} from "ext:deno_web/06_streams.js";
 
 const supportedNamedCurves = ["P-256", "P-384", "P-521"];
 const recognisedUsages = [
@@ -506,6 +516,42 @@ function getKeyLength(algorithm) {
   }
 }
 
+/**
+ * @param {string} algorithm
+ * @param {ReadableStream<BufferSource>} stream
+ * @param {string} prefix
+ * @returns {Promise<Uint8Array>}
+ */
+async function digestStream(algorithm, stream, prefix) {
+  const reader = new ReadableStreamDefaultReader(stream);
+  const rid = op_crypto_digest_new(algorithm);
+  try {
+    while (true) {
+      const { value, done } = await reader.read();
+      if (done) {
+        return op_crypto_digest_finish(rid);
+      }
+      const chunk = webidl.converters.BufferSource(value, prefix, "Chunk");
+      op_crypto_digest_update(rid, chunk);
+    }
+  } catch (error) {
+    core.tryClose(rid);
+    PromisePrototypeCatch(reader.cancel(error), () => {});
+    throw error;
+  } finally {
+    reader.releaseLock();
+  }
+}
+
+/**
+ * @param {unknown} value
+ * @returns {boolean}
+ */
+function isFsFile(value) {
+  return value !== null && typeof value === "object" &&
+    typeof value[internalRidSymbol] === "number";
+}
+
 class SubtleCrypto {
   constructor() {
     webidl.illegalConstructor();
@@ -513,7 +559,7 @@ class SubtleCrypto {
 
   /**
    * @param {string} algorithm
-   * @param {BufferSource} data
+   * @param {BufferSource | ReadableStream<BufferSource> | Deno.FsFile} data
    * @returns {Promise<ArrayBuffer>}
    */
   async digest(algorithm, data) {
@@ -525,6 +571,22 @@ class SubtleCrypto {
       prefix,
       "Argument 1",
     );
+
+    // streams and files are hashed chunk by chunk instead of being buffered
+    if (ObjectPrototypeIsPrototypeOf(ReadableStreamPrototype, data)) {
+      algorithm = normalizeAlgorithm(algorithm, "digest");
+      const result = await digestStream(algorithm.name, data, prefix);
+      return TypedArrayPrototypeGetBuffer(result);
+    }
+    if (isFsFile(data)) {
+      algorithm = normalizeAlgorithm(algorithm, "digest");
+      const result = await op_crypto_hash_file(
+        data[internalRidSymbol],
+        algorithm.name,
+      );
+      return TypedArrayPrototypeGetBuffer(result);
+    }
+
     data = webidl.converters.BufferSource(data, prefix, "Argument 2");
 
     data = copyBuffer(data);
@@ -0,0 +1,115 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Digests computed over chunks, for `crypto.subtle.digest()` with a
+//! `ReadableStream` or a `Deno.FsFile`, so that the data never has to be
+//! buffered in full.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use aws_lc_rs::digest;
+use deno_core::OpState;
+use deno_core::Resource;
+use deno_core::ResourceId;
+use deno_core::ToJsBuffer;
+use deno_core::op2;
+use deno_error::JsErrorBox;
+
+use crate::key::CryptoHash;
+
+/// The size of the chunks read from a file.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// A digest that is being computed, finished once all data was added.
+pub struct DigestContext(RefCell<Option<digest::Context>>);
+
+impl DigestContext {
+  pub fn new(algorithm: CryptoHash) -> Self {
+    Self(RefCell::new(Some(digest::Context::new(algorithm.into()))))
+  }
+
+  fn update(&self, data: &[u8]) -> Result<(), JsErrorBox> {
+    match self.0.borrow_mut().as_mut() {
+      Some(context) => {
+        context.update(data);
+        Ok(())
+      }
+      None => Err(finished_error()),
+    }
+  }
+
+  fn finish(&self) -> Result<ToJsBuffer, JsErrorBox> {
+    let context = self.0.borrow_mut().take().ok_or_else(finished_error)?;
+    Ok(context.finish().as_ref().to_vec().into())
+  }
+}
+
+impl Resource for DigestContext {
+  fn name(&self) -> Cow<'_, str> {
+    "cryptoDigest".into()
+  }
+}
+
+fn finished_error() -> JsErrorBox {
+  JsErrorBox::generic("The digest has already been finished")
+}
+
+#[op2(fast)]
+#[smi]
+pub fn op_crypto_digest_new(
+  state: &mut OpState,
+  #[serde] algorithm: CryptoHash,
+) -> ResourceId {
+  state.resource_table.add(DigestContext::new(algorithm))
+}
+
+#[op2(fast)]
+pub fn op_crypto_digest_update(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+  #[anybuffer] data: &[u8],
+) -> Result<(), JsErrorBox> {
+  let context = state
+    .resource_table
+    .get::<DigestContext>(rid)
+    .map_err(JsErrorBox::from_err)?;
+  context.update(data)
+}
+
+#[op2]
+#[serde]
+pub fn op_crypto_digest_finish(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+) -> Result<ToJsBuffer, JsErrorBox> {
+  let context = state
+    .resource_table
+    .take::<DigestContext>(rid)
+    .map_err(JsErrorBox::from_err)?;
+  context.finish()
+}
+
+/// Digests the rest of a file, read in chunks straight from its resource.
+#[op2(async)]
+#[serde]
+pub async fn op_crypto_hash_file(
+  state: Rc<RefCell<OpState>>,
+  #[smi] rid: ResourceId,
+  #[serde] algorithm: CryptoHash,
+) -> Result<ToJsBuffer, JsErrorBox> {
+  let resource = state
+    .borrow()
+    .resource_table
+    .get_any(rid)
+    .map_err(JsErrorBox::from_err)?;
+  let context = DigestContext::new(algorithm);
+  loop {
+    let chunk = resource.clone().read(CHUNK_SIZE).await?;
+    if chunk.is_empty() {
+      break;
+    }
+    context.update(&chunk)?;
+  }
+  context.finish()
+}
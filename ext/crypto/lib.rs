@@ -51,6 +51,7 @@ use signature::hazmat::PrehashSigner;
 use signature::hazmat::PrehashVerifier; // Re-export rand
 
 mod decrypt;
+mod digest_stream;
 mod ed25519;
 mod encrypt;
 mod export_key;
@@ -94,6 +95,10 @@ deno_core::extension!(deno_crypto,
     op_crypto_encrypt,
     op_crypto_decrypt,
     op_crypto_subtle_digest,
+    digest_stream::op_crypto_digest_new,
+    digest_stream::op_crypto_digest_update,
+    digest_stream::op_crypto_digest_finish,
+    digest_stream::op_crypto_hash_file,
     op_crypto_random_uuid,
     op_crypto_wrap_key,
     op_crypto_unwrap_key,
@@ -11,7 +11,10 @@
 /// <reference lib="esnext" />
 
 import { core, primordials } from "ext:core/mod.js";
-import { op_fetch_custom_client } from "ext:core/ops";
+import {
+  op_fetch_client_pool_stats,
+  op_fetch_custom_client,
+} from "ext:core/ops";
 import { loadTlsKeyPair } from "ext:deno_net/02_tls.js";
 
 const { internalRidSymbol } = core;
@@ -30,6 +33,14 @@ const {
  */
 function createHttpClient(options) {
   options.caCerts ??= [];
+  if (options.unixSocketPath !== undefined) {
+    if (options.proxy) {
+      throw new TypeError(
+        "Cannot specify both 'unixSocketPath' and 'proxy' options",
+      );
+    }
+    options.proxy = { transport: "unix", path: options.unixSocketPath };
+  }
   if (options.proxy) {
     if (ObjectHasOwn(options.proxy, "transport")) {
       switch (options.proxy.transport) {
@@ -119,6 +130,10 @@ class HttpClient {
     core.close(this.#rid);
   }
 
+  poolStats() {
+    return op_fetch_client_pool_stats(this.#rid);
+  }
+
   [SymbolDispose]() {
     core.tryClose(this.#rid);
   }
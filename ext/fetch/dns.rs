@@ -8,6 +8,8 @@ use std::task::Poll;
 use std::task::{self};
 use std::vec;
 
+use hickory_resolver::config::NameServerConfigGroup;
+use hickory_resolver::config::ResolverConfig;
 use hickory_resolver::name_server::TokioConnectionProvider;
 use hyper_util::client::legacy::connect::dns::GaiResolver;
 use hyper_util::client::legacy::connect::dns::Name;
@@ -59,6 +61,22 @@ impl Resolver {
     ))
   }
 
+  /// Create a [`AsyncResolver`] which only queries `name_server`.
+  pub fn hickory_with_name_server(name_server: SocketAddr) -> Self {
+    let group = NameServerConfigGroup::from_ips_clear(
+      &[name_server.ip()],
+      name_server.port(),
+      true,
+    );
+    Self::Hickory(
+      hickory_resolver::Resolver::builder_with_config(
+        ResolverConfig::from_parts(None, vec![], group),
+        TokioConnectionProvider::default(),
+      )
+      .build(),
+    )
+  }
+
   pub fn hickory_from_resolver(
     resolver: hickory_resolver::Resolver<TokioConnectionProvider>,
   ) -> Self {
@@ -1,29 +1,47 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
 use std::borrow::Cow;
+use std::future::Future;
+use std::io::SeekFrom;
+use std::pin::Pin;
 use std::rc::Rc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
 
+use bytes::Bytes;
+use deno_core::BufView;
+use deno_core::ByteString;
 use deno_core::CancelFuture;
 use deno_core::OpState;
 use deno_core::futures::FutureExt;
 use deno_core::futures::TryFutureExt;
-use deno_core::futures::TryStreamExt;
 use deno_core::url::Url;
 use deno_error::JsErrorBox;
+use deno_fs::FileSystemRc;
 use deno_fs::OpenOptions;
-use deno_fs::open_options_for_checked_path;
+use deno_fs::range::ByteRange;
+use deno_fs::range::parse_range;
+use deno_io::fs::File;
+use deno_io::fs::FsResult;
 use deno_permissions::OpenAccessKind;
 use deno_permissions::PermissionsContainer;
 use http::StatusCode;
+use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_RANGE;
 use http_body_util::BodyExt;
-use tokio_util::io::ReaderStream;
+use hyper::body::Frame;
+use hyper::body::SizeHint;
 
 use crate::CancelHandle;
 use crate::CancelableResponseFuture;
+use crate::FetchError;
 use crate::FetchHandler;
 
-/// An implementation which tries to read file URLs from the file system via
-/// tokio::fs.
+/// An implementation which reads file URLs through the [`deno_fs::FileSystem`]
+/// in the op state, so embedders with a virtual file system serve file URLs
+/// from it as well. Single `Range` requests are answered with a partial
+/// response.
 #[derive(Clone)]
 pub struct FsFetchHandler;
 
@@ -32,6 +50,7 @@ impl FetchHandler for FsFetchHandler {
     &self,
     state: &mut OpState,
     url: &Url,
+    headers: &[(ByteString, ByteString)],
   ) -> (CancelableResponseFuture, Option<Rc<CancelHandle>>) {
     let cancel_handle = CancelHandle::new_rc();
     let path = match url.to_file_path() {
@@ -40,46 +59,62 @@ impl FetchHandler for FsFetchHandler {
         let fut = async move { Err::<_, _>(()) };
         return (
           fut
-            .map_err(move |_| super::FetchError::NetworkError)
+            .map_err(move |_| FetchError::NetworkError)
             .or_cancel(&cancel_handle)
             .boxed_local(),
           Some(cancel_handle),
         );
       }
     };
-    let path_and_opts_result = {
-      state
-        .borrow::<PermissionsContainer>()
-        .check_open(Cow::Owned(path), OpenAccessKind::Read, Some("fetch()"))
-        .map(|path| {
-          (
-            open_options_for_checked_path(
-              OpenOptions {
-                read: true,
-                ..Default::default()
-              },
-              &path,
-            ),
-            path.into_owned(),
-          )
-        })
-    };
+    let range = headers
+      .iter()
+      .find(|(name, _)| name.eq_ignore_ascii_case(b"range"))
+      .map(|(_, value)| value.to_vec());
+    let fs = state.borrow::<FileSystemRc>().clone();
+    let path_result = state
+      .borrow::<PermissionsContainer>()
+      .check_open(Cow::Owned(path), OpenAccessKind::Read, Some("fetch()"))
+      .map(|path| path.into_owned());
     let response_fut = async move {
-      let (opts, path) = path_and_opts_result?;
-      let file = tokio::fs::OpenOptions::from(opts)
-        .open(path)
-        .await
-        .map_err(|_| super::FetchError::NetworkError)?;
-      let stream = ReaderStream::new(file)
-        .map_ok(hyper::body::Frame::data)
-        .map_err(JsErrorBox::from_err);
-
-      let body = http_body_util::StreamBody::new(stream).boxed();
-      let response = http::Response::builder()
-        .status(StatusCode::OK)
-        .body(body)
-        .map_err(move |_| super::FetchError::NetworkError)?;
-      Ok::<_, _>(response)
+      let path = path_result?;
+      let file = fs.open_async(path, OpenOptions::read()).await?;
+      let stat = file.clone().stat_async().await?;
+      if stat.is_directory {
+        return Err(FetchError::NetworkError);
+      }
+
+      let mut builder = http::Response::builder().status(StatusCode::OK);
+      // Special files such as the ones in procfs report a size of 0, so
+      // only regular files with content get a length and range support.
+      let mut len = None;
+      if stat.is_file && stat.size > 0 {
+        let range = range
+          .as_deref()
+          .map(|range| parse_range(range, stat.size))
+          .unwrap_or(ByteRange::Full);
+        let body_len = match range {
+          ByteRange::Full => stat.size,
+          ByteRange::Partial(start, end) => {
+            file.clone().seek_async(SeekFrom::Start(start)).await?;
+            builder = builder.status(StatusCode::PARTIAL_CONTENT).header(
+              CONTENT_RANGE,
+              format!("bytes {start}-{end}/{}", stat.size),
+            );
+            end - start + 1
+          }
+          ByteRange::Unsatisfiable => {
+            builder = builder
+              .status(StatusCode::RANGE_NOT_SATISFIABLE)
+              .header(CONTENT_RANGE, format!("bytes */{}", stat.size));
+            0
+          }
+        };
+        builder = builder.header(CONTENT_LENGTH, body_len);
+        len = Some(body_len);
+      }
+
+      let body = FileBody::new(file, len).boxed();
+      builder.body(body).map_err(|_| FetchError::NetworkError)
     }
     .or_cancel(&cancel_handle)
     .boxed_local();
@@ -87,3 +122,74 @@ impl FetchHandler for FsFetchHandler {
     (response_fut, Some(cancel_handle))
   }
 }
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams a file, stopping after `remaining` bytes when the length is known.
+struct FileBody {
+  file: Rc<dyn File>,
+  remaining: Option<u64>,
+  read: Option<Pin<Box<dyn Future<Output = FsResult<BufView>>>>>,
+}
+
+impl FileBody {
+  fn new(file: Rc<dyn File>, remaining: Option<u64>) -> Self {
+    Self {
+      file,
+      remaining,
+      read: None,
+    }
+  }
+}
+
+// SAFETY: we only use this on a single-threaded executor
+unsafe impl Send for FileBody {}
+// SAFETY: we only use this on a single-threaded executor
+unsafe impl Sync for FileBody {}
+
+impl hyper::body::Body for FileBody {
+  type Data = Bytes;
+  type Error = JsErrorBox;
+
+  fn poll_frame(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+    let this = self.get_mut();
+    if this.remaining == Some(0) {
+      return Poll::Ready(None);
+    }
+    let limit = match this.remaining {
+      Some(remaining) => remaining.min(CHUNK_SIZE as u64) as usize,
+      None => CHUNK_SIZE,
+    };
+    let file = &this.file;
+    let read = this.read.get_or_insert_with(|| file.clone().read(limit));
+    let res = ready!(read.poll_unpin(cx));
+    this.read = None;
+    match res {
+      Ok(buf) if buf.is_empty() => {
+        this.remaining = Some(0);
+        Poll::Ready(None)
+      }
+      Ok(buf) => {
+        if let Some(remaining) = &mut this.remaining {
+          *remaining = remaining.saturating_sub(buf.len() as u64);
+        }
+        Poll::Ready(Some(Ok(Frame::data(Bytes::from(buf)))))
+      }
+      Err(err) => Poll::Ready(Some(Err(JsErrorBox::from_err(err)))),
+    }
+  }
+
+  fn is_end_stream(&self) -> bool {
+    self.remaining == Some(0)
+  }
+
+  fn size_hint(&self) -> SizeHint {
+    match self.remaining {
+      Some(remaining) => SizeHint::with_exact(remaining),
+      None => SizeHint::default(),
+    }
+  }
+}
@@ -2,7 +2,9 @@
 
 pub mod dns;
 mod fs_fetch_handler;
+mod pool_stats;
 mod proxy;
+mod request_compression;
 #[cfg(test)]
 mod tests;
 
@@ -13,6 +15,7 @@ use std::convert::From;
 use std::future;
 use std::future::Future;
 use std::net::IpAddr;
+use std::net::SocketAddr;
 use std::path::Path;
 #[cfg(not(windows))]
 use std::path::PathBuf;
@@ -69,6 +72,7 @@ use http::Uri;
 use http::header::ACCEPT;
 use http::header::ACCEPT_ENCODING;
 use http::header::AUTHORIZATION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::HOST;
 use http::header::HeaderName;
@@ -86,7 +90,10 @@ use hyper_util::client::legacy::connect::HttpInfo;
 use hyper_util::rt::TokioExecutor;
 use hyper_util::rt::TokioIo;
 use hyper_util::rt::TokioTimer;
+pub use pool_stats::OriginPoolStats;
+use pool_stats::PoolStats;
 pub use proxy::basic_auth;
+pub use request_compression::RequestCompression;
 use serde::Deserialize;
 use serde::Serialize;
 use tower::BoxError;
@@ -151,6 +158,7 @@ deno_core::extension!(deno_fetch,
     op_fetch_send,
     op_utf8_to_byte_string,
     op_fetch_custom_client,
+    op_fetch_client_pool_stats,
     op_fetch_promise_is_settled,
   ],
   esm = [
@@ -246,6 +254,7 @@ impl From<deno_fs::FsError> for FetchError {
     match value {
       deno_fs::FsError::Io(_)
       | deno_fs::FsError::FileBusy
+      | deno_fs::FsError::Overloaded(_)
       | deno_fs::FsError::NotSupported => FetchError::NetworkError,
       deno_fs::FsError::PermissionCheck(err) => {
         FetchError::PermissionCheck(err)
@@ -265,6 +274,7 @@ pub trait FetchHandler: dyn_clone::DynClone {
     &self,
     state: &mut OpState,
     url: &Url,
+    headers: &[(ByteString, ByteString)],
   ) -> (CancelableResponseFuture, Option<Rc<CancelHandle>>);
 }
 
@@ -279,6 +289,7 @@ impl FetchHandler for DefaultFileFetchHandler {
     &self,
     _state: &mut OpState,
     _url: &Url,
+    _headers: &[(ByteString, ByteString)],
   ) -> (CancelableResponseFuture, Option<Rc<CancelHandle>>) {
     let fut = async move { Ok(Err(FetchError::NetworkError)) };
     (Box::pin(fut), None)
@@ -327,8 +338,11 @@ pub fn create_client_from_options(
         .unwrap_or_default(),
       pool_max_idle_per_host: None,
       pool_idle_timeout: None,
+      http2_keep_alive_interval: None,
+      http2_keep_alive_timeout: None,
       http1: true,
       http2: true,
+      http2_prior_knowledge: false,
       local_address: None,
       client_builder_hook: options.client_builder_hook,
     },
@@ -418,11 +432,12 @@ pub fn op_fetch(
   #[buffer] data: Option<JsBuffer>,
   #[smi] resource: Option<ResourceId>,
 ) -> Result<FetchReturn, FetchError> {
-  let (client, allow_host) = if let Some(rid) = client_rid {
+  let (client, allow_host, request_compression) = if let Some(rid) = client_rid
+  {
     let r = state.resource_table.get::<HttpClientResource>(rid)?;
-    (r.client.clone(), r.allow_host)
+    (r.client.clone(), r.allow_host, r.request_compression)
   } else {
-    (get_or_create_client_from_state(state)?, false)
+    (get_or_create_client_from_state(state)?, false, None)
   };
 
   let method = Method::from_bytes(&method)?;
@@ -440,7 +455,7 @@ pub fn op_fetch(
       } = state.borrow_mut::<Options>();
       let file_fetch_handler = file_fetch_handler.clone();
       let (future, maybe_cancel_handle) =
-        file_fetch_handler.fetch_file(state, &url);
+        file_fetch_handler.fetch_file(state, &url, &headers);
       let request_rid = state
         .resource_table
         .add(FetchRequestResource { future, url });
@@ -489,6 +504,21 @@ pub fn op_fetch(
         ReqBody::empty()
       };
 
+      // Bodies which are already encoded are sent as they are.
+      let compression = request_compression.filter(|_| {
+        has_body
+          && !headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(b"content-encoding"))
+      });
+      let body = match compression {
+        Some(compression) => {
+          con_len = None;
+          compression.compress(body)
+        }
+        None => body,
+      };
+
       let mut request = http::Request::new(body);
       *request.method_mut() = method.clone();
       *request.uri_mut() = uri.clone();
@@ -512,6 +542,12 @@ pub fn op_fetch(
         }
       }
 
+      if let Some(compression) = compression {
+        request
+          .headers_mut()
+          .insert(CONTENT_ENCODING, compression.content_encoding());
+      }
+
       if request.headers().contains_key(RANGE) {
         // https://fetch.spec.whatwg.org/#http-network-or-cache-fetch step 18
         // If httpRequest’s header list contains `Range`, then append (`Accept-Encoding`, `identity`)
@@ -802,6 +838,8 @@ impl Resource for FetchResponseResource {
 pub struct HttpClientResource {
   pub client: Client,
   pub allow_host: bool,
+  /// Encoding applied to the bodies of requests sent with this client.
+  pub request_compression: Option<RequestCompression>,
 }
 
 impl Resource for HttpClientResource {
@@ -811,8 +849,16 @@ impl Resource for HttpClientResource {
 }
 
 impl HttpClientResource {
-  fn new(client: Client, allow_host: bool) -> Self {
-    Self { client, allow_host }
+  fn new(
+    client: Client,
+    allow_host: bool,
+    request_compression: Option<RequestCompression>,
+  ) -> Self {
+    Self {
+      client,
+      allow_host,
+      request_compression,
+    }
   }
 }
 
@@ -823,19 +869,37 @@ pub struct CreateHttpClientArgs {
   proxy: Option<Proxy>,
   pool_max_idle_per_host: Option<usize>,
   pool_idle_timeout: Option<serde_json::Value>,
+  http2_keep_alive_interval: Option<u64>,
+  http2_keep_alive_timeout: Option<u64>,
   #[serde(default = "default_true")]
   http1: bool,
   #[serde(default = "default_true")]
   http2: bool,
   #[serde(default)]
+  http2_prior_knowledge: bool,
+  #[serde(default)]
   allow_host: bool,
   local_address: Option<String>,
+  name_server: Option<NameServer>,
+  compress_request_body: Option<RequestCompression>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct NameServer {
+  ip_addr: String,
+  #[serde(default = "default_name_server_port")]
+  port: u16,
 }
 
 fn default_true() -> bool {
   true
 }
 
+fn default_name_server_port() -> u16 {
+  53
+}
+
 #[op2(stack_trace)]
 #[smi]
 #[allow(clippy::result_large_err)]
@@ -877,6 +941,21 @@ pub fn op_fetch_custom_client(
     }
   }
 
+  let dns_resolver = match &args.name_server {
+    Some(name_server) => {
+      let ip = name_server.ip_addr.parse().map_err(|_| {
+        HttpClientCreateError::InvalidAddress(name_server.ip_addr.clone())
+      })?;
+      let name_server = SocketAddr::new(ip, name_server.port);
+      state.borrow_mut::<PermissionsContainer>().check_net(
+        &(&ip.to_string(), Some(name_server.port())),
+        "Deno.createHttpClient()",
+      )?;
+      dns::Resolver::hickory_with_name_server(name_server)
+    }
+    None => dns::Resolver::default(),
+  };
+
   let options = state.borrow::<Options>();
   let ca_certs = args
     .ca_certs
@@ -892,7 +971,7 @@ pub fn op_fetch_custom_client(
         .map_err(HttpClientCreateError::RootCertStore)?,
       ca_certs,
       proxy: args.proxy,
-      dns_resolver: dns::Resolver::default(),
+      dns_resolver,
       unsafely_ignore_certificate_errors: options
         .unsafely_ignore_certificate_errors
         .clone(),
@@ -908,19 +987,34 @@ pub fn op_fetch_custom_client(
           _ => Some(None),
         },
       ),
+      http2_keep_alive_interval: args.http2_keep_alive_interval,
+      http2_keep_alive_timeout: args.http2_keep_alive_timeout,
       http1: args.http1,
       http2: args.http2,
+      http2_prior_knowledge: args.http2_prior_knowledge,
       local_address: args.local_address,
       client_builder_hook: options.client_builder_hook,
     },
   )?;
 
-  let rid = state
-    .resource_table
-    .add(HttpClientResource::new(client, args.allow_host));
+  let rid = state.resource_table.add(HttpClientResource::new(
+    client,
+    args.allow_host,
+    args.compress_request_body,
+  ));
   Ok(rid)
 }
 
+#[op2]
+#[serde]
+pub fn op_fetch_client_pool_stats(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+) -> Result<Vec<OriginPoolStats>, deno_core::error::ResourceError> {
+  let r = state.resource_table.get::<HttpClientResource>(rid)?;
+  Ok(r.client.pool_stats())
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateHttpClientOptions {
   pub root_cert_store: Option<RootCertStore>,
@@ -931,8 +1025,17 @@ pub struct CreateHttpClientOptions {
   pub client_cert_chain_and_key: Option<TlsKey>,
   pub pool_max_idle_per_host: Option<usize>,
   pub pool_idle_timeout: Option<Option<u64>>,
+  /// Interval in milliseconds between HTTP/2 `PING` frames sent to check
+  /// that a connection is still alive, including idle pooled connections.
+  pub http2_keep_alive_interval: Option<u64>,
+  /// How long in milliseconds to wait for the acknowledgement of a `PING`
+  /// before the connection is closed.
+  pub http2_keep_alive_timeout: Option<u64>,
   pub http1: bool,
   pub http2: bool,
+  /// Speak HTTP/2 on cleartext `http:` connections without negotiating it
+  /// first (h2c with prior knowledge). TLS connections still use ALPN.
+  pub http2_prior_knowledge: bool,
   pub local_address: Option<String>,
   pub client_builder_hook: Option<fn(HyperClientBuilder) -> HyperClientBuilder>,
 }
@@ -948,8 +1051,11 @@ impl Default for CreateHttpClientOptions {
       client_cert_chain_and_key: None,
       pool_max_idle_per_host: None,
       pool_idle_timeout: None,
+      http2_keep_alive_interval: None,
+      http2_keep_alive_timeout: None,
       http1: true,
       http2: true,
+      http2_prior_knowledge: false,
       local_address: None,
       client_builder_hook: None,
     }
@@ -971,6 +1077,10 @@ pub enum HttpClientCreateError {
     "Cannot create Http Client: either `http1` or `http2` needs to be set to true"
   )]
   HttpVersionSelectionInvalid,
+  #[error(
+    "Cannot create Http Client: `http2PriorKnowledge` requires `http2` to be set to true"
+  )]
+  Http2PriorKnowledgeWithoutHttp2,
   #[class(inherit)]
   #[error(transparent)]
   RootCertStore(JsErrorBox),
@@ -1089,6 +1199,7 @@ pub fn create_http_client(
     tls: tls_config,
     tls_proxy: proxy_tls_config,
     user_agent: Some(user_agent.clone()),
+    http2_prior_knowledge: options.http2_prior_knowledge,
   };
 
   if let Some(pool_max_idle_per_host) = options.pool_max_idle_per_host {
@@ -1101,6 +1212,16 @@ pub fn create_http_client(
     );
   }
 
+  if let Some(interval) = options.http2_keep_alive_interval {
+    builder
+      .http2_keep_alive_interval(std::time::Duration::from_millis(interval))
+      .http2_keep_alive_while_idle(true);
+  }
+
+  if let Some(timeout) = options.http2_keep_alive_timeout {
+    builder.http2_keep_alive_timeout(std::time::Duration::from_millis(timeout));
+  }
+
   match (options.http1, options.http2) {
     (true, false) => {} // noop, handled by ALPN above
     (false, true) => {
@@ -1112,14 +1233,26 @@ pub fn create_http_client(
     }
   }
 
-  let pooled_client = builder.build(connector.clone());
+  if options.http2_prior_knowledge && !options.http2 {
+    return Err(HttpClientCreateError::Http2PriorKnowledgeWithoutHttp2);
+  }
+
+  let pool_stats = PoolStats::default();
+  let pooled_client = builder.build(pool_stats::TrackedConnector::new(
+    connector.clone(),
+    pool_stats.clone(),
+  ));
   let retry_client = retry::Retry::new(FetchRetry, pooled_client);
-  let decompress = Decompression::new(retry_client).gzip(true).br(true);
+  let decompress = Decompression::new(retry_client)
+    .gzip(true)
+    .br(true)
+    .zstd(true);
 
   Ok(Client {
     inner: decompress,
     connector,
     user_agent,
+    pool_stats,
   })
 }
 
@@ -1134,11 +1267,15 @@ pub struct Client {
   inner: Decompression<
     retry::Retry<
       FetchRetry,
-      hyper_util::client::legacy::Client<Connector, ReqBody>,
+      hyper_util::client::legacy::Client<
+        pool_stats::TrackedConnector<Connector>,
+        ReqBody,
+      >,
     >,
   >,
   connector: Connector,
   user_agent: HeaderValue,
+  pool_stats: PoolStats,
 }
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
@@ -1155,6 +1292,10 @@ pub enum ClientConnectError {
 }
 
 impl Client {
+  pub fn pool_stats(&self) -> Vec<OriginPoolStats> {
+    self.pool_stats.snapshot()
+  }
+
   pub async fn connect(
     &self,
     uri: Uri,
@@ -1297,13 +1438,21 @@ impl Client {
     req.headers_mut().entry(ACCEPT).or_insert(STAR_STAR);
 
     let uri = req.uri().clone();
+    let request_guard = self.pool_stats.request_started(&uri);
 
     let resp = self
       .inner
       .oneshot(req)
       .await
       .map_err(|e| ClientSendError { uri, source: e })?;
-    Ok(resp.map(|b| b.map_err(|e| JsErrorBox::generic(e.to_string())).boxed()))
+    Ok(resp.map(|b| {
+      b.map_err(move |e| {
+        // The request stays in flight for as long as its body is alive.
+        let _request_guard = &request_guard;
+        JsErrorBox::generic(e.to_string())
+      })
+      .boxed()
+    }))
   }
 }
 
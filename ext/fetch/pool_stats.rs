@@ -0,0 +1,271 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Bookkeeping of the connections a client keeps per origin, for
+//! `Deno.HttpClient.poolStats()`. hyper's pool does not expose its state, so
+//! connections are counted as the connector hands them out and as they are
+//! dropped, and requests while they are in flight.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+
+use http::Uri;
+use http::uri::Scheme;
+use hyper::rt::ReadBufCursor;
+use hyper_util::client::legacy::connect::Connected;
+use hyper_util::client::legacy::connect::Connection;
+use serde::Serialize;
+use tower_service::Service;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct OriginCounts {
+  connections: usize,
+  requests: usize,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PoolStats(Arc<Mutex<BTreeMap<String, OriginCounts>>>);
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OriginPoolStats {
+  origin: String,
+  /// Connections currently open to the origin.
+  connections: usize,
+  /// Open connections without a request in flight. HTTP/2 connections can
+  /// carry several requests at once, so this is a lower bound.
+  idle_connections: usize,
+  /// Requests sent whose response body has not been consumed yet.
+  active_requests: usize,
+}
+
+impl PoolStats {
+  fn update(&self, origin: &str, f: impl FnOnce(&mut OriginCounts)) {
+    let mut origins = self.0.lock().unwrap();
+    let counts = origins.entry(origin.to_string()).or_default();
+    f(counts);
+    if counts.connections == 0 && counts.requests == 0 {
+      origins.remove(origin);
+    }
+  }
+
+  fn connection_opened(&self, origin: String) -> Guard {
+    self.update(&origin, |counts| counts.connections += 1);
+    Guard {
+      stats: self.clone(),
+      origin,
+      kind: GuardKind::Connection,
+    }
+  }
+
+  /// Counts a request to `uri` as in flight until the guard is dropped.
+  pub fn request_started(&self, uri: &Uri) -> Guard {
+    let origin = origin(uri);
+    self.update(&origin, |counts| counts.requests += 1);
+    Guard {
+      stats: self.clone(),
+      origin,
+      kind: GuardKind::Request,
+    }
+  }
+
+  pub fn snapshot(&self) -> Vec<OriginPoolStats> {
+    self
+      .0
+      .lock()
+      .unwrap()
+      .iter()
+      .map(|(origin, counts)| OriginPoolStats {
+        origin: origin.clone(),
+        connections: counts.connections,
+        idle_connections: counts.connections.saturating_sub(counts.requests),
+        active_requests: counts.requests,
+      })
+      .collect()
+  }
+}
+
+/// The origin of `uri` in its serialized form, with the default port left
+/// out like in `URL.prototype.origin`.
+fn origin(uri: &Uri) -> String {
+  let scheme = uri.scheme().unwrap_or(&Scheme::HTTP);
+  let host = uri.host().unwrap_or_default();
+  let default_port = if scheme == &Scheme::HTTPS { 443 } else { 80 };
+  match uri.port_u16() {
+    Some(port) if port != default_port => format!("{scheme}://{host}:{port}"),
+    _ => format!("{scheme}://{host}"),
+  }
+}
+
+#[derive(Debug)]
+enum GuardKind {
+  Connection,
+  Request,
+}
+
+#[derive(Debug)]
+pub struct Guard {
+  stats: PoolStats,
+  origin: String,
+  kind: GuardKind,
+}
+
+impl Drop for Guard {
+  fn drop(&mut self) {
+    self.stats.update(&self.origin, |counts| match self.kind {
+      GuardKind::Connection => counts.connections -= 1,
+      GuardKind::Request => counts.requests -= 1,
+    });
+  }
+}
+
+/// A connector that counts the connections it opens in [`PoolStats`].
+#[derive(Debug, Clone)]
+pub struct TrackedConnector<C> {
+  inner: C,
+  stats: PoolStats,
+}
+
+impl<C> TrackedConnector<C> {
+  pub fn new(inner: C, stats: PoolStats) -> Self {
+    Self { inner, stats }
+  }
+}
+
+impl<C> Service<Uri> for TrackedConnector<C>
+where
+  C: Service<Uri>,
+  C::Future: Send + 'static,
+{
+  type Response = Tracked<C::Response>;
+  type Error = C::Error;
+  type Future = BoxFuture<Result<Self::Response, Self::Error>>;
+
+  fn poll_ready(
+    &mut self,
+    cx: &mut Context<'_>,
+  ) -> Poll<Result<(), Self::Error>> {
+    self.inner.poll_ready(cx)
+  }
+
+  fn call(&mut self, dst: Uri) -> Self::Future {
+    let origin = origin(&dst);
+    let stats = self.stats.clone();
+    let connecting = self.inner.call(dst);
+    Box::pin(async move {
+      let io = connecting.await?;
+      Ok(Tracked {
+        io,
+        _guard: stats.connection_opened(origin),
+      })
+    })
+  }
+}
+
+/// A connection which stops being counted once hyper drops it.
+pub struct Tracked<T> {
+  io: T,
+  _guard: Guard,
+}
+
+impl<T> hyper::rt::Read for Tracked<T>
+where
+  T: hyper::rt::Read + Unpin,
+{
+  fn poll_read(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: ReadBufCursor<'_>,
+  ) -> Poll<io::Result<()>> {
+    Pin::new(&mut self.get_mut().io).poll_read(cx, buf)
+  }
+}
+
+impl<T> hyper::rt::Write for Tracked<T>
+where
+  T: hyper::rt::Write + Unpin,
+{
+  fn poll_write(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &[u8],
+  ) -> Poll<io::Result<usize>> {
+    Pin::new(&mut self.get_mut().io).poll_write(cx, buf)
+  }
+
+  fn poll_flush(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<io::Result<()>> {
+    Pin::new(&mut self.get_mut().io).poll_flush(cx)
+  }
+
+  fn poll_shutdown(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<io::Result<()>> {
+    Pin::new(&mut self.get_mut().io).poll_shutdown(cx)
+  }
+
+  fn is_write_vectored(&self) -> bool {
+    self.io.is_write_vectored()
+  }
+
+  fn poll_write_vectored(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    bufs: &[io::IoSlice<'_>],
+  ) -> Poll<io::Result<usize>> {
+    Pin::new(&mut self.get_mut().io).poll_write_vectored(cx, bufs)
+  }
+}
+
+impl<T: Connection> Connection for Tracked<T> {
+  fn connected(&self) -> Connected {
+    self.io.connected()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn counts_connections_and_requests_per_origin() {
+    let stats = PoolStats::default();
+    let uri = Uri::from_static("https://deno.land:443/x");
+    let connection = stats.connection_opened(origin(&uri));
+    let other = stats.connection_opened("http://localhost:4545".into());
+    let request = stats.request_started(&uri);
+
+    assert_eq!(
+      stats.snapshot(),
+      vec![
+        OriginPoolStats {
+          origin: "http://localhost:4545".into(),
+          connections: 1,
+          idle_connections: 1,
+          active_requests: 0,
+        },
+        OriginPoolStats {
+          origin: "https://deno.land".into(),
+          connections: 1,
+          idle_connections: 0,
+          active_requests: 1,
+        },
+      ]
+    );
+
+    drop(request);
+    drop(connection);
+    drop(other);
+    assert_eq!(stats.snapshot(), vec![]);
+  }
+}
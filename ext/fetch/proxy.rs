@@ -49,6 +49,8 @@ pub(crate) struct ProxyConnector<C> {
   /// Notably, does not include ALPN
   pub(crate) tls_proxy: Arc<TlsConfig>,
   pub(crate) user_agent: Option<HeaderValue>,
+  /// Use HTTP/2 on cleartext connections that are not proxied
+  pub(crate) http2_prior_knowledge: bool,
 }
 
 impl<C> ProxyConnector<C> {
@@ -67,6 +69,7 @@ impl<C> ProxyConnector<C> {
       tls: Arc::new(tls),
       tls_proxy: self.tls_proxy,
       user_agent: self.user_agent,
+      http2_prior_knowledge: false,
     })
   }
 
@@ -85,6 +88,7 @@ impl<C> ProxyConnector<C> {
       tls: Arc::new(tls),
       tls_proxy: self.tls_proxy,
       user_agent: self.user_agent,
+      http2_prior_knowledge: self.http2_prior_knowledge,
     })
   }
 }
@@ -546,6 +550,8 @@ type BoxError = Box<dyn std::error::Error + Send + Sync>;
 pub enum Proxied<T> {
   /// Not proxied
   PassThrough(T),
+  /// Not proxied, speaking HTTP/2 over cleartext with prior knowledge
+  H2c(T),
   /// Forwarded via TCP socket
   Tcp(T),
   /// Tunneled through HTTP CONNECT
@@ -694,12 +700,20 @@ where
       };
     }
 
+    let h2c =
+      self.http2_prior_knowledge && orig_dst.scheme() == Some(&Scheme::HTTP);
     let mut connector =
       HttpsConnector::from((self.http.clone(), self.tls.clone()));
     Box::pin(
       connector
         .call(orig_dst)
-        .map_ok(Proxied::PassThrough)
+        .map_ok(move |io| {
+          if h2c {
+            Proxied::H2c(io)
+          } else {
+            Proxied::PassThrough(io)
+          }
+        })
         .map_err(Into::into),
     )
   }
@@ -796,6 +810,7 @@ where
   ) -> Poll<Result<(), std::io::Error>> {
     match *self {
       Proxied::PassThrough(ref mut p) => Pin::new(p).poll_read(cx, buf),
+      Proxied::H2c(ref mut p) => Pin::new(p).poll_read(cx, buf),
       Proxied::Tcp(ref mut p) => Pin::new(p).poll_read(cx, buf),
       Proxied::HttpTunneled(ref mut p) => Pin::new(p).poll_read(cx, buf),
       Proxied::Socks(ref mut p) => Pin::new(p).poll_read(cx, buf),
@@ -823,6 +838,7 @@ where
   ) -> Poll<Result<usize, std::io::Error>> {
     match *self {
       Proxied::PassThrough(ref mut p) => Pin::new(p).poll_write(cx, buf),
+      Proxied::H2c(ref mut p) => Pin::new(p).poll_write(cx, buf),
       Proxied::Tcp(ref mut p) => Pin::new(p).poll_write(cx, buf),
       Proxied::HttpTunneled(ref mut p) => Pin::new(p).poll_write(cx, buf),
       Proxied::Socks(ref mut p) => Pin::new(p).poll_write(cx, buf),
@@ -844,6 +860,7 @@ where
   ) -> Poll<Result<(), std::io::Error>> {
     match *self {
       Proxied::PassThrough(ref mut p) => Pin::new(p).poll_flush(cx),
+      Proxied::H2c(ref mut p) => Pin::new(p).poll_flush(cx),
       Proxied::Tcp(ref mut p) => Pin::new(p).poll_flush(cx),
       Proxied::HttpTunneled(ref mut p) => Pin::new(p).poll_flush(cx),
       Proxied::Socks(ref mut p) => Pin::new(p).poll_flush(cx),
@@ -865,6 +882,7 @@ where
   ) -> Poll<Result<(), std::io::Error>> {
     match *self {
       Proxied::PassThrough(ref mut p) => Pin::new(p).poll_shutdown(cx),
+      Proxied::H2c(ref mut p) => Pin::new(p).poll_shutdown(cx),
       Proxied::Tcp(ref mut p) => Pin::new(p).poll_shutdown(cx),
       Proxied::HttpTunneled(ref mut p) => Pin::new(p).poll_shutdown(cx),
       Proxied::Socks(ref mut p) => Pin::new(p).poll_shutdown(cx),
@@ -883,6 +901,7 @@ where
   fn is_write_vectored(&self) -> bool {
     match *self {
       Proxied::PassThrough(ref p) => p.is_write_vectored(),
+      Proxied::H2c(ref p) => p.is_write_vectored(),
       Proxied::Tcp(ref p) => p.is_write_vectored(),
       Proxied::HttpTunneled(ref p) => p.is_write_vectored(),
       Proxied::Socks(ref p) => p.is_write_vectored(),
@@ -907,6 +926,7 @@ where
       Proxied::PassThrough(ref mut p) => {
         Pin::new(p).poll_write_vectored(cx, bufs)
       }
+      Proxied::H2c(ref mut p) => Pin::new(p).poll_write_vectored(cx, bufs),
       Proxied::Tcp(ref mut p) => Pin::new(p).poll_write_vectored(cx, bufs),
       Proxied::HttpTunneled(ref mut p) => {
         Pin::new(p).poll_write_vectored(cx, bufs)
@@ -932,6 +952,7 @@ where
   fn connected(&self) -> Connected {
     match self {
       Proxied::PassThrough(p) => p.connected(),
+      Proxied::H2c(p) => p.connected().negotiated_h2(),
       Proxied::Tcp(p) => p.connected().proxy(true),
       Proxied::HttpTunneled(p) => {
         let tunneled_tls = p.inner().get_ref();
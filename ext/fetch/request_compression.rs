@@ -0,0 +1,62 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Compression of request bodies sent by clients created with
+//! `Deno.createHttpClient({ compressRequestBody })`.
+
+use std::io;
+
+use async_compression::tokio::bufread::BrotliEncoder;
+use async_compression::tokio::bufread::GzipEncoder;
+use async_compression::tokio::bufread::ZstdEncoder;
+use deno_core::futures::TryStreamExt;
+use deno_error::JsErrorBox;
+use http::header::HeaderValue;
+use http_body_util::BodyExt;
+use http_body_util::StreamBody;
+use hyper::body::Frame;
+use serde::Deserialize;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+use tokio_util::io::StreamReader;
+
+use crate::ReqBody;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestCompression {
+  Gzip,
+  Br,
+  Zstd,
+}
+
+impl RequestCompression {
+  pub fn content_encoding(self) -> HeaderValue {
+    HeaderValue::from_static(match self {
+      Self::Gzip => "gzip",
+      Self::Br => "br",
+      Self::Zstd => "zstd",
+    })
+  }
+
+  /// Compresses `body` while it is sent. The compressed length is not known
+  /// up front, so the request goes out without a `content-length`.
+  pub fn compress(self, body: ReqBody) -> ReqBody {
+    let reader =
+      StreamReader::new(body.into_data_stream().map_err(io::Error::other));
+    match self {
+      Self::Gzip => encoded(GzipEncoder::new(reader)),
+      Self::Br => encoded(BrotliEncoder::new(reader)),
+      Self::Zstd => encoded(ZstdEncoder::new(reader)),
+    }
+  }
+}
+
+fn encoded<R>(encoder: R) -> ReqBody
+where
+  R: AsyncRead + Send + Sync + 'static,
+{
+  let stream = ReaderStream::new(encoder)
+    .map_ok(Frame::data)
+    .map_err(JsErrorBox::from_err);
+  ReqBody::streaming(StreamBody::new(stream))
+}
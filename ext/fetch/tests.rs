@@ -119,9 +119,12 @@ async fn rust_test_client_with_resolver(
       client_cert_chain_and_key: None,
       pool_max_idle_per_host: None,
       pool_idle_timeout: None,
+      http2_keep_alive_interval: None,
+      http2_keep_alive_timeout: None,
       dns_resolver: resolver,
       http1: true,
       http2: true,
+      http2_prior_knowledge: false,
       local_address: None,
       client_builder_hook: None,
     },
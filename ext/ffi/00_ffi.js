@@ -396,11 +396,6 @@ class UnsafeCallback {
   pointer;
 
   constructor(definition, callback) {
-    if (definition.nonblocking) {
-      throw new TypeError(
-        "Cannot construct UnsafeCallback: cannot be nonblocking",
-      );
-    }
     const { 0: rid, 1: pointer } = op_ffi_unsafe_callback_create(
       definition,
       callback,
@@ -2,6 +2,7 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::ffi::c_void;
 use std::future::Future;
 use std::future::IntoFuture;
@@ -9,7 +10,10 @@ use std::pin::Pin;
 use std::ptr;
 use std::ptr::NonNull;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::atomic;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU32;
 use std::task::Poll;
 
@@ -21,6 +25,7 @@ use deno_core::ResourceId;
 use deno_core::V8CrossThreadTaskSpawner;
 use deno_core::op2;
 use deno_core::v8;
+use deno_error::JsErrorBox;
 use deno_permissions::PermissionsContainer;
 use libffi::middle::Cif;
 use serde::Deserialize;
@@ -105,6 +110,75 @@ struct CallbackInfo {
   pub parameters: Box<[NativeType]>,
   pub result: NativeType,
   pub thread_id: u32,
+  /// The queue of calls from foreign threads, for nonblocking callbacks.
+  pub queue: Option<Arc<CallQueue>>,
+}
+
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum DropPolicy {
+  /// Drop the oldest queued call to make room for the new one.
+  #[default]
+  Oldest,
+  /// Drop the new call.
+  Newest,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NonblockingOptions {
+  #[serde(default = "default_queue_size")]
+  queue_size: usize,
+  #[serde(default)]
+  drop: DropPolicy,
+}
+
+fn default_queue_size() -> usize {
+  1024
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum NonblockingArg {
+  Enabled(bool),
+  Options(NonblockingOptions),
+}
+
+/// The arguments of a call, copied out of the foreign thread's stack. Each
+/// argument is kept in words, so that it is aligned for reading it back.
+struct QueuedCall(Vec<Box<[u64]>>);
+
+/// The bounded queue of a nonblocking callback. Foreign threads push their
+/// calls to it and return immediately, and the calls are run by tasks on the
+/// event loop of the isolate.
+struct CallQueue {
+  calls: Mutex<VecDeque<QueuedCall>>,
+  max_size: usize,
+  drop: DropPolicy,
+  /// Set once the callback is closed, after which the callback info must
+  /// not be read.
+  closed: AtomicBool,
+}
+
+impl CallQueue {
+  /// Queues a call, returning `false` if it was dropped.
+  fn push(&self, call: QueuedCall) -> bool {
+    let mut calls = self.calls.lock().unwrap();
+    if calls.len() >= self.max_size {
+      match self.drop {
+        DropPolicy::Oldest => {
+          calls.pop_front();
+        }
+        DropPolicy::Newest => return false,
+      }
+    }
+    calls.push_back(call);
+    true
+  }
+
+  fn pop(&self) -> Option<QueuedCall> {
+    self.calls.lock().unwrap().pop_front()
+  }
 }
 
 impl Future for CallbackInfo {
@@ -173,6 +247,43 @@ unsafe extern "C" fn deno_ffi_callback(
         v8::scope!(scope, cb_scope);
 
         do_ffi_callback(scope, cif, info, result, args);
+      } else if let Some(queue) = &info.queue {
+        let call = QueuedCall(copy_args(cif, args));
+        if !queue.push(call) {
+          return;
+        }
+        let queue = queue.clone();
+        let async_work_sender = &info.async_work_sender;
+        let cif = SendPtr(NonNull::from(cif));
+        let info = SendPtr(NonNull::from(info));
+        async_work_sender.spawn(move |scope: &mut v8::PinScope<'_, '_>| {
+          // the callback info and cif are freed once the callback is closed,
+          // which happens on this thread
+          if queue.closed.load(atomic::Ordering::Acquire) {
+            return;
+          }
+          let Some(call) = queue.pop() else {
+            // the call was dropped to make room for a newer one
+            return;
+          };
+          let args = call
+            .0
+            .iter()
+            .map(|arg| arg.as_ptr() as *const c_void)
+            .collect::<Vec<_>>();
+          let mut result = 0u64;
+          v8::tc_scope!(tc_scope, scope);
+          do_ffi_callback(
+            tc_scope,
+            cif.0.as_ref(),
+            info.0.as_ref(),
+            &mut *(&mut result as *mut u64 as *mut c_void),
+            args.as_ptr(),
+          );
+          if tc_scope.exception().is_some() {
+            log::error!("Illegal unhandled exception in nonblocking callback");
+          }
+        });
       } else {
         let async_work_sender = &info.async_work_sender;
 
@@ -196,6 +307,40 @@ unsafe extern "C" fn deno_ffi_callback(
   }
 }
 
+struct SendPtr<T>(NonNull<T>);
+
+// SAFETY: only sent to the thread of the isolate, which owns the pointee.
+unsafe impl<T> Send for SendPtr<T> {}
+
+/// Copies the arguments of a call, which only live as long as the call.
+///
+/// # Safety
+///
+/// `args` must point to the arguments of `cif`.
+unsafe fn copy_args(
+  cif: &libffi::low::ffi_cif,
+  args: *const *const c_void,
+) -> Vec<Box<[u64]>> {
+  let nargs = cif.nargs as usize;
+  let mut copied = Vec::with_capacity(nargs);
+  for index in 0..nargs {
+    // SAFETY: the cif has `nargs` argument types and the call as many
+    // arguments, each of the size of its type.
+    unsafe {
+      let size = (**cif.arg_types.add(index)).size;
+      let mut arg =
+        vec![0u64; size.div_ceil(size_of::<u64>()).max(1)].into_boxed_slice();
+      std::ptr::copy_nonoverlapping(
+        *args.add(index) as *const u8,
+        arg.as_mut_ptr() as *mut u8,
+        size,
+      );
+      copied.push(arg);
+    }
+  }
+  copied
+}
+
 unsafe fn do_ffi_callback(
   scope: &mut v8::PinScope<'_, '_>,
   cif: &libffi::low::ffi_cif,
@@ -572,6 +717,8 @@ pub fn op_ffi_unsafe_callback_ref(
 pub struct RegisterCallbackArgs {
   parameters: Vec<NativeType>,
   result: NativeType,
+  #[serde(default)]
+  nonblocking: Option<NonblockingArg>,
 }
 
 #[op2(stack_trace)]
@@ -599,6 +746,42 @@ pub fn op_ffi_unsafe_callback_create<'scope>(
     panic!("Isolate ID counter overflowed u32");
   }
 
+  let nonblocking = match args.nonblocking {
+    None | Some(NonblockingArg::Enabled(false)) => None,
+    Some(NonblockingArg::Enabled(true)) => Some(NonblockingOptions {
+      queue_size: default_queue_size(),
+      drop: DropPolicy::default(),
+    }),
+    Some(NonblockingArg::Options(options)) => Some(options),
+  };
+  let queue = match nonblocking {
+    Some(options) => {
+      if args.result != NativeType::Void {
+        return Err(
+          JsErrorBox::type_error(
+            "Cannot construct UnsafeCallback: nonblocking callbacks must return void",
+          )
+          .into(),
+        );
+      }
+      if options.queue_size == 0 {
+        return Err(
+          JsErrorBox::type_error(
+            "Cannot construct UnsafeCallback: queueSize must be greater than 0",
+          )
+          .into(),
+        );
+      }
+      Some(Arc::new(CallQueue {
+        calls: Mutex::new(VecDeque::new()),
+        max_size: options.queue_size,
+        drop: options.drop,
+        closed: AtomicBool::new(false),
+      }))
+    }
+    None => None,
+  };
+
   let async_work_sender = state.borrow::<V8CrossThreadTaskSpawner>().clone();
 
   let callback = v8::Global::new(scope, cb).into_raw();
@@ -612,6 +795,7 @@ pub fn op_ffi_unsafe_callback_create<'scope>(
     parameters: args.parameters.clone().into(),
     result: args.result.clone(),
     thread_id,
+    queue,
   }));
   let cif = Cif::new(
     args
@@ -658,6 +842,9 @@ pub fn op_ffi_unsafe_callback_close(
     let callback_resource =
       state.resource_table.take::<UnsafeCallbackResource>(rid)?;
     let info = Box::from_raw(callback_resource.info);
+    if let Some(queue) = &info.queue {
+      queue.closed.store(true, atomic::Ordering::Release);
+    }
     let _ = v8::Global::from_raw(scope, info.callback);
     let _ = v8::Global::from_raw(scope, info.context);
     callback_resource.close();
@@ -7,6 +7,7 @@ const {
   createCancelHandle,
 } = core;
 import {
+  op_fs_backend_name,
   op_fs_chdir,
   op_fs_chmod_async,
   op_fs_chmod_sync,
@@ -21,6 +22,8 @@ import {
   op_fs_file_sync_data_async,
   op_fs_file_sync_data_sync,
   op_fs_file_sync_sync,
+  op_fs_file_flush_sync,
+  op_fs_file_set_buffering,
   op_fs_file_truncate_async,
   op_fs_flock_async,
   op_fs_flock_sync,
@@ -40,8 +43,12 @@ import {
   op_fs_mkdir_async,
   op_fs_mkdir_sync,
   op_fs_open_async,
+  op_fs_open_raw_async,
+  op_fs_open_raw_sync,
   op_fs_open_sync,
   op_fs_read_dir_async,
+  op_fs_read_dir_raw_async,
+  op_fs_read_dir_raw_sync,
   op_fs_read_dir_sync,
   op_fs_read_file_async,
   op_fs_read_file_sync,
@@ -56,8 +63,12 @@ import {
   op_fs_rename_async,
   op_fs_rename_sync,
   op_fs_seek_async,
+  op_fs_seek_bigint_async,
+  op_fs_seek_bigint_sync,
   op_fs_seek_sync,
   op_fs_stat_async,
+  op_fs_stat_raw_async,
+  op_fs_stat_raw_sync,
   op_fs_stat_sync,
   op_fs_symlink_async,
   op_fs_symlink_sync,
@@ -68,31 +79,47 @@ import {
   op_fs_utime_sync,
   op_fs_write_file_async,
   op_fs_write_file_sync,
-  op_set_raw,
 } from "ext:core/ops";
 const {
   ArrayPrototypeFilter,
   Date,
+  DateNow,
   DatePrototypeGetTime,
   Error,
   Function,
+  MathMax,
+  MathMin,
   MathTrunc,
   Number,
+  NumberIsSafeInteger,
   ObjectEntries,
   ObjectDefineProperty,
   ObjectPrototypeIsPrototypeOf,
   ObjectValues,
+  StringPrototypeLastIndexOf,
   StringPrototypeSlice,
   StringPrototypeStartsWith,
   SymbolAsyncIterator,
   SymbolDispose,
   SymbolIterator,
   SymbolFor,
+  TypedArrayPrototypeGetByteLength,
+  TypedArrayPrototypeGetSymbolToStringTag,
+  TypedArrayPrototypeSubarray,
+  RangeError,
   TypeError,
   Uint32Array,
+  Uint8Array,
 } = primordials;
 
-import { read, readSync, write, writeSync } from "ext:deno_io/12_io.js";
+import {
+  read,
+  readSync,
+  SeekMode,
+  setRaw,
+  write,
+  writeSync,
+} from "ext:deno_io/12_io.js";
 import * as abortSignal from "ext:deno_web/03_abort_signal.js";
 import {
   readableStreamForRid,
@@ -100,6 +127,21 @@ import {
   writableStreamForRid,
 } from "ext:deno_web/06_streams.js";
 import { pathFromURL } from "ext:deno_web/00_infra.js";
+import { BlobReference, fileFromPart } from "ext:deno_web/09_file.js";
+import { IO_SPAN_FS, traceIo } from "ext:deno_telemetry/telemetry.ts";
+
+let fsBackend;
+
+function traceFs(name, path, fn, bytesOf) {
+  fsBackend ??= op_fs_backend_name();
+  return traceIo(IO_SPAN_FS, name, fsBackend, path, fn, bytesOf);
+}
+
+// Paths given as a `Uint8Array` are OS-native bytes and bypass UTF-8
+// decoding, so that non-UTF-8 file names can be addressed.
+function isRawPath(path) {
+  return TypedArrayPrototypeGetSymbolToStringTag(path) === "Uint8Array";
+}
 
 function chmodSync(path, mode) {
   op_fs_chmod_sync(pathFromURL(path), mode);
@@ -206,15 +248,20 @@ async function mkdir(path, options) {
 }
 
 function readDirSync(path) {
+  if (isRawPath(path)) {
+    return op_fs_read_dir_raw_sync(path)[SymbolIterator]();
+  }
   return op_fs_read_dir_sync(pathFromURL(path))[
     SymbolIterator
   ]();
 }
 
 function readDir(path) {
-  const array = op_fs_read_dir_async(
-    pathFromURL(path),
-  );
+  const array = isRawPath(path)
+    ? op_fs_read_dir_raw_async(path)
+    : op_fs_read_dir_async(
+      pathFromURL(path),
+    );
   return {
     async *[SymbolAsyncIterator]() {
       const dir = await array;
@@ -248,6 +295,7 @@ function removeSync(
   op_fs_remove_sync(
     pathFromURL(path),
     !!options.recursive,
+    !!options.trash,
   );
 }
 
@@ -258,6 +306,7 @@ async function remove(
   await op_fs_remove_async(
     pathFromURL(path),
     !!options.recursive,
+    !!options.trash,
   );
 }
 
@@ -412,22 +461,38 @@ function parseFileInfo(response) {
 }
 
 async function lstat(path) {
-  const res = await op_fs_lstat_async(pathFromURL(path));
+  if (isRawPath(path)) {
+    return parseFileInfo(await op_fs_stat_raw_async(path, true));
+  }
+  path = pathFromURL(path);
+  const res = await traceFs("Deno.lstat", path, () => op_fs_lstat_async(path));
   return parseFileInfo(res);
 }
 
 function lstatSync(path) {
-  op_fs_lstat_sync(pathFromURL(path), statBuf);
+  if (isRawPath(path)) {
+    op_fs_stat_raw_sync(path, true, statBuf);
+  } else {
+    op_fs_lstat_sync(pathFromURL(path), statBuf);
+  }
   return statStruct(statBuf);
 }
 
 async function stat(path) {
-  const res = await op_fs_stat_async(pathFromURL(path));
+  if (isRawPath(path)) {
+    return parseFileInfo(await op_fs_stat_raw_async(path, false));
+  }
+  path = pathFromURL(path);
+  const res = await traceFs("Deno.stat", path, () => op_fs_stat_async(path));
   return parseFileInfo(res);
 }
 
 function statSync(path) {
-  op_fs_stat_sync(pathFromURL(path), statBuf);
+  if (isRawPath(path)) {
+    op_fs_stat_raw_sync(path, false, statBuf);
+  } else {
+    op_fs_stat_sync(pathFromURL(path), statBuf);
+  }
   return statStruct(statBuf);
 }
 
@@ -540,10 +605,12 @@ function openSync(
   options,
 ) {
   if (options) checkOpenOptions(options);
-  const rid = op_fs_open_sync(
-    pathFromURL(path),
-    options,
-  );
+  const rid = isRawPath(path)
+    ? op_fs_open_raw_sync(path, options)
+    : op_fs_open_sync(
+      pathFromURL(path),
+      options,
+    );
 
   return new FsFile(rid, SymbolFor("Deno.internal.FsFile"));
 }
@@ -553,14 +620,33 @@ async function open(
   options,
 ) {
   if (options) checkOpenOptions(options);
-  const rid = await op_fs_open_async(
-    pathFromURL(path),
-    options,
-  );
+  let rid;
+  if (isRawPath(path)) {
+    rid = await op_fs_open_raw_async(path, options);
+  } else {
+    path = pathFromURL(path);
+    rid = await traceFs(
+      "Deno.open",
+      path,
+      () => op_fs_open_async(path, options),
+    );
+  }
 
   return new FsFile(rid, SymbolFor("Deno.internal.FsFile"));
 }
 
+const DEFAULT_BUFFERING_BYTES = 64 * 1024;
+const MAX_BUFFERING_BYTES = 64 * 1024 * 1024;
+
+function bufferingOption(value, name) {
+  if (!NumberIsSafeInteger(value) || value < 0) {
+    throw new RangeError(
+      `Invalid '${name}': must be a non-negative integer, received ${value}`,
+    );
+  }
+  return value;
+}
+
 function createSync(path) {
   return openSync(path, {
     read: true,
@@ -600,7 +686,12 @@ class FsFile {
   }
 
   write(p) {
-    return write(this.#rid, p);
+    return traceFs(
+      "Deno.FsFile.write",
+      undefined,
+      () => write(this.#rid, p),
+      (nwritten) => nwritten,
+    );
   }
 
   writeSync(p) {
@@ -616,7 +707,12 @@ class FsFile {
   }
 
   read(p) {
-    return read(this.#rid, p);
+    return traceFs(
+      "Deno.FsFile.read",
+      undefined,
+      () => read(this.#rid, p),
+      (nread) => nread,
+    );
   }
 
   readSync(p) {
@@ -624,10 +720,16 @@ class FsFile {
   }
 
   seek(offset, whence) {
+    if (typeof offset === "bigint") {
+      return op_fs_seek_bigint_async(this.#rid, offset, whence);
+    }
     return op_fs_seek_async(this.#rid, offset, whence);
   }
 
   seekSync(offset, whence) {
+    if (typeof offset === "bigint") {
+      return op_fs_seek_bigint_sync(this.#rid, offset, whence);
+    }
     return op_fs_seek_sync(this.#rid, offset, whence);
   }
 
@@ -648,6 +750,28 @@ class FsFile {
     op_fs_file_sync_data_sync(this.#rid);
   }
 
+  setBuffering(options) {
+    let bytes = 0;
+    let flushMs = 0;
+    if (options != null) {
+      bytes = bufferingOption(
+        options.bytes ?? DEFAULT_BUFFERING_BYTES,
+        "bytes",
+      );
+      if (bytes > MAX_BUFFERING_BYTES) {
+        throw new RangeError(
+          `Invalid 'bytes': must be at most ${MAX_BUFFERING_BYTES}, received ${bytes}`,
+        );
+      }
+      flushMs = bufferingOption(options.flushMs ?? 0, "flushMs");
+    }
+    op_fs_file_set_buffering(this.#rid, bytes, flushMs);
+  }
+
+  flushSync() {
+    op_fs_file_flush_sync(this.#rid);
+  }
+
   close() {
     core.close(this.#rid);
   }
@@ -697,8 +821,7 @@ class FsFile {
   }
 
   setRaw(mode, options = { __proto__: null }) {
-    const cbreak = !!(options.cbreak ?? false);
-    op_set_raw(this.#rid, mode, cbreak);
+    setRaw(this.#rid, mode, options);
   }
 
   lockSync(exclusive = false) {
@@ -751,6 +874,97 @@ function checkOpenOptions(options) {
   }
 }
 
+const FILE_PART_CHUNK_SIZE = 64 * 1024;
+
+/**
+ * The data of a `File` from `Deno.fileFromPath()`, which is read from disk
+ * each time it is needed. Like in browsers, the file is expected to not
+ * change while it is in use.
+ */
+class DiskFilePart {
+  #path;
+  #start;
+  /** The size of the whole file. */
+  #fileSize;
+  /** @type {BlobReference | undefined} */
+  #reference;
+
+  constructor(path, start, size, fileSize) {
+    this.#path = path;
+    this.#start = start;
+    this.size = size;
+    this.#fileSize = fileSize;
+  }
+
+  slice(start, end) {
+    return new DiskFilePart(
+      this.#path,
+      this.#start + start,
+      end - start,
+      this.#fileSize,
+    );
+  }
+
+  async *stream() {
+    const file = await open(this.#path);
+    try {
+      if (this.#start > 0) {
+        await file.seek(this.#start, SeekMode.Start);
+      }
+      let remaining = this.size;
+      while (remaining > 0) {
+        const buf = new Uint8Array(MathMin(remaining, FILE_PART_CHUNK_SIZE));
+        const nread = await file.read(buf);
+        if (nread === null) {
+          throw new Error(`File was truncated while reading: ${this.#path}`);
+        }
+        remaining -= nread;
+        yield TypedArrayPrototypeSubarray(buf, 0, nread);
+      }
+    } finally {
+      file.close();
+    }
+  }
+
+  resourceStream() {
+    if (this.#start !== 0 || this.size !== this.#fileSize) {
+      return null;
+    }
+    return openSync(this.#path).readable;
+  }
+
+  /** Object URLs need the data in the blob store, so it is read then. */
+  get _id() {
+    if (this.#reference === undefined) {
+      const data = readFileSync(this.#path);
+      this.#reference = BlobReference.fromUint8Array(
+        TypedArrayPrototypeSubarray(data, this.#start, this.#start + this.size),
+      );
+    }
+    return this.#reference._id;
+  }
+}
+
+async function fileFromPath(path, options = { __proto__: null }) {
+  path = pathFromURL(path);
+  const info = await stat(path);
+  if (!info.isFile) {
+    throw new TypeError(`Not a file: ${path}`);
+  }
+  const separator = core.build.os === "windows"
+    ? MathMax(
+      StringPrototypeLastIndexOf(path, "/"),
+      StringPrototypeLastIndexOf(path, "\\"),
+    )
+    : StringPrototypeLastIndexOf(path, "/");
+  return fileFromPart(
+    new DiskFilePart(path, 0, info.size, info.size),
+    StringPrototypeSlice(path, separator + 1),
+    options.type ?? "",
+    info.mtime !== null ? DatePrototypeGetTime(info.mtime) : DateNow(),
+  );
+}
+
 function readFileSync(path) {
   return op_fs_read_file_sync(pathFromURL(path));
 }
@@ -766,9 +980,12 @@ async function readFile(path, options) {
   }
 
   try {
-    const read = await op_fs_read_file_async(
-      pathFromURL(path),
-      cancelRid,
+    path = pathFromURL(path);
+    const read = await traceFs(
+      "Deno.readFile",
+      path,
+      () => op_fs_read_file_async(path, cancelRid),
+      (data) => TypedArrayPrototypeGetByteLength(data),
     );
     return read;
   } finally {
@@ -796,9 +1013,11 @@ async function readTextFile(path, options) {
   }
 
   try {
-    const read = await op_fs_read_file_text_async(
-      pathFromURL(path),
-      cancelRid,
+    path = pathFromURL(path);
+    const read = await traceFs(
+      "Deno.readTextFile",
+      path,
+      () => op_fs_read_file_text_async(path, cancelRid),
     );
     return read;
   } finally {
@@ -854,14 +1073,21 @@ async function writeFile(
         signal: options.signal,
       });
     } else {
-      await op_fs_write_file_async(
-        pathFromURL(path),
-        options.mode,
-        options.append ?? false,
-        options.create ?? true,
-        options.createNew ?? false,
-        data,
-        cancelRid,
+      path = pathFromURL(path);
+      await traceFs(
+        "Deno.writeFile",
+        path,
+        () =>
+          op_fs_write_file_async(
+            path,
+            options.mode,
+            options.append ?? false,
+            options.create ?? true,
+            options.createNew ?? false,
+            data,
+            cancelRid,
+          ),
+        () => TypedArrayPrototypeGetByteLength(data),
       );
     }
   } finally {
@@ -911,6 +1137,7 @@ export {
   create,
   createSync,
   cwd,
+  fileFromPath,
   FsFile,
   link,
   linkSync,
@@ -2,6 +2,7 @@
 
 use core::str;
 use std::borrow::Cow;
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -134,11 +135,35 @@ pub struct FsDirEntry {
   pub is_symlink: bool,
 }
 
+/// Like [`FsDirEntry`], but with the name kept as OS-native bytes so that
+/// non-UTF-8 file names survive the round trip to JS.
+#[derive(Debug, Clone)]
+pub struct FsRawDirEntry {
+  pub name: OsString,
+  pub is_file: bool,
+  pub is_directory: bool,
+  pub is_symlink: bool,
+}
+
+impl From<FsDirEntry> for FsRawDirEntry {
+  fn from(entry: FsDirEntry) -> Self {
+    Self {
+      name: entry.name.into(),
+      is_file: entry.is_file,
+      is_directory: entry.is_directory,
+      is_symlink: entry.is_symlink,
+    }
+  }
+}
+
 #[allow(clippy::disallowed_types)]
 pub type FileSystemRc = deno_maybe_sync::MaybeArc<dyn FileSystem>;
 
 #[async_trait::async_trait(?Send)]
 pub trait FileSystem: std::fmt::Debug + MaybeSend + MaybeSync {
+  /// Identifies the implementation, for example in telemetry.
+  fn backend_name(&self) -> &'static str;
+
   fn cwd(&self) -> FsResult<PathBuf>;
   fn tmp_dir(&self) -> FsResult<PathBuf>;
   fn chdir(&self, path: &CheckedPath) -> FsResult<()>;
@@ -215,6 +240,10 @@ pub trait FileSystem: std::fmt::Debug + MaybeSend + MaybeSync {
     recursive: bool,
   ) -> FsResult<()>;
 
+  /// Moves `path` to the platform trash instead of unlinking it.
+  fn trash_sync(&self, path: &CheckedPath) -> FsResult<()>;
+  async fn trash_async(&self, path: CheckedPathBuf) -> FsResult<()>;
+
   fn copy_file_sync(
     &self,
     oldpath: &CheckedPath,
@@ -249,6 +278,24 @@ pub trait FileSystem: std::fmt::Debug + MaybeSend + MaybeSync {
     path: CheckedPathBuf,
   ) -> FsResult<Vec<FsDirEntry>>;
 
+  /// Reads a directory without dropping entries whose names are not valid
+  /// UTF-8. The default implementation is only correct for file systems that
+  /// can't contain such names.
+  fn read_dir_raw_sync(
+    &self,
+    path: &CheckedPath,
+  ) -> FsResult<Vec<FsRawDirEntry>> {
+    let entries = self.read_dir_sync(path)?;
+    Ok(entries.into_iter().map(FsRawDirEntry::from).collect())
+  }
+  async fn read_dir_raw_async(
+    &self,
+    path: CheckedPathBuf,
+  ) -> FsResult<Vec<FsRawDirEntry>> {
+    let entries = self.read_dir_async(path).await?;
+    Ok(entries.into_iter().map(FsRawDirEntry::from).collect())
+  }
+
   fn rename_sync(
     &self,
     oldpath: &CheckedPath,
@@ -2,7 +2,10 @@
 
 mod interface;
 mod ops;
+#[doc(hidden)]
+pub mod range;
 mod std_fs;
+mod trash;
 
 pub use deno_io::fs::FsError;
 pub use deno_maybe_sync as sync;
@@ -13,6 +16,7 @@ pub use crate::interface::FileSystem;
 pub use crate::interface::FileSystemRc;
 pub use crate::interface::FsDirEntry;
 pub use crate::interface::FsFileType;
+pub use crate::interface::FsRawDirEntry;
 pub use crate::interface::OpenOptions;
 pub use crate::ops::FsOpsError;
 pub use crate::ops::FsOpsErrorKind;
@@ -26,12 +30,15 @@ pub const UNSTABLE_FEATURE_NAME: &str = "fs";
 deno_core::extension!(deno_fs,
   deps = [ deno_web ],
   ops = [
+    op_fs_backend_name,
     op_fs_cwd,
     op_fs_umask,
     op_fs_chdir,
 
     op_fs_open_sync,
     op_fs_open_async,
+    op_fs_open_raw_sync,
+    op_fs_open_raw_async,
     op_fs_mkdir_sync,
     op_fs_mkdir_async,
     op_fs_chmod_sync,
@@ -46,10 +53,14 @@ deno_core::extension!(deno_fs,
     op_fs_stat_async,
     op_fs_lstat_sync,
     op_fs_lstat_async,
+    op_fs_stat_raw_sync,
+    op_fs_stat_raw_async,
     op_fs_realpath_sync,
     op_fs_realpath_async,
     op_fs_read_dir_sync,
     op_fs_read_dir_async,
+    op_fs_read_dir_raw_sync,
+    op_fs_read_dir_raw_async,
     op_fs_rename_sync,
     op_fs_rename_async,
     op_fs_link_sync,
@@ -75,6 +86,8 @@ deno_core::extension!(deno_fs,
 
     op_fs_seek_sync,
     op_fs_seek_async,
+    op_fs_seek_bigint_sync,
+    op_fs_seek_bigint_async,
     op_fs_file_sync_data_sync,
     op_fs_file_sync_data_async,
     op_fs_file_sync_sync,
@@ -91,6 +104,8 @@ deno_core::extension!(deno_fs,
     op_fs_funlock_sync,
     op_fs_ftruncate_sync,
     op_fs_file_truncate_async,
+    op_fs_file_set_buffering,
+    op_fs_file_flush_sync,
     op_fs_futime_sync,
     op_fs_futime_async,
 
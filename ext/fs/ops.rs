@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::error::Error;
+use std::ffi::OsString;
 use std::fmt::Formatter;
 use std::io;
 use std::io::SeekFrom;
@@ -10,6 +11,7 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::path::StripPrefixError;
 use std::rc::Rc;
+use std::time::Duration;
 
 use boxed_error::Boxed;
 use deno_core::CancelFuture;
@@ -22,6 +24,7 @@ use deno_core::ToJsBuffer;
 use deno_core::error::ResourceError;
 use deno_core::op2;
 use deno_error::JsErrorBox;
+use deno_error::JsErrorClass;
 use deno_io::fs::FileResource;
 use deno_io::fs::FsError;
 use deno_io::fs::FsStat;
@@ -40,6 +43,7 @@ use crate::OpenOptions;
 use crate::interface::FileSystemRc;
 use crate::interface::FsDirEntry;
 use crate::interface::FsFileType;
+use crate::interface::FsRawDirEntry;
 
 #[derive(Debug, Boxed, deno_error::JsError)]
 pub struct FsOpsError(pub Box<FsOpsErrorKind>);
@@ -70,6 +74,9 @@ pub enum FsOpsErrorKind {
   #[class(type)]
   #[error("Invalid seek mode: {0}")]
   InvalidSeekMode(i32),
+  #[class(type)]
+  #[error("Invalid seek offset: {0}")]
+  InvalidSeekOffset(i64),
   #[class(generic)]
   #[error("Invalid control character in prefix or suffix: {0:?}")]
   InvalidControlCharacter(String),
@@ -93,6 +100,9 @@ impl From<FsError> for FsOpsError {
       FsError::NotSupported => {
         FsOpsErrorKind::Other(JsErrorBox::not_supported())
       }
+      err @ FsError::Overloaded(_) => {
+        FsOpsErrorKind::Other(JsErrorBox::from_err(err))
+      }
       FsError::PermissionCheck(err) => FsOpsErrorKind::Permission(err),
     }
     .into_box()
@@ -118,6 +128,12 @@ pub fn op_fs_cwd(state: &mut OpState) -> Result<String, FsOpsError> {
   Ok(path_str)
 }
 
+#[op2]
+#[string]
+pub fn op_fs_backend_name(state: &mut OpState) -> &'static str {
+  state.borrow::<FileSystemRc>().backend_name()
+}
+
 #[op2(fast, stack_trace)]
 pub fn op_fs_chdir(
   state: &mut OpState,
@@ -240,6 +256,70 @@ pub async fn op_fs_open_async(
   Ok(rid)
 }
 
+#[op2(stack_trace)]
+#[smi]
+pub fn op_fs_open_raw_sync(
+  state: &mut OpState,
+  #[buffer] path: &[u8],
+  #[serde] options: Option<FsOpenOptions>,
+) -> Result<ResourceId, FsOpsError> {
+  let options = match options {
+    Some(options) => OpenOptions::from(options),
+    None => OpenOptions::read(),
+  };
+
+  let fs = state.borrow::<FileSystemRc>().clone();
+  let path = state
+    .borrow_mut::<deno_permissions::PermissionsContainer>()
+    .check_open(
+      Cow::Owned(path_from_bytes(path)?),
+      open_options_to_access_kind(&options),
+      Some("Deno.openSync()"),
+    )?;
+  let file = fs.open_sync(&path, options).context_path("open", &path)?;
+  let rid = state
+    .resource_table
+    .add(FileResource::new(file, "fsFile".to_string()));
+  Ok(rid)
+}
+
+#[op2(async, stack_trace)]
+#[smi]
+pub async fn op_fs_open_raw_async(
+  state: Rc<RefCell<OpState>>,
+  #[buffer] path: JsBuffer,
+  #[serde] options: Option<FsOpenOptions>,
+) -> Result<ResourceId, FsOpsError> {
+  let options = match options {
+    Some(options) => OpenOptions::from(options),
+    None => OpenOptions::read(),
+  };
+
+  let (fs, path) = {
+    let mut state = state.borrow_mut();
+    (
+      state.borrow::<FileSystemRc>().clone(),
+      state
+        .borrow_mut::<deno_permissions::PermissionsContainer>()
+        .check_open(
+          Cow::Owned(path_from_bytes(&path)?),
+          open_options_to_access_kind(&options),
+          Some("Deno.open()"),
+        )?,
+    )
+  };
+  let file = fs
+    .open_async(path.as_owned(), options)
+    .await
+    .context_path("open", &path)?;
+
+  let rid = state
+    .borrow_mut()
+    .resource_table
+    .add(FileResource::new(file, "fsFile".to_string()));
+  Ok(rid)
+}
+
 #[op2(stack_trace)]
 pub fn op_fs_mkdir_sync(
   state: &mut OpState,
@@ -430,7 +510,10 @@ pub fn op_fs_fchmod_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.chmod_sync(mode)?;
+  file
+    .clone()
+    .chmod_sync(mode)
+    .context_maybe_path("fchmod", file.maybe_path())?;
   Ok(())
 }
 
@@ -442,7 +525,11 @@ pub async fn op_fs_fchmod_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.chmod_async(mode).await?;
+  file
+    .clone()
+    .chmod_async(mode)
+    .await
+    .context_maybe_path("fchmod", file.maybe_path())?;
   Ok(())
 }
 
@@ -455,7 +542,10 @@ pub fn op_fs_fchown_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.chown_sync(uid, gid)?;
+  file
+    .clone()
+    .chown_sync(uid, gid)
+    .context_maybe_path("fchown", file.maybe_path())?;
   Ok(())
 }
 
@@ -468,7 +558,25 @@ pub async fn op_fs_fchown_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.chown_async(uid, gid).await?;
+  file
+    .clone()
+    .chown_async(uid, gid)
+    .await
+    .context_maybe_path("fchown", file.maybe_path())?;
+  Ok(())
+}
+
+/// Moving a path to the trash writes to a directory outside of it, which is
+/// found through environment variables, so it needs write access to
+/// everything and access to those variables.
+fn check_trash(
+  permissions: &mut deno_permissions::PermissionsContainer,
+  api_name: &str,
+) -> Result<(), FsOpsError> {
+  permissions.check_write_all(api_name)?;
+  for var in crate::trash::ENV_VARS {
+    permissions.check_env(var)?;
+  }
   Ok(())
 }
 
@@ -477,9 +585,16 @@ pub fn op_fs_remove_sync(
   state: &mut OpState,
   #[string] path: &str,
   recursive: bool,
+  trash: bool,
 ) -> Result<(), FsOpsError> {
   let path = Cow::Borrowed(Path::new(path));
-  let path = if recursive {
+  if trash {
+    check_trash(
+      state.borrow_mut::<deno_permissions::PermissionsContainer>(),
+      "Deno.removeSync()",
+    )?;
+  }
+  let path = if recursive || trash {
     state
       .borrow_mut::<deno_permissions::PermissionsContainer>()
       .check_open(
@@ -494,8 +609,12 @@ pub fn op_fs_remove_sync(
   };
 
   let fs = state.borrow::<FileSystemRc>();
-  fs.remove_sync(&path, recursive)
-    .context_path("remove", &path)?;
+  if trash {
+    fs.trash_sync(&path).context_path("trash", &path)?;
+  } else {
+    fs.remove_sync(&path, recursive)
+      .context_path("remove", &path)?;
+  }
 
   Ok(())
 }
@@ -505,11 +624,18 @@ pub async fn op_fs_remove_async(
   state: Rc<RefCell<OpState>>,
   #[string] path: String,
   recursive: bool,
+  trash: bool,
 ) -> Result<(), FsOpsError> {
   let (fs, path) = {
     let mut state = state.borrow_mut();
     let path = Cow::Owned(PathBuf::from(path));
-    let path = if recursive {
+    if trash {
+      check_trash(
+        state.borrow_mut::<deno_permissions::PermissionsContainer>(),
+        "Deno.remove()",
+      )?;
+    }
+    let path = if recursive || trash {
       state
         .borrow_mut::<deno_permissions::PermissionsContainer>()
         .check_open(
@@ -526,9 +652,15 @@ pub async fn op_fs_remove_async(
     (state.borrow::<FileSystemRc>().clone(), path)
   };
 
-  fs.remove_async(path.as_owned(), recursive)
-    .await
-    .context_path("remove", &path)?;
+  if trash {
+    fs.trash_async(path.as_owned())
+      .await
+      .context_path("trash", &path)?;
+  } else {
+    fs.remove_async(path.as_owned(), recursive)
+      .await
+      .context_path("remove", &path)?;
+  }
 
   Ok(())
 }
@@ -724,6 +856,166 @@ pub async fn op_fs_realpath_async(
   Ok(path_string)
 }
 
+/// Builds a path from OS-native bytes passed from JS as a `Uint8Array`.
+fn path_from_bytes(bytes: &[u8]) -> Result<PathBuf, FsOpsError> {
+  #[cfg(unix)]
+  {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(PathBuf::from(std::ffi::OsStr::from_bytes(bytes)))
+  }
+  #[cfg(not(unix))]
+  {
+    // Native paths are UTF-16 on Windows, so byte paths are taken as UTF-8.
+    std::str::from_utf8(bytes).map(PathBuf::from).map_err(|_| {
+      FsOpsErrorKind::InvalidUtf8(
+        String::from_utf8_lossy(bytes).into_owned().into(),
+      )
+      .into_box()
+    })
+  }
+}
+
+fn os_string_into_bytes(s: OsString) -> Result<Vec<u8>, FsOpsError> {
+  #[cfg(unix)]
+  {
+    use std::os::unix::ffi::OsStringExt;
+    Ok(s.into_vec())
+  }
+  #[cfg(not(unix))]
+  {
+    path_into_string(s).map(String::into_bytes)
+  }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawDirEntry {
+  name: ToJsBuffer,
+  is_file: bool,
+  is_directory: bool,
+  is_symlink: bool,
+}
+
+fn into_raw_dir_entries(
+  entries: Vec<FsRawDirEntry>,
+) -> Result<Vec<RawDirEntry>, FsOpsError> {
+  entries
+    .into_iter()
+    .map(|entry| {
+      Ok(RawDirEntry {
+        name: os_string_into_bytes(entry.name)?.into_boxed_slice().into(),
+        is_file: entry.is_file,
+        is_directory: entry.is_directory,
+        is_symlink: entry.is_symlink,
+      })
+    })
+    .collect()
+}
+
+#[op2(stack_trace)]
+#[serde]
+pub fn op_fs_read_dir_raw_sync(
+  state: &mut OpState,
+  #[buffer] path: &[u8],
+) -> Result<Vec<RawDirEntry>, FsOpsError> {
+  let path = state
+    .borrow_mut::<deno_permissions::PermissionsContainer>()
+    .check_open(
+      Cow::Owned(path_from_bytes(path)?),
+      OpenAccessKind::ReadNoFollow,
+      Some("Deno.readDirSync()"),
+    )?;
+
+  let fs = state.borrow::<FileSystemRc>();
+  let entries = fs.read_dir_raw_sync(&path).context_path("readdir", &path)?;
+
+  into_raw_dir_entries(entries)
+}
+
+#[op2(async, stack_trace)]
+#[serde]
+pub async fn op_fs_read_dir_raw_async(
+  state: Rc<RefCell<OpState>>,
+  #[buffer] path: JsBuffer,
+) -> Result<Vec<RawDirEntry>, FsOpsError> {
+  let (fs, path) = {
+    let mut state = state.borrow_mut();
+    let path = state
+      .borrow_mut::<deno_permissions::PermissionsContainer>()
+      .check_open(
+        Cow::Owned(path_from_bytes(&path)?),
+        OpenAccessKind::ReadNoFollow,
+        Some("Deno.readDir()"),
+      )?;
+    (state.borrow::<FileSystemRc>().clone(), path)
+  };
+
+  let entries = fs
+    .read_dir_raw_async(path.as_owned())
+    .await
+    .context_path("readdir", &path)?;
+
+  into_raw_dir_entries(entries)
+}
+
+#[op2(stack_trace)]
+pub fn op_fs_stat_raw_sync(
+  state: &mut OpState,
+  #[buffer] path: &[u8],
+  lstat: bool,
+  #[buffer] stat_out_buf: &mut [u32],
+) -> Result<(), FsOpsError> {
+  let path = state
+    .borrow_mut::<deno_permissions::PermissionsContainer>()
+    .check_open(
+      Cow::Owned(path_from_bytes(path)?),
+      OpenAccessKind::ReadNoFollow,
+      Some(if lstat {
+        "Deno.lstatSync()"
+      } else {
+        "Deno.statSync()"
+      }),
+    )?;
+  let fs = state.borrow::<FileSystemRc>();
+  let stat = if lstat {
+    fs.lstat_sync(&path).context_path("lstat", &path)?
+  } else {
+    fs.stat_sync(&path).context_path("stat", &path)?
+  };
+  SerializableStat::from(stat).write(stat_out_buf);
+  Ok(())
+}
+
+#[op2(async, stack_trace)]
+#[serde]
+pub async fn op_fs_stat_raw_async(
+  state: Rc<RefCell<OpState>>,
+  #[buffer] path: JsBuffer,
+  lstat: bool,
+) -> Result<SerializableStat, FsOpsError> {
+  let (fs, path) = {
+    let mut state = state.borrow_mut();
+    let path = state
+      .borrow_mut::<deno_permissions::PermissionsContainer>()
+      .check_open(
+        Cow::Owned(path_from_bytes(&path)?),
+        OpenAccessKind::ReadNoFollow,
+        Some(if lstat { "Deno.lstat()" } else { "Deno.stat()" }),
+      )?;
+    (state.borrow::<FileSystemRc>().clone(), path)
+  };
+  let stat = if lstat {
+    fs.lstat_async(path.as_owned())
+      .await
+      .context_path("lstat", &path)?
+  } else {
+    fs.stat_async(path.as_owned())
+      .await
+      .context_path("stat", &path)?
+  };
+  Ok(SerializableStat::from(stat))
+}
+
 #[op2(stack_trace)]
 #[serde]
 pub fn op_fs_read_dir_sync(
@@ -1501,7 +1793,10 @@ pub fn op_fs_read_file_sync(
     .read_file_sync(&path, options)
     .context_path("readfile", &path)?;
 
-  // todo(https://github.com/denoland/deno/issues/27107): do not clone here
+  // Owned buffers are allocated to the file's exact size, so this hands the
+  // allocation to V8 as is.
+  // todo(https://github.com/denoland/deno/issues/27107): do not clone
+  // borrowed buffers here
   Ok(buf.into_owned().into_boxed_slice().into())
 }
 
@@ -1550,7 +1845,10 @@ pub async fn op_fs_read_file_async(
     fut.await.context_path("readfile", &path)?
   };
 
-  // todo(https://github.com/denoland/deno/issues/27107): do not clone here
+  // Owned buffers are allocated to the file's exact size, so this hands the
+  // allocation to V8 as is.
+  // todo(https://github.com/denoland/deno/issues/27107): do not clone
+  // borrowed buffers here
   Ok(buf.into_owned().into_boxed_slice().into())
 }
 
@@ -1626,7 +1924,12 @@ pub async fn op_fs_read_file_text_async(
 
 fn to_seek_from(offset: i64, whence: i32) -> Result<SeekFrom, FsOpsError> {
   let seek_from = match whence {
-    0 => SeekFrom::Start(offset as u64),
+    0 => match u64::try_from(offset) {
+      Ok(offset) => SeekFrom::Start(offset),
+      Err(_) => {
+        return Err(FsOpsErrorKind::InvalidSeekOffset(offset).into_box());
+      }
+    },
     1 => SeekFrom::Current(offset),
     2 => SeekFrom::End(offset),
     _ => {
@@ -1647,7 +1950,10 @@ pub fn op_fs_seek_sync(
   let pos = to_seek_from(offset, whence)?;
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  let cursor = file.seek_sync(pos)?;
+  let cursor = file
+    .clone()
+    .seek_sync(pos)
+    .context_maybe_path("seek", file.maybe_path())?;
   Ok(cursor)
 }
 
@@ -1662,7 +1968,50 @@ pub async fn op_fs_seek_async(
   let pos = to_seek_from(offset, whence)?;
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  let cursor = file.seek_async(pos).await?;
+  let cursor = file
+    .clone()
+    .seek_async(pos)
+    .await
+    .context_maybe_path("seek", file.maybe_path())?;
+  Ok(cursor)
+}
+
+/// Same as `op_fs_seek_sync`, but takes and returns a `BigInt` so that
+/// positions beyond `Number.MAX_SAFE_INTEGER` round-trip exactly.
+#[op2(fast)]
+#[bigint]
+pub fn op_fs_seek_bigint_sync(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+  #[bigint] offset: i64,
+  #[smi] whence: i32,
+) -> Result<u64, FsOpsError> {
+  let pos = to_seek_from(offset, whence)?;
+  let file =
+    FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
+  let cursor = file
+    .clone()
+    .seek_sync(pos)
+    .context_maybe_path("seek", file.maybe_path())?;
+  Ok(cursor)
+}
+
+#[op2(async)]
+#[bigint]
+pub async fn op_fs_seek_bigint_async(
+  state: Rc<RefCell<OpState>>,
+  #[smi] rid: ResourceId,
+  #[bigint] offset: i64,
+  #[smi] whence: i32,
+) -> Result<u64, FsOpsError> {
+  let pos = to_seek_from(offset, whence)?;
+  let file = FileResource::get_file(&state.borrow(), rid)
+    .map_err(FsOpsErrorKind::Resource)?;
+  let cursor = file
+    .clone()
+    .seek_async(pos)
+    .await
+    .context_maybe_path("seek", file.maybe_path())?;
   Ok(cursor)
 }
 
@@ -1673,7 +2022,10 @@ pub fn op_fs_file_sync_data_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.datasync_sync()?;
+  file
+    .clone()
+    .datasync_sync()
+    .context_maybe_path("fdatasync", file.maybe_path())?;
   Ok(())
 }
 
@@ -1684,7 +2036,11 @@ pub async fn op_fs_file_sync_data_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.datasync_async().await?;
+  file
+    .clone()
+    .datasync_async()
+    .await
+    .context_maybe_path("fdatasync", file.maybe_path())?;
   Ok(())
 }
 
@@ -1695,7 +2051,10 @@ pub fn op_fs_file_sync_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.sync_sync()?;
+  file
+    .clone()
+    .sync_sync()
+    .context_maybe_path("fsync", file.maybe_path())?;
   Ok(())
 }
 
@@ -1706,7 +2065,11 @@ pub async fn op_fs_file_sync_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.sync_async().await?;
+  file
+    .clone()
+    .sync_async()
+    .await
+    .context_maybe_path("fsync", file.maybe_path())?;
   Ok(())
 }
 
@@ -1727,7 +2090,10 @@ pub fn op_fs_file_stat_sync(
         Some("Deno.FsFile.prototype.statSync()"),
       )?;
   }
-  let stat = file.stat_sync()?;
+  let stat = file
+    .clone()
+    .stat_sync()
+    .context_maybe_path("fstat", file.maybe_path())?;
   let serializable_stat = SerializableStat::from(stat);
   serializable_stat.write(stat_out_buf);
   Ok(())
@@ -1751,7 +2117,11 @@ pub async fn op_fs_file_stat_async(
         Some("Deno.FsFile.prototype.stat()"),
       )?;
   }
-  let stat = file.stat_async().await?;
+  let stat = file
+    .clone()
+    .stat_async()
+    .await
+    .context_maybe_path("fstat", file.maybe_path())?;
   Ok(stat.into())
 }
 
@@ -1763,7 +2133,10 @@ pub fn op_fs_flock_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.lock_sync(exclusive)?;
+  file
+    .clone()
+    .lock_sync(exclusive)
+    .context_maybe_path("flock", file.maybe_path())?;
   Ok(())
 }
 
@@ -1775,7 +2148,11 @@ pub async fn op_fs_flock_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.lock_async(exclusive).await?;
+  file
+    .clone()
+    .lock_async(exclusive)
+    .await
+    .context_maybe_path("flock", file.maybe_path())?;
   Ok(())
 }
 
@@ -1786,7 +2163,10 @@ pub fn op_fs_funlock_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.unlock_sync()?;
+  file
+    .clone()
+    .unlock_sync()
+    .context_maybe_path("funlock", file.maybe_path())?;
   Ok(())
 }
 
@@ -1797,7 +2177,11 @@ pub async fn op_fs_funlock_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.unlock_async().await?;
+  file
+    .clone()
+    .unlock_async()
+    .await
+    .context_maybe_path("funlock", file.maybe_path())?;
   Ok(())
 }
 
@@ -1809,7 +2193,10 @@ pub fn op_fs_ftruncate_sync(
 ) -> Result<(), FsOpsError> {
   let file =
     FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
-  file.truncate_sync(len)?;
+  file
+    .clone()
+    .truncate_sync(len)
+    .context_maybe_path("ftruncate", file.maybe_path())?;
   Ok(())
 }
 
@@ -1821,7 +2208,45 @@ pub async fn op_fs_file_truncate_async(
 ) -> Result<(), FsOpsError> {
   let file = FileResource::get_file(&state.borrow(), rid)
     .map_err(FsOpsErrorKind::Resource)?;
-  file.truncate_async(len).await?;
+  file
+    .clone()
+    .truncate_async(len)
+    .await
+    .context_maybe_path("ftruncate", file.maybe_path())?;
+  Ok(())
+}
+
+#[op2(fast)]
+pub fn op_fs_file_set_buffering(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+  #[number] bytes: usize,
+  #[number] flush_ms: u64,
+) -> Result<(), FsOpsError> {
+  let file =
+    FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
+  let flush_interval = (flush_ms > 0).then(|| Duration::from_millis(flush_ms));
+  file
+    .clone()
+    .set_write_buffering(
+      bytes.min(deno_io::write_buffer::MAX_CAPACITY),
+      flush_interval,
+    )
+    .context_maybe_path("setbuffering", file.maybe_path())?;
+  Ok(())
+}
+
+#[op2(fast)]
+pub fn op_fs_file_flush_sync(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+) -> Result<(), FsOpsError> {
+  let file =
+    FileResource::get_file(state, rid).map_err(FsOpsErrorKind::Resource)?;
+  file
+    .clone()
+    .flush_write_buffer()
+    .context_maybe_path("flush", file.maybe_path())?;
   Ok(())
 }
 
@@ -1845,7 +2270,10 @@ pub fn op_fs_futime_sync(
         Some("Deno.FsFile.prototype.utimeSync()"),
       )?;
   }
-  file.utime_sync(atime_secs, atime_nanos, mtime_secs, mtime_nanos)?;
+  file
+    .clone()
+    .utime_sync(atime_secs, atime_nanos, mtime_secs, mtime_nanos)
+    .context_maybe_path("futime", file.maybe_path())?;
   Ok(())
 }
 
@@ -1876,15 +2304,107 @@ pub async fn op_fs_futime_async(
   Ok(())
 }
 
-#[derive(Debug, deno_error::JsError)]
-#[class(inherit)]
+/// An [`FsError`] annotated with the operation that failed and the path(s)
+/// it operated on.
+///
+/// Besides the message, the operation, paths and raw OS error code are
+/// exposed to JS as the `syscall`, `path`, `dest` and `errno` properties of
+/// the thrown error.
+#[derive(Debug)]
 pub struct OperationError {
   operation: &'static str,
   kind: OperationErrorKind,
-  #[inherit]
   pub err: FsError,
 }
 
+impl OperationError {
+  pub fn operation(&self) -> &'static str {
+    self.operation
+  }
+
+  pub fn path(&self) -> Option<&str> {
+    match &self.kind {
+      OperationErrorKind::Bare => None,
+      OperationErrorKind::WithPath(path)
+      | OperationErrorKind::WithTwoPaths(path, _) => Some(path),
+    }
+  }
+
+  pub fn dest(&self) -> Option<&str> {
+    match &self.kind {
+      OperationErrorKind::WithTwoPaths(_, dest) => Some(dest),
+      _ => None,
+    }
+  }
+
+  pub fn raw_os_error(&self) -> Option<i32> {
+    match &self.err {
+      FsError::Io(err) => err.raw_os_error(),
+      _ => None,
+    }
+  }
+
+  /// Error classes for conditions that the generic io error mapping folds
+  /// into `Error`.
+  fn specific_class(&self) -> Option<&'static str> {
+    let FsError::Io(err) = &self.err else {
+      return None;
+    };
+    match err.kind() {
+      io::ErrorKind::CrossesDevices => Some("CrossDevice"),
+      io::ErrorKind::ExecutableFileBusy => Some("TextFileBusy"),
+      io::ErrorKind::FileTooLarge => Some("FileTooLarge"),
+      io::ErrorKind::DirectoryNotEmpty => Some("DirectoryNotEmpty"),
+      io::ErrorKind::ReadOnlyFilesystem => Some("ReadOnlyFileSystem"),
+      io::ErrorKind::StorageFull => Some("StorageFull"),
+      _ => None,
+    }
+  }
+}
+
+impl deno_error::JsErrorClass for OperationError {
+  fn get_class(&self) -> Cow<'static, str> {
+    match self.specific_class() {
+      Some(class) => Cow::Borrowed(class),
+      None => self.err.get_class(),
+    }
+  }
+
+  fn get_message(&self) -> Cow<'static, str> {
+    Cow::Owned(self.to_string())
+  }
+
+  fn get_additional_properties(&self) -> deno_error::AdditionalProperties {
+    let mut properties = vec![(
+      Cow::Borrowed("syscall"),
+      deno_error::PropertyValue::String(Cow::Borrowed(self.operation)),
+    )];
+    if let Some(path) = self.path() {
+      properties.push((
+        Cow::Borrowed("path"),
+        deno_error::PropertyValue::String(Cow::Owned(path.to_string())),
+      ));
+    }
+    if let Some(dest) = self.dest() {
+      properties.push((
+        Cow::Borrowed("dest"),
+        deno_error::PropertyValue::String(Cow::Owned(dest.to_string())),
+      ));
+    }
+    if let Some(errno) = self.raw_os_error() {
+      properties.push((
+        Cow::Borrowed("errno"),
+        deno_error::PropertyValue::Number(errno as f64),
+      ));
+    }
+    Box::new(self.err.get_additional_properties().chain(properties))
+  }
+
+  fn get_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
+    self
+  }
+}
+
 impl std::fmt::Display for OperationError {
   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     if let FsError::Io(e) = &self.err {
@@ -1930,6 +2450,12 @@ trait MapErrContext {
 
   fn context(self, desc: &'static str) -> Self::R;
 
+  fn context_maybe_path(
+    self,
+    operation: &'static str,
+    path: Option<&Path>,
+  ) -> Self::R;
+
   fn context_path<'a>(
     self,
     operation: &'static str,
@@ -1962,6 +2488,21 @@ impl<T> MapErrContext for Result<T, FsError> {
     })
   }
 
+  fn context_maybe_path(
+    self,
+    operation: &'static str,
+    path: Option<&Path>,
+  ) -> Self::R {
+    self.context_fn(|err| OperationError {
+      operation,
+      kind: match path {
+        Some(path) => OperationErrorKind::WithPath(path.display().to_string()),
+        None => OperationErrorKind::Bare,
+      },
+      err,
+    })
+  }
+
   fn context_path<'a>(
     self,
     operation: &'static str,
@@ -0,0 +1,79 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Parsing of HTTP `Range` headers for file responses. This is shared by the
+//! `file:` fetch handler in `deno_fetch` and `Deno.serveFile` in `deno_http`,
+//! and isn't meant to be used outside of them.
+
+/// The part of a file a `Range` header asks for.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ByteRange {
+  Full,
+  /// Inclusive start and end offsets.
+  Partial(u64, u64),
+  Unsatisfiable,
+}
+
+/// Parses a `Range` header against a file of `size` bytes, which must not be
+/// 0. Headers that are malformed or ask for several ranges (which would need
+/// a multipart body) are ignored, like most servers do.
+pub fn parse_range(value: &[u8], size: u64) -> ByteRange {
+  let Some(spec) = std::str::from_utf8(value)
+    .ok()
+    .and_then(|value| value.trim().strip_prefix("bytes="))
+  else {
+    return ByteRange::Full;
+  };
+  if spec.contains(',') {
+    return ByteRange::Full;
+  }
+  let Some((start, end)) = spec.split_once('-') else {
+    return ByteRange::Full;
+  };
+  let (start, end) = (start.trim(), end.trim());
+  if start.is_empty() {
+    // suffix range with the last `end` bytes
+    return match end.parse::<u64>() {
+      Ok(0) => ByteRange::Unsatisfiable,
+      Ok(suffix) => ByteRange::Partial(size.saturating_sub(suffix), size - 1),
+      Err(_) => ByteRange::Full,
+    };
+  }
+  let Ok(start) = start.parse::<u64>() else {
+    return ByteRange::Full;
+  };
+  let end = if end.is_empty() {
+    size - 1
+  } else {
+    match end.parse::<u64>() {
+      Ok(end) if end >= start => end.min(size - 1),
+      _ => return ByteRange::Full,
+    }
+  };
+  if start >= size {
+    return ByteRange::Unsatisfiable;
+  }
+  ByteRange::Partial(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_byte_ranges() {
+    assert_eq!(parse_range(b"bytes=0-9", 100), ByteRange::Partial(0, 9));
+    assert_eq!(parse_range(b"bytes=90-", 100), ByteRange::Partial(90, 99));
+    assert_eq!(
+      parse_range(b"bytes=90-200", 100),
+      ByteRange::Partial(90, 99)
+    );
+    assert_eq!(parse_range(b"bytes=-10", 100), ByteRange::Partial(90, 99));
+    assert_eq!(parse_range(b"bytes=-200", 100), ByteRange::Partial(0, 99));
+    assert_eq!(parse_range(b"bytes=100-", 100), ByteRange::Unsatisfiable);
+    assert_eq!(parse_range(b"bytes=-0", 100), ByteRange::Unsatisfiable);
+    assert_eq!(parse_range(b"bytes=0-1,5-6", 100), ByteRange::Full);
+    assert_eq!(parse_range(b"bytes=9-0", 100), ByteRange::Full);
+    assert_eq!(parse_range(b"items=0-9", 100), ByteRange::Full);
+    assert_eq!(parse_range(b"bytes=a-b", 100), ByteRange::Full);
+  }
+}
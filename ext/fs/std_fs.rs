@@ -12,8 +12,8 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 
-use deno_core::unsync::spawn_blocking;
 use deno_io::StdFileResourceInner;
+use deno_io::blocking::spawn_blocking;
 use deno_io::fs::File;
 use deno_io::fs::FsError;
 use deno_io::fs::FsResult;
@@ -25,12 +25,17 @@ use crate::FileSystem;
 use crate::OpenOptions;
 use crate::interface::FsDirEntry;
 use crate::interface::FsFileType;
+use crate::interface::FsRawDirEntry;
 
 #[derive(Debug, Default, Clone)]
 pub struct RealFs;
 
 #[async_trait::async_trait(?Send)]
 impl FileSystem for RealFs {
+  fn backend_name(&self) -> &'static str {
+    "std"
+  }
+
   fn cwd(&self) -> FsResult<PathBuf> {
     std::env::current_dir().map_err(Into::into)
   }
@@ -116,7 +121,7 @@ impl FileSystem for RealFs {
     recursive: bool,
     mode: Option<u32>,
   ) -> FsResult<()> {
-    spawn_blocking(move || mkdir(&path, recursive, mode)).await?
+    spawn_blocking(move || mkdir(&path, recursive, mode))?.await?
   }
 
   #[cfg(unix)]
@@ -130,11 +135,11 @@ impl FileSystem for RealFs {
 
   #[cfg(unix)]
   async fn chmod_async(&self, path: CheckedPathBuf, mode: u32) -> FsResult<()> {
-    spawn_blocking(move || chmod(&path, mode)).await?
+    spawn_blocking(move || chmod(&path, mode))?.await?
   }
   #[cfg(not(unix))]
   async fn chmod_async(&self, path: CheckedPathBuf, mode: i32) -> FsResult<()> {
-    spawn_blocking(move || chmod(&path, mode)).await?
+    spawn_blocking(move || chmod(&path, mode))?.await?
   }
 
   fn chown_sync(
@@ -151,7 +156,7 @@ impl FileSystem for RealFs {
     uid: Option<u32>,
     gid: Option<u32>,
   ) -> FsResult<()> {
-    spawn_blocking(move || chown(&path, uid, gid)).await?
+    spawn_blocking(move || chown(&path, uid, gid))?.await?
   }
 
   fn remove_sync(&self, path: &CheckedPath, recursive: bool) -> FsResult<()> {
@@ -162,7 +167,14 @@ impl FileSystem for RealFs {
     path: CheckedPathBuf,
     recursive: bool,
   ) -> FsResult<()> {
-    spawn_blocking(move || remove(&path, recursive)).await?
+    spawn_blocking(move || remove(&path, recursive))?.await?
+  }
+
+  fn trash_sync(&self, path: &CheckedPath) -> FsResult<()> {
+    crate::trash::trash(path)
+  }
+  async fn trash_async(&self, path: CheckedPathBuf) -> FsResult<()> {
+    spawn_blocking(move || crate::trash::trash(&path))?.await?
   }
 
   fn copy_file_sync(
@@ -177,7 +189,7 @@ impl FileSystem for RealFs {
     from: CheckedPathBuf,
     to: CheckedPathBuf,
   ) -> FsResult<()> {
-    spawn_blocking(move || copy_file(&from, &to)).await?
+    spawn_blocking(move || copy_file(&from, &to))?.await?
   }
 
   fn cp_sync(&self, fro: &CheckedPath, to: &CheckedPath) -> FsResult<()> {
@@ -188,28 +200,28 @@ impl FileSystem for RealFs {
     fro: CheckedPathBuf,
     to: CheckedPathBuf,
   ) -> FsResult<()> {
-    spawn_blocking(move || cp(&fro, &to)).await?
+    spawn_blocking(move || cp(&fro, &to))?.await?
   }
 
   fn stat_sync(&self, path: &CheckedPath) -> FsResult<FsStat> {
     stat(path)
   }
   async fn stat_async(&self, path: CheckedPathBuf) -> FsResult<FsStat> {
-    spawn_blocking(move || stat(&path)).await?
+    spawn_blocking(move || stat(&path))?.await?
   }
 
   fn lstat_sync(&self, path: &CheckedPath) -> FsResult<FsStat> {
     lstat(path)
   }
   async fn lstat_async(&self, path: CheckedPathBuf) -> FsResult<FsStat> {
-    spawn_blocking(move || lstat(&path)).await?
+    spawn_blocking(move || lstat(&path))?.await?
   }
 
   fn exists_sync(&self, path: &CheckedPath) -> bool {
     exists(path)
   }
   async fn exists_async(&self, path: CheckedPathBuf) -> FsResult<bool> {
-    spawn_blocking(move || exists(&path))
+    spawn_blocking(move || exists(&path))?
       .await
       .map_err(Into::into)
   }
@@ -218,7 +230,7 @@ impl FileSystem for RealFs {
     realpath(path)
   }
   async fn realpath_async(&self, path: CheckedPathBuf) -> FsResult<PathBuf> {
-    spawn_blocking(move || realpath(&path)).await?
+    spawn_blocking(move || realpath(&path))?.await?
   }
 
   fn read_dir_sync(&self, path: &CheckedPath) -> FsResult<Vec<FsDirEntry>> {
@@ -228,7 +240,20 @@ impl FileSystem for RealFs {
     &self,
     path: CheckedPathBuf,
   ) -> FsResult<Vec<FsDirEntry>> {
-    spawn_blocking(move || read_dir(&path)).await?
+    spawn_blocking(move || read_dir(&path))?.await?
+  }
+
+  fn read_dir_raw_sync(
+    &self,
+    path: &CheckedPath,
+  ) -> FsResult<Vec<FsRawDirEntry>> {
+    read_dir_raw(path)
+  }
+  async fn read_dir_raw_async(
+    &self,
+    path: CheckedPathBuf,
+  ) -> FsResult<Vec<FsRawDirEntry>> {
+    spawn_blocking(move || read_dir_raw(&path))?.await?
   }
 
   fn rename_sync(
@@ -243,7 +268,7 @@ impl FileSystem for RealFs {
     oldpath: CheckedPathBuf,
     newpath: CheckedPathBuf,
   ) -> FsResult<()> {
-    spawn_blocking(move || fs::rename(oldpath, newpath))
+    spawn_blocking(move || fs::rename(oldpath, newpath))?
       .await?
       .map_err(Into::into)
   }
@@ -257,7 +282,7 @@ impl FileSystem for RealFs {
     path: CheckedPathBuf,
     mode: u32,
   ) -> FsResult<()> {
-    spawn_blocking(move || lchmod(&path, mode)).await?
+    spawn_blocking(move || lchmod(&path, mode))?.await?
   }
 
   fn link_sync(
@@ -272,7 +297,7 @@ impl FileSystem for RealFs {
     oldpath: CheckedPathBuf,
     newpath: CheckedPathBuf,
   ) -> FsResult<()> {
-    spawn_blocking(move || fs::hard_link(oldpath, newpath))
+    spawn_blocking(move || fs::hard_link(oldpath, newpath))?
       .await?
       .map_err(Into::into)
   }
@@ -291,14 +316,14 @@ impl FileSystem for RealFs {
     newpath: CheckedPathBuf,
     file_type: Option<FsFileType>,
   ) -> FsResult<()> {
-    spawn_blocking(move || symlink(&oldpath, &newpath, file_type)).await?
+    spawn_blocking(move || symlink(&oldpath, &newpath, file_type))?.await?
   }
 
   fn read_link_sync(&self, path: &CheckedPath) -> FsResult<PathBuf> {
     fs::read_link(path).map_err(Into::into)
   }
   async fn read_link_async(&self, path: CheckedPathBuf) -> FsResult<PathBuf> {
-    spawn_blocking(move || fs::read_link(path))
+    spawn_blocking(move || fs::read_link(path))?
       .await?
       .map_err(Into::into)
   }
@@ -311,7 +336,7 @@ impl FileSystem for RealFs {
     path: CheckedPathBuf,
     len: u64,
   ) -> FsResult<()> {
-    spawn_blocking(move || truncate(&path, len)).await?
+    spawn_blocking(move || truncate(&path, len))?.await?
   }
 
   fn utime_sync(
@@ -338,7 +363,7 @@ impl FileSystem for RealFs {
     let mtime = filetime::FileTime::from_unix_time(mtime_secs, mtime_nanos);
     spawn_blocking(move || {
       filetime::set_file_times(path, atime, mtime).map_err(Into::into)
-    })
+    })?
     .await?
   }
 
@@ -367,7 +392,7 @@ impl FileSystem for RealFs {
     let mtime = filetime::FileTime::from_unix_time(mtime_secs, mtime_nanos);
     spawn_blocking(move || {
       filetime::set_symlink_file_times(path, atime, mtime).map_err(Into::into)
-    })
+    })?
     .await?
   }
 
@@ -386,7 +411,7 @@ impl FileSystem for RealFs {
     uid: Option<u32>,
     gid: Option<u32>,
   ) -> FsResult<()> {
-    spawn_blocking(move || lchown(&path, uid, gid)).await?
+    spawn_blocking(move || lchown(&path, uid, gid))?.await?
   }
 
   fn write_file_sync(
@@ -420,7 +445,7 @@ impl FileSystem for RealFs {
       }
       file.write_all(&data)?;
       Ok(())
-    })
+    })?
     .await?
   }
 
@@ -429,26 +454,61 @@ impl FileSystem for RealFs {
     path: &CheckedPath,
     options: OpenOptions,
   ) -> FsResult<Cow<'static, [u8]>> {
-    let mut file = open_with_checked_path(options, path)?;
-    let mut buf = Vec::new();
-    file.read_to_end(&mut buf)?;
-    Ok(Cow::Owned(buf))
+    let file = open_with_checked_path(options, path)?;
+    Ok(Cow::Owned(read_file_exact(&file)?))
   }
   async fn read_file_async<'a>(
     &'a self,
     path: CheckedPathBuf,
     options: OpenOptions,
   ) -> FsResult<Cow<'static, [u8]>> {
-    let mut file = open_with_checked_path(options, &path.as_checked_path())?;
+    let file = open_with_checked_path(options, &path.as_checked_path())?;
     spawn_blocking(move || {
-      let mut buf = Vec::new();
-      file.read_to_end(&mut buf)?;
-      Ok::<_, FsError>(Cow::Owned(buf))
-    })
+      Ok::<_, FsError>(Cow::Owned(read_file_exact(&file)?))
+    })?
     .await?
   }
 }
 
+/// Reads a whole file with a single `fstat` and a buffer of exactly the
+/// file's size, so handing it to JS as a boxed slice needs no reallocation
+/// or copy. Files that report no size (procfs, pipes) or that grow while
+/// being read fall back to growing the buffer.
+fn read_file_exact(mut file: &fs::File) -> io::Result<Vec<u8>> {
+  let size = file.metadata().map(|m| m.len() as usize).unwrap_or(0);
+  let mut buf = vec![0; size];
+  let mut filled = 0;
+  while filled < size {
+    match file.read(&mut buf[filled..]) {
+      Ok(0) => break,
+      Ok(n) => filled += n,
+      Err(err) if err.kind() == ErrorKind::Interrupted => {}
+      Err(err) => return Err(err),
+    }
+  }
+  if filled < size {
+    // the file shrank since the fstat
+    buf.truncate(filled);
+    return Ok(buf);
+  }
+  // Probe on the stack for EOF so the common case keeps the exact
+  // allocation.
+  let mut probe = [0; 8192];
+  loop {
+    match file.read(&mut probe) {
+      Ok(0) => return Ok(buf),
+      Ok(n) => {
+        buf.extend_from_slice(&probe[..n]);
+        break;
+      }
+      Err(err) if err.kind() == ErrorKind::Interrupted => {}
+      Err(err) => return Err(err),
+    }
+  }
+  file.read_to_end(&mut buf)?;
+  Ok(buf)
+}
+
 fn mkdir(path: &Path, recursive: bool, mode: Option<u32>) -> FsResult<()> {
   let mut builder = fs::DirBuilder::new();
   builder.recursive(recursive);
@@ -933,6 +993,23 @@ fn read_dir(path: &Path) -> FsResult<Vec<FsDirEntry>> {
   Ok(entries)
 }
 
+fn read_dir_raw(path: &Path) -> FsResult<Vec<FsRawDirEntry>> {
+  let entries = fs::read_dir(path)?
+    .filter_map(|entry| {
+      let entry = entry.ok()?;
+      let file_type = entry.file_type().ok();
+      Some(FsRawDirEntry {
+        name: entry.file_name(),
+        is_file: file_type.is_some_and(|t| t.is_file()),
+        is_directory: file_type.is_some_and(|t| t.is_dir()),
+        is_symlink: file_type.is_some_and(|t| t.is_symlink()),
+      })
+    })
+    .collect();
+
+  Ok(entries)
+}
+
 #[cfg(not(windows))]
 fn symlink(
   oldpath: &Path,
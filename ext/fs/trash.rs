@@ -0,0 +1,433 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+#![allow(clippy::disallowed_methods)]
+
+//! Moves files and directories to the platform trash (recycle bin) instead
+//! of unlinking them.
+//!
+//! - Linux and other freedesktop platforms follow the
+//!   [Trash specification](https://specifications.freedesktop.org/trash-spec/latest/).
+//! - macOS moves the entry into `~/.Trash` (or `.Trashes/<uid>` on other
+//!   volumes), which is what `NSFileManager.trashItemAtURL` does.
+//! - Windows uses `SHFileOperationW` with `FOF_ALLOWUNDO`.
+
+use std::path::Path;
+
+use deno_io::fs::FsResult;
+
+/// The environment variables that the trash directory is found through.
+#[cfg(all(unix, not(target_os = "macos")))]
+pub const ENV_VARS: &[&str] = &["XDG_DATA_HOME", "HOME"];
+#[cfg(target_os = "macos")]
+pub const ENV_VARS: &[&str] = &["HOME"];
+#[cfg(windows)]
+pub const ENV_VARS: &[&str] = &[];
+
+pub fn trash(path: &Path) -> FsResult<()> {
+  let path = std::path::absolute(path)?;
+  // surface NotFound before touching the trash directory
+  std::fs::symlink_metadata(&path)?;
+  imp::trash(&path).map_err(Into::into)
+}
+
+#[cfg(unix)]
+fn file_name(path: &Path) -> std::io::Result<&std::ffi::OsStr> {
+  path.file_name().ok_or_else(|| {
+    std::io::Error::new(
+      std::io::ErrorKind::InvalidInput,
+      "cannot move a path without a file name to the trash",
+    )
+  })
+}
+
+/// Finds the top directory of the mount point containing `path`, which is
+/// where per-volume trash directories live.
+#[cfg(unix)]
+fn mount_top_dir(path: &Path) -> std::io::Result<std::path::PathBuf> {
+  use std::os::unix::fs::MetadataExt;
+
+  let dev = std::fs::symlink_metadata(path)?.dev();
+  let mut top = path.parent().unwrap_or(path);
+  while let Some(parent) = top.parent() {
+    if std::fs::metadata(parent)?.dev() != dev {
+      break;
+    }
+    top = parent;
+  }
+  Ok(top.to_path_buf())
+}
+
+#[cfg(unix)]
+fn create_private_dir(path: &Path) -> std::io::Result<()> {
+  use std::os::unix::fs::DirBuilderExt;
+
+  std::fs::DirBuilder::new()
+    .recursive(true)
+    .mode(0o700)
+    .create(path)
+}
+
+/// Renames `from` to `to`, failing with `AlreadyExists` instead of replacing
+/// an existing `to`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn rename_no_replace(from: &Path, to: &Path) -> std::io::Result<()> {
+  use std::os::unix::ffi::OsStrExt;
+
+  let from_c = std::ffi::CString::new(from.as_os_str().as_bytes())?;
+  let to_c = std::ffi::CString::new(to.as_os_str().as_bytes())?;
+  // SAFETY: both paths are valid nul-terminated strings
+  let result = unsafe {
+    libc::syscall(
+      libc::SYS_renameat2,
+      libc::AT_FDCWD,
+      from_c.as_ptr(),
+      libc::AT_FDCWD,
+      to_c.as_ptr(),
+      libc::RENAME_NOREPLACE,
+    )
+  };
+  if result == 0 {
+    return Ok(());
+  }
+  let err = std::io::Error::last_os_error();
+  match err.raw_os_error() {
+    // the file system or kernel does not support RENAME_NOREPLACE
+    Some(libc::EINVAL | libc::ENOSYS) => rename_if_free(from, to),
+    _ => Err(err),
+  }
+}
+
+#[cfg(target_os = "macos")]
+fn rename_no_replace(from: &Path, to: &Path) -> std::io::Result<()> {
+  use std::os::unix::ffi::OsStrExt;
+
+  let from_c = std::ffi::CString::new(from.as_os_str().as_bytes())?;
+  let to_c = std::ffi::CString::new(to.as_os_str().as_bytes())?;
+  // SAFETY: both paths are valid nul-terminated strings
+  let result = unsafe {
+    libc::renamex_np(from_c.as_ptr(), to_c.as_ptr(), libc::RENAME_EXCL)
+  };
+  if result != 0 {
+    return Err(std::io::Error::last_os_error());
+  }
+  Ok(())
+}
+
+#[cfg(all(
+  unix,
+  not(any(target_os = "android", target_os = "linux", target_os = "macos"))
+))]
+fn rename_no_replace(from: &Path, to: &Path) -> std::io::Result<()> {
+  rename_if_free(from, to)
+}
+
+/// Renames `from` to `to` unless `to` exists, for systems without an atomic
+/// rename that refuses to replace.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn rename_if_free(from: &Path, to: &Path) -> std::io::Result<()> {
+  if std::fs::symlink_metadata(to).is_ok() {
+    return Err(std::io::ErrorKind::AlreadyExists.into());
+  }
+  std::fs::rename(from, to)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod imp {
+  use std::ffi::OsStr;
+  use std::fs;
+  use std::io;
+  use std::io::ErrorKind;
+  use std::io::Write;
+  use std::os::unix::ffi::OsStrExt;
+  use std::path::Path;
+  use std::path::PathBuf;
+
+  use super::create_private_dir;
+  use super::file_name;
+  use super::mount_top_dir;
+  use super::rename_no_replace;
+
+  pub fn trash(path: &Path) -> io::Result<()> {
+    let home_trash = home_trash_dir()?;
+    match move_to_trash(path, &home_trash) {
+      // The home trash lives on another device, so use the trash at the
+      // top of the mount point instead of copying the data across.
+      Err(err) if err.raw_os_error() == Some(libc::EXDEV) => {
+        // SAFETY: libc call without preconditions
+        let uid = unsafe { libc::geteuid() };
+        let top = mount_top_dir(path)?;
+        if let Some(shared_trash) = shared_top_trash_dir(&top) {
+          let res = move_to_trash(path, &shared_trash.join(uid.to_string()));
+          if res.is_ok() {
+            return res;
+          }
+        }
+        move_to_trash(path, &top.join(format!(".Trash-{uid}")))
+      }
+      res => res,
+    }
+  }
+
+  /// The `$topdir/.Trash` directory shared by all users, which may only be
+  /// used when it is a real directory with the sticky bit set.
+  fn shared_top_trash_dir(top: &Path) -> Option<PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let shared_trash = top.join(".Trash");
+    let metadata = fs::symlink_metadata(&shared_trash).ok()?;
+    let is_sticky = metadata.permissions().mode() & 0o1000 != 0;
+    (metadata.is_dir() && is_sticky).then_some(shared_trash)
+  }
+
+  fn home_trash_dir() -> io::Result<PathBuf> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+      .map(PathBuf::from)
+      .filter(|p| p.is_absolute());
+    let data_home = match data_home {
+      Some(data_home) => data_home,
+      None => {
+        let home = std::env::var_os("HOME").ok_or_else(|| {
+          io::Error::new(
+            ErrorKind::NotFound,
+            "could not determine the home directory for the trash",
+          )
+        })?;
+        PathBuf::from(home).join(".local").join("share")
+      }
+    };
+    Ok(data_home.join("Trash"))
+  }
+
+  fn move_to_trash(path: &Path, trash_dir: &Path) -> io::Result<()> {
+    let files_dir = trash_dir.join("files");
+    let info_dir = trash_dir.join("info");
+    create_private_dir(&files_dir)?;
+    create_private_dir(&info_dir)?;
+
+    let name = file_name(path)?;
+    let info = trashinfo(path);
+    for n in 0u32.. {
+      let candidate = if n == 0 {
+        name.to_os_string()
+      } else {
+        let mut candidate = name.to_os_string();
+        candidate.push(format!(".{n}"));
+        candidate
+      };
+      let mut info_name = candidate.clone();
+      info_name.push(".trashinfo");
+      let info_path = info_dir.join(&info_name);
+      // Creating the .trashinfo file atomically reserves the name.
+      let mut info_file = match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&info_path)
+      {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+        Err(err) => return Err(err),
+      };
+      let res = info_file
+        .write_all(info.as_bytes())
+        .and_then(|_| rename_no_replace(path, &files_dir.join(&candidate)));
+      if res.is_err() {
+        let _ = fs::remove_file(&info_path);
+      }
+      match res {
+        // an entry without a .trashinfo file, left by an interrupted trash
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+        res => return res,
+      }
+    }
+    unreachable!()
+  }
+
+  fn trashinfo(path: &Path) -> String {
+    format!(
+      "[Trash Info]\nPath={}\nDeletionDate={}\n",
+      percent_encode_path(path.as_os_str()),
+      local_deletion_date(),
+    )
+  }
+
+  fn percent_encode_path(path: &OsStr) -> String {
+    let mut out = String::with_capacity(path.len());
+    for &b in path.as_bytes() {
+      match b {
+        b'a'..=b'z'
+        | b'A'..=b'Z'
+        | b'0'..=b'9'
+        | b'/'
+        | b'-'
+        | b'_'
+        | b'.'
+        | b'~' => out.push(b as char),
+        _ => out.push_str(&format!("%{b:02X}")),
+      }
+    }
+    out
+  }
+
+  /// Formats the current local time as `YYYY-MM-DDThh:mm:ss`.
+  fn local_deletion_date() -> String {
+    // SAFETY: libc calls with valid pointers to stack values
+    unsafe {
+      let now = libc::time(std::ptr::null_mut());
+      let mut tm = std::mem::zeroed::<libc::tm>();
+      libc::localtime_r(&now, &mut tm);
+      format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        tm.tm_year + 1900,
+        tm.tm_mon + 1,
+        tm.tm_mday,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec,
+      )
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_trashinfo_path() {
+      assert_eq!(
+        percent_encode_path(OsStr::new("/home/user/my file%.txt")),
+        "/home/user/my%20file%25.txt"
+      );
+      assert_eq!(
+        percent_encode_path(OsStr::from_bytes(b"/tmp/\xff")),
+        "/tmp/%FF"
+      );
+    }
+
+    #[test]
+    fn rename_does_not_replace() {
+      let dir = std::env::temp_dir()
+        .join(format!("deno_trash_rename_{}", std::process::id()));
+      fs::create_dir_all(&dir).unwrap();
+      let from = dir.join("from");
+      let to = dir.join("to");
+      fs::write(&from, "from").unwrap();
+      fs::write(&to, "to").unwrap();
+      let err = rename_no_replace(&from, &to).unwrap_err();
+      assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+      assert_eq!(fs::read_to_string(&to).unwrap(), "to");
+      fs::remove_file(&to).unwrap();
+      rename_no_replace(&from, &to).unwrap();
+      assert_eq!(fs::read_to_string(&to).unwrap(), "from");
+      fs::remove_dir_all(&dir).unwrap();
+    }
+  }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+  use std::io;
+  use std::io::ErrorKind;
+  use std::path::Path;
+  use std::path::PathBuf;
+
+  use super::create_private_dir;
+  use super::file_name;
+  use super::mount_top_dir;
+  use super::rename_no_replace;
+
+  pub fn trash(path: &Path) -> io::Result<()> {
+    let home = std::env::var_os("HOME").ok_or_else(|| {
+      io::Error::new(
+        ErrorKind::NotFound,
+        "could not determine the home directory for the trash",
+      )
+    })?;
+    let home_trash = PathBuf::from(home).join(".Trash");
+    match move_to_trash(path, &home_trash) {
+      Err(err) if err.raw_os_error() == Some(libc::EXDEV) => {
+        // SAFETY: libc call without preconditions
+        let uid = unsafe { libc::geteuid() };
+        let volume_trash =
+          mount_top_dir(path)?.join(".Trashes").join(uid.to_string());
+        create_private_dir(&volume_trash)?;
+        move_to_trash(path, &volume_trash)
+      }
+      res => res,
+    }
+  }
+
+  fn move_to_trash(path: &Path, trash_dir: &Path) -> io::Result<()> {
+    let name = Path::new(file_name(path)?);
+    let stem = name.file_stem().unwrap_or(name.as_os_str());
+    let ext = name.extension();
+    // Finder appends a counter before the extension on collisions.
+    for n in 0u32.. {
+      let candidate = if n == 0 {
+        name.as_os_str().to_os_string()
+      } else {
+        let mut candidate = stem.to_os_string();
+        candidate.push(format!(" {n}"));
+        if let Some(ext) = ext {
+          candidate.push(".");
+          candidate.push(ext);
+        }
+        candidate
+      };
+      match rename_no_replace(path, &trash_dir.join(candidate)) {
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+        res => return res,
+      }
+    }
+    unreachable!()
+  }
+}
+
+#[cfg(windows)]
+mod imp {
+  use std::io;
+  use std::os::windows::ffi::OsStrExt;
+  use std::path::Path;
+
+  use windows_sys::Win32::UI::Shell::FO_DELETE;
+  use windows_sys::Win32::UI::Shell::FOF_ALLOWUNDO;
+  use windows_sys::Win32::UI::Shell::FOF_NOCONFIRMATION;
+  use windows_sys::Win32::UI::Shell::FOF_NOERRORUI;
+  use windows_sys::Win32::UI::Shell::FOF_SILENT;
+  use windows_sys::Win32::UI::Shell::SHFILEOPSTRUCTW;
+  use windows_sys::Win32::UI::Shell::SHFileOperationW;
+
+  pub fn trash(path: &Path) -> io::Result<()> {
+    // pFrom is a list of paths terminated by an empty string
+    let from = path
+      .as_os_str()
+      .encode_wide()
+      .chain([0, 0])
+      .collect::<Vec<u16>>();
+    let mut op = SHFILEOPSTRUCTW {
+      hwnd: std::ptr::null_mut(),
+      wFunc: FO_DELETE as _,
+      pFrom: from.as_ptr(),
+      pTo: std::ptr::null(),
+      fFlags: (FOF_ALLOWUNDO | FOF_NOCONFIRMATION | FOF_NOERRORUI | FOF_SILENT)
+        as _,
+      fAnyOperationsAborted: 0,
+      hNameMappings: std::ptr::null_mut(),
+      lpszProgressTitle: std::ptr::null(),
+    };
+    // SAFETY: `op` is fully initialized and `from` outlives the call
+    let code = unsafe { SHFileOperationW(&mut op) };
+    if code != 0 {
+      return Err(io::Error::other(format!(
+        "moving '{}' to the Recycle Bin failed with code {code:#x}",
+        path.display()
+      )));
+    }
+    if op.fAnyOperationsAborted != 0 {
+      return Err(io::Error::new(
+        io::ErrorKind::Interrupted,
+        "moving to the Recycle Bin was aborted",
+      ));
+    }
+    Ok(())
+  }
+}
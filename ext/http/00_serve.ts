@@ -14,6 +14,7 @@ import {
   op_http_get_request_headers,
   op_http_get_request_method_and_url,
   op_http_metric_handle_otel_error,
+  op_http_metrics,
   op_http_notify_serving,
   op_http_read_request_body,
   op_http_request_on_cancel,
@@ -36,6 +37,9 @@ const {
   ArrayPrototypeFind,
   ArrayPrototypeMap,
   ArrayPrototypePush,
+  MathCeil,
+  NumberIsFinite,
+  NumberIsSafeInteger,
   ObjectHasOwn,
   ObjectPrototypeIsPrototypeOf,
   PromisePrototypeCatch,
@@ -43,6 +47,7 @@ const {
   SafePromisePrototypeFinally,
   SafePromiseAll,
   PromisePrototypeThen,
+  RangeError,
   StringPrototypeIncludes,
   StringPrototypeSlice,
   StringPrototypeStartsWith,
@@ -86,10 +91,15 @@ import {
 } from "ext:deno_web/06_streams.js";
 import {
   listen,
+  Listener,
   listenOptionApiName,
   UpgradedConn,
 } from "ext:deno_net/01_net.js";
-import { hasTlsKeyPairOptions, listenTls } from "ext:deno_net/02_tls.js";
+import {
+  hasTlsKeyPairOptions,
+  listenTls,
+  TlsListener,
+} from "ext:deno_net/02_tls.js";
 import {
   builtinTracer,
   ContextManager,
@@ -795,11 +805,15 @@ function serve(arg1, arg2) {
       return {
         addr: userListener.addr,
         finished: SafePromiseAll([envListener.finished, userListener.finished]),
-        shutdown() {
-          return SafePromiseAll([
-            envListener.shutdown(),
-            userListener.shutdown(),
+        async shutdown(options) {
+          const { 0: env, 1: user } = await SafePromiseAll([
+            envListener.shutdown(options),
+            userListener.shutdown(options),
           ]);
+          return {
+            droppedConnections: env.droppedConnections +
+              user.droppedConnections,
+          };
         },
         ref() {
           envListener.ref();
@@ -809,6 +823,13 @@ function serve(arg1, arg2) {
           envListener.unref();
           userListener.unref();
         },
+        metrics() {
+          return userListener.metrics();
+        },
+        clearCertificateCache() {
+          envListener.clearCertificateCache();
+          userListener.clearCertificateCache();
+        },
         [SymbolAsyncDispose]() {
           return this.shutdown();
         },
@@ -821,7 +842,73 @@ function serve(arg1, arg2) {
   return serveInner(options, handler);
 }
 
+const LIMIT_NAMES = [
+  "maxHeaderSize",
+  "maxBodySize",
+  "headerReadTimeoutMs",
+  "bodyReadTimeoutMs",
+  "http2MaxConcurrentStreams",
+];
+
+// hyper can't limit HTTP/1 request heads to fewer bytes
+const MIN_MAX_HEADER_SIZE = 8192;
+
+/**
+ * Validates the `limits` option of `Deno.serve`.
+ */
+function serveLimits(limits) {
+  if (limits === undefined) {
+    return undefined;
+  }
+  const result = { __proto__: null };
+  for (const name of new SafeArrayIterator(LIMIT_NAMES)) {
+    const value = limits[name];
+    if (value === undefined) {
+      continue;
+    }
+    if (!NumberIsSafeInteger(value) || value < 0) {
+      throw new TypeError(
+        `'limits.${name}' must be a non-negative integer: received ${value}`,
+      );
+    }
+    result[name] = value;
+  }
+  if (
+    result.maxHeaderSize !== undefined &&
+    result.maxHeaderSize < MIN_MAX_HEADER_SIZE
+  ) {
+    throw new RangeError(
+      `'limits.maxHeaderSize' must be at least ${MIN_MAX_HEADER_SIZE}: received ${result.maxHeaderSize}`,
+    );
+  }
+  return result;
+}
+
+/**
+ * Validates the `metrics` option of `Deno.serve`.
+ */
+function serveMetrics(metrics) {
+  if (metrics === undefined || metrics === false) {
+    return undefined;
+  }
+  if (metrics === true) {
+    return { __proto__: null, path: undefined };
+  }
+  const path = metrics?.path;
+  if (
+    path !== undefined &&
+    (typeof path !== "string" || !StringPrototypeStartsWith(path, "/"))
+  ) {
+    throw new TypeError(
+      `'metrics.path' must be a string starting with '/': received ${path}`,
+    );
+  }
+  return { __proto__: null, path };
+}
+
 function serveInner(options, handler) {
+  const limits = serveLimits(options.limits);
+  const metrics = serveMetrics(options.metrics);
   const wantsHttps = hasTlsKeyPairOptions(options);
   const wantsUnix = ObjectHasOwn(options, "path");
   const wantsVsock = ObjectHasOwn(options, "cid");
@@ -833,6 +920,24 @@ function serveInner(options, handler) {
       return internalServerError();
     };
 
+  if (options.listener !== undefined) {
+    const listener = options.listener;
+    if (!ObjectPrototypeIsPrototypeOf(Listener.prototype, listener)) {
+      throw new TypeError("Option 'listener' must be a 'Deno.Listener'");
+    }
+    const addr = listener.addr;
+    return serveHttpOnListener(listener, signal, handler, onError, (scheme) => {
+      if (options.onListen) {
+        options.onListen(addr);
+      } else if (addr.transport === "unix") {
+        import.meta.log("info", `Listening on ${addr.path}`);
+      } else {
+        const host = formatHostName(addr.hostname);
+        import.meta.log("info", `Listening on ${scheme}${host}:${addr.port}/`);
+      }
+    }, limits, metrics);
+  }
+
   if (wantsUnix) {
     const listener = listen({
       transport: "unix",
@@ -846,7 +951,7 @@ function serveInner(options, handler) {
       } else {
         import.meta.log("info", `Listening on ${path}`);
       }
-    });
+    }, limits, metrics);
   }
 
   if (wantsVsock) {
@@ -863,7 +968,7 @@ function serveInner(options, handler) {
       } else {
         import.meta.log("info", `Listening on vsock:${cid}:${port}`);
       }
-    });
+    }, limits, metrics);
   }
 
   if (wantsTunnel) {
@@ -885,7 +990,7 @@ function serveInner(options, handler) {
           }${additional}`,
         );
       }
-    });
+    }, limits, metrics);
   }
 
   const listenOpts = {
@@ -894,6 +999,8 @@ function serveInner(options, handler) {
     reusePort: options.reusePort ?? false,
     loadBalanced: options[kLoadBalanced] ?? false,
     tcpBacklog: options.tcpBacklog,
+    tcpFastOpen: options.tcpFastOpen,
+    deferAccept: options.deferAccept,
   };
 
   if (options.certFile || options.keyFile) {
@@ -909,13 +1016,16 @@ function serveInner(options, handler) {
 
   let listener;
   if (wantsHttps) {
-    if (!options.cert || !options.key) {
+    if (options.resolveCertificate !== undefined) {
+      listenOpts.resolveCertificate = options.resolveCertificate;
+    } else if (!options.cert || !options.key) {
       throw new TypeError(
         "Both 'cert' and 'key' must be provided to enable HTTPS",
       );
+    } else {
+      listenOpts.cert = options.cert;
+      listenOpts.key = options.key;
     }
-    listenOpts.cert = options.cert;
-    listenOpts.key = options.key;
     listenOpts.alpnProtocols = ["h2", "http/1.1"];
     listener = listenTls(listenOpts);
     listenOpts.port = listener.addr.port;
@@ -942,16 +1052,32 @@ function serveInner(options, handler) {
     }
   };
 
-  return serveHttpOnListener(listener, signal, handler, onError, onListen);
+  return serveHttpOnListener(
+    listener,
+    signal,
+    handler,
+    onError,
+    onListen,
+    limits,
+    metrics,
+  );
 }
 
 /**
  * Serve HTTP/1.1 and/or HTTP/2 on an arbitrary listener.
  */
-function serveHttpOnListener(listener, signal, handler, onError, onListen) {
+function serveHttpOnListener(
+  listener,
+  signal,
+  handler,
+  onError,
+  onListen,
+  limits = undefined,
+  metrics = undefined,
+) {
   const context = new CallbackContext(
     signal,
-    op_http_serve(listener[internalRidSymbol]),
+    op_http_serve(listener[internalRidSymbol], limits, metrics),
     listener,
   );
   const callback = mapToCallback(context, handler, onError);
@@ -964,10 +1090,18 @@ function serveHttpOnListener(listener, signal, handler, onError, onListen) {
 /**
  * Serve HTTP/1.1 and/or HTTP/2 on an arbitrary connection.
  */
-function serveHttpOnConnection(connection, signal, handler, onError, onListen) {
+function serveHttpOnConnection(
+  connection,
+  signal,
+  handler,
+  onError,
+  onListen,
+  limits = undefined,
+  metrics = undefined,
+) {
   const context = new CallbackContext(
     signal,
-    op_http_serve_on(connection[internalRidSymbol]),
+    op_http_serve_on(connection[internalRidSymbol], limits, metrics),
     null,
   );
   const callback = mapToCallback(context, handler, onError);
@@ -1050,21 +1184,36 @@ function serveHttpOn(context, addr, callback) {
   return {
     addr,
     finished,
-    async shutdown() {
+    async shutdown(options = undefined) {
+      const gracePeriodMs = options?.gracePeriodMs;
+      if (
+        gracePeriodMs !== undefined &&
+        (typeof gracePeriodMs !== "number" || !NumberIsFinite(gracePeriodMs) ||
+          gracePeriodMs < 0)
+      ) {
+        throw new TypeError(
+          `'gracePeriodMs' must be a non-negative number: received ${gracePeriodMs}`,
+        );
+      }
       try {
         if (!context.closing && !context.closed) {
           // Shut this HTTP server down gracefully
-          context.closing = op_http_close(context.serverRid, true);
+          context.closing = op_http_close(
+            context.serverRid,
+            true,
+            gracePeriodMs === undefined ? undefined : MathCeil(gracePeriodMs),
+          );
         }
 
-        await context.closing;
+        const droppedConnections = await context.closing;
+        return { droppedConnections: droppedConnections ?? 0 };
       } catch (error) {
         // The server was interrupted
         if (ObjectPrototypeIsPrototypeOf(InterruptedPrototype, error)) {
-          return;
+          return { droppedConnections: 0 };
         }
         if (ObjectPrototypeIsPrototypeOf(BadResourcePrototype, error)) {
-          return;
+          return { droppedConnections: 0 };
         }
 
         throw error;
@@ -1084,6 +1233,22 @@ function serveHttpOn(context, addr, callback) {
         core.unrefOpPromise(currentPromise);
       }
     },
+    metrics() {
+      try {
+        return op_http_metrics(context.serverRid);
+      } catch (error) {
+        if (ObjectPrototypeIsPrototypeOf(BadResourcePrototype, error)) {
+          return null;
+        }
+        throw error;
+      }
+    },
+    clearCertificateCache() {
+      const listener = context.listener;
+      if (ObjectPrototypeIsPrototypeOf(TlsListener.prototype, listener)) {
+        listener.clearCertificateCache();
+      }
+    },
     [SymbolAsyncDispose]() {
       return this.shutdown();
     },
@@ -0,0 +1,139 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+import { primordials } from "ext:core/mod.js";
+import { op_http_serve_file } from "ext:core/ops";
+const {
+  ArrayPrototypeJoin,
+  ArrayPrototypePop,
+  ArrayPrototypePush,
+  ObjectPrototypeIsPrototypeOf,
+  SafeArrayIterator,
+  StringPrototypeEndsWith,
+  StringPrototypeIncludes,
+  StringPrototypeSlice,
+  StringPrototypeSplit,
+  StringPrototypeStartsWith,
+  decodeURIComponent,
+} = primordials;
+
+import { Response } from "ext:deno_fetch/23_response.js";
+import { readableStreamForRid } from "ext:deno_web/06_streams.js";
+
+const CONDITIONAL_HEADERS = [
+  "if-none-match",
+  "if-modified-since",
+  "if-range",
+  "range",
+];
+
+function notFound(): Response {
+  return new Response("Not Found", { status: 404 });
+}
+
+function isNotFound(error: unknown): boolean {
+  return ObjectPrototypeIsPrototypeOf(Deno.errors.NotFound.prototype, error);
+}
+
+async function serveFile(req: Request, filePath: string): Promise<Response> {
+  const requestHeaders: [string, string][] = [];
+  for (const name of new SafeArrayIterator(CONDITIONAL_HEADERS)) {
+    const value = req.headers.get(name);
+    if (value !== null) {
+      ArrayPrototypePush(requestHeaders, [name, value]);
+    }
+  }
+
+  let served;
+  try {
+    served = await op_http_serve_file(
+      filePath,
+      requestHeaders,
+      req.method === "HEAD",
+    );
+  } catch (error) {
+    if (isNotFound(error)) {
+      return notFound();
+    }
+    throw error;
+  }
+
+  const body = served.bodyRid === null
+    ? null
+    : readableStreamForRid(served.bodyRid);
+  return new Response(body, {
+    status: served.status,
+    headers: served.headers,
+  });
+}
+
+/**
+ * Resolves `.` and `..` segments of a decoded URL path. The result never
+ * climbs above the root.
+ */
+function normalizeUrlPath(path: string): string {
+  const segments: string[] = [];
+  const parts = StringPrototypeSplit(path, "/");
+  for (const segment of new SafeArrayIterator(parts)) {
+    if (segment === "..") {
+      ArrayPrototypePop(segments);
+    } else if (segment !== "" && segment !== ".") {
+      ArrayPrototypePush(segments, segment);
+    }
+  }
+  return ArrayPrototypeJoin(segments, "/");
+}
+
+async function serveDir(
+  req: Request,
+  options: Deno.ServeDirOptions = { __proto__: null },
+): Promise<Response> {
+  if (req.method !== "GET" && req.method !== "HEAD") {
+    return new Response("Method Not Allowed", {
+      status: 405,
+      headers: { allow: "GET, HEAD" },
+    });
+  }
+  const fsRoot = options.fsRoot ?? ".";
+  const urlRoot = options.urlRoot ?? "";
+  const showIndex = options.showIndex ?? true;
+
+  let pathname;
+  try {
+    pathname = decodeURIComponent(new URL(req.url).pathname);
+  } catch {
+    return new Response("Bad Request", { status: 400 });
+  }
+  const prefix = urlRoot === "" ? "" : `/${normalizeUrlPath(urlRoot)}`;
+  if (
+    prefix !== "" &&
+    pathname !== prefix &&
+    !StringPrototypeStartsWith(pathname, `${prefix}/`)
+  ) {
+    return notFound();
+  }
+  const relative = normalizeUrlPath(
+    StringPrototypeSlice(pathname, prefix.length),
+  );
+  // decoded backslashes are separators on Windows, and NUL cannot be in paths
+  if (
+    StringPrototypeIncludes(relative, "\\") ||
+    StringPrototypeIncludes(relative, "\0")
+  ) {
+    return notFound();
+  }
+  const root = StringPrototypeEndsWith(fsRoot, "/") ? fsRoot : `${fsRoot}/`;
+  const filePath = `${root}${relative}`;
+
+  const response = relative === ""
+    ? notFound()
+    : await serveFile(req, filePath);
+  if (response.status !== 404 || !showIndex) {
+    return response;
+  }
+  return await serveFile(
+    req,
+    relative === "" ? `${root}index.html` : `${filePath}/index.html`,
+  );
+}
+
+export { serveDir, serveFile };
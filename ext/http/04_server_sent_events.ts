@@ -0,0 +1,149 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+import { core, primordials } from "ext:core/mod.js";
+const {
+  ArrayPrototypeJoin,
+  ArrayPrototypeMap,
+  NumberIsSafeInteger,
+  Promise,
+  SafeRegExp,
+  StringPrototypeIncludes,
+  StringPrototypeSplit,
+  TypeError,
+} = primordials;
+
+import { Response } from "ext:deno_fetch/23_response.js";
+import { ReadableStream } from "ext:deno_web/06_streams.js";
+import {
+  clearInterval,
+  setInterval,
+  unrefTimer,
+} from "ext:deno_web/02_timers.js";
+
+const DEFAULT_KEEP_ALIVE_INTERVAL = 15_000;
+const KEEP_ALIVE = core.encode(":keep-alive\n\n");
+const LINE_BREAK = new SafeRegExp("\r\n|\r|\n");
+
+function assertSingleLine(name: string, value: string) {
+  if (
+    StringPrototypeIncludes(value, "\n") ||
+    StringPrototypeIncludes(value, "\r")
+  ) {
+    throw new TypeError(`'${name}' must not contain line breaks`);
+  }
+}
+
+function linesOf(field: string, value: string): string {
+  const lines = StringPrototypeSplit(value, LINE_BREAK);
+  return ArrayPrototypeJoin(
+    ArrayPrototypeMap(lines, (line) => `${field}: ${line}\n`),
+    "",
+  );
+}
+
+function encodeEvent(event: Deno.ServerSentEvent): string {
+  let text = "";
+  if (event.comment !== undefined) {
+    text += linesOf("", event.comment);
+  }
+  if (event.event !== undefined) {
+    assertSingleLine("event", event.event);
+    text += `event: ${event.event}\n`;
+  }
+  if (event.id !== undefined) {
+    assertSingleLine("id", event.id);
+    text += `id: ${event.id}\n`;
+  }
+  if (event.retry !== undefined) {
+    if (!NumberIsSafeInteger(event.retry) || event.retry < 0) {
+      throw new TypeError(
+        `'retry' must be a non-negative integer: received ${event.retry}`,
+      );
+    }
+    text += `retry: ${event.retry}\n`;
+  }
+  if (event.data !== undefined) {
+    text += linesOf("data", event.data);
+  }
+  return `${text}\n`;
+}
+
+function serverSentEvents(
+  req: Request,
+  options: Deno.ServerSentEventsOptions = { __proto__: null },
+): Deno.ServerSentEvents {
+  const keepAliveInterval = options.keepAliveInterval ??
+    DEFAULT_KEEP_ALIVE_INTERVAL;
+  if (
+    keepAliveInterval !== false &&
+    (!NumberIsSafeInteger(keepAliveInterval) || keepAliveInterval <= 0)
+  ) {
+    throw new TypeError(
+      `'keepAliveInterval' must be a positive integer or false: received ${keepAliveInterval}`,
+    );
+  }
+
+  let controller: ReadableStreamDefaultController<Uint8Array>;
+  let keepAlive: number | undefined;
+  let isClosed = false;
+  let resolveClosed: () => void;
+  const closed = new Promise<void>((resolve) => {
+    resolveClosed = resolve;
+  });
+
+  const finish = () => {
+    if (isClosed) return;
+    isClosed = true;
+    if (keepAlive !== undefined) {
+      clearInterval(keepAlive);
+    }
+    resolveClosed();
+  };
+
+  const body = new ReadableStream<Uint8Array>({
+    start(c) {
+      controller = c;
+    },
+    // the client went away
+    cancel: finish,
+  });
+
+  if (keepAliveInterval !== false) {
+    keepAlive = setInterval(() => {
+      controller.enqueue(KEEP_ALIVE);
+    }, keepAliveInterval);
+    unrefTimer(keepAlive);
+  }
+  req.signal.addEventListener("abort", finish, { once: true });
+
+  const response = new Response(body, {
+    headers: {
+      "content-type": "text/event-stream",
+      "cache-control": "no-cache",
+      // disables response buffering in nginx and similar proxies
+      "x-accel-buffering": "no",
+    },
+  });
+
+  return {
+    response,
+    lastEventId: req.headers.get("last-event-id"),
+    closed,
+    get isClosed() {
+      return isClosed;
+    },
+    send(event) {
+      if (isClosed) {
+        throw new TypeError("The event stream is closed");
+      }
+      controller.enqueue(core.encode(encodeEvent(event)));
+    },
+    close() {
+      if (isClosed) return;
+      finish();
+      controller.close();
+    },
+  };
+}
+
+export { serverSentEvents };
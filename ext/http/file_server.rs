@@ -0,0 +1,312 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Static file responses for `Deno.serveFile`. Conditional and range
+//! headers are resolved here and the body is handed to `Deno.serve` as a
+//! resource, so the bytes never pass through JavaScript.
+
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use deno_core::AsyncResult;
+use deno_core::BufView;
+use deno_core::ByteString;
+use deno_core::OpState;
+use deno_core::Resource;
+use deno_core::ResourceId;
+use deno_core::op2;
+use deno_error::JsErrorBox;
+use deno_fs::FileSystemRc;
+use deno_fs::OpenOptions;
+use deno_fs::range::ByteRange;
+use deno_fs::range::parse_range;
+use deno_io::fs::File;
+use deno_io::fs::FsError;
+use deno_permissions::OpenAccessKind;
+use deno_permissions::PermissionsContainer;
+use serde::Serialize;
+
+#[derive(Debug, thiserror::Error, deno_error::JsError)]
+pub enum ServeFileError {
+  #[class(inherit)]
+  #[error(transparent)]
+  Fs(
+    #[from]
+    #[inherit]
+    FsError,
+  ),
+  #[class(inherit)]
+  #[error(transparent)]
+  Permission(
+    #[from]
+    #[inherit]
+    deno_permissions::PermissionCheckError,
+  ),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServedFile {
+  status: u16,
+  headers: Vec<(ByteString, ByteString)>,
+  /// Resource with the response body, unless the response has none.
+  body_rid: Option<ResourceId>,
+}
+
+/// Opens `path` and works out the response to `request_headers`. Only the
+/// `if-none-match`, `if-modified-since`, `if-range` and `range` headers are
+/// looked at. Directories and missing files result in a `NotFound` error.
+#[op2(async, stack_trace)]
+#[serde]
+pub async fn op_http_serve_file(
+  state: Rc<RefCell<OpState>>,
+  #[string] path: String,
+  #[serde] request_headers: Vec<(ByteString, ByteString)>,
+  head: bool,
+) -> Result<ServedFile, ServeFileError> {
+  let (fs, path) = {
+    let state = state.borrow();
+    let path = state
+      .borrow::<PermissionsContainer>()
+      .check_open(
+        Cow::Owned(PathBuf::from(path)),
+        OpenAccessKind::Read,
+        Some("Deno.serveFile()"),
+      )?
+      .into_owned();
+    (state.borrow::<FileSystemRc>().clone(), path)
+  };
+  let mime = content_type(&path);
+  let file = fs.open_async(path, OpenOptions::read()).await?;
+  let stat = file.clone().stat_async().await?;
+  if !stat.is_file {
+    return Err(FsError::Io(std::io::ErrorKind::NotFound.into()).into());
+  }
+
+  let header = |name: &str| {
+    request_headers
+      .iter()
+      .find(|(n, _)| n.eq_ignore_ascii_case(name.as_bytes()))
+      .and_then(|(_, v)| std::str::from_utf8(v).ok())
+  };
+  let mtime = stat.mtime.map(|ms| UNIX_EPOCH + Duration::from_millis(ms));
+  let etag =
+    format!("W/\"{:x}-{:x}\"", stat.size, stat.mtime.unwrap_or_default());
+
+  let mut headers = vec![
+    (ByteString::from("etag"), ByteString::from(etag.as_str())),
+    (ByteString::from("accept-ranges"), ByteString::from("bytes")),
+  ];
+  if let Some(mtime) = mtime {
+    headers.push((
+      ByteString::from("last-modified"),
+      ByteString::from(httpdate::fmt_http_date(mtime)),
+    ));
+  }
+
+  let not_modified = match header("if-none-match") {
+    Some(value) => etag_matches(value, &etag),
+    None => match (header("if-modified-since"), mtime) {
+      (Some(since), Some(mtime)) => httpdate::parse_http_date(since)
+        .is_ok_and(|since| truncate_to_secs(mtime) <= since),
+      _ => false,
+    },
+  };
+  if not_modified {
+    return Ok(ServedFile {
+      status: 304,
+      headers,
+      body_rid: None,
+    });
+  }
+
+  if let Some(mime) = mime {
+    headers.push((ByteString::from("content-type"), ByteString::from(mime)));
+  }
+
+  // A range is only honored while the representation the client has is
+  // still current. If-Range compares entity tags strongly, which a weak tag
+  // like ours never passes.
+  let range_is_fresh = match header("if-range") {
+    Some(value) if value.starts_with('"') || value.starts_with("W/") => false,
+    Some(value) => match (httpdate::parse_http_date(value), mtime) {
+      (Ok(date), Some(mtime)) => truncate_to_secs(mtime) == date,
+      _ => false,
+    },
+    None => true,
+  };
+  let range = match header("range") {
+    Some(range) if range_is_fresh && stat.size > 0 => {
+      parse_range(range.as_bytes(), stat.size)
+    }
+    _ => ByteRange::Full,
+  };
+
+  let (status, start, len) = match range {
+    ByteRange::Full => (200, 0, stat.size),
+    ByteRange::Partial(start, end) => {
+      headers.push((
+        ByteString::from("content-range"),
+        ByteString::from(format!("bytes {start}-{end}/{}", stat.size)),
+      ));
+      (206, start, end - start + 1)
+    }
+    ByteRange::Unsatisfiable => {
+      headers.push((
+        ByteString::from("content-range"),
+        ByteString::from(format!("bytes */{}", stat.size)),
+      ));
+      (416, 0, 0)
+    }
+  };
+  headers.push((
+    ByteString::from("content-length"),
+    ByteString::from(len.to_string()),
+  ));
+
+  if head || len == 0 {
+    return Ok(ServedFile {
+      status,
+      headers,
+      body_rid: None,
+    });
+  }
+  if start > 0 {
+    file.clone().seek_async(SeekFrom::Start(start)).await?;
+  }
+  let body = FileBodyResource {
+    file,
+    remaining: Cell::new(len),
+  };
+  let body_rid = state.borrow_mut().resource_table.add(body);
+  Ok(ServedFile {
+    status,
+    headers,
+    body_rid: Some(body_rid),
+  })
+}
+
+/// HTTP dates have a resolution of one second.
+fn truncate_to_secs(time: SystemTime) -> SystemTime {
+  let secs = time
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_secs())
+    .unwrap_or_default();
+  UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+/// Weak comparison of an `If-None-Match` list against `etag`.
+fn etag_matches(value: &str, etag: &str) -> bool {
+  let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+  let etag = opaque(etag);
+  value
+    .split(',')
+    .any(|tag| tag.trim() == "*" || opaque(tag) == etag)
+}
+
+fn content_type(path: &Path) -> Option<&'static str> {
+  let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+  let content_type = match ext.as_str() {
+    "html" | "htm" => "text/html; charset=UTF-8",
+    "css" => "text/css; charset=UTF-8",
+    "js" | "mjs" => "text/javascript; charset=UTF-8",
+    "json" | "map" => "application/json; charset=UTF-8",
+    "txt" => "text/plain; charset=UTF-8",
+    "md" => "text/markdown; charset=UTF-8",
+    "csv" => "text/csv; charset=UTF-8",
+    "xml" => "application/xml",
+    "svg" => "image/svg+xml",
+    "png" => "image/png",
+    "jpg" | "jpeg" => "image/jpeg",
+    "gif" => "image/gif",
+    "webp" => "image/webp",
+    "avif" => "image/avif",
+    "ico" => "image/vnd.microsoft.icon",
+    "wasm" => "application/wasm",
+    "pdf" => "application/pdf",
+    "zip" => "application/zip",
+    "gz" => "application/gzip",
+    "woff" => "font/woff",
+    "woff2" => "font/woff2",
+    "ttf" => "font/ttf",
+    "otf" => "font/otf",
+    "mp3" => "audio/mpeg",
+    "mp4" => "video/mp4",
+    "webm" => "video/webm",
+    _ => return None,
+  };
+  Some(content_type)
+}
+
+/// A file body which ends after `remaining` bytes, so range responses stop
+/// at the end of the range and the exact length is known up front.
+struct FileBodyResource {
+  file: Rc<dyn File>,
+  remaining: Cell<u64>,
+}
+
+impl Resource for FileBodyResource {
+  fn name(&self) -> Cow<'_, str> {
+    "httpFileBody".into()
+  }
+
+  fn read(self: Rc<Self>, limit: usize) -> AsyncResult<BufView> {
+    Box::pin(async move {
+      let remaining = self.remaining.get();
+      if remaining == 0 {
+        return Ok(BufView::empty());
+      }
+      let limit = remaining.min(limit as u64) as usize;
+      let buf = self
+        .file
+        .clone()
+        .read(limit)
+        .await
+        .map_err(JsErrorBox::from_err)?;
+      self
+        .remaining
+        .set(remaining.saturating_sub(buf.len() as u64));
+      Ok(buf)
+    })
+  }
+
+  fn size_hint(&self) -> (u64, Option<u64>) {
+    let remaining = self.remaining.get();
+    (remaining, Some(remaining))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn matches_weak_etags() {
+    let etag = "W/\"b-18f\"";
+    assert!(etag_matches("W/\"b-18f\"", etag));
+    assert!(etag_matches("\"b-18f\"", etag));
+    assert!(etag_matches("\"a\", W/\"b-18f\"", etag));
+    assert!(etag_matches("*", etag));
+    assert!(!etag_matches("\"b-180\"", etag));
+  }
+
+  #[test]
+  fn guesses_content_type_from_extension() {
+    assert_eq!(
+      content_type(Path::new("/srv/index.HTML")),
+      Some("text/html; charset=UTF-8")
+    );
+    assert_eq!(
+      content_type(Path::new("app.wasm")),
+      Some("application/wasm")
+    );
+    assert_eq!(content_type(Path::new("README")), None);
+  }
+}
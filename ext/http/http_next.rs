@@ -1,5 +1,6 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::ffi::c_void;
 use std::future::Future;
@@ -8,6 +9,7 @@ use std::io;
 use std::pin::Pin;
 use std::ptr::null;
 use std::rc::Rc;
+use std::time::Duration;
 
 use bytes::Bytes;
 use bytes::BytesMut;
@@ -57,7 +59,9 @@ use hyper::service::HttpService;
 use hyper::service::service_fn;
 use hyper::upgrade::OnUpgrade;
 use hyper_util::rt::TokioIo;
+use hyper_util::rt::TokioTimer;
 use once_cell::sync::Lazy;
+use serde::Deserialize;
 use smallvec::SmallVec;
 use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
@@ -68,6 +72,9 @@ use crate::LocalExecutor;
 use crate::Options;
 use crate::compressible::is_content_compressible;
 use crate::extract_network_stream;
+use crate::metrics::ServeMetrics;
+use crate::metrics::ServeMetricsOptions;
+use crate::metrics::ServeMetricsSnapshot;
 use crate::network_buffered_stream::NetworkStreamPrefixCheck;
 use crate::request_body::HttpRequestBody;
 use crate::request_properties::HttpConnectionProperties;
@@ -460,7 +467,12 @@ pub fn op_http_read_request_body(
     unsafe { clone_external!(external, "op_http_read_request_body") };
   let rid = match http.take_request_body() {
     Some(incoming) => {
-      let body_resource = Rc::new(HttpRequestBody::new(incoming));
+      let body_resource = Rc::new(HttpRequestBody::new(
+        incoming,
+        http.max_body_size(),
+        http.body_read_timeout(),
+        http.metrics(),
+      ));
       state.borrow_mut().resource_table.add_rc(body_resource)
     }
     _ => {
@@ -813,14 +825,47 @@ pub fn op_http_set_response_body_bytes(
   }
 }
 
+/// Limits of a single server, set with the `limits` option of `Deno.serve`.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServeLimits {
+  /// The most bytes an HTTP/1 request head or an HTTP/2 header list may
+  /// take. Larger heads are answered with `431`.
+  max_header_size: Option<usize>,
+  /// The most bytes a request body may take. Larger bodies are answered with
+  /// `413` when the length is declared, and fail to read otherwise.
+  max_body_size: Option<u64>,
+  /// How long a client may take to send an HTTP/1 request head before the
+  /// connection is closed, without a response.
+  header_read_timeout_ms: Option<u64>,
+  /// How long a read of the request body may wait for more data before it
+  /// fails.
+  body_read_timeout_ms: Option<u64>,
+  /// The most concurrent streams an HTTP/2 client may open.
+  http2_max_concurrent_streams: Option<u32>,
+}
+
+/// hyper does not accept a smaller read buffer for HTTP/1, and panics on
+/// one. `Deno.serve` rejects smaller values of `maxHeaderSize` already.
+const MIN_HTTP1_MAX_BUF_SIZE: usize = 8192;
+
 fn serve_http11_unconditional(
   io: impl HttpServeStream,
   svc: impl HttpService<Incoming, ResBody = HttpRecordResponse> + 'static,
   cancel: Rc<CancelHandle>,
   http1_builder_hook: Option<fn(http1::Builder) -> http1::Builder>,
+  limits: ServeLimits,
 ) -> impl Future<Output = Result<(), hyper::Error>> + 'static {
   let mut builder = http1::Builder::new();
   builder.keep_alive(true).writev(*USE_WRITEV);
+  if let Some(max_header_size) = limits.max_header_size {
+    builder.max_buf_size(max_header_size.max(MIN_HTTP1_MAX_BUF_SIZE));
+  }
+  if let Some(ms) = limits.header_read_timeout_ms {
+    builder
+      .timer(TokioTimer::new())
+      .header_read_timeout(Duration::from_millis(ms));
+  }
 
   if let Some(http1_builder_hook) = http1_builder_hook {
     builder = http1_builder_hook(builder);
@@ -848,8 +893,16 @@ fn serve_http2_unconditional(
   http2_builder_hook: Option<
     fn(http2::Builder<LocalExecutor>) -> http2::Builder<LocalExecutor>,
   >,
+  limits: ServeLimits,
 ) -> impl Future<Output = Result<(), hyper::Error>> + 'static {
   let mut builder = http2::Builder::new(LocalExecutor);
+  if let Some(max_header_size) = limits.max_header_size {
+    builder
+      .max_header_list_size(max_header_size.try_into().unwrap_or(u32::MAX));
+  }
+  if let Some(max_streams) = limits.http2_max_concurrent_streams {
+    builder.max_concurrent_streams(max_streams);
+  }
 
   if let Some(http2_builder_hook) = http2_builder_hook {
     builder = http2_builder_hook(builder);
@@ -872,17 +925,30 @@ async fn serve_http2_autodetect(
   svc: impl HttpService<Incoming, ResBody = HttpRecordResponse> + 'static,
   cancel: Rc<CancelHandle>,
   options: Options,
+  limits: ServeLimits,
 ) -> Result<(), HttpNextError> {
   let prefix = NetworkStreamPrefixCheck::new(io, HTTP2_PREFIX);
   let (matches, io) = prefix.match_prefix().await?;
   if matches {
-    serve_http2_unconditional(io, svc, cancel, options.http2_builder_hook)
-      .await
-      .map_err(HttpNextError::Hyper)
+    serve_http2_unconditional(
+      io,
+      svc,
+      cancel,
+      options.http2_builder_hook,
+      limits,
+    )
+    .await
+    .map_err(HttpNextError::Hyper)
   } else {
-    serve_http11_unconditional(io, svc, cancel, options.http1_builder_hook)
-      .await
-      .map_err(HttpNextError::Hyper)
+    serve_http11_unconditional(
+      io,
+      svc,
+      cancel,
+      options.http1_builder_hook,
+      limits,
+    )
+    .await
+    .map_err(HttpNextError::Hyper)
   }
 }
 
@@ -897,8 +963,11 @@ fn serve_https(
     server_state,
     connection_cancel_handle,
     listen_cancel_handle,
+    active_connections,
+    limits,
   } = lifetime;
 
+  let active_connection = ActiveConnection::new(active_connections);
   let legacy_abort = !options.no_legacy_abort;
   let svc = service_fn(move |req: Request| {
     handle_request(
@@ -911,6 +980,7 @@ fn serve_https(
   });
   spawn(
     async move {
+      let _active_connection = active_connection;
       let handshake = io.handshake().await?;
       // If the client specifically negotiates a protocol, we will use it. If not, we'll auto-detect
       // based on the prefix bytes
@@ -921,6 +991,7 @@ fn serve_https(
           svc,
           listen_cancel_handle,
           options.http2_builder_hook,
+          limits,
         )
         .await
         .map_err(HttpNextError::Hyper)
@@ -930,11 +1001,13 @@ fn serve_https(
           svc,
           listen_cancel_handle,
           options.http1_builder_hook,
+          limits,
         )
         .await
         .map_err(HttpNextError::Hyper)
       } else {
-        serve_http2_autodetect(io, svc, listen_cancel_handle, options).await
+        serve_http2_autodetect(io, svc, listen_cancel_handle, options, limits)
+          .await
       }
     }
     .try_or_cancel(connection_cancel_handle),
@@ -952,8 +1025,11 @@ fn serve_http(
     server_state,
     connection_cancel_handle,
     listen_cancel_handle,
+    active_connections,
+    limits,
   } = lifetime;
 
+  let active_connection = ActiveConnection::new(active_connections);
   let legacy_abort = !options.no_legacy_abort;
   let svc = service_fn(move |req: Request| {
     handle_request(
@@ -964,10 +1040,12 @@ fn serve_http(
       legacy_abort,
     )
   });
-  spawn(
-    serve_http2_autodetect(io, svc, listen_cancel_handle, options)
-      .try_or_cancel(connection_cancel_handle),
-  )
+  spawn(async move {
+    let _active_connection = active_connection;
+    serve_http2_autodetect(io, svc, listen_cancel_handle, options, limits)
+      .try_or_cancel(connection_cancel_handle)
+      .await
+  })
 }
 
 fn serve_http_on<HTTP>(
@@ -1015,6 +1093,24 @@ struct HttpLifetime {
   connection_cancel_handle: Rc<CancelHandle>,
   listen_cancel_handle: Rc<CancelHandle>,
   server_state: SignallingRc<HttpServerState>,
+  active_connections: Rc<Cell<u32>>,
+  limits: ServeLimits,
+}
+
+/// Counts a connection as active for as long as it is alive.
+struct ActiveConnection(Rc<Cell<u32>>);
+
+impl ActiveConnection {
+  fn new(active_connections: Rc<Cell<u32>>) -> Self {
+    active_connections.set(active_connections.get() + 1);
+    Self(active_connections)
+  }
+}
+
+impl Drop for ActiveConnection {
+  fn drop(&mut self) {
+    self.0.set(self.0.get() - 1);
+  }
 }
 
 struct HttpJoinHandle {
@@ -1023,16 +1119,35 @@ struct HttpJoinHandle {
   listen_cancel_handle: Rc<CancelHandle>,
   rx: AsyncRefCell<tokio::sync::mpsc::Receiver<Rc<HttpRecord>>>,
   server_state: SignallingRc<HttpServerState>,
+  active_connections: Rc<Cell<u32>>,
+  limits: ServeLimits,
 }
 
 impl HttpJoinHandle {
-  fn new(rx: tokio::sync::mpsc::Receiver<Rc<HttpRecord>>) -> Self {
+  fn new(
+    rx: tokio::sync::mpsc::Receiver<Rc<HttpRecord>>,
+    limits: ServeLimits,
+    metrics: Option<ServeMetricsOptions>,
+  ) -> Self {
+    let server_state = HttpServerState::new();
+    let active_connections = Rc::new(Cell::new(0));
+    {
+      let mut server_state = server_state.borrow_mut();
+      server_state.max_body_size = limits.max_body_size;
+      server_state.body_read_timeout =
+        limits.body_read_timeout_ms.map(Duration::from_millis);
+      server_state.metrics = metrics.map(|options| {
+        Rc::new(ServeMetrics::new(options, active_connections.clone()))
+      });
+    }
     Self {
       join_handle: AsyncRefCell::new(None),
       connection_cancel_handle: CancelHandle::new_rc(),
       listen_cancel_handle: CancelHandle::new_rc(),
       rx: AsyncRefCell::new(rx),
-      server_state: HttpServerState::new(),
+      server_state,
+      active_connections,
+      limits,
     }
   }
 
@@ -1041,6 +1156,8 @@ impl HttpJoinHandle {
       connection_cancel_handle: self.connection_cancel_handle.clone(),
       listen_cancel_handle: self.listen_cancel_handle.clone(),
       server_state: self.server_state.clone(),
+      active_connections: self.active_connections.clone(),
+      limits: self.limits,
     }
   }
 
@@ -1078,6 +1195,8 @@ impl Drop for HttpJoinHandle {
 pub fn op_http_serve<HTTP>(
   state: Rc<RefCell<OpState>>,
   #[smi] listener_rid: ResourceId,
+  #[serde] limits: Option<ServeLimits>,
+  #[serde] metrics: Option<ServeMetricsOptions>,
 ) -> Result<(ResourceId, &'static str, String, bool), HttpNextError>
 where
   HTTP: HttpPropertyExtractor,
@@ -1088,7 +1207,8 @@ where
   let listen_properties = HTTP::listen_properties_from_listener(&listener)?;
 
   let (tx, rx) = tokio::sync::mpsc::channel(10);
-  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(rx));
+  let resource: Rc<HttpJoinHandle> =
+    Rc::new(HttpJoinHandle::new(rx, limits.unwrap_or_default(), metrics));
   let listen_cancel_clone = resource.listen_cancel_handle();
 
   let lifetime = resource.lifetime();
@@ -1134,6 +1254,8 @@ where
 pub fn op_http_serve_on<HTTP>(
   state: Rc<RefCell<OpState>>,
   #[smi] connection_rid: ResourceId,
+  #[serde] limits: Option<ServeLimits>,
+  #[serde] metrics: Option<ServeMetricsOptions>,
 ) -> Result<(ResourceId, &'static str, String, bool), HttpNextError>
 where
   HTTP: HttpPropertyExtractor,
@@ -1144,7 +1266,8 @@ where
   let listen_properties = HTTP::listen_properties_from_connection(&connection)?;
 
   let (tx, rx) = tokio::sync::mpsc::channel(10);
-  let resource: Rc<HttpJoinHandle> = Rc::new(HttpJoinHandle::new(rx));
+  let resource: Rc<HttpJoinHandle> =
+    Rc::new(HttpJoinHandle::new(rx, limits.unwrap_or_default(), metrics));
 
   let options = {
     let state = state.borrow();
@@ -1321,25 +1444,58 @@ pub fn op_http_cancel(
   Ok(())
 }
 
+/// Snapshot of the metrics of a server, if it was started with them.
+#[op2]
+#[serde]
+pub fn op_http_metrics(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+) -> Result<Option<ServeMetricsSnapshot>, deno_core::error::ResourceError> {
+  let join_handle = state.resource_table.get::<HttpJoinHandle>(rid)?;
+  let metrics = join_handle.server_state.borrow().metrics.clone();
+  Ok(metrics.map(|metrics| metrics.snapshot()))
+}
+
+/// Shuts the server down and resolves with the number of connections that
+/// were closed while still open. A graceful shutdown stops accepting and
+/// lets the open connections drain, but only for `grace_period_ms` if given.
 #[op2(async)]
 pub async fn op_http_close(
   state: Rc<RefCell<OpState>>,
   #[smi] rid: ResourceId,
   graceful: bool,
-) -> Result<(), HttpNextError> {
+  #[serde] grace_period_ms: Option<u64>,
+) -> Result<u32, HttpNextError> {
   let join_handle = state
     .borrow_mut()
     .resource_table
     .take::<HttpJoinHandle>(rid)?;
 
+  let mut dropped_connections = 0;
   if graceful {
     http_general_trace!("graceful shutdown");
     // In a graceful shutdown, we close the listener and allow all the remaining connections to drain
     join_handle.listen_cancel_handle().cancel();
-    poll_fn(|cx| join_handle.server_state.poll_complete(cx)).await;
+    let drained = poll_fn(|cx| join_handle.server_state.poll_complete(cx));
+    match grace_period_ms {
+      Some(ms) => {
+        if tokio::time::timeout(Duration::from_millis(ms), drained)
+          .await
+          .is_err()
+        {
+          http_general_trace!("grace period elapsed");
+          dropped_connections = join_handle.active_connections.get();
+          join_handle.connection_cancel_handle().cancel();
+          // Give streaming responses a tick to close
+          tokio::task::yield_now().await;
+        }
+      }
+      None => drained.await,
+    }
   } else {
     http_general_trace!("forceful shutdown");
     // In a forceful shutdown, we close everything
+    dropped_connections = join_handle.active_connections.get();
     join_handle.listen_cancel_handle().cancel();
     join_handle.connection_cancel_handle().cancel();
     // Give streaming responses a tick to close
@@ -1355,7 +1511,7 @@ pub async fn op_http_close(
     join_handle.await??;
   }
 
-  Ok(())
+  Ok(dropped_connections)
 }
 
 enum UpgradeStreamWriteState {
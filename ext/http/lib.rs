@@ -90,8 +90,10 @@ use crate::reader_stream::ExternallyAbortableReaderStream;
 use crate::reader_stream::ShutdownHandle;
 
 pub mod compressible;
+mod file_server;
 mod fly_accept_encoding;
 mod http_next;
+mod metrics;
 mod network_buffered_stream;
 mod reader_stream;
 mod request_body;
@@ -99,6 +101,7 @@ mod request_properties;
 mod response_body;
 mod service;
 
+pub use file_server::ServeFileError;
 use fly_accept_encoding::Encoding;
 pub use http_next::HttpNextError;
 pub use request_properties::DefaultHttpPropertyExtractor;
@@ -154,6 +157,7 @@ deno_core::extension!(
     op_http_write_headers,
     op_http_write_resource,
     op_http_write,
+    file_server::op_http_serve_file,
     http_next::op_http_close_after_finish,
     http_next::op_http_get_request_header,
     http_next::op_http_get_request_headers,
@@ -178,9 +182,16 @@ deno_core::extension!(
     http_next::op_http_wait,
     http_next::op_http_close,
     http_next::op_http_cancel,
+    http_next::op_http_metrics,
     http_next::op_http_metric_handle_otel_error,
   ],
-  esm = ["00_serve.ts", "01_http.js", "02_websocket.ts"],
+  esm = [
+    "00_serve.ts",
+    "01_http.js",
+    "02_websocket.ts",
+    "03_file_server.ts",
+    "04_server_sent_events.ts",
+  ],
   options = {
     options: Options,
   },
@@ -204,6 +215,7 @@ deno_core::extension!(
     op_http_write_resource,
     op_http_write,
     op_http_notify_serving,
+    file_server::op_http_serve_file,
     http_next::op_http_close_after_finish,
     http_next::op_http_get_request_header,
     http_next::op_http_get_request_headers,
@@ -228,9 +240,16 @@ deno_core::extension!(
     http_next::op_http_wait,
     http_next::op_http_close,
     http_next::op_http_cancel,
+    http_next::op_http_metrics,
     http_next::op_http_metric_handle_otel_error,
   ],
-  esm = ["00_serve.ts", "01_http.js", "02_websocket.ts"],
+  esm = [
+    "00_serve.ts",
+    "01_http.js",
+    "02_websocket.ts",
+    "03_file_server.ts",
+    "04_server_sent_events.ts",
+  ],
   options = {
     options: Options,
   },
@@ -0,0 +1,289 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Opt-in metrics for `Deno.serve`, readable from JavaScript as a snapshot
+//! and from HTTP clients in the Prometheus text exposition format.
+
+use std::cell::Cell;
+use std::fmt::Write;
+use std::rc::Rc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Upper bounds, in seconds, of the request duration histogram. These are
+/// the default buckets of the Prometheus client libraries.
+const DURATION_BUCKETS: [f64; 11] = [
+  0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+const STATUS_CLASSES: [&str; 5] = ["1xx", "2xx", "3xx", "4xx", "5xx"];
+
+/// Content type of the text exposition format.
+pub const METRICS_CONTENT_TYPE: &str =
+  "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ServeMetricsOptions {
+  /// Requests to this path are answered with the metrics instead of being
+  /// passed to the handler.
+  path: Option<String>,
+}
+
+pub struct ServeMetrics {
+  path: Option<String>,
+  active_connections: Rc<Cell<u32>>,
+  responses: [Cell<u64>; 5],
+  /// Non-cumulative counts per bucket, with a final bucket for `+Inf`.
+  duration_buckets: [Cell<u64>; DURATION_BUCKETS.len() + 1],
+  duration_sum: Cell<f64>,
+  request_body_bytes: Cell<u64>,
+  response_body_bytes: Cell<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationBucket {
+  le: f64,
+  count: u64,
+}
+
+#[derive(Serialize)]
+pub struct ResponsesByStatusClass {
+  #[serde(rename = "1xx")]
+  informational: u64,
+  #[serde(rename = "2xx")]
+  success: u64,
+  #[serde(rename = "3xx")]
+  redirection: u64,
+  #[serde(rename = "4xx")]
+  client_error: u64,
+  #[serde(rename = "5xx")]
+  server_error: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServeMetricsSnapshot {
+  requests: u64,
+  responses: ResponsesByStatusClass,
+  active_connections: u32,
+  request_body_bytes: u64,
+  response_body_bytes: u64,
+  /// Cumulative buckets, the way Prometheus reports them. Durations above
+  /// the last bucket are only part of `requests`.
+  request_duration_buckets: Vec<DurationBucket>,
+  request_duration_sum: f64,
+}
+
+impl ServeMetrics {
+  pub fn new(
+    options: ServeMetricsOptions,
+    active_connections: Rc<Cell<u32>>,
+  ) -> Self {
+    Self {
+      path: options.path,
+      active_connections,
+      responses: Default::default(),
+      duration_buckets: Default::default(),
+      duration_sum: Cell::new(0.0),
+      request_body_bytes: Cell::new(0),
+      response_body_bytes: Cell::new(0),
+    }
+  }
+
+  /// Whether a request for `path` should be answered with the metrics.
+  pub fn is_metrics_path(&self, path: &str) -> bool {
+    self.path.as_deref() == Some(path)
+  }
+
+  /// Records a response once its head is ready. `duration` is the time
+  /// from receiving the request until then.
+  pub fn record_response(&self, status: u16, duration: Duration) {
+    if let Some(count) = self.responses.get(status as usize / 100 - 1) {
+      count.set(count.get() + 1);
+    }
+    let secs = duration.as_secs_f64();
+    let bucket = DURATION_BUCKETS
+      .iter()
+      .position(|le| secs <= *le)
+      .unwrap_or(DURATION_BUCKETS.len());
+    let count = &self.duration_buckets[bucket];
+    count.set(count.get() + 1);
+    self.duration_sum.set(self.duration_sum.get() + secs);
+  }
+
+  pub fn add_request_body_bytes(&self, len: usize) {
+    let bytes = &self.request_body_bytes;
+    bytes.set(bytes.get() + len as u64);
+  }
+
+  pub fn add_response_body_bytes(&self, len: usize) {
+    let bytes = &self.response_body_bytes;
+    bytes.set(bytes.get() + len as u64);
+  }
+
+  fn requests(&self) -> u64 {
+    self.duration_buckets.iter().map(Cell::get).sum()
+  }
+
+  fn cumulative_buckets(&self) -> impl Iterator<Item = (f64, u64)> + '_ {
+    let mut total = 0;
+    DURATION_BUCKETS.iter().zip(&self.duration_buckets).map(
+      move |(le, count)| {
+        total += count.get();
+        (*le, total)
+      },
+    )
+  }
+
+  pub fn snapshot(&self) -> ServeMetricsSnapshot {
+    ServeMetricsSnapshot {
+      requests: self.requests(),
+      responses: ResponsesByStatusClass {
+        informational: self.responses[0].get(),
+        success: self.responses[1].get(),
+        redirection: self.responses[2].get(),
+        client_error: self.responses[3].get(),
+        server_error: self.responses[4].get(),
+      },
+      active_connections: self.active_connections.get(),
+      request_body_bytes: self.request_body_bytes.get(),
+      response_body_bytes: self.response_body_bytes.get(),
+      request_duration_buckets: self
+        .cumulative_buckets()
+        .map(|(le, count)| DurationBucket { le, count })
+        .collect(),
+      request_duration_sum: self.duration_sum.get(),
+    }
+  }
+
+  /// Renders the metrics in the Prometheus text exposition format.
+  pub fn render(&self) -> String {
+    let mut out = String::new();
+    // Writing to a `String` cannot fail.
+    let _ = self.write_text(&mut out);
+    out
+  }
+
+  fn write_text(&self, out: &mut String) -> std::fmt::Result {
+    writeln!(
+      out,
+      "# HELP deno_serve_responses_total Responses sent, by status class."
+    )?;
+    writeln!(out, "# TYPE deno_serve_responses_total counter")?;
+    for (class, count) in STATUS_CLASSES.iter().zip(&self.responses) {
+      writeln!(
+        out,
+        "deno_serve_responses_total{{status_class=\"{class}\"}} {}",
+        count.get()
+      )?;
+    }
+
+    let name = "deno_serve_request_duration_seconds";
+    writeln!(
+      out,
+      "# HELP {name} Time from receiving a request until the response head \
+       was ready."
+    )?;
+    writeln!(out, "# TYPE {name} histogram")?;
+    for (le, count) in self.cumulative_buckets() {
+      writeln!(out, "{name}_bucket{{le=\"{le}\"}} {count}")?;
+    }
+    let requests = self.requests();
+    writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {requests}")?;
+    writeln!(out, "{name}_sum {}", self.duration_sum.get())?;
+    writeln!(out, "{name}_count {requests}")?;
+
+    writeln!(
+      out,
+      "# HELP deno_serve_active_connections Connections currently open."
+    )?;
+    writeln!(out, "# TYPE deno_serve_active_connections gauge")?;
+    writeln!(
+      out,
+      "deno_serve_active_connections {}",
+      self.active_connections.get()
+    )?;
+
+    for (name, help, bytes) in [
+      (
+        "deno_serve_request_body_bytes_total",
+        "Request body bytes read by handlers.",
+        &self.request_body_bytes,
+      ),
+      (
+        "deno_serve_response_body_bytes_total",
+        "Response body bytes sent.",
+        &self.response_body_bytes,
+      ),
+    ] {
+      writeln!(out, "# HELP {name} {help}")?;
+      writeln!(out, "# TYPE {name} counter")?;
+      writeln!(out, "{name} {}", bytes.get())?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn renders_text_exposition() {
+    let metrics = ServeMetrics::new(Default::default(), Rc::new(Cell::new(2)));
+    metrics.record_response(200, Duration::from_millis(3));
+    metrics.record_response(404, Duration::from_millis(30));
+    metrics.record_response(503, Duration::from_secs(20));
+    metrics.add_response_body_bytes(11);
+
+    let text = metrics.render();
+    assert!(
+      text.contains("deno_serve_responses_total{status_class=\"2xx\"} 1")
+    );
+    assert!(
+      text.contains("deno_serve_responses_total{status_class=\"3xx\"} 0")
+    );
+    assert!(
+      text
+        .contains("deno_serve_request_duration_seconds_bucket{le=\"0.005\"} 1")
+    );
+    assert!(
+      text
+        .contains("deno_serve_request_duration_seconds_bucket{le=\"0.05\"} 2")
+    );
+    assert!(
+      text.contains("deno_serve_request_duration_seconds_bucket{le=\"10\"} 2")
+    );
+    assert!(
+      text
+        .contains("deno_serve_request_duration_seconds_bucket{le=\"+Inf\"} 3")
+    );
+    assert!(text.contains("deno_serve_request_duration_seconds_count 3"));
+    assert!(text.contains("deno_serve_active_connections 2"));
+    assert!(text.contains("deno_serve_response_body_bytes_total 11"));
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.requests, 3);
+    assert_eq!(snapshot.responses.server_error, 1);
+    assert_eq!(snapshot.request_duration_buckets[10].count, 2);
+  }
+
+  #[test]
+  fn matches_metrics_path() {
+    let metrics = ServeMetrics::new(
+      ServeMetricsOptions {
+        path: Some("/metrics".into()),
+      },
+      Default::default(),
+    );
+    assert!(metrics.is_metrics_path("/metrics"));
+    assert!(!metrics.is_metrics_path("/"));
+    assert!(
+      !ServeMetrics::new(Default::default(), Default::default())
+        .is_metrics_path("/metrics")
+    );
+  }
+}
@@ -1,9 +1,11 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::pin::Pin;
 use std::rc::Rc;
 use std::task::Poll;
 use std::task::ready;
+use std::time::Duration;
 
 use bytes::Bytes;
 use deno_core::AsyncRefCell;
@@ -20,6 +22,8 @@ use hyper::body::Body;
 use hyper::body::Incoming;
 use hyper::body::SizeHint;
 
+use crate::metrics::ServeMetrics;
+
 /// Converts a hyper incoming body stream into a stream of [`Bytes`] that we can use to read in V8.
 struct ReadFuture(Incoming);
 
@@ -52,12 +56,31 @@ impl Stream for ReadFuture {
   }
 }
 
-pub struct HttpRequestBody(AsyncRefCell<Peekable<ReadFuture>>, SizeHint);
+pub struct HttpRequestBody(
+  AsyncRefCell<Peekable<ReadFuture>>,
+  SizeHint,
+  /// How many more bytes may be read, if the body size is limited.
+  Option<Cell<u64>>,
+  /// How long a read may wait for data.
+  Option<Duration>,
+  Option<Rc<ServeMetrics>>,
+);
 
 impl HttpRequestBody {
-  pub fn new(body: Incoming) -> Self {
+  pub fn new(
+    body: Incoming,
+    max_size: Option<u64>,
+    read_timeout: Option<Duration>,
+    metrics: Option<Rc<ServeMetrics>>,
+  ) -> Self {
     let size_hint = body.size_hint();
-    Self(AsyncRefCell::new(ReadFuture(body).peekable()), size_hint)
+    Self(
+      AsyncRefCell::new(ReadFuture(body).peekable()),
+      size_hint,
+      max_size.map(Cell::new),
+      read_timeout,
+      metrics,
+    )
   }
 
   async fn read(self: Rc<Self>, limit: usize) -> Result<BufView, hyper::Error> {
@@ -84,10 +107,32 @@ impl Resource for HttpRequestBody {
   }
 
   fn read(self: Rc<Self>, limit: usize) -> AsyncResult<BufView> {
-    Box::pin(
-      HttpRequestBody::read(self, limit)
-        .map_err(|e| JsErrorBox::new("Http", e.to_string())),
-    )
+    Box::pin(async move {
+      let read = HttpRequestBody::read(self.clone(), limit)
+        .map_err(|e| JsErrorBox::new("Http", e.to_string()));
+      let buf = match self.3 {
+        Some(timeout) => {
+          tokio::time::timeout(timeout, read).await.map_err(|_| {
+            JsErrorBox::new("Http", "Timed out reading the request body")
+          })??
+        }
+        None => read.await?,
+      };
+      if let Some(remaining) = &self.2 {
+        let len = buf.len() as u64;
+        if len > remaining.get() {
+          return Err(JsErrorBox::new(
+            "Http",
+            "Request body is larger than the limit of the server",
+          ));
+        }
+        remaining.set(remaining.get() - len);
+      }
+      if let Some(metrics) = &self.4 {
+        metrics.add_request_body_bytes(buf.len());
+      }
+      Ok(buf)
+    })
   }
 
   fn size_hint(&self) -> (u64, Option<u64>) {
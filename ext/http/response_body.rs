@@ -206,18 +206,35 @@ impl ResponseBytesInner {
 pub struct ResourceBodyAdapter {
   auto_close: bool,
   stm: Rc<dyn Resource>,
+  /// The bytes left of the upper bound of the size hint, which is sent as the
+  /// `Content-Length`. The body is cut off there, for example when a file
+  /// grows while it is being sent.
+  remaining: Option<u64>,
   future: AsyncResult<BufView>,
 }
 
 impl ResourceBodyAdapter {
   pub fn new(stm: Rc<dyn Resource>, auto_close: bool) -> Self {
-    let future = stm.clone().read(64 * 1024);
+    let remaining = stm.size_hint().1;
+    let future = Self::read(&stm, remaining);
     ResourceBodyAdapter {
       auto_close,
       stm,
+      remaining,
       future,
     }
   }
+
+  fn read(
+    stm: &Rc<dyn Resource>,
+    remaining: Option<u64>,
+  ) -> AsyncResult<BufView> {
+    match remaining {
+      Some(0) => Box::pin(std::future::ready(Ok(BufView::empty()))),
+      Some(remaining) => stm.clone().read(remaining.min(64 * 1024) as usize),
+      None => stm.clone().read(64 * 1024),
+    }
+  }
 }
 
 impl PollFrame for ResponseStream {
@@ -248,15 +265,21 @@ impl PollFrame for ResourceBodyAdapter {
   ) -> std::task::Poll<ResponseStreamResult> {
     let res = match ready!(self.future.poll_unpin(cx)) {
       Err(err) => ResponseStreamResult::Error(err),
-      Ok(buf) => {
+      Ok(mut buf) => {
         if buf.is_empty() {
           if self.auto_close {
             self.stm.clone().close();
           }
           ResponseStreamResult::EndOfStream
         } else {
+          if let Some(remaining) = self.remaining {
+            if buf.len() as u64 > remaining {
+              buf = BufView::from(buf[..remaining as usize].to_vec());
+            }
+            self.remaining = Some(remaining - buf.len() as u64);
+          }
           // Re-arm the future
-          self.future = self.stm.clone().read(64 * 1024);
+          self.future = Self::read(&self.stm, self.remaining);
           ResponseStreamResult::NonEmptyBuf(buf)
         }
       }
@@ -265,6 +288,9 @@ impl PollFrame for ResourceBodyAdapter {
   }
 
   fn size_hint(&self) -> SizeHint {
+    if let Some(remaining) = self.remaining {
+      return SizeHint::with_exact(remaining);
+    }
     let hint = self.stm.size_hint();
     let mut size_hint = SizeHint::new();
     size_hint.set_lower(hint.0);
@@ -647,6 +673,50 @@ mod tests {
     vec![v, v2].into_iter()
   }
 
+  /// A resource that returns its chunks regardless of the read limit.
+  struct ChunksResource {
+    chunks: std::cell::RefCell<Vec<&'static [u8]>>,
+    len: u64,
+  }
+
+  impl Resource for ChunksResource {
+    fn read(self: Rc<Self>, _limit: usize) -> AsyncResult<BufView> {
+      let mut chunks = self.chunks.borrow_mut();
+      let buf = if chunks.is_empty() {
+        BufView::empty()
+      } else {
+        BufView::from(chunks.remove(0).to_vec())
+      };
+      Box::pin(std::future::ready(Ok(buf)))
+    }
+
+    fn size_hint(&self) -> (u64, Option<u64>) {
+      (self.len, Some(self.len))
+    }
+  }
+
+  #[tokio::test]
+  async fn resource_body_stops_at_size_hint() {
+    let stm = Rc::new(ChunksResource {
+      chunks: std::cell::RefCell::new(vec![
+        b"hello".as_slice(),
+        b" world".as_slice(),
+        b"!".as_slice(),
+      ]),
+      len: 8,
+    });
+    let mut body = ResourceBodyAdapter::new(stm, false);
+    let mut out = vec![];
+    loop {
+      match poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+        ResponseStreamResult::NonEmptyBuf(buf) => out.extend(&*buf),
+        ResponseStreamResult::EndOfStream => break,
+        _ => panic!("Unexpected stream type"),
+      }
+    }
+    assert_eq!(out, b"hello wo");
+  }
+
   async fn test_gzip(i: impl Iterator<Item = Vec<u8>> + Send + 'static) {
     let v = i.collect::<Vec<_>>();
     let mut expected: Vec<u8> = vec![];
@@ -14,6 +14,7 @@ use std::task::Context;
 use std::task::Poll;
 use std::task::Waker;
 use std::task::ready;
+use std::time::Duration;
 
 use deno_core::BufView;
 use deno_core::OpState;
@@ -32,6 +33,8 @@ use tokio::sync::oneshot;
 
 use crate::OtelInfo;
 use crate::OtelInfoAttributes;
+use crate::metrics::METRICS_CONTENT_TYPE;
+use crate::metrics::ServeMetrics;
 use crate::request_properties::HttpConnectionProperties;
 use crate::response_body::ResponseBytesInner;
 use crate::response_body::ResponseStreamResult;
@@ -80,6 +83,11 @@ pub(crate) use http_trace;
 
 pub(crate) struct HttpServerStateInner {
   pool: Vec<(Rc<HttpRecord>, HeaderMap)>,
+  /// Requests with larger bodies are rejected with `413 Content Too Large`.
+  pub max_body_size: Option<u64>,
+  /// Reads of request bodies that wait longer for data fail.
+  pub body_read_timeout: Option<Duration>,
+  pub metrics: Option<Rc<ServeMetrics>>,
 }
 
 /// A signalling version of `Rc` that allows one to poll for when all other references
@@ -143,6 +151,9 @@ impl HttpServerState {
   pub fn new() -> SignallingRc<Self> {
     SignallingRc::new(Self(RefCell::new(HttpServerStateInner {
       pool: Vec::new(),
+      max_body_size: None,
+      body_read_timeout: None,
+      metrics: None,
     })))
   }
 }
@@ -216,6 +227,34 @@ pub(crate) async fn handle_request(
     return Ok(response);
   }
 
+  let max_body_size = server_state.borrow().max_body_size;
+  if let Some(max_body_size) = max_body_size
+    && request.body().size_hint().lower() > max_body_size
+  {
+    let mut response = Response::new(HttpRecordResponse(None));
+    *response.version_mut() = request.version();
+    *response.status_mut() = http::StatusCode::PAYLOAD_TOO_LARGE;
+    return Ok(response);
+  }
+
+  let metrics = server_state.borrow().metrics.clone();
+  let received = metrics.as_ref().map(|_| std::time::Instant::now());
+  if let Some(metrics) = &metrics
+    && request.method() == http::Method::GET
+    && metrics.is_metrics_path(request.uri().path())
+  {
+    let record =
+      HttpRecord::new(request, request_info, server_state, None, legacy_abort);
+    record.response_parts().headers.insert(
+      http::header::CONTENT_TYPE,
+      http::HeaderValue::from_static(METRICS_CONTENT_TYPE),
+    );
+    record.set_response_body(ResponseBytesInner::Bytes(BufView::from(
+      metrics.render().into_bytes(),
+    )));
+    return Ok(record.into_response());
+  }
+
   let otel_info = if let Some(otel) = deno_telemetry::OTEL_GLOBALS
     .get()
     .filter(|o| o.has_metrics())
@@ -273,6 +312,9 @@ pub(crate) async fn handle_request(
   let record = ScopeGuard::into_inner(guarded_record);
   http_trace!(record, "handle_request complete");
   let response = record.into_response();
+  if let (Some(metrics), Some(received)) = (metrics, received) {
+    metrics.record_response(response.status().as_u16(), received.elapsed());
+  }
   Ok(response)
 }
 
@@ -468,6 +510,20 @@ impl HttpRecord {
   }
 
   /// Take the Hyper body from this record.
+  /// The body size limit of the server this request was received on.
+  pub fn metrics(&self) -> Option<Rc<ServeMetrics>> {
+    self.self_ref().server_state.borrow().metrics.clone()
+  }
+
+  pub fn max_body_size(&self) -> Option<u64> {
+    self.self_ref().server_state.borrow().max_body_size
+  }
+
+  /// The body read timeout of the server this request was received on.
+  pub fn body_read_timeout(&self) -> Option<Duration> {
+    self.self_ref().server_state.borrow().body_read_timeout
+  }
+
   pub fn take_request_body(&self) -> Option<Incoming> {
     let body_holder = &mut self.self_mut().request_body;
     let body = body_holder.take();
@@ -683,6 +739,9 @@ impl Body for HttpRecordResponse {
           else {
             unreachable!();
           };
+          if let Some(metrics) = record.metrics() {
+            metrics.add_response_body_bytes(data.len());
+          }
           return Poll::Ready(Some(Ok(Frame::data(data))));
         }
         ResponseBytesInner::UncompressedStream(stm) => {
@@ -710,6 +769,9 @@ impl Body for HttpRecordResponse {
     }
 
     if let ResponseStreamResult::NonEmptyBuf(buf) = &res {
+      if let Some(metrics) = record.metrics() {
+        metrics.add_response_body_bytes(buf.len());
+      }
       let mut http = record.0.borrow_mut();
       if let Some(otel_info) = &mut http.as_mut().unwrap().otel_info
         && let Some(response_size) = &mut otel_info.response_size
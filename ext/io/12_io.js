@@ -8,11 +8,14 @@ import { core, primordials } from "ext:core/mod.js";
 import {
   op_read_create_cancel_handle,
   op_read_with_cancel_handle,
+  op_set_bracketed_paste,
   op_set_raw,
 } from "ext:core/ops";
 const {
   Uint8Array,
   ArrayPrototypePush,
+  NumberIsInteger,
+  RangeError,
   Symbol,
   TypedArrayPrototypeSubarray,
   TypedArrayPrototypeSet,
@@ -37,6 +40,26 @@ const SeekMode = {
   End: 2,
 };
 
+function rawModeControlChar(value, name, defaultValue) {
+  if (value === undefined) {
+    return defaultValue;
+  }
+  if (!NumberIsInteger(value) || value < 0 || value > 255) {
+    throw new RangeError(
+      `The "${name}" option must be an integer between 0 and 255, ` +
+        `received ${value}`,
+    );
+  }
+  return value;
+}
+
+function setRaw(rid, mode, options) {
+  const cbreak = !!(options.cbreak ?? false);
+  const min = rawModeControlChar(options.min, "min", 1);
+  const time = rawModeControlChar(options.time, "time", 0);
+  op_set_raw(rid, mode, cbreak, min, time);
+}
+
 function readSync(rid, buffer) {
   if (buffer.length === 0) return 0;
   const nread = core.readSync(rid, buffer);
@@ -173,8 +196,7 @@ class Stdin {
   }
 
   setRaw(mode, options = { __proto__: null }) {
-    const cbreak = !!(options.cbreak ?? false);
-    op_set_raw(this.#rid, mode, cbreak);
+    setRaw(this.#rid, mode, options);
   }
 
   isTerminal() {
@@ -229,6 +251,10 @@ class Stdout {
   isTerminal() {
     return core.isTerminal(this.#rid);
   }
+
+  setBracketedPaste(enabled) {
+    op_set_bracketed_paste(this.#rid, !!enabled);
+  }
 }
 
 class Stderr {
@@ -278,6 +304,7 @@ export {
   readSync,
   REF,
   SeekMode,
+  setRaw,
   Stderr,
   stderr,
   STDERR_RID,
@@ -0,0 +1,170 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Admission control for the blocking thread pool that async fs ops fall
+//! back to. The pool has a fixed number of threads, and tasks beyond that
+//! wait in an unbounded queue, so thousands of concurrent reads show up as
+//! latency of everything else that uses the pool. The tasks are counted
+//! here, and once too many are queued new ones fail with a `Busy` error,
+//! so that callers can back off instead of waiting.
+//!
+//! This covers the async ops of `Deno.FsFile` too, which run on the same
+//! pool. The limit follows the size of the pool, but doesn't adapt to how
+//! long the tasks take.
+//!
+//! Every worker runs its own current-thread runtime with its own pool, so the
+//! counts are kept per thread that runs a runtime.
+
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use tokio::task::JoinHandle;
+
+use crate::fs::FsError;
+use crate::fs::FsResult;
+
+/// Tasks queued per thread of the pool before new ones are refused.
+const QUEUE_LIMIT_PER_THREAD: usize = 64;
+
+/// The size of the pool when it wasn't set by the runtime, which is the
+/// default of `deno_runtime::tokio_util`.
+const DEFAULT_POOL_SIZE: usize = 32;
+
+struct Pool {
+  size: AtomicUsize,
+  /// The tasks that are running or waiting for a thread.
+  in_flight: AtomicUsize,
+}
+
+impl Pool {
+  fn queue_depth(&self) -> usize {
+    self
+      .in_flight
+      .load(Ordering::Relaxed)
+      .saturating_sub(self.size.load(Ordering::Relaxed))
+  }
+}
+
+thread_local! {
+  /// The pool of the runtime on this thread. Tasks finish on the threads of
+  /// the pool, hence the `Arc`.
+  static POOL: Arc<Pool> = Arc::new(Pool {
+    size: AtomicUsize::new(DEFAULT_POOL_SIZE),
+    in_flight: AtomicUsize::new(0),
+  });
+}
+
+/// Records the number of threads of the blocking pool of the runtime that
+/// is created on this thread, which the queue limit is derived from.
+pub fn set_pool_size(threads: usize) {
+  POOL.with(|pool| pool.size.store(threads.max(1), Ordering::Relaxed));
+}
+
+/// The number of tasks that wait for a thread of the pool of the runtime on
+/// this thread.
+pub fn queue_depth() -> usize {
+  POOL.with(|pool| pool.queue_depth())
+}
+
+/// The number of queued tasks at which new ones are refused, set with
+/// `DENO_BLOCKING_QUEUE_LIMIT`. A limit of 0 turns the check off.
+fn queue_limit(pool: &Pool) -> usize {
+  static ENV_LIMIT: OnceLock<Option<usize>> = OnceLock::new();
+  let env_limit = ENV_LIMIT.get_or_init(|| {
+    let value = std::env::var("DENO_BLOCKING_QUEUE_LIMIT").ok()?;
+    match value.parse() {
+      Ok(limit) => Some(limit),
+      Err(_) => {
+        log::warn!("Ignoring invalid DENO_BLOCKING_QUEUE_LIMIT: {value}");
+        None
+      }
+    }
+  });
+  env_limit.unwrap_or_else(|| {
+    pool.size.load(Ordering::Relaxed) * QUEUE_LIMIT_PER_THREAD
+  })
+}
+
+/// A task counted against the limit, which holds its place until it is
+/// spawned and finishes.
+pub(crate) struct InFlightGuard(Arc<Pool>);
+
+impl InFlightGuard {
+  pub(crate) fn acquire() -> FsResult<Self> {
+    let pool = POOL.with(Arc::clone);
+    let limit = queue_limit(&pool);
+    let in_flight = pool.in_flight.fetch_add(1, Ordering::Relaxed);
+    let depth = in_flight.saturating_sub(pool.size.load(Ordering::Relaxed));
+    if limit != 0 && depth >= limit {
+      pool.in_flight.fetch_sub(1, Ordering::Relaxed);
+      log::debug!("Refusing a blocking task, {depth} are queued");
+      return Err(FsError::Overloaded(depth));
+    }
+    Ok(Self(pool))
+  }
+
+  /// Spawns the task the place was taken for. This can't fail, so callers
+  /// that move state into the task acquire the guard before giving it up.
+  pub(crate) fn spawn<F, R>(self, f: F) -> JoinHandle<R>
+  where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+  {
+    deno_core::unsync::spawn_blocking(move || {
+      let _guard = self;
+      f()
+    })
+  }
+}
+
+impl Drop for InFlightGuard {
+  fn drop(&mut self) {
+    self.0.in_flight.fetch_sub(1, Ordering::Relaxed);
+  }
+}
+
+/// Like `deno_core::unsync::spawn_blocking`, but fails with
+/// [`FsError::Overloaded`] when too many tasks wait for the pool already.
+pub fn spawn_blocking<F, R>(f: F) -> FsResult<JoinHandle<R>>
+where
+  F: FnOnce() -> R + Send + 'static,
+  R: Send + 'static,
+{
+  Ok(InFlightGuard::acquire()?.spawn(f))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn refuses_tasks_over_the_limit() {
+    set_pool_size(1);
+    let limit = POOL.with(|pool| queue_limit(pool));
+    let guards = (0..limit + 1)
+      .map(|_| InFlightGuard::acquire())
+      .collect::<Result<Vec<_>, _>>()
+      .unwrap();
+    assert_eq!(queue_depth(), limit);
+    let err = InFlightGuard::acquire().err().unwrap();
+    assert!(matches!(err, FsError::Overloaded(depth) if depth == limit));
+    drop(guards);
+    assert_eq!(queue_depth(), 0);
+    assert!(InFlightGuard::acquire().is_ok());
+  }
+
+  #[test]
+  fn counts_per_thread() {
+    set_pool_size(1);
+    let _guards = (0..3)
+      .map(|_| InFlightGuard::acquire())
+      .collect::<Result<Vec<_>, _>>()
+      .unwrap();
+    assert_eq!(queue_depth(), 2);
+    // the runtime of another thread has a pool of its own
+    std::thread::spawn(|| assert_eq!(queue_depth(), 0))
+      .join()
+      .unwrap();
+  }
+}
@@ -0,0 +1,181 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! A size-classed pool of byte buffers for reads that run on a blocking
+//! task. Without it every `read(limit)` allocates a fresh `Vec` of `limit`
+//! bytes, which adds up quickly in servers that stream many files.
+
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+/// Smallest class is 4 KiB.
+const MIN_CLASS_SHIFT: u32 = 12;
+/// Largest class is 1 MiB. Bigger requests are allocated directly and are
+/// not recycled.
+const MAX_CLASS_SHIFT: u32 = 20;
+const NUM_CLASSES: usize = (MAX_CLASS_SHIFT - MIN_CLASS_SHIFT + 1) as usize;
+/// Upper bound on idle buffers kept per class.
+const MAX_IDLE_PER_CLASS: usize = 16;
+
+static POOL: Lazy<BufferPool> = Lazy::new(BufferPool::new);
+
+pub(crate) struct BufferPool {
+  classes: [Mutex<Vec<Vec<u8>>>; NUM_CLASSES],
+}
+
+impl BufferPool {
+  fn new() -> Self {
+    Self {
+      classes: std::array::from_fn(|_| Mutex::new(Vec::new())),
+    }
+  }
+
+  pub fn global() -> &'static BufferPool {
+    &POOL
+  }
+
+  fn class_of(capacity: usize) -> Option<usize> {
+    let shift = capacity
+      .max(1 << MIN_CLASS_SHIFT)
+      .next_power_of_two()
+      .trailing_zeros();
+    if shift > MAX_CLASS_SHIFT {
+      None
+    } else {
+      Some((shift - MIN_CLASS_SHIFT) as usize)
+    }
+  }
+
+  /// Returns a zeroed buffer of `len` bytes. It goes back to the pool
+  /// once dropped.
+  pub fn take(&'static self, len: usize) -> PooledBuf {
+    let buf = match Self::class_of(len) {
+      Some(class) => {
+        let recycled = self.classes[class].lock().unwrap().pop();
+        let mut buf = recycled.unwrap_or_else(|| {
+          Vec::with_capacity(1 << (class as u32 + MIN_CLASS_SHIFT))
+        });
+        buf.resize(len, 0);
+        buf
+      }
+      None => vec![0; len],
+    };
+    PooledBuf { buf, pool: self }
+  }
+
+  fn give_back(&self, mut buf: Vec<u8>) {
+    // Only buffers whose capacity is exactly a class size are recycled,
+    // so everything in a class can serve any request for that class.
+    let capacity = buf.capacity();
+    if !capacity.is_power_of_two() {
+      return;
+    }
+    let Some(class) = Self::class_of(capacity) else {
+      return;
+    };
+    if capacity != 1 << (class as u32 + MIN_CLASS_SHIFT) {
+      return;
+    }
+    buf.clear();
+    let mut idle = self.classes[class].lock().unwrap();
+    if idle.len() < MAX_IDLE_PER_CLASS {
+      idle.push(buf);
+    }
+  }
+}
+
+/// A buffer borrowed from a [`BufferPool`].
+pub(crate) struct PooledBuf {
+  buf: Vec<u8>,
+  pool: &'static BufferPool,
+}
+
+impl PooledBuf {
+  pub fn truncate(&mut self, len: usize) {
+    self.buf.truncate(len);
+  }
+
+  /// Hands the buffer over as `Bytes` without copying; it is recycled when
+  /// the last reference is dropped.
+  pub fn into_bytes(self) -> bytes::Bytes {
+    bytes::Bytes::from_owner(self)
+  }
+}
+
+impl Deref for PooledBuf {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    &self.buf
+  }
+}
+
+impl DerefMut for PooledBuf {
+  fn deref_mut(&mut self) -> &mut [u8] {
+    &mut self.buf
+  }
+}
+
+impl AsRef<[u8]> for PooledBuf {
+  fn as_ref(&self) -> &[u8] {
+    &self.buf
+  }
+}
+
+impl Drop for PooledBuf {
+  fn drop(&mut self) {
+    self.pool.give_back(std::mem::take(&mut self.buf));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn recycles_by_size_class() {
+    static POOL: Lazy<BufferPool> = Lazy::new(BufferPool::new);
+
+    let mut buf = POOL.take(5000);
+    assert_eq!(buf.len(), 5000);
+    buf[0] = 1;
+    let ptr = buf.as_ptr();
+    drop(buf);
+
+    // a request in the same 8 KiB class reuses the buffer, zeroed
+    let buf = POOL.take(8192);
+    assert_eq!(buf.as_ptr(), ptr);
+    assert_eq!(buf.len(), 8192);
+    assert_eq!(buf[0], 0);
+    drop(buf);
+
+    // a smaller class gets its own allocation
+    let buf = POOL.take(100);
+    assert_ne!(buf.as_ptr(), ptr);
+    assert_eq!(buf.len(), 100);
+  }
+
+  #[test]
+  fn oversized_buffers_are_not_recycled() {
+    static POOL: Lazy<BufferPool> = Lazy::new(BufferPool::new);
+
+    let len = (1 << MAX_CLASS_SHIFT) + 1;
+    drop(POOL.take(len));
+    assert!(POOL.classes.iter().all(|c| c.lock().unwrap().is_empty()));
+  }
+
+  #[test]
+  fn bytes_return_to_pool_on_drop() {
+    static POOL: Lazy<BufferPool> = Lazy::new(BufferPool::new);
+
+    let mut buf = POOL.take(4096);
+    buf.truncate(10);
+    let bytes = buf.into_bytes();
+    assert_eq!(bytes.len(), 10);
+    assert!(POOL.classes[0].lock().unwrap().is_empty());
+    drop(bytes);
+    assert_eq!(POOL.classes[0].lock().unwrap().len(), 1);
+  }
+}
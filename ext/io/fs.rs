@@ -4,9 +4,11 @@ use std::borrow::Cow;
 use std::fmt::Formatter;
 use std::io;
 use std::path::Path;
+use std::path::PathBuf;
 #[cfg(unix)]
 use std::process::Stdio as StdStdio;
 use std::rc::Rc;
+use std::time::Duration;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -15,6 +17,7 @@ use deno_core::BufView;
 use deno_core::OpState;
 use deno_core::ResourceHandleFd;
 use deno_core::ResourceId;
+use deno_core::TransferredResource;
 use deno_core::error::ResourceError;
 use deno_error::JsErrorBox;
 use deno_permissions::PermissionCheckError;
@@ -28,6 +31,10 @@ pub enum FsError {
   Io(io::Error),
   #[class("Busy")]
   FileBusy,
+  /// Too many tasks wait for the blocking thread pool, see
+  /// [`crate::blocking`].
+  #[class("Busy")]
+  Overloaded(usize),
   #[class(not_supported)]
   NotSupported,
   #[class(inherit)]
@@ -39,6 +46,10 @@ impl std::fmt::Display for FsError {
     match self {
       FsError::Io(err) => std::fmt::Display::fmt(err, f),
       FsError::FileBusy => f.write_str("file busy"),
+      FsError::Overloaded(depth) => write!(
+        f,
+        "too many pending file system operations ({depth} queued), try again later"
+      ),
       FsError::NotSupported => f.write_str("not supported"),
       FsError::PermissionCheck(err) => std::fmt::Display::fmt(err, f),
     }
@@ -52,6 +63,7 @@ impl FsError {
     match self {
       Self::Io(err) => err.kind(),
       Self::FileBusy => io::ErrorKind::Other,
+      Self::Overloaded(_) => io::ErrorKind::Other,
       Self::NotSupported => io::ErrorKind::Other,
       Self::PermissionCheck(e) => e.kind(),
     }
@@ -61,6 +73,7 @@ impl FsError {
     match self {
       FsError::Io(err) => err,
       FsError::FileBusy => io::Error::new(self.kind(), "file busy"),
+      FsError::Overloaded(_) => io::Error::new(self.kind(), self.to_string()),
       FsError::NotSupported => io::Error::new(self.kind(), "not supported"),
       FsError::PermissionCheck(err) => err.into_io_error(),
     }
@@ -300,10 +313,38 @@ pub trait File {
     mtime_nanos: u32,
   ) -> FsResult<()>;
 
+  /// Number of bytes left between the cursor and the end of the file, when
+  /// it can be known without blocking. Consumers such as HTTP responses use
+  /// it to send a `Content-Length` instead of a chunked body.
+  fn remaining_len(&self) -> Option<u64> {
+    None
+  }
+
+  /// Coalesces small writes in a buffer of `capacity` bytes that is written
+  /// once it is full, `flush_interval` after the first buffered write, or on
+  /// `flush_write_buffer()`. A `capacity` of 0 turns buffering off.
+  fn set_write_buffering(
+    self: Rc<Self>,
+    _capacity: usize,
+    _flush_interval: Option<Duration>,
+  ) -> FsResult<()> {
+    Err(FsError::NotSupported)
+  }
+  fn flush_write_buffer(self: Rc<Self>) -> FsResult<()> {
+    Ok(())
+  }
+
   // lower level functionality
   fn as_stdio(self: Rc<Self>) -> FsResult<StdStdio>;
   fn backing_fd(self: Rc<Self>) -> Option<ResourceHandleFd>;
   fn try_clone_inner(self: Rc<Self>) -> FsResult<Rc<dyn File>>;
+  /// Takes the OS file out, leaving this one busy, so that it can be moved
+  /// to another worker.
+  fn take_std_file(
+    self: Rc<Self>,
+  ) -> FsResult<(std::fs::File, Option<PathBuf>)> {
+    Err(FsError::NotSupported)
+  }
 }
 
 pub struct FileResource {
@@ -432,4 +473,40 @@ impl deno_core::Resource for FileResource {
   fn backing_fd(self: Rc<Self>) -> Option<ResourceHandleFd> {
     self.file.clone().backing_fd()
   }
+
+  fn size_hint(&self) -> (u64, Option<u64>) {
+    match self.file.remaining_len() {
+      Some(len) => (len, Some(len)),
+      None => (0, None),
+    }
+  }
+
+  fn transfer(
+    self: Rc<Self>,
+  ) -> Result<Box<dyn TransferredResource>, JsErrorBox> {
+    let (file, maybe_path) = self
+      .file
+      .clone()
+      .take_std_file()
+      .map_err(JsErrorBox::from_err)?;
+    Ok(Box::new(TransferredFile {
+      name: self.name.clone(),
+      file,
+      maybe_path,
+    }))
+  }
+}
+
+/// A file on its way to another worker.
+struct TransferredFile {
+  name: String,
+  file: std::fs::File,
+  maybe_path: Option<PathBuf>,
+}
+
+impl TransferredResource for TransferredFile {
+  fn receive(self: Box<Self>) -> Rc<dyn deno_core::Resource> {
+    let file = crate::StdFileResourceInner::file(self.file, self.maybe_path);
+    Rc::new(FileResource::new(Rc::new(file), self.name))
+  }
 }
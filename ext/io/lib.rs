@@ -22,7 +22,9 @@ use std::process::Stdio as StdStdio;
 use std::rc::Rc;
 #[cfg(windows)]
 use std::sync::Arc;
+use std::time::Duration;
 
+use buffer_pool::BufferPool;
 use deno_core::AsyncMutFuture;
 use deno_core::AsyncRefCell;
 use deno_core::AsyncResult;
@@ -63,11 +65,16 @@ use tokio::process;
 use winapi::um::processenv::GetStdHandle;
 #[cfg(windows)]
 use winapi::um::winbase;
+use write_buffer::SharedWriteBuffer;
+use write_buffer::WriteBuffer;
 
+pub mod blocking;
+mod buffer_pool;
 pub mod fs;
 mod pipe;
 #[cfg(windows)]
 mod winpipe;
+pub mod write_buffer;
 
 mod bi_pipe;
 
@@ -500,6 +507,8 @@ pub struct StdFileResourceInner {
   cell_async_task_queue: Rc<TaskQueue>,
   handle: ResourceHandleFd,
   maybe_path: Option<PathBuf>,
+  /// Set when small writes are coalesced, see `set_write_buffering()`.
+  write_buffer: RefCell<Option<SharedWriteBuffer>>,
 }
 
 impl StdFileResourceInner {
@@ -520,23 +529,101 @@ impl StdFileResourceInner {
       cell: RefCell::new(Some(fs_file)),
       cell_async_task_queue: Default::default(),
       maybe_path,
+      write_buffer: Default::default(),
     }
   }
 
+  /// Writes the data that was buffered, which has to happen before any other
+  /// use of the file.
+  fn flush_pending_writes(&self) -> FsResult<()> {
+    match &*self.write_buffer.borrow() {
+      Some(buffer) => Ok(buffer.lock().unwrap().flush()?),
+      None => Ok(()),
+    }
+  }
+
+  /// Buffers the data when buffering is enabled, or returns `None` when it
+  /// has to be written to the file.
+  fn buffer_write(&self, buf: &[u8]) -> Option<FsResult<()>> {
+    let cell = self.write_buffer.borrow();
+    let buffer = cell.as_ref()?;
+    let result = buffer.lock().unwrap().write(buf);
+    Some(match result {
+      Ok(schedule) => {
+        if schedule {
+          write_buffer::schedule_flush(
+            buffer,
+            self.cell_async_task_queue.clone(),
+          );
+        }
+        Ok(())
+      }
+      Err(err) => Err(err.into()),
+    })
+  }
+
+  /// Like `buffer_write()`, after the async ops that were started before.
+  /// When the data doesn't fit, the buffer is flushed on a blocking task,
+  /// which counts against the limit of [`blocking`].
+  async fn buffer_write_async(
+    &self,
+    buffer: SharedWriteBuffer,
+    buf: BufView,
+  ) -> FsResult<()> {
+    let _permit = self.cell_async_task_queue.acquire().await;
+    let fits = buffer.lock().unwrap().fits(buf.len());
+    let schedule = if fits {
+      buffer.lock().unwrap().write(&buf)?
+    } else {
+      let blocking_buffer = buffer.clone();
+      blocking::spawn_blocking(move || {
+        blocking_buffer.lock().unwrap().write(&buf)
+      })?
+      .await
+      .unwrap()?
+    };
+    if schedule {
+      write_buffer::schedule_flush(&buffer, self.cell_async_task_queue.clone());
+    }
+    Ok(())
+  }
+
   fn with_sync<F, R>(&self, action: F) -> FsResult<R>
   where
     F: FnOnce(&mut StdFile) -> FsResult<R>,
   {
+    self.flush_pending_writes()?;
     match self.cell.try_borrow_mut() {
       Ok(mut cell) if cell.is_some() => action(cell.as_mut().unwrap()),
       _ => Err(fs::FsError::FileBusy),
     }
   }
 
+  /// Runs `action` on a blocking task, after writing the buffered data on the
+  /// same task. The op fails without running `action` when that fails.
   fn with_inner_blocking_task<F, R: 'static + Send>(
     &self,
     action: F,
-  ) -> impl Future<Output = R> + '_
+  ) -> impl Future<Output = FsResult<R>> + '_
+  where
+    F: FnOnce(&mut StdFile) -> FsResult<R> + Send + 'static,
+  {
+    let buffer = self.write_buffer.borrow().clone();
+    let fut = self.with_unbuffered_blocking_task(move |file| {
+      if let Some(buffer) = buffer {
+        buffer.lock().unwrap().flush()?;
+      }
+      action(file)
+    });
+    async move { fut.await? }
+  }
+
+  /// Runs `action` on a blocking task, which fails with
+  /// `FsError::Overloaded` when too many wait for the pool, see [`blocking`].
+  fn with_unbuffered_blocking_task<F, R: 'static + Send>(
+    &self,
+    action: F,
+  ) -> impl Future<Output = FsResult<R>> + '_
   where
     F: FnOnce(&mut StdFile) -> R + Send + 'static,
   {
@@ -544,6 +631,8 @@ impl StdFileResourceInner {
     let acquire_fut = self.cell_async_task_queue.acquire();
     async move {
       let permit = acquire_fut.await;
+      // count the task before the file is moved into it
+      let in_flight = blocking::InFlightGuard::acquire()?;
       // we take the value out of the cell, use it on a blocking task,
       // then put it back into the cell when we're done
       let mut did_take = false;
@@ -557,12 +646,13 @@ impl StdFileResourceInner {
           }
         }
       };
-      let (cell_value, result) = spawn_blocking(move || {
-        let result = action(&mut cell_value);
-        (cell_value, result)
-      })
-      .await
-      .unwrap();
+      let (cell_value, result) = in_flight
+        .spawn(move || {
+          let result = action(&mut cell_value);
+          (cell_value, result)
+        })
+        .await
+        .unwrap();
 
       if did_take {
         // put it back
@@ -570,7 +660,7 @@ impl StdFileResourceInner {
       }
 
       drop(permit); // explicit for clarity
-      result
+      Ok(result)
     }
   }
 
@@ -598,7 +688,8 @@ impl StdFileResourceInner {
     loop {
       let state = state.clone();
 
-      let fut = self.with_inner_blocking_task(move |file| {
+      // stdin is never buffered
+      let fut = self.with_unbuffered_blocking_task(move |file| {
         /* Start reading, and set the reading flag to true */
         state.lock().reading = true;
         let nread = match file.read(&mut buf) {
@@ -657,7 +748,7 @@ impl StdFileResourceInner {
         Ok((nread, buf))
       });
 
-      match fut.await {
+      match fut.await? {
         Err((FsError::FileBusy, b)) => {
           buf = b;
           continue;
@@ -682,7 +773,12 @@ impl crate::fs::File for StdFileResourceInner {
     // that we get solved for free by using Rust's stdio wrappers (see
     // std/src/sys/windows/stdio.rs in Rust's source code).
     match self.kind {
-      StdFileResourceKind::File => self.with_sync(|file| Ok(file.write(buf)?)),
+      StdFileResourceKind::File => {
+        if let Some(result) = self.buffer_write(buf) {
+          return result.map(|()| buf.len());
+        }
+        self.with_sync(|file| Ok(file.write(buf)?))
+      }
       StdFileResourceKind::Stdin(_) => {
         Err(Into::<std::io::Error>::into(ErrorKind::Unsupported).into())
       }
@@ -717,6 +813,9 @@ impl crate::fs::File for StdFileResourceInner {
   fn write_all_sync(self: Rc<Self>, buf: &[u8]) -> FsResult<()> {
     match self.kind {
       StdFileResourceKind::File => {
+        if let Some(result) = self.buffer_write(buf) {
+          return result;
+        }
         self.with_sync(|file| Ok(file.write_all(buf)?))
       }
       StdFileResourceKind::Stdin(_) => {
@@ -741,6 +840,10 @@ impl crate::fs::File for StdFileResourceInner {
   async fn write_all(self: Rc<Self>, buf: BufView) -> FsResult<()> {
     match self.kind {
       StdFileResourceKind::File => {
+        let buffer = self.write_buffer.borrow().clone();
+        if let Some(buffer) = buffer {
+          return self.buffer_write_async(buffer, buf).await;
+        }
         self
           .with_inner_blocking_task(move |file| Ok(file.write_all(&buf)?))
           .await
@@ -779,6 +882,12 @@ impl crate::fs::File for StdFileResourceInner {
   ) -> FsResult<deno_core::WriteOutcome> {
     match self.kind {
       StdFileResourceKind::File => {
+        let buffer = self.write_buffer.borrow().clone();
+        if let Some(buffer) = buffer {
+          let nwritten = view.len();
+          self.buffer_write_async(buffer, view).await?;
+          return Ok(deno_core::WriteOutcome::Full { nwritten });
+        }
         self
           .with_inner_blocking_task(|file| {
             let nwritten = file.write(&view)?;
@@ -1087,6 +1196,30 @@ impl crate::fs::File for StdFileResourceInner {
       .await
   }
 
+  async fn read(self: Rc<Self>, limit: usize) -> FsResult<BufView> {
+    match &self.kind {
+      #[cfg(windows)]
+      StdFileResourceKind::Stdin(state) => {
+        let buf = BufMutView::new(limit);
+        let (nread, mut buf) =
+          self.handle_stdin_read(state.clone(), buf).await?;
+        buf.truncate(nread);
+        Ok(buf.into_view())
+      }
+      _ => {
+        let mut buf = BufferPool::global().take(limit);
+        let buf = self
+          .with_inner_blocking_task(move |file| {
+            let nread = file.read(&mut buf)?;
+            buf.truncate(nread);
+            Ok::<_, FsError>(buf)
+          })
+          .await?;
+        Ok(BufView::from(buf.into_bytes()))
+      }
+    }
+  }
+
   async fn read_byob(
     self: Rc<Self>,
     mut buf: BufMutView,
@@ -1108,6 +1241,38 @@ impl crate::fs::File for StdFileResourceInner {
     }
   }
 
+  fn remaining_len(&self) -> Option<u64> {
+    if !matches!(self.kind, StdFileResourceKind::File) {
+      return None;
+    }
+    self.flush_pending_writes().ok()?;
+    // the cell is empty while an async op has the file on a blocking task
+    let cell = self.cell.try_borrow().ok()?;
+    let mut file: &StdFile = cell.as_ref()?;
+    let metadata = file.metadata().ok()?;
+    if !metadata.is_file() {
+      return None;
+    }
+    let pos = file.stream_position().ok()?;
+    Some(metadata.len().saturating_sub(pos))
+  }
+
+  fn take_std_file(self: Rc<Self>) -> FsResult<(StdFile, Option<PathBuf>)> {
+    if !matches!(self.kind, StdFileResourceKind::File) {
+      return Err(FsError::NotSupported);
+    }
+    self.flush_pending_writes()?;
+    // the cell is empty while an async op has the file on a blocking task
+    let file = self
+      .cell
+      .try_borrow_mut()
+      .ok()
+      .and_then(|mut cell| cell.take())
+      .ok_or(FsError::FileBusy)?;
+    self.write_buffer.borrow_mut().take();
+    Ok((file, self.maybe_path.clone()))
+  }
+
   fn try_clone_inner(self: Rc<Self>) -> FsResult<Rc<dyn fs::File>> {
     let inner: &Option<_> = &self.cell.borrow();
     match inner {
@@ -1117,6 +1282,7 @@ impl crate::fs::File for StdFileResourceInner {
         cell_async_task_queue: Default::default(),
         handle: self.handle,
         maybe_path: self.maybe_path.clone(),
+        write_buffer: Default::default(),
       })),
       None => Err(FsError::FileBusy),
     }
@@ -1135,6 +1301,26 @@ impl crate::fs::File for StdFileResourceInner {
   fn backing_fd(self: Rc<Self>) -> Option<ResourceHandleFd> {
     Some(self.handle)
   }
+
+  fn set_write_buffering(
+    self: Rc<Self>,
+    capacity: usize,
+    flush_interval: Option<Duration>,
+  ) -> FsResult<()> {
+    if !matches!(self.kind, StdFileResourceKind::File) {
+      return Err(FsError::NotSupported);
+    }
+    // flushes the previous buffer
+    let file = self.with_sync(|file| Ok(file.try_clone()?))?;
+    let buffer =
+      (capacity > 0).then(|| WriteBuffer::new(file, capacity, flush_interval));
+    *self.write_buffer.borrow_mut() = buffer;
+    Ok(())
+  }
+
+  fn flush_write_buffer(self: Rc<Self>) -> FsResult<()> {
+    self.flush_pending_writes()
+  }
 }
 
 pub struct ReadCancelResource(Rc<CancelHandle>);
@@ -0,0 +1,209 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Coalescing of small sequential writes to a file, behind
+//! `Deno.FsFile.prototype.setBuffering()`. Loggers tend to write a line at a
+//! time, which is an op and a syscall per line; with buffering enabled the
+//! lines are collected and written in one go once the buffer is full, after
+//! a delay, on an explicit flush, or when the file is closed.
+//!
+//! The buffer writes through its own duplicate of the file, which shares the
+//! cursor with the original, so that it can be flushed while the file is in
+//! use by an async op, and from the exit hook on any thread.
+
+use std::fs::File as StdFile;
+use std::io;
+use std::io::Write;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Once;
+use std::sync::Weak;
+use std::time::Duration;
+
+use deno_core::unsync::TaskQueue;
+use once_cell::sync::Lazy;
+
+pub type SharedWriteBuffer = Arc<Mutex<WriteBuffer>>;
+
+/// The largest buffer, which `setBuffering()` checks as well.
+pub const MAX_CAPACITY: usize = 64 * 1024 * 1024;
+
+/// The buffers that are alive, flushed before the process exits since
+/// `Deno.exit()` doesn't run destructors.
+static BUFFERS: Lazy<Mutex<Vec<Weak<Mutex<WriteBuffer>>>>> =
+  Lazy::new(Default::default);
+static EXIT_HOOK: Once = Once::new();
+
+pub struct WriteBuffer {
+  file: StdFile,
+  data: Vec<u8>,
+  capacity: usize,
+  flush_interval: Option<Duration>,
+  flush_scheduled: bool,
+  /// The error of a flush that wasn't requested by the user, reported by the
+  /// next write or flush.
+  error: Option<io::Error>,
+}
+
+impl WriteBuffer {
+  pub fn new(
+    file: StdFile,
+    capacity: usize,
+    flush_interval: Option<Duration>,
+  ) -> SharedWriteBuffer {
+    EXIT_HOOK.call_once(|| deno_signals::before_exit(flush_all));
+    let buffer = Arc::new(Mutex::new(WriteBuffer {
+      file,
+      // grown as data is buffered
+      data: Vec::new(),
+      capacity: capacity.min(MAX_CAPACITY),
+      flush_interval,
+      flush_scheduled: false,
+      error: None,
+    }));
+    let mut buffers = BUFFERS.lock().unwrap();
+    buffers.retain(|buffer| buffer.strong_count() > 0);
+    buffers.push(Arc::downgrade(&buffer));
+    buffer
+  }
+
+  /// Whether `write()` can buffer data of length `len` without writing to
+  /// the file.
+  pub fn fits(&self, len: usize) -> bool {
+    self.error.is_none()
+      && len < self.capacity
+      && self.data.len() + len <= self.capacity
+  }
+
+  /// Buffers the data, flushing first if it doesn't fit. Data at least as
+  /// large as the buffer is written directly. Returns whether a delayed
+  /// flush has to be scheduled.
+  pub fn write(&mut self, buf: &[u8]) -> io::Result<bool> {
+    self.take_error()?;
+    if self.data.len() + buf.len() > self.capacity {
+      self.flush()?;
+    }
+    if buf.len() >= self.capacity {
+      self.file.write_all(buf)?;
+      return Ok(false);
+    }
+    self.data.extend_from_slice(buf);
+    let schedule = self.flush_interval.is_some() && !self.flush_scheduled;
+    self.flush_scheduled |= schedule;
+    Ok(schedule)
+  }
+
+  pub fn flush(&mut self) -> io::Result<()> {
+    self.take_error()?;
+    if self.data.is_empty() {
+      return Ok(());
+    }
+    // the data is dropped on errors, like a failed unbuffered write
+    let result = self.file.write_all(&self.data);
+    self.data.clear();
+    result
+  }
+
+  /// Flushes, keeping an error to be reported by the next write or flush.
+  fn flush_deferring_error(&mut self) {
+    if let Err(err) = self.flush() {
+      self.error = Some(err);
+    }
+  }
+
+  fn take_error(&mut self) -> io::Result<()> {
+    match self.error.take() {
+      Some(err) => Err(err),
+      None => Ok(()),
+    }
+  }
+}
+
+impl Drop for WriteBuffer {
+  fn drop(&mut self) {
+    // Note: errors are lost here, like when a `std::io::BufWriter` is
+    // dropped. Calling `flushSync()` before closing the file reports them.
+    let _ = self.flush();
+  }
+}
+
+/// Flushes the buffer once its flush interval passed, in turn with the
+/// async ops of the file in `queue`.
+pub fn schedule_flush(buffer: &SharedWriteBuffer, queue: Rc<TaskQueue>) {
+  let Some(interval) = buffer.lock().unwrap().flush_interval else {
+    return;
+  };
+  let buffer = Arc::downgrade(buffer);
+  deno_core::unsync::spawn(async move {
+    tokio::time::sleep(interval).await;
+    let _permit = queue.acquire().await;
+    let Some(buffer) = buffer.upgrade() else {
+      return;
+    };
+    // the next write or op of the file reports an error
+    let _ = deno_core::unsync::spawn_blocking(move || {
+      let mut buffer = buffer.lock().unwrap();
+      buffer.flush_scheduled = false;
+      buffer.flush_deferring_error();
+    })
+    .await;
+  });
+}
+
+fn flush_all() {
+  let buffers = std::mem::take(&mut *BUFFERS.lock().unwrap());
+  for buffer in buffers.iter().filter_map(Weak::upgrade) {
+    // a buffer that is locked is being flushed or written to by the thread
+    // that exits, which can't be waited for
+    if let Ok(mut buffer) = buffer.try_lock() {
+      let _ = buffer.flush();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Read;
+  use std::io::Seek;
+
+  use super::*;
+
+  #[test]
+  fn coalesces_writes() {
+    let path = std::env::temp_dir()
+      .join(format!("deno_io_write_buffer_{}", std::process::id()));
+    let mut file = std::fs::OpenOptions::new()
+      .create(true)
+      .truncate(true)
+      .read(true)
+      .write(true)
+      .open(&path)
+      .unwrap();
+    let buffer = WriteBuffer::new(file.try_clone().unwrap(), 8, None);
+    let mut read_back = || {
+      let mut text = String::new();
+      file.rewind().unwrap();
+      file.read_to_string(&mut text).unwrap();
+      text
+    };
+    {
+      let mut buffer = buffer.lock().unwrap();
+      assert!(!buffer.write(b"abc").unwrap());
+      buffer.write(b"def").unwrap();
+      assert!(buffer.fits(2));
+      assert!(!buffer.fits(3));
+      assert!(!buffer.fits(8));
+    }
+    assert_eq!(read_back(), "");
+    // doesn't fit anymore, so the buffered data is written first
+    buffer.lock().unwrap().write(b"ghi").unwrap();
+    assert_eq!(read_back(), "abcdef");
+    // larger than the buffer, written directly
+    buffer.lock().unwrap().write(b"0123456789").unwrap();
+    assert_eq!(read_back(), "abcdefghi0123456789");
+    buffer.lock().unwrap().write(b"jk").unwrap();
+    drop(buffer);
+    assert_eq!(read_back(), "abcdefghi0123456789jk");
+    std::fs::remove_file(&path).unwrap();
+  }
+}
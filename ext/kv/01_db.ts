@@ -6,7 +6,9 @@ const {
 } = core;
 import {
   op_kv_atomic_write,
+  op_kv_database_backup,
   op_kv_database_open,
+  op_kv_database_restore,
   op_kv_dequeue_next_message,
   op_kv_encode_cursor,
   op_kv_finish_dequeued_message,
@@ -45,6 +47,7 @@ const {
 } = primordials;
 
 import { ReadableStream } from "ext:deno_web/06_streams.js";
+import { pathFromURL } from "ext:deno_web/00_infra.js";
 
 const encodeCursor: (
   selector: [Deno.KvKey | null, Deno.KvKey | null, Deno.KvKey | null],
@@ -54,7 +57,7 @@ const encodeCursor: (
 
 async function openKv(path: string) {
   const rid = await op_kv_database_open(path);
-  return new Kv(rid, kvSymbol);
+  return new Kv(rid, kvSymbol, path);
 }
 
 const maxQueueDelay = 30 * 24 * 60 * 60 * 1000;
@@ -117,15 +120,21 @@ const commitVersionstampSymbol = Symbol("KvCommitVersionstamp");
 
 class Kv {
   #rid: number;
+  #path: string | undefined;
   #isClosed: boolean;
 
-  constructor(rid: number = undefined, symbol: symbol = undefined) {
+  constructor(
+    rid: number = undefined,
+    symbol: symbol = undefined,
+    path: string = undefined,
+  ) {
     if (kvSymbol !== symbol) {
       throw new TypeError(
         "Deno.Kv can not be constructed: use Deno.openKv instead",
       );
     }
     this.#rid = rid;
+    this.#path = path;
     this.#isClosed = false;
   }
 
@@ -415,6 +424,14 @@ class Kv {
     });
   }
 
+  async backup(destination: string | URL): Promise<void> {
+    await op_kv_database_backup(this.#path, pathFromURL(destination));
+  }
+
+  async restore(source: string | URL): Promise<void> {
+    await op_kv_database_restore(this.#path, pathFromURL(source));
+  }
+
   close() {
     core.close(this.#rid);
     this.#isClosed = true;
@@ -57,8 +57,40 @@ impl DatabaseHandler for MultiBackendDbHandler {
   async fn open(
     &self,
     state: Rc<RefCell<OpState>>,
-    mut path: Option<String>,
+    path: Option<String>,
   ) -> Result<Self::DB, JsErrorBox> {
+    let (handler, path) = self.backend(path)?;
+    handler.dyn_open(state, path).await
+  }
+
+  async fn backup(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    destination: String,
+  ) -> Result<(), JsErrorBox> {
+    let (handler, path) = self.backend(path)?;
+    handler.dyn_backup(state, path, destination).await
+  }
+
+  async fn restore(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    source: String,
+  ) -> Result<(), JsErrorBox> {
+    let (handler, path) = self.backend(path)?;
+    handler.dyn_restore(state, path, source).await
+  }
+}
+
+impl MultiBackendDbHandler {
+  /// The backend handling `path`, and the path after applying the
+  /// `DENO_KV_DEFAULT_PATH` and `DENO_KV_PATH_PREFIX` environment variables.
+  fn backend(
+    &self,
+    mut path: Option<String>,
+  ) -> Result<(&dyn DynamicDbHandler, Option<String>), JsErrorBox> {
     if path.is_none()
       && let Ok(x) = std::env::var("DENO_KV_DEFAULT_PATH")
       && !x.is_empty()
@@ -75,13 +107,13 @@ impl DatabaseHandler for MultiBackendDbHandler {
     for (prefixes, handler) in &self.backends {
       for &prefix in *prefixes {
         if prefix.is_empty() {
-          return handler.dyn_open(state.clone(), path.clone()).await;
+          return Ok((handler.as_ref(), path));
         }
         let Some(path) = &path else {
           continue;
         };
         if path.starts_with(prefix) {
-          return handler.dyn_open(state.clone(), Some(path.clone())).await;
+          return Ok((handler.as_ref(), Some(path.clone())));
         }
       }
     }
@@ -99,6 +131,20 @@ pub trait DynamicDbHandler {
     state: Rc<RefCell<OpState>>,
     path: Option<String>,
   ) -> Result<RcDynamicDb, JsErrorBox>;
+
+  async fn dyn_backup(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    destination: String,
+  ) -> Result<(), JsErrorBox>;
+
+  async fn dyn_restore(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    source: String,
+  ) -> Result<(), JsErrorBox>;
 }
 
 #[async_trait(?Send)]
@@ -112,6 +158,24 @@ impl DatabaseHandler for Box<dyn DynamicDbHandler> {
   ) -> Result<Self::DB, JsErrorBox> {
     (**self).dyn_open(state, path).await
   }
+
+  async fn backup(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    destination: String,
+  ) -> Result<(), JsErrorBox> {
+    (**self).dyn_backup(state, path, destination).await
+  }
+
+  async fn restore(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    source: String,
+  ) -> Result<(), JsErrorBox> {
+    (**self).dyn_restore(state, path, source).await
+  }
 }
 
 #[async_trait(?Send)]
@@ -127,6 +191,24 @@ where
   ) -> Result<RcDynamicDb, JsErrorBox> {
     Ok(RcDynamicDb(Rc::new(self.open(state, path).await?)))
   }
+
+  async fn dyn_backup(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    destination: String,
+  ) -> Result<(), JsErrorBox> {
+    self.backup(state, path, destination).await
+  }
+
+  async fn dyn_restore(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    source: String,
+  ) -> Result<(), JsErrorBox> {
+    self.restore(state, path, source).await
+  }
 }
 
 #[async_trait(?Send)]
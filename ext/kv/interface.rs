@@ -17,4 +17,32 @@ pub trait DatabaseHandler {
     state: Rc<RefCell<OpState>>,
     path: Option<String>,
   ) -> Result<Self::DB, JsErrorBox>;
+
+  /// Copies a point-in-time snapshot of the database at `path` to the new
+  /// file `destination`.
+  async fn backup(
+    &self,
+    _state: Rc<RefCell<OpState>>,
+    _path: Option<String>,
+    _destination: String,
+  ) -> Result<(), JsErrorBox> {
+    Err(JsErrorBox::new(
+      "NotSupported",
+      "Backups are not supported by this database backend",
+    ))
+  }
+
+  /// Replaces the contents of the database at `path` with the backup in
+  /// `source`.
+  async fn restore(
+    &self,
+    _state: Rc<RefCell<OpState>>,
+    _path: Option<String>,
+    _source: String,
+  ) -> Result<(), JsErrorBox> {
+    Err(JsErrorBox::new(
+      "NotSupported",
+      "Restoring backups is not supported by this database backend",
+    ))
+  }
 }
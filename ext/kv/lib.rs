@@ -67,6 +67,8 @@ deno_core::extension!(deno_kv,
   parameters = [ DBH: DatabaseHandler ],
   ops = [
     op_kv_database_open<DBH>,
+    op_kv_database_backup<DBH>,
+    op_kv_database_restore<DBH>,
     op_kv_snapshot_read<DBH>,
     op_kv_atomic_write<DBH>,
     op_kv_encode_cursor,
@@ -236,6 +238,52 @@ where
   Ok(rid)
 }
 
+#[op2(async, stack_trace)]
+async fn op_kv_database_backup<DBH>(
+  state: Rc<RefCell<OpState>>,
+  #[string] path: Option<String>,
+  #[string] destination: String,
+) -> Result<(), KvError>
+where
+  DBH: DatabaseHandler + 'static,
+{
+  let handler = {
+    let state = state.borrow();
+    state
+      .borrow::<Arc<FeatureChecker>>()
+      .check_or_exit(UNSTABLE_FEATURE_NAME, "Deno.Kv.prototype.backup");
+    state.borrow::<Rc<DBH>>().clone()
+  };
+  handler
+    .backup(state, path, destination)
+    .await
+    .map_err(KvErrorKind::DatabaseHandler)?;
+  Ok(())
+}
+
+#[op2(async, stack_trace)]
+async fn op_kv_database_restore<DBH>(
+  state: Rc<RefCell<OpState>>,
+  #[string] path: Option<String>,
+  #[string] source: String,
+) -> Result<(), KvError>
+where
+  DBH: DatabaseHandler + 'static,
+{
+  let handler = {
+    let state = state.borrow();
+    state
+      .borrow::<Arc<FeatureChecker>>()
+      .check_or_exit(UNSTABLE_FEATURE_NAME, "Deno.Kv.prototype.restore");
+    state.borrow::<Rc<DBH>>().clone()
+  };
+  handler
+    .restore(state, path, source)
+    .await
+    .map_err(KvErrorKind::DatabaseHandler)?;
+  Ok(())
+}
+
 type KvKey = Vec<AnyValue>;
 
 fn key_part_from_v8(value: AnyValue) -> KeyPart {
@@ -189,8 +189,11 @@ impl DatabaseHandler for RemoteDbHandler {
           .unwrap(),
         pool_max_idle_per_host: None,
         pool_idle_timeout: None,
+        http2_keep_alive_interval: None,
+        http2_keep_alive_timeout: None,
         http1: false,
         http2: true,
+        http2_prior_knowledge: false,
         local_address: None,
         client_builder_hook: None,
       },
@@ -55,6 +55,80 @@ enum Mode {
   InMemory,
 }
 
+enum PathOrInMemory {
+  InMemory,
+  Path(PathBuf),
+}
+
+#[must_use = "the resolved return value to mitigate time-of-check to time-of-use issues"]
+fn validate_path(
+  state: &RefCell<OpState>,
+  path: Option<String>,
+  api_name: &str,
+) -> Result<Option<PathOrInMemory>, JsErrorBox> {
+  let Some(path) = path else {
+    return Ok(None);
+  };
+  if path == ":memory:" {
+    return Ok(Some(PathOrInMemory::InMemory));
+  }
+  if path.is_empty() {
+    return Err(JsErrorBox::type_error("Filename cannot be empty"));
+  }
+  if path.starts_with(':') {
+    return Err(JsErrorBox::type_error(
+      "Filename cannot start with ':' unless prefixed with './'",
+    ));
+  }
+  let path =
+    check_open(state, path, OpenAccessKind::ReadWriteNoFollow, api_name)?;
+  Ok(Some(PathOrInMemory::Path(path)))
+}
+
+fn check_open(
+  state: &RefCell<OpState>,
+  path: String,
+  access_kind: OpenAccessKind,
+  api_name: &str,
+) -> Result<PathBuf, JsErrorBox> {
+  let state = state.borrow();
+  let permissions = state.borrow::<PermissionsContainer>();
+  let path = permissions
+    .check_open(Cow::Owned(PathBuf::from(path)), access_kind, Some(api_name))
+    .map_err(JsErrorBox::from_err)?;
+  Ok(path.into_owned_path())
+}
+
+impl SqliteDbHandler {
+  /// The file of the database at `path`. Backups and restores open their
+  /// own connection to it, so in-memory databases can't be backed up.
+  fn database_file(
+    &self,
+    state: &RefCell<OpState>,
+    path: Option<String>,
+    api_name: &str,
+  ) -> Result<PathBuf, JsErrorBox> {
+    let in_memory = || {
+      JsErrorBox::type_error("Cannot back up or restore an in-memory database")
+    };
+    if std::env::var("DENO_KV_DB_MODE").as_deref() == Ok("memory") {
+      return Err(in_memory());
+    }
+    match (
+      validate_path(state, path, api_name)?,
+      &self.default_storage_dir,
+    ) {
+      (Some(PathOrInMemory::Path(path)), _) => Ok(path),
+      (None, Some(dir)) => Ok(dir.join("kv.sqlite3")),
+      (Some(PathOrInMemory::InMemory), _) | (None, None) => Err(in_memory()),
+    }
+  }
+}
+
+fn sqlite_error(err: rusqlite::Error) -> JsErrorBox {
+  JsErrorBox::generic(err.to_string())
+}
+
 #[async_trait(?Send)]
 impl DatabaseHandler for SqliteDbHandler {
   type DB = denokv_sqlite::Sqlite;
@@ -64,45 +138,7 @@ impl DatabaseHandler for SqliteDbHandler {
     state: Rc<RefCell<OpState>>,
     path: Option<String>,
   ) -> Result<Self::DB, JsErrorBox> {
-    enum PathOrInMemory {
-      InMemory,
-      Path(PathBuf),
-    }
-
-    #[must_use = "the resolved return value to mitigate time-of-check to time-of-use issues"]
-    fn validate_path(
-      state: &RefCell<OpState>,
-      path: Option<String>,
-    ) -> Result<Option<PathOrInMemory>, JsErrorBox> {
-      let Some(path) = path else {
-        return Ok(None);
-      };
-      if path == ":memory:" {
-        return Ok(Some(PathOrInMemory::InMemory));
-      }
-      if path.is_empty() {
-        return Err(JsErrorBox::type_error("Filename cannot be empty"));
-      }
-      if path.starts_with(':') {
-        return Err(JsErrorBox::type_error(
-          "Filename cannot start with ':' unless prefixed with './'",
-        ));
-      }
-      {
-        let state = state.borrow();
-        let permissions = state.borrow::<PermissionsContainer>();
-        let path = permissions
-          .check_open(
-            Cow::Owned(PathBuf::from(path)),
-            OpenAccessKind::ReadWriteNoFollow,
-            Some("Deno.openKv"),
-          )
-          .map_err(JsErrorBox::from_err)?;
-        Ok(Some(PathOrInMemory::Path(path.into_owned_path())))
-      }
-    }
-
-    let path = validate_path(&state, path)?;
+    let path = validate_path(&state, path, "Deno.openKv")?;
     let default_storage_dir = self.default_storage_dir.clone();
     type ConnGen =
       Arc<dyn Fn() -> rusqlite::Result<rusqlite::Connection> + Send + Sync>;
@@ -207,4 +243,65 @@ impl DatabaseHandler for SqliteDbHandler {
     )
     .map_err(|e| JsErrorBox::generic(e.to_string()))
   }
+
+  async fn backup(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    destination: String,
+  ) -> Result<(), JsErrorBox> {
+    const API_NAME: &str = "Deno.Kv.prototype.backup";
+    let path = self.database_file(&state, path, API_NAME)?;
+    let destination =
+      check_open(&state, destination, OpenAccessKind::Write, API_NAME)?;
+    let destination = destination
+      .into_os_string()
+      .into_string()
+      .map_err(|_| JsErrorBox::type_error("Backup path must be valid UTF-8"))?;
+    spawn_blocking(move || {
+      let conn = rusqlite::Connection::open_with_flags(
+        &path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY,
+      )
+      .map_err(sqlite_error)?;
+      // `VACUUM INTO` copies the database in a single read transaction, so
+      // the backup is consistent even while the database is written to
+      conn
+        .execute("VACUUM INTO ?1", [destination])
+        .map_err(sqlite_error)?;
+      Ok(())
+    })
+    .await
+    .unwrap()
+  }
+
+  async fn restore(
+    &self,
+    state: Rc<RefCell<OpState>>,
+    path: Option<String>,
+    source: String,
+  ) -> Result<(), JsErrorBox> {
+    const API_NAME: &str = "Deno.Kv.prototype.restore";
+    let path = self.database_file(&state, path, API_NAME)?;
+    let source = check_open(&state, source, OpenAccessKind::Read, API_NAME)?;
+    spawn_blocking(move || {
+      let source = rusqlite::Connection::open_with_flags(
+        &source,
+        OpenFlags::SQLITE_OPEN_READ_ONLY,
+      )
+      .map_err(sqlite_error)?;
+      let mut conn = rusqlite::Connection::open(&path).map_err(sqlite_error)?;
+      // the backup API replaces the pages of the database while holding a
+      // write lock, so other connections see either the old or the restored
+      // contents
+      let backup = rusqlite::backup::Backup::new(&source, &mut conn)
+        .map_err(sqlite_error)?;
+      backup
+        .run_to_completion(-1, std::time::Duration::ZERO, None)
+        .map_err(sqlite_error)?;
+      Ok(())
+    })
+    .await
+    .unwrap()
+  }
 }
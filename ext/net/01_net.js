@@ -17,14 +17,18 @@ import {
   op_net_connect_tcp,
   op_net_connect_unix,
   op_net_connect_vsock,
+  op_net_get_socket_options,
+  op_net_icmp_ping,
   op_net_join_multi_v4_udp,
   op_net_join_multi_v6_udp,
   op_net_leave_multi_v4_udp,
   op_net_leave_multi_v6_udp,
+  op_net_listen_systemd,
   op_net_listen_tcp,
   op_net_listen_tunnel,
   op_net_listen_unix,
   op_net_listen_vsock,
+  op_net_notify_systemd,
   op_net_recv_udp,
   op_net_recv_unixpacket,
   op_net_send_udp,
@@ -32,15 +36,22 @@ import {
   op_net_set_broadcast_udp,
   op_net_set_multi_loopback_udp,
   op_net_set_multi_ttl_udp,
+  op_net_set_rate_limit,
+  op_net_set_socket_options,
+  op_net_unix_peer_credentials,
+  op_net_unix_read_with_handles,
+  op_net_unix_write_with_handles,
   op_set_keepalive,
   op_set_nodelay,
 } from "ext:core/ops";
 const UDP_DGRAM_MAXSIZE = 65507;
 
 const {
+  ArrayIsArray,
   ArrayPrototypeMap,
   Error,
   Number,
+  NumberIsFinite,
   NumberIsNaN,
   NumberIsInteger,
   ObjectPrototypeIsPrototypeOf,
@@ -53,6 +64,8 @@ const {
   SetPrototypeForEach,
   SymbolAsyncIterator,
   SymbolDispose,
+  SymbolFor,
+  String,
   Symbol,
   TypeError,
   TypedArrayPrototypeSubarray,
@@ -66,6 +79,8 @@ import {
   writableStreamForRid,
 } from "ext:deno_web/06_streams.js";
 import * as abortSignal from "ext:deno_web/03_abort_signal.js";
+import { FsFile } from "ext:deno_fs/30_fs.js";
+import { IO_SPAN_NET, traceIo } from "ext:deno_telemetry/telemetry.ts";
 
 async function write(rid, data) {
   return await core.write(rid, data);
@@ -99,6 +114,19 @@ async function resolveDns(query, recordType, options) {
   }
 }
 
+function setSocketOptions(rid, options) {
+  const linger = options.linger;
+  op_net_set_socket_options(rid, {
+    noDelay: options.noDelay,
+    keepAlive: options.keepAlive,
+    keepAliveIdle: options.keepAliveIdle,
+    recvBuffer: options.recvBuffer,
+    sendBuffer: options.sendBuffer,
+    // `null` turns lingering off, which the op encodes as a negative value
+    linger: linger === null ? -1 : linger,
+  });
+}
+
 class Conn {
   #rid = 0;
   #remoteAddr = null;
@@ -133,10 +161,28 @@ class Conn {
   }
 
   write(p) {
-    return write(this.#rid, p);
+    return traceIo(
+      IO_SPAN_NET,
+      "Deno.Conn.write",
+      this.#remoteAddr?.transport ?? "tcp",
+      undefined,
+      () => write(this.#rid, p),
+      (nwritten) => nwritten,
+    );
   }
 
-  async read(buffer) {
+  read(buffer) {
+    return traceIo(
+      IO_SPAN_NET,
+      "Deno.Conn.read",
+      this.#remoteAddr?.transport ?? "tcp",
+      undefined,
+      () => this.#read(buffer),
+      (nread) => nread,
+    );
+  }
+
+  async #read(buffer) {
     if (buffer.length === 0) {
       return 0;
     }
@@ -162,6 +208,13 @@ class Conn {
     return core.shutdown(this.#rid);
   }
 
+  setRateLimit(options = { __proto__: null }) {
+    op_net_set_rate_limit(this.#rid, {
+      readBps: validateRate("readBps", options.readBps),
+      writeBps: validateRate("writeBps", options.writeBps),
+    });
+  }
+
   get readable() {
     if (this.#readable === undefined) {
       this.#readable = readableStreamForRidUnrefable(this.#rid);
@@ -241,6 +294,14 @@ class TcpConn extends Conn {
   setKeepAlive(keepAlive = true) {
     return op_set_keepalive(this.#rid, keepAlive);
   }
+
+  setOption(options) {
+    setSocketOptions(this.#rid, options);
+  }
+
+  getOption() {
+    return op_net_get_socket_options(this.#rid);
+  }
 }
 
 class UnixConn extends Conn {
@@ -252,6 +313,83 @@ class UnixConn extends Conn {
       value: rid,
     });
   }
+
+  setOption(options) {
+    setSocketOptions(this[internalRidSymbol], options);
+  }
+
+  getOption() {
+    return op_net_get_socket_options(this[internalRidSymbol]);
+  }
+
+  peerCredentials() {
+    return op_net_unix_peer_credentials(this[internalRidSymbol]);
+  }
+
+  async writeWithHandles(p, handles) {
+    const rids = ArrayPrototypeMap(handles, (handle) => {
+      const rid = handle?.[internalRidSymbol];
+      if (typeof rid !== "number") {
+        throw new TypeError(
+          "Only files, connections and listeners can be sent",
+        );
+      }
+      return rid;
+    });
+    return await op_net_unix_write_with_handles(
+      this[internalRidSymbol],
+      p,
+      rids,
+    );
+  }
+
+  async readWithHandles(p) {
+    if (p.length === 0) {
+      return { nread: 0, handles: [] };
+    }
+    const { 0: nread, 1: received } = await op_net_unix_read_with_handles(
+      this[internalRidSymbol],
+      p,
+    );
+    const handles = ArrayPrototypeMap(received, handleFromReceived);
+    // like `read()`, a read of zero bytes signals the end of the stream
+    if (nread === 0 && handles.length === 0) {
+      return { nread: null, handles };
+    }
+    return { nread, handles };
+  }
+}
+
+function handleFromReceived(received) {
+  switch (received.kind) {
+    case "file":
+      return new FsFile(received.rid, SymbolFor("Deno.internal.FsFile"));
+    case "tcp":
+      received.localAddr.transport = "tcp";
+      received.remoteAddr.transport = "tcp";
+      return new TcpConn(
+        received.rid,
+        received.remoteAddr,
+        received.localAddr,
+      );
+    case "tcpListener":
+      received.localAddr.transport = "tcp";
+      return new Listener(received.rid, received.localAddr, "tcp");
+    case "unix":
+      return new UnixConn(
+        received.rid,
+        { transport: "unix", path: received.remotePath },
+        { transport: "unix", path: received.localPath },
+      );
+    case "unixListener":
+      return new Listener(
+        received.rid,
+        { transport: "unix", path: received.localPath },
+        "unix",
+      );
+    default:
+      throw new Error("unreachable");
+  }
 }
 
 class VsockConn extends Conn {
@@ -404,12 +542,11 @@ class Listener {
 }
 
 const _dropMembership = Symbol("dropMembership");
-const _setBroadcast = Symbol("setBroadcast");
 const _setMultiLoopback = Symbol("setMultiLoopback");
 const _setMulticastTTL = Symbol("setMulticastTTL");
 
 function setDatagramBroadcast(conn, broadcast) {
-  return conn[_setBroadcast](broadcast);
+  return conn.setBroadcast(broadcast);
 }
 
 function setMulticastLoopback(conn, v6, loopback) {
@@ -440,8 +577,16 @@ class DatagramConn {
     return this.#addr;
   }
 
-  [_setBroadcast](broadcast) {
-    op_net_set_broadcast_udp(this.#rid, broadcast);
+  setOption(options) {
+    setSocketOptions(this.#rid, options);
+  }
+
+  getOption() {
+    return op_net_get_socket_options(this.#rid);
+  }
+
+  setBroadcast(broadcast) {
+    return op_net_set_broadcast_udp(this.#rid, broadcast);
   }
 
   [_dropMembership](v6, addr, multiInterface) {
@@ -610,6 +755,8 @@ function listen(args) {
         args.reusePort,
         args.loadBalanced ?? false,
         args.tcpBacklog ?? 511,
+        tcpFastOpenQueue(args.tcpFastOpen),
+        deferAcceptSeconds(args.deferAccept),
       );
       addr.transport = "tcp";
       return new Listener(rid, addr, "tcp");
@@ -646,6 +793,58 @@ function listen(args) {
   }
 }
 
+function listenSystemd(options = { __proto__: null }) {
+  const received = op_net_listen_systemd(options.name ?? null);
+  return ArrayPrototypeMap(received, handleFromReceived);
+}
+
+function notifySystemd(state) {
+  return op_net_notify_systemd(state);
+}
+
+function icmpPing(hostname, options = { __proto__: null }) {
+  return op_net_icmp_ping(hostname, {
+    timeout: options.timeout,
+    ttl: options.ttl,
+    payloadSize: options.payloadSize,
+  });
+}
+
+function validateRate(name, rate) {
+  if (rate === undefined || rate === null) return undefined;
+  if (typeof rate !== "number" || !NumberIsFinite(rate) || rate <= 0) {
+    throw new TypeError(
+      `'${name}' must be a positive number: received ${rate}`,
+    );
+  }
+  return rate;
+}
+
+const DEFAULT_TCP_FAST_OPEN_QUEUE = 256;
+
+function validateAcceptOption(name, value, valueIfTrue) {
+  if (value === undefined || value === false) return 0;
+  if (value === true) return valueIfTrue;
+  if (!NumberIsInteger(value) || value < 0 || value > 0xffffffff) {
+    throw new TypeError(
+      `'${name}' must be a boolean or a non-negative integer: received ${value}`,
+    );
+  }
+  return value;
+}
+
+function tcpFastOpenQueue(tcpFastOpen) {
+  return validateAcceptOption(
+    "tcpFastOpen",
+    tcpFastOpen,
+    DEFAULT_TCP_FAST_OPEN_QUEUE,
+  );
+}
+
+function deferAcceptSeconds(deferAccept) {
+  return validateAcceptOption("deferAccept", deferAccept, 1);
+}
+
 function validatePort(maybePort) {
   if (typeof maybePort !== "number" && typeof maybePort !== "string") {
     throw new TypeError(`Invalid port (expected number): ${maybePort}`);
@@ -694,6 +893,30 @@ function createListenDatagram(udpOpFn, unixOpFn) {
   };
 }
 
+/**
+ * Turns the `nameServer` and `lookup` options of `Deno.connect` and
+ * `Deno.connectTls` into the argument of the connect ops. `lookup` runs
+ * here, so the ops only see the addresses it returned.
+ */
+async function connectDnsArgs(options, hostname) {
+  if (options.lookup !== undefined) {
+    if (typeof options.lookup !== "function") {
+      throw new TypeError("Option 'lookup' must be a function");
+    }
+    const addresses = await options.lookup(hostname);
+    if (!ArrayIsArray(addresses) || addresses.length === 0) {
+      throw new TypeError(
+        `Option 'lookup' returned no addresses for '${hostname}'`,
+      );
+    }
+    return { addresses: ArrayPrototypeMap(addresses, String) };
+  }
+  if (options.nameServer !== undefined) {
+    return { nameServer: options.nameServer };
+  }
+  return undefined;
+}
+
 async function connect(args) {
   switch (args.transport ?? "tcp") {
     case "tcp": {
@@ -706,17 +929,25 @@ async function connect(args) {
         args.signal[abortSignal.add](abortHandler);
       }
       const port = validatePort(args.port);
+      const hostname = args.hostname ?? "127.0.0.1";
 
       try {
-        const { 0: rid, 1: localAddr, 2: remoteAddr } =
-          await op_net_connect_tcp(
-            {
-              hostname: args.hostname ?? "127.0.0.1",
-              port,
-            },
-            undefined,
-            cancelRid,
-          );
+        const dns = await connectDnsArgs(args, hostname);
+        const { 0: rid, 1: localAddr, 2: remoteAddr } = await traceIo(
+          IO_SPAN_NET,
+          "Deno.connect",
+          "tcp",
+          undefined,
+          () =>
+            op_net_connect_tcp(
+              { hostname, port },
+              undefined,
+              cancelRid,
+              args.happyEyeballsDelay,
+              args.proxy,
+              dns,
+            ),
+        );
         localAddr.transport = "tcp";
         remoteAddr.transport = "tcp";
 
@@ -755,17 +986,23 @@ async function connect(args) {
 export {
   Conn,
   connect,
+  connectDnsArgs,
   createListenDatagram,
+  deferAcceptSeconds,
   dropMembership,
+  icmpPing,
   listen,
   Listener,
   listenOptionApiName,
+  listenSystemd,
+  notifySystemd,
   PipeConn,
   resolveDns,
   setDatagramBroadcast,
   setMulticastLoopback,
   setMulticastTTL,
   TcpConn,
+  tcpFastOpenQueue,
   UnixConn,
   UpgradedConn,
   validatePort,
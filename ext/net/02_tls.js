@@ -6,6 +6,7 @@ import {
   op_net_accept_tls,
   op_net_connect_tls,
   op_net_listen_tls,
+  op_tls_cert_resolver_clear_cache,
   op_tls_cert_resolver_create,
   op_tls_cert_resolver_poll,
   op_tls_cert_resolver_resolve,
@@ -23,7 +24,14 @@ const {
   SymbolFor,
 } = primordials;
 
-import { Conn, Listener, validatePort } from "ext:deno_net/01_net.js";
+import {
+  Conn,
+  connectDnsArgs,
+  deferAcceptSeconds,
+  Listener,
+  tcpFastOpenQueue,
+  validatePort,
+} from "ext:deno_net/01_net.js";
 
 const _getPeerCertificate = Symbol("getPeerCertificate");
 
@@ -59,6 +67,9 @@ async function connectTls({
   cert = undefined,
   key = undefined,
   unsafelyDisableHostnameVerification = false,
+  proxy = undefined,
+  nameServer = undefined,
+  lookup = undefined,
 }) {
   if (transport !== "tcp") {
     throw new TypeError(`Unsupported transport: '${transport}'`);
@@ -72,9 +83,17 @@ async function connectTls({
   // TODO(mmastrac): We only expose this feature via symbol for now. This should actually be a feature
   // in Deno.connectTls, however.
   const serverName = arguments[0][serverNameSymbol] ?? null;
+  const dns = await connectDnsArgs({ nameServer, lookup }, hostname);
   const { 0: rid, 1: localAddr, 2: remoteAddr } = await op_net_connect_tls(
     { hostname, port },
-    { caCerts, alpnProtocols, serverName, unsafelyDisableHostnameVerification },
+    {
+      caCerts,
+      alpnProtocols,
+      serverName,
+      unsafelyDisableHostnameVerification,
+      proxy,
+      dns,
+    },
     keyPair,
   );
   localAddr.transport = "tcp";
@@ -84,8 +103,9 @@ async function connectTls({
 
 class TlsListener extends Listener {
   #rid = 0;
+  #lookup = null;
 
-  constructor(rid, addr) {
+  constructor(rid, addr, lookup = null) {
     super(rid, addr);
     ObjectDefineProperty(this, internalRidSymbol, {
       __proto__: null,
@@ -93,6 +113,13 @@ class TlsListener extends Listener {
       value: rid,
     });
     this.#rid = rid;
+    this.#lookup = lookup;
+  }
+
+  clearCertificateCache() {
+    if (this.#lookup !== null) {
+      op_tls_cert_resolver_clear_cache(this.#lookup);
+    }
   }
 
   async accept() {
@@ -114,6 +141,9 @@ function hasTlsKeyPairOptions(options) {
   if (options[resolverSymbol] !== undefined) {
     return true;
   }
+  if (options.resolveCertificate !== undefined) {
+    return true;
+  }
   return (options.cert !== undefined || options.key !== undefined);
 }
 
@@ -163,6 +193,9 @@ function listenTls({
   alpnProtocols = undefined,
   reusePort = false,
   tcpBacklog = 511,
+  tcpFastOpen = undefined,
+  deferAccept = undefined,
+  resolveCertificate = undefined,
 }) {
   if (transport !== "tcp") {
     throw new TypeError(`Unsupported transport: '${transport}'`);
@@ -174,13 +207,35 @@ function listenTls({
       "A key and certificate are required for `Deno.listenTls`",
     );
   }
-  const keyPair = loadTlsKeyPair("Deno.listenTls", arguments[0]);
+  let keyPair;
+  let lookup = null;
+  if (resolveCertificate !== undefined) {
+    if (typeof resolveCertificate !== "function") {
+      throw new TypeError(
+        "`resolveCertificate` must be a function for `Deno.listenTls`",
+      );
+    }
+    if (arguments[0].cert !== undefined || arguments[0].key !== undefined) {
+      throw new TypeError(
+        "`resolveCertificate` cannot be combined with `cert` and `key` for `Deno.listenTls`",
+      );
+    }
+    ({ 0: keyPair, 1: lookup } = createTlsKeyLookup(resolveCertificate));
+  } else {
+    keyPair = loadTlsKeyPair("Deno.listenTls", arguments[0]);
+  }
   const { 0: rid, 1: localAddr } = op_net_listen_tls(
     { hostname, port },
-    { alpnProtocols, reusePort, tcpBacklog },
+    {
+      alpnProtocols,
+      reusePort,
+      tcpBacklog,
+      tcpFastOpen: tcpFastOpenQueue(tcpFastOpen),
+      deferAccept: deferAcceptSeconds(deferAccept),
+    },
     keyPair,
   );
-  return new TlsListener(rid, localAddr);
+  return new TlsListener(rid, localAddr, lookup);
 }
 
 // deno-lint-ignore require-await
@@ -190,13 +245,22 @@ async function startTls(
     hostname = "127.0.0.1",
     caCerts = [],
     alpnProtocols = undefined,
+    keyFormat = undefined,
+    cert = undefined,
+    key = undefined,
     unsafelyDisableHostnameVerification = false,
   } = { __proto__: null },
 ) {
+  const keyPair = loadTlsKeyPair("Deno.startTls", {
+    keyFormat,
+    cert,
+    key,
+  });
   return startTlsInternal(conn, {
     hostname,
     caCerts,
     alpnProtocols,
+    keyPair,
     unsafelyDisableHostnameVerification,
   });
 }
@@ -226,7 +290,11 @@ function startTlsInternal(
 const resolverSymbol = SymbolFor("unstableSniResolver");
 const serverNameSymbol = SymbolFor("unstableServerName");
 
-function createTlsKeyResolver(callback) {
+/**
+ * Creates a resolver which asks `callback` for the key pair of each server
+ * name, along with the lookup used to clear the resolved key pairs.
+ */
+function createTlsKeyLookup(callback) {
   const { 0: resolver, 1: lookup } = op_tls_cert_resolver_create();
   (async () => {
     while (true) {
@@ -247,7 +315,11 @@ function createTlsKeyResolver(callback) {
       }
     }
   })();
-  return resolver;
+  return [resolver, lookup];
+}
+
+function createTlsKeyResolver(callback) {
+  return createTlsKeyLookup(callback)[0];
 }
 
 internals.resolverSymbol = resolverSymbol;
@@ -0,0 +1,300 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! ICMP echo requests for `Deno.icmpPing()`.
+//!
+//! A raw socket is used when the process is allowed to open one
+//! (`CAP_NET_RAW` or root). Otherwise Linux, Android and macOS allow
+//! unprivileged "ping sockets" of type `SOCK_DGRAM`, which this falls back
+//! to. On Linux the kernel replaces the identifier of echo requests sent on
+//! those, so replies are only matched by sequence number and source address.
+
+use std::cell::RefCell;
+use std::io;
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU16;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use deno_core::OpState;
+use deno_core::op2;
+use deno_features::FeatureChecker;
+use deno_permissions::PermissionsContainer;
+use serde::Deserialize;
+use serde::Serialize;
+use socket2::Domain;
+use socket2::Protocol;
+use socket2::Socket;
+use socket2::Type;
+use tokio::net::UdpSocket;
+
+use crate::ops::NetError;
+use crate::resolve_addr::resolve_addr;
+
+const ECHO_REQUEST_V4: u8 = 8;
+const ECHO_REPLY_V4: u8 = 0;
+const ECHO_REQUEST_V6: u8 = 128;
+const ECHO_REPLY_V6: u8 = 129;
+
+const DEFAULT_TIMEOUT: u64 = 5_000;
+const DEFAULT_PAYLOAD_SIZE: usize = 56;
+const MAX_PAYLOAD_SIZE: usize = 65_000;
+
+static SEQUENCE: AtomicU16 = AtomicU16::new(0);
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IcmpPingOptions {
+  /// Milliseconds to wait for the reply.
+  timeout: Option<u64>,
+  /// The TTL, or hop limit for IPv6, of the echo request.
+  ttl: Option<u32>,
+  /// Bytes of payload carried by the echo request.
+  payload_size: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcmpPingReply {
+  address: IpAddr,
+  sequence: u16,
+  /// Round-trip time in milliseconds.
+  time: f64,
+  /// TTL of the reply, when the socket type exposes the IP header.
+  ttl: Option<u8>,
+  /// Whether a raw socket was used, rather than an unprivileged ping socket.
+  privileged: bool,
+}
+
+struct PingSocket {
+  socket: UdpSocket,
+  privileged: bool,
+}
+
+fn open_socket(ip: &IpAddr) -> io::Result<PingSocket> {
+  let (domain, protocol) = match ip {
+    IpAddr::V4(_) => (Domain::IPV4, Protocol::ICMPV4),
+    IpAddr::V6(_) => (Domain::IPV6, Protocol::ICMPV6),
+  };
+  let (socket, privileged) =
+    match Socket::new(domain, Type::RAW, Some(protocol)) {
+      Ok(socket) => (socket, true),
+      #[cfg(any(
+        target_os = "android",
+        target_os = "linux",
+        target_os = "macos"
+      ))]
+      Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+        (Socket::new(domain, Type::DGRAM, Some(protocol))?, false)
+      }
+      Err(err) => return Err(err),
+    };
+  socket.set_nonblocking(true)?;
+  let socket = UdpSocket::from_std(socket.into())?;
+  Ok(PingSocket { socket, privileged })
+}
+
+/// The internet checksum of RFC 1071.
+fn checksum(data: &[u8]) -> u16 {
+  let mut sum = data
+    .chunks(2)
+    .map(|pair| match pair {
+      [hi, lo] => u32::from(u16::from_be_bytes([*hi, *lo])),
+      [hi] => u32::from(*hi) << 8,
+      _ => unreachable!(),
+    })
+    .sum::<u32>();
+  while sum > 0xffff {
+    sum = (sum & 0xffff) + (sum >> 16);
+  }
+  !(sum as u16)
+}
+
+fn echo_request(
+  ip: &IpAddr,
+  id: u16,
+  seq: u16,
+  payload_size: usize,
+) -> Vec<u8> {
+  let kind = match ip {
+    IpAddr::V4(_) => ECHO_REQUEST_V4,
+    IpAddr::V6(_) => ECHO_REQUEST_V6,
+  };
+  let mut packet = vec![kind, 0, 0, 0];
+  packet.extend_from_slice(&id.to_be_bytes());
+  packet.extend_from_slice(&seq.to_be_bytes());
+  packet.extend((0..payload_size).map(|i| i as u8));
+  // The kernel fills in the checksum of ICMPv6 messages, as it covers the
+  // IPv6 pseudo-header.
+  if ip.is_ipv4() {
+    let sum = checksum(&packet);
+    packet[2..4].copy_from_slice(&sum.to_be_bytes());
+  }
+  packet
+}
+
+struct EchoReply {
+  id: u16,
+  seq: u16,
+  ttl: Option<u8>,
+}
+
+/// Parses an echo reply, which follows the IPv4 header on IPv4 raw sockets
+/// and macOS ping sockets.
+fn parse_reply(ip: &IpAddr, packet: &[u8]) -> Option<EchoReply> {
+  let (message, ttl, reply) = match ip {
+    IpAddr::V4(_) if packet.first()? >> 4 == 4 => {
+      let header_len = usize::from(packet[0] & 0x0f) * 4;
+      (
+        packet.get(header_len..)?,
+        packet.get(8).copied(),
+        ECHO_REPLY_V4,
+      )
+    }
+    IpAddr::V4(_) => (packet, None, ECHO_REPLY_V4),
+    IpAddr::V6(_) => (packet, None, ECHO_REPLY_V6),
+  };
+  if message.len() < 8 || message[0] != reply || message[1] != 0 {
+    return None;
+  }
+  Some(EchoReply {
+    id: u16::from_be_bytes([message[4], message[5]]),
+    seq: u16::from_be_bytes([message[6], message[7]]),
+    ttl,
+  })
+}
+
+async fn ping(
+  ip: IpAddr,
+  options: &IcmpPingOptions,
+) -> Result<IcmpPingReply, NetError> {
+  let payload_size = options.payload_size.unwrap_or(DEFAULT_PAYLOAD_SIZE);
+  if payload_size > MAX_PAYLOAD_SIZE {
+    return Err(NetError::Io(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      format!("Payload size must not exceed {MAX_PAYLOAD_SIZE} bytes"),
+    )));
+  }
+  let PingSocket { socket, privileged } = open_socket(&ip)?;
+  if let Some(ttl) = options.ttl {
+    match ip {
+      IpAddr::V4(_) => socket.set_ttl(ttl)?,
+      IpAddr::V6(_) => {
+        socket2::SockRef::from(&socket).set_unicast_hops_v6(ttl)?
+      }
+    }
+  }
+
+  let id = std::process::id() as u16;
+  let seq = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+  let target = SocketAddr::new(ip, 0);
+  let start = Instant::now();
+  socket
+    .send_to(&echo_request(&ip, id, seq, payload_size), target)
+    .await?;
+
+  let timeout =
+    Duration::from_millis(options.timeout.unwrap_or(DEFAULT_TIMEOUT));
+  let mut buf = vec![0; payload_size + 128];
+  let wait_for_reply = async {
+    loop {
+      let (len, from) = socket.recv_from(&mut buf).await?;
+      if from.ip() != ip {
+        continue;
+      }
+      let Some(reply) = parse_reply(&ip, &buf[..len]) else {
+        continue;
+      };
+      let id_matches =
+        reply.id == id || (!privileged && cfg!(target_os = "linux"));
+      if reply.seq == seq && id_matches {
+        return Ok::<_, io::Error>(reply.ttl);
+      }
+    }
+  };
+  let ttl = tokio::time::timeout(timeout, wait_for_reply)
+    .await
+    .map_err(|_| {
+      io::Error::new(io::ErrorKind::TimedOut, "ICMP echo request timed out")
+    })??;
+
+  Ok(IcmpPingReply {
+    address: ip,
+    sequence: seq,
+    time: start.elapsed().as_secs_f64() * 1000.0,
+    ttl,
+    privileged,
+  })
+}
+
+#[op2(async, stack_trace)]
+#[serde]
+pub async fn op_net_icmp_ping(
+  state: Rc<RefCell<OpState>>,
+  #[string] hostname: String,
+  #[serde] options: Option<IcmpPingOptions>,
+) -> Result<IcmpPingReply, NetError> {
+  {
+    let mut s = state.borrow_mut();
+    s.borrow::<Arc<FeatureChecker>>()
+      .check_or_exit(crate::UNSTABLE_FEATURE_NAME, "Deno.icmpPing");
+    let permissions = s.borrow_mut::<PermissionsContainer>();
+    permissions.check_sys("icmpPing", "Deno.icmpPing()")?;
+    permissions.check_net(&(&hostname, None), "Deno.icmpPing()")?;
+  }
+
+  let ip = resolve_addr(&hostname, 0)
+    .await?
+    .next()
+    .ok_or(NetError::NoResolvedAddress)?
+    .ip();
+  ping(ip, &options.unwrap_or_default()).await
+}
+
+#[cfg(test)]
+mod tests {
+  use std::net::Ipv4Addr;
+  use std::net::Ipv6Addr;
+
+  use super::*;
+
+  #[test]
+  fn echo_request_v4_checksum() {
+    let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let packet = echo_request(&ip, 0x1234, 1, 4);
+    assert_eq!(&packet[..2], &[ECHO_REQUEST_V4, 0]);
+    assert_eq!(&packet[4..8], &[0x12, 0x34, 0, 1]);
+    assert_eq!(packet.len(), 12);
+    // a packet including its checksum sums up to zero
+    assert_eq!(checksum(&packet), 0);
+  }
+
+  #[test]
+  fn parses_replies() {
+    let v4 = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let message = [ECHO_REPLY_V4, 0, 0, 0, 0x12, 0x34, 0, 7];
+
+    // ping socket on Linux, without the IP header
+    let reply = parse_reply(&v4, &message).unwrap();
+    assert_eq!((reply.id, reply.seq, reply.ttl), (0x1234, 7, None));
+
+    // raw socket, with a 20 byte IP header
+    let mut packet = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64];
+    packet.resize(20, 0);
+    packet.extend_from_slice(&message);
+    let reply = parse_reply(&v4, &packet).unwrap();
+    assert_eq!((reply.id, reply.seq, reply.ttl), (0x1234, 7, Some(64)));
+
+    // echo requests sent to ourselves are not replies
+    let request = echo_request(&v4, 0x1234, 7, 0);
+    assert!(parse_reply(&v4, &request).is_none());
+
+    let v6 = IpAddr::V6(Ipv6Addr::LOCALHOST);
+    let message = [ECHO_REPLY_V6, 0, 0, 0, 0, 1, 0, 2];
+    let reply = parse_reply(&v6, &message).unwrap();
+    assert_eq!((reply.id, reply.seq), (1, 2));
+  }
+}
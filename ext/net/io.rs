@@ -6,10 +6,14 @@ use std::rc::Rc;
 use deno_core::AsyncMutFuture;
 use deno_core::AsyncRefCell;
 use deno_core::AsyncResult;
+use deno_core::BufView;
+use deno_core::CancelFuture;
 use deno_core::CancelHandle;
 use deno_core::CancelTryFuture;
 use deno_core::RcRef;
 use deno_core::Resource;
+use deno_core::TransferredResource;
+use deno_core::WriteOutcome;
 use deno_core::futures::TryFutureExt;
 use deno_error::JsErrorBox;
 use socket2::SockRef;
@@ -21,6 +25,9 @@ use tokio::net::tcp;
 #[cfg(unix)]
 use tokio::net::unix;
 
+use crate::rate_limit::Direction;
+use crate::rate_limit::RateLimiter;
+
 /// A full duplex resource has a read and write ends that are completely
 /// independent, like TCP/Unix sockets and TLS streams.
 #[derive(Debug)]
@@ -31,6 +38,7 @@ pub struct FullDuplexResource<R, W> {
   // canceled, while 'write' ops are allowed to complete. Therefore only
   // 'read' futures should be attached to this cancel handle.
   cancel_handle: CancelHandle,
+  rate_limiter: RateLimiter,
 }
 
 impl<R, W> FullDuplexResource<R, W>
@@ -43,6 +51,7 @@ where
       rd: rd.into(),
       wr: wr.into(),
       cancel_handle: Default::default(),
+      rate_limiter: Default::default(),
     }
   }
 
@@ -66,13 +75,27 @@ where
     self.cancel_handle.cancel()
   }
 
+  pub fn rate_limiter(&self) -> &RateLimiter {
+    &self.rate_limiter
+  }
+
   pub async fn read(
     self: Rc<Self>,
     data: &mut [u8],
   ) -> Result<usize, std::io::Error> {
     let mut rd = self.rd_borrow_mut().await;
-    let nread = rd.read(data).try_or_cancel(self.cancel_handle()).await?;
-    Ok(nread)
+    let len = self
+      .rate_limiter
+      .acquire(Direction::Read, data.len())
+      .or_cancel(self.cancel_handle())
+      .await?;
+    let result = rd
+      .read(&mut data[..len])
+      .try_or_cancel(self.cancel_handle())
+      .await;
+    let nread = result.as_ref().copied().unwrap_or(0);
+    self.rate_limiter.release(Direction::Read, len - nread);
+    result
   }
 
   pub async fn write(
@@ -80,8 +103,14 @@ where
     data: &[u8],
   ) -> Result<usize, std::io::Error> {
     let mut wr = self.wr_borrow_mut().await;
-    let nwritten = wr.write(data).await?;
-    Ok(nwritten)
+    let len = self
+      .rate_limiter
+      .acquire(Direction::Write, data.len())
+      .await;
+    let result = wr.write(&data[..len]).await;
+    let nwritten = result.as_ref().copied().unwrap_or(0);
+    self.rate_limiter.release(Direction::Write, len - nwritten);
+    result
   }
 
   pub async fn shutdown(self: Rc<Self>) -> Result<(), std::io::Error> {
@@ -119,30 +148,93 @@ impl Resource for TcpStreamResource {
   fn close(self: Rc<Self>) {
     self.cancel_read_ops();
   }
+
+  fn transfer(
+    self: Rc<Self>,
+  ) -> Result<Box<dyn TransferredResource>, JsErrorBox> {
+    let resource = Rc::try_unwrap(self)
+      .map_err(|_| JsErrorBox::from_err(MapError::NoResources))?;
+    let (rd, wr) = resource.into_inner();
+    let stream = rd
+      .reunite(wr)
+      .map_err(|err| JsErrorBox::generic(err.to_string()))?
+      .into_std()
+      .map_err(|err| JsErrorBox::from_err(MapError::Io(err)))?;
+    Ok(Box::new(TransferredTcpStream(stream)))
+  }
+}
+
+/// A TCP stream on its way to another worker, taken out of the event loop of
+/// the sending one.
+struct TransferredTcpStream(std::net::TcpStream);
+
+impl TransferredResource for TransferredTcpStream {
+  fn receive(self: Box<Self>) -> Rc<dyn Resource> {
+    // `into_std` leaves the socket in non-blocking mode, as tokio needs it
+    match tokio::net::TcpStream::from_std(self.0) {
+      Ok(stream) => Rc::new(TcpStreamResource::new(stream.into_split())),
+      Err(err) => Rc::new(UnregisteredTcpStream {
+        kind: err.kind(),
+        message: err.to_string(),
+      }),
+    }
+  }
+}
+
+/// A transferred TCP stream that couldn't be registered with the event loop
+/// of the receiving worker. Reads and writes fail with the error.
+struct UnregisteredTcpStream {
+  kind: std::io::ErrorKind,
+  message: String,
+}
+
+impl UnregisteredTcpStream {
+  fn error(&self) -> JsErrorBox {
+    JsErrorBox::from_err(MapError::Io(std::io::Error::new(
+      self.kind,
+      self.message.clone(),
+    )))
+  }
+}
+
+impl Resource for UnregisteredTcpStream {
+  fn name(&self) -> Cow<'_, str> {
+    "tcpStream".into()
+  }
+
+  fn read(self: Rc<Self>, _limit: usize) -> AsyncResult<BufView> {
+    let err = self.error();
+    Box::pin(async move { Err(err) })
+  }
+
+  fn write(self: Rc<Self>, _buf: BufView) -> AsyncResult<WriteOutcome> {
+    let err = self.error();
+    Box::pin(async move { Err(err) })
+  }
 }
 
 impl TcpStreamResource {
   pub fn set_nodelay(self: Rc<Self>, nodelay: bool) -> Result<(), MapError> {
-    self.map_socket(Box::new(move |socket| socket.set_nodelay(nodelay)))
+    self.with_socket(move |socket| socket.set_nodelay(nodelay))
   }
 
   pub fn set_keepalive(
     self: Rc<Self>,
     keepalive: bool,
   ) -> Result<(), MapError> {
-    self.map_socket(Box::new(move |socket| socket.set_keepalive(keepalive)))
+    self.with_socket(move |socket| socket.set_keepalive(keepalive))
   }
 
-  #[allow(clippy::type_complexity)]
-  fn map_socket(
+  /// Runs `f` with the underlying socket. Fails while a write is pending.
+  pub fn with_socket<T>(
     self: Rc<Self>,
-    map: Box<dyn FnOnce(SockRef) -> Result<(), std::io::Error>>,
-  ) -> Result<(), MapError> {
+    f: impl FnOnce(SockRef) -> Result<T, std::io::Error>,
+  ) -> Result<T, MapError> {
     if let Some(wr) = RcRef::map(self, |r| &r.wr).try_borrow() {
       let stream = wr.as_ref().as_ref();
       let socket = socket2::SockRef::from(stream);
 
-      return map(socket).map_err(MapError::Io);
+      return f(socket).map_err(MapError::Io);
     }
 
     Err(MapError::NoResources)
@@ -153,6 +245,29 @@ impl TcpStreamResource {
 pub type UnixStreamResource =
   FullDuplexResource<unix::OwnedReadHalf, unix::OwnedWriteHalf>;
 
+#[cfg(unix)]
+impl UnixStreamResource {
+  /// Runs `f` with the underlying socket. Fails while a write is pending.
+  pub fn with_socket<T>(
+    self: Rc<Self>,
+    f: impl FnOnce(SockRef) -> Result<T, std::io::Error>,
+  ) -> Result<T, MapError> {
+    self.with_stream(|stream| f(SockRef::from(stream)))
+  }
+
+  /// Runs `f` with the underlying stream. Fails while a write is pending.
+  pub fn with_stream<T>(
+    self: Rc<Self>,
+    f: impl FnOnce(&tokio::net::UnixStream) -> Result<T, std::io::Error>,
+  ) -> Result<T, MapError> {
+    if let Some(wr) = RcRef::map(self, |r| &r.wr).try_borrow() {
+      return f(wr.as_ref().as_ref()).map_err(MapError::Io);
+    }
+
+    Err(MapError::NoResources)
+  }
+}
+
 #[cfg(not(unix))]
 pub struct UnixStreamResource;
 
@@ -1,5 +1,6 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+mod icmp;
 pub mod io;
 pub mod ops;
 pub mod ops_tls;
@@ -7,7 +8,9 @@ pub mod ops_tls;
 pub mod ops_unix;
 #[cfg(windows)]
 mod ops_win_pipe;
+pub mod proxy;
 mod quic;
+pub mod rate_limit;
 pub mod raw;
 pub mod resolve_addr;
 pub mod tcp;
@@ -61,6 +64,7 @@ deno_core::extension!(deno_net,
     ops::op_net_get_ips_from_perm_token,
     ops::op_net_connect_tcp,
     ops::op_net_listen_tcp,
+    ops::op_node_unstable_net_listen_tcp,
     ops::op_net_listen_udp,
     ops::op_node_unstable_net_listen_udp,
     ops::op_net_recv_udp,
@@ -76,11 +80,15 @@ deno_core::extension!(deno_net,
     ops::op_dns_resolve,
     ops::op_set_nodelay,
     ops::op_set_keepalive,
+    ops::op_net_set_rate_limit,
+    ops::op_net_set_socket_options,
+    ops::op_net_get_socket_options,
     ops::op_net_listen_vsock,
     ops::op_net_accept_vsock,
     ops::op_net_connect_vsock,
     ops::op_net_listen_tunnel,
     ops::op_net_accept_tunnel,
+    icmp::op_net_icmp_ping,
 
     ops_tls::op_tls_key_null,
     ops_tls::op_tls_key_static,
@@ -88,6 +96,7 @@ deno_core::extension!(deno_net,
     ops_tls::op_tls_cert_resolver_poll,
     ops_tls::op_tls_cert_resolver_resolve,
     ops_tls::op_tls_cert_resolver_resolve_error,
+    ops_tls::op_tls_cert_resolver_clear_cache,
     ops_tls::op_tls_start,
     ops_tls::op_net_connect_tls,
     ops_tls::op_net_listen_tls,
@@ -102,6 +111,11 @@ deno_core::extension!(deno_net,
     ops_unix::op_net_recv_unixpacket,
     ops_unix::op_net_send_unixpacket,
     ops_unix::op_net_unix_stream_from_fd,
+    ops_unix::op_net_unix_peer_credentials,
+    ops_unix::op_net_unix_write_with_handles,
+    ops_unix::op_net_unix_read_with_handles,
+    ops_unix::op_net_listen_systemd,
+    ops_unix::op_net_notify_systemd,
 
     ops_win_pipe::op_pipe_open,
     ops_win_pipe::op_pipe_connect,
@@ -156,6 +170,8 @@ deno_core::extension!(deno_net,
     state.put(UnsafelyIgnoreCertificateErrors(
       options.unsafely_ignore_certificate_errors,
     ));
+    state.put(resolve_addr::NameServerResolvers::default());
+    state.put(ops_tls::TlsClientSessionStore::default());
   },
 );
 
@@ -188,6 +204,11 @@ mod ops_unix {
   stub_op!(op_net_recv_unixpacket);
   stub_op!(op_net_send_unixpacket);
   stub_op!(op_net_unix_stream_from_fd);
+  stub_op!(op_net_unix_peer_credentials);
+  stub_op!(op_net_unix_write_with_handles);
+  stub_op!(op_net_unix_read_with_handles);
+  stub_op!(op_net_listen_systemd);
+  stub_op!(op_net_notify_systemd);
 }
 
 /// Stub ops for non-windows platforms.
@@ -7,6 +7,7 @@ use std::net::Ipv6Addr;
 use std::net::SocketAddr;
 use std::rc::Rc;
 use std::str::FromStr;
+use std::time::Duration;
 
 use deno_core::AsyncRefCell;
 use deno_core::ByteString;
@@ -38,14 +39,20 @@ use socket2::Domain;
 use socket2::Protocol;
 use socket2::Socket;
 use socket2::Type;
-use tokio::net::TcpStream;
 use tokio::net::UdpSocket;
 
 use crate::io::TcpStreamResource;
+use crate::proxy::ConnectProxy;
+use crate::proxy::ProxyArgs;
+use crate::rate_limit::RateLimitOptions;
 use crate::raw::NetworkListenerResource;
+use crate::resolve_addr::NameServerResolvers;
 use crate::resolve_addr::resolve_addr;
 use crate::resolve_addr::resolve_addr_sync;
+use crate::resolve_addr::resolve_addr_with;
+use crate::tcp::DEFAULT_HAPPY_EYEBALLS_DELAY;
 use crate::tcp::TcpListener;
+use crate::tcp::connect_happy_eyeballs;
 use crate::tunnel::TunnelAddr;
 
 pub type Fd = u32;
@@ -54,11 +61,37 @@ pub type Fd = u32;
 #[serde(rename_all = "camelCase")]
 pub struct TlsHandshakeInfo {
   pub alpn_protocol: Option<ByteString>,
-  #[serde(skip_serializing)]
+  /// Serialized as a list of PEM strings, the peer's own certificate first.
+  #[serde(serialize_with = "serialize_pem_certificates")]
   pub peer_certificates:
     Option<Vec<rustls::pki_types::CertificateDer<'static>>>,
 }
 
+fn serialize_pem_certificates<S: serde::Serializer>(
+  certificates: &Option<Vec<rustls::pki_types::CertificateDer<'static>>>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  use base64::Engine;
+
+  let pems = certificates
+    .iter()
+    .flatten()
+    .map(|certificate| {
+      let encoded =
+        base64::prelude::BASE64_STANDARD.encode(certificate.as_ref());
+      let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+      for line in encoded.as_bytes().chunks(64) {
+        // base64 output is ASCII
+        pem.push_str(std::str::from_utf8(line).unwrap());
+        pem.push('\n');
+      }
+      pem.push_str("-----END CERTIFICATE-----\n");
+      pem
+    })
+    .collect::<Vec<_>>();
+  serializer.collect_seq(pems)
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct IpAddr {
   pub hostname: String,
@@ -151,6 +184,9 @@ pub enum NetError {
   #[class("Busy")]
   #[error("TCP stream is currently in use")]
   TcpStreamBusy,
+  #[class("NotSupported")]
+  #[error("Socket option '{0}' is not supported by this socket")]
+  UnsupportedSocketOption(&'static str),
   #[class(generic)]
   #[error("{0}")]
   Rustls(#[from] deno_tls::rustls::Error),
@@ -172,6 +208,18 @@ pub enum NetError {
   #[class(generic)]
   #[error("Tunnel is not open")]
   TunnelMissing,
+  #[class(type)]
+  #[error("Resource {0} has no handle that can be sent")]
+  HandleNotTransferable(ResourceId),
+  #[class(type)]
+  #[error("Cannot send more than {0} handles at once")]
+  TooManyHandles(usize),
+  #[class(type)]
+  #[error("Handles must be sent along with at least one byte of data")]
+  HandlesWithoutData,
+  #[class(type)]
+  #[error("Invalid proxy url")]
+  InvalidProxyUrl,
 }
 
 pub(crate) fn accept_err(e: std::io::Error) -> NetError {
@@ -303,15 +351,20 @@ pub async fn op_net_join_multi_v4_udp(
   #[string] address: String,
   #[string] multi_interface: String,
 ) -> Result<(), NetError> {
-  let resource = state
-    .borrow_mut()
-    .resource_table
-    .get::<UdpSocketResource>(rid)
-    .map_err(|_| NetError::SocketClosed)?;
-  let socket = RcRef::map(&resource, |r| &r.socket).borrow().await;
-
   let addr = Ipv4Addr::from_str(address.as_str())?;
   let interface_addr = Ipv4Addr::from_str(multi_interface.as_str())?;
+  if !addr.is_multicast() {
+    return Err(NetError::InvalidHostname(address));
+  }
+  let resource = {
+    let mut s = state.borrow_mut();
+    s.borrow_mut::<PermissionsContainer>()
+      .check_net(&(&address, None), "Deno.DatagramConn.joinMulticastV4()")?;
+    s.resource_table
+      .get::<UdpSocketResource>(rid)
+      .map_err(|_| NetError::SocketClosed)?
+  };
+  let socket = RcRef::map(&resource, |r| &r.socket).borrow().await;
 
   socket.join_multicast_v4(addr, interface_addr)?;
 
@@ -325,14 +378,19 @@ pub async fn op_net_join_multi_v6_udp(
   #[string] address: String,
   #[smi] multi_interface: u32,
 ) -> Result<(), NetError> {
-  let resource = state
-    .borrow_mut()
-    .resource_table
-    .get::<UdpSocketResource>(rid)
-    .map_err(|_| NetError::SocketClosed)?;
-  let socket = RcRef::map(&resource, |r| &r.socket).borrow().await;
-
   let addr = Ipv6Addr::from_str(address.as_str())?;
+  if !addr.is_multicast() {
+    return Err(NetError::InvalidHostname(address));
+  }
+  let resource = {
+    let mut s = state.borrow_mut();
+    s.borrow_mut::<PermissionsContainer>()
+      .check_net(&(&address, None), "Deno.DatagramConn.joinMulticastV6()")?;
+    s.resource_table
+      .get::<UdpSocketResource>(rid)
+      .map_err(|_| NetError::SocketClosed)?
+  };
+  let socket = RcRef::map(&resource, |r| &r.socket).borrow().await;
 
   socket.join_multicast_v6(&addr, multi_interface)?;
 
@@ -480,8 +538,37 @@ pub async fn op_net_connect_tcp(
   #[serde] addr: IpAddr,
   #[cppgc] net_perm_token: Option<&NetPermToken>,
   #[smi] resource_abort_id: Option<ResourceId>,
+  #[smi] happy_eyeballs_delay: Option<u32>,
+  #[serde] proxy: Option<ProxyArgs>,
+  #[serde] dns: Option<ConnectDnsArgs>,
 ) -> Result<(ResourceId, IpAddr, IpAddr), NetError> {
-  op_net_connect_tcp_inner(state, addr, net_perm_token, resource_abort_id).await
+  let happy_eyeballs_delay = happy_eyeballs_delay
+    .map(|ms| Duration::from_millis(ms as u64))
+    .unwrap_or(DEFAULT_HAPPY_EYEBALLS_DELAY);
+  let proxy = match proxy {
+    Some(proxy) => {
+      super::check_unstable(&state.borrow(), "Deno.connect({ proxy })");
+      Some(ConnectProxy::parse(proxy).ok_or(NetError::InvalidProxyUrl)?)
+    }
+    None => None,
+  };
+  let resolution = match dns {
+    Some(dns) => {
+      super::check_unstable(&state.borrow(), "Deno.connect({ dns })");
+      dns.into_resolution()?
+    }
+    None => HostResolution::System,
+  };
+  op_net_connect_tcp_inner(
+    state,
+    addr,
+    net_perm_token,
+    resource_abort_id,
+    happy_eyeballs_delay,
+    proxy,
+    resolution,
+  )
+  .await
 }
 
 #[inline]
@@ -490,6 +577,9 @@ pub async fn op_net_connect_tcp_inner(
   addr: IpAddr,
   net_perm_token: Option<&NetPermToken>,
   resource_abort_id: Option<ResourceId>,
+  happy_eyeballs_delay: Duration,
+  proxy: Option<ConnectProxy>,
+  resolution: HostResolution,
 ) -> Result<(ResourceId, IpAddr, IpAddr), NetError> {
   {
     let mut state_ = state.borrow_mut();
@@ -499,15 +589,28 @@ pub async fn op_net_connect_tcp_inner(
       Some(token) if token.includes(&addr.hostname) => token.hostname.clone(),
       _ => addr.hostname.clone(),
     };
-    state_
-      .borrow_mut::<PermissionsContainer>()
+    let permissions = state_.borrow_mut::<PermissionsContainer>();
+    permissions
       .check_net(&(&hostname_to_check, Some(addr.port)), "Deno.connect()")?;
+    if let Some(proxy) = &proxy {
+      permissions
+        .check_net(&(proxy.hostname(), Some(proxy.port())), "Deno.connect()")?;
+    }
+    resolution.check_permissions(permissions, addr.port, "Deno.connect()")?;
   }
 
-  let addr = resolve_addr(&addr.hostname, addr.port)
-    .await?
-    .next()
-    .ok_or_else(|| NetError::NoResolvedAddress)?;
+  let addrs = match &proxy {
+    Some(_) => Vec::new(),
+    None => {
+      let addrs = resolution
+        .resolve(&state, &addr.hostname, addr.port)
+        .await?;
+      if addrs.is_empty() {
+        return Err(NetError::NoResolvedAddress);
+      }
+      addrs
+    }
+  };
 
   let cancel_handle = resource_abort_id.and_then(|rid| {
     state
@@ -517,10 +620,20 @@ pub async fn op_net_connect_tcp_inner(
       .ok()
   });
 
+  let connect = async {
+    match &proxy {
+      Some(proxy) => {
+        proxy
+          .connect(&addr.hostname, addr.port, happy_eyeballs_delay)
+          .await
+      }
+      None => connect_happy_eyeballs(addrs, happy_eyeballs_delay).await,
+    }
+  };
   let tcp_stream_result = if let Some(cancel_handle) = &cancel_handle {
-    TcpStream::connect(&addr).or_cancel(cancel_handle).await?
+    connect.or_cancel(cancel_handle).await?
   } else {
-    TcpStream::connect(&addr).await
+    connect.await
   };
 
   if let Some(cancel_rid) = resource_abort_id
@@ -545,8 +658,95 @@ pub async fn op_net_connect_tcp_inner(
   Ok((rid, IpAddr::from(local_addr), IpAddr::from(remote_addr)))
 }
 
-struct UdpSocketResource {
-  socket: AsyncRefCell<UdpSocket>,
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectDnsArgs {
+  name_server: Option<NameServer>,
+  /// The result of a `lookup` callback.
+  addresses: Option<Vec<String>>,
+}
+
+impl ConnectDnsArgs {
+  pub(crate) fn into_resolution(self) -> Result<HostResolution, NetError> {
+    if let Some(addresses) = self.addresses {
+      let addresses = addresses
+        .iter()
+        .map(|address| address.trim_matches(['[', ']']).parse())
+        .collect::<Result<Vec<_>, _>>()?;
+      return Ok(HostResolution::Addresses(addresses));
+    }
+    match self.name_server {
+      Some(name_server) => {
+        Ok(HostResolution::NameServer(name_server.socket_addr()?))
+      }
+      None => Ok(HostResolution::System),
+    }
+  }
+}
+
+/// How the host name of an outgoing connection is turned into addresses.
+pub enum HostResolution {
+  System,
+  /// Queries this DNS server instead of using the system configuration.
+  NameServer(SocketAddr),
+  /// The addresses were looked up by the caller already.
+  Addresses(Vec<std::net::IpAddr>),
+}
+
+impl HostResolution {
+  /// Checks the permissions needed in addition to the one for the host name:
+  /// the DNS server is queried, and looked up addresses are connected to.
+  pub(crate) fn check_permissions(
+    &self,
+    permissions: &mut PermissionsContainer,
+    port: u16,
+    api_name: &str,
+  ) -> Result<(), deno_permissions::PermissionCheckError> {
+    match self {
+      HostResolution::System => Ok(()),
+      HostResolution::NameServer(name_server) => permissions.check_net(
+        &(&name_server.ip().to_string(), Some(name_server.port())),
+        api_name,
+      ),
+      HostResolution::Addresses(addresses) => {
+        for address in addresses {
+          permissions
+            .check_net(&(&address.to_string(), Some(port)), api_name)?;
+        }
+        Ok(())
+      }
+    }
+  }
+
+  pub(crate) async fn resolve(
+    &self,
+    state: &RefCell<OpState>,
+    hostname: &str,
+    port: u16,
+  ) -> Result<Vec<SocketAddr>, std::io::Error> {
+    match self {
+      HostResolution::System => {
+        Ok(resolve_addr(hostname, port).await?.collect())
+      }
+      HostResolution::NameServer(name_server) => {
+        let resolver = state
+          .borrow_mut()
+          .borrow_mut::<NameServerResolvers>()
+          .get(*name_server);
+        resolve_addr_with(&resolver, hostname, port).await
+      }
+      HostResolution::Addresses(addresses) => Ok(
+        addresses
+          .iter()
+          .map(|address| SocketAddr::new(*address, port))
+          .collect(),
+      ),
+    }
+  }
+}
+
+pub(crate) struct UdpSocketResource {
+  pub(crate) socket: AsyncRefCell<UdpSocket>,
   cancel: CancelHandle,
 }
 
@@ -568,10 +768,44 @@ pub fn op_net_listen_tcp(
   reuse_port: bool,
   load_balanced: bool,
   tcp_backlog: i32,
+  #[smi] tcp_fast_open: u32,
+  #[smi] defer_accept: u32,
 ) -> Result<(ResourceId, IpAddr), NetError> {
   if reuse_port {
     super::check_unstable(state, "Deno.listen({ reusePort: true })");
   }
+  net_listen_tcp(
+    state,
+    addr,
+    reuse_port,
+    load_balanced,
+    tcp_backlog,
+    tcp_fast_open,
+    defer_accept,
+  )
+}
+
+/// `node:net` listens with `SO_REUSEPORT` in the workers of a cluster, which
+/// is stable in Node.
+#[op2(stack_trace)]
+#[serde]
+pub fn op_node_unstable_net_listen_tcp(
+  state: &mut OpState,
+  #[serde] addr: IpAddr,
+  reuse_port: bool,
+) -> Result<(ResourceId, IpAddr), NetError> {
+  net_listen_tcp(state, addr, reuse_port, false, 511, 0, 0)
+}
+
+fn net_listen_tcp(
+  state: &mut OpState,
+  addr: IpAddr,
+  reuse_port: bool,
+  load_balanced: bool,
+  tcp_backlog: i32,
+  tcp_fast_open: u32,
+  defer_accept: u32,
+) -> Result<(ResourceId, IpAddr), NetError> {
   state
     .borrow_mut::<PermissionsContainer>()
     .check_net(&(&addr.hostname, Some(addr.port)), "Deno.listen()")?;
@@ -584,6 +818,7 @@ pub fn op_net_listen_tcp(
   } else {
     TcpListener::bind_direct(addr, reuse_port, tcp_backlog)
   }?;
+  listener.set_accept_options(tcp_fast_open, defer_accept)?;
   let local_addr = listener.local_addr()?;
   let listener_resource = NetworkListenerResource::new(listener);
   let rid = state.resource_table.add(listener_resource);
@@ -949,6 +1184,12 @@ pub struct NameServer {
   port: u16,
 }
 
+impl NameServer {
+  pub(crate) fn socket_addr(&self) -> Result<SocketAddr, NetError> {
+    Ok(SocketAddr::new(self.ip_addr.parse()?, self.port))
+  }
+}
+
 #[op2(async, stack_trace)]
 #[serde]
 pub async fn op_dns_resolve(
@@ -1094,6 +1335,178 @@ pub fn op_set_keepalive_inner(
   resource.set_keepalive(keepalive).map_err(NetError::Map)
 }
 
+#[op2]
+pub fn op_net_set_rate_limit(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+  #[serde] options: RateLimitOptions,
+) -> Result<(), NetError> {
+  super::check_unstable(state, "Deno.Conn.setRateLimit");
+  if let Ok(resource) = state.resource_table.get::<TcpStreamResource>(rid) {
+    resource.rate_limiter().set(options);
+    return Ok(());
+  }
+  if let Ok(resource) = state
+    .resource_table
+    .get::<crate::ops_tls::TlsStreamResource>(rid)
+  {
+    resource.rate_limiter().set(options);
+    return Ok(());
+  }
+  #[cfg(unix)]
+  if let Ok(resource) = state
+    .resource_table
+    .get::<crate::io::UnixStreamResource>(rid)
+  {
+    resource.rate_limiter().set(options);
+    return Ok(());
+  }
+  Err(NetError::SocketClosed)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SocketOptions {
+  no_delay: Option<bool>,
+  keep_alive: Option<bool>,
+  /// Milliseconds of idleness before keepalive probes are sent.
+  keep_alive_idle: Option<u64>,
+  recv_buffer: Option<usize>,
+  send_buffer: Option<usize>,
+  /// Milliseconds to linger on close; negative disables lingering.
+  linger: Option<i64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SocketOptionValues {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  no_delay: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  keep_alive: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  keep_alive_idle: Option<u64>,
+  recv_buffer: usize,
+  send_buffer: usize,
+  /// `Some(None)` when lingering is off.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  linger: Option<Option<u64>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SocketKind {
+  Tcp,
+  #[cfg(unix)]
+  Unix,
+  Udp,
+}
+
+/// Runs `f` with the socket behind a TCP, Unix or UDP resource.
+fn with_socket<T>(
+  state: &OpState,
+  rid: ResourceId,
+  f: impl FnOnce(socket2::SockRef, SocketKind) -> Result<T, NetError>,
+) -> Result<T, NetError> {
+  if let Ok(resource) = state.resource_table.get::<TcpStreamResource>(rid) {
+    return resource
+      .with_socket(|socket| Ok(f(socket, SocketKind::Tcp)))
+      .map_err(NetError::Map)?;
+  }
+  #[cfg(unix)]
+  if let Ok(resource) = state
+    .resource_table
+    .get::<crate::io::UnixStreamResource>(rid)
+  {
+    return resource
+      .with_socket(|socket| Ok(f(socket, SocketKind::Unix)))
+      .map_err(NetError::Map)?;
+  }
+  let resource = state.resource_table.get::<UdpSocketResource>(rid)?;
+  let socket = RcRef::map(&resource, |r| &r.socket)
+    .try_borrow()
+    .ok_or(NetError::SocketBusy)?;
+  f(socket2::SockRef::from(&*socket), SocketKind::Udp)
+}
+
+#[op2]
+pub fn op_net_set_socket_options(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+  #[serde] options: SocketOptions,
+) -> Result<(), NetError> {
+  with_socket(state, rid, |socket, kind| {
+    let tcp_only = |name| {
+      if kind == SocketKind::Tcp {
+        Ok(())
+      } else {
+        Err(NetError::UnsupportedSocketOption(name))
+      }
+    };
+    if let Some(no_delay) = options.no_delay {
+      tcp_only("noDelay")?;
+      socket.set_nodelay(no_delay)?;
+    }
+    if let Some(keep_alive) = options.keep_alive {
+      tcp_only("keepAlive")?;
+      socket.set_keepalive(keep_alive)?;
+    }
+    if let Some(idle) = options.keep_alive_idle {
+      tcp_only("keepAliveIdle")?;
+      let keepalive = socket2::TcpKeepalive::new()
+        .with_time(std::time::Duration::from_millis(idle));
+      socket.set_tcp_keepalive(&keepalive)?;
+    }
+    if let Some(size) = options.recv_buffer {
+      socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(size) = options.send_buffer {
+      socket.set_send_buffer_size(size)?;
+    }
+    if let Some(linger) = options.linger {
+      if kind == SocketKind::Udp {
+        return Err(NetError::UnsupportedSocketOption("linger"));
+      }
+      let linger = u64::try_from(linger)
+        .ok()
+        .map(std::time::Duration::from_millis);
+      socket.set_linger(linger)?;
+    }
+    Ok(())
+  })
+}
+
+#[op2]
+#[serde]
+pub fn op_net_get_socket_options(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+) -> Result<SocketOptionValues, NetError> {
+  with_socket(state, rid, |socket, kind| {
+    let tcp = kind == SocketKind::Tcp;
+    #[cfg(not(windows))]
+    let keep_alive_idle = if tcp {
+      Some(socket.keepalive_time()?.as_millis() as u64)
+    } else {
+      None
+    };
+    // Windows has no way to read the keepalive time back.
+    #[cfg(windows)]
+    let keep_alive_idle = None;
+    Ok(SocketOptionValues {
+      no_delay: tcp.then(|| socket.nodelay()).transpose()?,
+      keep_alive: tcp.then(|| socket.keepalive()).transpose()?,
+      keep_alive_idle,
+      recv_buffer: socket.recv_buffer_size()?,
+      send_buffer: socket.send_buffer_size()?,
+      linger: if kind == SocketKind::Udp {
+        None
+      } else {
+        Some(socket.linger()?.map(|d| d.as_millis() as u64))
+      },
+    })
+  })
+}
+
 fn format_rdata(
   ty: RecordType,
 ) -> impl Fn(&RData) -> Result<Option<DnsRecordData>, NetError> {
@@ -1439,8 +1852,16 @@ mod tests {
       port: server_addr[1].parse().unwrap(),
     };
 
-    let mut connect_fut =
-      op_net_connect_tcp_inner(conn_state, ip_addr, None, None).boxed_local();
+    let mut connect_fut = op_net_connect_tcp_inner(
+      conn_state,
+      ip_addr,
+      None,
+      None,
+      DEFAULT_HAPPY_EYEBALLS_DELAY,
+      None,
+      HostResolution::System,
+    )
+    .boxed_local();
     let mut rid = None;
 
     tokio::select! {
@@ -15,6 +15,7 @@ use std::sync::Arc;
 
 use deno_core::AsyncRefCell;
 use deno_core::AsyncResult;
+use deno_core::CancelFuture;
 use deno_core::CancelHandle;
 use deno_core::CancelTryFuture;
 use deno_core::OpState;
@@ -40,6 +41,9 @@ use deno_tls::load_private_keys;
 use deno_tls::new_resolver;
 use deno_tls::rustls::ClientConnection;
 use deno_tls::rustls::ServerConfig;
+use deno_tls::rustls::client::ClientSessionMemoryCache;
+use deno_tls::rustls::client::ClientSessionStore;
+use deno_tls::rustls::client::Resumption;
 use deno_tls::rustls::pki_types::ServerName;
 pub use rustls_tokio_stream::TlsStream;
 pub use rustls_tokio_stream::TlsStreamRead;
@@ -52,17 +56,35 @@ use tokio::net::TcpStream;
 use crate::DefaultTlsOptions;
 use crate::UnsafelyIgnoreCertificateErrors;
 use crate::io::TcpStreamResource;
+use crate::ops::ConnectDnsArgs;
+use crate::ops::HostResolution;
 use crate::ops::IpAddr;
 use crate::ops::NetError;
 use crate::ops::TlsHandshakeInfo;
+use crate::proxy::ConnectProxy;
+use crate::proxy::ProxyArgs;
+use crate::rate_limit::Direction;
+use crate::rate_limit::RateLimiter;
 use crate::raw::NetworkListenerResource;
-use crate::resolve_addr::resolve_addr;
 use crate::resolve_addr::resolve_addr_sync;
+use crate::tcp::DEFAULT_HAPPY_EYEBALLS_DELAY;
 use crate::tcp::TcpListener;
+use crate::tcp::connect_happy_eyeballs;
 
 pub(crate) const TLS_BUFFER_SIZE: Option<NonZeroUsize> =
   NonZeroUsize::new(65536);
 
+/// The sessions of client connections, shared between connections so
+/// reconnecting to a server resumes the earlier session instead of doing a
+/// full handshake.
+pub struct TlsClientSessionStore(pub Arc<dyn ClientSessionStore>);
+
+impl Default for TlsClientSessionStore {
+  fn default() -> Self {
+    Self(Arc::new(ClientSessionMemoryCache::new(256)))
+  }
+}
+
 pub struct TlsListener {
   pub(crate) tcp_listener: TcpListener,
   pub(crate) tls_config: Option<Arc<ServerConfig>>,
@@ -108,6 +130,7 @@ pub struct TlsStreamResource {
   // `None` when a TLS handshake hasn't been done.
   handshake_info: RefCell<Option<TlsHandshakeInfo>>,
   cancel_handle: CancelHandle, // Only read and handshake ops get canceled.
+  rate_limiter: RateLimiter,
 }
 
 impl TlsStreamResource {
@@ -121,6 +144,7 @@ impl TlsStreamResource {
       },
       handshake_info: RefCell::new(None),
       cancel_handle: Default::default(),
+      rate_limiter: Default::default(),
     }
   }
 
@@ -134,6 +158,10 @@ impl TlsStreamResource {
     }
   }
 
+  pub fn rate_limiter(&self) -> &RateLimiter {
+    &self.rate_limiter
+  }
+
   pub fn peer_certificates(
     &self,
   ) -> Option<
@@ -155,8 +183,16 @@ impl TlsStreamResource {
     })
     .borrow_mut()
     .await;
+    let len = self
+      .rate_limiter
+      .acquire(Direction::Read, data.len())
+      .or_cancel(RcRef::map(&self, |r| &r.cancel_handle))
+      .await?;
     let cancel_handle = RcRef::map(&self, |r| &r.cancel_handle);
-    rd.read(data).try_or_cancel(cancel_handle).await
+    let result = rd.read(&mut data[..len]).try_or_cancel(cancel_handle).await;
+    let nread = result.as_ref().copied().unwrap_or(0);
+    self.rate_limiter.release(Direction::Read, len - nread);
+    result
   }
 
   pub async fn write(
@@ -168,9 +204,15 @@ impl TlsStreamResource {
     })
     .borrow_mut()
     .await;
-    let nwritten = wr.write(data).await?;
+    let len = self
+      .rate_limiter
+      .acquire(Direction::Write, data.len())
+      .await;
+    let result = wr.write(&data[..len]).await;
+    let nwritten = result.as_ref().copied().unwrap_or(0);
+    self.rate_limiter.release(Direction::Write, len - nwritten);
     wr.flush().await?;
-    Ok(nwritten)
+    result
   }
 
   pub async fn shutdown(self: Rc<Self>) -> Result<(), std::io::Error> {
@@ -234,6 +276,8 @@ pub struct ConnectTlsArgs {
   alpn_protocols: Option<Vec<String>>,
   server_name: Option<String>,
   unsafely_disable_hostname_verification: Option<bool>,
+  proxy: Option<ProxyArgs>,
+  dns: Option<ConnectDnsArgs>,
 }
 
 #[derive(Deserialize)]
@@ -310,6 +354,11 @@ pub fn op_tls_cert_resolver_resolve_error(
   lookup.resolve(sni, Err(error))
 }
 
+#[op2(fast)]
+pub fn op_tls_cert_resolver_clear_cache(#[cppgc] lookup: &TlsKeyLookup) {
+  lookup.clear_cache()
+}
+
 #[op2(stack_trace)]
 #[serde]
 pub fn op_tls_start(
@@ -383,6 +432,9 @@ pub fn op_tls_start(
     tls_config.alpn_protocols =
       alpn_protocols.into_iter().map(|s| s.into_bytes()).collect();
   }
+  tls_config.resumption = Resumption::store(
+    state.borrow().borrow::<TlsClientSessionStore>().0.clone(),
+  );
 
   let tls_config = Arc::new(tls_config);
   let tls_stream = TlsStream::new_client_side(
@@ -416,6 +468,20 @@ pub async fn op_net_connect_tls(
     .and_then(|it| it.0.clone());
   let unsafely_disable_hostname_verification =
     args.unsafely_disable_hostname_verification.unwrap_or(false);
+  let proxy = match args.proxy {
+    Some(proxy) => {
+      super::check_unstable(&state.borrow(), "Deno.connectTls({ proxy })");
+      Some(ConnectProxy::parse(proxy).ok_or(NetError::InvalidProxyUrl)?)
+    }
+    None => None,
+  };
+  let resolution = match args.dns {
+    Some(dns) => {
+      super::check_unstable(&state.borrow(), "Deno.connectTls({ dns })");
+      dns.into_resolution()?
+    }
+    None => HostResolution::System,
+  };
 
   let cert_file = {
     let mut s = state.borrow_mut();
@@ -423,6 +489,14 @@ pub async fn op_net_connect_tls(
     permissions
       .check_net(&(&addr.hostname, Some(addr.port)), "Deno.connectTls()")
       .map_err(NetError::Permission)?;
+    if let Some(proxy) = &proxy {
+      permissions
+        .check_net(&(proxy.hostname(), Some(proxy.port())), "Deno.connectTls()")
+        .map_err(NetError::Permission)?;
+    }
+    resolution
+      .check_permissions(permissions, addr.port, "Deno.connectTls()")
+      .map_err(NetError::Permission)?;
     if let Some(path) = cert_file {
       Some(
         permissions
@@ -461,11 +535,23 @@ pub async fn op_net_connect_tls(
     ServerName::try_from(addr.hostname.clone())
   }
   .map_err(|_| NetError::InvalidHostname(addr.hostname.clone()))?;
-  let connect_addr = resolve_addr(&addr.hostname, addr.port)
-    .await?
-    .next()
-    .ok_or_else(|| NetError::NoResolvedAddress)?;
-  let tcp_stream = TcpStream::connect(connect_addr).await?;
+  let tcp_stream = match proxy {
+    Some(proxy) => {
+      proxy
+        .connect(&addr.hostname, addr.port, DEFAULT_HAPPY_EYEBALLS_DELAY)
+        .await?
+    }
+    None => {
+      let connect_addrs = resolution
+        .resolve(&state, &addr.hostname, addr.port)
+        .await?;
+      if connect_addrs.is_empty() {
+        return Err(NetError::NoResolvedAddress);
+      }
+      connect_happy_eyeballs(connect_addrs, DEFAULT_HAPPY_EYEBALLS_DELAY)
+        .await?
+    }
+  };
   let local_addr = tcp_stream.local_addr()?;
   let remote_addr = tcp_stream.peer_addr()?;
 
@@ -482,6 +568,9 @@ pub async fn op_net_connect_tls(
     tls_config.alpn_protocols =
       alpn_protocols.into_iter().map(|s| s.into_bytes()).collect();
   }
+  tls_config.resumption = Resumption::store(
+    state.borrow().borrow::<TlsClientSessionStore>().0.clone(),
+  );
 
   let tls_config = Arc::new(tls_config);
 
@@ -509,6 +598,10 @@ pub struct ListenTlsArgs {
   #[serde(default)]
   load_balanced: bool,
   tcp_backlog: i32,
+  #[serde(default)]
+  tcp_fast_open: u32,
+  #[serde(default)]
+  defer_accept: u32,
 }
 
 #[op2(stack_trace)]
@@ -539,6 +632,7 @@ pub fn op_net_listen_tls(
   } else {
     TcpListener::bind_direct(bind_addr, args.reuse_port, args.tcp_backlog)
   }?;
+  tcp_listener.set_accept_options(args.tcp_fast_open, args.defer_accept)?;
   let local_addr = tcp_listener.local_addr()?;
   let alpn = args
     .alpn_protocols
@@ -553,17 +647,25 @@ pub fn op_net_listen_tls(
         .with_no_client_auth()
         .with_single_cert(cert, key)?;
       tls_config.alpn_protocols = alpn;
+      if let Some(key_log) = deno_tls::ssl_key_log() {
+        tls_config.key_log = key_log;
+      }
       TlsListener {
         tcp_listener,
         tls_config: Some(tls_config.into()),
         server_config_provider: None,
       }
     }
-    TlsKeys::Resolver(resolver) => TlsListener {
-      tcp_listener,
-      tls_config: None,
-      server_config_provider: Some(resolver.into_server_config_provider(alpn)),
-    },
+    TlsKeys::Resolver(resolver) => {
+      super::check_unstable(state, "Deno.listenTls({ resolveCertificate })");
+      TlsListener {
+        tcp_listener,
+        tls_config: None,
+        server_config_provider: Some(
+          resolver.into_server_config_provider(alpn),
+        ),
+      }
+    }
   };
 
   let tls_listener_resource = NetworkListenerResource::new(listener);
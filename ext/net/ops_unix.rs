@@ -2,9 +2,16 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::os::fd::AsRawFd;
+use std::os::fd::BorrowedFd;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::fd::RawFd;
 use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
 
 use deno_core::AsyncRefCell;
 use deno_core::CancelHandle;
@@ -15,17 +22,24 @@ use deno_core::RcRef;
 use deno_core::Resource;
 use deno_core::ResourceId;
 use deno_core::op2;
+use deno_io::StdFileResourceInner;
+use deno_io::fs::FileResource;
 use deno_permissions::OpenAccessKind;
 use deno_permissions::PermissionsContainer;
 use serde::Deserialize;
 use serde::Serialize;
+use socket2::SockRef;
+use tokio::io::Interest;
 use tokio::net::UnixDatagram;
 use tokio::net::UnixListener;
 pub use tokio::net::UnixStream;
 
+use crate::io::TcpStreamResource;
 use crate::io::UnixStreamResource;
+use crate::ops::IpAddr;
 use crate::ops::NetError;
 use crate::raw::NetworkListenerResource;
+use crate::tcp::TcpListener;
 
 /// A utility function to map OsStrings to Strings
 pub fn into_string(s: std::ffi::OsString) -> Result<String, NetError> {
@@ -261,8 +275,6 @@ pub fn op_net_unix_stream_from_fd(
   state: &mut OpState,
   fd: i32,
 ) -> Result<ResourceId, NetError> {
-  use std::os::unix::io::FromRawFd;
-
   // Validate fd is non-negative
   if fd < 0 {
     return Err(NetError::Io(std::io::Error::new(
@@ -288,3 +300,568 @@ pub fn op_net_unix_stream_from_fd(
   let rid = state.resource_table.add(resource);
   Ok(rid)
 }
+
+#[derive(Serialize)]
+pub struct UnixCredentials {
+  uid: u32,
+  gid: u32,
+  pid: Option<i32>,
+}
+
+/// Credentials of the process on the other end of a unix socket, as of the
+/// time the connection was made (`SO_PEERCRED` or `getpeereid`).
+#[op2]
+#[serde]
+pub fn op_net_unix_peer_credentials(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+) -> Result<UnixCredentials, NetError> {
+  super::check_unstable(state, "Deno.UnixConn.peerCredentials");
+  let resource = state
+    .resource_table
+    .get::<UnixStreamResource>(rid)
+    .map_err(|_| NetError::SocketClosed)?;
+  let cred = resource
+    .with_stream(|stream| stream.peer_cred())
+    .map_err(NetError::Map)?;
+  Ok(UnixCredentials {
+    uid: cred.uid(),
+    gid: cred.gid(),
+    pid: cred.pid(),
+  })
+}
+
+/// The most descriptors one message can carry (`SCM_MAX_FD` on Linux).
+const MAX_FDS_PER_MESSAGE: usize = 253;
+
+/// Duplicates the descriptor behind `rid`, so the resource may be closed
+/// while the message is still waiting to be sent.
+fn dup_resource_fd(
+  state: &OpState,
+  rid: ResourceId,
+) -> Result<OwnedFd, NetError> {
+  let table = &state.resource_table;
+  let fd = if let Ok(fd) = table.get_fd(rid) {
+    fd
+  } else if let Ok(resource) = table.get::<TcpStreamResource>(rid) {
+    resource
+      .with_socket(|socket| Ok(socket.as_raw_fd()))
+      .map_err(NetError::Map)?
+  } else if let Ok(resource) = table.get::<UnixStreamResource>(rid) {
+    resource
+      .with_socket(|socket| Ok(socket.as_raw_fd()))
+      .map_err(NetError::Map)?
+  } else if let Ok(resource) =
+    table.get::<NetworkListenerResource<TcpListener>>(rid)
+  {
+    RcRef::map(&resource, |r| &r.listener)
+      .try_borrow()
+      .ok_or(NetError::ListenerBusy)?
+      .as_raw_fd()
+  } else if let Ok(resource) =
+    table.get::<NetworkListenerResource<UnixListener>>(rid)
+  {
+    RcRef::map(&resource, |r| &r.listener)
+      .try_borrow()
+      .ok_or(NetError::ListenerBusy)?
+      .as_raw_fd()
+  } else {
+    return Err(NetError::HandleNotTransferable(rid));
+  };
+  // SAFETY: `fd` is open for as long as the resource is in the table.
+  let fd = unsafe { BorrowedFd::borrow_raw(fd) };
+  Ok(fd.try_clone_to_owned()?)
+}
+
+/// Writes `data` to a unix socket with the descriptors of `rids` attached
+/// (`SCM_RIGHTS`). The receiving process gets its own copies; the resources
+/// stay open here.
+#[op2(async)]
+#[number]
+pub async fn op_net_unix_write_with_handles(
+  state: Rc<RefCell<OpState>>,
+  #[smi] rid: ResourceId,
+  #[buffer] data: JsBuffer,
+  #[serde] rids: Vec<ResourceId>,
+) -> Result<usize, NetError> {
+  let (resource, fds) = {
+    let state = state.borrow();
+    super::check_unstable(&state, "Deno.UnixConn.writeWithHandles");
+    let resource = state
+      .resource_table
+      .get::<UnixStreamResource>(rid)
+      .map_err(|_| NetError::SocketClosed)?;
+    if rids.len() > MAX_FDS_PER_MESSAGE {
+      return Err(NetError::TooManyHandles(MAX_FDS_PER_MESSAGE));
+    }
+    let fds = rids
+      .into_iter()
+      .map(|rid| dup_resource_fd(&state, rid))
+      .collect::<Result<Vec<_>, _>>()?;
+    (resource, fds)
+  };
+  if data.is_empty() && !fds.is_empty() {
+    return Err(NetError::HandlesWithoutData);
+  }
+  let wr = resource.wr_borrow_mut().await;
+  let stream: &UnixStream = (*wr).as_ref();
+  let nwritten = stream
+    .async_io(Interest::WRITABLE, || {
+      send_with_fds(stream.as_raw_fd(), &data, &fds)
+    })
+    .await?;
+  Ok(nwritten)
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ReceivedHandle {
+  File {
+    rid: ResourceId,
+  },
+  Tcp {
+    rid: ResourceId,
+    #[serde(rename = "localAddr")]
+    local_addr: IpAddr,
+    #[serde(rename = "remoteAddr")]
+    remote_addr: IpAddr,
+  },
+  TcpListener {
+    rid: ResourceId,
+    #[serde(rename = "localAddr")]
+    local_addr: IpAddr,
+  },
+  Unix {
+    rid: ResourceId,
+    #[serde(rename = "localPath")]
+    local_path: Option<String>,
+    #[serde(rename = "remotePath")]
+    remote_path: Option<String>,
+  },
+  UnixListener {
+    rid: ResourceId,
+    #[serde(rename = "localPath")]
+    local_path: Option<String>,
+  },
+}
+
+/// Reads from a unix socket into `buf`, adding a resource for each
+/// descriptor that came along with the data.
+#[op2(async)]
+#[serde]
+pub async fn op_net_unix_read_with_handles(
+  state: Rc<RefCell<OpState>>,
+  #[smi] rid: ResourceId,
+  #[buffer] mut buf: JsBuffer,
+) -> Result<(usize, Vec<ReceivedHandle>), NetError> {
+  let resource = {
+    let state = state.borrow();
+    super::check_unstable(&state, "Deno.UnixConn.readWithHandles");
+    state
+      .resource_table
+      .get::<UnixStreamResource>(rid)
+      .map_err(|_| NetError::SocketClosed)?
+  };
+  let (nread, fds) = {
+    let rd = resource.rd_borrow_mut().await;
+    let stream: &UnixStream = (*rd).as_ref();
+    stream
+      .async_io(Interest::READABLE, || {
+        recv_with_fds(stream.as_raw_fd(), &mut buf)
+      })
+      .try_or_cancel(resource.cancel_handle())
+      .await?
+  };
+  let mut state = state.borrow_mut();
+  let mut handles = Vec::with_capacity(fds.len());
+  for fd in fds {
+    if let Some(handle) = add_received_fd(&mut state, fd)? {
+      handles.push(handle);
+    }
+  }
+  Ok((nread, handles))
+}
+
+/// Turns a received descriptor into a resource. Sockets other than stream
+/// sockets are closed, since there is no resource which could wrap them.
+fn add_received_fd(
+  state: &mut OpState,
+  fd: OwnedFd,
+) -> Result<Option<ReceivedHandle>, NetError> {
+  if !is_socket_fd(fd.as_raw_fd()) {
+    let file = Rc::new(StdFileResourceInner::file(fd.into(), None));
+    let rid = state
+      .resource_table
+      .add(FileResource::new(file, "fsFile".to_string()));
+    return Ok(Some(ReceivedHandle::File { rid }));
+  }
+
+  let socket = SockRef::from(&fd);
+  if socket.r#type()? != socket2::Type::STREAM {
+    return Ok(None);
+  }
+  let listening = is_listening(fd.as_raw_fd())?;
+  let is_unix = socket.local_addr()?.family() == libc::AF_UNIX as _;
+  let handle = match (is_unix, listening) {
+    (false, false) => {
+      let stream = std::net::TcpStream::from(fd);
+      stream.set_nonblocking(true)?;
+      let stream = tokio::net::TcpStream::from_std(stream)?;
+      let local_addr = stream.local_addr()?.into();
+      let remote_addr = stream.peer_addr()?.into();
+      let rid = state
+        .resource_table
+        .add(TcpStreamResource::new(stream.into_split()));
+      ReceivedHandle::Tcp {
+        rid,
+        local_addr,
+        remote_addr,
+      }
+    }
+    (false, true) => {
+      let listener = TcpListener::from_std(fd.into())?;
+      let local_addr = listener.local_addr()?.into();
+      let rid = state
+        .resource_table
+        .add(NetworkListenerResource::new(listener));
+      ReceivedHandle::TcpListener { rid, local_addr }
+    }
+    (true, false) => {
+      let stream = std::os::unix::net::UnixStream::from(fd);
+      stream.set_nonblocking(true)?;
+      let stream = UnixStream::from_std(stream)?;
+      let local_path = stream
+        .local_addr()?
+        .as_pathname()
+        .map(pathstring)
+        .transpose()?;
+      let remote_path = stream
+        .peer_addr()?
+        .as_pathname()
+        .map(pathstring)
+        .transpose()?;
+      let rid = state
+        .resource_table
+        .add(UnixStreamResource::new(stream.into_split()));
+      ReceivedHandle::Unix {
+        rid,
+        local_path,
+        remote_path,
+      }
+    }
+    (true, true) => {
+      let listener = std::os::unix::net::UnixListener::from(fd);
+      listener.set_nonblocking(true)?;
+      let listener = UnixListener::from_std(listener)?;
+      let local_path = listener
+        .local_addr()?
+        .as_pathname()
+        .map(pathstring)
+        .transpose()?;
+      let rid = state
+        .resource_table
+        .add(NetworkListenerResource::new(listener));
+      ReceivedHandle::UnixListener { rid, local_path }
+    }
+  };
+  Ok(Some(handle))
+}
+
+fn is_listening(fd: RawFd) -> std::io::Result<bool> {
+  let mut value: libc::c_int = 0;
+  let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+  // SAFETY: `value` and `len` are valid for writes of the option size.
+  let result = unsafe {
+    libc::getsockopt(
+      fd,
+      libc::SOL_SOCKET,
+      libc::SO_ACCEPTCONN,
+      &mut value as *mut libc::c_int as *mut libc::c_void,
+      &mut len,
+    )
+  };
+  if result != 0 {
+    return Err(std::io::Error::last_os_error());
+  }
+  Ok(value != 0)
+}
+
+/// Control message buffer with room for `fds` descriptors, aligned for
+/// `cmsghdr`.
+fn cmsg_buffer(fds: usize) -> Vec<u64> {
+  // SAFETY: CMSG_SPACE only does arithmetic.
+  let space = unsafe {
+    libc::CMSG_SPACE((fds * std::mem::size_of::<RawFd>()) as u32) as usize
+  };
+  vec![0; space.div_ceil(std::mem::size_of::<u64>())]
+}
+
+fn send_with_fds(
+  socket: RawFd,
+  data: &[u8],
+  fds: &[OwnedFd],
+) -> std::io::Result<usize> {
+  let mut iov = libc::iovec {
+    iov_base: data.as_ptr() as *mut libc::c_void,
+    iov_len: data.len(),
+  };
+  let mut control = cmsg_buffer(fds.len());
+  // SAFETY: an all-zero msghdr is a valid empty message.
+  let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+  msg.msg_iov = &mut iov;
+  msg.msg_iovlen = 1;
+  if !fds.is_empty() {
+    let len = fds.len() * std::mem::size_of::<RawFd>();
+    msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+    msg.msg_controllen = (control.len() * std::mem::size_of::<u64>()) as _;
+    // SAFETY: the control buffer has room for a header and `fds`.
+    unsafe {
+      let cmsg = libc::CMSG_FIRSTHDR(&msg);
+      (*cmsg).cmsg_level = libc::SOL_SOCKET;
+      (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+      (*cmsg).cmsg_len = libc::CMSG_LEN(len as u32) as _;
+      let data = libc::CMSG_DATA(cmsg) as *mut RawFd;
+      for (i, fd) in fds.iter().enumerate() {
+        data.add(i).write_unaligned(fd.as_raw_fd());
+      }
+    }
+  }
+  #[cfg(any(target_os = "android", target_os = "linux"))]
+  let flags = libc::MSG_NOSIGNAL;
+  #[cfg(not(any(target_os = "android", target_os = "linux")))]
+  let flags = 0;
+  // SAFETY: `msg` points at buffers which outlive the call.
+  let nwritten = unsafe { libc::sendmsg(socket, &msg, flags) };
+  if nwritten < 0 {
+    return Err(std::io::Error::last_os_error());
+  }
+  Ok(nwritten as usize)
+}
+
+fn recv_with_fds(
+  socket: RawFd,
+  buf: &mut [u8],
+) -> std::io::Result<(usize, Vec<OwnedFd>)> {
+  let mut iov = libc::iovec {
+    iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+    iov_len: buf.len(),
+  };
+  let mut control = cmsg_buffer(MAX_FDS_PER_MESSAGE);
+  // SAFETY: an all-zero msghdr is a valid empty message.
+  let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+  msg.msg_iov = &mut iov;
+  msg.msg_iovlen = 1;
+  msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+  msg.msg_controllen = (control.len() * std::mem::size_of::<u64>()) as _;
+  #[cfg(any(target_os = "android", target_os = "linux"))]
+  let flags = libc::MSG_CMSG_CLOEXEC;
+  #[cfg(not(any(target_os = "android", target_os = "linux")))]
+  let flags = 0;
+  // SAFETY: `msg` points at buffers which outlive the call.
+  let nread = unsafe { libc::recvmsg(socket, &mut msg, flags) };
+  if nread < 0 {
+    return Err(std::io::Error::last_os_error());
+  }
+
+  let mut fds = Vec::new();
+  // SAFETY: the kernel filled in `msg`, so walking its control messages
+  // stays within `control`.
+  unsafe {
+    let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+    while !cmsg.is_null() {
+      if (*cmsg).cmsg_level == libc::SOL_SOCKET
+        && (*cmsg).cmsg_type == libc::SCM_RIGHTS
+      {
+        let data = libc::CMSG_DATA(cmsg) as *const RawFd;
+        let len = (*cmsg).cmsg_len as usize - libc::CMSG_LEN(0) as usize;
+        for i in 0..len / std::mem::size_of::<RawFd>() {
+          fds.push(OwnedFd::from_raw_fd(data.add(i).read_unaligned()));
+        }
+      }
+      cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+    }
+  }
+  if msg.msg_flags & libc::MSG_CTRUNC != 0 {
+    // the descriptors that did fit are closed when `fds` is dropped
+    return Err(std::io::Error::new(
+      std::io::ErrorKind::InvalidData,
+      format!(
+        "received more than {MAX_FDS_PER_MESSAGE} handles in one message, \
+        the rest were discarded"
+      ),
+    ));
+  }
+  // There is no MSG_CMSG_CLOEXEC on these platforms, so a process spawned by
+  // another thread between recvmsg() and here inherits the descriptors.
+  #[cfg(not(any(target_os = "android", target_os = "linux")))]
+  for fd in &fds {
+    // SAFETY: `fd` was just received and is owned by us.
+    unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) };
+  }
+  Ok((nread as usize, fds))
+}
+
+/// Descriptors passed in by systemd socket activation, with their names from
+/// `LISTEN_FDNAMES`. Read from the environment once, as every descriptor
+/// can only be adopted by one resource.
+static SYSTEMD_FDS: OnceLock<Mutex<Vec<(String, OwnedFd)>>> = OnceLock::new();
+
+fn inherited_systemd_fds() -> Vec<(String, OwnedFd)> {
+  /// `SD_LISTEN_FDS_START`
+  const FIRST_FD: RawFd = 3;
+  /// More descriptors than any unit passes in, which keeps a bogus
+  /// `LISTEN_FDS` from making us adopt arbitrary descriptors.
+  const MAX_COUNT: RawFd = 4096;
+
+  let for_this_process = std::env::var("LISTEN_PID")
+    .ok()
+    .and_then(|pid| pid.parse::<u32>().ok())
+    == Some(std::process::id());
+  let count = std::env::var("LISTEN_FDS")
+    .ok()
+    .and_then(|count| count.parse::<RawFd>().ok())
+    .filter(|count| for_this_process && (1..=MAX_COUNT).contains(count));
+  let names = std::env::var("LISTEN_FDNAMES").unwrap_or_default();
+  // Like sd_listen_fds(unset_environment = 1), so that subprocesses don't
+  // adopt the same descriptors.
+  for var in ["LISTEN_PID", "LISTEN_FDS", "LISTEN_FDNAMES"] {
+    #[allow(clippy::undocumented_unsafe_blocks)]
+    unsafe {
+      std::env::remove_var(var)
+    };
+  }
+  let Some(count) = count else {
+    return Vec::new();
+  };
+  let mut names = names.split(':');
+  (FIRST_FD..FIRST_FD + count)
+    .filter_map(|fd| {
+      let name = names.next().filter(|name| !name.is_empty());
+      // SAFETY: fcntl() only inspects the descriptor.
+      if unsafe { libc::fcntl(fd, libc::F_GETFD) } == -1 {
+        // not open, the environment doesn't match what was inherited
+        return None;
+      }
+      // SAFETY: systemd hands these descriptors to this process, and they
+      // are only taken out of `SYSTEMD_FDS` once.
+      unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) };
+      // SAFETY: see above.
+      let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+      Some((name.unwrap_or("unknown").to_string(), fd))
+    })
+    .collect()
+}
+
+/// Checks that the address an inherited socket is bound to could have been
+/// listened on with the current permissions.
+fn check_inherited_fd(
+  state: &mut OpState,
+  fd: &OwnedFd,
+) -> Result<(), NetError> {
+  const API_NAME: &str = "Deno.listenSystemd()";
+  if !is_socket_fd(fd.as_raw_fd()) {
+    return Ok(());
+  }
+  let permissions = state.borrow_mut::<PermissionsContainer>();
+  if let Some(addr) = SockRef::from(fd).local_addr()?.as_socket() {
+    permissions
+      .check_net(&(addr.ip().to_string(), Some(addr.port())), API_NAME)?;
+    return Ok(());
+  }
+  // SAFETY: the descriptor stays owned by `fd`, the listener is never
+  // dropped.
+  let socket = std::mem::ManuallyDrop::new(unsafe {
+    std::os::unix::net::UnixListener::from_raw_fd(fd.as_raw_fd())
+  });
+  if let Some(path) = socket.local_addr()?.as_pathname() {
+    permissions.check_open(
+      Cow::Borrowed(path),
+      OpenAccessKind::ReadWriteNoFollow,
+      Some(API_NAME),
+    )?;
+  }
+  Ok(())
+}
+
+/// Adopts the descriptors systemd passed in (`LISTEN_FDS`), optionally only
+/// the ones named `name`. Descriptors are handed out once per process.
+#[op2(stack_trace)]
+#[serde]
+pub fn op_net_listen_systemd(
+  state: &mut OpState,
+  #[string] name: Option<String>,
+) -> Result<Vec<ReceivedHandle>, NetError> {
+  super::check_unstable(state, "Deno.listenSystemd");
+  {
+    let permissions = state.borrow::<PermissionsContainer>();
+    permissions.check_env("LISTEN_PID")?;
+    permissions.check_env("LISTEN_FDS")?;
+    permissions.check_env("LISTEN_FDNAMES")?;
+  }
+  let matches = |fd_name: &str| name.as_deref().is_none_or(|n| n == fd_name);
+  let taken = {
+    let mut fds = SYSTEMD_FDS
+      .get_or_init(|| Mutex::new(inherited_systemd_fds()))
+      .lock()
+      .unwrap();
+    for (fd_name, fd) in fds.iter() {
+      if matches(fd_name) {
+        check_inherited_fd(state, fd)?;
+      }
+    }
+    let (taken, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut *fds)
+      .into_iter()
+      .partition(|(n, _)| matches(n));
+    *fds = rest;
+    taken
+  };
+  let mut handles = Vec::with_capacity(taken.len());
+  for (_, fd) in taken {
+    if let Some(handle) = add_received_fd(state, fd)? {
+      handles.push(handle);
+    }
+  }
+  Ok(handles)
+}
+
+/// Sends `message` to the service manager (`sd_notify`). Returns `false`
+/// when the process was not started with a `NOTIFY_SOCKET`.
+#[op2(fast, stack_trace)]
+pub fn op_net_notify_systemd(
+  state: &mut OpState,
+  #[string] message: &str,
+) -> Result<bool, NetError> {
+  super::check_unstable(state, "Deno.notifySystemd");
+  let permissions = state.borrow_mut::<PermissionsContainer>();
+  permissions.check_env("NOTIFY_SOCKET")?;
+  let Some(path) = std::env::var_os("NOTIFY_SOCKET") else {
+    return Ok(false);
+  };
+  let socket = std::os::unix::net::UnixDatagram::unbound()?;
+  if let Some(name) = path.as_encoded_bytes().strip_prefix(b"@") {
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+      use std::os::linux::net::SocketAddrExt;
+      let addr = std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+      socket.send_to_addr(message.as_bytes(), &addr)?;
+    }
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    {
+      let _ = name;
+      return Err(NetError::Io(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Abstract notify sockets are only supported on Linux",
+      )));
+    }
+  } else {
+    let path = permissions
+      .check_open(
+        Cow::Owned(PathBuf::from(path)),
+        OpenAccessKind::WriteNoFollow,
+        Some("Deno.notifySystemd()"),
+      )
+      .map_err(NetError::Permission)?;
+    socket.send_to(message.as_bytes(), path)?;
+  }
+  Ok(true)
+}
@@ -0,0 +1,164 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Token buckets shaping the reads and writes of a connection, set with
+//! `Deno.Conn.setRateLimit()`.
+
+use std::cell::RefCell;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde::Deserialize;
+
+/// The longest single wait for tokens. Tiny rates would otherwise overflow
+/// the wait, after which the bucket is checked again.
+const MAX_WAIT: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize, Debug, Default, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitOptions {
+  /// Bytes per second that may be read, or `None` for no limit.
+  read_bps: Option<f64>,
+  /// Bytes per second that may be written, or `None` for no limit.
+  write_bps: Option<f64>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+  /// Bytes added per second.
+  rate: f64,
+  /// At most one second worth of bytes accumulates while the connection is
+  /// idle, which is also the largest single read or write.
+  capacity: f64,
+  tokens: f64,
+  updated: Instant,
+}
+
+impl TokenBucket {
+  fn new(rate: f64) -> Self {
+    // a single byte must fit for any progress to be made
+    let capacity = rate.max(1.0);
+    Self {
+      rate,
+      capacity,
+      tokens: capacity,
+      updated: Instant::now(),
+    }
+  }
+
+  fn refill(&mut self) {
+    let now = Instant::now();
+    let elapsed = now.duration_since(self.updated).as_secs_f64();
+    self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+    self.updated = now;
+  }
+
+  /// Takes up to `want` tokens, or returns how long to wait until enough
+  /// are available. To not trickle out single bytes, at least a twentieth
+  /// of a second worth of bytes is waited for.
+  fn take(&mut self, want: usize) -> Result<usize, Duration> {
+    self.refill();
+    let min = (want as f64)
+      .min((self.rate / 20.0).max(1.0))
+      .min(self.capacity);
+    if self.tokens < min {
+      let wait = Duration::try_from_secs_f64((min - self.tokens) / self.rate)
+        .unwrap_or(MAX_WAIT);
+      return Err(wait.min(MAX_WAIT));
+    }
+    let granted = (self.tokens as usize).min(want);
+    self.tokens -= granted as f64;
+    Ok(granted)
+  }
+
+  fn put_back(&mut self, tokens: usize) {
+    self.tokens = (self.tokens + tokens as f64).min(self.capacity);
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+  read: RefCell<Option<TokenBucket>>,
+  write: RefCell<Option<TokenBucket>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+  Read,
+  Write,
+}
+
+impl RateLimiter {
+  pub fn set(&self, options: RateLimitOptions) {
+    *self.read.borrow_mut() = options.read_bps.map(TokenBucket::new);
+    *self.write.borrow_mut() = options.write_bps.map(TokenBucket::new);
+  }
+
+  fn bucket(&self, direction: Direction) -> &RefCell<Option<TokenBucket>> {
+    match direction {
+      Direction::Read => &self.read,
+      Direction::Write => &self.write,
+    }
+  }
+
+  /// Waits until some of `len` bytes may be transferred and returns how
+  /// many. Without a limit this is `len` right away. Unused bytes must be
+  /// given back with [`RateLimiter::release`].
+  pub async fn acquire(&self, direction: Direction, len: usize) -> usize {
+    if len == 0 {
+      return 0;
+    }
+    loop {
+      let wait = match self.bucket(direction).borrow_mut().as_mut() {
+        None => return len,
+        Some(bucket) => match bucket.take(len) {
+          Ok(granted) => return granted,
+          Err(wait) => wait,
+        },
+      };
+      tokio::time::sleep(wait).await;
+    }
+  }
+
+  pub fn release(&self, direction: Direction, unused: usize) {
+    if let Some(bucket) = self.bucket(direction).borrow_mut().as_mut() {
+      bucket.put_back(unused);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn token_bucket_paces_transfers() {
+    let mut bucket = TokenBucket::new(100.0);
+    assert_eq!(bucket.take(60), Ok(60));
+    assert_eq!(bucket.take(60), Ok(40));
+    // waits for 5 bytes, a twentieth of the rate
+    let wait = bucket.take(60).unwrap_err();
+    assert!(wait <= Duration::from_millis(50));
+
+    bucket.put_back(30);
+    assert_eq!(bucket.take(60), Ok(30));
+  }
+
+  #[test]
+  fn token_bucket_caps_wait_of_tiny_rates() {
+    let mut bucket = TokenBucket::new(1e-300);
+    assert_eq!(bucket.take(1), Ok(1));
+    assert_eq!(bucket.take(1), Err(MAX_WAIT));
+  }
+
+  #[tokio::test]
+  async fn unlimited_without_options() {
+    let limiter = RateLimiter::default();
+    assert_eq!(limiter.acquire(Direction::Read, 1 << 20).await, 1 << 20);
+    limiter.set(RateLimitOptions {
+      read_bps: Some(10.0),
+      write_bps: None,
+    });
+    assert_eq!(limiter.acquire(Direction::Read, 1 << 20).await, 10);
+    assert_eq!(limiter.acquire(Direction::Write, 1 << 20).await, 1 << 20);
+  }
+}
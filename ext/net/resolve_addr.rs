@@ -1,8 +1,17 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::net::ToSocketAddrs;
 
+use hickory_proto::ProtoError;
+use hickory_proto::ProtoErrorKind;
+use hickory_resolver::ResolveErrorKind;
+use hickory_resolver::Resolver;
+use hickory_resolver::config::NameServerConfigGroup;
+use hickory_resolver::config::ResolverConfig;
+use hickory_resolver::config::ResolverOpts;
+use hickory_resolver::name_server::TokioConnectionProvider;
 use tokio::net::lookup_host;
 
 /// Resolve network address *asynchronously*.
@@ -25,6 +34,62 @@ pub fn resolve_addr_sync(
   Ok(result)
 }
 
+/// Resolvers for connections that name their own DNS server, kept around so
+/// their caches outlive a single connection. Cached answers expire with the
+/// TTL of their records.
+#[derive(Default)]
+pub struct NameServerResolvers(
+  HashMap<SocketAddr, Resolver<TokioConnectionProvider>>,
+);
+
+impl NameServerResolvers {
+  pub fn get(
+    &mut self,
+    name_server: SocketAddr,
+  ) -> Resolver<TokioConnectionProvider> {
+    self
+      .0
+      .entry(name_server)
+      .or_insert_with(|| {
+        let group = NameServerConfigGroup::from_ips_clear(
+          &[name_server.ip()],
+          name_server.port(),
+          true,
+        );
+        Resolver::builder_with_config(
+          ResolverConfig::from_parts(None, vec![], group),
+          TokioConnectionProvider::default(),
+        )
+        .with_options(ResolverOpts::default())
+        .build()
+      })
+      .clone()
+  }
+}
+
+/// Resolve network address *asynchronously* with the given resolver rather
+/// than the system one.
+pub async fn resolve_addr_with(
+  resolver: &Resolver<TokioConnectionProvider>,
+  hostname: &str,
+  port: u16,
+) -> Result<Vec<SocketAddr>, std::io::Error> {
+  let (addr, port) = make_addr_port_pair(hostname, port);
+  let lookup =
+    resolver
+      .lookup_ip(addr)
+      .await
+      .map_err(|err| match err.kind() {
+        ResolveErrorKind::Proto(ProtoError { kind, .. })
+          if matches!(**kind, ProtoErrorKind::NoRecordsFound { .. }) =>
+        {
+          std::io::Error::new(std::io::ErrorKind::NotFound, err)
+        }
+        _ => std::io::Error::other(err),
+      })?;
+  Ok(lookup.iter().map(|ip| SocketAddr::new(ip, port)).collect())
+}
+
 fn make_addr_port_pair(hostname: &str, port: u16) -> (&str, u16) {
   // Default to localhost if given just the port. Example: ":80"
   if hostname.is_empty() {
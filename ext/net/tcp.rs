@@ -1,8 +1,12 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
+use deno_core::futures::StreamExt;
+use deno_core::futures::stream::FuturesUnordered;
 use socket2::Domain;
 use socket2::Protocol;
 use socket2::Type;
@@ -124,6 +128,16 @@ impl TcpListener {
     })
   }
 
+  /// Adopts a socket that is already listening, such as one passed in by
+  /// another process.
+  pub fn from_std(listener: std::net::TcpListener) -> std::io::Result<Self> {
+    listener.set_nonblocking(true)?;
+    Ok(Self {
+      listener: Some(tokio::net::TcpListener::from_std(listener)?),
+      conn: None,
+    })
+  }
+
   pub async fn accept(
     &self,
   ) -> std::io::Result<(tokio::net::TcpStream, SocketAddr)> {
@@ -134,6 +148,39 @@ impl TcpListener {
   pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
     self.listener.as_ref().unwrap().local_addr()
   }
+
+  /// Enables TCP Fast Open with a queue of up to `fast_open` pending
+  /// connections, and defers accepting connections until the client sent
+  /// data, for at most `defer_accept` seconds. Zero leaves an option unset.
+  /// Both are ignored on platforms other than Linux and Android.
+  pub fn set_accept_options(
+    &self,
+    fast_open: u32,
+    defer_accept: u32,
+  ) -> std::io::Result<()> {
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+      use std::os::fd::AsRawFd;
+
+      let fd = self.as_raw_fd();
+      if fast_open > 0 {
+        set_tcp_option(fd, libc::TCP_FASTOPEN, fast_open)?;
+      }
+      if defer_accept > 0 {
+        set_tcp_option(fd, libc::TCP_DEFER_ACCEPT, defer_accept)?;
+      }
+    }
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    let _ = (fast_open, defer_accept);
+    Ok(())
+  }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsRawFd for TcpListener {
+  fn as_raw_fd(&self) -> std::os::fd::RawFd {
+    self.listener.as_ref().unwrap().as_raw_fd()
+  }
 }
 
 impl Drop for TcpListener {
@@ -151,6 +198,30 @@ impl Drop for TcpListener {
   }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn set_tcp_option(
+  fd: std::os::fd::RawFd,
+  option: libc::c_int,
+  value: u32,
+) -> std::io::Result<()> {
+  let value = value as libc::c_int;
+  // SAFETY: `fd` is an open socket and `value` outlives the call.
+  let ret = unsafe {
+    libc::setsockopt(
+      fd,
+      libc::IPPROTO_TCP,
+      option,
+      &value as *const libc::c_int as *const libc::c_void,
+      std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+    )
+  };
+  if ret == 0 {
+    Ok(())
+  } else {
+    Err(std::io::Error::last_os_error())
+  }
+}
+
 /// Bind a socket to an address and listen with the low-level options we need.
 #[allow(unused_variables)]
 fn bind_socket_and_listen(
@@ -180,3 +251,106 @@ fn bind_socket_and_listen(
   let listener = socket.into();
   Ok(listener)
 }
+
+/// How long a connection attempt gets before the next address is tried in
+/// parallel (the "Connection Attempt Delay" of RFC 8305).
+pub const DEFAULT_HAPPY_EYEBALLS_DELAY: Duration = Duration::from_millis(250);
+
+/// Connects to the first of `addrs` that answers, racing the addresses the
+/// way RFC 8305 ("Happy Eyeballs") describes: attempts start `delay` apart,
+/// or as soon as the previous one failed, alternating between IPv6 and IPv4
+/// so a broken address family costs at most one `delay`.
+pub async fn connect_happy_eyeballs(
+  addrs: impl IntoIterator<Item = SocketAddr>,
+  delay: Duration,
+) -> std::io::Result<tokio::net::TcpStream> {
+  let mut pending =
+    interleave_families(addrs.into_iter().collect()).into_iter();
+  let mut attempts = FuturesUnordered::new();
+  let mut last_error = None;
+  loop {
+    if attempts.is_empty() {
+      let Some(addr) = pending.next() else {
+        return Err(last_error.unwrap_or_else(|| {
+          std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No address to connect to",
+          )
+        }));
+      };
+      attempts.push(tokio::net::TcpStream::connect(addr));
+    }
+    tokio::select! {
+      Some(result) = attempts.next() => match result {
+        Ok(stream) => return Ok(stream),
+        Err(err) => {
+          last_error = Some(err);
+          if let Some(addr) = pending.next() {
+            attempts.push(tokio::net::TcpStream::connect(addr));
+          }
+        }
+      },
+      _ = tokio::time::sleep(delay), if !pending.as_slice().is_empty() => {
+        if let Some(addr) = pending.next() {
+          attempts.push(tokio::net::TcpStream::connect(addr));
+        }
+      }
+    }
+  }
+}
+
+/// Reorders resolved addresses so the families alternate, starting with
+/// the family of the first address the resolver returned.
+fn interleave_families(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+  let Some(first) = addrs.first() else {
+    return addrs;
+  };
+  let first_is_ipv6 = first.is_ipv6();
+  let (mut preferred, mut other): (VecDeque<_>, VecDeque<_>) = addrs
+    .into_iter()
+    .partition(|addr| addr.is_ipv6() == first_is_ipv6);
+  let mut interleaved = Vec::with_capacity(preferred.len() + other.len());
+  loop {
+    match (preferred.pop_front(), other.pop_front()) {
+      (None, None) => return interleaved,
+      (a, b) => interleaved.extend(a.into_iter().chain(b)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn interleaves_address_families() {
+    let addrs = ["[::1]:80", "[::2]:80", "[::3]:80", "1.1.1.1:80"]
+      .map(|addr| addr.parse::<SocketAddr>().unwrap());
+    assert_eq!(
+      interleave_families(addrs.to_vec()),
+      vec![addrs[0], addrs[3], addrs[1], addrs[2]]
+    );
+    assert_eq!(interleave_families(vec![]), vec![]);
+  }
+
+  #[tokio::test]
+  async fn connects_past_unreachable_addresses() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let listening = listener.local_addr().unwrap();
+    // nothing listens on a port that was just released
+    let closed = {
+      let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+      listener.local_addr().unwrap()
+    };
+    let stream =
+      connect_happy_eyeballs([closed, listening], Duration::from_secs(10))
+        .await
+        .unwrap();
+    assert_eq!(stream.peer_addr().unwrap(), listening);
+    assert!(
+      connect_happy_eyeballs([closed], DEFAULT_HAPPY_EYEBALLS_DELAY)
+        .await
+        .is_err()
+    );
+  }
+}
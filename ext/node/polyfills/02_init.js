@@ -45,6 +45,9 @@ function initialize(args) {
       moduleSpecifier,
     );
     internals.__setupChildProcessIpcChannel();
+    if (runningOnMainThread) {
+      internals.__initCluster();
+    }
     // `Deno[Deno.internal].requireImpl` will be unreachable after this line.
     delete internals.requireImpl;
   } else {
@@ -5,31 +5,52 @@ import {
   type Dirent,
   direntFromDeno,
 } from "ext:deno_node/internal/fs/utils.mjs";
-import { assert } from "ext:deno_node/_util/asserts.ts";
-import { ERR_MISSING_ARGS } from "ext:deno_node/internal/errors.ts";
+import {
+  denoErrorToNodeError,
+  ERR_DIR_CLOSED,
+  ERR_MISSING_ARGS,
+} from "ext:deno_node/internal/errors.ts";
 import { TextDecoder } from "ext:deno_web/08_text_encoding.js";
 
 const {
-  Promise,
+  ArrayFrom,
+  ArrayPrototypePush,
+  ArrayPrototypeShift,
   ObjectPrototypeIsPrototypeOf,
-  Uint8ArrayPrototype,
+  Promise,
   PromisePrototypeThen,
+  PromiseReject,
+  PromiseResolve,
+  SymbolAsyncDispose,
   SymbolAsyncIterator,
-  ArrayIteratorPrototypeNext,
-  AsyncGeneratorPrototypeNext,
-  SymbolIterator,
+  SymbolDispose,
+  Uint8ArrayPrototype,
 } = primordials;
 
+async function readEntries(path: string): Promise<Deno.DirEntry[]> {
+  const entries: Deno.DirEntry[] = [];
+  for await (const entry of Deno.readDir(path)) {
+    ArrayPrototypePush(entries, entry);
+  }
+  return entries;
+}
+
 export default class Dir {
   #dirPath: string | Uint8Array;
-  #syncIterator!: Iterator<Deno.DirEntry, undefined> | null;
-  #asyncIterator!: AsyncIterator<Deno.DirEntry, undefined> | null;
+  #entries: Deno.DirEntry[] | null;
+  #loading: Promise<Deno.DirEntry[]> | null = null;
+  #closed = false;
 
-  constructor(path: string | Uint8Array) {
+  /**
+   * `entries` is the snapshot `opendir` already read while validating the
+   * path. Without it the directory is read on first use.
+   */
+  constructor(path: string | Uint8Array, entries?: Deno.DirEntry[]) {
     if (!path) {
       throw new ERR_MISSING_ARGS("path");
     }
     this.#dirPath = path;
+    this.#entries = entries ?? null;
   }
 
   get path(): string {
@@ -39,27 +60,40 @@ export default class Dir {
     return this.#dirPath;
   }
 
+  #next(): Dirent | null {
+    const entry = ArrayPrototypeShift(this.#entries!);
+    if (entry === undefined) {
+      return null;
+    }
+    return direntFromDeno({ ...entry, parentPath: this.path });
+  }
+
   // deno-lint-ignore no-explicit-any
   read(callback?: (...args: any[]) => void): Promise<Dirent | null> {
-    return new Promise((resolve, reject) => {
-      if (!this.#asyncIterator) {
-        this.#asyncIterator = Deno.readDir(this.path)[SymbolAsyncIterator]();
+    if (this.#closed) {
+      const err = new ERR_DIR_CLOSED();
+      if (callback) {
+        callback(err);
+        return PromiseResolve(null);
       }
-      assert(this.#asyncIterator);
+      return PromiseReject(err);
+    }
+    return new Promise((resolve, reject) => {
+      const entries = this.#entries === null
+        ? (this.#loading ??= readEntries(this.path))
+        : PromiseResolve(this.#entries);
       PromisePrototypeThen(
-        AsyncGeneratorPrototypeNext(this.#asyncIterator),
-        (iteratorResult) => {
-          resolve(
-            iteratorResult.done ? null : direntFromDeno(iteratorResult.value),
-          );
+        entries,
+        (entries) => {
+          this.#entries = entries;
+          const dirent = this.#next();
+          resolve(dirent);
           if (callback) {
-            callback(
-              null,
-              iteratorResult.done ? null : direntFromDeno(iteratorResult.value),
-            );
+            callback(null, dirent);
           }
         },
         (err) => {
+          err = denoErrorToNodeError(err as Error, { syscall: "opendir" });
           if (callback) {
             callback(err);
           }
@@ -70,26 +104,38 @@ export default class Dir {
   }
 
   readSync(): Dirent | null {
-    if (!this.#syncIterator) {
-      this.#syncIterator = Deno.readDirSync(this.path)![SymbolIterator]();
+    if (this.#closed) {
+      throw new ERR_DIR_CLOSED();
     }
-
-    const iteratorResult = ArrayIteratorPrototypeNext(this.#syncIterator);
-    if (iteratorResult.done) {
-      return null;
-    } else {
-      return direntFromDeno(iteratorResult.value);
+    if (this.#entries === null) {
+      try {
+        this.#entries = ArrayFrom(Deno.readDirSync(this.path));
+      } catch (err) {
+        throw denoErrorToNodeError(err as Error, { syscall: "opendir" });
+      }
     }
+    return this.#next();
   }
 
   /**
-   * Unlike Node, Deno does not require managing resource ids for reading
-   * directories, and therefore does not need to close directories when
-   * finished reading.
+   * Unlike Node, Deno does not keep an open file descriptor for the
+   * directory, so closing only marks the handle as unusable.
    */
   // deno-lint-ignore no-explicit-any
   close(callback?: (...args: any[]) => void): Promise<void> {
-    return new Promise((resolve) => {
+    return new Promise((resolve, reject) => {
+      if (this.#closed) {
+        const err = new ERR_DIR_CLOSED();
+        if (callback) {
+          callback(err);
+          resolve();
+        } else {
+          reject(err);
+        }
+        return;
+      }
+      this.#closed = true;
+      this.#entries = null;
       if (callback) {
         callback(null);
       }
@@ -97,13 +143,12 @@ export default class Dir {
     });
   }
 
-  /**
-   * Unlike Node, Deno does not require managing resource ids for reading
-   * directories, and therefore does not need to close directories when
-   * finished reading
-   */
   closeSync() {
-    //No op
+    if (this.#closed) {
+      throw new ERR_DIR_CLOSED();
+    }
+    this.#closed = true;
+    this.#entries = null;
   }
 
   async *[SymbolAsyncIterator](): AsyncIterableIterator<Dirent> {
@@ -116,6 +161,20 @@ export default class Dir {
         yield dirent;
       }
     } finally {
+      if (!this.#closed) {
+        await this.close();
+      }
+    }
+  }
+
+  [SymbolDispose]() {
+    if (!this.#closed) {
+      this.closeSync();
+    }
+  }
+
+  async [SymbolAsyncDispose]() {
+    if (!this.#closed) {
       await this.close();
     }
   }
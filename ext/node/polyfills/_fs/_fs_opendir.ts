@@ -15,11 +15,13 @@ import { promisify } from "ext:deno_node/internal/util.mjs";
 import { primordials } from "ext:core/mod.js";
 
 const {
+  ArrayFrom,
   StringPrototypeToString,
 } = primordials;
 
-/** These options aren't functionally used right now, as `Dir` doesn't yet support them.
- * However, these values are still validated.
+/** These options aren't functionally used right now, as `Dir` reads the
+ * whole directory when it is opened. However, these values are still
+ * validated.
  */
 type Options = {
   encoding?: string;
@@ -62,9 +64,7 @@ export function opendir(
     validateInteger(bufferSize, "options.bufferSize", 1, 4294967295);
 
     /** Throws if path is invalid */
-    Deno.readDirSync(path);
-
-    dir = new Dir(path);
+    dir = new Dir(path, ArrayFrom(Deno.readDirSync(path)));
   } catch (error) {
     err = denoErrorToNodeError(error as Error, { syscall: "opendir" });
   }
@@ -96,9 +96,7 @@ export function opendirSync(
 
   try {
     /** Throws if path is invalid */
-    Deno.readDirSync(path);
-
-    return new Dir(path);
+    return new Dir(path, ArrayFrom(Deno.readDirSync(path)));
   } catch (err) {
     throw denoErrorToNodeError(err as Error, { syscall: "opendir" });
   }
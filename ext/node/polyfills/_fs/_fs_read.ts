@@ -32,6 +32,12 @@ const { ObjectDefineProperty } = primordials;
 
 const validateOptionArgs = { __proto__: null, nullable: true };
 
+// Positions can be bigints so that offsets past 2^53 are not rounded.
+function isPosition(position: unknown): position is number | bigint {
+  return (typeof position === "number" || typeof position === "bigint") &&
+    position >= 0;
+}
+
 type BinaryCallback = (
   err: Error | null,
   bytesRead: number | null,
@@ -56,7 +62,7 @@ export function read(
   buffer: ArrayBufferView,
   offset: number,
   length: number,
-  position: number | null,
+  position: number | bigint | null,
   callback: Callback,
 ): void;
 export function read(
@@ -70,7 +76,7 @@ export function read(
     | ReadAsyncOptions<NodeJS.ArrayBufferView>
     | Callback,
   lengthOrCb?: number | Callback,
-  position?: number | null,
+  position?: number | bigint | null,
   callback?: Callback,
 ) {
   fd = getValidatedFd(fd);
@@ -145,7 +151,7 @@ export function read(
   (async () => {
     try {
       let nread: number | null;
-      if (typeof position === "number" && position >= 0) {
+      if (isPosition(position)) {
         const currentPosition = await op_fs_seek_async(
           fd,
           0,
@@ -189,7 +195,7 @@ export function readSync(
   buffer: ArrayBufferView,
   offset: number,
   length: number,
-  position: number | null,
+  position: number | bigint | null,
 ): number;
 export function readSync(
   fd: number,
@@ -201,7 +207,7 @@ export function readSync(
   buffer: ArrayBufferView,
   offsetOrOpt?: number | ReadSyncOptions,
   length?: number,
-  position?: number | null,
+  position?: number | bigint | null,
 ): number {
   fd = getValidatedFd(fd);
 
@@ -249,7 +255,7 @@ export function readSync(
   }
 
   let currentPosition = 0;
-  if (typeof position === "number" && position >= 0) {
+  if (isPosition(position)) {
     currentPosition = op_fs_seek_sync(fd, 0, io.SeekMode.Current);
     op_fs_seek_sync(fd, position, io.SeekMode.Start);
   }
@@ -259,7 +265,7 @@ export function readSync(
     arrayBufferViewToUint8Array(buffer).subarray(offset, offset + length!),
   );
 
-  if (typeof position === "number" && position >= 0) {
+  if (isPosition(position)) {
     op_fs_seek_sync(fd, currentPosition, io.SeekMode.Start);
   }
 
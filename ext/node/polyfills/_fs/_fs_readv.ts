@@ -18,10 +18,31 @@ import { customPromisifyArgs } from "ext:deno_node/internal/util.mjs";
 
 const {
   ObjectDefineProperty,
+  Promise,
   PromisePrototypeThen,
+  TypedArrayPrototypeGetBuffer,
   TypedArrayPrototypeGetByteLength,
+  TypedArrayPrototypeGetByteOffset,
+  Uint8Array,
 } = primordials;
 
+export interface ReadVResult {
+  bytesRead: number;
+  buffers: readonly ArrayBufferView[];
+}
+
+/**
+ * The part of `buf` past the first `offset` bytes, so a short read into one
+ * buffer continues where it stopped instead of overwriting it.
+ */
+function remainingBytes(buf: ArrayBufferView, offset: number): Uint8Array {
+  return new Uint8Array(
+    TypedArrayPrototypeGetBuffer(buf),
+    TypedArrayPrototypeGetByteOffset(buf) + offset,
+    TypedArrayPrototypeGetByteLength(buf) - offset,
+  );
+}
+
 type Callback = (
   err: ErrnoException | null,
   bytesRead: number,
@@ -36,7 +57,13 @@ export function readv(
 export function readv(
   fd: number,
   buffers: readonly ArrayBufferView[],
-  position: number | Callback,
+  position: number | null,
+  callback: Callback,
+): void;
+export function readv(
+  fd: number,
+  buffers: readonly ArrayBufferView[],
+  position: number | null | Callback,
   callback?: Callback,
 ): void {
   if (typeof fd !== "number") {
@@ -70,7 +97,7 @@ export function readv(
     let bufIdx = 0;
     let buf = buffers[bufIdx];
     while (bufIdx < buffers.length) {
-      const nread = await io.read(fd, buf);
+      const nread = await io.read(fd, remainingBytes(buf, readInBuf));
       if (nread === null) {
         break;
       }
@@ -98,6 +125,19 @@ ObjectDefineProperty(readv, customPromisifyArgs, {
   enumerable: false,
 });
 
+export function readvPromise(
+  fd: number,
+  buffers: readonly ArrayBufferView[],
+  position?: number | null,
+): Promise<ReadVResult> {
+  return new Promise((resolve, reject) => {
+    readv(fd, buffers, position ?? null, (err, bytesRead, buffers) => {
+      if (err) reject(err);
+      else resolve({ bytesRead, buffers });
+    });
+  });
+}
+
 export function readvSync(
   fd: number,
   buffers: readonly ArrayBufferView[],
@@ -121,7 +161,7 @@ export function readvSync(
   let bufIdx = 0;
   let buf = buffers[bufIdx];
   while (bufIdx < buffers.length) {
-    const nread = io.readSync(fd, buf);
+    const nread = io.readSync(fd, remainingBytes(buf, readInBuf));
     if (nread === null) {
       break;
     }
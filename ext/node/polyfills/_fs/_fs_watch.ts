@@ -3,7 +3,7 @@
 // TODO(petamoriken): enable prefer-primordials for node polyfills
 // deno-lint-ignore-file prefer-primordials
 
-import { basename } from "node:path";
+import { basename, isAbsolute, relative, resolve } from "node:path";
 import { EventEmitter } from "node:events";
 import { notImplemented } from "ext:deno_node/_utils.ts";
 import { promisify } from "node:util";
@@ -113,6 +113,7 @@ export function watch(
     : undefined;
 
   const watchPath = getValidatedPath(filename).toString();
+  const watchRoot = resolveWatchRoot(watchPath);
 
   let iterator: Deno.FsWatcher;
   // Start the actual watcher a few msec later to avoid race condition
@@ -125,11 +126,10 @@ export function watch(
 
     asyncIterableToCallback<Deno.FsEvent>(iterator, (val, done) => {
       if (done) return;
-      fsWatcher.emit(
-        "change",
-        convertDenoFsEventToNodeFsEvent(val.kind),
-        basename(val.paths[0]),
-      );
+      const eventType = convertDenoFsEventToNodeFsEvent(val.kind);
+      for (const path of eventPaths(val)) {
+        fsWatcher.emit("change", eventType, eventFilename(watchRoot, path));
+      }
     }, (e) => {
       fsWatcher.emit("error", e);
     });
@@ -165,6 +165,7 @@ export function watchPromise(
   },
 ): AsyncIterable<{ eventType: string; filename: string | Buffer | null }> {
   const watchPath = getValidatedPath(filename).toString();
+  const watchRoot = resolveWatchRoot(watchPath);
 
   const watcher = Deno.watchFs(watchPath, {
     recursive: options?.recursive ?? false,
@@ -175,16 +176,21 @@ export function watchPromise(
   }
 
   const fsIterable = watcher[Symbol.asyncIterator]();
+  // a rename carries both the old and the new path, which Node reports as
+  // two events
+  const pending: { eventType: string; filename: string }[] = [];
   const iterable = {
     async next() {
-      const result = await fsIterable.next();
-      if (result.done) return result;
-
-      const eventType = convertDenoFsEventToNodeFsEvent(result.value.kind);
-      return {
-        value: { eventType, filename: basename(result.value.paths[0]) },
-        done: result.done,
-      };
+      while (pending.length === 0) {
+        const result = await fsIterable.next();
+        if (result.done) return result;
+
+        const eventType = convertDenoFsEventToNodeFsEvent(result.value.kind);
+        for (const path of eventPaths(result.value)) {
+          pending.push({ eventType, filename: eventFilename(watchRoot, path) });
+        }
+      }
+      return { value: pending.shift()!, done: false };
     },
   };
 
@@ -363,6 +369,31 @@ class FSWatcher extends EventEmitter {
 
 type NodeFsEventType = "rename" | "change";
 
+// Event paths are canonical on some platforms (e.g. /private/var on macOS).
+function resolveWatchRoot(watchPath: string): string {
+  try {
+    return Deno.realPathSync(watchPath);
+  } catch {
+    return resolve(watchPath);
+  }
+}
+
+function eventPaths(event: Deno.FsEvent): string[] {
+  return event.kind === "rename" ? event.paths : event.paths.slice(0, 1);
+}
+
+/**
+ * Node reports filenames relative to the watched directory, which includes
+ * the subdirectory for recursive watches. Watching a file reports its name.
+ */
+function eventFilename(watchRoot: string, path: string): string {
+  const rel = relative(watchRoot, path);
+  if (rel === "" || rel.startsWith("..") || isAbsolute(rel)) {
+    return basename(path);
+  }
+  return rel;
+}
+
 function convertDenoFsEventToNodeFsEvent(
   kind: Deno.FsEvent["kind"],
 ): NodeFsEventType {
@@ -1,78 +1,338 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 // Copyright Joyent and Node contributors. All rights reserved. MIT license.
 
-import { notImplemented } from "ext:deno_node/_utils.ts";
+// The workers of a cluster are forked with an IPC channel to the primary.
+// Instead of the primary accepting connections and passing the handles to
+// the workers, each worker listens on the shared port itself with
+// SO_REUSEPORT and the operating system distributes the connections, which
+// is what `SCHED_NONE` does in Node. As nothing is shared through the
+// primary, workers that listen on port 0 each get a random port of their own
+// rather than a common one.
+
+import { internals } from "ext:core/mod.js";
 import { EventEmitter } from "node:events";
+import { ChildProcess, fork as forkChildProcess } from "node:child_process";
+import process from "node:process";
+
+const kClusterCommand = "NODE_CLUSTER";
+
+type ClusterMessage = {
+  cmd: typeof kClusterCommand;
+  act: string;
+  // deno-lint-ignore no-explicit-any
+  [key: string]: any;
+};
+
+type ClusterSettings = {
+  execArgv?: string[];
+  exec?: string;
+  args?: string[];
+  cwd?: string;
+  serialization?: "json" | "advanced";
+  silent?: boolean;
+  // deno-lint-ignore no-explicit-any
+  stdio?: any[];
+  uid?: number;
+  gid?: number;
+  windowsHide?: boolean;
+};
+
+// deno-lint-ignore no-explicit-any
+function isClusterMessage(message: any): message is ClusterMessage {
+  return message !== null && typeof message === "object" &&
+    message.cmd === kClusterCommand;
+}
 
 /** A Worker object contains all public information and method about a worker.
  * In the primary it can be obtained using cluster.workers. In a worker it can
  * be obtained using cluster.worker.
  */
-export class Worker {
-  constructor() {
-    notImplemented("cluster.Worker.prototype.constructor");
+export class Worker extends EventEmitter {
+  id: number;
+  // deno-lint-ignore no-explicit-any
+  process: any;
+  state: string;
+  exitedAfterDisconnect: boolean | undefined = undefined;
+
+  // deno-lint-ignore no-explicit-any
+  constructor(options: { id?: number; process?: any; state?: string } = {}) {
+    super();
+    this.id = options.id ?? 0;
+    this.process = options.process;
+    this.state = options.state ?? "none";
+    if (this.process) {
+      this.process.on(
+        "message",
+        // deno-lint-ignore no-explicit-any
+        (message: any, handle: any) => this.emit("message", message, handle),
+      );
+      this.process.on("error", (err: Error) => this.emit("error", err));
+    }
+  }
+
+  /** Sends a message to the primary, or to the worker from the primary. */
+  // deno-lint-ignore no-explicit-any
+  send(...args: any[]): boolean {
+    return this.process.send(...args);
+  }
+
+  /** Kills the worker, or exits the worker process from itself. */
+  kill(signal = "SIGTERM") {
+    this.exitedAfterDisconnect = true;
+    if (this.process === process) {
+      process.exit(0);
+    }
+    if (this.isConnected()) {
+      this.process.disconnect();
+    }
+    this.process.kill(signal);
+  }
+
+  destroy(signal?: string) {
+    this.kill(signal);
+  }
+
+  /** Closes the servers of the worker and then its IPC channel, letting it
+   * exit once it has no more work. */
+  disconnect(): this {
+    this.exitedAfterDisconnect = true;
+    if (this.process === process) {
+      disconnectWorker();
+    } else if (this.isConnected()) {
+      sendClusterMessage(this.process, { act: "disconnect" });
+    }
+    return this;
+  }
+
+  isConnected(): boolean {
+    return this.process.connected === true;
+  }
+
+  isDead(): boolean {
+    return this.process.exitCode != null || this.process.signalCode != null;
   }
 }
-/** Calls .disconnect() on each worker in cluster.workers. */
-export function disconnect() {
-  notImplemented("cluster.disconnect");
-}
-/** Spawn a new worker process. */
+
 // deno-lint-ignore no-explicit-any
-export function fork(_env?: any): Worker {
-  notImplemented("cluster.fork");
+function sendClusterMessage(target: any, message: Record<string, unknown>) {
+  if (target.connected) {
+    target.send({ cmd: kClusterCommand, ...message });
+  }
 }
+
+export const SCHED_NONE = 1;
+export const SCHED_RR = 2;
+
 /** True if the process is a primary. This is determined by
  * the process.env.NODE_UNIQUE_ID. If process.env.NODE_UNIQUE_ID is undefined,
  * then isPrimary is true. */
-// TODO(@marvinhagemeister): Replace this with an env check once
-// we properly set NODE_UNIQUE_ID
-export const isPrimary = true;
+export let isPrimary = true;
 /** True if the process is not a primary (it is the negation of
  * cluster.isPrimary). */
-export const isWorker = false;
+export let isWorker = false;
 /** Deprecated alias for cluster.isPrimary. details. */
-export const isMaster = isPrimary;
+export let isMaster = isPrimary;
 /** The scheduling policy, either cluster.SCHED_RR for round-robin or
- * cluster.SCHED_NONE to leave it to the operating system. This is a global
- * setting and effectively frozen once either the first worker is spawned, or
- * .setupPrimary() is called, whichever comes first. */
-export const schedulingPolicy = undefined;
+ * cluster.SCHED_NONE to leave it to the operating system. Connections are
+ * always distributed by the operating system. */
+export const schedulingPolicy = SCHED_NONE;
 /** The settings object */
-export const settings = {};
-/** setupPrimary is used to change the default 'fork' behavior. Once called,
- * the settings will be present in cluster.settings. */
-export function setupPrimary() {
-  notImplemented("cluster.setupPrimary");
-}
-/** Deprecated alias for .setupPrimary(). */
-export const setupMaster = setupPrimary;
+export const settings: ClusterSettings = {};
 /** A reference to the current worker object. Not available in the primary
  * process. */
-export const worker = undefined;
+export let worker: Worker | undefined = undefined;
 /** A hash that stores the active worker objects, keyed by id field. Makes it
  * easy to loop through all the workers. It is only available in the primary
  * process. */
-export const workers = {};
+export const workers: Record<string, Worker> = {};
 
-export const SCHED_NONE = 1;
-export const SCHED_RR = 2;
+let nextWorkerId = 0;
+let setupDone = false;
+/** Emits "disconnect" when the last worker is gone. */
+const intercom = new EventEmitter();
+
+/** setupPrimary is used to change the default 'fork' behavior. Once called,
+ * the settings will be present in cluster.settings. */
+export function setupPrimary(options?: ClusterSettings) {
+  Object.assign(settings, {
+    args: process.argv.slice(2),
+    exec: process.argv[1],
+    execArgv: process.execArgv,
+    silent: false,
+    ...settings,
+    ...options,
+  });
+  setupDone = true;
+  cluster.emit("setup", settings);
+}
+/** Deprecated alias for .setupPrimary(). */
+export const setupMaster = setupPrimary;
+
+/** Spawn a new worker process. */
+// deno-lint-ignore no-explicit-any
+export function fork(env?: Record<string, any>): Worker {
+  if (!isPrimary) {
+    throw new Error("cluster.fork() can only be called in the primary");
+  }
+  if (!setupDone) {
+    setupPrimary();
+  }
+  const id = ++nextWorkerId;
+  const child: ChildProcess = forkChildProcess(settings.exec!, settings.args, {
+    cwd: settings.cwd,
+    env: { ...process.env, ...env, NODE_UNIQUE_ID: `${id}` },
+    execArgv: settings.execArgv,
+    serialization: settings.serialization,
+    silent: settings.silent,
+    stdio: settings.stdio,
+    uid: settings.uid,
+    gid: settings.gid,
+    windowsHide: settings.windowsHide,
+  });
+  const worker = new Worker({ id, process: child });
+  worker.on(
+    "message",
+    // deno-lint-ignore no-explicit-any
+    (message: any, handle: any) =>
+      cluster.emit("message", worker, message, handle),
+  );
+  child.on("internalMessage", (message) => {
+    if (!isClusterMessage(message)) {
+      return;
+    }
+    if (message.act === "online") {
+      worker.state = "online";
+      worker.emit("online");
+      cluster.emit("online", worker);
+    } else if (message.act === "listening") {
+      const address = {
+        address: message.address,
+        port: message.port,
+        addressType: message.addressType,
+      };
+      worker.state = "listening";
+      worker.emit("listening", address);
+      cluster.emit("listening", worker, address);
+    }
+  });
+  child.once("disconnect", () => {
+    worker.state = "disconnected";
+    worker.emit("disconnect");
+    cluster.emit("disconnect", worker);
+  });
+  child.once("exit", (code: number | null, signal: string | null) => {
+    worker.state = "dead";
+    delete workers[id];
+    worker.emit("exit", code, signal);
+    cluster.emit("exit", worker, code, signal);
+    if (Object.keys(workers).length === 0) {
+      intercom.emit("disconnect");
+    }
+  });
+  workers[id] = worker;
+  cluster.emit("fork", worker);
+  return worker;
+}
+
+/** Calls .disconnect() on each worker in cluster.workers. */
+export function disconnect(callback?: () => void) {
+  if (!isPrimary) {
+    throw new Error("cluster.disconnect() can only be called in the primary");
+  }
+  const remaining = Object.values(workers);
+  if (remaining.length === 0) {
+    process.nextTick(() => intercom.emit("disconnect"));
+  }
+  for (const worker of remaining) {
+    worker.disconnect();
+  }
+  if (callback) {
+    intercom.once("disconnect", callback);
+  }
+}
+
+/** The servers listening in this worker, closed on disconnect. */
+// deno-lint-ignore no-explicit-any
+const workerServers = new Set<any>();
+
+function disconnectWorker() {
+  let pending = workerServers.size + 1;
+  const done = () => {
+    if (--pending === 0 && process.connected) {
+      process.disconnect();
+    }
+  };
+  for (const server of workerServers) {
+    server.close(done);
+  }
+  workerServers.clear();
+  done();
+}
+
+/** Called by `net` when a server of a cluster worker starts listening, to
+ * report it to the primary. */
+// deno-lint-ignore no-explicit-any
+export function _onWorkerListening(server: any) {
+  workerServers.add(server);
+  server.once("close", () => workerServers.delete(server));
+  const address = server.address();
+  sendClusterMessage(process, {
+    act: "listening",
+    address: typeof address === "object" ? address?.address : address,
+    port: typeof address === "object" ? address?.port : undefined,
+    addressType: typeof address === "object"
+      ? (address?.family === "IPv6" ? 6 : 4)
+      : -1,
+  });
+}
+
+function initCluster() {
+  const id = process.env.NODE_UNIQUE_ID;
+  if (id === undefined) {
+    return;
+  }
+  delete process.env.NODE_UNIQUE_ID;
+  isPrimary = false;
+  isWorker = true;
+  isMaster = false;
+  worker = new Worker({ id: Number(id), process, state: "online" });
+  cluster.isPrimary = isPrimary;
+  cluster.isWorker = isWorker;
+  cluster.isMaster = isMaster;
+  cluster.worker = worker;
+  // deno-lint-ignore no-explicit-any
+  (process as any).on("internalMessage", (message: any) => {
+    if (isClusterMessage(message) && message.act === "disconnect") {
+      worker!.exitedAfterDisconnect = true;
+      disconnectWorker();
+    }
+  });
+  process.once("disconnect", () => {
+    worker!.emit("disconnect");
+    if (!worker!.exitedAfterDisconnect) {
+      // the primary went away
+      process.exit(0);
+    }
+  });
+  sendClusterMessage(process, { act: "online" });
+}
+
+internals.__initCluster = initCluster;
 
 const cluster = new EventEmitter() as EventEmitter & {
   isWorker: boolean;
   isMaster: boolean;
   isPrimary: boolean;
-  Worker: Worker;
+  Worker: typeof Worker;
+  worker: Worker | undefined;
   workers: Record<string, Worker>;
-  settings: Record<string, unknown>;
-  // deno-lint-ignore no-explicit-any
-  setupPrimary(options?: any): void;
-  // deno-lint-ignore no-explicit-any
-  setupMaster(options?: any): void;
-  // deno-lint-ignore no-explicit-any
-  fork(env: any): Worker;
+  settings: ClusterSettings;
+  schedulingPolicy: number;
+  setupPrimary(options?: ClusterSettings): void;
+  setupMaster(options?: ClusterSettings): void;
   // deno-lint-ignore no-explicit-any
-  disconnect(cb: any): void;
+  fork(env?: Record<string, any>): Worker;
+  disconnect(callback?: () => void): void;
   SCHED_NONE: 1;
   SCHED_RR: 2;
 };
@@ -80,8 +340,10 @@ cluster.isWorker = isWorker;
 cluster.isMaster = isMaster;
 cluster.isPrimary = isPrimary;
 cluster.Worker = Worker;
+cluster.worker = worker;
 cluster.workers = workers;
-cluster.settings = {};
+cluster.settings = settings;
+cluster.schedulingPolicy = schedulingPolicy;
 cluster.setupPrimary = setupPrimary;
 cluster.setupMaster = setupMaster;
 cluster.fork = fork;
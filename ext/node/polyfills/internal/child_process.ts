@@ -1460,6 +1460,7 @@ export function setupChannel(
             // TODO(nathanwhit): once we add support for sending
             // handles, if we want to support deno-node IPC interop,
             // we'll need to handle the NODE_HANDLE_* messages here.
+            nextTick(() => target.emit("internalMessage", msg));
             continue;
           }
         }
@@ -22,7 +22,12 @@ import {
   TextOptionsArgument,
 } from "ext:deno_node/_fs/_fs_common.ts";
 import { ftruncatePromise } from "ext:deno_node/_fs/_fs_ftruncate.ts";
-import { writevPromise, WriteVResult } from "ext:deno_node/_fs/_fs_writev.ts";
+import { readvPromise, ReadVResult } from "ext:deno_node/_fs/_fs_readv.ts";
+import {
+  writev as writevAsync,
+  writevPromise,
+  WriteVResult,
+} from "ext:deno_node/_fs/_fs_writev.ts";
 import { fchmodPromise } from "ext:deno_node/_fs/_fs_fchmod.ts";
 import { fchownPromise } from "ext:deno_node/_fs/_fs_fchown.ts";
 import { fdatasyncPromise } from "ext:deno_node/_fs/_fs_fdatasync.ts";
@@ -152,6 +157,13 @@ export class FileHandle extends EventEmitter {
     return fsCall(promises.writeFile, "writeFile", this, data, options);
   }
 
+  readv(
+    buffers: ArrayBufferView[],
+    position?: number | null,
+  ): Promise<ReadVResult> {
+    return fsCall(readvPromise, "readv", this, buffers, position);
+  }
+
   writev(buffers: ArrayBufferView[], position?: number): Promise<WriteVResult> {
     return fsCall(writevPromise, "writev", this, buffers, position);
   }
@@ -251,13 +263,33 @@ export class FileHandle extends EventEmitter {
   }
 
   createReadStream(options?: CreateReadStreamOptions): ReadStream {
-    return new ReadStream(undefined, { ...options, fd: this.fd });
+    return new ReadStream(undefined, {
+      ...options,
+      fd: this.fd,
+      fs: { read: readAsync, close: this.#streamClose },
+    });
   }
 
   createWriteStream(options?: CreateWriteStreamOptions): WriteStream {
-    return new WriteStream(undefined, { ...options, fd: this.fd });
+    return new WriteStream(undefined, {
+      ...options,
+      fd: this.fd,
+      fs: {
+        write: writeAsync,
+        writev: writevAsync,
+        close: this.#streamClose,
+      },
+    });
   }
 
+  /**
+   * Streams created from the handle close the handle itself rather than the
+   * raw fd, so the handle does not outlive its descriptor with `autoClose`.
+   */
+  #streamClose = (_fd: number, callback: (err?: Error | null) => void) => {
+    PromisePrototypeThen(this.close(), () => callback(null), callback);
+  };
+
   readLines(options?: CreateReadStreamOptions): ReadlineInterface {
     return createInterface({
       input: this.createReadStream({ ...options, autoClose: false }),
@@ -27,8 +27,11 @@
 // TODO(petamoriken): enable prefer-primordials for node polyfills
 // deno-lint-ignore-file prefer-primordials
 
-import { op_net_connect_tcp } from "ext:core/ops";
-import { TcpConn } from "ext:deno_net/01_net.js";
+import {
+  op_net_connect_tcp,
+  op_node_unstable_net_listen_tcp,
+} from "ext:core/ops";
+import { Listener, TcpConn } from "ext:deno_net/01_net.js";
 import { core } from "ext:core/mod.js";
 const { internalFdSymbol } = core;
 import { notImplemented } from "ext:deno_node/_utils.ts";
@@ -84,7 +87,9 @@ export class TCPConnectWrap extends AsyncWrap {
 export enum constants {
   SOCKET = socketType.SOCKET,
   SERVER = socketType.SERVER,
-  UV_TCP_IPV6ONLY,
+  // bind flags, as in libuv
+  UV_TCP_IPV6ONLY = 1,
+  UV_TCP_REUSEPORT = 2,
 }
 
 export class TCP extends ConnectionWrap {
@@ -93,6 +98,7 @@ export class TCP extends ConnectionWrap {
 
   #address?: string;
   #port?: number;
+  #reusePort = false;
 
   #remoteAddress?: string;
   #remoteFamily?: string;
@@ -167,8 +173,8 @@ export class TCP extends ConnectionWrap {
    * @param port The port to bind to
    * @return An error status code.
    */
-  bind(address: string, port: number): number {
-    return this.#bind(address, port, 0);
+  bind(address: string, port: number, flags = 0): number {
+    return this.#bind(address, port, flags);
   }
 
   /**
@@ -211,16 +217,16 @@ export class TCP extends ConnectionWrap {
   listen(backlog: number): number {
     this.#backlog = ceilPowOf2(backlog + 1);
 
-    const listenOptions = {
-      hostname: this.#address!,
-      port: this.#port!,
-      transport: "tcp" as const,
-    };
-
     let listener;
 
     try {
-      listener = Deno.listen(listenOptions);
+      // `reusePort` is unstable in `Deno.listen()`
+      const { 0: rid, 1: addr } = op_node_unstable_net_listen_tcp(
+        { hostname: this.#address!, port: this.#port! },
+        this.#reusePort,
+      );
+      addr.transport = "tcp";
+      listener = new Listener(rid, addr, "tcp");
     } catch (e) {
       if (e instanceof Deno.errors.NotCapable) {
         throw e;
@@ -347,7 +353,7 @@ export class TCP extends ConnectionWrap {
    * @param _flags
    * @return An error status code.
    */
-  #bind(address: string, port: number, _flags: number): number {
+  #bind(address: string, port: number, flags: number): number {
     // Deno doesn't currently separate bind from connect etc.
     // REF:
     // - https://doc.deno.land/deno/stable/~/Deno.connect
@@ -361,6 +367,7 @@ export class TCP extends ConnectionWrap {
 
     this.#address = address;
     this.#port = port;
+    this.#reusePort = (flags & constants.UV_TCP_REUSEPORT) !== 0;
 
     return 0;
   }
@@ -114,6 +114,7 @@ import type { DuplexOptions } from "ext:deno_node/_stream.d.ts";
 import type { BufferEncoding } from "ext:deno_node/_global.d.ts";
 import type { Abortable } from "ext:deno_node/_events.d.ts";
 import { channel } from "node:diagnostics_channel";
+import { _onWorkerListening, isWorker as isClusterWorker } from "node:cluster";
 import { primordials } from "ext:core/mod.js";
 
 const {
@@ -1950,23 +1951,18 @@ function _listenInCluster(
 ) {
   exclusive = !!exclusive;
 
-  // TODO(cmorten): here we deviate somewhat from the Node implementation which
-  // makes use of the https://nodejs.org/api/cluster.html module to run servers
-  // across a "cluster" of Node processes to take advantage of multi-core
-  // systems.
-  //
-  // Though Deno has has a Worker capability from which we could simulate this,
-  // for now we assert that we are _always_ on the primary process.
-  const isPrimary = true;
-
-  if (isPrimary || exclusive) {
-    // Will create a new handle
-    // _listen2 sets up the listened handle, it is still named like this
-    // to avoid breaking code that wraps this method
-    server._listen2(address, port, addressType, backlog, fd, flags);
-
-    return;
+  // Unlike Node, the primary doesn't own the listening handles of a cluster.
+  // The workers listen on the same port with SO_REUSEPORT instead, leaving the
+  // distribution of connections to the operating system.
+  if (isClusterWorker && !exclusive) {
+    flags = (flags ?? 0) | TCPConstants.UV_TCP_REUSEPORT;
+    server.once("listening", () => _onWorkerListening(server));
   }
+
+  // Will create a new handle
+  // _listen2 sets up the listened handle, it is still named like this
+  // to avoid breaking code that wraps this method
+  server._listen2(address, port, addressType, backlog, fd, flags);
 }
 
 function _lookupAndListen(
@@ -2089,7 +2085,7 @@ export function _createServerHandle(
     } else if (addressType === 6) {
       err = (handle as TCP).bind6(address, port ?? 0, flags ?? 0);
     } else {
-      err = (handle as TCP).bind(address, port ?? 0);
+      err = (handle as TCP).bind(address, port ?? 0, flags ?? 0);
     }
   }
 
@@ -25,6 +25,7 @@ import {
 } from "ext:deno_web/13_message_port.js";
 import * as webidl from "ext:deno_webidl/00_webidl.js";
 import { notImplemented } from "ext:deno_node/_utils.ts";
+import { ERR_WORKER_OUT_OF_MEMORY } from "ext:deno_node/internal/errors.ts";
 import { EventEmitter } from "node:events";
 import { BroadcastChannel } from "ext:deno_web/01_broadcast_channel.js";
 import { untransferableSymbol } from "ext:deno_node/internal_binding/util.ts";
@@ -37,12 +38,18 @@ const {
   FunctionPrototypeCall,
   JSONParse,
   JSONStringify,
+  MapPrototype,
+  MapPrototypeForEach,
+  MathCeil,
   ObjectHasOwn,
   ObjectPrototypeIsPrototypeOf,
   PromiseResolve,
   SafeMap,
   SafeSet,
   SafeWeakMap,
+  SetPrototype,
+  SetPrototypeForEach,
+  StringPrototypeIncludes,
   StringPrototypeStartsWith,
   StringPrototypeTrim,
   Symbol,
@@ -91,6 +98,33 @@ export interface WorkerOptions {
   name?: string;
 }
 
+/** Part of the error a worker is terminated with when its heap reaches the
+ * limit it was created with. */
+const kHeapLimitExceeded = "exceeded its heap limit";
+
+/** The heap limit of a worker, enforced by the runtime, is the sum of the
+ * young and old generation limits, as V8 doesn't limit them separately. */
+function heapLimit(
+  resourceLimits: NonNullable<WorkerOptions["resourceLimits"]>,
+) {
+  const { maxOldGenerationSizeMb: old, maxYoungGenerationSizeMb: young } =
+    resourceLimits;
+  if (!(old > 0)) {
+    return null;
+  }
+  return { heapMb: MathCeil(old + (young > 0 ? young : 0)) };
+}
+
+// deno-lint-ignore no-explicit-any
+function assertTransferable(transferList: any) {
+  for (let i = 0; i < transferList?.length; i++) {
+    const item = transferList[i];
+    if (item?.[untransferableSymbol] === true) {
+      throw new DOMException("Value not transferable", "DataCloneError");
+    }
+  }
+}
+
 const privateWorkerRef = Symbol("privateWorkerRef");
 class NodeWorker extends EventEmitter {
   #id = 0;
@@ -160,12 +194,19 @@ class NodeWorker extends EventEmitter {
     if (options?.env) {
       env_ = JSONParse(JSONStringify(options?.env));
     }
+    this.resourceLimits = {
+      ...this.resourceLimits,
+      ...options?.resourceLimits,
+    };
+    const transferList = options?.transferList ?? [];
+    assertTransferable(transferList);
     const serializedWorkerMetadata = serializeJsMessageData({
       workerData: options?.workerData,
       environmentData: environmentData,
       env: env_,
       isWorkerThread: true,
-    }, options?.transferList ?? []);
+      resourceLimits: options?.resourceLimits,
+    }, transferList);
     const id = op_create_worker(
       {
         // deno-lint-ignore prefer-primordials
@@ -176,6 +217,7 @@ class NodeWorker extends EventEmitter {
         name: this.#name,
         workerType: "node",
         closeOnIdle: true,
+        limits: heapLimit(this.resourceLimits),
       },
       serializedWorkerMetadata,
     );
@@ -230,6 +272,11 @@ class NodeWorker extends EventEmitter {
       switch (type) {
         case 1: { // TerminalError
           this.#status = "CLOSED";
+          if (StringPrototypeIncludes(data?.message, kHeapLimitExceeded)) {
+            this.#handleError(
+              new ERR_WORKER_OUT_OF_MEMORY("JS heap out of memory"),
+            );
+          }
           if (!this.#exited) {
             this.#exited = true;
             this.emit("exit", 1);
@@ -284,6 +331,7 @@ class NodeWorker extends EventEmitter {
         this.#workerOnline = true;
         this.emit("online");
       } else {
+        patchMessagePortIfFound(message);
         this.emit("message", message);
       }
     }
@@ -313,6 +361,7 @@ class NodeWorker extends EventEmitter {
       );
     }
     const { transfer } = options;
+    assertTransferable(transfer);
     const data = serializeJsMessageData(message, transfer);
     if (this.#status === "RUNNING") {
       op_host_post_message(this.#id, data);
@@ -425,6 +474,10 @@ internals.__initWorkerThreads = (
       workerData = metadata.workerData;
       environmentData = metadata.environmentData;
       isWorkerThread = metadata.isWorkerThread;
+      if (metadata.resourceLimits) {
+        resourceLimits = { ...resourceLimits, ...metadata.resourceLimits };
+        defaultExport.resourceLimits = resourceLimits;
+      }
       const env = metadata.env;
       if (env) {
         process.env = env;
@@ -517,8 +570,21 @@ export function setEnvironmentData(key: unknown, value?: unknown) {
 }
 
 export const SHARE_ENV = SymbolFor("nodejs.worker_threads.SHARE_ENV");
-export function markAsUntransferable() {
-  notImplemented("markAsUntransferable");
+/** Marks an object as not transferable, so that it throws when it is in the
+ * transfer list of a `postMessage()` call. */
+// deno-lint-ignore no-explicit-any
+export function markAsUntransferable(obj: any) {
+  if ((typeof obj !== "object" && typeof obj !== "function") || obj === null) {
+    return;
+  }
+  obj[untransferableSymbol] = true;
+}
+// deno-lint-ignore no-explicit-any
+export function isMarkedAsUntransferable(obj: any): boolean {
+  if (obj == null) {
+    return false;
+  }
+  return obj[untransferableSymbol] === true;
 }
 export function moveMessagePortToContext() {
   notImplemented("moveMessagePortToContext");
@@ -616,13 +682,7 @@ function webMessagePortToNodeMessagePort(port: MessagePort) {
   };
   const webPostMessage = port.postMessage;
   port.postMessage = (message, transferList) => {
-    for (let i = 0; i < transferList?.length; i++) {
-      const item = transferList[i];
-      if (item[untransferableSymbol] === true) {
-        throw new DOMException("Value not transferable", "DataCloneError");
-      }
-    }
-
+    assertTransferable(transferList?.transfer ?? transferList);
     return FunctionPrototypeCall(
       webPostMessage,
       port,
@@ -653,6 +713,13 @@ function patchMessagePortIfFound(data: any, seen = new SafeSet<any>()) {
 
   if (ObjectPrototypeIsPrototypeOf(MessagePortPrototype, data)) {
     webMessagePortToNodeMessagePort(data);
+  } else if (ObjectPrototypeIsPrototypeOf(MapPrototype, data)) {
+    MapPrototypeForEach(data, (value, key) => {
+      patchMessagePortIfFound(key, seen);
+      patchMessagePortIfFound(value, seen);
+    });
+  } else if (ObjectPrototypeIsPrototypeOf(SetPrototype, data)) {
+    SetPrototypeForEach(data, (value) => patchMessagePortIfFound(value, seen));
   } else {
     for (const obj in data as Record<string, unknown>) {
       if (ObjectHasOwn(data, obj)) {
@@ -673,6 +740,7 @@ export {
 };
 
 const defaultExport = {
+  isMarkedAsUntransferable,
   markAsUntransferable,
   moveMessagePortToContext,
   receiveMessageOnPort,
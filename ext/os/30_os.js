@@ -2,6 +2,7 @@
 
 import { primordials } from "ext:core/mod.js";
 import {
+  op_cgroup_limits,
   op_delete_env,
   op_env,
   op_exec_path,
@@ -55,6 +56,10 @@ function systemMemoryInfo() {
   return op_system_memory_info();
 }
 
+function cgroupLimits() {
+  return op_cgroup_limits();
+}
+
 function networkInterfaces() {
   return op_network_interfaces();
 }
@@ -145,6 +150,7 @@ function execPath() {
 }
 
 export {
+  cgroupLimits,
   env,
   execPath,
   exit,
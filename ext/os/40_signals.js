@@ -3,6 +3,7 @@
 import { core, primordials } from "ext:core/mod.js";
 import { op_signal_bind, op_signal_poll, op_signal_unbind } from "ext:core/ops";
 const {
+  ObjectFreeze,
   SafeSet,
   SafeSetIterator,
   SetPrototypeAdd,
@@ -52,7 +53,7 @@ function addSignalListener(signo, listener) {
     // If signal resource doesn't exist, create it.
     // The program starts listening to the signal
     sigData.rid = bindSignal(signo);
-    loop(sigData);
+    loop(signo, sigData);
   }
 }
 
@@ -68,13 +69,21 @@ function removeSignalListener(signo, listener) {
   }
 }
 
-async function loop(sigData) {
+async function loop(signo, sigData) {
   while (sigData.rid) {
-    if (await pollSignal(sigData.rid)) {
+    const event = await pollSignal(sigData.rid);
+    if (event === null) {
       return;
     }
+    const info = ObjectFreeze({
+      signal: signo,
+      pid: event.pid,
+      uid: event.uid,
+      code: event.code,
+      value: event.value,
+    });
     for (const listener of new SafeSetIterator(sigData.listeners)) {
-      listener();
+      listener(info);
     }
   }
 }
@@ -0,0 +1,267 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! The CPU and memory limits of the cgroup the process runs in, for both
+//! cgroup v1 and v2. Containers usually get less than the host has, so the
+//! limits are applied to `Deno.systemMemoryInfo()` and to the size of the
+//! blocking thread pool, and are exposed with `Deno.cgroupLimits()`. The
+//! standard library already takes the CPU limit into account for
+//! `available_parallelism`, which `navigator.hardwareConcurrency` uses.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CgroupLimits {
+  /// The cgroup version, 1 or 2.
+  pub version: u8,
+  /// The CPUs the cgroup may use, as its quota divided by its period.
+  pub cpu_quota: Option<f64>,
+  /// Bytes of memory the cgroup may use.
+  pub memory_max: Option<u64>,
+  /// Bytes of memory the cgroup currently uses.
+  pub memory_current: Option<u64>,
+}
+
+/// The files a cgroup reports its limits in.
+struct Controller {
+  cpu: PathBuf,
+  memory: PathBuf,
+  version: u8,
+}
+
+/// Parses the `cpu.max` of cgroup v2, like `max 100000` or `50000 100000`.
+fn parse_cpu_max(content: &str) -> Option<f64> {
+  let mut parts = content.split_whitespace();
+  let quota = parts.next()?.parse::<f64>().ok()?;
+  let period = parts.next().map_or(Some(100_000.0), |p| p.parse().ok())?;
+  (quota > 0.0 && period > 0.0).then(|| quota / period)
+}
+
+/// Parses `cpu.cfs_quota_us` and `cpu.cfs_period_us` of cgroup v1, where a
+/// negative quota means no limit.
+fn parse_cfs_quota(quota: &str, period: &str) -> Option<f64> {
+  let quota = quota.trim().parse::<i64>().ok()?;
+  let period = period.trim().parse::<i64>().ok()?;
+  (quota > 0 && period > 0).then(|| quota as f64 / period as f64)
+}
+
+/// Parses `memory.max` of cgroup v2, or `memory.limit_in_bytes` of cgroup
+/// v1, which reports no limit as a value close to `i64::MAX`.
+fn parse_memory_max(content: &str) -> Option<u64> {
+  let bytes = content.trim().parse::<u64>().ok()?;
+  (bytes < (i64::MAX as u64 & !0xfff)).then_some(bytes)
+}
+
+/// The path of the cgroup of the process within each hierarchy, from
+/// `/proc/self/cgroup`.
+fn parse_proc_cgroup(content: &str) -> Vec<(Vec<&str>, &str)> {
+  content
+    .lines()
+    .filter_map(|line| {
+      let mut parts = line.splitn(3, ':');
+      let _id = parts.next()?;
+      let controllers = parts.next()?;
+      let path = parts.next()?;
+      let controllers = controllers
+        .split(',')
+        .filter(|c| !c.is_empty())
+        .collect::<Vec<_>>();
+      Some((controllers, path))
+    })
+    .collect()
+}
+
+/// A cgroup file system mount from `/proc/self/mountinfo`.
+#[derive(Debug, PartialEq, Eq)]
+struct CgroupMount<'a> {
+  root: &'a str,
+  mount_point: &'a str,
+  version: u8,
+  controllers: Vec<&'a str>,
+}
+
+fn parse_mountinfo(content: &str) -> Vec<CgroupMount<'_>> {
+  content
+    .lines()
+    .filter_map(|line| {
+      let (fields, rest) = line.split_once(" - ")?;
+      let fields = fields.split(' ').collect::<Vec<_>>();
+      let mut rest = rest.split(' ');
+      let fs_type = rest.next()?;
+      let _source = rest.next()?;
+      let options = rest.next().unwrap_or("");
+      let version = match fs_type {
+        "cgroup2" => 2,
+        "cgroup" => 1,
+        _ => return None,
+      };
+      Some(CgroupMount {
+        root: fields.get(3)?,
+        mount_point: fields.get(4)?,
+        version,
+        controllers: options.split(',').collect(),
+      })
+    })
+    .collect()
+}
+
+/// The directory of a cgroup, given the mount of its hierarchy.
+fn cgroup_dir(mount: &CgroupMount, path: &str) -> PathBuf {
+  // within a cgroup namespace the path is relative to the mount's root
+  let relative = path
+    .strip_prefix(mount.root)
+    .unwrap_or(path)
+    .trim_start_matches('/');
+  Path::new(mount.mount_point).join(relative)
+}
+
+fn find_controller(proc_cgroup: &str, mountinfo: &str) -> Option<Controller> {
+  let cgroups = parse_proc_cgroup(proc_cgroup);
+  let mounts = parse_mountinfo(mountinfo);
+  let v1_dir = |controller: &str| {
+    let (_, path) = cgroups.iter().find(|(c, _)| c.contains(&controller))?;
+    let mount = mounts
+      .iter()
+      .find(|m| m.version == 1 && m.controllers.contains(&controller))?;
+    Some(cgroup_dir(mount, path))
+  };
+  if let (Some(cpu), Some(memory)) = (v1_dir("cpu"), v1_dir("memory")) {
+    return Some(Controller {
+      cpu,
+      memory,
+      version: 1,
+    });
+  }
+  let (_, path) = cgroups.iter().find(|(c, _)| c.is_empty())?;
+  let mount = mounts.iter().find(|m| m.version == 2)?;
+  let dir = cgroup_dir(mount, path);
+  Some(Controller {
+    cpu: dir.clone(),
+    memory: dir,
+    version: 2,
+  })
+}
+
+/// Reads a limit of the cgroup in `dir` and its ancestors up to `root`,
+/// returning the tightest one, as limits of a parent also apply to its
+/// children.
+fn tightest<T: PartialOrd>(
+  dir: &Path,
+  root: &Path,
+  read: impl Fn(&Path) -> Option<T>,
+) -> Option<T> {
+  let mut limit: Option<T> = None;
+  for dir in dir.ancestors() {
+    if let Some(value) = read(dir)
+      && limit.as_ref().is_none_or(|limit| value < *limit)
+    {
+      limit = Some(value);
+    }
+    if dir == root || !dir.starts_with(root) {
+      break;
+    }
+  }
+  limit
+}
+
+#[allow(clippy::disallowed_methods)]
+fn read(path: &Path) -> Option<String> {
+  std::fs::read_to_string(path).ok()
+}
+
+/// The limits of the cgroup of the process, or `None` when it isn't in one
+/// or the platform doesn't have cgroups.
+pub fn limits() -> Option<CgroupLimits> {
+  if !cfg!(any(target_os = "android", target_os = "linux")) {
+    return None;
+  }
+  let controller = find_controller(
+    &read(Path::new("/proc/self/cgroup"))?,
+    &read(Path::new("/proc/self/mountinfo"))?,
+  )?;
+  let root = Path::new("/sys/fs/cgroup");
+  Some(match controller.version {
+    1 => CgroupLimits {
+      version: 1,
+      cpu_quota: tightest(&controller.cpu, root, |dir| {
+        parse_cfs_quota(
+          &read(&dir.join("cpu.cfs_quota_us"))?,
+          &read(&dir.join("cpu.cfs_period_us"))?,
+        )
+      }),
+      memory_max: tightest(&controller.memory, root, |dir| {
+        parse_memory_max(&read(&dir.join("memory.limit_in_bytes"))?)
+      }),
+      memory_current: read(&controller.memory.join("memory.usage_in_bytes"))
+        .and_then(|v| v.trim().parse().ok()),
+    },
+    _ => CgroupLimits {
+      version: 2,
+      cpu_quota: tightest(&controller.cpu, root, |dir| {
+        parse_cpu_max(&read(&dir.join("cpu.max"))?)
+      }),
+      memory_max: tightest(&controller.memory, root, |dir| {
+        parse_memory_max(&read(&dir.join("memory.max"))?)
+      }),
+      memory_current: read(&controller.memory.join("memory.current"))
+        .and_then(|v| v.trim().parse().ok()),
+    },
+  })
+}
+
+/// The CPUs the process may use, rounded up, when its cgroup limits them.
+/// This is read once, as it is used when creating every runtime.
+pub fn cpu_limit() -> Option<usize> {
+  static CPU_LIMIT: OnceLock<Option<usize>> = OnceLock::new();
+  *CPU_LIMIT.get_or_init(|| {
+    let quota = limits()?.cpu_quota?;
+    Some((quota.ceil() as usize).max(1))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_limits() {
+    assert_eq!(parse_cpu_max("max 100000\n"), None);
+    assert_eq!(parse_cpu_max("150000 100000\n"), Some(1.5));
+    assert_eq!(parse_cfs_quota("-1\n", "100000\n"), None);
+    assert_eq!(parse_cfs_quota("50000\n", "100000\n"), Some(0.5));
+    assert_eq!(parse_memory_max("max\n"), None);
+    assert_eq!(parse_memory_max("536870912\n"), Some(536870912));
+    assert_eq!(parse_memory_max("9223372036854771712\n"), None);
+  }
+
+  #[test]
+  fn finds_cgroup_v2() {
+    let controller = find_controller(
+      "0::/system.slice/app.service\n",
+      "35 24 0:30 / /sys/fs/cgroup rw,nosuid - cgroup2 cgroup2 rw\n",
+    )
+    .unwrap();
+    assert_eq!(controller.version, 2);
+    assert_eq!(
+      controller.memory,
+      Path::new("/sys/fs/cgroup/system.slice/app.service")
+    );
+  }
+
+  #[test]
+  fn finds_cgroup_v1() {
+    let controller = find_controller(
+      "12:memory:/docker/abc\n4:cpu,cpuacct:/docker/abc\n0::/\n",
+      "40 31 0:35 /docker/abc /sys/fs/cgroup/memory ro - cgroup cgroup rw,memory\n\
+       41 31 0:36 /docker/abc /sys/fs/cgroup/cpu,cpuacct ro - cgroup cgroup rw,cpu,cpuacct\n",
+    )
+    .unwrap();
+    assert_eq!(controller.version, 1);
+    assert_eq!(controller.memory, Path::new("/sys/fs/cgroup/memory"));
+    assert_eq!(controller.cpu, Path::new("/sys/fs/cgroup/cpu,cpuacct"));
+  }
+}
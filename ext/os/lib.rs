@@ -1,6 +1,7 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::env;
 use std::ffi::OsString;
@@ -18,6 +19,7 @@ use deno_permissions::PermissionState;
 use deno_permissions::PermissionsContainer;
 use serde::Serialize;
 
+pub mod cgroup;
 mod ops;
 pub mod sys_info;
 
@@ -66,9 +68,11 @@ deno_core::extension!(
     op_set_exit_code,
     op_get_exit_code,
     op_system_memory_info,
+    op_cgroup_limits,
     op_uid,
     op_runtime_cpu_usage,
     op_runtime_memory_usage,
+    op_runtime_memory_breakdown,
     ops::signal::op_signal_bind,
     ops::signal::op_signal_unbind,
     ops::signal::op_signal_poll,
@@ -414,6 +418,17 @@ fn op_system_memory_info(
   Ok(sys_info::mem_info())
 }
 
+#[op2(stack_trace)]
+#[serde]
+fn op_cgroup_limits(
+  state: &mut OpState,
+) -> Result<Option<cgroup::CgroupLimits>, PermissionCheckError> {
+  state
+    .borrow_mut::<PermissionsContainer>()
+    .check_sys("systemMemoryInfo", "Deno.cgroupLimits()")?;
+  Ok(cgroup::limits())
+}
+
 #[cfg(not(windows))]
 #[op2(stack_trace)]
 #[smi]
@@ -588,6 +603,26 @@ fn op_runtime_memory_usage(
   out[3] = external as f64;
 }
 
+#[derive(Serialize)]
+struct MemoryBreakdown {
+  allocator: Option<sys_info::AllocatorInfo>,
+  /// The number of open resources of each type.
+  resources: BTreeMap<String, usize>,
+}
+
+#[op2]
+#[serde]
+fn op_runtime_memory_breakdown(state: &mut OpState) -> MemoryBreakdown {
+  let mut resources = BTreeMap::new();
+  for (_, name) in state.resource_table.names() {
+    *resources.entry(name.into_owned()).or_default() += 1;
+  }
+  MemoryBreakdown {
+    allocator: sys_info::allocator_info(),
+    resources,
+  }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 fn rss() -> u64 {
   // Inspired by https://github.com/Arc-blroth/memory-stats/blob/5364d0d09143de2a470d33161b2330914228fde9/src/linux.rs
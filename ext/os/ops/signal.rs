@@ -10,6 +10,8 @@ use deno_core::Resource;
 use deno_core::ResourceId;
 use deno_core::error::ResourceError;
 use deno_core::op2;
+use deno_signals::SignalInfo;
+use serde::Serialize;
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
 pub enum SignalError {
@@ -30,7 +32,7 @@ pub enum SignalError {
 struct SignalStreamResource {
   signo: i32,
   id: u32,
-  rx: AsyncRefCell<tokio::sync::watch::Receiver<()>>,
+  rx: AsyncRefCell<tokio::sync::watch::Receiver<SignalInfo>>,
 }
 
 impl Resource for SignalStreamResource {
@@ -54,12 +56,12 @@ pub fn op_signal_bind(
     return Err(SignalError::SignalNotAllowed(sig.to_string()));
   }
 
-  let (tx, rx) = tokio::sync::watch::channel(());
-  let id = deno_signals::register(
+  let (tx, rx) = tokio::sync::watch::channel(SignalInfo::default());
+  let id = deno_signals::register_with_info(
     signo,
     true,
-    Box::new(move || {
-      let _ = tx.send(());
+    Box::new(move |info| {
+      let _ = tx.send(*info);
     }),
   )?;
 
@@ -72,11 +74,23 @@ pub fn op_signal_bind(
   Ok(rid)
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalEvent {
+  pid: Option<i32>,
+  uid: Option<u32>,
+  code: Option<i32>,
+  value: Option<i64>,
+}
+
+/// Waits for the next signal, resolving to `null` once the listener was
+/// unbound.
 #[op2(async)]
+#[serde]
 pub async fn op_signal_poll(
   state: Rc<RefCell<OpState>>,
   #[smi] rid: ResourceId,
-) -> Result<bool, ResourceError> {
+) -> Result<Option<SignalEvent>, ResourceError> {
   let resource = state
     .borrow_mut()
     .resource_table
@@ -84,7 +98,16 @@ pub async fn op_signal_poll(
 
   let mut rx = RcRef::map(&resource, |r| &r.rx).borrow_mut().await;
 
-  Ok(rx.changed().await.is_err())
+  if rx.changed().await.is_err() {
+    return Ok(None);
+  }
+  let info = *rx.borrow_and_update();
+  Ok(Some(SignalEvent {
+    pid: info.pid,
+    uid: info.uid,
+    code: info.code,
+    value: info.value,
+  }))
 }
 
 #[op2(fast)]
@@ -193,6 +193,38 @@ pub fn hostname() -> String {
   }
 }
 
+/// Memory of the process allocated through the system allocator, which is
+/// where Rust code, as opposed to the V8 heap, allocates.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllocatorInfo {
+  /// Bytes in use.
+  pub allocated: u64,
+  /// Bytes the allocator holds on to but that are not in use.
+  pub free: u64,
+  /// Bytes in use in regions mapped separately for large allocations.
+  pub mapped: u64,
+}
+
+/// The statistics of the allocator, or `None` when it doesn't report any.
+/// Only glibc reports them.
+pub fn allocator_info() -> Option<AllocatorInfo> {
+  #[cfg(all(target_os = "linux", target_env = "gnu"))]
+  {
+    // SAFETY: libc call without arguments
+    let info = unsafe { libc::mallinfo2() };
+    Some(AllocatorInfo {
+      allocated: info.uordblks as u64,
+      free: info.fordblks as u64,
+      mapped: info.hblkhd as u64,
+    })
+  }
+  #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+  {
+    None
+  }
+}
+
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MemInfo {
@@ -242,6 +274,17 @@ pub fn mem_info() -> Option<MemInfo> {
         mem_info.available = mem.unwrap_or(0) * 1024;
       }
     }
+
+    // A container only gets the memory of its cgroup
+    if let Some(limits) = crate::cgroup::limits()
+      && let Some(max) = limits.memory_max
+      && max < mem_info.total
+    {
+      let free = max.saturating_sub(limits.memory_current.unwrap_or(0));
+      mem_info.total = max;
+      mem_info.free = mem_info.free.min(free);
+      mem_info.available = mem_info.available.min(free);
+    }
   }
   #[cfg(target_vendor = "apple")]
   {
@@ -7,12 +7,14 @@ import {
   op_run_status,
   op_spawn_child,
   op_spawn_kill,
+  op_spawn_pty_resize,
   op_spawn_sync,
   op_spawn_wait,
 } from "ext:core/ops";
 const {
   ArrayPrototypeMap,
   ArrayPrototypeSlice,
+  NumberIsSafeInteger,
   TypeError,
   ObjectEntries,
   SafeArrayIterator,
@@ -43,12 +45,16 @@ import {
 // The key for private `input` option for `Deno.Command`
 const kInputOption = Symbol("kInputOption");
 
-function opKill(pid, signo, apiName) {
-  op_kill(pid, signo, apiName);
+function opKill(pid, signo, apiName, value = undefined) {
+  op_kill(pid, signo, value, apiName);
 }
 
-function kill(pid, signo = "SIGTERM") {
-  opKill(pid, signo, "Deno.kill()");
+function kill(pid, signo = "SIGTERM", options = undefined) {
+  const value = options?.value;
+  if (value !== undefined && !NumberIsSafeInteger(value)) {
+    throw new TypeError("Signal value must be a safe integer");
+  }
+  opKill(pid, signo, "Deno.kill()", value);
 }
 
 function opRunStatus(rid) {
@@ -173,12 +179,16 @@ function spawnChildInner(command, apiName, {
   env = { __proto__: null },
   uid = undefined,
   gid = undefined,
+  groups = undefined,
+  chroot = undefined,
+  cgroup = undefined,
   signal = undefined,
   stdin = "null",
   stdout = "piped",
   stderr = "piped",
   windowsRawArguments = false,
   detached = false,
+  pty = undefined,
   [kSerialization]: serialization = "json",
   [kExtraStdio]: extraStdio = [],
   [kIpc]: ipc = -1,
@@ -192,6 +202,9 @@ function spawnChildInner(command, apiName, {
     env: ObjectEntries(env),
     uid,
     gid,
+    groups,
+    chroot: pathFromURL(chroot),
+    cgroup: pathFromURL(cgroup),
     stdin,
     stdout,
     stderr,
@@ -201,6 +214,7 @@ function spawnChildInner(command, apiName, {
     extraStdio,
     detached,
     needsNpmProcessState,
+    pty: pty === undefined ? undefined : { rows: pty.rows, cols: pty.cols },
   }, apiName);
   return new ChildProcess(illegalConstructorKey, {
     ...child,
@@ -270,6 +284,14 @@ class ChildProcess {
     return this.#stderr;
   }
 
+  #pty = null;
+  get pty() {
+    if (this.#pty == null) {
+      throw new TypeError("Cannot get 'pty': no 'pty' was requested");
+    }
+    return this.#pty;
+  }
+
   constructor(key = null, {
     signal,
     rid,
@@ -279,6 +301,7 @@ class ChildProcess {
     stderrRid,
     ipcPipeRid, // internal
     extraPipeRids,
+    ptyRid,
   } = null) {
     if (key !== illegalConstructorKey) {
       throw new TypeError("Illegal constructor");
@@ -307,6 +330,10 @@ class ChildProcess {
       );
     }
 
+    if (ptyRid !== null) {
+      this.#pty = new ChildPty(illegalConstructorKey, ptyRid);
+    }
+
     const onAbort = () => {
       try {
         this.kill("SIGTERM");
@@ -386,12 +413,49 @@ class ChildProcess {
     core.refOpPromise(this.#waitPromise);
     if (this.#stdout) readableStreamForRidUnrefableRef(this.#stdout);
     if (this.#stderr) readableStreamForRidUnrefableRef(this.#stderr);
+    if (this.#pty) readableStreamForRidUnrefableRef(this.#pty.readable);
   }
 
   unref() {
     core.unrefOpPromise(this.#waitPromise);
     if (this.#stdout) readableStreamForRidUnrefableUnref(this.#stdout);
     if (this.#stderr) readableStreamForRidUnrefableUnref(this.#stderr);
+    if (this.#pty) readableStreamForRidUnrefableUnref(this.#pty.readable);
+  }
+}
+
+/**
+ * The parent side of the pseudo-terminal of a child. Both streams share one
+ * resource, which is closed once the child closed the terminal and all its
+ * output was read.
+ */
+class ChildPty {
+  #rid;
+  #readable;
+  #writable;
+
+  constructor(key = null, rid) {
+    if (key !== illegalConstructorKey) {
+      throw new TypeError("Illegal constructor");
+    }
+    this.#rid = rid;
+    this.#readable = readableStreamForRidUnrefable(
+      rid,
+      ReadableStreamWithCollectors,
+    );
+    this.#writable = writableStreamForRid(rid, false);
+  }
+
+  get readable() {
+    return this.#readable;
+  }
+
+  get writable() {
+    return this.#writable;
+  }
+
+  resize(rows, cols) {
+    op_spawn_pty_resize(this.#rid, { rows, cols });
   }
 }
 
@@ -422,6 +486,11 @@ class ReadableStreamWithCollectors extends ReadableStream {
 }
 
 function spawn(command, options) {
+  if (options?.pty !== undefined) {
+    throw new TypeError(
+      "'pty' is not supported for this function, use 'Deno.Command().spawn()' instead",
+    );
+  }
   if (options?.stdin === "piped") {
     throw new TypeError(
       "Piped stdin is not supported for this function, use 'Deno.Command().spawn()' instead",
@@ -442,6 +511,9 @@ function spawnSync(command, {
   env = { __proto__: null },
   uid = undefined,
   gid = undefined,
+  groups = undefined,
+  chroot = undefined,
+  cgroup = undefined,
   stdin = "null",
   stdout = "piped",
   stderr = "piped",
@@ -462,6 +534,9 @@ function spawnSync(command, {
     env: ObjectEntries(env),
     uid,
     gid,
+    groups,
+    chroot: pathFromURL(chroot),
+    cgroup: pathFromURL(cgroup),
     stdin,
     stdout,
     stderr,
@@ -509,6 +584,11 @@ class Command {
   }
 
   outputSync() {
+    if (this.#options?.pty !== undefined) {
+      throw new TypeError(
+        "'pty' is not supported for this function, use 'Deno.Command.spawn()' instead",
+      );
+    }
     if (this.#options?.stdin === "piped") {
       throw new TypeError(
         "Piped stdin is not supported for this function, use 'Deno.Command.spawn()' instead",
@@ -518,6 +598,23 @@ class Command {
   }
 
   spawn() {
+    if (this.#options?.pty !== undefined) {
+      const { stdin, stdout, stderr } = this.#options;
+      if (
+        stdin !== undefined || stdout !== undefined || stderr !== undefined
+      ) {
+        throw new TypeError(
+          "Cannot use 'stdin', 'stdout' or 'stderr' together with 'pty'",
+        );
+      }
+      return spawnChild(this.#command, {
+        __proto__: null,
+        ...this.#options,
+        stdin: "null",
+        stdout: "null",
+        stderr: "null",
+      });
+    }
     const options = {
       __proto__: null,
       ...(this.#options ?? {}),
@@ -0,0 +1,120 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! The privileged options of `Deno.Command`: supplementary groups, a new
+//! root directory and a cgroup to place the child in. These are applied in
+//! the child before it drops privileges with `gid` and `uid`, so when any of
+//! them is given the credentials are changed here rather than by the
+//! standard library, which only runs `pre_exec` hooks after `setuid`.
+
+use std::ffi::CString;
+use std::io;
+#[cfg(target_os = "linux")]
+use std::os::fd::AsRawFd;
+#[cfg(target_os = "linux")]
+use std::os::fd::OwnedFd;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+pub struct ChildCredentials {
+  uid: Option<u32>,
+  gid: Option<u32>,
+  groups: Option<Vec<libc::gid_t>>,
+  /// The new root, and the working directory within it.
+  chroot: Option<(CString, CString)>,
+  /// `cgroup.procs` of the cgroup, opened in the parent.
+  #[cfg(target_os = "linux")]
+  cgroup_procs: Option<OwnedFd>,
+}
+
+fn c_path(path: &Path) -> io::Result<CString> {
+  CString::new(path.as_os_str().as_bytes())
+    .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))
+}
+
+fn cvt(result: libc::c_int) -> io::Result<()> {
+  if result < 0 {
+    Err(io::Error::last_os_error())
+  } else {
+    Ok(())
+  }
+}
+
+impl ChildCredentials {
+  pub fn new(
+    uid: Option<u32>,
+    gid: Option<u32>,
+    groups: Option<Vec<u32>>,
+    chroot: Option<&str>,
+    cwd: Option<&str>,
+    cgroup: Option<&str>,
+  ) -> Result<Self, super::ProcessError> {
+    let chroot = chroot
+      .map(|root| {
+        Ok::<_, io::Error>((
+          c_path(Path::new(root))?,
+          c_path(&Path::new("/").join(cwd.unwrap_or("/")))?,
+        ))
+      })
+      .transpose()?;
+    #[cfg(target_os = "linux")]
+    let cgroup_procs = cgroup
+      .map(|cgroup| {
+        std::fs::OpenOptions::new()
+          .write(true)
+          .open(Path::new(cgroup).join("cgroup.procs"))
+          .map(OwnedFd::from)
+      })
+      .transpose()?;
+    #[cfg(not(target_os = "linux"))]
+    if cgroup.is_some() {
+      return Err(super::ProcessError::CgroupNotSupported);
+    }
+    Ok(Self {
+      uid,
+      gid,
+      groups: groups.map(|groups| groups.into_iter().map(|g| g as _).collect()),
+      chroot,
+      #[cfg(target_os = "linux")]
+      cgroup_procs,
+    })
+  }
+
+  /// Changes the credentials of the child. Runs between fork and exec, so
+  /// only async-signal-safe calls are made.
+  ///
+  /// # Safety
+  ///
+  /// Must only be called in the child process.
+  pub unsafe fn apply(&self) -> io::Result<()> {
+    // SAFETY: plain libc calls with valid pointers
+    unsafe {
+      // writing 0 moves the writing process into the cgroup
+      #[cfg(target_os = "linux")]
+      if let Some(cgroup_procs) = &self.cgroup_procs
+        && libc::write(cgroup_procs.as_raw_fd(), b"0".as_ptr() as _, 1) < 0
+      {
+        return Err(io::Error::last_os_error());
+      }
+      if let Some((root, cwd)) = &self.chroot {
+        cvt(libc::chroot(root.as_ptr()))?;
+        cvt(libc::chdir(cwd.as_ptr()))?;
+      }
+      match &self.groups {
+        Some(groups) => {
+          cvt(libc::setgroups(groups.len() as _, groups.as_ptr()))?
+        }
+        None if self.uid.is_some() && libc::getuid() == 0 => {
+          cvt(libc::setgroups(0, std::ptr::null()))?
+        }
+        None => {}
+      }
+      if let Some(gid) = self.gid {
+        cvt(libc::setgid(gid))?;
+      }
+      if let Some(uid) = self.uid {
+        cvt(libc::setuid(uid))?;
+      }
+    }
+    Ok(())
+  }
+}
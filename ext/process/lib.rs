@@ -49,10 +49,13 @@ use serde::Serialize;
 #[cfg(unix)]
 use tokio::process::Child as AsyncChild;
 
+#[cfg(unix)]
+mod credentials;
 pub mod ipc;
 use ipc::IpcAdvancedStreamResource;
 use ipc::IpcJsonStreamResource;
 use ipc::IpcRefTracker;
+pub mod pty;
 
 pub const UNSTABLE_FEATURE_NAME: &str = "process";
 
@@ -165,6 +168,7 @@ deno_core::extension!(
     op_spawn_wait,
     op_spawn_sync,
     op_spawn_kill,
+    op_spawn_pty_resize,
     deprecated::op_run,
     deprecated::op_run_status,
     deprecated::op_kill,
@@ -198,6 +202,12 @@ pub struct SpawnArgs {
   gid: Option<u32>,
   #[cfg(unix)]
   uid: Option<u32>,
+  #[cfg(unix)]
+  groups: Option<Vec<u32>>,
+  #[cfg(unix)]
+  chroot: Option<String>,
+  #[cfg(unix)]
+  cgroup: Option<String>,
   #[cfg(windows)]
   windows_raw_arguments: bool,
   ipc: Option<i32>,
@@ -212,6 +222,7 @@ pub struct SpawnArgs {
   extra_stdio: Vec<Stdio>,
   detached: bool,
   needs_npm_process_state: bool,
+  pty: Option<pty::PtySize>,
 }
 
 #[derive(Deserialize)]
@@ -303,6 +314,16 @@ pub enum ProcessError {
   #[class(type)]
   #[error("Missing cmd")]
   MissingCmd, // only for Deno.run
+  #[class("NotSupported")]
+  #[error("Pseudo-terminals are not supported on this platform")]
+  PtyNotSupported,
+  #[cfg(unix)]
+  #[class("NotSupported")]
+  #[error("Placing subprocesses in a cgroup is only supported on Linux")]
+  CgroupNotSupported,
+  #[class("NotSupported")]
+  #[error("Queuing a value with a signal is only supported on Linux")]
+  SignalValueNotSupported,
 }
 
 #[derive(Deserialize)]
@@ -452,18 +473,47 @@ fn create_command(
   #[cfg(not(windows))]
   command.args(args.args);
 
+  // with a new root, the child changes to `cwd` within it instead
+  #[cfg(unix)]
+  if args.chroot.is_none() {
+    command.current_dir(run_env.cwd);
+  }
+  #[cfg(windows)]
   command.current_dir(run_env.cwd);
   command.env_clear();
   command.envs(run_env.envs.into_iter().map(|(k, v)| (k.into_inner(), v)));
 
   #[cfg(unix)]
-  if let Some(gid) = args.gid {
-    command.gid(gid);
-  }
-  #[cfg(unix)]
-  if let Some(uid) = args.uid {
-    command.uid(uid);
-  }
+  let credentials = if args.groups.is_some()
+    || args.chroot.is_some()
+    || args.cgroup.is_some()
+  {
+    for (option, given) in [
+      ("groups", args.groups.is_some()),
+      ("chroot", args.chroot.is_some()),
+      ("cgroup", args.cgroup.is_some()),
+    ] {
+      if given {
+        check_privileged_option(state, option, api_name)?;
+      }
+    }
+    Some(credentials::ChildCredentials::new(
+      args.uid,
+      args.gid,
+      args.groups.take(),
+      args.chroot.as_deref(),
+      args.cwd.as_deref(),
+      args.cgroup.as_deref(),
+    )?)
+  } else {
+    if let Some(gid) = args.gid {
+      command.gid(gid);
+    }
+    if let Some(uid) = args.uid {
+      command.uid(uid);
+    }
+    None
+  };
 
   if args.stdio.stdin.is_ipc() {
     args.ipc = Some(0);
@@ -554,8 +604,16 @@ fn create_command(
       }
     }
 
+    // Without a `pre_exec` hook the standard library spawns with
+    // `posix_spawn`, which avoids copying the page tables of the runtime, so
+    // the hook is only installed when the child needs it. Note that detached,
+    // extra fd and credential spawns need it, so they still fork and exec.
     let detached = args.detached;
-    if detached || !fds_to_dup.is_empty() || args.gid.is_some() {
+    if detached
+      || !fds_to_dup.is_empty()
+      || args.gid.is_some()
+      || credentials.is_some()
+    {
       command.pre_exec(move || {
         if detached {
           libc::setsid();
@@ -566,7 +624,12 @@ fn create_command(
             libc::close(src);
           }
         }
-        libc::setgroups(0, std::ptr::null());
+        match &credentials {
+          Some(credentials) => credentials.apply()?,
+          None => {
+            libc::setgroups(0, std::ptr::null());
+          }
+        }
         Ok(())
       });
     }
@@ -662,6 +725,7 @@ struct Child {
   stderr_rid: Option<ResourceId>,
   ipc_pipe_rid: Option<ResourceId>,
   extra_pipe_rids: Vec<Option<ResourceId>>,
+  pty_rid: Option<ResourceId>,
 }
 
 fn spawn_child(
@@ -786,6 +850,7 @@ fn spawn_child(
     stderr_rid,
     ipc_pipe_rid,
     extra_pipe_rids,
+    pty_rid: None,
   })
 }
 
@@ -980,6 +1045,26 @@ fn check_run_permission(
   Ok(())
 }
 
+/// The privileged options of `Deno.Command` require the permission to run
+/// any program, like the environment variables of the dynamic loader.
+#[cfg(unix)]
+fn check_privileged_option(
+  state: &mut OpState,
+  option: &str,
+  api_name: &str,
+) -> Result<(), ProcessError> {
+  let permissions = state.borrow_mut::<PermissionsContainer>();
+  if !permissions.query_run_all(api_name) {
+    return Err(ProcessError::Other(JsErrorBox::new(
+      "NotCapable",
+      format!(
+        "Requires --allow-run permissions to spawn subprocess with the '{option}' option."
+      ),
+    )));
+  }
+  Ok(())
+}
+
 fn get_requires_allow_all_env_vars(env: &RunEnv) -> Vec<&str> {
   fn requires_allow_all(key: &str) -> bool {
     fn starts_with_ignore_case(key: &str, search_value: &str) -> bool {
@@ -1021,19 +1106,74 @@ fn get_requires_allow_all_env_vars(env: &RunEnv) -> Vec<&str> {
 #[serde]
 fn op_spawn_child(
   state: &mut OpState,
-  #[serde] args: SpawnArgs,
+  #[serde] mut args: SpawnArgs,
   #[string] api_name: String,
 ) -> Result<Child, ProcessError> {
   let detached = args.detached;
-  let (command, pipe_rid, extra_pipe_rids, handles_to_close) =
+  let pty_size = args.pty.take();
+  let (mut command, pipe_rid, extra_pipe_rids, handles_to_close) =
     create_command(state, args, &api_name)?;
-  let child = spawn_child(state, command, pipe_rid, extra_pipe_rids, detached);
+  let pty_rid = pty_size
+    .map(|size| attach_pty(state, &mut command, size))
+    .transpose();
+  let child = pty_rid.and_then(|pty_rid| {
+    let child =
+      spawn_child(state, command, pipe_rid, extra_pipe_rids, detached);
+    if child.is_err()
+      && let Some(rid) = pty_rid
+      && let Ok(resource) = state.resource_table.take_any(rid)
+    {
+      resource.close();
+    }
+    child.map(|child| Child { pty_rid, ..child })
+  });
   for handle in handles_to_close {
     deno_io::close_raw_handle(handle);
   }
   child
 }
 
+#[cfg(unix)]
+fn attach_pty(
+  state: &mut OpState,
+  command: &mut Command,
+  size: pty::PtySize,
+) -> Result<ResourceId, ProcessError> {
+  let pty = pty::attach(command, size)?;
+  Ok(state.resource_table.add(pty))
+}
+
+#[cfg(windows)]
+fn attach_pty(
+  _state: &mut OpState,
+  _command: &mut Command,
+  _size: pty::PtySize,
+) -> Result<ResourceId, ProcessError> {
+  Err(ProcessError::PtyNotSupported)
+}
+
+#[op2]
+fn op_spawn_pty_resize(
+  state: &mut OpState,
+  #[smi] rid: ResourceId,
+  #[serde] size: pty::PtySize,
+) -> Result<(), ProcessError> {
+  #[cfg(unix)]
+  {
+    let resource = state
+      .resource_table
+      .get::<pty::PtyResource>(rid)
+      .map_err(ProcessError::Resource)?;
+    pty::resize(&resource, size)?;
+    Ok(())
+  }
+  #[cfg(windows)]
+  {
+    let _ = (state, rid, size);
+    Err(ProcessError::PtyNotSupported)
+  }
+}
+
 #[op2(async)]
 #[allow(clippy::await_holding_refcell_ref)]
 #[serde]
@@ -1118,7 +1258,7 @@ fn op_spawn_kill(
   #[serde] signal: SignalArg,
 ) -> Result<(), ProcessError> {
   if let Ok(child_resource) = state.resource_table.get::<ChildResource>(rid) {
-    deprecated::kill(child_resource.1 as i32, &signal)?;
+    deprecated::kill(child_resource.1 as i32, &signal, None)?;
     return Ok(());
   }
   Err(ProcessError::ChildProcessAlreadyTerminated)
@@ -1326,32 +1466,49 @@ mod deprecated {
   }
 
   #[cfg(unix)]
-  pub fn kill(pid: i32, signal: &SignalArg) -> Result<(), ProcessError> {
+  pub fn kill(
+    pid: i32,
+    signal: &SignalArg,
+    value: Option<i64>,
+  ) -> Result<(), ProcessError> {
     let signo = match signal {
       SignalArg::Int(n) => *n,
       SignalArg::String(s) => deno_signals::signal_str_to_int(s)
         .map_err(SignalError::InvalidSignalStr)?,
     };
-    use nix::sys::signal::Signal;
-    use nix::sys::signal::kill as unix_kill;
-    use nix::unistd::Pid;
 
-    // Signal 0 is special, it checks if the process exists without sending a signal
-    let sig = if signo == 0 {
-      None
-    } else {
-      Some(
-        Signal::try_from(signo)
-          .map_err(|e| ProcessError::Nix(JsNixError(e)))?,
-      )
+    // Signal 0 is special, it checks if the process exists without sending a
+    // signal. Real-time signals aren't part of nix's `Signal`, so this calls
+    // libc directly.
+    let result = match value {
+      // SAFETY: libc call
+      None => unsafe { libc::kill(pid, signo) },
+      #[cfg(any(target_os = "android", target_os = "linux"))]
+      // SAFETY: libc call, the value is passed as is rather than dereferenced
+      Some(value) => unsafe {
+        libc::sigqueue(
+          pid,
+          signo,
+          libc::sigval {
+            sival_ptr: value as isize as *mut libc::c_void,
+          },
+        )
+      },
+      #[cfg(not(any(target_os = "android", target_os = "linux")))]
+      Some(_) => return Err(ProcessError::SignalValueNotSupported),
     };
-
-    unix_kill(Pid::from_raw(pid), sig)
-      .map_err(|e| ProcessError::Nix(JsNixError(e)))
+    if result != 0 {
+      return Err(ProcessError::Nix(JsNixError(nix::Error::last())));
+    }
+    Ok(())
   }
 
   #[cfg(not(unix))]
-  pub fn kill(pid: i32, signal: &SignalArg) -> Result<(), ProcessError> {
+  pub fn kill(
+    pid: i32,
+    signal: &SignalArg,
+    value: Option<i64>,
+  ) -> Result<(), ProcessError> {
     use std::io::Error;
     use std::io::ErrorKind::NotFound;
 
@@ -1365,6 +1522,10 @@ mod deprecated {
     use winapi::um::processthreadsapi::TerminateProcess;
     use winapi::um::winnt::PROCESS_TERMINATE;
 
+    if value.is_some() {
+      return Err(ProcessError::SignalValueNotSupported);
+    }
+
     let signal_str = match signal {
       SignalArg::Int(n) => n.to_string(),
       SignalArg::String(s) => s.clone(),
@@ -1411,11 +1572,12 @@ mod deprecated {
     state: &mut OpState,
     #[smi] pid: i32,
     #[serde] signal: SignalArg,
+    #[serde] value: Option<i64>,
     #[string] api_name: String,
   ) -> Result<(), ProcessError> {
     state
       .borrow_mut::<PermissionsContainer>()
       .check_run_all(&api_name)?;
-    kill(pid, &signal)
+    kill(pid, &signal, value)
   }
 }
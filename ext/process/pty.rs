@@ -0,0 +1,219 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Pseudo-terminals for `Deno.Command` with the `pty` option. The child gets
+//! the terminal side as its stdin, stdout and stderr and as its controlling
+//! terminal, while the parent reads and writes the other side through a
+//! resource. Only supported on Unix.
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(windows, allow(dead_code))]
+pub struct PtySize {
+  rows: u16,
+  cols: u16,
+}
+
+#[cfg(unix)]
+pub use unix::PtyResource;
+#[cfg(unix)]
+pub use unix::attach;
+#[cfg(unix)]
+pub use unix::resize;
+
+#[cfg(unix)]
+mod unix {
+  use std::borrow::Cow;
+  use std::io;
+  use std::os::fd::AsRawFd;
+  use std::os::fd::FromRawFd;
+  use std::os::fd::OwnedFd;
+  use std::os::fd::RawFd;
+  use std::os::unix::process::CommandExt;
+  use std::process::Command;
+  use std::process::Stdio;
+  use std::rc::Rc;
+
+  use deno_core::AsyncRefCell;
+  use deno_core::CancelHandle;
+  use deno_core::CancelTryFuture;
+  use deno_core::RcRef;
+  use deno_core::Resource;
+  use tokio::io::unix::AsyncFd;
+
+  use super::PtySize;
+
+  /// The parent side of a pseudo-terminal.
+  pub struct PtyResource {
+    fd: AsyncFd<OwnedFd>,
+    /// Reads and writes each take turns, like for other stream resources.
+    read_lock: AsyncRefCell<()>,
+    write_lock: AsyncRefCell<()>,
+    cancel: CancelHandle,
+  }
+
+  impl Resource for PtyResource {
+    fn name(&self) -> Cow<'_, str> {
+      "pty".into()
+    }
+
+    fn close(self: Rc<Self>) {
+      self.cancel.cancel();
+    }
+
+    fn backing_handle(self: Rc<Self>) -> Option<deno_core::ResourceHandle> {
+      Some(deno_core::ResourceHandle::from_fd_like(self.fd.get_ref()))
+    }
+
+    deno_core::impl_readable_byob!();
+    deno_core::impl_writable!();
+  }
+
+  fn winsize(size: PtySize) -> libc::winsize {
+    libc::winsize {
+      ws_row: size.rows,
+      ws_col: size.cols,
+      ws_xpixel: 0,
+      ws_ypixel: 0,
+    }
+  }
+
+  fn cvt(result: libc::ssize_t) -> io::Result<usize> {
+    if result < 0 {
+      Err(io::Error::last_os_error())
+    } else {
+      Ok(result as usize)
+    }
+  }
+
+  impl PtyResource {
+    fn new(master: OwnedFd) -> io::Result<Self> {
+      // SAFETY: the fd is open
+      unsafe {
+        let flags = libc::fcntl(master.as_raw_fd(), libc::F_GETFL);
+        if flags < 0
+          || libc::fcntl(
+            master.as_raw_fd(),
+            libc::F_SETFL,
+            flags | libc::O_NONBLOCK,
+          ) < 0
+        {
+          return Err(io::Error::last_os_error());
+        }
+      }
+      Ok(Self {
+        fd: AsyncFd::new(master)?,
+        read_lock: AsyncRefCell::new(()),
+        write_lock: AsyncRefCell::new(()),
+        cancel: Default::default(),
+      })
+    }
+
+    pub async fn read(self: Rc<Self>, data: &mut [u8]) -> io::Result<usize> {
+      let _lock = RcRef::map(&self, |r| &r.read_lock).borrow_mut().await;
+      let cancel_handle = RcRef::map(&self, |r| &r.cancel);
+      async {
+        loop {
+          let mut guard = self.fd.readable().await?;
+          let result = guard.try_io(|fd| {
+            // SAFETY: `data` is valid for writes of its length
+            let read = cvt(unsafe {
+              libc::read(fd.as_raw_fd(), data.as_mut_ptr() as _, data.len())
+            });
+            match read {
+              // Linux reports EIO once the child closed the terminal
+              Err(err) if err.raw_os_error() == Some(libc::EIO) => Ok(0),
+              read => read,
+            }
+          });
+          if let Ok(result) = result {
+            return result;
+          }
+        }
+      }
+      .try_or_cancel(cancel_handle)
+      .await
+    }
+
+    pub async fn write(self: Rc<Self>, data: &[u8]) -> io::Result<usize> {
+      let _lock = RcRef::map(&self, |r| &r.write_lock).borrow_mut().await;
+      loop {
+        let mut guard = self.fd.writable().await?;
+        let result = guard.try_io(|fd| {
+          // SAFETY: `data` is valid for reads of its length
+          cvt(unsafe {
+            libc::write(fd.as_raw_fd(), data.as_ptr() as _, data.len())
+          })
+        });
+        if let Ok(result) = result {
+          return result;
+        }
+      }
+    }
+
+    fn raw_fd(&self) -> RawFd {
+      self.fd.get_ref().as_raw_fd()
+    }
+  }
+
+  /// Opens a pseudo-terminal of the given size and attaches the child that
+  /// `command` spawns to it.
+  pub fn attach(
+    command: &mut Command,
+    size: PtySize,
+  ) -> io::Result<PtyResource> {
+    let mut master: libc::c_int = -1;
+    let mut slave: libc::c_int = -1;
+    let mut winsize = winsize(size);
+    // SAFETY: all pointers outlive the call, a null name and termios are
+    // allowed
+    let result = unsafe {
+      libc::openpty(
+        &mut master,
+        &mut slave,
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+        &mut winsize,
+      )
+    };
+    if result != 0 {
+      return Err(io::Error::last_os_error());
+    }
+    // SAFETY: openpty returned two new fds which nothing else owns
+    let (master, slave) =
+      unsafe { (OwnedFd::from_raw_fd(master), OwnedFd::from_raw_fd(slave)) };
+    // SAFETY: the fd is open
+    unsafe {
+      libc::fcntl(master.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC);
+    }
+
+    command.stdin(Stdio::from(slave.try_clone()?));
+    command.stdout(Stdio::from(slave.try_clone()?));
+    command.stderr(Stdio::from(slave));
+    // SAFETY: only async-signal-safe calls are made in the child
+    unsafe {
+      command.pre_exec(|| {
+        // `detached` may have made the child a session leader already
+        if libc::getsid(0) != libc::getpid() && libc::setsid() < 0 {
+          return Err(io::Error::last_os_error());
+        }
+        if libc::ioctl(0, libc::TIOCSCTTY as _, 0) < 0 {
+          return Err(io::Error::last_os_error());
+        }
+        Ok(())
+      });
+    }
+
+    PtyResource::new(master)
+  }
+
+  pub fn resize(pty: &PtyResource, size: PtySize) -> io::Result<()> {
+    let winsize = winsize(size);
+    // SAFETY: `winsize` outlives the call and the fd is open
+    if unsafe { libc::ioctl(pty.raw_fd(), libc::TIOCSWINSZ, &winsize) } < 0 {
+      return Err(io::Error::last_os_error());
+    }
+    Ok(())
+  }
+}
@@ -58,19 +58,65 @@ macro_rules! signal_dict {
     pub fn signal_str_to_int(s: &str) -> Result<libc::c_int, InvalidSignalStrError> {
       match s {
         $($($name)|* => Ok($number),)*
-        _ => Err(InvalidSignalStrError(s.to_string())),
+        _ => realtime_signal_str_to_int(s)
+          .ok_or_else(|| InvalidSignalStrError(s.to_string())),
       }
     }
 
-    pub fn signal_int_to_str(s: libc::c_int) -> Result<&'static str, InvalidSignalIntError> {
+    pub fn signal_int_to_str(s: libc::c_int) -> Result<std::borrow::Cow<'static, str>, InvalidSignalIntError> {
       match s {
-        $($number => Ok(first_literal!($($name),+)),)*
-        _ => Err(InvalidSignalIntError(s)),
+        $($number => Ok(first_literal!($($name),+).into()),)*
+        _ => realtime_signal_int_to_str(s)
+          .map(Into::into)
+          .ok_or(InvalidSignalIntError(s)),
       }
     }
   }
 }
 
+/// Real-time signals are named relative to `SIGRTMIN` and `SIGRTMAX`, like
+/// `SIGRTMIN+3`, as the C library reserves some of them for itself.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn realtime_signal_str_to_int(s: &str) -> Option<libc::c_int> {
+  let (min, max) = (libc::SIGRTMIN(), libc::SIGRTMAX());
+  let signo = match s {
+    "SIGRTMIN" => min,
+    "SIGRTMAX" => max,
+    _ => {
+      if let Some(n) = s.strip_prefix("SIGRTMIN+") {
+        min.checked_add(n.parse().ok()?)?
+      } else if let Some(n) = s.strip_prefix("SIGRTMAX-") {
+        max.checked_sub(n.parse().ok()?)?
+      } else {
+        return None;
+      }
+    }
+  };
+  (min..=max).contains(&signo).then_some(signo)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn realtime_signal_int_to_str(signo: libc::c_int) -> Option<String> {
+  let min = libc::SIGRTMIN();
+  if !(min..=libc::SIGRTMAX()).contains(&signo) {
+    return None;
+  }
+  Some(match signo - min {
+    0 => "SIGRTMIN".to_string(),
+    n => format!("SIGRTMIN+{n}"),
+  })
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+fn realtime_signal_str_to_int(_s: &str) -> Option<libc::c_int> {
+  None
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+fn realtime_signal_int_to_str(_signo: libc::c_int) -> Option<String> {
+  None
+}
+
 #[cfg(target_os = "freebsd")]
 signal_dict!(
   (1, "SIGHUP"),
@@ -259,3 +305,21 @@ signal_dict!(
 
 #[cfg(target_os = "windows")]
 signal_dict!((1, "SIGHUP"), (2, "SIGINT"), (21, "SIGBREAK"));
+
+#[cfg(all(test, any(target_os = "android", target_os = "linux")))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn realtime_signals() {
+    let min = libc::SIGRTMIN();
+    let max = libc::SIGRTMAX();
+    assert_eq!(signal_str_to_int("SIGRTMIN").unwrap(), min);
+    assert_eq!(signal_str_to_int("SIGRTMIN+2").unwrap(), min + 2);
+    assert_eq!(signal_str_to_int("SIGRTMAX-1").unwrap(), max - 1);
+    assert!(signal_str_to_int("SIGRTMIN+100").is_err());
+    assert!(signal_str_to_int("SIGRTMIN+x").is_err());
+    assert_eq!(signal_int_to_str(min + 2).unwrap(), "SIGRTMIN+2");
+    assert_eq!(signal_int_to_str(15).unwrap(), "SIGTERM");
+  }
+}
@@ -17,8 +17,52 @@ static SIGHUP: i32 = 1;
 
 static COUNTER: AtomicU32 = AtomicU32::new(0);
 
+/// Details of a delivered signal, where the platform provides them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SignalInfo {
+  /// The process that sent the signal, if it was sent by a process.
+  pub pid: Option<i32>,
+  /// The real user ID of the process that sent the signal.
+  pub uid: Option<u32>,
+  /// The `si_code` of the signal, which tells how it was sent.
+  pub code: Option<i32>,
+  /// The value queued with the signal with `sigqueue`.
+  pub value: Option<i64>,
+}
+
+#[cfg(unix)]
+impl SignalInfo {
+  fn from_siginfo(info: &libc::siginfo_t) -> Self {
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+      const SI_USER: i32 = 0;
+      const SI_QUEUE: i32 = -1;
+      const SI_TKILL: i32 = -6;
+      let code = info.si_code;
+      let sent = matches!(code, SI_USER | SI_QUEUE | SI_TKILL);
+      // SAFETY: the sender fields are set for signals sent by a process, and
+      // the value for signals queued with sigqueue
+      unsafe {
+        SignalInfo {
+          pid: sent.then(|| info.si_pid()),
+          uid: sent.then(|| info.si_uid()),
+          code: Some(code),
+          value: (code == SI_QUEUE)
+            .then(|| info.si_value().sival_ptr as isize as i64),
+        }
+      }
+    }
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    SignalInfo {
+      code: Some(info.si_code),
+      ..Default::default()
+    }
+  }
+}
+
 type Handler = Box<dyn Fn() + Send>;
-type Handlers = HashMap<i32, Vec<(u32, bool, Handler)>>;
+type InfoHandler = Box<dyn Fn(&SignalInfo) + Send>;
+type Handlers = HashMap<i32, Vec<(u32, bool, InfoHandler)>>;
 static HANDLERS: OnceLock<(Handle, Mutex<Handlers>)> = OnceLock::new();
 
 #[cfg(unix)]
@@ -27,7 +71,7 @@ struct Handle(signal_hook::iterator::Handle);
 #[cfg(windows)]
 struct Handle;
 
-fn handle_signal(signal: i32) -> bool {
+fn handle_signal(signal: i32, info: &SignalInfo) -> bool {
   let Some((_, handlers)) = HANDLERS.get() else {
     return false;
   };
@@ -43,7 +87,7 @@ fn handle_signal(signal: i32) -> bool {
       handled = true;
     }
 
-    f();
+    f(info);
   }
 
   handled
@@ -51,14 +95,19 @@ fn handle_signal(signal: i32) -> bool {
 
 #[cfg(unix)]
 fn init() -> Handle {
-  use signal_hook::iterator::Signals;
+  use signal_hook::iterator::SignalsInfo;
+  use signal_hook::iterator::exfiltrator::raw::WithRawSiginfo;
 
-  let mut signals = Signals::new([SIGHUP, SIGTERM, SIGINT]).unwrap();
+  let mut signals =
+    SignalsInfo::<WithRawSiginfo>::new([SIGHUP, SIGTERM, SIGINT]).unwrap();
   let handle = signals.handle();
 
   std::thread::spawn(move || {
-    for signal in signals.forever() {
-      let handled = handle_signal(signal);
+    // signals arriving before the previous one was handled are coalesced,
+    // so only the details of the last one are delivered
+    for info in signals.forever() {
+      let signal = info.si_signo;
+      let handled = handle_signal(signal, &SignalInfo::from_siginfo(&info));
       if !handled {
         if signal == SIGHUP || signal == SIGTERM || signal == SIGINT {
           run_exit();
@@ -82,7 +131,7 @@ fn init() -> Handle {
       6 => SIGTERM,
       _ => return 0,
     };
-    let handled = handle_signal(signal);
+    let handled = handle_signal(signal, &SignalInfo::default());
     handled as _
   }
 
@@ -98,6 +147,16 @@ pub fn register(
   signal: i32,
   prevent_default: bool,
   f: Box<dyn Fn() + Send>,
+) -> Result<u32, std::io::Error> {
+  register_with_info(signal, prevent_default, Box::new(move |_| f()))
+}
+
+/// Like [`register`], with the details of each delivered signal passed to
+/// the handler.
+pub fn register_with_info(
+  signal: i32,
+  prevent_default: bool,
+  f: InfoHandler,
 ) -> Result<u32, std::io::Error> {
   if is_forbidden(signal) {
     return Err(std::io::Error::other(format!(
@@ -101,6 +101,7 @@ deno_core::extension!(
     op_otel_enable_isolate_metrics,
     op_otel_log,
     op_otel_log_foreign,
+    op_otel_sample_io_span,
     op_otel_span_attribute1,
     op_otel_span_attribute2,
     op_otel_span_attribute3,
@@ -135,6 +136,8 @@ pub struct OtelConfig {
   pub console: OtelConsoleConfig,
   pub deterministic_prefix: Option<u8>,
   pub propagators: std::collections::HashSet<OtelPropagators>,
+  #[serde(default)]
+  pub io_spans: OtelIoSpansConfig,
 }
 
 impl OtelConfig {
@@ -173,6 +176,60 @@ pub enum OtelConsoleConfig {
   Replace = 2,
 }
 
+/// Spans for individual file system and network ops, which are only started
+/// within another span so that they show up in the traces of server
+/// handlers and the like.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OtelIoSpansConfig {
+  pub fs: bool,
+  pub net: bool,
+  /// The fraction of ops that get a span, from 0 to 1.
+  pub sample_ratio: f64,
+  /// At most this many op spans are started per second, on top of sampling.
+  pub max_per_second: u32,
+}
+
+impl Default for OtelIoSpansConfig {
+  fn default() -> Self {
+    Self {
+      fs: false,
+      net: false,
+      sample_ratio: 1.0,
+      max_per_second: 100,
+    }
+  }
+}
+
+const IO_SPAN_KIND_FS: u8 = 0;
+const IO_SPAN_KIND_NET: u8 = 1;
+
+#[derive(Debug)]
+struct IoSpanBudget {
+  window_start: Instant,
+  started: u32,
+}
+
+impl IoSpanBudget {
+  fn take(&mut self, now: Instant, max_per_second: u32) -> bool {
+    if now.duration_since(self.window_start) >= Duration::from_secs(1) {
+      self.window_start = now;
+      self.started = 0;
+    }
+    if self.started >= max_per_second {
+      return false;
+    }
+    self.started += 1;
+    true
+  }
+}
+
+static IO_SPAN_BUDGET: Lazy<Mutex<IoSpanBudget>> = Lazy::new(|| {
+  Mutex::new(IoSpanBudget {
+    window_start: Instant::now(),
+    started: 0,
+  })
+});
+
 static OTEL_SHARED_RUNTIME_SPAWN_TASK_TX: Lazy<
   UnboundedSender<BoxFuture<'static, ()>>,
 > = Lazy::new(otel_create_shared_runtime);
@@ -1398,6 +1455,40 @@ fn owned_string<'s>(
   }
 }
 
+/// Whether an op of the given kind should get a span, as decided by the
+/// `OTEL_DENO_IO_SPANS*` configuration.
+#[op2(fast)]
+fn op_otel_sample_io_span(#[smi] kind: u8) -> bool {
+  let Some(OtelGlobals {
+    id_generator,
+    config,
+    ..
+  }) = OTEL_GLOBALS.get()
+  else {
+    return false;
+  };
+  let io_spans = &config.io_spans;
+  let enabled = match kind {
+    IO_SPAN_KIND_FS => io_spans.fs,
+    IO_SPAN_KIND_NET => io_spans.net,
+    _ => false,
+  };
+  if !enabled || !config.tracing_enabled {
+    return false;
+  }
+  if io_spans.sample_ratio < 1.0 {
+    // span ids are random, like the trace ids ratio based samplers look at
+    let sample = u64::from_be_bytes(id_generator.new_span_id().to_bytes());
+    if (sample >> 11) as f64 / (1u64 << 53) as f64 >= io_spans.sample_ratio {
+      return false;
+    }
+  }
+  IO_SPAN_BUDGET
+    .lock()
+    .unwrap()
+    .take(Instant::now(), io_spans.max_per_second)
+}
+
 struct OtelTracer(InstrumentationScope);
 
 // SAFETY: we're sure this can be GCed
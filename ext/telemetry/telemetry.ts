@@ -17,6 +17,7 @@ import {
   op_otel_metric_record2,
   op_otel_metric_record3,
   op_otel_metric_wait_to_observe,
+  op_otel_sample_io_span,
   op_otel_span_add_link,
   op_otel_span_attribute1,
   op_otel_span_attribute2,
@@ -43,6 +44,7 @@ const {
   ArrayPrototypeSlice,
   DatePrototype,
   DatePrototypeGetTime,
+  MathImul,
   decodeURIComponent,
   encodeURIComponent,
   Error,
@@ -57,6 +59,7 @@ const {
   ObjectKeys,
   ObjectPrototypeIsPrototypeOf,
   ObjectValues,
+  PromisePrototypeThen,
   ReflectApply,
   SafeArrayIterator,
   SafeMap,
@@ -65,7 +68,10 @@ const {
   SafeRegExp,
   SafeSet,
   SafeWeakSet,
+  String,
+  StringPrototypeCharCodeAt,
   StringPrototypeIndexOf,
+  StringPrototypePadStart,
   StringPrototypeSlice,
   StringPrototypeSplit,
   StringPrototypeSubstring,
@@ -1804,6 +1810,78 @@ export function builtinTracer(): Tracer {
   return builtinTracerCache;
 }
 
+export const IO_SPAN_FS = 0;
+export const IO_SPAN_NET = 1;
+
+/** FNV-1a, to tell paths apart in traces without exporting them. */
+function hashPath(path: string): string {
+  let hash = 0x811c9dc5;
+  for (let i = 0; i < path.length; i++) {
+    hash ^= StringPrototypeCharCodeAt(path, i);
+    hash = MathImul(hash, 0x01000193);
+  }
+  return StringPrototypePadStart(
+    NumberPrototypeToString(hash >>> 0, 16),
+    8,
+    "0",
+  );
+}
+
+// deno-lint-ignore no-explicit-any
+function endIoSpanWithError(span: Span, error: any) {
+  span.setAttribute("error.type", error?.name ?? "Error");
+  span.setStatus({ code: 2, message: error?.message ?? String(error) });
+  span.end();
+}
+
+/**
+ * Runs a file system or network op in a span of its own. Spans are only
+ * started within another span, for the kinds of ops that are enabled with
+ * `OTEL_DENO_IO_SPANS`, and as sampled and rate limited by
+ * `OTEL_DENO_IO_SPANS_SAMPLE_RATIO` and `OTEL_DENO_IO_SPANS_MAX_PER_SECOND`.
+ */
+export function traceIo<T>(
+  kind: typeof IO_SPAN_FS | typeof IO_SPAN_NET,
+  name: string,
+  backend: string,
+  path: string | undefined,
+  fn: () => Promise<T>,
+  bytesOf?: (result: T) => number | null | undefined,
+): Promise<T> {
+  if (
+    !TRACING_ENABLED ||
+    CURRENT.get()?.getValue(SPAN_KEY) === undefined ||
+    !op_otel_sample_io_span(kind)
+  ) {
+    return fn();
+  }
+  const span = builtinTracer().startSpan(name, {
+    kind: kind === IO_SPAN_NET ? 2 : 0,
+  });
+  span.setAttribute("deno.io.backend", backend);
+  if (path !== undefined) {
+    span.setAttribute("deno.io.path_hash", hashPath(path));
+  }
+  let promise;
+  try {
+    promise = fn();
+  } catch (error) {
+    endIoSpanWithError(span, error);
+    throw error;
+  }
+  return PromisePrototypeThen(promise, (result) => {
+    const bytes = bytesOf?.(result);
+    if (typeof bytes === "number") {
+      span.setAttribute("deno.io.bytes", bytes);
+    }
+    span.end();
+    return result;
+  }, (error) => {
+    endIoSpanWithError(span, error);
+    throw error;
+  });
+}
+
 function enableIsolateMetrics() {
   op_otel_enable_isolate_metrics();
   ISOLATE_METRICS = true;
@@ -4,12 +4,15 @@ use std::io::BufReader;
 use std::io::Cursor;
 use std::net::IpAddr;
 use std::sync::Arc;
+use std::sync::OnceLock;
 
 use deno_error::JsErrorBox;
 pub use deno_native_certs;
 pub use rustls;
 use rustls::ClientConfig;
 use rustls::DigitallySignedStruct;
+use rustls::KeyLog;
+use rustls::KeyLogFile;
 use rustls::RootCertStore;
 use rustls::client::WebPkiServerVerifier;
 use rustls::client::danger::HandshakeSignatureValid;
@@ -61,6 +64,21 @@ pub trait RootCertStoreProvider: Send + Sync {
   fn get_or_try_init(&self) -> Result<&RootCertStore, JsErrorBox>;
 }
 
+static KEY_LOG: OnceLock<Arc<dyn KeyLog>> = OnceLock::new();
+
+/// Makes every TLS client and server config created from now on log its
+/// session secrets to the file named by the `SSLKEYLOGFILE` environment
+/// variable, in the NSS key log format understood by Wireshark. Nothing is
+/// logged when the variable is not set.
+pub fn enable_ssl_key_log() {
+  _ = KEY_LOG.set(Arc::new(KeyLogFile::new()));
+}
+
+/// The key log enabled with [`enable_ssl_key_log`], if any.
+pub fn ssl_key_log() -> Option<Arc<dyn KeyLog>> {
+  KEY_LOG.get().cloned()
+}
+
 // This extension has no runtime apis, it only exports some shared native functions.
 deno_core::extension!(deno_tls);
 
@@ -314,6 +332,9 @@ pub fn create_client_config(
     };
 
     add_alpn(&mut client, socket_use);
+    if let Some(key_log) = ssl_key_log() {
+      client.key_log = key_log;
+    }
     return Ok(client);
   }
 
@@ -347,6 +368,9 @@ pub fn create_client_config(
   };
 
   add_alpn(&mut client, socket_use);
+  if let Some(key_log) = ssl_key_log() {
+    client.key_log = key_log;
+  }
 
   if unsafely_disable_hostname_verification {
     let inner =
@@ -119,7 +119,7 @@ struct TlsKeyResolverInner {
     String,
     broadcast::Sender<Result<TlsKey, ErrorType>>,
   )>,
-  cache: RefCell<HashMap<String, TlsKeyState>>,
+  cache: Rc<RefCell<HashMap<String, TlsKeyState>>>,
 }
 
 #[derive(Clone)]
@@ -139,6 +139,9 @@ impl TlsKeyResolver {
       .with_no_client_auth()
       .with_single_cert(key.0, key.1.clone_key())?;
     tls_config.alpn_protocols = alpn;
+    if let Some(key_log) = crate::ssl_key_log() {
+      tls_config.key_log = key_log;
+    }
     Ok(tls_config.into())
   }
 
@@ -181,16 +184,18 @@ impl Debug for TlsKeyResolver {
 
 pub fn new_resolver() -> (TlsKeyResolver, TlsKeyLookup) {
   let (resolution_tx, resolution_rx) = mpsc::unbounded_channel();
+  let cache = Rc::new(RefCell::new(HashMap::new()));
   (
     TlsKeyResolver {
       inner: Rc::new(TlsKeyResolverInner {
         resolution_tx,
-        cache: Default::default(),
+        cache: cache.clone(),
       }),
     },
     TlsKeyLookup {
       resolution_rx: RefCell::new(resolution_rx),
       pending: Default::default(),
+      cache,
     },
   )
 }
@@ -246,6 +251,7 @@ pub struct TlsKeyLookup {
   >,
   pending:
     RefCell<HashMap<String, broadcast::Sender<Result<TlsKey, ErrorType>>>>,
+  cache: Rc<RefCell<HashMap<String, TlsKeyState>>>,
 }
 
 // SAFETY: we're sure `TlsKeyLookup` can be GCed
@@ -279,6 +285,15 @@ impl TlsKeyLookup {
       .unwrap()
       .send(res.map_err(|e| Arc::new(e.into_boxed_str())));
   }
+
+  /// Forget all resolved certificates so the next handshake for each server
+  /// name is resolved again. Resolutions in flight are not affected.
+  pub fn clear_cache(&self) {
+    self
+      .cache
+      .borrow_mut()
+      .retain(|_, state| matches!(state, TlsKeyState::Resolving(..)));
+  }
 }
 
 #[cfg(test)]
@@ -3,7 +3,15 @@
 import { core, primordials } from "ext:core/mod.js";
 import { op_defer } from "ext:core/ops";
 const {
+  Error,
+  MapPrototypeDelete,
+  MapPrototypeSet,
+  MathMax,
+  NumberIsFinite,
+  Promise,
   PromisePrototypeThen,
+  SafeMap,
+  SafeMapIterator,
   TypeError,
   indirectEval,
   ReflectApply,
@@ -45,6 +53,9 @@ function setTimeout(callback, timeout = 0, ...args) {
     }
   };
   timeout = webidl.converters.long(timeout);
+  if (virtualClock !== null) {
+    return virtualClock.schedule(false, timeout, callback);
+  }
   return core.queueUserTimer(
     core.getTimerDepth() + 1,
     false,
@@ -74,6 +85,9 @@ function setInterval(callback, timeout = 0, ...args) {
     }
   };
   timeout = webidl.converters.long(timeout);
+  if (virtualClock !== null) {
+    return virtualClock.schedule(true, timeout, callback);
+  }
   return core.queueUserTimer(
     core.getTimerDepth() + 1,
     true,
@@ -88,7 +102,9 @@ function setInterval(callback, timeout = 0, ...args) {
 function clearTimeout(id = 0) {
   checkThis(this);
   id = webidl.converters.long(id);
-  core.cancelTimer(id);
+  if (virtualClock === null || !virtualClock.cancel(id)) {
+    core.cancelTimer(id);
+  }
 }
 
 /**
@@ -97,7 +113,9 @@ function clearTimeout(id = 0) {
 function clearInterval(id = 0) {
   checkThis(this);
   id = webidl.converters.long(id);
-  core.cancelTimer(id);
+  if (virtualClock === null || !virtualClock.cancel(id)) {
+    core.cancelTimer(id);
+  }
 }
 
 /**
@@ -121,12 +139,189 @@ function defer(go) {
   PromisePrototypeThen(op_defer(), () => go());
 }
 
+// ---------------------------------------------------------------------------
+// Virtual time, used by `deno test` for tests with `virtualTime` set. While a
+// clock is installed, new timers and `performance.now()` are driven by it
+// instead of the real time, and time only moves forward when the clock is
+// advanced. `Date` keeps using the real time.
+
+// Far above the ids of real timers, so that both can be cleared while a
+// clock is installed.
+const VIRTUAL_TIMER_ID_START = 0x40000000;
+// An interval that keeps rescheduling itself would make `runAll()` loop
+// forever.
+const MAX_RUN_ALL_TIMERS = 10_000;
+
+/** @type {VirtualClock | null} */
+let virtualClock = null;
+// `performance.now()` minus the time of the clock when it was installed
+let virtualMonotonicOffset = 0;
+
+class VirtualClock {
+  #now;
+  #nextId = VIRTUAL_TIMER_ID_START;
+  // insertion order breaks ties between timers that are due at the same time
+  #nextSeq = 0;
+  /**
+   * @type {Map<number, {
+   *   time: number,
+   *   seq: number,
+   *   repeat: boolean,
+   *   timeout: number,
+   *   callback: () => void,
+   * }>}
+   */
+  #timers = new SafeMap();
+
+  constructor(now) {
+    this.#now = now;
+  }
+
+  /** The current virtual time, in milliseconds since the epoch. */
+  get now() {
+    return this.#now;
+  }
+
+  /** The number of timers that have not fired or been cleared yet. */
+  get pending() {
+    return this.#timers.size;
+  }
+
+  schedule(repeat, timeout, callback) {
+    const id = this.#nextId++;
+    timeout = MathMax(timeout, repeat ? 1 : 0);
+    MapPrototypeSet(this.#timers, id, {
+      time: this.#now + timeout,
+      seq: this.#nextSeq++,
+      repeat,
+      timeout,
+      callback,
+    });
+    return id;
+  }
+
+  cancel(id) {
+    return MapPrototypeDelete(this.#timers, id);
+  }
+
+  #nextDue(until) {
+    let next = null;
+    for (const { 0: id, 1: timer } of new SafeMapIterator(this.#timers)) {
+      if (
+        timer.time <= until &&
+        (next === null || timer.time < next.timer.time ||
+          (timer.time === next.timer.time && timer.seq < next.timer.seq))
+      ) {
+        next = { id, timer };
+      }
+    }
+    return next;
+  }
+
+  #fire(next) {
+    const { id, timer } = next;
+    this.#now = MathMax(this.#now, timer.time);
+    if (timer.repeat) {
+      timer.time = this.#now + timer.timeout;
+      timer.seq = this.#nextSeq++;
+    } else {
+      MapPrototypeDelete(this.#timers, id);
+    }
+    timer.callback();
+  }
+
+  /** Moves the time forward, firing the timers that become due in order. */
+  tick(ms) {
+    const until = this.#now + checkTickDuration(ms);
+    let next;
+    while ((next = this.#nextDue(until)) !== null) {
+      this.#fire(next);
+    }
+    this.#now = until;
+  }
+
+  /** Like `tick()`, but lets promises settle after each timer fired. */
+  async tickAsync(ms) {
+    const until = this.#now + checkTickDuration(ms);
+    await settle();
+    let next;
+    while ((next = this.#nextDue(until)) !== null) {
+      this.#fire(next);
+      await settle();
+    }
+    this.#now = until;
+  }
+
+  /** Fires timers until none are left, including ones scheduled by them. */
+  runAll() {
+    for (let i = 0; i < MAX_RUN_ALL_TIMERS; i++) {
+      const next = this.#nextDue(Infinity);
+      if (next === null) {
+        return;
+      }
+      this.#fire(next);
+    }
+    throw new Error(
+      `Aborted after running ${MAX_RUN_ALL_TIMERS} timers, ` +
+        "an interval is probably still scheduled",
+    );
+  }
+}
+
+function checkTickDuration(ms) {
+  if (typeof ms !== "number" || !NumberIsFinite(ms) || ms < 0) {
+    throw new TypeError(
+      `Cannot advance the clock by ${ms}, expected a non-negative number`,
+    );
+  }
+  return ms;
+}
+
+function settle() {
+  return new Promise((resolve) => defer(resolve));
+}
+
+/**
+ * Installs a virtual clock starting at the given time, which drives the
+ * timers created and `performance.now()` until it is uninstalled.
+ * @param {number} now
+ * @param {number} monotonicNow The current `performance.now()`.
+ * @returns {VirtualClock}
+ */
+function installVirtualClock(now, monotonicNow) {
+  if (virtualClock !== null) {
+    throw new Error("A virtual clock is already installed");
+  }
+  virtualClock = new VirtualClock(now);
+  virtualMonotonicOffset = monotonicNow - now;
+  return virtualClock;
+}
+
+/** Uninstalls the virtual clock, dropping the timers that did not fire. */
+function uninstallVirtualClock() {
+  virtualClock = null;
+}
+
+/**
+ * The virtual `performance.now()`, or `null` without a virtual clock.
+ * @returns {number | null}
+ */
+function virtualMonotonicNow() {
+  if (virtualClock === null) {
+    return null;
+  }
+  return virtualClock.now + virtualMonotonicOffset;
+}
+
 export {
   clearInterval,
   clearTimeout,
   defer,
+  installVirtualClock,
   refTimer,
   setInterval,
   setTimeout,
+  uninstallVirtualClock,
   unrefTimer,
+  virtualMonotonicNow,
 };
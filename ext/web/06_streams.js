@@ -946,6 +946,7 @@ const _original = Symbol("[[original]]");
 function readableStreamForRid(rid, autoClose = true, cfn, onError) {
   const stream = cfn ? cfn(_brand) : new ReadableStream(_brand);
   stream[_resourceBacking] = { rid, autoClose };
+  stream[core.hostObjectBrand] = "ReadableStreamResource";
 
   const tryClose = () => {
     if (!autoClose) return;
@@ -1023,6 +1024,7 @@ function readableStreamForRidUnrefable(rid, constructor = ReadableStream) {
   stream[promiseSymbol] = undefined;
   stream[_isUnref] = false;
   stream[_resourceBackingUnrefable] = { rid, autoClose: true };
+  stream[core.hostObjectBrand] = "ReadableStreamResource";
   const underlyingSource = {
     type: "bytes",
     async pull(controller) {
@@ -1173,6 +1175,7 @@ async function readableStreamCollectIntoUint8Array(stream) {
 function writableStreamForRid(rid, autoClose = true, cfn) {
   const stream = cfn ? cfn(_brand) : new WritableStream(_brand);
   stream[_resourceBacking] = { rid, autoClose };
+  stream[core.hostObjectBrand] = "WritableStreamResource";
 
   const tryClose = () => {
     if (!autoClose) return;
@@ -7010,6 +7013,60 @@ core.registerTransferableResource(
   },
 );
 
+// Streams backed by resources are transferred by moving their resource to the
+// receiving worker, so that it owns the file or connection from then on. The
+// stream left behind is locked, as it has nothing to read from or write to.
+
+core.registerTransferableResource(
+  "ReadableStreamResource",
+  (value) => {
+    if (isReadableStreamLocked(value)) {
+      throw new DOMException(
+        "Cannot transfer a locked ReadableStream",
+        "DataCloneError",
+      );
+    }
+    if (isReadableStreamDisturbed(value)) {
+      throw new DOMException(
+        "Cannot transfer a disturbed ReadableStream",
+        "DataCloneError",
+      );
+    }
+    const { rid } = value[_resourceBacking] ??
+      value[_resourceBackingUnrefable];
+    acquireReadableStreamDefaultReader(value);
+    value[_resourceBacking] = null;
+    value[_resourceBackingUnrefable] = null;
+    RESOURCE_REGISTRY.unregister(value);
+    return rid;
+  },
+  (rid) => readableStreamForRid(rid),
+);
+
+core.registerTransferableResource(
+  "WritableStreamResource",
+  (value) => {
+    if (isWritableStreamLocked(value)) {
+      throw new DOMException(
+        "Cannot transfer a locked WritableStream",
+        "DataCloneError",
+      );
+    }
+    if (value[_state] !== "writable") {
+      throw new DOMException(
+        "Cannot transfer a closed or errored WritableStream",
+        "DataCloneError",
+      );
+    }
+    const { rid } = value[_resourceBacking];
+    acquireWritableStreamDefaultWriter(value);
+    value[_resourceBacking] = null;
+    RESOURCE_REGISTRY.unregister(value);
+    return rid;
+  },
+  (rid) => writableStreamForRid(rid),
+);
+
 core.registerTransferableResource(
   "TransformStream",
   (value) => {
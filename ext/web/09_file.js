@@ -363,7 +363,16 @@ class Blob {
    */
   stream() {
     webidl.assertBranded(this, BlobPrototype);
-    const partIterator = toIterator(this[_parts]);
+    const parts = this[_parts];
+    // a whole file on disk is streamed from its resource, which `Response`
+    // sends without copying it through JS
+    if (parts.length === 1 && parts[0].resourceStream) {
+      const stream = parts[0].resourceStream();
+      if (stream !== null) {
+        return stream;
+      }
+    }
+    const partIterator = toIterator(parts);
     const stream = new ReadableStream({
       type: "bytes",
       /** @param {ReadableByteStreamController} controller */
@@ -654,6 +663,29 @@ class BlobReference {
   }
 }
 
+/**
+ * Create a File whose data is read through `part` when it is needed, like a
+ * file on disk, instead of being held in memory. Besides the `size`,
+ * `slice()` and `stream()` of a BlobReference, the part can have a
+ * `resourceStream()` that returns a resource backed stream of all of its
+ * data, or null.
+ *
+ * @param {BlobReference} part
+ * @param {string} name
+ * @param {string} type
+ * @param {number} lastModified
+ * @returns {File}
+ */
+function fileFromPart(part, name, type, lastModified) {
+  const file = webidl.createBranded(File);
+  file[_type] = normalizeType(type);
+  file[_size] = part.size;
+  file[_parts] = [part];
+  file[_Name] = name;
+  file[_LastModified] = lastModified;
+  return file;
+}
+
 /**
  * Construct a new Blob object from an object URL.
  *
@@ -726,7 +758,9 @@ export {
   Blob,
   blobFromObjectUrl,
   BlobPrototype,
+  BlobReference,
   File,
+  fileFromPart,
   FilePrototype,
   getParts,
   isBlob,
@@ -21,6 +21,7 @@ import { structuredClone } from "./02_structured_clone.js";
 import { createFilteredInspectProxy } from "./01_console.js";
 import { EventTarget } from "./02_event.js";
 import { DOMException } from "./01_dom_exception.js";
+import { virtualMonotonicNow } from "./02_timers.js";
 
 const illegalConstructorKey = Symbol("illegalConstructorKey");
 let performanceEntries = [];
@@ -35,6 +36,10 @@ function setTimeOrigin() {
 }
 
 function now() {
+  const virtualNow = virtualMonotonicNow();
+  if (virtualNow !== null) {
+    return virtualNow;
+  }
   op_now(hrU8);
   return hr[0] * 1000 + hr[1] / 1e6;
 }
@@ -616,5 +621,6 @@ export {
   PerformanceEntry,
   PerformanceMark,
   PerformanceMeasure,
+  now,
   setTimeOrigin,
 };
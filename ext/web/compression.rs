@@ -11,6 +11,9 @@ use flate2::write::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::write::ZlibDecoder;
 use flate2::write::ZlibEncoder;
+use zstd::stream::raw::Decoder as ZstdRawDecoder;
+use zstd::stream::write::Encoder as ZstdEncoder;
+use zstd::stream::zio::Writer as ZstdWriter;
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
 pub enum CompressionError {
@@ -28,7 +31,6 @@ pub enum CompressionError {
   Io(std::io::Error),
 }
 
-#[derive(Debug)]
 struct CompressionResource(RefCell<Option<Inner>>);
 
 // SAFETY: we're sure `CompressionResource` can be GCed
@@ -41,7 +43,6 @@ unsafe impl deno_core::GarbageCollected for CompressionResource {
 }
 
 /// https://wicg.github.io/compression/#supported-formats
-#[derive(Debug)]
 enum Inner {
   DeflateDecoder(ZlibDecoder<Vec<u8>>),
   DeflateEncoder(ZlibEncoder<Vec<u8>>),
@@ -49,6 +50,8 @@ enum Inner {
   DeflateRawEncoder(DeflateEncoder<Vec<u8>>),
   GzDecoder(GzDecoder<Vec<u8>>),
   GzEncoder(GzEncoder<Vec<u8>>),
+  ZstdDecoder(ZstdWriter<Vec<u8>, ZstdRawDecoder<'static>>),
+  ZstdEncoder(ZstdEncoder<'static, Vec<u8>>),
 }
 
 #[op2]
@@ -71,6 +74,14 @@ pub fn op_compression_new(
     ("gzip", false) => {
       Inner::GzEncoder(GzEncoder::new(w, Compression::default()))
     }
+    ("zstd", true) => Inner::ZstdDecoder(ZstdWriter::new(
+      w,
+      ZstdRawDecoder::new().map_err(CompressionError::Io)?,
+    )),
+    ("zstd", false) => Inner::ZstdEncoder(
+      // Level 0 is zstd's default level.
+      ZstdEncoder::new(w, 0).map_err(CompressionError::Io)?,
+    ),
     _ => return Err(CompressionError::UnsupportedFormat),
   };
   Ok(CompressionResource(RefCell::new(Some(inner))))
@@ -115,6 +126,16 @@ pub fn op_compression_write(
       d.flush().map_err(CompressionError::Io)?;
       d.get_mut().drain(..)
     }
+    Inner::ZstdDecoder(d) => {
+      d.write_all(input).map_err(CompressionError::IoTypeError)?;
+      d.flush().map_err(CompressionError::Io)?;
+      d.writer_mut().drain(..)
+    }
+    Inner::ZstdEncoder(d) => {
+      d.write_all(input).map_err(CompressionError::IoTypeError)?;
+      d.flush().map_err(CompressionError::Io)?;
+      d.get_mut().drain(..)
+    }
   }
   .collect();
   Ok(out)
@@ -146,6 +167,12 @@ pub fn op_compression_finish(
     }
     Inner::GzDecoder(d) => d.finish().map_err(CompressionError::IoTypeError),
     Inner::GzEncoder(d) => d.finish().map_err(CompressionError::IoTypeError),
+    // Fails if the input ended in the middle of a frame.
+    Inner::ZstdDecoder(mut d) => d
+      .finish()
+      .map(|_| d.into_inner().0)
+      .map_err(CompressionError::IoTypeError),
+    Inner::ZstdEncoder(d) => d.finish().map_err(CompressionError::IoTypeError),
   };
   match out {
     Err(err) => {
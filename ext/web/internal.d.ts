@@ -89,6 +89,12 @@ declare module "ext:deno_web/05_base64.js" {
 declare module "ext:deno_web/09_file.js" {
   function blobFromObjectUrl(url: string): Blob | null;
   function getParts(blob: Blob): string[];
+  function fileFromPart(
+    part: unknown,
+    name: string,
+    type: string,
+    lastModified: number,
+  ): File;
   const Blob: typeof Blob;
   const File: typeof File;
 }
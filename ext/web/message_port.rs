@@ -2,6 +2,7 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::future::poll_fn;
 use std::rc::Rc;
 
@@ -33,6 +34,9 @@ pub enum MessagePortError {
   #[class(type)]
   #[error("Can not transfer self message port")]
   TransferSelf,
+  #[class("DOMExceptionDataCloneError")]
+  #[error("Cannot transfer a stream whose resource is in use")]
+  ResourceInUse,
   #[class(inherit)]
   #[error(transparent)]
   Canceled(#[from] deno_core::Canceled),
@@ -48,6 +52,9 @@ pub enum Transferable {
   Resource(String, Box<dyn TransferredResource>),
   MultiResource(String, Vec<Box<dyn TransferredResource>>),
   ArrayBuffer(u32),
+  /// A resource already transferred at the index, like both streams of a
+  /// connection.
+  SameResource(String, usize),
 }
 
 type MessagePortMessage = (DetachedBuffer, Vec<Transferable>);
@@ -188,10 +195,37 @@ pub fn deserialize_js_transferables(
   state: &mut OpState,
   js_transferables: Vec<JsTransferable>,
 ) -> Result<Vec<Transferable>, MessagePortError> {
+  // checked before any resource is taken out of the table, as a failed
+  // transfer would close it
+  for js_transferable in &js_transferables {
+    if let JsTransferable::Resource(name, rid) = js_transferable
+      && matches!(
+        name.as_str(),
+        "ReadableStreamResource" | "WritableStreamResource"
+      )
+    {
+      let resource = state
+        .resource_table
+        .get_any(*rid)
+        .map_err(|_| MessagePortError::InvalidTransfer)?;
+      // the streams' resources are moved, which an op holding on to them
+      // prevents; the table and `resource` hold a reference each
+      if Rc::strong_count(&resource) > 2 {
+        return Err(MessagePortError::ResourceInUse);
+      }
+    }
+  }
+
   let mut transferables = Vec::with_capacity(js_transferables.len());
+  let mut transferred = HashMap::new();
   for js_transferable in js_transferables {
     match js_transferable {
       JsTransferable::Resource(name, rid) => {
+        if let Some(index) = transferred.get(&rid) {
+          transferables.push(Transferable::SameResource(name, *index));
+          continue;
+        }
+        transferred.insert(rid, transferables.len());
         let resource = state
           .resource_table
           .take_any(rid)
@@ -231,6 +265,13 @@ pub fn serialize_transferables(
         let rid = state.resource_table.add_rc_dyn(rx);
         js_transferables.push(JsTransferable::Resource(name, rid));
       }
+      Transferable::SameResource(name, index) => {
+        let rid = match js_transferables.get(index) {
+          Some(JsTransferable::Resource(_, rid)) => *rid,
+          _ => unreachable!("a resource is transferred before its aliases"),
+        };
+        js_transferables.push(JsTransferable::Resource(name, rid));
+      }
       Transferable::MultiResource(name, txs) => {
         let rids = txs
           .into_iter()
@@ -146,6 +146,11 @@ impl<TSys: DiskCacheSys> DenoDir<TSys> {
     self.root.join("npm")
   }
 
+  /// Folder used for permissions remembered with `--remember-permissions`.
+  pub fn permission_grants_folder_path(&self) -> PathBuf {
+    self.root.join("permission_grants")
+  }
+
   /// Path used for the REPL history file.
   /// Can be overridden or disabled by setting `DENO_REPL_HISTORY` environment variable.
   pub fn repl_history_file_path(&self) -> Option<PathBuf> {
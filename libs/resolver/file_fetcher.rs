@@ -17,12 +17,15 @@ use deno_cache_dir::file_fetcher::File;
 use deno_cache_dir::file_fetcher::FileFetcherSys;
 use deno_cache_dir::file_fetcher::FileOrRedirect;
 use deno_cache_dir::file_fetcher::HttpClient;
+use deno_cache_dir::file_fetcher::LoadedFrom;
+use deno_cache_dir::file_fetcher::MemoryFiles as _;
 use deno_cache_dir::file_fetcher::TooManyRedirectsError;
 use deno_cache_dir::file_fetcher::UnsupportedSchemeError;
 use deno_error::JsError;
 use deno_error::JsErrorBox;
 use deno_graph::source::CacheInfo;
 use deno_graph::source::CacheSetting as LoaderCacheSetting;
+use deno_graph::source::ChecksumIntegrityError;
 use deno_graph::source::LoadFuture;
 use deno_graph::source::LoadResponse;
 use deno_graph::source::Loader;
@@ -64,6 +67,15 @@ pub enum FetchNoFollowErrorKind {
   #[error(transparent)]
   #[class(generic)]
   PermissionCheck(#[from] PermissionCheckError),
+  #[error("Module not found \"{0}\".")]
+  #[class("NotFound")]
+  VirtualModuleNotFound(Url),
+  #[error(transparent)]
+  #[class(inherit)]
+  VirtualModule(JsErrorBox),
+  #[error(transparent)]
+  #[class(generic)]
+  ChecksumIntegrity(#[from] ChecksumIntegrityError),
 }
 
 #[derive(Debug, Boxed, JsError)]
@@ -121,6 +133,42 @@ impl<'a> FetchNoFollowOptions<'a> {
   }
 }
 
+/// A module supplied by a [`VirtualModuleProvider`].
+#[derive(Debug, Clone)]
+pub struct VirtualModule {
+  #[allow(clippy::disallowed_types)]
+  pub source: std::sync::Arc<[u8]>,
+  /// Headers of the module, like a `content-type` to give its media type,
+  /// which is otherwise taken from the extension of the specifier.
+  pub maybe_headers: Option<HashMap<String, String>>,
+  /// Whether the module is kept in memory and reused by later loads of the
+  /// specifier instead of asking the provider again.
+  pub cache: bool,
+}
+
+/// An extension point for embedders to serve modules from somewhere other
+/// than the file system or the network, like a database or an encrypted
+/// bundle. The modules are part of the module graph and are checked
+/// against the lockfile like any other module.
+///
+/// Permissions aren't checked for the specifiers of a provider, so it's
+/// responsible for any access control.
+pub trait VirtualModuleProvider: std::fmt::Debug + Send + Sync {
+  /// Whether the specifier is served by this provider, usually by its
+  /// scheme. Other specifiers are loaded as usual.
+  fn handles(&self, specifier: &Url) -> bool;
+
+  /// Loads the module, or returns `None` when it doesn't exist.
+  fn load(
+    &self,
+    specifier: &Url,
+  ) -> LocalBoxFuture<'static, Result<Option<VirtualModule>, JsErrorBox>>;
+}
+
+#[allow(clippy::disallowed_types)]
+pub type VirtualModuleProviderRc =
+  deno_maybe_sync::MaybeArc<dyn VirtualModuleProvider>;
+
 #[sys_traits::auto_impl]
 pub trait PermissionedFileFetcherSys:
   FileFetcherSys + sys_traits::EnvVar
@@ -136,6 +184,7 @@ type PermissionedFileFetcherRc<TBlobStore, TSys, THttpClient> =
 pub struct PermissionedFileFetcherOptions {
   pub allow_remote: bool,
   pub cache_setting: CacheSetting,
+  pub virtual_module_provider: Option<VirtualModuleProviderRc>,
 }
 
 /// A structure for resolving, fetching and caching source files.
@@ -148,6 +197,7 @@ pub struct PermissionedFileFetcher<
   file_fetcher:
     deno_cache_dir::file_fetcher::FileFetcher<TBlobStore, TSys, THttpClient>,
   memory_files: MemoryFilesRc,
+  virtual_module_provider: Option<VirtualModuleProviderRc>,
 }
 
 impl<
@@ -180,9 +230,18 @@ impl<
     Self {
       file_fetcher,
       memory_files,
+      virtual_module_provider: options.virtual_module_provider,
     }
   }
 
+  /// Whether the specifier is served by the virtual module provider.
+  pub fn is_virtual_module(&self, specifier: &Url) -> bool {
+    self
+      .virtual_module_provider
+      .as_ref()
+      .is_some_and(|provider| provider.handles(specifier))
+  }
+
   pub fn cache_setting(&self) -> &CacheSetting {
     self.file_fetcher.cache_setting()
   }
@@ -305,6 +364,10 @@ impl<
     permissions: FetchPermissionsOptionRef<'_>,
     options: FetchNoFollowOptions<'_>,
   ) -> Result<CachedOrRedirect, FetchNoFollowError> {
+    if self.is_virtual_module(specifier) {
+      // the provider supplies the module whenever it's loaded
+      return Ok(CachedOrRedirect::Cached);
+    }
     self.validate_fetch(specifier, permissions)?;
     self
       .file_fetcher
@@ -320,6 +383,14 @@ impl<
     permissions: FetchPermissionsOptionRef<'_>,
     options: FetchNoFollowOptions<'_>,
   ) -> Result<FileOrRedirect, FetchNoFollowError> {
+    if let Some(provider) = &self.virtual_module_provider
+      && provider.handles(specifier)
+    {
+      return self
+        .fetch_virtual_module(provider, specifier, options.maybe_checksum)
+        .await
+        .map(FileOrRedirect::File);
+    }
     self.validate_fetch(specifier, permissions)?;
     self
       .file_fetcher
@@ -328,6 +399,42 @@ impl<
       .map_err(|err| FetchNoFollowErrorKind::FetchNoFollow(err).into_box())
   }
 
+  async fn fetch_virtual_module(
+    &self,
+    provider: &VirtualModuleProviderRc,
+    specifier: &Url,
+    maybe_checksum: Option<&LoaderChecksum>,
+  ) -> Result<File, FetchNoFollowError> {
+    let file = match self.memory_files.get(specifier) {
+      Some(file) => file,
+      None => {
+        let module = provider
+          .load(specifier)
+          .await
+          .map_err(|err| FetchNoFollowErrorKind::VirtualModule(err).into_box())?
+          .ok_or_else(|| {
+            FetchNoFollowErrorKind::VirtualModuleNotFound(specifier.clone())
+              .into_box()
+          })?;
+        let file = File {
+          url: specifier.clone(),
+          mtime: None,
+          maybe_headers: module.maybe_headers,
+          source: module.source,
+          loaded_from: LoadedFrom::Local,
+        };
+        if module.cache {
+          self.memory_files.insert(specifier.clone(), file.clone());
+        }
+        file
+      }
+    };
+    if let Some(checksum) = maybe_checksum {
+      checksum.check_source(&file.source)?;
+    }
+    Ok(file)
+  }
+
   fn validate_fetch(
     &self,
     specifier: &Url,
@@ -354,7 +461,9 @@ impl<
     &self,
     specifier: &Url,
   ) -> Result<Option<File>, GetCachedSourceOrLocalError> {
-    if specifier.scheme() == "file" {
+    if self.is_virtual_module(specifier) {
+      Ok(self.memory_files.get(specifier))
+    } else if specifier.scheme() == "file" {
       Ok(
         self
           .file_fetcher
@@ -579,6 +688,11 @@ impl<
             FetchNoFollowErrorKind::PermissionCheck(permission_check_error) => {
               Err(load_error(JsErrorBox::from_err(permission_check_error)))
             }
+            FetchNoFollowErrorKind::VirtualModuleNotFound(_) => Ok(None),
+            FetchNoFollowErrorKind::VirtualModule(err) => Err(load_error(err)),
+            FetchNoFollowErrorKind::ChecksumIntegrity(err) => {
+              Err(deno_graph::source::LoadError::ChecksumIntegrity(err))
+            }
           }
         }
       }
@@ -632,7 +746,8 @@ impl<
     if !matches!(
       specifier.scheme(),
       "file" | "http" | "https" | "blob" | "data"
-    ) {
+    ) && !self.file_fetcher.is_virtual_module(specifier)
+    {
       return Box::pin(std::future::ready(Ok(Some(
         deno_graph::source::LoadResponse::External {
           specifier: specifier.clone(),
@@ -158,6 +158,48 @@ class NotADirectory extends Error {
   }
 }
 
+class CrossDevice extends Error {
+  constructor(msg) {
+    super(msg);
+    this.name = "CrossDevice";
+  }
+}
+
+class TextFileBusy extends Error {
+  constructor(msg) {
+    super(msg);
+    this.name = "TextFileBusy";
+  }
+}
+
+class FileTooLarge extends Error {
+  constructor(msg) {
+    super(msg);
+    this.name = "FileTooLarge";
+  }
+}
+
+class DirectoryNotEmpty extends Error {
+  constructor(msg) {
+    super(msg);
+    this.name = "DirectoryNotEmpty";
+  }
+}
+
+class ReadOnlyFileSystem extends Error {
+  constructor(msg) {
+    super(msg);
+    this.name = "ReadOnlyFileSystem";
+  }
+}
+
+class StorageFull extends Error {
+  constructor(msg) {
+    super(msg);
+    this.name = "StorageFull";
+  }
+}
+
 const errors = {
   NotFound,
   PermissionDenied,
@@ -184,6 +226,12 @@ const errors = {
   NetworkUnreachable,
   NotADirectory,
   NotCapable,
+  CrossDevice,
+  TextFileBusy,
+  FileTooLarge,
+  DirectoryNotEmpty,
+  ReadOnlyFileSystem,
+  StorageFull,
 };
 
 export { errors };
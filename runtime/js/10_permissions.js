@@ -15,6 +15,7 @@ const {
   MapPrototypeHas,
   MapPrototypeSet,
   FunctionPrototypeCall,
+  NumberIsSafeInteger,
   PromiseResolve,
   PromiseReject,
   ReflectHas,
@@ -67,10 +68,11 @@ function opRevoke(desc) {
 
 /**
  * @param {Deno.PermissionDescriptor} desc
+ * @param {Deno.PermissionRequestOptions} [options]
  * @returns {Deno.PermissionState}
  */
-function opRequest(desc) {
-  return op_request_permission(desc);
+function opRequest(desc, options) {
+  return op_request_permission(desc, options);
 }
 
 class PermissionStatus extends EventTarget {
@@ -244,24 +246,33 @@ class Permissions {
     return cache(desc, status);
   }
 
-  request(desc) {
+  request(desc, options) {
     try {
-      return PromiseResolve(this.requestSync(desc));
+      return PromiseResolve(this.requestSync(desc, options));
     } catch (error) {
       return PromiseReject(error);
     }
   }
 
-  requestSync(desc) {
+  requestSync(desc, options = undefined) {
     if (!isValidDescriptor(desc)) {
       throw new TypeError(
         `The provided value "${desc?.name}" is not a valid permission name.`,
       );
     }
+    const expiresIn = options?.expiresIn;
+    if (
+      expiresIn !== undefined &&
+      (!NumberIsSafeInteger(expiresIn) || expiresIn < 0)
+    ) {
+      throw new TypeError(
+        `'expiresIn' must be a non-negative integer: received ${expiresIn}`,
+      );
+    }
 
     formDescriptor(desc);
 
-    const status = opRequest(desc);
+    const status = opRequest(desc, { expiresIn });
     return cache(desc, status);
   }
 }
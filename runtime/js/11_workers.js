@@ -11,13 +11,16 @@ import {
 const {
   ArrayPrototypeFilter,
   Error,
+  NumberIsInteger,
   ObjectPrototypeIsPrototypeOf,
+  SafeArrayIterator,
   String,
   StringPrototypeStartsWith,
   Symbol,
   SymbolFor,
   SymbolIterator,
   SymbolToStringTag,
+  TypeError,
 } = primordials;
 
 import * as webidl from "ext:deno_webidl/00_webidl.js";
@@ -47,6 +50,7 @@ function createWorker(
   name,
   workerType,
   closeOnIdle,
+  limits,
 ) {
   return op_create_worker({
     hasSourceCode,
@@ -56,9 +60,32 @@ function createWorker(
     specifier,
     workerType,
     closeOnIdle,
+    limits: serializeLimits(limits),
   });
 }
 
+const LIMIT_KEYS = ["heapMb", "cpuMs", "maxOpenFds"];
+
+function serializeLimits(limits) {
+  if (limits == null) {
+    return null;
+  }
+  const serialized = { __proto__: null };
+  for (const key of new SafeArrayIterator(LIMIT_KEYS)) {
+    const value = limits[key];
+    if (value === undefined) {
+      continue;
+    }
+    if (!NumberIsInteger(value) || value <= 0) {
+      throw new TypeError(
+        `Worker limit "${key}" must be a positive integer, received ${value}`,
+      );
+    }
+    serialized[key] = value;
+  }
+  return serialized;
+}
+
 function hostTerminateWorker(id) {
   op_host_terminate_worker(id);
 }
@@ -131,6 +158,7 @@ class Worker extends EventTarget {
       this.#name,
       workerType,
       false,
+      deno?.limits,
     );
     this.#id = id;
     this.#pollControl();
@@ -1,7 +1,19 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 import { core, primordials } from "ext:core/mod.js";
 import { op_console_size } from "ext:core/ops";
+import {
+  addSignalListener,
+  removeSignalListener,
+} from "ext:deno_os/40_signals.js";
+import {
+  clearInterval,
+  setInterval,
+  unrefTimer,
+} from "ext:deno_web/02_timers.js";
 const {
+  Promise,
+  PromiseResolve,
+  SymbolAsyncIterator,
   Uint32Array,
 } = primordials;
 const {
@@ -15,10 +27,89 @@ function consoleSize() {
   return { columns: size[0], rows: size[1] };
 }
 
+// Windows has no SIGWINCH, so the size is polled there instead.
+const WINDOWS_POLL_INTERVAL = 250;
+
+function consoleSizeChanges() {
+  let current = consoleSize();
+  // whether `current` was not yielded yet
+  let changed = false;
+  let resolveNext = null;
+  let done = false;
+
+  const onChange = () => {
+    let next;
+    try {
+      next = consoleSize();
+    } catch {
+      return;
+    }
+    if (next.columns === current.columns && next.rows === current.rows) {
+      return;
+    }
+    // sizes that were never read are replaced by the latest one
+    current = next;
+    changed = true;
+    if (resolveNext !== null) {
+      const resolve = resolveNext;
+      resolveNext = null;
+      changed = false;
+      resolve({ value: current, done: false });
+    }
+  };
+
+  let intervalId;
+  if (core.build.os === "windows") {
+    intervalId = setInterval(onChange, WINDOWS_POLL_INTERVAL);
+    unrefTimer(intervalId);
+  } else {
+    addSignalListener("SIGWINCH", onChange);
+  }
+
+  const stop = () => {
+    if (done) {
+      return;
+    }
+    done = true;
+    if (intervalId !== undefined) {
+      clearInterval(intervalId);
+    } else {
+      removeSignalListener("SIGWINCH", onChange);
+    }
+    if (resolveNext !== null) {
+      const resolve = resolveNext;
+      resolveNext = null;
+      resolve({ value: undefined, done: true });
+    }
+  };
+
+  return {
+    [SymbolAsyncIterator]() {
+      return this;
+    },
+    next() {
+      if (done) {
+        return PromiseResolve({ value: undefined, done: true });
+      }
+      if (changed) {
+        changed = false;
+        return PromiseResolve({ value: current, done: false });
+      }
+      return new Promise((resolve) => {
+        resolveNext = resolve;
+      });
+    },
+    return() {
+      stop();
+      return PromiseResolve({ value: undefined, done: true });
+    },
+  };
+}
+
 // Note: This function was soft-removed in Deno 2. Its types have been removed,
 // but its implementation has been kept to avoid breaking changes.
 function isatty(rid) {
   return isTerminal(rid);
 }
 
-export { consoleSize, isatty };
+export { consoleSize, consoleSizeChanges, isatty };
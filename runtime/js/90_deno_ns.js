@@ -2,9 +2,11 @@
 
 import { core, primordials } from "ext:core/mod.js";
 import {
+  op_embedded_files,
   op_net_listen_udp,
   op_net_listen_unixpacket,
   op_runtime_cpu_usage,
+  op_runtime_memory_breakdown,
   op_runtime_memory_usage,
 } from "ext:core/ops";
 
@@ -17,6 +19,8 @@ import * as tls from "ext:deno_net/02_tls.js";
 import * as serve from "ext:deno_http/00_serve.ts";
 import * as http from "ext:deno_http/01_http.js";
 import * as websocket from "ext:deno_http/02_websocket.ts";
+import * as fileServer from "ext:deno_http/03_file_server.ts";
+import * as serverSentEvents from "ext:deno_http/04_server_sent_events.ts";
 import * as errors from "ext:runtime/01_errors.js";
 import * as version from "ext:runtime/01_version.ts";
 import * as permissions from "ext:runtime/10_permissions.js";
@@ -55,6 +59,7 @@ const denoNs = {
   readTextFileSync: fs.readTextFileSync,
   readFile: fs.readFile,
   readFileSync: fs.readFileSync,
+  fileFromPath: fs.fileFromPath,
   watchFs: fsEvents.watchFs,
   chmodSync: fs.chmodSync,
   chmod: fs.chmod,
@@ -74,7 +79,7 @@ const denoNs = {
       user,
     };
   },
-  memoryUsage: () => {
+  memoryUsage: (options) => {
     op_runtime_memory_usage(usageBuffer);
     const {
       0: rss,
@@ -82,6 +87,17 @@ const denoNs = {
       2: heapUsed,
       3: external,
     } = usageBuffer;
+    if (options?.detailed) {
+      const { allocator, resources } = op_runtime_memory_breakdown();
+      return {
+        rss,
+        heapTotal,
+        heapUsed,
+        external,
+        allocator,
+        resources,
+      };
+    }
     return {
       rss,
       heapTotal,
@@ -116,6 +132,7 @@ const denoNs = {
   env: os.env,
   exit: os.exit,
   execPath: os.execPath,
+  embeddedFiles: () => op_embedded_files(),
   SeekMode: io.SeekMode,
   FsFile: fs.FsFile,
   open: fs.open,
@@ -155,6 +172,7 @@ const denoNs = {
   systemMemoryInfo: os.systemMemoryInfo,
   networkInterfaces: os.networkInterfaces,
   consoleSize: tty.consoleSize,
+  consoleSizeChanges: tty.consoleSizeChanges,
   gid: os.gid,
   uid: os.uid,
   Command: process.Command,
@@ -182,6 +200,12 @@ denoNsUnstableById[unstableIds.cron] = {
   cron: cron.cron,
 };
 
+denoNsUnstableById[unstableIds.http] = {
+  serveDir: fileServer.serveDir,
+  serveFile: fileServer.serveFile,
+  serverSentEvents: serverSentEvents.serverSentEvents,
+};
+
 denoNsUnstableById[unstableIds.kv] = {
   openKv: kv.openKv,
   AtomicOperation: kv.AtomicOperation,
@@ -195,6 +219,9 @@ denoNsUnstableById[unstableIds.net] = {
     op_net_listen_udp,
     op_net_listen_unixpacket,
   ),
+  icmpPing: net.icmpPing,
+  listenSystemd: net.listenSystemd,
+  notifySystemd: net.notifySystemd,
 };
 
 ObjectDefineProperties(denoNsUnstableById[unstableIds.net], {
@@ -221,6 +248,10 @@ ObjectDefineProperties(denoNsUnstableById[unstableIds.net], {
   ),
 });
 
+denoNsUnstableById[unstableIds.process] = {
+  cgroupLimits: os.cgroupLimits,
+};
+
 // denoNsUnstableById[unstableIds.unsafeProto] = { __proto__: null }
 
 denoNsUnstableById[unstableIds.webgpu] = {
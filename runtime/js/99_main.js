@@ -8,6 +8,7 @@ import { core, internals, primordials } from "ext:core/mod.js";
 const ops = core.ops;
 import {
   op_bootstrap_args,
+  op_bootstrap_build_features,
   op_bootstrap_is_from_unconfigured_runtime,
   op_bootstrap_no_color,
   op_bootstrap_pid,
@@ -36,6 +37,7 @@ const {
   ObjectAssign,
   ObjectDefineProperties,
   ObjectDefineProperty,
+  ObjectFreeze,
   ObjectHasOwn,
   ObjectKeys,
   ObjectPrototypeIsPrototypeOf,
@@ -342,6 +344,12 @@ core.registerErrorClass("FilesystemLoop", errors.FilesystemLoop);
 core.registerErrorClass("IsADirectory", errors.IsADirectory);
 core.registerErrorClass("NetworkUnreachable", errors.NetworkUnreachable);
 core.registerErrorClass("NotADirectory", errors.NotADirectory);
+core.registerErrorClass("CrossDevice", errors.CrossDevice);
+core.registerErrorClass("TextFileBusy", errors.TextFileBusy);
+core.registerErrorClass("FileTooLarge", errors.FileTooLarge);
+core.registerErrorClass("DirectoryNotEmpty", errors.DirectoryNotEmpty);
+core.registerErrorClass("ReadOnlyFileSystem", errors.ReadOnlyFileSystem);
+core.registerErrorClass("StorageFull", errors.StorageFull);
 core.registerErrorBuilder(
   "DOMExceptionOperationError",
   function DOMExceptionOperationError(msg) {
@@ -378,6 +386,12 @@ core.registerErrorBuilder(
     return new DOMException(msg, "InvalidCharacterError");
   },
 );
+core.registerErrorBuilder(
+  "DOMExceptionDataCloneError",
+  function DOMExceptionDataCloneError(msg) {
+    return new DOMException(msg, "DataCloneError");
+  },
+);
 core.registerErrorBuilder(
   "DOMExceptionDataError",
   function DOMExceptionDataError(msg) {
@@ -640,6 +654,7 @@ function bootstrapMainRuntime(runtimeOptions, warmup = false) {
     } = runtimeOptions;
 
     denoNs.build.standalone = standalone;
+    denoNs.build.features = ObjectFreeze(op_bootstrap_build_features());
 
     let serveIsMain_ = serveIsMain;
     let serveWorkerCountOrIndex_ = serveWorkerCountOrIndex;
@@ -849,6 +864,7 @@ function bootstrapWorkerRuntime(
     } = runtimeOptions;
 
     denoNs.build.standalone = standalone;
+    denoNs.build.features = ObjectFreeze(op_bootstrap_build_features());
 
     closeOnIdle = runtimeOptions[14];
 
@@ -33,14 +33,19 @@ pub mod inspector_server;
 pub mod js;
 pub mod ops;
 pub mod permissions;
+pub mod profiler;
+pub mod resource_leaks;
+pub mod seccomp;
 #[cfg(feature = "snapshot")]
 pub mod snapshot;
 pub mod snapshot_info;
 pub mod tokio_util;
+pub mod trace_ops;
 #[cfg(feature = "transpile")]
 pub mod transpile;
 pub mod web_worker;
 pub mod worker;
+pub mod worker_limits;
 
 mod worker_bootstrap;
 pub use worker::UnconfiguredRuntime;
@@ -55,4 +60,5 @@ pub use deno_features::UNSTABLE_ENV_VAR_NAMES;
 pub use deno_features::UNSTABLE_FEATURES;
 pub use deno_features::UnstableFeatureKind;
 pub use deno_os::exit;
+pub use ops::bootstrap::build_features;
 pub use shared::runtime;
@@ -22,6 +22,7 @@ deno_core::extension!(
     op_bootstrap_stderr_no_color,
     op_bootstrap_unstable_args,
     op_bootstrap_is_from_unconfigured_runtime,
+    op_bootstrap_build_features,
     op_snapshot_options,
   ],
   options = {
@@ -79,6 +80,43 @@ pub fn op_snapshot_options(state: &mut OpState) -> SnapshotOptions {
   }
 }
 
+/// The optional parts of the runtime this binary was built with, or that
+/// are only built for some platforms, for `Deno.build.features` and
+/// `deno info --json`. Parts that every build has are not listed.
+pub fn build_features() -> Vec<&'static str> {
+  [
+    (
+      "cgroups",
+      cfg!(any(target_os = "android", target_os = "linux")),
+    ),
+    ("hmr", cfg!(feature = "hmr")),
+    ("pty", cfg!(unix)),
+    (
+      "realtime-signals",
+      cfg!(any(target_os = "android", target_os = "linux")),
+    ),
+    (
+      "seccomp",
+      cfg!(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+      )),
+    ),
+    ("tokio-metrics", cfg!(tokio_unstable)),
+    ("transpile", cfg!(feature = "transpile")),
+    ("worker-cpu-limit", crate::worker_limits::SUPPORTS_CPU_LIMIT),
+  ]
+  .into_iter()
+  .filter_map(|(name, enabled)| enabled.then_some(name))
+  .collect()
+}
+
+#[op2]
+#[serde]
+pub fn op_bootstrap_build_features() -> Vec<&'static str> {
+  build_features()
+}
+
 #[op2]
 #[serde]
 pub fn op_bootstrap_args(state: &mut OpState) -> Vec<String> {
@@ -1,5 +1,8 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::time::Duration;
+use std::time::Instant;
+
 use ::deno_permissions::PermissionState;
 use ::deno_permissions::PermissionsContainer;
 use deno_core::OpState;
@@ -26,6 +29,13 @@ pub struct PermissionArgs {
   command: Option<String>,
 }
 
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionRequestOptions {
+  /// Milliseconds after which a permission granted at the prompt lapses.
+  expires_in: Option<u64>,
+}
+
 #[derive(Serialize)]
 pub struct PermissionStatus {
   state: &'static str,
@@ -113,17 +123,22 @@ pub fn op_revoke_permission(
 pub fn op_request_permission(
   state: &mut OpState,
   #[serde] args: PermissionArgs,
+  #[serde] options: Option<PermissionRequestOptions>,
 ) -> Result<PermissionStatus, PermissionError> {
   let permissions = state.borrow::<PermissionsContainer>();
+  let expires_at = options
+    .unwrap_or_default()
+    .expires_in
+    .map(|ms| Instant::now() + Duration::from_millis(ms));
   let perm = match args.name.as_ref() {
-    "read" => permissions.request_read(args.path.as_deref())?,
-    "write" => permissions.request_write(args.path.as_deref())?,
-    "net" => permissions.request_net(args.host.as_deref())?,
-    "env" => permissions.request_env(args.variable.as_deref()),
-    "sys" => permissions.request_sys(args.kind.as_deref())?,
-    "run" => permissions.request_run(args.command.as_deref())?,
-    "ffi" => permissions.request_ffi(args.path.as_deref())?,
-    "import" => permissions.request_import(args.host.as_deref())?,
+    "read" => permissions.request_read(args.path.as_deref(), expires_at)?,
+    "write" => permissions.request_write(args.path.as_deref(), expires_at)?,
+    "net" => permissions.request_net(args.host.as_deref(), expires_at)?,
+    "env" => permissions.request_env(args.variable.as_deref(), expires_at),
+    "sys" => permissions.request_sys(args.kind.as_deref(), expires_at)?,
+    "run" => permissions.request_run(args.command.as_deref(), expires_at)?,
+    "ffi" => permissions.request_ffi(args.path.as_deref(), expires_at)?,
+    "import" => permissions.request_import(args.host.as_deref(), expires_at)?,
     _ => return Err(PermissionError::InvalidPermissionName(args.name)),
   };
   Ok(PermissionStatus::from(perm))
@@ -1,18 +1,33 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::sync::Arc;
+
 use deno_core::ModuleSpecifier;
 use deno_core::OpState;
 use deno_core::op2;
 
 deno_core::extension!(
   deno_runtime,
-  ops = [op_main_module, op_ppid, op_internal_log],
+  ops = [op_main_module, op_ppid, op_internal_log, op_embedded_files],
   options = { main_module: ModuleSpecifier },
   state = |state, options| {
     state.put::<ModuleSpecifier>(options.main_module);
   },
 );
 
+/// The paths of the files embedded in a `deno compile` binary with
+/// `--include`, which are readable with the fs APIs.
+#[derive(Debug, Clone, Default)]
+pub struct EmbeddedFiles(pub Arc<Vec<String>>);
+
+deno_core::extension!(
+  deno_embedded_files,
+  options = { files: EmbeddedFiles },
+  state = |state, options| {
+    state.put::<EmbeddedFiles>(options.files);
+  },
+);
+
 #[op2]
 #[string]
 fn op_main_module(state: &mut OpState) -> String {
@@ -20,6 +35,15 @@ fn op_main_module(state: &mut OpState) -> String {
   main_url.to_string()
 }
 
+#[op2]
+#[serde]
+fn op_embedded_files(state: &mut OpState) -> Vec<String> {
+  state
+    .try_borrow::<EmbeddedFiles>()
+    .map(|files| files.0.as_ref().clone())
+    .unwrap_or_default()
+}
+
 /// This is an op instead of being done at initialization time because
 /// it's expensive to retrieve the ppid on Windows.
 #[op2(fast)]
@@ -5,8 +5,12 @@ use std::cell::RefCell;
 #[cfg(unix)]
 use std::collections::HashMap;
 use std::io::Error;
+use std::io::Write;
 #[cfg(windows)]
 use std::sync::Arc;
+use std::sync::Once;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use deno_core::OpState;
 #[cfg(unix)]
@@ -59,7 +63,12 @@ use winapi::um::wincon;
 
 deno_core::extension!(
   deno_tty,
-  ops = [op_set_raw, op_console_size, op_read_line_prompt],
+  ops = [
+    op_set_raw,
+    op_set_bracketed_paste,
+    op_console_size,
+    op_read_line_prompt
+  ],
   state = |state| {
     #[cfg(unix)]
     state.put(TtyModeStore::default());
@@ -119,6 +128,8 @@ fn op_set_raw(
   rid: u32,
   is_raw: bool,
   cbreak: bool,
+  min: u32,
+  time: u32,
 ) -> Result<(), TtyError> {
   let handle_or_fd = state.resource_table.get_fd(rid)?;
 
@@ -135,7 +146,9 @@ fn op_set_raw(
 
     let handle = handle_or_fd;
 
-    if cbreak {
+    // VMIN and VTIME are termios settings, reads return as soon as there is
+    // input on Windows
+    if cbreak || min != 1 || time != 0 {
       return Err(TtyError::Other(JsErrorBox::not_supported()));
     }
 
@@ -302,8 +315,10 @@ fn op_set_raw(
       if !cbreak {
         raw.local_flags &= !(termios::LocalFlags::ISIG);
       }
-      raw.control_chars[termios::SpecialCharacterIndices::VMIN as usize] = 1;
-      raw.control_chars[termios::SpecialCharacterIndices::VTIME as usize] = 0;
+      raw.control_chars[termios::SpecialCharacterIndices::VMIN as usize] =
+        min as u8;
+      raw.control_chars[termios::SpecialCharacterIndices::VTIME as usize] =
+        time as u8;
       termios::tcsetattr(raw_fd, termios::SetArg::TCSADRAIN, &raw)
         .map_err(|e| TtyError::Nix(JsNixError(e)))?;
     } else {
@@ -318,6 +333,48 @@ fn op_set_raw(
   }
 }
 
+const BRACKETED_PASTE_ON: &[u8] = b"\x1b[?2004h";
+const BRACKETED_PASTE_OFF: &[u8] = b"\x1b[?2004l";
+
+/// Whether bracketed paste has to be turned off again on exit.
+static BRACKETED_PASTE: AtomicBool = AtomicBool::new(false);
+
+#[op2(fast)]
+fn op_set_bracketed_paste(
+  state: &mut OpState,
+  rid: u32,
+  enabled: bool,
+) -> Result<(), TtyError> {
+  static RESET_ON_EXIT: Once = Once::new();
+  RESET_ON_EXIT.call_once(|| {
+    extern "C" fn reset_bracketed_paste() {
+      if BRACKETED_PASTE.load(Ordering::Relaxed) {
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(BRACKETED_PASTE_OFF);
+        let _ = stdout.flush();
+      }
+    }
+    // SAFETY: libc call, the handler only writes to stdout
+    unsafe { libc::atexit(reset_bracketed_paste) };
+  });
+
+  let resource = state.resource_table.get_any(rid)?;
+  let sequence = if enabled {
+    BRACKETED_PASTE_ON
+  } else {
+    BRACKETED_PASTE_OFF
+  };
+  let mut written = 0;
+  while written < sequence.len() {
+    written += resource
+      .clone()
+      .write_sync(&sequence[written..])
+      .map_err(TtyError::Other)?;
+  }
+  BRACKETED_PASTE.store(enabled, Ordering::Relaxed);
+  Ok(())
+}
+
 #[op2(fast)]
 fn op_console_size(
   state: &mut OpState,
@@ -29,6 +29,8 @@ use crate::web_worker::WorkerMetadata;
 use crate::web_worker::WorkerThreadType;
 use crate::web_worker::run_web_worker;
 use crate::worker::FormatJsErrorFn;
+use crate::worker_limits::SUPPORTS_CPU_LIMIT;
+use crate::worker_limits::WorkerLimits;
 
 pub const UNSTABLE_FEATURE_NAME: &str = "worker-options";
 
@@ -41,6 +43,7 @@ pub struct CreateWebWorkerArgs {
   pub worker_type: WorkerThreadType,
   pub close_on_idle: bool,
   pub maybe_worker_metadata: Option<WorkerMetadata>,
+  pub limits: WorkerLimits,
 }
 
 pub type CreateWebWorkerCb = dyn Fn(CreateWebWorkerArgs) -> (WebWorker, SendableWebWorkerHandle)
@@ -119,6 +122,7 @@ pub struct CreateWorkerArgs {
   specifier: String,
   worker_type: WorkerThreadType,
   close_on_idle: bool,
+  limits: Option<WorkerLimits>,
 }
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
@@ -126,6 +130,11 @@ pub enum CreateWorkerError {
   #[class("DOMExceptionNotSupportedError")]
   #[error("Classic workers are not supported.")]
   ClassicWorkers,
+  #[class("NotSupported")]
+  #[error(
+    "Limiting the CPU time of workers is not supported on this platform."
+  )]
+  CpuLimit,
   #[class(inherit)]
   #[error(transparent)]
   Permission(deno_permissions::ChildPermissionError),
@@ -170,6 +179,17 @@ fn op_create_worker(
     );
   }
 
+  let limits = args.limits.unwrap_or_default();
+  if args.limits.is_some() {
+    // `resourceLimits` of `node:worker_threads` workers are stable API.
+    if !matches!(worker_type, WorkerThreadType::Node) {
+      super::check_unstable(state, UNSTABLE_FEATURE_NAME, "Worker.deno.limits");
+    }
+    if limits.cpu_ms.is_some() && !SUPPORTS_CPU_LIMIT {
+      return Err(CreateWorkerError::CpuLimit);
+    }
+  }
+
   let parent_permissions = state.borrow_mut::<PermissionsContainer>();
   let worker_permissions = if let Some(child_permissions_arg) = args.permissions
   {
@@ -219,6 +239,7 @@ fn op_create_worker(
           worker_type,
           close_on_idle: args.close_on_idle,
           maybe_worker_metadata,
+          limits,
         });
 
       // Send thread safe handle from newly created worker to host thread
@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fmt;
 use std::fmt::Debug;
@@ -11,11 +13,14 @@ use std::net::IpAddr;
 use std::net::Ipv6Addr;
 use std::net::SocketAddr;
 use std::ops::Deref;
+use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
 use std::string::ToString;
 use std::sync::Arc;
 use std::sync::OnceLock;
+use std::sync::atomic::AtomicU64;
+use std::time::Instant;
 
 use capacity_builder::StringBuilder;
 use deno_path_util::normalize_path;
@@ -40,6 +45,7 @@ pub mod which;
 
 use prompter::MAYBE_CURRENT_STACKTRACE;
 use prompter::PERMISSION_EMOJI;
+use prompter::notify_prompt_grant;
 use prompter::permission_prompt;
 pub use runtime_descriptor_parser::RuntimePermissionDescriptorParser;
 
@@ -77,9 +83,30 @@ fn format_permission_error(name: &'static str) -> String {
   }
 }
 
+/// How a permission check written to the audit file was resolved.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum AuditResult {
+  Granted,
+  Denied,
+  Ignored,
+}
+
+/// Records a check of a permission that is fully granted.
 fn write_audit<T>(flag_name: &str, value: T)
 where
   T: Serialize,
+{
+  write_audit_check(flag_name, value, AuditResult::Granted, false);
+}
+
+fn write_audit_check<T>(
+  flag_name: &str,
+  value: T,
+  result: AuditResult,
+  prompted: bool,
+) where
+  T: Serialize,
 {
   let Some(file) = AUDIT_FILE.get() else {
     return;
@@ -87,7 +114,7 @@ where
 
   let mut file = file.lock();
 
-  let mut map = serde_json::Map::with_capacity(5);
+  let mut map = serde_json::Map::with_capacity(7);
   let _ = map.insert("v".into(), serde_json::Value::Number(1.into()));
   let _ = map.insert(
     "datetime".into(),
@@ -100,6 +127,8 @@ where
     serde_json::to_value(flag_name).unwrap(),
   );
   let _ = map.insert("value".into(), serde_json::to_value(value).unwrap());
+  let _ = map.insert("result".into(), serde_json::to_value(result).unwrap());
+  let _ = map.insert("prompted".into(), serde_json::Value::Bool(prompted));
 
   let get_stack = MAYBE_CURRENT_STACKTRACE.lock();
   if let Some(stack) = get_stack.as_ref().map(|s| s()) {
@@ -112,12 +141,12 @@ where
 }
 
 /// Fast exit from permission check routines if this permission
-/// is in the "fully-granted" state.
+/// is in the "fully-granted" state. Other checks are audited once they
+/// are resolved, in `UnaryPermission::check_desc`.
 macro_rules! audit_and_skip_check_if_is_permission_fully_granted {
   ($this:expr, $flag_name:expr, $value:expr) => {
-    write_audit($flag_name, $value);
-
     if $this.is_allow_all() {
+      write_audit($flag_name, $value);
       return Ok(());
     }
   };
@@ -140,7 +169,7 @@ pub enum PermissionState {
   Ignored = 5,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum OpenAccessKind {
   Read,
   ReadNoFollow,
@@ -384,6 +413,62 @@ impl AsRef<Path> for CheckedPathBuf {
   }
 }
 
+/// Incremented whenever granted or denied permissions change, which makes
+/// cached path checks stale.
+static PERMISSIONS_REVISION: AtomicU64 = AtomicU64::new(0);
+
+fn bump_permissions_revision() {
+  PERMISSIONS_REVISION.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+}
+
+const CHECKED_PATH_CACHE_CAPACITY: usize = 1024;
+
+/// Absolute paths that matched the permissions in
+/// `PermissionsContainer::check_open()`. Only the match against the
+/// descriptors is saved, the path is still resolved when it is checked again,
+/// since the file system may change what it resolves to. Entries are dropped
+/// once the permissions change.
+#[derive(Debug, Default)]
+struct CheckedPathCache {
+  revision: u64,
+  len: usize,
+  entries: HashMap<OpenAccessKind, HashSet<PathBuf>>,
+}
+
+impl CheckedPathCache {
+  fn sync_revision(&mut self) {
+    let revision =
+      PERMISSIONS_REVISION.load(std::sync::atomic::Ordering::Relaxed);
+    if self.revision != revision {
+      self.clear();
+      self.revision = revision;
+    }
+  }
+
+  fn contains(&mut self, path: &Path, access_kind: OpenAccessKind) -> bool {
+    self.sync_revision();
+    self
+      .entries
+      .get(&access_kind)
+      .is_some_and(|paths| paths.contains(path))
+  }
+
+  fn insert(&mut self, path: PathBuf, access_kind: OpenAccessKind) {
+    self.sync_revision();
+    if self.len >= CHECKED_PATH_CACHE_CAPACITY {
+      self.clear();
+    }
+    if self.entries.entry(access_kind).or_default().insert(path) {
+      self.len += 1;
+    }
+  }
+
+  fn clear(&mut self) {
+    self.entries.clear();
+    self.len = 0;
+  }
+}
+
 /// `AllowPartial` prescribes how to treat a permission which is partially
 /// denied due to a `--deny-*` flag affecting a subscope of the queried
 /// permission.
@@ -691,6 +776,12 @@ pub trait QueryDescriptor: Debug {
   fn flag_name() -> &'static str;
   fn display_name(&self) -> Cow<'_, str>;
 
+  /// The value of the `--allow-*` flag that grants this descriptor, which
+  /// is the resolved path rather than the requested one for paths.
+  fn flag_value(&self) -> Cow<'_, str> {
+    self.display_name()
+  }
+
   fn from_allow(allow: &Self::AllowDesc) -> Self;
 
   fn as_allow(&self) -> Option<Self::AllowDesc>;
@@ -895,6 +986,15 @@ impl<TAllowDesc: AllowDescriptor> UnaryPermissionDescriptors<TAllowDesc> {
       | UnaryPermissionDesc::PromptDenied(_) => true,
     })
   }
+
+  pub fn remove_granted(&mut self, desc: &TAllowDesc) {
+    self.inner.retain(|v| match v {
+      UnaryPermissionDesc::Granted(v) => v != desc,
+      UnaryPermissionDesc::FlagDenied(_)
+      | UnaryPermissionDesc::FlagIgnored(_)
+      | UnaryPermissionDesc::PromptDenied(_) => true,
+    })
+  }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -904,6 +1004,9 @@ pub struct UnaryPermission<TAllowDesc: AllowDescriptor> {
   flag_ignored_global: bool,
   prompt_denied_global: bool,
   descriptors: UnaryPermissionDescriptors<TAllowDesc>,
+  /// Grants made with `Deno.permissions.request()` which lapse at the given
+  /// time, with `None` for the whole kind.
+  expiring: Vec<(Option<TAllowDesc>, Instant)>,
   prompt: bool,
 }
 
@@ -915,6 +1018,7 @@ impl<TAllowDesc: AllowDescriptor> Default for UnaryPermission<TAllowDesc> {
       flag_ignored_global: Default::default(),
       prompt_denied_global: Default::default(),
       descriptors: Default::default(),
+      expiring: Default::default(),
       prompt: Default::default(),
     }
   }
@@ -928,6 +1032,7 @@ impl<TAllowDesc: AllowDescriptor> Clone for UnaryPermission<TAllowDesc> {
       flag_ignored_global: self.flag_ignored_global,
       prompt_denied_global: self.prompt_denied_global,
       descriptors: self.descriptors.clone(),
+      expiring: self.expiring.clone(),
       prompt: self.prompt,
     }
   }
@@ -951,9 +1056,64 @@ impl<
       && !self.prompt_denied_global
       && !self.flag_ignored_global
       && !self.descriptors.has_any_denied_or_ignored()
+      && self.expiring.is_empty()
       && !has_broker()
   }
 
+  /// The descriptors that were granted, in order.
+  fn granted_descriptors(&self) -> impl Iterator<Item = &TAllowDesc> {
+    self.descriptors.iter().filter_map(|item| match item {
+      UnaryPermissionDesc::Granted(desc) => Some(desc),
+      _ => None,
+    })
+  }
+
+  fn has_expired(&self) -> bool {
+    let now = Instant::now();
+    self.expiring.iter().any(|(_, at)| *at <= now)
+  }
+
+  /// Drops the grants whose expiry has passed.
+  fn prune_expired(&mut self) {
+    if self.has_expired() {
+      self.remove_expired();
+      bump_permissions_revision();
+    }
+  }
+
+  fn remove_expired(&mut self) {
+    let now = Instant::now();
+    let (expired, expiring) = std::mem::take(&mut self.expiring)
+      .into_iter()
+      .partition::<Vec<_>, _>(|(_, at)| *at <= now);
+    self.expiring = expiring;
+    for (desc, _) in expired {
+      self.remove_grant(desc.as_ref());
+    }
+  }
+
+  fn remove_grant(&mut self, desc: Option<&TAllowDesc>) {
+    match desc {
+      Some(desc) => self.descriptors.remove_granted(desc),
+      None => self.granted_global = false,
+    }
+  }
+
+  /// The time at which `desc` stops being granted, when it is only granted by
+  /// grants that lapse.
+  fn grant_expiry(&self, desc: &TAllowDesc) -> Option<Instant> {
+    let earliest = self.expiring.iter().map(|(_, at)| *at).min()?;
+    let mut permanent = self.clone();
+    for (desc, _) in std::mem::take(&mut permanent.expiring) {
+      permanent.remove_grant(desc.as_ref());
+    }
+    let query = TAllowDesc::QueryDesc::from_allow(desc);
+    match permanent.query_desc(Some(&query), AllowPartial::TreatAsDenied) {
+      PermissionState::Granted => None,
+      _ => Some(earliest),
+    }
+  }
+
   pub fn check_all_api(
     &mut self,
     api_name: Option<&str>,
@@ -972,6 +1132,7 @@ impl<
     assert_non_partial: bool,
     api_name: Option<&str>,
   ) -> Result<(), PermissionDeniedError> {
+    self.prune_expired();
     let (result, prompted, is_allow_all) = self
       .query_desc(desc, AllowPartial::from(!assert_non_partial))
       .check(
@@ -983,6 +1144,13 @@ impl<
       );
     if prompted {
       if result.is_ok() {
+        let flag_name = TAllowDesc::QueryDesc::flag_name();
+        match desc {
+          Some(desc) if !is_allow_all => {
+            notify_prompt_grant(flag_name, Some(desc.flag_value().as_ref()));
+          }
+          _ => notify_prompt_grant(flag_name, None),
+        }
         if is_allow_all {
           self.insert_granted(None);
         } else {
@@ -992,6 +1160,21 @@ impl<
         self.insert_prompt_denied(desc.map(|d| d.as_deny()));
       }
     }
+    if AUDIT_FILE.get().is_some() {
+      let audit_result = match &result {
+        Ok(()) => AuditResult::Granted,
+        Err(err) if err.state == PermissionState::Ignored => {
+          AuditResult::Ignored
+        }
+        Err(_) => AuditResult::Denied,
+      };
+      write_audit_check(
+        TAllowDesc::QueryDesc::flag_name(),
+        desc.map(|d| d.display_name()),
+        audit_result,
+        prompted,
+      );
+    }
     result
   }
 
@@ -1000,6 +1183,12 @@ impl<
     desc: Option<&TAllowDesc::QueryDesc<'_>>,
     allow_partial: AllowPartial,
   ) -> PermissionState {
+    // queries can't prune, so lapsed grants are disregarded on a copy
+    if self.has_expired() {
+      let mut current = self.clone();
+      current.remove_expired();
+      return current.query_desc(desc, allow_partial);
+    }
     if let Some(state) =
       self.query_allowed_desc_for_exact_match(desc, allow_partial)
     {
@@ -1079,13 +1268,21 @@ impl<
     }
   }
 
+  /// Prompts for the permission unless it is granted already. A permission
+  /// granted at the prompt lapses at `expires_at`, if given.
   fn request_desc(
     &mut self,
     desc: Option<&TAllowDesc::QueryDesc<'_>>,
+    expires_at: Option<Instant>,
   ) -> PermissionState {
+    self.prune_expired();
     let state = self.query_desc(desc, AllowPartial::TreatAsPartialGranted);
     if state == PermissionState::Granted {
-      self.insert_granted(desc);
+      // a grant which lapses must not be made permanent by requesting it
+      // again
+      if self.expiring.is_empty() {
+        self.insert_granted(desc);
+      }
       return state;
     }
     if state != PermissionState::Prompt {
@@ -1112,7 +1309,12 @@ impl<
       true,
     ) {
       PromptResponse::Allow => {
-        self.insert_granted(desc);
+        if self.insert_granted(desc)
+          && let Some(expires_at) = expires_at
+        {
+          let desc = desc.and_then(|d| d.as_allow());
+          self.expiring.push((desc, expires_at));
+        }
         PermissionState::Granted
       }
       PromptResponse::Deny => {
@@ -1121,6 +1323,9 @@ impl<
       }
       PromptResponse::AllowAll => {
         self.insert_granted(None);
+        if let Some(expires_at) = expires_at {
+          self.expiring.push((None, expires_at));
+        }
         PermissionState::Granted
       }
     }
@@ -1130,6 +1335,7 @@ impl<
     &mut self,
     desc: Option<&TAllowDesc::QueryDesc<'_>>,
   ) -> PermissionState {
+    self.prune_expired();
     match desc {
       Some(desc) => {
         self.descriptors.revoke_granted(desc);
@@ -1142,6 +1348,7 @@ impl<
         self.descriptors.revoke_all_granted();
       }
     }
+    bump_permissions_revision();
     self.query_desc(desc, AllowPartial::TreatAsPartialGranted)
   }
 
@@ -1203,6 +1410,7 @@ impl<
       }
       None => *list_global = true,
     }
+    bump_permissions_revision();
   }
 
   fn create_child_permissions<E>(
@@ -1214,7 +1422,9 @@ impl<
     ChildPermissionError: From<E>,
   {
     let mut perms = Self::default();
+    self.prune_expired();
 
+    // grants which lapse in this scope lapse in the child too
     match flag {
       ChildUnaryPermissionArg::Inherit => {
         perms.clone_from(self);
@@ -1224,6 +1434,11 @@ impl<
           return Err(ChildPermissionError::Escalation);
         }
         perms.granted_global = true;
+        if let Some((_, expires_at)) =
+          self.expiring.iter().find(|(desc, _)| desc.is_none())
+        {
+          perms.expiring.push((None, *expires_at));
+        }
       }
       ChildUnaryPermissionArg::NotGranted => {}
       ChildUnaryPermissionArg::GrantedList(granted_list) => {
@@ -1235,6 +1450,9 @@ impl<
           {
             return Err(ChildPermissionError::Escalation);
           }
+          if let Some(expires_at) = self.grant_expiry(&desc) {
+            perms.expiring.push((Some(desc.clone()), expires_at));
+          }
           perms.descriptors.insert(UnaryPermissionDesc::Granted(desc));
         }
       }
@@ -1266,11 +1484,13 @@ pub struct PathQueryDescriptor<'a> {
   /// Custom requested display name when differs from resolved.
   requested: Option<String>,
   is_windows_device_path: bool,
+  /// Set when created from an allow or deny descriptor that is a glob.
+  glob: Option<glob::Pattern>,
 }
 
 impl PartialEq for PathQueryDescriptor<'_> {
   fn eq(&self, other: &Self) -> bool {
-    self.path == other.path
+    self.path == other.path && self.glob == other.glob
   }
 }
 
@@ -1278,7 +1498,7 @@ impl Eq for PathQueryDescriptor<'_> {}
 
 impl PartialEq<PathDescriptor> for PathQueryDescriptor<'_> {
   fn eq(&self, other: &PathDescriptor) -> bool {
-    self.path == other.path
+    self.path == other.path && self.glob == other.glob
   }
 }
 
@@ -1313,6 +1533,7 @@ impl<'a> PathQueryDescriptor<'a> {
       path,
       requested,
       is_windows_device_path,
+      glob: None,
     })
   }
 
@@ -1332,6 +1553,7 @@ impl<'a> PathQueryDescriptor<'a> {
       path,
       requested: None,
       is_windows_device_path,
+      glob: None,
     }
   }
 
@@ -1343,7 +1565,20 @@ impl<'a> PathQueryDescriptor<'a> {
   }
 
   pub fn starts_with(&self, base: &PathDescriptor) -> bool {
-    self.path.starts_with(&base.path)
+    match (&base.glob, &self.glob) {
+      (None, _) => self.path.starts_with(&base.path),
+      (Some(base_glob), None) => {
+        base_glob.matches_path_with(&self.path, GLOB_MATCH_OPTIONS)
+      }
+      (Some(base_glob), Some(glob)) => base_glob == glob,
+    }
+  }
+
+  /// Whether this is the directory the literal components of the glob
+  /// descriptor `base` resolve to, which read access is granted along with
+  /// the matching paths, so that their directory can be listed.
+  pub fn is_glob_root_of(&self, base: &PathDescriptor) -> bool {
+    base.glob.is_some() && self.glob.is_none() && self.path == base.path
   }
 
   pub fn display_name(&self) -> Cow<'_, str> {
@@ -1358,6 +1593,7 @@ impl<'a> PathQueryDescriptor<'a> {
       path: self.path.to_path_buf(),
       requested: self.requested.clone(),
       is_windows_device_path: self.is_windows_device_path,
+      glob: self.glob.clone(),
     }
   }
 
@@ -1366,6 +1602,7 @@ impl<'a> PathQueryDescriptor<'a> {
       path: self.path.into_owned(),
       requested: self.requested,
       is_windows_device_path: self.is_windows_device_path,
+      glob: self.glob,
     }
   }
 
@@ -1397,6 +1634,10 @@ impl QueryDescriptor for ReadQueryDescriptor<'_> {
     self.0.display_name()
   }
 
+  fn flag_value(&self) -> Cow<'_, str> {
+    self.0.path.to_string_lossy()
+  }
+
   fn from_allow(allow: &Self::AllowDesc) -> Self {
     allow.0.as_query_descriptor().into_read()
   }
@@ -1423,7 +1664,7 @@ impl QueryDescriptor for ReadQueryDescriptor<'_> {
   }
 
   fn matches_allow(&self, other: &Self::AllowDesc) -> bool {
-    self.0.starts_with(&other.0)
+    self.0.starts_with(&other.0) || self.0.is_glob_root_of(&other.0)
   }
 
   fn matches_deny(&self, other: &Self::DenyDesc) -> bool {
@@ -1445,15 +1686,20 @@ impl QueryDescriptor for ReadQueryDescriptor<'_> {
 
 #[derive(Clone, Debug)]
 pub struct PathDescriptor {
+  /// The resolved path, or for a glob the directory its literal leading
+  /// components resolve to.
   path: PathBuf,
   /// Custom requested display name when differs from resolved.
   requested: Option<String>,
   is_windows_device_path: bool,
+  /// The absolute pattern paths are matched against, for descriptors
+  /// like `./src/**/*.ts`.
+  glob: Option<glob::Pattern>,
 }
 
 impl PartialEq for PathDescriptor {
   fn eq(&self, other: &Self) -> bool {
-    self.path == other.path
+    self.path == other.path && self.glob == other.glob
   }
 }
 
@@ -1462,7 +1708,42 @@ impl Eq for PathDescriptor {}
 impl Hash for PathDescriptor {
   fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
     self.path.hash(state);
+    self.glob.hash(state);
+  }
+}
+
+/// `*` does not match across directories, while `**` does.
+const GLOB_MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+  case_sensitive: true,
+  require_literal_separator: true,
+  require_literal_leading_dot: false,
+};
+
+/// Whether a path component of a descriptor is a glob pattern.
+pub fn is_glob_component(component: &std::ffi::OsStr) -> bool {
+  component
+    .as_encoded_bytes()
+    .iter()
+    .any(|b| matches!(b, b'*' | b'?' | b'['))
+}
+
+/// Whether `path` is granted by one of `granted` only because it matches a
+/// glob. Such paths are checked again once symlinks are resolved, so that a
+/// `.ts` symlink matching `src/**/*.ts` doesn't grant access to its target.
+fn is_granted_by_glob_only<'a>(
+  granted: impl Iterator<Item = &'a PathDescriptor>,
+  path: &PathQueryDescriptor,
+) -> bool {
+  let mut by_glob = false;
+  for desc in granted {
+    if path.starts_with(desc) {
+      if desc.glob.is_none() {
+        return false;
+      }
+      by_glob = true;
+    }
   }
+  by_glob
 }
 
 impl PathDescriptor {
@@ -1473,6 +1754,60 @@ impl PathDescriptor {
     PathQueryDescriptor::new(sys, path).map(|p| p.into_descriptor())
   }
 
+  /// Parses a descriptor given on the command line or in the config file,
+  /// which is a glob pattern when one of its components is, see
+  /// [`is_glob_component`]. Components before the first one with a pattern
+  /// character are resolved like a path and matched literally.
+  pub fn new_allowing_glob(
+    sys: &impl sys_traits::EnvCurrentDir,
+    text: &str,
+  ) -> Result<Self, PathResolveError> {
+    // prefixes like `\\?\C:` are never patterns
+    let is_pattern = |component: &Component| match component {
+      Component::Normal(name) => is_glob_component(name),
+      _ => false,
+    };
+    let path = Path::new(text);
+    if !path.components().any(|component| is_pattern(&component)) {
+      return Self::new(sys, Cow::Borrowed(path));
+    }
+    let mut literal = PathBuf::new();
+    let mut rest = PathBuf::new();
+    for component in path.components() {
+      if rest.as_os_str().is_empty() && !is_pattern(&component) {
+        literal.push(component);
+      } else {
+        rest.push(component);
+      }
+    }
+    if literal.as_os_str().is_empty() {
+      literal.push(".");
+    }
+    let root = PathQueryDescriptor::new(sys, Cow::Owned(literal))?;
+    // the resolved root is matched literally, even if it happens to contain
+    // pattern characters
+    let root_text = root.path.to_string_lossy();
+    let separator = if root_text.ends_with(std::path::is_separator) {
+      ""
+    } else {
+      std::path::MAIN_SEPARATOR_STR
+    };
+    let pattern = format!(
+      "{}{}{}",
+      glob::Pattern::escape(&root_text),
+      separator,
+      rest.to_string_lossy()
+    );
+    let glob =
+      glob::Pattern::new(&pattern).map_err(PathResolveError::InvalidGlob)?;
+    Ok(Self {
+      path: root.path.into_owned(),
+      requested: Some(text.to_string()),
+      is_windows_device_path: false,
+      glob: Some(glob),
+    })
+  }
+
   pub fn new_known_cwd(path: Cow<'_, Path>, cwd: &Path) -> Self {
     let path_bytes = path.as_os_str().as_encoded_bytes();
     let is_windows_device_path = cfg!(windows)
@@ -1494,6 +1829,7 @@ impl PathDescriptor {
       path: path.into_owned(),
       requested: display,
       is_windows_device_path,
+      glob: None,
     }
   }
 
@@ -1517,6 +1853,7 @@ impl PathDescriptor {
       path: Cow::Owned(self.path.clone()),
       requested: self.requested.clone(),
       is_windows_device_path: self.is_windows_device_path,
+      glob: self.glob.clone(),
     }
   }
 
@@ -1538,7 +1875,13 @@ impl PathDescriptor {
 
   fn cmp_allow_allow(&self, other: &PathDescriptor) -> Ordering {
     if self.path == other.path {
-      Ordering::Equal
+      // a glob only grants part of its root, so goes first
+      match (&self.glob, &other.glob) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+      }
     } else if other.path.starts_with(&self.path) {
       Ordering::Greater
     } else if self.path.starts_with(&other.path) {
@@ -1600,6 +1943,10 @@ impl QueryDescriptor for WriteQueryDescriptor<'_> {
     self.0.display_name()
   }
 
+  fn flag_value(&self) -> Cow<'_, str> {
+    self.0.path.to_string_lossy()
+  }
+
   fn from_allow(allow: &Self::AllowDesc) -> Self {
     WriteQueryDescriptor(allow.0.as_query_descriptor())
   }
@@ -2423,6 +2770,9 @@ pub enum PathResolveError {
   #[class(generic)]
   #[error("Empty path is not allowed")]
   EmptyPath,
+  #[class(generic)]
+  #[error("Invalid path pattern: {0}")]
+  InvalidGlob(#[source] glob::PatternError),
 }
 
 impl PathResolveError {
@@ -2431,14 +2781,14 @@ impl PathResolveError {
       Self::CwdResolve(e) | Self::Canonicalize(e) | Self::NotFound(e) => {
         e.kind()
       }
-      Self::EmptyPath => std::io::ErrorKind::InvalidData,
+      Self::EmptyPath | Self::InvalidGlob(_) => std::io::ErrorKind::InvalidData,
     }
   }
 
   pub fn into_io_error(self) -> std::io::Error {
     match self {
       Self::CwdResolve(e) | Self::Canonicalize(e) | Self::NotFound(e) => e,
-      PathResolveError::EmptyPath => {
+      PathResolveError::EmptyPath | PathResolveError::InvalidGlob(_) => {
         std::io::Error::new(self.kind(), format!("{}", self))
       }
     }
@@ -2464,6 +2814,7 @@ impl<'a> RunQueryDescriptor<'a> {
           path: Cow::Owned(resolved),
           requested: Some(requested.to_string()),
           is_windows_device_path: false,
+          glob: None,
         })),
         Err(_) => Ok(RunQueryDescriptor::Name(requested.to_string())),
       }
@@ -2486,6 +2837,13 @@ impl QueryDescriptor for RunQueryDescriptor<'_> {
     }
   }
 
+  fn flag_value(&self) -> Cow<'_, str> {
+    match self {
+      RunQueryDescriptor::Path(path) => path.path.to_string_lossy(),
+      RunQueryDescriptor::Name(name) => Cow::Borrowed(name),
+    }
+  }
+
   fn from_allow(allow: &Self::AllowDesc) -> Self {
     RunQueryDescriptor::Path(allow.0.as_query_descriptor())
   }
@@ -2741,6 +3099,7 @@ impl<'a> SpecialFilePathQueryDescriptor<'a> {
       is_windows_device_path,
       path,
       requested,
+      glob: _,
     } = path;
     // On Linux, /proc may contain magic links that we don't want to resolve
     let is_linux_special_path = cfg!(target_os = "linux")
@@ -2789,9 +3148,8 @@ impl SysDescriptor {
       "hostname" | "inspector" | "osRelease" | "osUptime" | "loadavg"
       | "networkInterfaces" | "systemMemoryInfo" | "uid" | "gid" | "cpus"
       | "homedir" | "getegid" | "statfs" | "getPriority" | "setPriority"
-      | "userInfo" | "setegid" | "seteuid" | "setgid" | "setuid" => {
-        Ok(Self(kind))
-      }
+      | "userInfo" | "setegid" | "seteuid" | "setgid" | "setuid"
+      | "icmpPing" => Ok(Self(kind)),
 
       // the underlying permission check changed to `userInfo` to better match the API,
       // alias this to avoid breaking existing projects with `--allow-sys=username`
@@ -2897,6 +3255,10 @@ impl QueryDescriptor for FfiQueryDescriptor<'_> {
     self.0.display_name()
   }
 
+  fn flag_value(&self) -> Cow<'_, str> {
+    self.0.path.to_string_lossy()
+  }
+
   fn from_allow(allow: &Self::AllowDesc) -> Self {
     allow.0.as_query_descriptor().into_ffi()
   }
@@ -2974,7 +3336,7 @@ impl UnaryPermission<ReadDescriptor> {
     &mut self,
     path: Option<&ReadQueryDescriptor>,
   ) -> PermissionState {
-    self.request_desc(path)
+    self.request_desc(path, None)
   }
 
   pub fn revoke(
@@ -3019,7 +3381,7 @@ impl UnaryPermission<WriteDescriptor> {
     &mut self,
     path: Option<&WriteQueryDescriptor>,
   ) -> PermissionState {
-    self.request_desc(path)
+    self.request_desc(path, None)
   }
 
   pub fn revoke(
@@ -3075,7 +3437,7 @@ impl UnaryPermission<NetDescriptor> {
   }
 
   pub fn request(&mut self, host: Option<&NetDescriptor>) -> PermissionState {
-    self.request_desc(host)
+    self.request_desc(host, None)
   }
 
   pub fn revoke(&mut self, host: Option<&NetDescriptor>) -> PermissionState {
@@ -3114,7 +3476,7 @@ impl UnaryPermission<ImportDescriptor> {
     &mut self,
     host: Option<&ImportDescriptor>,
   ) -> PermissionState {
-    self.request_desc(host)
+    self.request_desc(host, None)
   }
 
   pub fn revoke(&mut self, host: Option<&ImportDescriptor>) -> PermissionState {
@@ -3159,6 +3521,7 @@ impl UnaryPermission<EnvDescriptor> {
       env
         .map(|env| EnvQueryDescriptor::new(Cow::Borrowed(env)))
         .as_ref(),
+      None,
     )
   }
 
@@ -3203,7 +3566,7 @@ impl UnaryPermission<SysDescriptor> {
   }
 
   pub fn request(&mut self, kind: Option<&SysDescriptor>) -> PermissionState {
-    self.request_desc(kind)
+    self.request_desc(kind, None)
   }
 
   pub fn revoke(&mut self, kind: Option<&SysDescriptor>) -> PermissionState {
@@ -3242,7 +3605,7 @@ impl UnaryPermission<AllowRunDescriptor> {
     &mut self,
     cmd: Option<&RunQueryDescriptor>,
   ) -> PermissionState {
-    self.request_desc(cmd)
+    self.request_desc(cmd, None)
   }
 
   pub fn revoke(
@@ -3293,7 +3656,7 @@ impl UnaryPermission<FfiDescriptor> {
     &mut self,
     path: Option<&FfiQueryDescriptor>,
   ) -> PermissionState {
-    self.request_desc(path)
+    self.request_desc(path, None)
   }
 
   pub fn revoke(
@@ -3771,6 +4134,7 @@ impl PermissionCheckError {
 pub struct PermissionsContainer {
   descriptor_parser: Arc<dyn PermissionDescriptorParser>,
   inner: Arc<Mutex<Permissions>>,
+  checked_path_cache: Arc<Mutex<CheckedPathCache>>,
 }
 
 impl PermissionsContainer {
@@ -3781,14 +4145,12 @@ impl PermissionsContainer {
     Self {
       descriptor_parser,
       inner: Arc::new(Mutex::new(perms)),
+      checked_path_cache: Default::default(),
     }
   }
 
   pub fn deep_clone(&self) -> PermissionsContainer {
-    Self {
-      descriptor_parser: self.descriptor_parser.clone(),
-      inner: Arc::new(Mutex::new(self.inner.lock().clone())),
-    }
+    Self::new(self.descriptor_parser.clone(), self.inner.lock().clone())
   }
 
   pub fn allow_all(
@@ -3964,6 +4326,51 @@ impl PermissionsContainer {
     access_kind: OpenAccessKind,
     blind_requested: Option<&str>,
     api_name: Option<&str>,
+  ) -> Result<CheckedPath<'a>, PermissionCheckError> {
+    let is_cacheable = {
+      let inner = self.inner.lock();
+      // nothing to save when all is granted, and grants which lapse are only
+      // noticed when checking
+      !inner.all_granted()
+        && inner.read.expiring.is_empty()
+        && inner.write.expiring.is_empty()
+    };
+    // every check is recorded when auditing and asks the broker, and relative
+    // paths are resolved against the cwd, which may change
+    if !is_cacheable
+      || path.is_relative()
+      || access_kind.is_write()
+      || blind_requested.is_some()
+      || AUDIT_FILE.get().is_some()
+      || has_broker()
+    {
+      return self.check_open_uncached(
+        path,
+        access_kind,
+        blind_requested,
+        api_name,
+      );
+    }
+    if self.checked_path_cache.lock().contains(&path, access_kind) {
+      let path = PathQueryDescriptor::new_known_absolute(path);
+      return self.resolve_checked_path(path, access_kind, api_name);
+    }
+    let checked =
+      self.check_open_uncached(path.clone(), access_kind, None, api_name)?;
+    self
+      .checked_path_cache
+      .lock()
+      .insert(path.into_owned(), access_kind);
+    Ok(checked)
+  }
+
+  #[inline(always)]
+  fn check_open_uncached<'a>(
+    &self,
+    path: Cow<'a, Path>,
+    access_kind: OpenAccessKind,
+    blind_requested: Option<&str>,
+    api_name: Option<&str>,
   ) -> Result<CheckedPath<'a>, PermissionCheckError> {
     let path = {
       let mut inner = self.inner.lock();
@@ -4014,7 +4421,18 @@ impl PermissionsContainer {
         }
       }
     };
+    self.resolve_checked_path(path, access_kind, api_name)
+  }
 
+  /// Resolves a path that matched the permissions, checking the file it
+  /// resolves to unless `access_kind` does not follow symlinks. When only a
+  /// glob granted the path, the file it resolves to has to be granted too.
+  fn resolve_checked_path<'a>(
+    &self,
+    path: PathQueryDescriptor<'a>,
+    access_kind: OpenAccessKind,
+    api_name: Option<&str>,
+  ) -> Result<CheckedPath<'a>, PermissionCheckError> {
     if access_kind.is_no_follow() {
       Ok(CheckedPath {
         path: PathWithRequested {
@@ -4024,7 +4442,39 @@ impl PermissionsContainer {
         canonicalized: false,
       })
     } else {
+      let (recheck_read, recheck_write) = {
+        let inner = self.inner.lock();
+        (
+          access_kind.is_read()
+            && !inner.read.is_allow_all()
+            && is_granted_by_glob_only(
+              inner.read.granted_descriptors().map(|desc| &desc.0),
+              &path,
+            ),
+          access_kind.is_write()
+            && !inner.write.is_allow_all()
+            && is_granted_by_glob_only(
+              inner.write.granted_descriptors().map(|desc| &desc.0),
+              &path,
+            ),
+        )
+      };
       let path = self.descriptor_parser.parse_special_file_descriptor(path)?;
+      if path.canonicalized && (recheck_read || recheck_write) {
+        let resolved = PathQueryDescriptor::new_known_absolute(Cow::Borrowed(
+          path.path.as_ref(),
+        ));
+        let mut inner = self.inner.lock();
+        if recheck_read {
+          inner
+            .read
+            .check(&resolved.clone().into_read(), api_name)
+            .map_err(ignored_to_not_found)?;
+        }
+        if recheck_write {
+          inner.write.check(&resolved.into_write(), api_name)?;
+        }
+      }
       self.check_special_file(path, api_name)
     }
   }
@@ -4425,8 +4875,9 @@ impl PermissionsContainer {
     &self,
     path: Option<&str>,
   ) -> Result<PermissionState, PathResolveError> {
-    let inner = self.inner.lock();
-    let permission = &inner.read;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.read;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4452,8 +4903,9 @@ impl PermissionsContainer {
     &self,
     path: Option<&str>,
   ) -> Result<PermissionState, PathResolveError> {
-    let inner = self.inner.lock();
-    let permission = &inner.write;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.write;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4479,8 +4931,9 @@ impl PermissionsContainer {
     &self,
     host: Option<&str>,
   ) -> Result<PermissionState, NetDescriptorParseError> {
-    let inner = self.inner.lock();
-    let permission = &inner.net;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.net;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4497,8 +4950,9 @@ impl PermissionsContainer {
 
   #[inline(always)]
   pub fn query_env(&self, var: Option<&str>) -> PermissionState {
-    let inner = self.inner.lock();
-    let permission = &inner.env;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.env;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return PermissionState::Granted;
     }
@@ -4510,8 +4964,9 @@ impl PermissionsContainer {
     &self,
     kind: Option<&str>,
   ) -> Result<PermissionState, SysDescriptorParseError> {
-    let inner = self.inner.lock();
-    let permission = &inner.sys;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.sys;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4530,8 +4985,9 @@ impl PermissionsContainer {
     &self,
     cmd: Option<&str>,
   ) -> Result<PermissionState, RunDescriptorParseError> {
-    let inner = self.inner.lock();
-    let permission = &inner.run;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.run;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4550,8 +5006,9 @@ impl PermissionsContainer {
     &self,
     path: Option<&str>,
   ) -> Result<PermissionState, PathResolveError> {
-    let inner = self.inner.lock();
-    let permission = &inner.ffi;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.ffi;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4577,8 +5034,9 @@ impl PermissionsContainer {
     &self,
     host: Option<&str>,
   ) -> Result<PermissionState, NetDescriptorParseError> {
-    let inner = self.inner.lock();
-    let permission = &inner.import;
+    let mut inner = self.inner.lock();
+    let permission = &mut inner.import;
+    permission.prune_expired();
     if permission.is_allow_all() {
       return Ok(PermissionState::Granted);
     }
@@ -4733,14 +5191,17 @@ impl PermissionsContainer {
   }
 
   // request
+  //
+  // A permission granted at the prompt lapses at `expires_at`, if given.
 
   #[inline(always)]
   pub fn request_read(
     &self,
     path: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, PathResolveError> {
     Ok(
-      self.inner.lock().read.request(
+      self.inner.lock().read.request_desc(
         path
           .map(|path| {
             Ok::<_, PathResolveError>(
@@ -4752,6 +5213,7 @@ impl PermissionsContainer {
           })
           .transpose()?
           .as_ref(),
+        expires_at,
       ),
     )
   }
@@ -4760,9 +5222,10 @@ impl PermissionsContainer {
   pub fn request_write(
     &self,
     path: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, PathResolveError> {
     Ok(
-      self.inner.lock().write.request(
+      self.inner.lock().write.request_desc(
         path
           .map(|path| {
             Ok::<_, PathResolveError>(
@@ -4774,6 +5237,7 @@ impl PermissionsContainer {
           })
           .transpose()?
           .as_ref(),
+        expires_at,
       ),
     )
   }
@@ -4782,34 +5246,47 @@ impl PermissionsContainer {
   pub fn request_net(
     &self,
     host: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, NetDescriptorParseError> {
     Ok(
-      self.inner.lock().net.request(
+      self.inner.lock().net.request_desc(
         match host {
           None => None,
           Some(h) => Some(self.descriptor_parser.parse_net_query(h)?),
         }
         .as_ref(),
+        expires_at,
       ),
     )
   }
 
   #[inline(always)]
-  pub fn request_env(&self, var: Option<&str>) -> PermissionState {
-    self.inner.lock().env.request(var)
+  pub fn request_env(
+    &self,
+    var: Option<&str>,
+    expires_at: Option<Instant>,
+  ) -> PermissionState {
+    self.inner.lock().env.request_desc(
+      var
+        .map(|var| EnvQueryDescriptor::new(Cow::Borrowed(var)))
+        .as_ref(),
+      expires_at,
+    )
   }
 
   #[inline(always)]
   pub fn request_sys(
     &self,
     kind: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, SysDescriptorParseError> {
     Ok(
-      self.inner.lock().sys.request(
+      self.inner.lock().sys.request_desc(
         kind
           .map(|kind| self.descriptor_parser.parse_sys_descriptor(kind))
           .transpose()?
           .as_ref(),
+        expires_at,
       ),
     )
   }
@@ -4818,13 +5295,15 @@ impl PermissionsContainer {
   pub fn request_run(
     &self,
     cmd: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, RunDescriptorParseError> {
     Ok(
-      self.inner.lock().run.request(
+      self.inner.lock().run.request_desc(
         cmd
           .map(|request| self.descriptor_parser.parse_run_query(request))
           .transpose()?
           .as_ref(),
+        expires_at,
       ),
     )
   }
@@ -4833,9 +5312,10 @@ impl PermissionsContainer {
   pub fn request_ffi(
     &self,
     path: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, PathResolveError> {
     Ok(
-      self.inner.lock().ffi.request(
+      self.inner.lock().ffi.request_desc(
         path
           .map(|path| {
             Ok::<_, PathResolveError>(
@@ -4847,6 +5327,7 @@ impl PermissionsContainer {
           })
           .transpose()?
           .as_ref(),
+        expires_at,
       ),
     )
   }
@@ -4855,9 +5336,10 @@ impl PermissionsContainer {
   pub fn request_import(
     &self,
     host: Option<&str>,
+    expires_at: Option<Instant>,
   ) -> Result<PermissionState, NetDescriptorParseError> {
     Ok(
-      self.inner.lock().import.request(
+      self.inner.lock().import.request_desc(
         match host {
           None => None,
           Some(h) => {
@@ -4865,6 +5347,7 @@ impl PermissionsContainer {
           }
         }
         .as_ref(),
+        expires_at,
       ),
     )
   }
@@ -5246,6 +5729,7 @@ pub fn is_standalone() -> bool {
 
 #[cfg(test)]
 mod tests {
+  use std::cell::RefCell;
   use std::net::Ipv4Addr;
 
   use fqdn::fqdn;
@@ -5260,6 +5744,11 @@ mod tests {
       ($($x:expr),*) => (vec![$($x.to_string()),*]);
   }
 
+  thread_local! {
+    /// The directory relative path queries are resolved against, when set.
+    static TEST_CWD: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+  }
+
   #[derive(Debug, Clone)]
   struct TestPermissionDescriptorParser;
 
@@ -5274,6 +5763,7 @@ mod tests {
         path,
         requested: None,
         is_windows_device_path: false,
+        glob: None,
       }
     }
   }
@@ -5352,10 +5842,18 @@ mod tests {
       &self,
       path: Cow<'a, Path>,
     ) -> Result<PathQueryDescriptor<'a>, PathResolveError> {
+      let (path, requested) = match TEST_CWD.with_borrow(Clone::clone) {
+        Some(cwd) if path.is_relative() => (
+          Cow::Owned(cwd.join(&path)),
+          Some(path.to_string_lossy().into_owned()),
+        ),
+        _ => (path, None),
+      };
       Ok(PathQueryDescriptor {
         path,
-        requested: None,
+        requested,
         is_windows_device_path: false,
+        glob: None,
       })
     }
 
@@ -6991,6 +7489,186 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_request_expiring() {
+    set_prompter(Box::new(TestPrompter));
+    let prompt_value = PERMISSION_PROMPT_STUB_VALUE_SETTER.lock();
+    let perms = PermissionsContainer::new(
+      Arc::new(TestPermissionDescriptorParser),
+      Permissions::none_with_prompt(),
+    );
+    let later = Instant::now() + std::time::Duration::from_secs(60);
+    prompt_value.set(true);
+    assert_eq!(
+      perms.request_net(Some("foo"), Some(later)).unwrap(),
+      PermissionState::Granted
+    );
+    // requesting it again does not make the grant permanent
+    assert_eq!(
+      perms.request_net(Some("foo:8000"), None).unwrap(),
+      PermissionState::Granted
+    );
+    assert_eq!(
+      perms.query_net(Some("foo:8000")).unwrap(),
+      PermissionState::Granted
+    );
+    let worker_perms = perms
+      .create_child_permissions(ChildPermissionsArg {
+        net: ChildUnaryPermissionArg::GrantedList(svec!["foo"]),
+        ..ChildPermissionsArg::none()
+      })
+      .unwrap();
+    assert_eq!(
+      worker_perms.inner.lock().net.expiring,
+      vec![(Some(NetDescriptor::parse_for_list("foo").unwrap()), later)]
+    );
+
+    assert_eq!(
+      perms.request_env(None, Some(Instant::now())),
+      PermissionState::Granted
+    );
+    assert_eq!(perms.query_env(None), PermissionState::Prompt);
+    prompt_value.set(false);
+    assert!(perms.check_env("HOME").is_err());
+    perms.inner.lock().net.expiring[0].1 = Instant::now();
+    assert_eq!(
+      perms.query_net(Some("foo:8000")).unwrap(),
+      PermissionState::Prompt
+    );
+  }
+
+  #[test]
+  fn test_query_all_expired() {
+    set_prompter(Box::new(TestPrompter));
+    let prompt_value = PERMISSION_PROMPT_STUB_VALUE_SETTER.lock();
+    let mut perms = PermissionsContainer::new(
+      Arc::new(TestPermissionDescriptorParser),
+      Permissions::none_with_prompt(),
+    );
+    let later = Instant::now() + std::time::Duration::from_secs(60);
+    prompt_value.set(true);
+    assert_eq!(
+      perms.request_run(None, Some(later)).unwrap(),
+      PermissionState::Granted
+    );
+    assert_eq!(
+      perms.request_read(None, Some(later)).unwrap(),
+      PermissionState::Granted
+    );
+    assert!(perms.query_run_all("Deno.run"));
+    assert!(perms.query_read_all());
+
+    perms.inner.lock().run.expiring[0].1 = Instant::now();
+    perms.inner.lock().read.expiring[0].1 = Instant::now();
+    assert!(!perms.query_run_all("Deno.run"));
+    assert!(!perms.query_read_all());
+  }
+
+  #[test]
+  fn test_checked_path_cache() {
+    set_prompter(Box::new(TestPrompter));
+    let perms = Permissions::from_options(
+      &TestPermissionDescriptorParser,
+      &PermissionsOptions {
+        allow_read: Some(svec!["/foo"]),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let perms = PermissionsContainer::new(
+      Arc::new(TestPermissionDescriptorParser),
+      perms,
+    );
+    let check = |path: &str| {
+      perms
+        .check_open(
+          Cow::Borrowed(Path::new(path)),
+          OpenAccessKind::Read,
+          Some("api"),
+        )
+        .map(|checked| checked.to_path_buf())
+    };
+    assert_eq!(check("/foo/bar").unwrap(), PathBuf::from("/foo/bar"));
+    assert_eq!(check("/foo/bar").unwrap(), PathBuf::from("/foo/bar"));
+    assert!(check("/baz").is_err());
+    assert_eq!(perms.checked_path_cache.lock().len, 1);
+
+    perms.revoke_read(Some("/foo")).unwrap();
+    assert!(check("/foo/bar").is_err());
+    assert_eq!(perms.checked_path_cache.lock().len, 0);
+  }
+
+  #[test]
+  fn test_checked_path_cache_chdir() {
+    set_prompter(Box::new(TestPrompter));
+    let perms = Permissions::from_options(
+      &TestPermissionDescriptorParser,
+      &PermissionsOptions {
+        allow_read: Some(svec!["/foo"]),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let perms = PermissionsContainer::new(
+      Arc::new(TestPermissionDescriptorParser),
+      perms,
+    );
+    let check = |path: &str| {
+      perms
+        .check_open(
+          Cow::Borrowed(Path::new(path)),
+          OpenAccessKind::Read,
+          Some("api"),
+        )
+        .map(|checked| checked.to_path_buf())
+    };
+    TEST_CWD.set(Some(PathBuf::from("/foo")));
+    assert_eq!(check("bar").unwrap(), PathBuf::from("/foo/bar"));
+    assert_eq!(check("bar").unwrap(), PathBuf::from("/foo/bar"));
+    // relative paths are not cached
+    assert_eq!(perms.checked_path_cache.lock().len, 0);
+    // the same relative path resolves elsewhere after changing the cwd
+    TEST_CWD.set(Some(PathBuf::from("/baz")));
+    assert!(check("bar").is_err());
+    TEST_CWD.set(None);
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_glob_checks_symlink_target() {
+    set_prompter(Box::new(TestPrompter));
+    let dir = std::env::temp_dir()
+      .join(format!("deno_permissions_glob_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("src/main.ts"), "").unwrap();
+    std::fs::write(dir.join("secret.key"), "").unwrap();
+    std::os::unix::fs::symlink(dir.join("secret.key"), dir.join("src/key.ts"))
+      .unwrap();
+    let dir = std::fs::canonicalize(dir).unwrap();
+
+    let parser =
+      RuntimePermissionDescriptorParser::new(sys_traits::impls::RealSys);
+    let perms = Permissions::from_options(
+      &parser,
+      &PermissionsOptions {
+        allow_read: Some(vec![format!("{}/src/**/*.ts", dir.display())]),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let perms = PermissionsContainer::new(Arc::new(parser), perms);
+    let check = |path: &Path, access_kind| {
+      perms.check_open(Cow::Borrowed(path), access_kind, Some("api"))
+    };
+    let link = dir.join("src/key.ts");
+    assert!(check(&dir.join("src/main.ts"), OpenAccessKind::Read).is_ok());
+    // the symlink itself matches, the file it points to doesn't
+    assert!(check(&link, OpenAccessKind::ReadNoFollow).is_ok());
+    assert!(check(&link, OpenAccessKind::Read).is_err());
+    assert!(check(&link, OpenAccessKind::Read).is_err());
+    std::fs::remove_dir_all(dir).unwrap();
+  }
+
   #[test]
   fn test_create_child_permissions_with_prompt() {
     set_prompter(Box::new(TestPrompter));
@@ -8172,6 +8850,73 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_read_glob_descriptors() {
+    use sys_traits::EnvCurrentDir;
+
+    let sys = sys_traits::impls::RealSys;
+    let cwd = sys.env_current_dir().unwrap();
+    let parse = |text: &str| {
+      PathDescriptor::new_allowing_glob(&sys, text)
+        .unwrap()
+        .into_read()
+    };
+    let query = |path: &str| {
+      PathQueryDescriptor::new_known_absolute(Cow::Owned(cwd.join(path)))
+        .into_read()
+    };
+
+    let nested = parse("./src/**/*.ts");
+    assert_eq!(nested.0.path, cwd.join("src"));
+    assert_eq!(nested.0.display_name(), "./src/**/*.ts");
+    assert!(query("src/main.ts").matches_allow(&nested));
+    assert!(query("src/a/b/c.ts").matches_allow(&nested));
+    assert!(!query("src/main.js").matches_allow(&nested));
+    assert!(!query("test/main.ts").matches_allow(&nested));
+    // the root can be listed, but not written to
+    assert!(query("src").matches_allow(&nested));
+    assert!(
+      !query("src")
+        .0
+        .into_write()
+        .matches_allow(&WriteDescriptor(nested.0.clone()))
+    );
+    assert!(!query("src").matches_deny(&nested));
+
+    let flat = parse("src/*.ts");
+    assert!(query("src/main.ts").matches_allow(&flat));
+    assert!(!query("src/a/main.ts").matches_allow(&flat));
+
+    let single = parse("src/?.ts");
+    assert!(single.0.glob.is_some());
+    assert!(query("src/a.ts").matches_allow(&single));
+    assert!(!query("src/ab.ts").matches_allow(&single));
+
+    // a query made from a glob descriptor only matches the same glob
+    let from_nested = ReadQueryDescriptor::from_allow(&nested);
+    assert!(from_nested.matches_allow(&nested));
+    assert!(!from_nested.matches_allow(&flat));
+    assert!(from_nested.matches_allow(&parse(".")));
+
+    // globs sharing a root are kept apart, ahead of the root itself
+    check_comparison(
+      &UnaryPermissionDesc::Granted(nested.clone()),
+      &UnaryPermissionDesc::Granted(parse("./src")),
+      Ordering::Less,
+    );
+    assert_ne!(
+      UnaryPermissionDesc::Granted(nested)
+        .cmp(&UnaryPermissionDesc::Granted(parse("./src/**/*.js"))),
+      Ordering::Equal
+    );
+
+    assert!(parse("./src").0.glob.is_none());
+    assert!(matches!(
+      PathDescriptor::new_allowing_glob(&sys, "./src/[*.ts"),
+      Err(PathResolveError::InvalidGlob(_))
+    ));
+  }
+
   #[test]
   fn test_cmp_write_descriptors() {
     let parser = TestPermissionDescriptorParser;
@@ -49,6 +49,9 @@ static MAYBE_BEFORE_PROMPT_CALLBACK: Lazy<Mutex<Option<PromptCallback>>> =
 static MAYBE_AFTER_PROMPT_CALLBACK: Lazy<Mutex<Option<PromptCallback>>> =
   Lazy::new(|| Mutex::new(None));
 
+static MAYBE_PROMPT_GRANT_CALLBACK: Lazy<Mutex<Option<PromptGrantCallback>>> =
+  Lazy::new(|| Mutex::new(None));
+
 pub(crate) static MAYBE_CURRENT_STACKTRACE: Lazy<
   Mutex<Option<GetFormattedStackFn>>,
 > = Lazy::new(|| Mutex::new(None));
@@ -88,8 +91,28 @@ pub fn set_prompter(prompter: Box<dyn PermissionPrompter>) {
   *PERMISSION_PROMPTER.lock() = prompter;
 }
 
+/// Sets a callback that is told about permissions granted at a prompt, for
+/// example to remember them for the next run.
+pub fn set_prompt_grant_callback(callback: PromptGrantCallback) {
+  *MAYBE_PROMPT_GRANT_CALLBACK.lock() = Some(callback);
+}
+
+pub(crate) fn notify_prompt_grant(
+  flag_name: &'static str,
+  value: Option<&str>,
+) {
+  if let Some(callback) = MAYBE_PROMPT_GRANT_CALLBACK.lock().as_mut() {
+    callback(flag_name, value);
+  }
+}
+
 pub type PromptCallback = Box<dyn FnMut() + Send + Sync>;
 
+/// Called with the flag name of a permission granted at a prompt and the
+/// value that grants it, or `None` when all of its kind were granted.
+pub type PromptGrantCallback =
+  Box<dyn FnMut(&'static str, Option<&str>) + Send + Sync>;
+
 pub type GetFormattedStackFn = Box<dyn Fn() -> Vec<String> + Send + Sync>;
 
 pub trait PermissionPrompter: Send + Sync {
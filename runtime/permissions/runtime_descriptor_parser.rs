@@ -65,18 +65,18 @@ impl<TSys: RuntimePermissionDescriptorParserSys + std::fmt::Debug>
     &self,
     text: &str,
   ) -> Result<ReadDescriptor, PathResolveError> {
-    Ok(ReadDescriptor(
-      self.parse_path_descriptor(Cow::Borrowed(Path::new(text)))?,
-    ))
+    Ok(ReadDescriptor(PathDescriptor::new_allowing_glob(
+      &self.sys, text,
+    )?))
   }
 
   fn parse_write_descriptor(
     &self,
     text: &str,
   ) -> Result<WriteDescriptor, PathResolveError> {
-    Ok(WriteDescriptor(
-      self.parse_path_descriptor(Cow::Borrowed(Path::new(text)))?,
-    ))
+    Ok(WriteDescriptor(PathDescriptor::new_allowing_glob(
+      &self.sys, text,
+    )?))
   }
 
   fn parse_net_descriptor(
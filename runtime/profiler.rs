@@ -0,0 +1,273 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! CPU profiles and heap snapshots of the main worker, enabled with
+//! `deno run --cpu-prof` and `--heap-prof`. They are taken over a local
+//! inspector session, like coverage, and written in the formats Chrome
+//! DevTools loads: `.cpuprofile` and `.heapsnapshot`. A profile is written
+//! when the program exits, and on Unix also whenever the process receives
+//! `SIGUSR2`, in which case the file name gets a sequence number.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::AtomicI32;
+
+use deno_core::InspectorSessionKind;
+use deno_core::JsRuntime;
+use deno_core::JsRuntimeInspector;
+use deno_core::LocalInspectorSession;
+use deno_core::parking_lot::Mutex;
+use deno_core::serde_json;
+
+static NEXT_MSG_ID: AtomicI32 = AtomicI32::new(0);
+
+fn next_msg_id() -> i32 {
+  NEXT_MSG_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Where the profiles are written.
+#[derive(Debug, Clone, Default)]
+pub struct ProfilerOptions {
+  pub cpu_prof: Option<PathBuf>,
+  pub heap_prof: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+struct ProfilerInner {
+  stop_msg_id: Option<i32>,
+  cpu_prof_path: Option<PathBuf>,
+  snapshot_msg_id: Option<i32>,
+  snapshot: Option<(PathBuf, BufWriter<File>)>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct ProfilerState(Arc<Mutex<ProfilerInner>>);
+
+impl ProfilerState {
+  fn callback(&self, msg: deno_core::InspectorMsg) {
+    let message: serde_json::Value = match serde_json::from_str(&msg.content) {
+      Ok(message) => message,
+      Err(_) => return,
+    };
+    let mut inner = self.0.lock();
+    let deno_core::InspectorMsgKind::Message(msg_id) = msg.kind else {
+      // the snapshot is streamed in chunks before the response
+      if message["method"] == "HeapProfiler.addHeapSnapshotChunk"
+        && let Some((path, out)) = inner.snapshot.as_mut()
+        && let Some(chunk) = message["params"]["chunk"].as_str()
+        && let Err(err) = out.write_all(chunk.as_bytes())
+      {
+        log::error!("Failed to write heap snapshot to {:?}: {}", path, err);
+        inner.snapshot = None;
+      }
+      return;
+    };
+    if inner.stop_msg_id == Some(msg_id) {
+      inner.stop_msg_id = None;
+      let Some(path) = inner.cpu_prof_path.take() else {
+        return;
+      };
+      if let Err(err) = write_json(&path, &message["result"]["profile"]) {
+        log::error!("Failed to write CPU profile to {:?}: {}", path, err);
+      }
+    } else if inner.snapshot_msg_id == Some(msg_id) {
+      inner.snapshot_msg_id = None;
+      if let Some((path, mut out)) = inner.snapshot.take()
+        && let Err(err) = out.flush()
+      {
+        log::error!("Failed to write heap snapshot to {:?}: {}", path, err);
+      }
+    }
+  }
+}
+
+#[allow(clippy::disallowed_methods)]
+fn create_file(path: &Path) -> std::io::Result<BufWriter<File>> {
+  Ok(BufWriter::new(File::create(path)?))
+}
+
+fn write_json(path: &Path, value: &serde_json::Value) -> std::io::Result<()> {
+  let mut out = create_file(path)?;
+  serde_json::to_writer(&mut out, value)?;
+  out.flush()
+}
+
+/// The path of the `n`th profile written on a signal, e.g.
+/// `app.1.cpuprofile` for `app.cpuprofile`.
+#[cfg_attr(not(unix), allow(dead_code))]
+fn numbered_path(path: &Path, n: u32) -> PathBuf {
+  let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+  match path.extension() {
+    Some(ext) => {
+      path.with_file_name(format!("{stem}.{n}.{}", ext.to_string_lossy()))
+    }
+    None => path.with_file_name(format!("{stem}.{n}")),
+  }
+}
+
+/// Unregisters the `SIGUSR2` handler once profiles are no longer written on
+/// the signal.
+#[cfg(unix)]
+struct SignalRegistration(u32);
+
+#[cfg(unix)]
+impl Drop for SignalRegistration {
+  fn drop(&mut self) {
+    deno_signals::unregister(libc::SIGUSR2, self.0);
+  }
+}
+
+pub struct Profiler {
+  options: ProfilerOptions,
+  state: ProfilerState,
+  session: LocalInspectorSession,
+  /// The number of profiles written on a signal.
+  #[cfg_attr(not(unix), allow(dead_code))]
+  written: u32,
+}
+
+impl Profiler {
+  pub fn new(js_runtime: &mut JsRuntime, options: ProfilerOptions) -> Self {
+    let state = ProfilerState::default();
+
+    js_runtime.maybe_init_inspector();
+    let insp = js_runtime.inspector();
+
+    let s = state.clone();
+    let callback = Box::new(move |message| s.callback(message));
+    let session = JsRuntimeInspector::create_local_session(
+      insp,
+      callback,
+      InspectorSessionKind::Blocking,
+    );
+
+    Self {
+      options,
+      state,
+      session,
+      written: 0,
+    }
+  }
+
+  pub fn start(&mut self) {
+    if self.options.cpu_prof.is_some() {
+      self
+        .session
+        .post_message::<()>(next_msg_id(), "Profiler.enable", None);
+      self.start_cpu_profile();
+    }
+  }
+
+  fn start_cpu_profile(&mut self) {
+    self
+      .session
+      .post_message::<()>(next_msg_id(), "Profiler.start", None);
+  }
+
+  fn write_profiles(
+    &mut self,
+    cpu_prof: Option<PathBuf>,
+    heap_prof: Option<PathBuf>,
+  ) {
+    if let Some(path) = cpu_prof {
+      let msg_id = next_msg_id();
+      let mut inner = self.state.0.lock();
+      inner.stop_msg_id = Some(msg_id);
+      inner.cpu_prof_path = Some(path);
+      drop(inner);
+      self
+        .session
+        .post_message::<()>(msg_id, "Profiler.stop", None);
+    }
+    if let Some(path) = heap_prof {
+      let out = match create_file(&path) {
+        Ok(out) => out,
+        Err(err) => {
+          log::error!("Failed to write heap snapshot to {:?}: {}", path, err);
+          return;
+        }
+      };
+      let msg_id = next_msg_id();
+      let mut inner = self.state.0.lock();
+      inner.snapshot_msg_id = Some(msg_id);
+      inner.snapshot = Some((path, out));
+      drop(inner);
+      self.session.post_message::<()>(
+        msg_id,
+        "HeapProfiler.takeHeapSnapshot",
+        None,
+      );
+    }
+  }
+
+  /// Writes the profiles, when the program exits.
+  pub fn stop(&mut self) {
+    let cpu_prof = self.options.cpu_prof.clone();
+    let heap_prof = self.options.heap_prof.clone();
+    self.write_profiles(cpu_prof, heap_prof);
+  }
+
+  /// Writes the profiles whenever the process receives `SIGUSR2`, continuing
+  /// the CPU profile afterwards. Never completes.
+  pub async fn write_on_signal(&mut self) {
+    #[cfg(unix)]
+    {
+      let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+      let id = deno_signals::register(
+        libc::SIGUSR2,
+        true,
+        Box::new(move || {
+          let _ = tx.send(());
+        }),
+      );
+      let Ok(id) = id else {
+        return std::future::pending().await;
+      };
+      let _registration = SignalRegistration(id);
+      while rx.recv().await.is_some() {
+        self.written += 1;
+        let n = self.written;
+        let cpu_prof = self
+          .options
+          .cpu_prof
+          .as_deref()
+          .map(|p| numbered_path(p, n));
+        let heap_prof = self
+          .options
+          .heap_prof
+          .as_deref()
+          .map(|p| numbered_path(p, n));
+        let continue_cpu_profile = cpu_prof.is_some();
+        self.write_profiles(cpu_prof, heap_prof);
+        if continue_cpu_profile {
+          self.start_cpu_profile();
+        }
+      }
+    }
+    std::future::pending().await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn numbers_profile_paths() {
+    assert_eq!(
+      numbered_path(Path::new("/tmp/app.cpuprofile"), 1),
+      Path::new("/tmp/app.1.cpuprofile")
+    );
+    assert_eq!(
+      numbered_path(Path::new("Heap.20250101.120000.42.heapsnapshot"), 2),
+      Path::new("Heap.20250101.120000.42.2.heapsnapshot")
+    );
+    assert_eq!(
+      numbered_path(Path::new("profile"), 3),
+      Path::new("profile.3")
+    );
+  }
+}
@@ -0,0 +1,166 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Tracking of where the resources of the main worker were created, enabled
+//! with `deno run --trace-leaks`. After every op call the resource table is
+//! compared with the resources seen before, and new resources are recorded
+//! with the op that created them and the last JavaScript stack captured for
+//! an op with a `stack_trace` argument on this thread. The resources still
+//! open are reported when the program exits.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+use deno_core::OpMetricsEvent;
+use deno_core::OpMetricsFactoryFn;
+use deno_core::OpStackTraceCallback;
+use deno_core::ResourceId;
+use deno_core::error::JsStackFrame;
+use deno_core::error::NoAnsiColors;
+use deno_core::error::format_frame;
+
+/// Where an open resource was created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceOrigin {
+  /// The type name of the resource, e.g. `fsFile`.
+  pub name: String,
+  /// The op that created the resource.
+  pub op: &'static str,
+  /// The user code frames of the stack the resource was created from.
+  pub stack: Vec<String>,
+}
+
+/// The open resources of the main worker. This is a global, rather than
+/// state of the worker, so that it can be reported from exit hooks.
+static OPEN_RESOURCES: Mutex<BTreeMap<ResourceId, ResourceOrigin>> =
+  Mutex::new(BTreeMap::new());
+
+thread_local! {
+  static LAST_STACK: RefCell<Vec<JsStackFrame>> =
+    const { RefCell::new(Vec::new()) };
+}
+
+/// An op stack trace callback recording the stack for resources created by
+/// the op, before passing it on to `next`.
+pub fn create_stack_trace_callback(
+  next: Option<OpStackTraceCallback>,
+) -> OpStackTraceCallback {
+  Box::new(move |stack: Vec<JsStackFrame>| {
+    LAST_STACK.with(|last| *last.borrow_mut() = stack.clone());
+    if let Some(next) = &next {
+      next(stack);
+    }
+  })
+}
+
+fn user_frames(stack: &[JsStackFrame]) -> Vec<String> {
+  stack
+    .iter()
+    .filter(|frame| {
+      !frame
+        .file_name
+        .as_deref()
+        .is_some_and(|file_name| file_name.starts_with("ext:"))
+    })
+    .map(|frame| format_frame::<NoAnsiColors>(frame, None))
+    .collect()
+}
+
+/// Updates `open` with the resources currently in the table, attributing new
+/// ones to `op`.
+fn update_open_resources<'a>(
+  open: &mut BTreeMap<ResourceId, ResourceOrigin>,
+  resources: impl Iterator<Item = (ResourceId, std::borrow::Cow<'a, str>)>,
+  op: &'static str,
+  stack: impl Fn() -> Vec<String>,
+) {
+  let mut current = BTreeMap::new();
+  for (rid, name) in resources {
+    let origin = match open.remove(&rid) {
+      // the same rid can be reused for a resource of another type
+      Some(origin) if origin.name == name => origin,
+      _ => ResourceOrigin {
+        name: name.into_owned(),
+        op,
+        stack: stack(),
+      },
+    };
+    current.insert(rid, origin);
+  }
+  *open = current;
+}
+
+/// Records the creation of resources after each completed op call.
+pub fn create_resource_tracking_factory() -> OpMetricsFactoryFn {
+  Box::new(|_, _, _| {
+    Some(Rc::new(|op: &deno_core::_ops::OpCtx, event, _source| {
+      if !matches!(
+        event,
+        OpMetricsEvent::Completed | OpMetricsEvent::CompletedAsync
+      ) {
+        return;
+      }
+      // the op may still hold the state, in which case the next completed
+      // op picks up its resources
+      let Ok(state) = op.state.try_borrow() else {
+        return;
+      };
+      let mut open = OPEN_RESOURCES.lock().unwrap();
+      update_open_resources(
+        &mut open,
+        state.resource_table.names(),
+        op.decl().name,
+        || LAST_STACK.with(|last| user_frames(&last.borrow())),
+      );
+    }))
+  })
+}
+
+/// Takes the resources that are still open, with where they were created.
+pub fn take_open_resources() -> BTreeMap<ResourceId, ResourceOrigin> {
+  std::mem::take(&mut OPEN_RESOURCES.lock().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tracks_resource_origins() {
+    let mut open = BTreeMap::new();
+    let stack = || vec!["file:///main.ts:1:1".to_string()];
+    update_open_resources(
+      &mut open,
+      [(0, "stdin".into()), (3, "fsFile".into())].into_iter(),
+      "op_fs_open_async",
+      stack,
+    );
+    assert_eq!(open[&3].op, "op_fs_open_async");
+    assert_eq!(open[&3].stack, ["file:///main.ts:1:1"]);
+
+    // resources seen before keep their origin
+    update_open_resources(
+      &mut open,
+      [
+        (0, "stdin".into()),
+        (3, "fsFile".into()),
+        (4, "tcpStream".into()),
+      ]
+      .into_iter(),
+      "op_net_connect_tcp",
+      Vec::new,
+    );
+    assert_eq!(open[&3].op, "op_fs_open_async");
+    assert_eq!(open[&4].op, "op_net_connect_tcp");
+
+    // closed resources are dropped
+    update_open_resources(
+      &mut open,
+      [(0, "stdin".into())].into_iter(),
+      "op_close",
+      Vec::new,
+    );
+    assert_eq!(open.keys().copied().collect::<Vec<_>>(), [0]);
+  }
+}
@@ -0,0 +1,674 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Seccomp filters restricting the system calls of the process, for running
+//! untrusted code with a defense beyond V8 and permissions.
+//!
+//! A profile is a JSON file with a default action and rules for system
+//! calls by name:
+//!
+//! ```json
+//! {
+//!   "defaultAction": "allow",
+//!   "syscalls": [{ "names": ["ptrace", "mount"], "action": "errno" }]
+//! }
+//! ```
+//!
+//! Instead of a profile, `--seccomp-denylist` installs
+//! [`SeccompProfile::denylist`], which allows every system call but a few
+//! that Deno never makes. It is not an allowlist, and only narrows what a
+//! compromised process can reach.
+//!
+//! The filter is synchronized to all threads of the process and inherited by
+//! subprocesses. It can not be removed once installed.
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SeccompAction {
+  Allow,
+  /// Fails the system call with `EPERM`.
+  Errno,
+  /// Allows the system call and logs it to the audit log.
+  Log,
+  /// Kills the process.
+  Kill,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SeccompRule {
+  pub names: Vec<String>,
+  pub action: SeccompAction,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SeccompProfile {
+  pub default_action: SeccompAction,
+  #[serde(default)]
+  pub syscalls: Vec<SeccompRule>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SeccompError {
+  #[error("Invalid seccomp profile: {0}")]
+  Parse(#[from] deno_core::serde_json::Error),
+  #[error("Unknown system call '{0}' in seccomp profile")]
+  UnknownSyscall(String),
+  #[error("Seccomp filters are only supported on Linux on x86_64 and aarch64")]
+  Unsupported,
+  #[error("Failed installing seccomp filter: {0}")]
+  Io(#[from] std::io::Error),
+}
+
+/// System calls which Deno never makes, mostly administering the system or
+/// inspecting other processes. `chroot` is not among them, as the `chroot`
+/// option of `Deno.Command` needs it.
+const DENYLIST: &[&str] = &[
+  "acct",
+  "add_key",
+  "adjtimex",
+  "bpf",
+  "clock_adjtime",
+  "clock_settime",
+  "delete_module",
+  "fanotify_init",
+  "finit_module",
+  "fsconfig",
+  "fsmount",
+  "fsopen",
+  "fspick",
+  "init_module",
+  "kcmp",
+  "kexec_file_load",
+  "kexec_load",
+  "keyctl",
+  "mount",
+  "mount_setattr",
+  "move_mount",
+  "name_to_handle_at",
+  "open_by_handle_at",
+  "open_tree",
+  "perf_event_open",
+  "pivot_root",
+  "process_vm_readv",
+  "process_vm_writev",
+  "ptrace",
+  "quotactl",
+  "reboot",
+  "request_key",
+  "sethostname",
+  "setdomainname",
+  "setns",
+  "settimeofday",
+  "swapoff",
+  "swapon",
+  "syslog",
+  "umount2",
+  "unshare",
+  "userfaultfd",
+  "vhangup",
+  // a frequent source of kernel vulnerabilities, and unused by Deno
+  "io_uring_enter",
+  "io_uring_register",
+  "io_uring_setup",
+];
+
+impl SeccompProfile {
+  /// Allows everything but the system calls Deno never makes.
+  pub fn denylist() -> Self {
+    Self {
+      default_action: SeccompAction::Allow,
+      syscalls: vec![SeccompRule {
+        names: DENYLIST.iter().map(|name| name.to_string()).collect(),
+        action: SeccompAction::Errno,
+      }],
+    }
+  }
+
+  pub fn from_json(text: &str) -> Result<Self, SeccompError> {
+    Ok(deno_core::serde_json::from_str(text)?)
+  }
+
+  /// Installs the profile as a filter for all threads of the process.
+  pub fn install(&self) -> Result<(), SeccompError> {
+    #[cfg(all(
+      target_os = "linux",
+      any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    {
+      let program = linux::compile(self)?;
+      linux::install(&program)
+    }
+    #[cfg(not(all(
+      target_os = "linux",
+      any(target_arch = "x86_64", target_arch = "aarch64")
+    )))]
+    Err(SeccompError::Unsupported)
+  }
+}
+
+#[cfg(all(
+  target_os = "linux",
+  any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod linux {
+  use std::io;
+
+  use super::SeccompAction;
+  use super::SeccompError;
+  use super::SeccompProfile;
+
+  /// `BPF_LD | BPF_W | BPF_ABS`
+  const BPF_LD_W_ABS: u16 = 0x20;
+  /// `BPF_JMP | BPF_JEQ | BPF_K`
+  const BPF_JMP_JEQ_K: u16 = 0x15;
+  /// `BPF_JMP | BPF_JGE | BPF_K`
+  const BPF_JMP_JGE_K: u16 = 0x35;
+  /// `BPF_RET | BPF_K`
+  const BPF_RET_K: u16 = 0x06;
+
+  const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+  const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+  const SECCOMP_RET_LOG: u32 = 0x7ffc_0000;
+  const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+
+  const SECCOMP_SET_MODE_FILTER: libc::c_uint = 1;
+  const SECCOMP_FILTER_FLAG_TSYNC: libc::c_uint = 1;
+
+  /// Offsets into `struct seccomp_data`.
+  const DATA_NR: u32 = 0;
+  const DATA_ARCH: u32 = 4;
+
+  #[cfg(target_arch = "x86_64")]
+  const AUDIT_ARCH: u32 = 0xc000_003e;
+  #[cfg(target_arch = "aarch64")]
+  const AUDIT_ARCH: u32 = 0xc000_00b7;
+  /// System calls of the x32 ABI, which share the x86_64 architecture.
+  #[cfg(target_arch = "x86_64")]
+  const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+  fn statement(code: u16, k: u32) -> libc::sock_filter {
+    libc::sock_filter {
+      code,
+      jt: 0,
+      jf: 0,
+      k,
+    }
+  }
+
+  fn jump(code: u16, k: u32, jt: u8, jf: u8) -> libc::sock_filter {
+    libc::sock_filter { code, jt, jf, k }
+  }
+
+  fn ret(action: SeccompAction) -> libc::sock_filter {
+    let k = match action {
+      SeccompAction::Allow => SECCOMP_RET_ALLOW,
+      SeccompAction::Errno => SECCOMP_RET_ERRNO | libc::EPERM as u32,
+      SeccompAction::Log => SECCOMP_RET_LOG,
+      SeccompAction::Kill => SECCOMP_RET_KILL_PROCESS,
+    };
+    statement(BPF_RET_K, k)
+  }
+
+  pub fn syscall_number(name: &str) -> Option<libc::c_long> {
+    let found = SYSCALLS.iter().find(|(n, _)| *n == name);
+    #[cfg(target_arch = "x86_64")]
+    let found =
+      found.or_else(|| X86_64_SYSCALLS.iter().find(|(n, _)| *n == name));
+    found.map(|(_, nr)| *nr)
+  }
+
+  /// Compiles the profile to a classic BPF program. The first rule naming a
+  /// system call decides its action.
+  pub fn compile(
+    profile: &SeccompProfile,
+  ) -> Result<Vec<libc::sock_filter>, SeccompError> {
+    let mut program = vec![
+      statement(BPF_LD_W_ABS, DATA_ARCH),
+      jump(BPF_JMP_JEQ_K, AUDIT_ARCH, 1, 0),
+      ret(SeccompAction::Kill),
+      statement(BPF_LD_W_ABS, DATA_NR),
+    ];
+    #[cfg(target_arch = "x86_64")]
+    program.extend([
+      jump(BPF_JMP_JGE_K, X32_SYSCALL_BIT, 0, 1),
+      ret(SeccompAction::Errno),
+    ]);
+    let mut seen = std::collections::HashSet::new();
+    for rule in &profile.syscalls {
+      for name in &rule.names {
+        let nr = syscall_number(name)
+          .ok_or_else(|| SeccompError::UnknownSyscall(name.clone()))?;
+        if !seen.insert(nr) {
+          continue;
+        }
+        program.push(jump(BPF_JMP_JEQ_K, nr as u32, 0, 1));
+        program.push(ret(rule.action));
+      }
+    }
+    program.push(ret(profile.default_action));
+    Ok(program)
+  }
+
+  pub fn install(program: &[libc::sock_filter]) -> Result<(), SeccompError> {
+    let prog = libc::sock_fprog {
+      len: program
+        .len()
+        .try_into()
+        .map_err(|_| io::Error::other("seccomp profile is too large"))?,
+      filter: program.as_ptr() as *mut libc::sock_filter,
+    };
+    // SAFETY: plain prctl call, required to install a filter without
+    // CAP_SYS_ADMIN
+    if unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) } != 0 {
+      return Err(io::Error::last_os_error().into());
+    }
+    // SAFETY: `prog` points into `program` which outlives the call
+    let result = unsafe {
+      libc::syscall(
+        libc::SYS_seccomp,
+        SECCOMP_SET_MODE_FILTER,
+        SECCOMP_FILTER_FLAG_TSYNC,
+        &prog as *const libc::sock_fprog,
+      )
+    };
+    if result != 0 {
+      return Err(io::Error::last_os_error().into());
+    }
+    Ok(())
+  }
+
+  #[rustfmt::skip]
+  const SYSCALLS: &[(&str, libc::c_long)] = &[
+    ("accept", libc::SYS_accept),
+    ("accept4", libc::SYS_accept4),
+    ("acct", libc::SYS_acct),
+    ("add_key", libc::SYS_add_key),
+    ("adjtimex", libc::SYS_adjtimex),
+    ("bind", libc::SYS_bind),
+    ("bpf", libc::SYS_bpf),
+    ("brk", libc::SYS_brk),
+    ("capget", libc::SYS_capget),
+    ("capset", libc::SYS_capset),
+    ("chdir", libc::SYS_chdir),
+    ("chroot", libc::SYS_chroot),
+    ("clock_adjtime", libc::SYS_clock_adjtime),
+    ("clock_getres", libc::SYS_clock_getres),
+    ("clock_gettime", libc::SYS_clock_gettime),
+    ("clock_nanosleep", libc::SYS_clock_nanosleep),
+    ("clock_settime", libc::SYS_clock_settime),
+    ("clone", libc::SYS_clone),
+    ("clone3", libc::SYS_clone3),
+    ("close", libc::SYS_close),
+    ("close_range", libc::SYS_close_range),
+    ("connect", libc::SYS_connect),
+    ("copy_file_range", libc::SYS_copy_file_range),
+    ("delete_module", libc::SYS_delete_module),
+    ("dup", libc::SYS_dup),
+    ("dup3", libc::SYS_dup3),
+    ("epoll_create1", libc::SYS_epoll_create1),
+    ("epoll_ctl", libc::SYS_epoll_ctl),
+    ("epoll_pwait", libc::SYS_epoll_pwait),
+    ("epoll_pwait2", libc::SYS_epoll_pwait2),
+    ("eventfd2", libc::SYS_eventfd2),
+    ("execve", libc::SYS_execve),
+    ("execveat", libc::SYS_execveat),
+    ("exit", libc::SYS_exit),
+    ("exit_group", libc::SYS_exit_group),
+    ("faccessat", libc::SYS_faccessat),
+    ("faccessat2", libc::SYS_faccessat2),
+    ("fadvise64", libc::SYS_fadvise64),
+    ("fallocate", libc::SYS_fallocate),
+    ("fanotify_init", libc::SYS_fanotify_init),
+    ("fanotify_mark", libc::SYS_fanotify_mark),
+    ("fchdir", libc::SYS_fchdir),
+    ("fchmod", libc::SYS_fchmod),
+    ("fchmodat", libc::SYS_fchmodat),
+    ("fchown", libc::SYS_fchown),
+    ("fchownat", libc::SYS_fchownat),
+    ("fcntl", libc::SYS_fcntl),
+    ("fdatasync", libc::SYS_fdatasync),
+    ("fgetxattr", libc::SYS_fgetxattr),
+    ("finit_module", libc::SYS_finit_module),
+    ("flistxattr", libc::SYS_flistxattr),
+    ("flock", libc::SYS_flock),
+    ("fremovexattr", libc::SYS_fremovexattr),
+    ("fsconfig", libc::SYS_fsconfig),
+    ("fsetxattr", libc::SYS_fsetxattr),
+    ("fsmount", libc::SYS_fsmount),
+    ("fsopen", libc::SYS_fsopen),
+    ("fspick", libc::SYS_fspick),
+    ("fstat", libc::SYS_fstat),
+    ("fstatfs", libc::SYS_fstatfs),
+    ("fsync", libc::SYS_fsync),
+    ("ftruncate", libc::SYS_ftruncate),
+    ("futex", libc::SYS_futex),
+    ("get_mempolicy", libc::SYS_get_mempolicy),
+    ("get_robust_list", libc::SYS_get_robust_list),
+    ("getcpu", libc::SYS_getcpu),
+    ("getcwd", libc::SYS_getcwd),
+    ("getdents64", libc::SYS_getdents64),
+    ("getegid", libc::SYS_getegid),
+    ("geteuid", libc::SYS_geteuid),
+    ("getgid", libc::SYS_getgid),
+    ("getgroups", libc::SYS_getgroups),
+    ("getitimer", libc::SYS_getitimer),
+    ("getpeername", libc::SYS_getpeername),
+    ("getpgid", libc::SYS_getpgid),
+    ("getpid", libc::SYS_getpid),
+    ("getppid", libc::SYS_getppid),
+    ("getpriority", libc::SYS_getpriority),
+    ("getrandom", libc::SYS_getrandom),
+    ("getresgid", libc::SYS_getresgid),
+    ("getresuid", libc::SYS_getresuid),
+    ("getrlimit", libc::SYS_getrlimit),
+    ("getrusage", libc::SYS_getrusage),
+    ("getsid", libc::SYS_getsid),
+    ("getsockname", libc::SYS_getsockname),
+    ("getsockopt", libc::SYS_getsockopt),
+    ("gettid", libc::SYS_gettid),
+    ("gettimeofday", libc::SYS_gettimeofday),
+    ("getuid", libc::SYS_getuid),
+    ("getxattr", libc::SYS_getxattr),
+    ("init_module", libc::SYS_init_module),
+    ("inotify_add_watch", libc::SYS_inotify_add_watch),
+    ("inotify_init1", libc::SYS_inotify_init1),
+    ("inotify_rm_watch", libc::SYS_inotify_rm_watch),
+    ("io_cancel", libc::SYS_io_cancel),
+    ("io_destroy", libc::SYS_io_destroy),
+    ("io_getevents", libc::SYS_io_getevents),
+    ("io_pgetevents", libc::SYS_io_pgetevents),
+    ("io_setup", libc::SYS_io_setup),
+    ("io_submit", libc::SYS_io_submit),
+    ("io_uring_enter", libc::SYS_io_uring_enter),
+    ("io_uring_register", libc::SYS_io_uring_register),
+    ("io_uring_setup", libc::SYS_io_uring_setup),
+    ("ioctl", libc::SYS_ioctl),
+    ("ioprio_get", libc::SYS_ioprio_get),
+    ("ioprio_set", libc::SYS_ioprio_set),
+    ("kcmp", libc::SYS_kcmp),
+    ("kexec_file_load", libc::SYS_kexec_file_load),
+    ("kexec_load", libc::SYS_kexec_load),
+    ("keyctl", libc::SYS_keyctl),
+    ("kill", libc::SYS_kill),
+    ("landlock_add_rule", libc::SYS_landlock_add_rule),
+    ("landlock_create_ruleset", libc::SYS_landlock_create_ruleset),
+    ("landlock_restrict_self", libc::SYS_landlock_restrict_self),
+    ("lgetxattr", libc::SYS_lgetxattr),
+    ("linkat", libc::SYS_linkat),
+    ("listen", libc::SYS_listen),
+    ("listxattr", libc::SYS_listxattr),
+    ("llistxattr", libc::SYS_llistxattr),
+    ("lremovexattr", libc::SYS_lremovexattr),
+    ("lseek", libc::SYS_lseek),
+    ("lsetxattr", libc::SYS_lsetxattr),
+    ("madvise", libc::SYS_madvise),
+    ("mbind", libc::SYS_mbind),
+    ("membarrier", libc::SYS_membarrier),
+    ("memfd_create", libc::SYS_memfd_create),
+    ("migrate_pages", libc::SYS_migrate_pages),
+    ("mincore", libc::SYS_mincore),
+    ("mkdirat", libc::SYS_mkdirat),
+    ("mknodat", libc::SYS_mknodat),
+    ("mlock", libc::SYS_mlock),
+    ("mlock2", libc::SYS_mlock2),
+    ("mlockall", libc::SYS_mlockall),
+    ("mmap", libc::SYS_mmap),
+    ("mount", libc::SYS_mount),
+    ("mount_setattr", libc::SYS_mount_setattr),
+    ("move_mount", libc::SYS_move_mount),
+    ("move_pages", libc::SYS_move_pages),
+    ("mprotect", libc::SYS_mprotect),
+    ("mq_getsetattr", libc::SYS_mq_getsetattr),
+    ("mq_notify", libc::SYS_mq_notify),
+    ("mq_open", libc::SYS_mq_open),
+    ("mq_timedreceive", libc::SYS_mq_timedreceive),
+    ("mq_timedsend", libc::SYS_mq_timedsend),
+    ("mq_unlink", libc::SYS_mq_unlink),
+    ("mremap", libc::SYS_mremap),
+    ("msgctl", libc::SYS_msgctl),
+    ("msgget", libc::SYS_msgget),
+    ("msgrcv", libc::SYS_msgrcv),
+    ("msgsnd", libc::SYS_msgsnd),
+    ("msync", libc::SYS_msync),
+    ("munlock", libc::SYS_munlock),
+    ("munlockall", libc::SYS_munlockall),
+    ("munmap", libc::SYS_munmap),
+    ("name_to_handle_at", libc::SYS_name_to_handle_at),
+    ("nanosleep", libc::SYS_nanosleep),
+    ("newfstatat", libc::SYS_newfstatat),
+    ("open_by_handle_at", libc::SYS_open_by_handle_at),
+    ("open_tree", libc::SYS_open_tree),
+    ("openat", libc::SYS_openat),
+    ("openat2", libc::SYS_openat2),
+    ("perf_event_open", libc::SYS_perf_event_open),
+    ("personality", libc::SYS_personality),
+    ("pidfd_getfd", libc::SYS_pidfd_getfd),
+    ("pidfd_open", libc::SYS_pidfd_open),
+    ("pidfd_send_signal", libc::SYS_pidfd_send_signal),
+    ("pipe2", libc::SYS_pipe2),
+    ("pivot_root", libc::SYS_pivot_root),
+    ("pkey_alloc", libc::SYS_pkey_alloc),
+    ("pkey_free", libc::SYS_pkey_free),
+    ("pkey_mprotect", libc::SYS_pkey_mprotect),
+    ("ppoll", libc::SYS_ppoll),
+    ("prctl", libc::SYS_prctl),
+    ("pread64", libc::SYS_pread64),
+    ("preadv", libc::SYS_preadv),
+    ("preadv2", libc::SYS_preadv2),
+    ("prlimit64", libc::SYS_prlimit64),
+    ("process_madvise", libc::SYS_process_madvise),
+    ("process_vm_readv", libc::SYS_process_vm_readv),
+    ("process_vm_writev", libc::SYS_process_vm_writev),
+    ("pselect6", libc::SYS_pselect6),
+    ("ptrace", libc::SYS_ptrace),
+    ("pwrite64", libc::SYS_pwrite64),
+    ("pwritev", libc::SYS_pwritev),
+    ("pwritev2", libc::SYS_pwritev2),
+    ("quotactl", libc::SYS_quotactl),
+    ("read", libc::SYS_read),
+    ("readahead", libc::SYS_readahead),
+    ("readlinkat", libc::SYS_readlinkat),
+    ("readv", libc::SYS_readv),
+    ("reboot", libc::SYS_reboot),
+    ("recvfrom", libc::SYS_recvfrom),
+    ("recvmmsg", libc::SYS_recvmmsg),
+    ("recvmsg", libc::SYS_recvmsg),
+    ("removexattr", libc::SYS_removexattr),
+    ("renameat2", libc::SYS_renameat2),
+    ("request_key", libc::SYS_request_key),
+    ("restart_syscall", libc::SYS_restart_syscall),
+    ("rseq", libc::SYS_rseq),
+    ("rt_sigaction", libc::SYS_rt_sigaction),
+    ("rt_sigpending", libc::SYS_rt_sigpending),
+    ("rt_sigprocmask", libc::SYS_rt_sigprocmask),
+    ("rt_sigqueueinfo", libc::SYS_rt_sigqueueinfo),
+    ("rt_sigreturn", libc::SYS_rt_sigreturn),
+    ("rt_sigsuspend", libc::SYS_rt_sigsuspend),
+    ("rt_sigtimedwait", libc::SYS_rt_sigtimedwait),
+    ("rt_tgsigqueueinfo", libc::SYS_rt_tgsigqueueinfo),
+    ("sched_get_priority_max", libc::SYS_sched_get_priority_max),
+    ("sched_get_priority_min", libc::SYS_sched_get_priority_min),
+    ("sched_getaffinity", libc::SYS_sched_getaffinity),
+    ("sched_getattr", libc::SYS_sched_getattr),
+    ("sched_getparam", libc::SYS_sched_getparam),
+    ("sched_getscheduler", libc::SYS_sched_getscheduler),
+    ("sched_rr_get_interval", libc::SYS_sched_rr_get_interval),
+    ("sched_setaffinity", libc::SYS_sched_setaffinity),
+    ("sched_setattr", libc::SYS_sched_setattr),
+    ("sched_setparam", libc::SYS_sched_setparam),
+    ("sched_setscheduler", libc::SYS_sched_setscheduler),
+    ("sched_yield", libc::SYS_sched_yield),
+    ("seccomp", libc::SYS_seccomp),
+    ("semctl", libc::SYS_semctl),
+    ("semget", libc::SYS_semget),
+    ("semop", libc::SYS_semop),
+    ("semtimedop", libc::SYS_semtimedop),
+    ("sendfile", libc::SYS_sendfile),
+    ("sendmmsg", libc::SYS_sendmmsg),
+    ("sendmsg", libc::SYS_sendmsg),
+    ("sendto", libc::SYS_sendto),
+    ("set_mempolicy", libc::SYS_set_mempolicy),
+    ("set_robust_list", libc::SYS_set_robust_list),
+    ("set_tid_address", libc::SYS_set_tid_address),
+    ("setdomainname", libc::SYS_setdomainname),
+    ("setfsgid", libc::SYS_setfsgid),
+    ("setfsuid", libc::SYS_setfsuid),
+    ("setgid", libc::SYS_setgid),
+    ("setgroups", libc::SYS_setgroups),
+    ("sethostname", libc::SYS_sethostname),
+    ("setitimer", libc::SYS_setitimer),
+    ("setns", libc::SYS_setns),
+    ("setpgid", libc::SYS_setpgid),
+    ("setpriority", libc::SYS_setpriority),
+    ("setregid", libc::SYS_setregid),
+    ("setresgid", libc::SYS_setresgid),
+    ("setresuid", libc::SYS_setresuid),
+    ("setreuid", libc::SYS_setreuid),
+    ("setrlimit", libc::SYS_setrlimit),
+    ("setsid", libc::SYS_setsid),
+    ("setsockopt", libc::SYS_setsockopt),
+    ("settimeofday", libc::SYS_settimeofday),
+    ("setuid", libc::SYS_setuid),
+    ("setxattr", libc::SYS_setxattr),
+    ("shmat", libc::SYS_shmat),
+    ("shmctl", libc::SYS_shmctl),
+    ("shmdt", libc::SYS_shmdt),
+    ("shmget", libc::SYS_shmget),
+    ("shutdown", libc::SYS_shutdown),
+    ("sigaltstack", libc::SYS_sigaltstack),
+    ("signalfd4", libc::SYS_signalfd4),
+    ("socket", libc::SYS_socket),
+    ("socketpair", libc::SYS_socketpair),
+    ("splice", libc::SYS_splice),
+    ("statfs", libc::SYS_statfs),
+    ("statx", libc::SYS_statx),
+    ("swapoff", libc::SYS_swapoff),
+    ("swapon", libc::SYS_swapon),
+    ("symlinkat", libc::SYS_symlinkat),
+    ("sync", libc::SYS_sync),
+    ("sync_file_range", libc::SYS_sync_file_range),
+    ("syncfs", libc::SYS_syncfs),
+    ("sysinfo", libc::SYS_sysinfo),
+    ("syslog", libc::SYS_syslog),
+    ("tee", libc::SYS_tee),
+    ("tgkill", libc::SYS_tgkill),
+    ("timer_create", libc::SYS_timer_create),
+    ("timer_delete", libc::SYS_timer_delete),
+    ("timer_getoverrun", libc::SYS_timer_getoverrun),
+    ("timer_gettime", libc::SYS_timer_gettime),
+    ("timer_settime", libc::SYS_timer_settime),
+    ("timerfd_create", libc::SYS_timerfd_create),
+    ("timerfd_gettime", libc::SYS_timerfd_gettime),
+    ("timerfd_settime", libc::SYS_timerfd_settime),
+    ("times", libc::SYS_times),
+    ("tkill", libc::SYS_tkill),
+    ("truncate", libc::SYS_truncate),
+    ("umask", libc::SYS_umask),
+    ("umount2", libc::SYS_umount2),
+    ("uname", libc::SYS_uname),
+    ("unlinkat", libc::SYS_unlinkat),
+    ("unshare", libc::SYS_unshare),
+    ("userfaultfd", libc::SYS_userfaultfd),
+    ("utimensat", libc::SYS_utimensat),
+    ("vhangup", libc::SYS_vhangup),
+    ("vmsplice", libc::SYS_vmsplice),
+    ("wait4", libc::SYS_wait4),
+    ("waitid", libc::SYS_waitid),
+    ("write", libc::SYS_write),
+    ("writev", libc::SYS_writev),
+  ];
+
+  #[cfg(target_arch = "x86_64")]
+  #[rustfmt::skip]
+  const X86_64_SYSCALLS: &[(&str, libc::c_long)] = &[
+    ("access", libc::SYS_access),
+    ("alarm", libc::SYS_alarm),
+    ("arch_prctl", libc::SYS_arch_prctl),
+    ("chmod", libc::SYS_chmod),
+    ("chown", libc::SYS_chown),
+    ("creat", libc::SYS_creat),
+    ("dup2", libc::SYS_dup2),
+    ("epoll_create", libc::SYS_epoll_create),
+    ("epoll_wait", libc::SYS_epoll_wait),
+    ("eventfd", libc::SYS_eventfd),
+    ("fork", libc::SYS_fork),
+    ("futimesat", libc::SYS_futimesat),
+    ("getdents", libc::SYS_getdents),
+    ("getpgrp", libc::SYS_getpgrp),
+    ("inotify_init", libc::SYS_inotify_init),
+    ("ioperm", libc::SYS_ioperm),
+    ("iopl", libc::SYS_iopl),
+    ("lchown", libc::SYS_lchown),
+    ("link", libc::SYS_link),
+    ("lstat", libc::SYS_lstat),
+    ("mkdir", libc::SYS_mkdir),
+    ("mknod", libc::SYS_mknod),
+    ("modify_ldt", libc::SYS_modify_ldt),
+    ("open", libc::SYS_open),
+    ("pause", libc::SYS_pause),
+    ("pipe", libc::SYS_pipe),
+    ("poll", libc::SYS_poll),
+    ("readlink", libc::SYS_readlink),
+    ("rename", libc::SYS_rename),
+    ("rmdir", libc::SYS_rmdir),
+    ("select", libc::SYS_select),
+    ("signalfd", libc::SYS_signalfd),
+    ("stat", libc::SYS_stat),
+    ("symlink", libc::SYS_symlink),
+    ("time", libc::SYS_time),
+    ("unlink", libc::SYS_unlink),
+    ("utime", libc::SYS_utime),
+    ("utimes", libc::SYS_utimes),
+    ("vfork", libc::SYS_vfork),
+  ];
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_profiles() {
+    let profile = SeccompProfile::from_json(
+      r#"{
+        "defaultAction": "errno",
+        "syscalls": [{ "names": ["read", "write"], "action": "allow" }]
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(profile.default_action, SeccompAction::Errno);
+    assert_eq!(profile.syscalls[0].names, ["read", "write"]);
+    assert!(
+      SeccompProfile::from_json(r#"{ "defaultAction": "deny" }"#).is_err()
+    );
+  }
+
+  #[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+  ))]
+  #[test]
+  fn compiles_profiles() {
+    let profile = SeccompProfile::denylist();
+    let program = linux::compile(&profile).unwrap();
+    // the architecture check, one jump and return per system call and the
+    // default action
+    let header = if cfg!(target_arch = "x86_64") { 6 } else { 4 };
+    assert_eq!(program.len(), header + DENYLIST.len() * 2 + 1);
+
+    let profile = SeccompProfile {
+      default_action: SeccompAction::Allow,
+      syscalls: vec![SeccompRule {
+        names: vec!["not_a_syscall".to_string()],
+        action: SeccompAction::Kill,
+      }],
+    };
+    assert!(matches!(
+      linux::compile(&profile),
+      Err(SeccompError::UnknownSyscall(name)) if name == "not_a_syscall"
+    ));
+  }
+}
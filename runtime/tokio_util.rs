@@ -25,6 +25,23 @@ where
 pub fn create_basic_runtime() -> tokio::runtime::Runtime {
   let (event_interval, global_queue_interval, max_io_events_per_tick) =
     tokio_configuration();
+  // This limits the number of threads for blocking operations (like for
+  // synchronous fs ops) or CPU bound tasks like when we run dprint in
+  // parallel for deno fmt.
+  // The default value is 512, which is an unhelpfully large thread pool. We
+  // don't ever want to have more than a couple dozen threads.
+  let max_blocking_threads = if cfg!(windows) {
+    // on windows, tokio uses blocking tasks for child process IO, make sure
+    // we have enough available threads for other tasks to run
+    4 * std::thread::available_parallelism()
+      .map(|n| n.get())
+      .unwrap_or(8)
+  } else {
+    // a container limited to a few CPUs can't make use of as many
+    deno_os::cgroup::cpu_limit().map_or(32, |cpus| (4 * cpus).clamp(8, 32))
+  };
+  // the queue of fs ops waiting for a thread is limited relative to it
+  deno_io::blocking::set_pool_size(max_blocking_threads);
 
   tokio::runtime::Builder::new_current_thread()
     .enable_io()
@@ -38,20 +55,7 @@ pub fn create_basic_runtime() -> tokio::runtime::Runtime {
       "DENO_TOKIO_MAX_IO_EVENTS_PER_TICK",
       max_io_events_per_tick,
     ))
-    // This limits the number of threads for blocking operations (like for
-    // synchronous fs ops) or CPU bound tasks like when we run dprint in
-    // parallel for deno fmt.
-    // The default value is 512, which is an unhelpfully large thread pool. We
-    // don't ever want to have more than a couple dozen threads.
-    .max_blocking_threads(if cfg!(windows) {
-      // on windows, tokio uses blocking tasks for child process IO, make sure
-      // we have enough available threads for other tasks to run
-      4 * std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(8)
-    } else {
-      32
-    })
+    .max_blocking_threads(max_blocking_threads)
     .build()
     .unwrap()
 }
@@ -89,9 +93,8 @@ where
       let handle = tokio::runtime::Handle::current();
       let runtime_monitor = RuntimeMonitor::new(&handle);
       tokio::spawn(async move {
-        #[allow(clippy::print_stderr)]
         for interval in runtime_monitor.intervals() {
-          eprintln!("{:#?}", interval);
+          log::info!(target: "deno_runtime::tokio_metrics", "{:#?}", interval);
           // wait 500ms
           tokio::time::sleep(std::time::Duration::from_millis(
             metrics_interval,
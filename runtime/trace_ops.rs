@@ -0,0 +1,234 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Low-level tracing of op calls, enabled with `--trace-ops`. Every call of
+//! an op matching the patterns is reported with the time since the process
+//! started, and its completion with how long the call took. The trace goes
+//! to stderr, or as newline delimited JSON to the file given with
+//! `--trace-ops-output`.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use deno_core::OpMetricsEvent;
+use deno_core::OpMetricsFactoryFn;
+use deno_core::OpMetricsSource;
+use deno_core::serde_json;
+use serde::Serialize;
+
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// The file a trace is written to, shared by all workers of the process.
+#[derive(Debug, Clone)]
+pub struct TraceOpsOutput(Arc<Mutex<File>>);
+
+impl TraceOpsOutput {
+  pub fn create(path: &Path) -> std::io::Result<Self> {
+    Ok(Self(Arc::new(Mutex::new(File::create(path)?))))
+  }
+
+  fn write_line(&self, line: &[u8]) {
+    // a trace that can't be written shouldn't bring the program down
+    let _ = self.0.lock().unwrap().write_all(line);
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceRecord<'a> {
+  /// Seconds since the process started.
+  time: f64,
+  op: &'a str,
+  event: String,
+  source: String,
+  /// Seconds the call took, on completion.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  duration: Option<f64>,
+}
+
+fn glob_matches(pattern: &[u8], name: &[u8]) -> bool {
+  match (pattern.split_first(), name.split_first()) {
+    (None, None) => true,
+    (Some((b'*', rest)), _) => {
+      glob_matches(rest, name)
+        || (!name.is_empty() && glob_matches(pattern, &name[1..]))
+    }
+    (Some((b'?', rest)), Some((_, name_rest))) => glob_matches(rest, name_rest),
+    (Some((p, rest)), Some((n, name_rest))) if p == n => {
+      glob_matches(rest, name_rest)
+    }
+    _ => false,
+  }
+}
+
+/// Patterns with `*` or `?` match the whole op name as a glob, others match
+/// any part of it.
+fn pattern_matches(pattern: &str, name: &str) -> bool {
+  if pattern.contains(['*', '?']) {
+    glob_matches(pattern.as_bytes(), name.as_bytes())
+  } else {
+    name.contains(pattern)
+  }
+}
+
+/// Match an op name against a list of patterns, where patterns starting
+/// with `-` exclude ops.
+fn matches_patterns(patterns: &[String], name: &str) -> bool {
+  let mut found_match = false;
+  let mut found_nomatch = false;
+  for pattern in patterns.iter() {
+    if let Some(pattern) = pattern.strip_prefix('-') {
+      if pattern_matches(pattern, name) {
+        return false;
+      }
+    } else if pattern_matches(pattern, name) {
+      found_match = true;
+    } else {
+      found_nomatch = true;
+    }
+  }
+
+  found_match || !found_nomatch
+}
+
+/// Start times of the calls of one op in flight. Async calls are assumed to
+/// complete in the order they were made, so the durations of overlapping
+/// calls of the same async op are approximate.
+#[derive(Default)]
+struct InFlight {
+  sync: Vec<Instant>,
+  async_: VecDeque<Instant>,
+}
+
+impl InFlight {
+  fn on_event(
+    &mut self,
+    now: Instant,
+    event: OpMetricsEvent,
+    source: OpMetricsSource,
+  ) -> Option<f64> {
+    let is_async = matches!(source, OpMetricsSource::Async);
+    if matches!(event, OpMetricsEvent::Dispatched) {
+      if is_async {
+        self.async_.push_back(now);
+      } else {
+        self.sync.push(now);
+      }
+      return None;
+    }
+    let start = if is_async {
+      self.async_.pop_front()
+    } else {
+      self.sync.pop()
+    };
+    start.map(|start| now.duration_since(start).as_secs_f64())
+  }
+}
+
+pub fn create_op_trace_factory(
+  patterns: Vec<String>,
+  output: Option<TraceOpsOutput>,
+) -> OpMetricsFactoryFn {
+  let start = *START.get_or_init(Instant::now);
+  let max_len: Rc<std::cell::Cell<usize>> = Default::default();
+  Box::new(move |_, _, decl| {
+    // If we don't match a requested pattern, or we match a negative pattern, bail
+    if !matches_patterns(&patterns, decl.name) {
+      return None;
+    }
+
+    max_len.set(max_len.get().max(decl.name.len()));
+    let max_len = max_len.clone();
+    let output = output.clone();
+    let in_flight = RefCell::new(InFlight::default());
+    Some(Rc::new(
+      #[allow(clippy::print_stderr)]
+      move |op: &deno_core::_ops::OpCtx, event, source| {
+        let now = Instant::now();
+        let time = now.duration_since(start).as_secs_f64();
+        let duration = in_flight.borrow_mut().on_event(now, event, source);
+        let name = op.decl().name;
+        match &output {
+          Some(output) => {
+            let record = TraceRecord {
+              time,
+              op: name,
+              event: format!("{event:?}"),
+              source: format!("{source:?}"),
+              duration,
+            };
+            let mut line = serde_json::to_vec(&record).unwrap();
+            line.push(b'\n');
+            output.write_line(&line);
+          }
+          None => match duration {
+            Some(duration) => eprintln!(
+              "[{time: >10.3}] {name:max_len$}: {event:?} {source:?} ({:.3}ms)",
+              duration * 1000.0,
+              max_len = max_len.get()
+            ),
+            None => eprintln!(
+              "[{time: >10.3}] {name:max_len$}: {event:?} {source:?}",
+              max_len = max_len.get()
+            ),
+          },
+        }
+      },
+    ))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::*;
+
+  #[test]
+  fn matches_op_names() {
+    let patterns =
+      vec!["op_fs_*_async".to_string(), "-op_fs_open*".to_string()];
+    assert!(matches_patterns(&patterns, "op_fs_stat_async"));
+    assert!(!matches_patterns(&patterns, "op_fs_stat_sync"));
+    assert!(!matches_patterns(&patterns, "op_fs_open_async"));
+
+    let patterns = vec!["fs".to_string(), "net".to_string()];
+    assert!(matches_patterns(&patterns, "op_net_connect_tcp"));
+    assert!(!matches_patterns(&patterns, "op_read"));
+    assert!(matches_patterns(&[], "op_read"));
+    assert!(matches_patterns(&["-fs".to_string()], "op_read"));
+
+    assert!(glob_matches(b"op_?ead", b"op_read"));
+    assert!(!glob_matches(b"op_?ead", b"op_rread"));
+  }
+
+  #[test]
+  fn measures_durations() {
+    let mut in_flight = InFlight::default();
+    let start = Instant::now();
+    let later = start + Duration::from_millis(5);
+    let dispatched = OpMetricsEvent::Dispatched;
+    let is_async = OpMetricsSource::Async;
+    assert_eq!(in_flight.on_event(start, dispatched, is_async), None);
+    assert_eq!(in_flight.on_event(later, dispatched, is_async), None);
+    assert_eq!(
+      in_flight.on_event(later, OpMetricsEvent::CompletedAsync, is_async),
+      Some(0.005)
+    );
+    assert_eq!(
+      in_flight.on_event(later, OpMetricsEvent::ErrorAsync, is_async),
+      Some(0.0)
+    );
+    assert_eq!(
+      in_flight.on_event(later, OpMetricsEvent::Completed, is_async),
+      None
+    );
+  }
+}
@@ -68,6 +68,7 @@ use crate::inspector_server::InspectorServer;
 use crate::inspector_server::MainInspectorSessionChannel;
 use crate::ops;
 use crate::shared::runtime;
+use crate::trace_ops::TraceOpsOutput;
 use crate::worker::FormatJsErrorFn;
 #[cfg(target_os = "linux")]
 use crate::worker::MEMORY_TRIM_HANDLER_ENABLED;
@@ -75,6 +76,10 @@ use crate::worker::MEMORY_TRIM_HANDLER_ENABLED;
 use crate::worker::SIGUSR2_RX;
 use crate::worker::create_op_metrics;
 use crate::worker::create_validate_import_attributes_callback;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::worker_limits::CpuWatchdog;
+use crate::worker_limits::WorkerLimitState;
+use crate::worker_limits::WorkerLimits;
 
 pub struct WorkerMetadata {
   pub buffer: DetachedBuffer,
@@ -409,13 +414,18 @@ pub struct WebWorkerOptions {
   pub format_js_error_fn: Option<Arc<FormatJsErrorFn>>,
   pub worker_type: WorkerThreadType,
   pub cache_storage_dir: Option<std::path::PathBuf>,
+  /// The backend of the Cache API, used instead of the one stored in
+  /// `cache_storage_dir`.
+  pub create_cache: Option<CreateCache>,
   pub stdio: Stdio,
   pub trace_ops: Option<Vec<String>>,
+  pub trace_ops_output: Option<TraceOpsOutput>,
   pub close_on_idle: bool,
   pub maybe_worker_metadata: Option<WorkerMetadata>,
   pub maybe_coverage_dir: Option<PathBuf>,
   pub enable_raw_imports: bool,
   pub enable_stack_trace_arg_in_ops: bool,
+  pub limits: WorkerLimits,
 }
 
 /// This struct is an implementation of `Worker` Web API
@@ -437,6 +447,10 @@ pub struct WebWorker {
   maybe_worker_metadata: Option<WorkerMetadata>,
   memory_trim_handle: Option<tokio::task::JoinHandle<()>>,
   maybe_coverage_dir: Option<PathBuf>,
+  limit_state: WorkerLimitState,
+  open_resources_handle: Option<deno_core::unsync::JoinHandle<()>>,
+  #[cfg(any(target_os = "android", target_os = "linux"))]
+  _cpu_watchdog: Option<CpuWatchdog>,
 }
 
 impl Drop for WebWorker {
@@ -447,6 +461,9 @@ impl Drop for WebWorker {
     if let Some(memory_trim_handle) = self.memory_trim_handle.take() {
       memory_trim_handle.abort();
     }
+    if let Some(open_resources_handle) = self.open_resources_handle.take() {
+      open_resources_handle.abort();
+    }
   }
 }
 
@@ -485,6 +502,10 @@ impl WebWorker {
     let enable_testing_features = options.bootstrap.enable_testing_features;
 
     fn create_cache_inner(options: &WebWorkerOptions) -> Option<CreateCache> {
+      if let Some(create_cache) = &options.create_cache {
+        return Some(create_cache.clone());
+      }
+
       if let Ok(var) = std::env::var("DENO_CACHE_LSC_ENDPOINT") {
         let elems: Vec<_> = var.split(",").collect();
         if elems.len() == 2 {
@@ -626,12 +647,16 @@ impl WebWorker {
     let (op_summary_metrics, op_metrics_factory_fn) = create_op_metrics(
       options.bootstrap.enable_op_summary_metrics,
       options.trace_ops,
+      options.trace_ops_output,
+      // only the resources of the main worker are tracked
+      false,
     );
+    let limit_state = WorkerLimitState::default();
 
     let mut js_runtime = JsRuntime::new(RuntimeOptions {
       module_loader: Some(services.module_loader),
       startup_snapshot: options.startup_snapshot,
-      create_params: options.create_params,
+      create_params: options.limits.create_params(options.create_params),
       shared_array_buffer_store: services.shared_array_buffer_store,
       compiled_wasm_module_store: services.compiled_wasm_module_store,
       extensions,
@@ -666,6 +691,28 @@ impl WebWorker {
       js_runtime.op_state().borrow_mut().put(op_summary_metrics);
     }
 
+    limit_state
+      .set_isolate_handle(js_runtime.v8_isolate().thread_safe_handle());
+    if let Some(heap_mb) = options.limits.heap_mb {
+      js_runtime.add_near_heap_limit_callback(
+        limit_state.near_heap_limit_callback(heap_mb),
+      );
+    }
+    // this runs on the worker thread, whose CPU time is limited
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    let cpu_watchdog = options.limits.cpu_ms.and_then(|cpu_ms| {
+      limit_state
+        .start_cpu_watchdog(cpu_ms)
+        .inspect_err(|err| {
+          log::warn!("Failed to enforce the CPU time limit of a worker: {err}")
+        })
+        .ok()
+    });
+    let open_resources_handle =
+      options.limits.max_open_fds.map(|max_open_fds| {
+        limit_state.watch_open_resources(js_runtime.op_state(), max_open_fds)
+      });
+
     {
       let state = js_runtime.op_state();
       let mut state = state.borrow_mut();
@@ -748,6 +795,10 @@ impl WebWorker {
         maybe_worker_metadata: options.maybe_worker_metadata,
         memory_trim_handle: None,
         maybe_coverage_dir: options.maybe_coverage_dir,
+        limit_state,
+        open_resources_handle,
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        _cpu_watchdog: cpu_watchdog,
       },
       external_handle,
       options.bootstrap,
@@ -1079,6 +1130,7 @@ pub async fn run_web_worker(
 
   let name = worker.name.to_string();
   let internal_handle = worker.internal_handle.clone();
+  let limit_state = worker.limit_state.clone();
 
   // Execute provided source code immediately
   let result = if let Some(source_code) = maybe_source_code.take() {
@@ -1121,6 +1173,12 @@ pub async fn run_web_worker(
     result
   };
 
+  // the isolate was terminated for exceeding a limit
+  let result = match limit_state.take_error() {
+    Some(e) => Err(e),
+    None => result,
+  };
+
   if let Err(e) = result {
     print_worker_error(&e, &name, format_js_error_fn.as_deref());
     internal_handle
@@ -8,7 +8,6 @@ use std::sync::LazyLock;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
-use std::time::Instant;
 
 use deno_cache::CacheImpl;
 use deno_cache::CreateCache;
@@ -59,7 +58,10 @@ use crate::code_cache::CodeCache;
 use crate::code_cache::CodeCacheType;
 use crate::inspector_server::InspectorServer;
 use crate::ops;
+use crate::resource_leaks;
 use crate::shared::runtime;
+use crate::trace_ops::TraceOpsOutput;
+use crate::trace_ops::create_op_trace_factory;
 
 pub type FormatJsErrorFn = dyn Fn(&JsError) -> String + Sync + Send;
 
@@ -254,8 +256,16 @@ pub struct WorkerOptions {
   pub should_wait_for_inspector_session: bool,
   /// If Some, print a low-level trace output for ops matching the given patterns.
   pub trace_ops: Option<Vec<String>>,
+  /// Where the trace of `trace_ops` is written to instead of stderr.
+  pub trace_ops_output: Option<TraceOpsOutput>,
+  /// If true, record where resources were created, to report the ones left
+  /// open on exit.
+  pub trace_leaks: bool,
 
   pub cache_storage_dir: Option<std::path::PathBuf>,
+  /// The backend of the Cache API, used instead of the one stored in
+  /// `cache_storage_dir`.
+  pub create_cache: Option<CreateCache>,
   pub origin_storage_dir: Option<std::path::PathBuf>,
   pub stdio: Stdio,
   pub enable_raw_imports: bool,
@@ -276,10 +286,13 @@ impl Default for WorkerOptions {
       should_break_on_first_statement: Default::default(),
       should_wait_for_inspector_session: Default::default(),
       trace_ops: Default::default(),
+      trace_ops_output: Default::default(),
+      trace_leaks: false,
       maybe_inspector_server: Default::default(),
       format_js_error_fn: Default::default(),
       origin_storage_dir: Default::default(),
       cache_storage_dir: Default::default(),
+      create_cache: Default::default(),
       extensions: Default::default(),
       startup_snapshot: Default::default(),
       create_params: Default::default(),
@@ -295,54 +308,25 @@ impl Default for WorkerOptions {
 pub fn create_op_metrics(
   enable_op_summary_metrics: bool,
   trace_ops: Option<Vec<String>>,
+  trace_ops_output: Option<TraceOpsOutput>,
+  trace_leaks: bool,
 ) -> (
   Option<Rc<OpMetricsSummaryTracker>>,
   Option<OpMetricsFactoryFn>,
 ) {
   let mut op_summary_metrics = None;
   let mut op_metrics_factory_fn: Option<OpMetricsFactoryFn> = None;
-  let now = Instant::now();
-  let max_len: Rc<std::cell::Cell<usize>> = Default::default();
   if let Some(patterns) = trace_ops {
-    /// Match an op name against a list of patterns
-    fn matches_pattern(patterns: &[String], name: &str) -> bool {
-      let mut found_match = false;
-      let mut found_nomatch = false;
-      for pattern in patterns.iter() {
-        if let Some(pattern) = pattern.strip_prefix('-') {
-          if name.contains(pattern) {
-            return false;
-          }
-        } else if name.contains(pattern.as_str()) {
-          found_match = true;
-        } else {
-          found_nomatch = true;
-        }
-      }
-
-      found_match || !found_nomatch
-    }
-
-    op_metrics_factory_fn = Some(Box::new(move |_, _, decl| {
-      // If we don't match a requested pattern, or we match a negative pattern, bail
-      if !matches_pattern(&patterns, decl.name) {
-        return None;
-      }
+    op_metrics_factory_fn =
+      Some(create_op_trace_factory(patterns, trace_ops_output));
+  }
 
-      max_len.set(max_len.get().max(decl.name.len()));
-      let max_len = max_len.clone();
-      Some(Rc::new(
-        #[allow(clippy::print_stderr)]
-        move |op: &deno_core::_ops::OpCtx, event, source| {
-          eprintln!(
-            "[{: >10.3}] {name:max_len$}: {event:?} {source:?}",
-            now.elapsed().as_secs_f64(),
-            name = op.decl().name,
-            max_len = max_len.get()
-          );
-        },
-      ))
-    }));
+  if trace_leaks {
+    let tracking = resource_leaks::create_resource_tracking_factory();
+    op_metrics_factory_fn = Some(match op_metrics_factory_fn {
+      Some(f) => merge_op_metrics(f, tracking),
+      None => tracking,
+    });
   }
 
   if enable_op_summary_metrics {
@@ -392,6 +376,10 @@ impl MainWorker {
     mut options: WorkerOptions,
   ) -> (Self, BootstrapOptions) {
     fn create_cache_inner(options: &WorkerOptions) -> Option<CreateCache> {
+      if let Some(create_cache) = &options.create_cache {
+        return Some(create_cache.clone());
+      }
+
       if let Ok(var) = std::env::var("DENO_CACHE_LSC_ENDPOINT") {
         let elems: Vec<_> = var.split(",").collect();
         if elems.len() == 2 {
@@ -429,6 +417,8 @@ impl MainWorker {
     let (op_summary_metrics, op_metrics_factory_fn) = create_op_metrics(
       options.bootstrap.enable_op_summary_metrics,
       options.trace_ops,
+      options.trace_ops_output,
+      options.trace_leaks,
     );
 
     // Permissions: many ops depend on this
@@ -475,6 +465,7 @@ impl MainWorker {
         extensions,
         op_metrics_factory_fn,
         enable_stack_trace_arg_in_ops: options.enable_stack_trace_arg_in_ops,
+        trace_leaks: options.trace_leaks,
       })
     };
 
@@ -1099,6 +1090,7 @@ struct CommonRuntimeOptions {
   extensions: Vec<Extension>,
   op_metrics_factory_fn: Option<OpMetricsFactoryFn>,
   enable_stack_trace_arg_in_ops: bool,
+  trace_leaks: bool,
 }
 
 struct EnableRawImports(Arc<AtomicBool>);
@@ -1132,9 +1124,18 @@ fn common_runtime(opts: CommonRuntimeOptions) -> JsRuntime {
       create_validate_import_attributes_callback(enable_raw_imports.clone()),
     ),
     import_assertions_support: deno_core::ImportAssertionsSupport::Error,
-    maybe_op_stack_trace_callback: opts
-      .enable_stack_trace_arg_in_ops
-      .then(create_permissions_stack_trace_callback),
+    maybe_op_stack_trace_callback: match (
+      opts.enable_stack_trace_arg_in_ops,
+      opts.trace_leaks,
+    ) {
+      (false, false) => None,
+      (true, false) => Some(create_permissions_stack_trace_callback()),
+      (trace_permissions, true) => {
+        Some(resource_leaks::create_stack_trace_callback(
+          trace_permissions.then(create_permissions_stack_trace_callback),
+        ))
+      }
+    },
     extension_code_cache: None,
     v8_platform: None,
     custom_module_evaluation_cb: None,
@@ -1207,6 +1208,7 @@ impl UnconfiguredRuntime {
       extensions,
       op_metrics_factory_fn: None,
       enable_stack_trace_arg_in_ops: false,
+      trace_leaks: false,
     });
 
     UnconfiguredRuntime {
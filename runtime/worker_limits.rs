@@ -0,0 +1,250 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Resource limits of a worker, set with
+//! `new Worker(url, { deno: { limits } })`. The heap is capped with V8's
+//! heap limits, the CPU time of the worker thread is polled by a watchdog
+//! thread, and the number of open resources is sampled by a task on the
+//! worker's event loop, so a worker that opens resources without yielding to
+//! the event loop can briefly hold more than its limit.
+//! A worker exceeding one of its limits has its isolate terminated, and the
+//! error is dispatched on the `Worker` object in the parent.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use std::sync::atomic::AtomicBool;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use deno_core::OpState;
+use deno_core::error::CoreError;
+use deno_core::unsync::JoinHandle;
+use deno_core::v8;
+use deno_error::JsErrorBox;
+use serde::Deserialize;
+
+/// Whether `cpu_ms` can be enforced on this platform.
+pub const SUPPORTS_CPU_LIMIT: bool =
+  cfg!(any(target_os = "android", target_os = "linux"));
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const CPU_POLL_INTERVAL: Duration = Duration::from_millis(10);
+const RESOURCE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerLimits {
+  /// Megabytes the heap of the worker may grow to.
+  pub heap_mb: Option<usize>,
+  /// Milliseconds of CPU time the worker thread may use.
+  pub cpu_ms: Option<u64>,
+  /// Resources, such as files and sockets, the worker may have open at the
+  /// same time, not counting its stdio.
+  pub max_open_fds: Option<usize>,
+}
+
+impl WorkerLimits {
+  /// Caps the heap of an isolate created with `params`.
+  pub fn create_params(
+    &self,
+    params: Option<v8::CreateParams>,
+  ) -> Option<v8::CreateParams> {
+    match self.heap_mb {
+      Some(heap_mb) => Some(
+        params
+          .unwrap_or_default()
+          .heap_limits(0, heap_mb.saturating_mul(1024 * 1024)),
+      ),
+      None => params,
+    }
+  }
+}
+
+/// The limit a worker exceeded, shared with the threads and callbacks
+/// enforcing them.
+#[derive(Debug, Clone, Default)]
+pub struct WorkerLimitState {
+  exceeded: Arc<Mutex<Option<String>>>,
+  isolate_handle: Arc<OnceLock<v8::IsolateHandle>>,
+}
+
+impl WorkerLimitState {
+  pub fn set_isolate_handle(&self, isolate_handle: v8::IsolateHandle) {
+    let _ = self.isolate_handle.set(isolate_handle);
+  }
+
+  /// Terminates the worker for exceeding a limit. Only the first limit
+  /// exceeded is reported.
+  fn exceed(&self, message: String) {
+    let mut exceeded = self.exceeded.lock().unwrap();
+    if exceeded.is_some() {
+      return;
+    }
+    *exceeded = Some(message);
+    if let Some(isolate_handle) = self.isolate_handle.get() {
+      isolate_handle.terminate_execution();
+    }
+  }
+
+  /// The error to report to the parent, if the worker was terminated for
+  /// exceeding a limit.
+  pub fn take_error(&self) -> Option<CoreError> {
+    self
+      .exceeded
+      .lock()
+      .unwrap()
+      .take()
+      .map(|message| JsErrorBox::new("RangeError", message).into())
+  }
+
+  /// Terminates the worker when its heap nears `heap_mb`. V8 is given some
+  /// more room, so that the isolate can be terminated rather than crash.
+  pub fn near_heap_limit_callback(
+    &self,
+    heap_mb: usize,
+  ) -> impl FnMut(usize, usize) -> usize + 'static {
+    let state = self.clone();
+    move |current, _initial| {
+      state.exceed(format!("Worker exceeded its heap limit of {heap_mb} MB"));
+      current + current / 2
+    }
+  }
+
+  /// Starts counting the resources open in `op_state`, and terminates the
+  /// worker once more than `max` are open. This must be called on the worker
+  /// thread. Counting stops when the returned handle is aborted.
+  pub fn watch_open_resources(
+    &self,
+    op_state: Rc<RefCell<OpState>>,
+    max: usize,
+  ) -> JoinHandle<()> {
+    let state = self.clone();
+    deno_core::unsync::spawn(async move {
+      let mut interval = tokio::time::interval(RESOURCE_POLL_INTERVAL);
+      loop {
+        interval.tick().await;
+        let Ok(op_state) = op_state.try_borrow() else {
+          continue;
+        };
+        let open = op_state
+          .resource_table
+          .names()
+          .filter(|(_, name)| {
+            !matches!(name.as_ref(), "stdin" | "stdout" | "stderr")
+          })
+          .count();
+        if open > max {
+          state.exceed(format!(
+            "Worker exceeded its limit of {max} open resources"
+          ));
+          return;
+        }
+      }
+    })
+  }
+
+  /// Starts polling the CPU time of the current thread, which must be the
+  /// worker thread. Polling stops when the returned watchdog is dropped.
+  #[cfg(any(target_os = "android", target_os = "linux"))]
+  pub fn start_cpu_watchdog(
+    &self,
+    cpu_ms: u64,
+  ) -> std::io::Result<CpuWatchdog> {
+    let clock = linux::current_thread_cpu_clock()?;
+    let limit = Duration::from_millis(cpu_ms);
+    let stopped = Arc::new(AtomicBool::new(false));
+    let state = self.clone();
+    let watchdog = CpuWatchdog(stopped.clone());
+    std::thread::Builder::new()
+      .name("worker-cpu-watchdog".to_string())
+      .spawn(move || {
+        while !stopped.load(Ordering::Relaxed) {
+          std::thread::sleep(CPU_POLL_INTERVAL);
+          // the clock is gone once the worker thread exited
+          let Some(used) = linux::cpu_time(clock) else {
+            return;
+          };
+          if used > limit {
+            state.exceed(format!(
+              "Worker exceeded its CPU time limit of {cpu_ms} ms"
+            ));
+            return;
+          }
+        }
+      })?;
+    Ok(watchdog)
+  }
+}
+
+/// Stops polling the CPU time of a worker when dropped.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub struct CpuWatchdog(Arc<AtomicBool>);
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl Drop for CpuWatchdog {
+  fn drop(&mut self) {
+    self.0.store(true, Ordering::Relaxed);
+  }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod linux {
+  use std::io;
+  use std::time::Duration;
+
+  pub fn current_thread_cpu_clock() -> io::Result<libc::clockid_t> {
+    let mut clock: libc::clockid_t = 0;
+    // SAFETY: `clock` outlives the call
+    let result =
+      unsafe { libc::pthread_getcpuclockid(libc::pthread_self(), &mut clock) };
+    if result != 0 {
+      return Err(io::Error::from_raw_os_error(result));
+    }
+    Ok(clock)
+  }
+
+  pub fn cpu_time(clock: libc::clockid_t) -> Option<Duration> {
+    let mut time = libc::timespec {
+      tv_sec: 0,
+      tv_nsec: 0,
+    };
+    // SAFETY: `time` outlives the call
+    if unsafe { libc::clock_gettime(clock, &mut time) } != 0 {
+      return None;
+    }
+    Some(Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reports_first_limit_exceeded() {
+    let state = WorkerLimitState::default();
+    assert!(state.take_error().is_none());
+    state.exceed("Worker exceeded its heap limit of 8 MB".to_string());
+    state.exceed("Worker exceeded its CPU time limit of 5 ms".to_string());
+    let error = state.take_error().unwrap();
+    assert!(error.to_string().contains("heap limit of 8 MB"));
+    assert!(state.take_error().is_none());
+  }
+
+  #[cfg(any(target_os = "android", target_os = "linux"))]
+  #[test]
+  fn measures_thread_cpu_time() {
+    let clock = linux::current_thread_cpu_clock().unwrap();
+    let before = linux::cpu_time(clock).unwrap();
+    let mut n = 0u64;
+    for i in 0..1_000_000 {
+      n = n.wrapping_add(std::hint::black_box(i));
+    }
+    std::hint::black_box(n);
+    assert!(linux::cpu_time(clock).unwrap() > before);
+  }
+}
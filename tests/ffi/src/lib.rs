@@ -277,6 +277,31 @@ pub extern "C" fn call_stored_function_2_thread_safe(arg: u8) {
   });
 }
 
+static STORED_FUNCTION_U32: Mutex<Option<extern "C" fn(u32)>> =
+  Mutex::new(None);
+
+#[unsafe(no_mangle)]
+pub extern "C" fn store_function_u32(func: Option<extern "C" fn(u32)>) {
+  *STORED_FUNCTION_U32.lock().unwrap() = func;
+}
+
+/// Calls the stored function `count` times from another thread, and waits
+/// for the thread to finish.
+#[unsafe(no_mangle)]
+pub extern "C" fn call_stored_function_u32_from_thread(count: u32) {
+  let f = *STORED_FUNCTION_U32.lock().unwrap();
+  let Some(f) = f else {
+    return;
+  };
+  std::thread::spawn(move || {
+    for i in 0..count {
+      f(i);
+    }
+  })
+  .join()
+  .unwrap();
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn log_many_parameters(
   a: u8,
@@ -305,3 +305,37 @@ fn ffi_callback_errors_test() {
     "Illegal unhandled exception in nonblocking callback\n".repeat(3)
   );
 }
+
+#[test]
+fn nonblocking_callback() {
+  build();
+
+  let output = deno_cmd()
+    .current_dir(ffi_tests_path())
+    .arg("run")
+    .arg("--config")
+    .arg(deno_config_path())
+    .arg("--no-lock")
+    .arg("--allow-ffi")
+    .arg("--allow-read")
+    .arg("--unstable-ffi")
+    .arg("--quiet")
+    .arg("tests/nonblocking_callback_test.js")
+    .env("NO_COLOR", "1")
+    .output()
+    .unwrap();
+  let stdout = std::str::from_utf8(&output.stdout).unwrap();
+  let stderr = std::str::from_utf8(&output.stderr).unwrap();
+  if !output.status.success() {
+    println!("stdout {stdout}");
+    println!("stderr {stderr}");
+  }
+  println!("{:?}", output.status);
+  assert!(output.status.success());
+  let expected = "\
+    drop oldest: 6,7,8,9\n\
+    drop newest: 0,1,2,3\n\
+    Cannot construct UnsafeCallback: nonblocking callbacks must return void\n";
+  assert_eq!(stdout, expected);
+  assert_eq!(stderr, "");
+}
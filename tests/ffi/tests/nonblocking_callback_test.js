@@ -0,0 +1,59 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+// deno-lint-ignore-file
+
+const targetDir = Deno.execPath().replace(/[^\/\\]+$/, "");
+const [libPrefix, libSuffix] = {
+  darwin: ["lib", "dylib"],
+  linux: ["lib", "so"],
+  windows: ["", "dll"],
+}[Deno.build.os];
+const libPath = `${targetDir}/${libPrefix}test_ffi.${libSuffix}`;
+
+const dylib = Deno.dlopen(libPath, {
+  store_function_u32: {
+    parameters: ["function"],
+    result: "void",
+  },
+  call_stored_function_u32_from_thread: {
+    parameters: ["u32"],
+    result: "void",
+  },
+});
+
+// The foreign thread finishes calling while the main thread is blocked in
+// the FFI call, so the calls are only run once the queue is full.
+async function callFromThread(drop) {
+  const { promise, resolve } = Promise.withResolvers();
+  const received = [];
+  const callback = Deno.UnsafeCallback.threadSafe(
+    {
+      parameters: ["u32"],
+      result: "void",
+      nonblocking: { queueSize: 4, drop },
+    },
+    (value) => {
+      received.push(value);
+      if (received.length === 4) {
+        resolve();
+      }
+    },
+  );
+  dylib.symbols.store_function_u32(callback.pointer);
+  dylib.symbols.call_stored_function_u32_from_thread(10);
+  await promise;
+  dylib.symbols.store_function_u32(null);
+  callback.close();
+  console.log(`drop ${drop}: ${received.join(",")}`);
+}
+
+await callFromThread("oldest");
+await callFromThread("newest");
+
+try {
+  new Deno.UnsafeCallback(
+    { parameters: [], result: "u8", nonblocking: true },
+    () => 0,
+  );
+} catch (error) {
+  console.log(error.message);
+}
@@ -662,3 +662,75 @@ async fn jupyter_http_server() -> Result<()> {
 
   Ok(())
 }
+
+#[test]
+async fn jupyter_comm_messages() -> Result<()> {
+  let (_ctx, client, _process) = setup().await;
+  client
+    .send(
+      Shell,
+      "execute_request",
+      json!({
+        "silent": false,
+        "store_history": false,
+        "code": r#"Deno.jupyter.registerCommTarget("echo", (comm) => { comm.onmessage = ({ data }) => comm.send(data); })"#,
+      }),
+    )
+    .await?;
+  let reply = client.recv(Shell).await?;
+  assert_eq!(reply.header.msg_type, "execute_reply");
+
+  client
+    .send(
+      Shell,
+      "comm_open",
+      json!({
+        "comm_id": "echo-comm",
+        "target_name": "echo",
+        "data": {},
+      }),
+    )
+    .await?;
+  client
+    .send(
+      Shell,
+      "comm_open",
+      json!({
+        "comm_id": "unknown-comm",
+        "target_name": "unknown",
+        "data": {},
+      }),
+    )
+    .await?;
+  client
+    .send(
+      Shell,
+      "comm_msg",
+      json!({
+        "comm_id": "echo-comm",
+        "data": { "hello": "world" },
+      }),
+    )
+    .await?;
+
+  let mut closed = None;
+  let mut echoed = None;
+  while closed.is_none() || echoed.is_none() {
+    let msg = client.recv(IoPub).await?;
+    match msg.header.msg_type.as_str() {
+      "comm_close" => closed = Some(msg.content),
+      "comm_msg" => echoed = Some(msg.content),
+      _ => {}
+    }
+  }
+  assert_json_subset(closed.unwrap(), json!({ "comm_id": "unknown-comm" }));
+  assert_json_subset(
+    echoed.unwrap(),
+    json!({
+      "comm_id": "echo-comm",
+      "data": { "hello": "world" },
+    }),
+  );
+
+  Ok(())
+}
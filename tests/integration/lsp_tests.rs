@@ -11598,6 +11598,74 @@ fn lsp_root_with_global_reference_types() {
   assert_eq!(json!(diagnostics.all()), json!([]));
 }
 
+#[test(timeout = 300)]
+fn lsp_diagnostics_unused_code() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir();
+  temp_dir.write(
+    "deno.json",
+    json!({
+      "name": "@scope/a",
+      "exports": "./mod.ts",
+    })
+    .to_string(),
+  );
+  temp_dir.write("mod.ts", "import { a } from \"./a.ts\";\nconsole.log(a);\n");
+  temp_dir.write("dead.ts", "console.log(1);\n");
+  let file =
+    temp_dir.source_file("a.ts", "export const a = 1;\nexport const b = 2;\n");
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  client.change_configuration(json!({
+    "deno": {
+      "enable": true,
+      "unusedCode": { "enable": true },
+    },
+  }));
+  let diagnostics = client.did_open_file(&file);
+  assert_eq!(
+    json!(
+      diagnostics
+        .for_file(&url_to_uri(&file.url()).unwrap())
+        .into_iter()
+        .filter(|d| d.source.as_deref() == Some("deno"))
+        .collect::<Vec<_>>()
+    ),
+    json!([
+      {
+        "range": {
+          "start": { "line": 1, "character": 13 },
+          "end": { "line": 1, "character": 14 },
+        },
+        "severity": 4,
+        "code": "unused-export",
+        "source": "deno",
+        "message": "\"b\" is exported but never imported in the workspace.",
+        "tags": [1],
+        "data": {
+          "range": {
+            "start": { "line": 1, "character": 0 },
+            "end": { "line": 1, "character": 7 },
+          },
+        },
+      },
+    ]),
+  );
+  let dead = client.did_open(json!({
+    "textDocument": {
+      "uri": url_to_uri(&temp_dir.url().join("dead.ts").unwrap()).unwrap(),
+      "languageId": "typescript",
+      "version": 1,
+      "text": "console.log(1);\n",
+    },
+  }));
+  assert!(
+    dead.all().iter().any(|d| d.code
+      == Some(lsp::NumberOrString::String("unused-file".to_string())))
+  );
+  client.shutdown();
+}
+
 #[test(timeout = 300)]
 fn lsp_diagnostics_refresh_dependents() {
   let context = TestContextBuilder::new().use_temp_cwd().build();
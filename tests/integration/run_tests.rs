@@ -462,6 +462,42 @@ fn permissions_trace() {
     });
 }
 
+#[test(flaky)]
+fn permissions_remembered() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir();
+  temp_dir.write("main.ts", "console.log(Deno.hostname() !== '');");
+
+  context
+    .new_command()
+    .args_vec(["run", "--quiet", "--remember-permissions", "main.ts"])
+    .with_pty(|mut console| {
+      console.expect("Allow? [y/n/A]");
+      console.human_delay();
+      console.write_line_raw("y");
+      console.expect("Granted sys access to \"hostname\".");
+      console.expect("true");
+    });
+
+  let run_without_prompt = || {
+    context.new_command().args_vec([
+      "run",
+      "--quiet",
+      "--no-prompt",
+      "--remember-permissions",
+      "main.ts",
+    ])
+  };
+  run_without_prompt().run().assert_matches_text("true\n");
+
+  context
+    .new_command()
+    .args_vec(["permissions", "revoke", "main.ts"])
+    .run()
+    .assert_matches_text("Revoked remembered permissions of 1 script.\n");
+  run_without_prompt().run().assert_exit_code(1);
+}
+
 #[test]
 fn permissions_audit() {
   let ctx = TestContext::default();
@@ -478,10 +514,10 @@ fn permissions_audit() {
   let file = std::fs::read_to_string(path).unwrap();
   test_util::assertions::assert_wildcard_match(
     &file,
-    r#"{"v":1,"datetime":"[WILDCARD]","permission":"sys","value":"hostname"}
-{"v":1,"datetime":"[WILDCARD]","permission":"read","value":"[WILDCARD]"}
-{"v":1,"datetime":"[WILDCARD]","permission":"write","value":"[WILDCARD]"}
-{"v":1,"datetime":"[WILDCARD]","permission":"env","value":"FOO"}
+    r#"{"v":1,"datetime":"[WILDCARD]","permission":"sys","value":"hostname","result":"granted","prompted":false}
+{"v":1,"datetime":"[WILDCARD]","permission":"read","value":"[WILDCARD]","result":"granted","prompted":false}
+{"v":1,"datetime":"[WILDCARD]","permission":"write","value":"[WILDCARD]","result":"granted","prompted":false}
+{"v":1,"datetime":"[WILDCARD]","permission":"env","value":"FOO","result":"granted","prompted":false}
 "#,
   );
 }
@@ -503,10 +539,37 @@ fn permissions_audit_with_traces() {
   let file = std::fs::read_to_string(path).unwrap();
   test_util::assertions::assert_wildcard_match(
     &file,
-    r#"{"v":1,"datetime":"[WILDCARD]","permission":"sys","value":"hostname","stack":[WILDCARD]}
-{"v":1,"datetime":"[WILDCARD]","permission":"read","value":"[WILDCARD]","stack":[WILDCARD]}
-{"v":1,"datetime":"[WILDCARD]","permission":"write","value":"[WILDCARD]","stack":[WILDCARD]}
-{"v":1,"datetime":"[WILDCARD]","permission":"env","value":"FOO","stack":[WILDCARD]}
+    r#"{"v":1,"datetime":"[WILDCARD]","permission":"sys","value":"hostname","result":"granted","prompted":false,"stack":[WILDCARD]}
+{"v":1,"datetime":"[WILDCARD]","permission":"read","value":"[WILDCARD]","result":"granted","prompted":false,"stack":[WILDCARD]}
+{"v":1,"datetime":"[WILDCARD]","permission":"write","value":"[WILDCARD]","result":"granted","prompted":false,"stack":[WILDCARD]}
+{"v":1,"datetime":"[WILDCARD]","permission":"env","value":"FOO","result":"granted","prompted":false,"stack":[WILDCARD]}
+"#,
+  );
+}
+
+#[test]
+fn permissions_audit_flag() {
+  let ctx = TestContext::default();
+  let dir = ctx.temp_dir();
+  let path = dir.path().join(std::path::Path::new("audit.jsonl"));
+
+  ctx
+    .new_command()
+    .args_vec([
+      "run",
+      "--allow-sys",
+      "--no-prompt",
+      &format!("--permission-audit={}", path),
+      "run/permissions_audit.ts",
+    ])
+    .run()
+    .skip_output_check();
+
+  let file = std::fs::read_to_string(path).unwrap();
+  test_util::assertions::assert_wildcard_match(
+    &file,
+    r#"{"v":1,"datetime":"[WILDCARD]","permission":"sys","value":"hostname","result":"granted","prompted":false}
+{"v":1,"datetime":"[WILDCARD]","result":"denied","prompted":false}
 "#,
   );
 }
@@ -1229,6 +1292,29 @@ fn deno_log() {
   assert!(!output.stderr.is_empty());
 }
 
+#[test]
+fn deno_log_format_json() {
+  let output = util::deno_cmd()
+    .current_dir(util::testdata_path())
+    .arg("run")
+    .arg("run/001_hello.js")
+    .env("DENO_LOG", "debug")
+    .env("DENO_LOG_FORMAT", "json")
+    .stderr_piped()
+    .spawn()
+    .unwrap()
+    .wait_with_output()
+    .unwrap();
+  assert!(output.status.success());
+  let stderr = String::from_utf8(output.stderr).unwrap();
+  assert!(!stderr.is_empty());
+  for line in stderr.lines() {
+    let record: serde_json::Value = serde_json::from_str(line).unwrap();
+    assert!(record["level"].is_string());
+    assert!(record["target"].is_string());
+  }
+}
+
 #[test]
 fn dont_cache_on_check_fail() {
   let context = TestContext::default();
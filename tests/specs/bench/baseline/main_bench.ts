@@ -0,0 +1,7 @@
+Deno.bench("sum", () => {
+  let sum = 0;
+  for (let i = 0; i < 1000; i++) {
+    sum += i;
+  }
+  if (sum < 0) throw new Error("unreachable");
+});
@@ -0,0 +1,13 @@
+const cache = await caches.open("max_size");
+const body = "0123456789";
+
+await cache.put("http://localhost/a", new Response(body));
+await cache.put("http://localhost/b", new Response(body));
+// makes "b" the least recently used
+await cache.match("http://localhost/a");
+await cache.put("http://localhost/c", new Response(body));
+
+for (const name of ["a", "b", "c"]) {
+  const response = await cache.match(`http://localhost/${name}`);
+  console.log(name, response ? await response.text() : undefined);
+}
@@ -0,0 +1,10 @@
+const res = await fetch("https://localhost:5545/cert/cafile_ts_fetch.ts.out");
+await res.body?.cancel();
+
+let log = "";
+try {
+  log = Deno.readTextFileSync("keylog.txt");
+} catch (err) {
+  if (!(err instanceof Deno.errors.NotFound)) throw err;
+}
+console.log(/^(CLIENT_RANDOM|CLIENT_TRAFFIC_SECRET_0) /m.test(log));
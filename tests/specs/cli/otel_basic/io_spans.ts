@@ -0,0 +1,17 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+import { trace } from "npm:@opentelemetry/api@1.9.0";
+
+const tracer = trace.getTracer("example-tracer");
+const path = await Deno.makeTempFile();
+
+// ops outside of a span don't get one
+await Deno.writeFile(path, new Uint8Array(3));
+
+await tracer.startActiveSpan("handler", async (span) => {
+  await Deno.writeFile(path, new Uint8Array(5));
+  await Deno.readFile(path);
+  span.end();
+});
+
+await Deno.remove(path);
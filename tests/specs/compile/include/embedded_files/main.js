@@ -0,0 +1,5 @@
+const files = Deno.embeddedFiles().map((path) =>
+  path.slice(import.meta.dirname.length + 1).replaceAll("\\", "/")
+);
+console.log(files);
+console.log(Deno.readTextFileSync(import.meta.dirname + "/assets/index.html"));
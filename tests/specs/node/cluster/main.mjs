@@ -0,0 +1,43 @@
+import cluster from "node:cluster";
+import http from "node:http";
+
+const port = 4612;
+
+if (cluster.isPrimary) {
+  let listening = 0;
+  let pongs = 0;
+  for (let i = 0; i < 2; i++) {
+    cluster.fork();
+  }
+  cluster.on("listening", (_worker, address) => {
+    if (address.port !== port || ++listening < 2) {
+      return;
+    }
+    for (const worker of Object.values(cluster.workers)) {
+      worker.send("ping");
+    }
+  });
+  cluster.on("message", (_worker, message) => {
+    if (message !== "pong" || ++pongs < 2) {
+      return;
+    }
+    http.get({ port, agent: false }, (res) => {
+      res.setEncoding("utf8");
+      res.on("data", (data) => console.log(data));
+      res.on("end", () => {
+        cluster.disconnect(() => console.log("disconnected"));
+      });
+    });
+  });
+  cluster.on("exit", (worker, code) => {
+    console.log(`worker exited: ${code} ${worker.exitedAfterDisconnect}`);
+  });
+} else {
+  http.createServer((_req, res) => res.end("hello from a worker"))
+    .listen(port);
+  process.on("message", (message) => {
+    if (message === "ping") {
+      process.send("pong");
+    }
+  });
+}
@@ -0,0 +1,4 @@
+const profile = JSON.parse(Deno.readTextFileSync("app.cpuprofile"));
+console.log(Array.isArray(profile.nodes), profile.startTime > 0);
+const snapshot = JSON.parse(Deno.readTextFileSync("app.heapsnapshot"));
+console.log(Array.isArray(snapshot.snapshot.meta.node_fields));
@@ -0,0 +1,5 @@
+let sum = 0;
+for (let i = 0; i < 100_000; i++) {
+  sum += i;
+}
+console.log(sum > 0 ? "done" : "");
@@ -0,0 +1,4 @@
+const file = await Deno.open("main.ts");
+const closed = await Deno.open("main.ts");
+closed.close();
+console.log("opened", file instanceof Deno.FsFile);
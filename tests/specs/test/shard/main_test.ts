@@ -0,0 +1,3 @@
+for (let i = 0; i < 8; i++) {
+  Deno.test(`test ${i}`, () => {});
+}
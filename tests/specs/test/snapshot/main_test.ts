@@ -0,0 +1,7 @@
+Deno.test("greeting", async (t) => {
+  const name = Deno.env.get("GREETING_NAME") ?? "world";
+  t.assertSnapshot({ greeting: `hello ${name}`, tags: ["a", "b"] });
+  await t.step("step", (t) => {
+    t.assertSnapshot("in a step");
+  });
+});
@@ -0,0 +1,71 @@
+function assertEquals(actual: unknown, expected: unknown) {
+  if (actual !== expected) {
+    throw new Error(`Expected ${expected}, got ${actual}`);
+  }
+}
+
+Deno.test({
+  name: "timers",
+  virtualTime: { now: 1_000_000 },
+  fn(t) {
+    const start = performance.now();
+    const fired: string[] = [];
+    setTimeout(() => fired.push("b"), 200);
+    setTimeout(() => fired.push("a"), 100);
+    const id = setTimeout(() => fired.push("cleared"), 150);
+    clearTimeout(id);
+    const interval = setInterval(() => fired.push("i"), 80);
+    assertEquals(t.clock.pending, 3);
+    t.clock.tick(199);
+    assertEquals(fired.join(), "i,a,i");
+    assertEquals(t.clock.now, 1_000_199);
+    assertEquals(Math.round(performance.now() - start), 199);
+    assertEquals(Date.now() > 1_000_000_000_000, true);
+    t.clock.tick(1);
+    assertEquals(fired.join(), "i,a,i,b");
+    clearInterval(interval);
+    assertEquals(t.clock.pending, 0);
+  },
+});
+
+Deno.test({
+  name: "tickAsync",
+  virtualTime: true,
+  async fn(t) {
+    const start = performance.now();
+    const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
+    let done = false;
+    (async () => {
+      await sleep(10);
+      await sleep(10);
+      done = true;
+    })();
+    await t.step("step", async (t) => {
+      await t.clock.tickAsync(20);
+    });
+    assertEquals(done, true);
+    assertEquals(Math.round(performance.now() - start), 20);
+  },
+});
+
+Deno.test("runAll", { virtualTime: true }, (t) => {
+  let count = 0;
+  const next = () => {
+    if (++count < 5) {
+      setTimeout(next, 1000);
+    }
+  };
+  setTimeout(next, 1000);
+  t.clock.runAll();
+  assertEquals(count, 5);
+});
+
+Deno.test("real time after", async () => {
+  const start = performance.now();
+  await new Promise((r) => setTimeout(r, 5));
+  assertEquals(performance.now() - start >= 4, true);
+});
+
+Deno.test("no virtual time", (t) => {
+  t.clock;
+});
@@ -0,0 +1,11 @@
+const dir = new URL(".", import.meta.url).pathname;
+const files = [
+  "text_decoder_perf.js",
+  "text_encoder_perf.js",
+  "text_encoder_into_perf.js",
+  "response_string_perf.js",
+].map((file) => dir + file);
+
+for (let i = 0; i < 20_000; i++) {
+  for (const file of files) Deno.statSync(file);
+}
@@ -0,0 +1,18 @@
+self.onmessage = async function ({ data }) {
+  if (data === "heap") {
+    const chunks = [];
+    while (true) {
+      chunks.push(new Array(1024 * 1024).fill(chunks.length));
+    }
+  } else if (data === "cpu") {
+    while (true) {
+      // spin
+    }
+  } else if (data === "fds") {
+    const files = [];
+    while (true) {
+      // open resources are counted while the event loop runs
+      files.push(await Deno.open(new URL(import.meta.url)));
+    }
+  }
+};
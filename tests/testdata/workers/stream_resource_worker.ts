@@ -0,0 +1,10 @@
+self.onmessage = async (e) => {
+  const { readable, writable } = e.data;
+  if (writable) {
+    // echoes a connection
+    await readable.pipeTo(writable);
+    self.postMessage("echoed");
+  } else {
+    self.postMessage(await new Response(readable).text());
+  }
+};
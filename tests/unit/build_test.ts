@@ -1,5 +1,5 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
-import { assert } from "./test_util.ts";
+import { assert, assertEquals } from "./test_util.ts";
 
 Deno.test(function buildInfo() {
   // Deno.build is injected by rollup at compile time. Here
@@ -8,3 +8,12 @@ Deno.test(function buildInfo() {
   assert(arch.length > 0);
   assert(os === "darwin" || os === "windows" || os === "linux");
 });
+
+Deno.test(function buildFeatures() {
+  const { features, os } = Deno.build;
+  assert(Object.isFrozen(features));
+  // parts that every build has are not listed
+  assert(!features.includes("ffi"));
+  assertEquals(features.includes("pty"), os !== "windows");
+  assertEquals(features.includes("cgroups"), os === "linux");
+});
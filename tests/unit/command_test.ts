@@ -1248,3 +1248,124 @@ Deno.test({ permissions: { run: true } }, async function collectText() {
 
   await process.status;
 });
+
+Deno.test(
+  {
+    permissions: { run: true, read: true },
+    ignore: Deno.build.os === "windows",
+  },
+  async function commandPty() {
+    const command = new Deno.Command(Deno.execPath(), {
+      args: [
+        "eval",
+        `const { columns, rows } = Deno.consoleSize();
+        console.log(Deno.stdin.isTerminal(), Deno.stdout.isTerminal());
+        console.log(columns, rows);
+        const buf = new Uint8Array(16);
+        const n = await Deno.stdin.read(buf);
+        const input = new TextDecoder().decode(buf.subarray(0, n));
+        console.log("got", input.trim());`,
+      ],
+      pty: { rows: 24, cols: 100 },
+    });
+    assertThrows(() => command.outputSync(), TypeError);
+    const child = command.spawn();
+    assertThrows(() => child.stdout, TypeError);
+
+    const writer = child.pty.writable.getWriter();
+    await writer.write(new TextEncoder().encode("hello\n"));
+    writer.releaseLock();
+
+    // the terminal echoes the input and translates newlines
+    const output = await child.pty.readable.text();
+    assertStringIncludes(output, "true true\r\n");
+    assertStringIncludes(output, "100 24\r\n");
+    assertStringIncludes(output, "got hello\r\n");
+    assertEquals((await child.status).success, true);
+  },
+);
+
+Deno.test(
+  {
+    permissions: { run: true, read: true },
+    ignore: Deno.build.os === "windows",
+  },
+  async function commandPtyResize() {
+    const child = new Deno.Command(Deno.execPath(), {
+      args: [
+        "eval",
+        `Deno.addSignalListener("SIGWINCH", () => {
+          const { columns, rows } = Deno.consoleSize();
+          console.log("resized", columns, rows);
+          Deno.exit(0);
+        });
+        console.log("ready");`,
+      ],
+      pty: { rows: 24, cols: 80 },
+    }).spawn();
+
+    const decoder = new TextDecoder();
+    let output = "";
+    let resized = false;
+    for await (const chunk of child.pty.readable) {
+      output += decoder.decode(chunk);
+      if (!resized && output.includes("ready")) {
+        child.pty.resize(40, 120);
+        resized = true;
+      }
+      if (output.includes("resized")) {
+        break;
+      }
+    }
+    assertStringIncludes(output, "resized 120 40");
+    await child.status;
+  },
+);
+
+Deno.test(
+  { permissions: { run: true, read: true } },
+  function commandPtyWithStdio() {
+    assertThrows(
+      () =>
+        new Deno.Command(Deno.execPath(), {
+          pty: { rows: 24, cols: 80 },
+          stdout: "piped",
+        }).spawn(),
+      TypeError,
+      "Cannot use 'stdin', 'stdout' or 'stderr' together with 'pty'",
+    );
+  },
+);
+
+Deno.test(
+  {
+    permissions: { run: ["id"], read: true },
+    ignore: Deno.build.os === "windows",
+  },
+  async function commandPrivilegedOptionsRequireAllowRun() {
+    for (
+      const options of [{ groups: [0] }, { chroot: "/" }, { cgroup: "/" }]
+    ) {
+      await assertRejects(
+        () => new Deno.Command("id", options).output(),
+        Deno.errors.NotCapable,
+        "Requires --allow-run permissions to spawn subprocess with the",
+      );
+    }
+  },
+);
+
+Deno.test(
+  {
+    permissions: { run: true, read: true },
+    ignore: Deno.build.os !== "linux",
+  },
+  async function commandCgroupNotFound() {
+    await assertRejects(
+      () =>
+        new Deno.Command("true", { cgroup: "/sys/fs/cgroup/nonexistent" })
+          .output(),
+      Deno.errors.NotFound,
+    );
+  },
+);
@@ -1,5 +1,5 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
-import { assert, assertThrows, fail } from "./test_util.ts";
+import { assert, assertEquals, assertThrows, fail } from "./test_util.ts";
 
 Deno.test("Errors work", () => {
   assert(new Deno.errors.NotFound("msg") instanceof Error);
@@ -23,8 +23,53 @@ Deno.test("Errors work", () => {
   assert(new Deno.errors.Busy("msg") instanceof Error);
   assert(new Deno.errors.NotSupported("msg") instanceof Error);
   assert(new Deno.errors.NotCapable("msg") instanceof Error);
+  assert(new Deno.errors.CrossDevice("msg") instanceof Error);
+  assert(new Deno.errors.TextFileBusy("msg") instanceof Error);
+  assert(new Deno.errors.FileTooLarge("msg") instanceof Error);
+  assert(new Deno.errors.DirectoryNotEmpty("msg") instanceof Error);
+  assert(new Deno.errors.ReadOnlyFileSystem("msg") instanceof Error);
+  assert(new Deno.errors.StorageFull("msg") instanceof Error);
 });
 
+Deno.test(
+  { permissions: { read: true } },
+  function fsErrorsHaveSyscallPathAndErrno() {
+    const path = Deno.makeTempDirSync() + "/missing";
+    const err = assertThrows(() => Deno.statSync(path), Deno.errors.NotFound);
+    // deno-lint-ignore no-explicit-any
+    const { syscall, path: errPath, errno, code } = err as any;
+    assertEquals(syscall, "stat");
+    assertEquals(errPath, path);
+    assertEquals(code, "ENOENT");
+    assertEquals(typeof errno, "number");
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  function fsErrorsHaveDest() {
+    const dir = Deno.makeTempDirSync();
+    const err = assertThrows(
+      () => Deno.renameSync(dir + "/missing", dir + "/other"),
+      Deno.errors.NotFound,
+    );
+    // deno-lint-ignore no-explicit-any
+    assertEquals((err as any).syscall, "rename");
+    // deno-lint-ignore no-explicit-any
+    assertEquals((err as any).dest, dir + "/other");
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  function fsErrorDirectoryNotEmpty() {
+    const dir = Deno.makeTempDirSync();
+    Deno.writeTextFileSync(dir + "/file.txt", "hello");
+    assertThrows(() => Deno.removeSync(dir), Deno.errors.DirectoryNotEmpty);
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
+
 Deno.test("Errors have some tamper resistance", () => {
   // deno-lint-ignore no-explicit-any
   (Object.prototype as any).get = () => {};
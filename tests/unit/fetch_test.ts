@@ -744,7 +744,7 @@ Deno.test(
       "accept: */*\r\n",
       "accept-language: *\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n",
+      "accept-encoding: gzip,br,zstd\r\n",
       `host: ${addr}\r\n\r\n`,
     ].join("");
     assertEquals(actual, expected);
@@ -776,7 +776,7 @@ Deno.test(
       "accept: text/html\r\n",
       "accept-language: en-US\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n",
+      "accept-encoding: gzip,br,zstd\r\n",
       `host: ${addr}\r\n\r\n`,
     ].join("");
     assertEquals(actual, expected);
@@ -813,7 +813,7 @@ Deno.test(
       "accept: */*\r\n",
       "accept-language: *\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n",
+      "accept-encoding: gzip,br,zstd\r\n",
       `host: ${addr}\r\n`,
       `\r\n`,
       body,
@@ -852,7 +852,7 @@ Deno.test(
       "accept: */*\r\n",
       "accept-language: *\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n",
+      "accept-encoding: gzip,br,zstd\r\n",
       `host: ${addr}\r\n`,
       `\r\n`,
       bodyStr,
@@ -885,7 +885,7 @@ Deno.test(
       "accept: */*\r\n",
       "accept-language: *\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n",
+      "accept-encoding: gzip,br,zstd\r\n",
       `host: ${addr}\r\n\r\n`,
     ].join("");
     assertEquals(actual, expected);
@@ -917,7 +917,7 @@ Deno.test(
       "accept: */*\r\n",
       "accept-language: *\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n\r\n",
+      "accept-encoding: gzip,br,zstd\r\n\r\n",
     ].join("");
     assertEquals(actual, expected);
   },
@@ -1300,7 +1300,7 @@ Deno.test(
       "accept: */*\r\n",
       "accept-language: *\r\n",
       `user-agent: Deno/${Deno.version.deno}\r\n`,
-      "accept-encoding: gzip,br\r\n",
+      "accept-encoding: gzip,br,zstd\r\n",
       `host: ${addr}\r\n`,
       `transfer-encoding: chunked\r\n\r\n`,
       "B\r\n",
@@ -1643,6 +1643,29 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { net: true, read: true } },
+  async function fetchHttp2PriorKnowledge() {
+    const caCert = await Deno.readTextFile("tests/testdata/tls/RootCA.pem");
+    const client = Deno.createHttpClient({
+      caCerts: [caCert],
+      http2PriorKnowledge: true,
+    });
+    const h2c = await fetch("http://localhost:5549/http_version", { client });
+    assertEquals(await h2c.text(), "HTTP/2.0");
+    // TLS connections still negotiate the protocol
+    const tls = await fetch("https://localhost:5546/http_version", { client });
+    assertEquals(await tls.text(), "HTTP/1.1");
+    client.close();
+
+    assertThrows(
+      () => Deno.createHttpClient({ http2: false, http2PriorKnowledge: true }),
+      TypeError,
+      "`http2PriorKnowledge` requires `http2`",
+    );
+  },
+);
+
 Deno.test(
   { permissions: { net: true, read: true } },
   async function fetchPrefersHttp2() {
@@ -1769,6 +1792,65 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { read: true } },
+  async function fetchFileContentLength() {
+    const fixture = await Deno.readFile("tests/testdata/subdir/json_1.json");
+    const res = await fetch(
+      import.meta.resolve("../testdata/subdir/json_1.json"),
+    );
+    assertEquals(res.status, 200);
+    assertEquals(res.headers.get("content-length"), String(fixture.length));
+    assertEquals(await res.bytes(), fixture);
+  },
+);
+
+Deno.test(
+  { permissions: { read: true } },
+  async function fetchFileRange() {
+    const fixture = await Deno.readFile("tests/testdata/subdir/json_1.json");
+    const url = import.meta.resolve("../testdata/subdir/json_1.json");
+
+    let res = await fetch(url, { headers: { Range: "bytes=2-5" } });
+    assertEquals(res.status, 206);
+    assertEquals(
+      res.headers.get("content-range"),
+      `bytes 2-5/${fixture.length}`,
+    );
+    assertEquals(res.headers.get("content-length"), "4");
+    assertEquals(await res.bytes(), fixture.subarray(2, 6));
+
+    res = await fetch(url, { headers: { Range: "bytes=-3" } });
+    assertEquals(res.status, 206);
+    assertEquals(await res.bytes(), fixture.subarray(fixture.length - 3));
+
+    res = await fetch(url, { headers: { Range: `bytes=${fixture.length}-` } });
+    assertEquals(res.status, 416);
+    assertEquals(
+      res.headers.get("content-range"),
+      `bytes */${fixture.length}`,
+    );
+    assertEquals((await res.bytes()).byteLength, 0);
+
+    // multiple ranges are not supported, so the whole file is returned
+    res = await fetch(url, { headers: { Range: "bytes=0-1,3-4" } });
+    assertEquals(res.status, 200);
+    assertEquals(await res.bytes(), fixture);
+  },
+);
+
+Deno.test(
+  { permissions: { read: true } },
+  async function fetchFileDirectory() {
+    await assertRejects(
+      async () => {
+        await fetch(import.meta.resolve("../testdata/subdir/"));
+      },
+      TypeError,
+    );
+  },
+);
+
 Deno.test(
   { permissions: { net: true } },
   async function fetchContentLengthPost() {
@@ -2268,6 +2350,131 @@ Deno.test(
   },
 );
 
+Deno.test(
+  {
+    permissions: { net: true, read: true, write: true },
+    ignore: Deno.build.os === "windows",
+  },
+  async function fetchUnixSocketPath() {
+    const tempDir = await Deno.makeTempDir();
+    const socketPath = `${tempDir}/unix.sock`;
+
+    await using _server = Deno.serve({
+      path: socketPath,
+      transport: "unix",
+      onListen: () => {},
+    }, (req) => new Response(new URL(req.url).pathname));
+
+    const resolvedPath = await Deno.realPath(socketPath);
+    using client = Deno.createHttpClient({ unixSocketPath: resolvedPath });
+    const resp = await fetch("http://docker/containers/json", { client });
+    assertEquals(await resp.text(), "/containers/json");
+
+    assertThrows(
+      () =>
+        Deno.createHttpClient({
+          unixSocketPath: resolvedPath,
+          proxy: { transport: "unix", path: resolvedPath },
+        }),
+      TypeError,
+      "Cannot specify both 'unixSocketPath' and 'proxy' options",
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpClientPoolStats() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    await using _server = Deno.serve(
+      { port: 0, onListen },
+      () => new Response("hello"),
+    );
+    const { port } = await listening;
+    const origin = `http://localhost:${port}`;
+
+    using client = Deno.createHttpClient({
+      http2KeepAliveInterval: 1000,
+      http2KeepAliveTimeout: 500,
+    });
+    assertEquals(client.poolStats(), []);
+
+    const resp = await fetch(origin, { client });
+    assertEquals(client.poolStats(), [
+      { origin, connections: 1, idleConnections: 0, activeRequests: 1 },
+    ]);
+    assertEquals(await resp.text(), "hello");
+    assertEquals(client.poolStats(), [
+      { origin, connections: 1, idleConnections: 1, activeRequests: 0 },
+    ]);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function fetchDecodesZstd() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    await using _server = Deno.serve({ port: 0, onListen }, (req) => {
+      // a zstd frame holding "hello" in a single raw block
+      const body = new Uint8Array([
+        0x28, 0xb5, 0x2f, 0xfd, 0x20, 0x05, 0x29, 0x00, 0x00,
+        0x68, 0x65, 0x6c, 0x6c, 0x6f,
+      ]);
+      return new Response(body, {
+        headers: {
+          "content-encoding": "zstd",
+          "x-accept-encoding": req.headers.get("accept-encoding") ?? "",
+        },
+      });
+    });
+    const { port } = await listening;
+
+    const resp = await fetch(`http://localhost:${port}/`);
+    assertEquals(resp.headers.get("x-accept-encoding"), "gzip,br,zstd");
+    assertEquals(await resp.text(), "hello");
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function fetchCompressRequestBody() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    await using _server = Deno.serve({ port: 0, onListen }, (req) => {
+      const encoding = req.headers.get("content-encoding");
+      const body = encoding === "gzip"
+        ? req.body!.pipeThrough(new DecompressionStream("gzip"))
+        : req.body;
+      return new Response(body, {
+        headers: { "x-content-encoding": encoding ?? "none" },
+      });
+    });
+    const { port } = await listening;
+    const url = `http://localhost:${port}/`;
+
+    using client = Deno.createHttpClient({ compressRequestBody: "gzip" });
+    const resp = await fetch(url, {
+      client,
+      method: "POST",
+      body: "hello world",
+    });
+    assertEquals(resp.headers.get("x-content-encoding"), "gzip");
+    assertEquals(await resp.text(), "hello world");
+
+    // already encoded bodies are left alone
+    const encoded = await fetch(url, {
+      client,
+      method: "POST",
+      headers: { "content-encoding": "identity" },
+      body: "hello world",
+    });
+    assertEquals(encoded.headers.get("x-content-encoding"), "identity");
+    assertEquals(await encoded.text(), "hello world");
+  },
+);
+
 Deno.test(
   {
     permissions: { net: true },
@@ -527,6 +527,65 @@ Deno.test({ permissions: { read: true } }, function seekSyncEnd() {
   assertEquals(decoded, "world!");
 });
 
+Deno.test({ permissions: { read: true } }, function seekSyncNegativeStart() {
+  const filename = "tests/testdata/assets/hello.txt";
+  using file = Deno.openSync(filename);
+  assertThrows(
+    () => {
+      file.seekSync(-1, Deno.SeekMode.Start);
+    },
+    TypeError,
+    "Invalid seek offset",
+  );
+});
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  function seekSyncPastFourGiB() {
+    const filename = Deno.makeTempDirSync() + "/test_seekSyncPastFourGiB";
+    using file = Deno.openSync(filename, {
+      create: true,
+      read: true,
+      write: true,
+    });
+    // Sparse, so this does not allocate 5 GiB on disk.
+    const size = 5 * 2 ** 30;
+    file.truncateSync(size);
+    assertEquals(file.statSync().size, size);
+
+    const offset = 2 ** 32 + 7;
+    assertEquals(file.seekSync(offset, Deno.SeekMode.Start), offset);
+    file.writeSync(new Uint8Array([42]));
+    assertEquals(file.seekSync(0, Deno.SeekMode.Current), offset + 1);
+    assertEquals(file.seekSync(-1, Deno.SeekMode.End), size - 1);
+
+    const buf = new Uint8Array(1);
+    file.seekSync(offset, Deno.SeekMode.Start);
+    file.readSync(buf);
+    assertEquals(buf[0], 42);
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function seekBigIntPastFourGiB() {
+    const filename = Deno.makeTempDirSync() + "/test_seekBigIntPastFourGiB";
+    using file = await Deno.open(filename, {
+      create: true,
+      read: true,
+      write: true,
+    });
+    const size = 5n * 2n ** 30n;
+    await file.truncate(size);
+    assertEquals((await file.stat()).size, Number(size));
+
+    const offset = 2n ** 32n + 7n;
+    assertEquals(await file.seek(offset, Deno.SeekMode.Start), offset);
+    assertEquals(file.seekSync(0n, Deno.SeekMode.Current), offset);
+    assertEquals(await file.seek(-1n, Deno.SeekMode.End), size - 1n);
+  },
+);
+
 Deno.test({ permissions: { read: true } }, async function seekMode() {
   const filename = "tests/testdata/assets/hello.txt";
   using file = await Deno.open(filename);
@@ -773,6 +832,113 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function fsFileSetBuffering() {
+    const filename = Deno.makeTempDirSync() + "/test_setBuffering.txt";
+    const file = Deno.openSync(filename, {
+      create: true,
+      read: true,
+      write: true,
+    });
+    const encoder = new TextEncoder();
+    file.setBuffering({ bytes: 8 });
+
+    assertEquals(file.writeSync(encoder.encode("abc")), 3);
+    assertEquals(await file.write(encoder.encode("def")), 3);
+    assertEquals(Deno.readTextFileSync(filename), "");
+    // doesn't fit, so the buffer is written first
+    file.writeSync(encoder.encode("ghi"));
+    assertEquals(Deno.readTextFileSync(filename), "abcdef");
+    file.flushSync();
+    assertEquals(Deno.readTextFileSync(filename), "abcdefghi");
+    // larger writes aren't buffered
+    file.writeSync(encoder.encode("0123456789"));
+    assertEquals(Deno.readTextFileSync(filename), "abcdefghi0123456789");
+
+    // other operations see the buffered data
+    file.writeSync(encoder.encode("jk"));
+    assertEquals(file.statSync().size, 21);
+
+    file.writeSync(encoder.encode("lm"));
+    file.setBuffering(null);
+    assertEquals(Deno.readTextFileSync(filename), "abcdefghi0123456789jklm");
+    file.writeSync(encoder.encode("n"));
+    assertEquals(Deno.readTextFileSync(filename), "abcdefghi0123456789jklmn");
+
+    file.setBuffering({ bytes: 8 });
+    file.writeSync(encoder.encode("op"));
+    file.close();
+    assertEquals(
+      Deno.readTextFileSync(filename),
+      "abcdefghi0123456789jklmnop",
+    );
+    Deno.removeSync(filename);
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function fsFileSetBufferingFlushMs() {
+    const filename = Deno.makeTempDirSync() + "/test_setBufferingFlushMs.txt";
+    using file = Deno.openSync(filename, { create: true, write: true });
+    file.setBuffering({ flushMs: 10 });
+    file.writeSync(new TextEncoder().encode("hello"));
+    assertEquals(Deno.readTextFileSync(filename), "");
+    await new Promise((resolve) => setTimeout(resolve, 100));
+    assertEquals(Deno.readTextFileSync(filename), "hello");
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function fsFileSetBufferingKeepsOrder() {
+    const filename = Deno.makeTempDirSync() + "/test_setBufferingOrder.txt";
+    using file = Deno.openSync(filename, { create: true, write: true });
+    const encoder = new TextEncoder();
+    file.writeSync(encoder.encode("abcdef"));
+    file.setBuffering({ bytes: 4 });
+    // the write isn't awaited before, but lands after the seek
+    const seek = file.seek(1, Deno.SeekMode.Start);
+    const write = file.write(encoder.encode("WXYZ"));
+    assertEquals(await seek, 1);
+    assertEquals(await write, 4);
+    assertEquals(Deno.readTextFileSync(filename), "aWXYZf");
+  },
+);
+
+Deno.test(
+  {
+    ignore: Deno.build.os !== "linux",
+    permissions: { read: true, write: true },
+  },
+  async function fsFileSetBufferingFailedFlushFailsOp() {
+    // writes to /dev/full fail with ENOSPC
+    using file = Deno.openSync("/dev/full", { write: true });
+    file.setBuffering({ bytes: 16 });
+    file.writeSync(new TextEncoder().encode("abc"));
+    await assertRejects(() => file.stat());
+    // the data was dropped with the error
+    await file.stat();
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  function fsFileSetBufferingInvalidOptions() {
+    const filename = Deno.makeTempDirSync() + "/test_setBufferingInvalid.txt";
+    using file = Deno.openSync(filename, { create: true, write: true });
+    assertThrows(() => file.setBuffering({ bytes: -1 }), RangeError);
+    assertThrows(() => file.setBuffering({ flushMs: 1.5 }), RangeError);
+    assertThrows(
+      () => file.setBuffering({ bytes: Number.MAX_SAFE_INTEGER }),
+      RangeError,
+    );
+    // nothing to flush
+    file.flushSync();
+  },
+);
+
 Deno.test({ permissions: { read: true } }, function fsFileIsTerminal() {
   // CI not under TTY, so cannot test stdin/stdout/stderr.
   using file = Deno.openSync("tests/testdata/assets/hello.txt");
@@ -2461,3 +2461,37 @@ dbTest("AtomicOperation custom inspect - handles special values", (db) => {
   assert(inspected.includes('set([ "undefined" ], undefined)'));
   assert(inspected.includes('set([ "bigint" ], 9007199254740991n)'));
 });
+
+Deno.test({
+  name: "backup and restore",
+  async fn() {
+    const dir = await Deno.makeTempDir({ prefix: "kv_backup" });
+    try {
+      const db = await Deno.openKv(`${dir}/kv.sqlite3`);
+      await db.set(["a"], "before");
+      await db.backup(`${dir}/backup.sqlite3`);
+      await db.set(["a"], "after");
+      await db.set(["b"], 1);
+
+      const backup = await Deno.openKv(`${dir}/backup.sqlite3`);
+      assertEquals((await backup.get(["a"])).value, "before");
+      assertEquals((await backup.get(["b"])).value, null);
+      backup.close();
+
+      await db.restore(`${dir}/backup.sqlite3`);
+      assertEquals((await db.get(["a"])).value, "before");
+      assertEquals((await db.get(["b"])).value, null);
+      db.close();
+    } finally {
+      await Deno.remove(dir, { recursive: true });
+    }
+  },
+});
+
+dbTest("backup of an in-memory database throws", async (db) => {
+  await assertRejects(
+    () => db.backup("./backup.sqlite3"),
+    TypeError,
+    "Cannot back up or restore an in-memory database",
+  );
+});
@@ -460,6 +460,26 @@ Deno.test("Plugin - parent", () => {
   assertEquals(parent?.type, "Program");
 });
 
+Deno.test("Plugin - sourceCode.getBinding()", () => {
+  const bindings: Array<[string, Deno.lint.Binding | null]> = [];
+
+  testPlugin("const a = 1;\nfunction f(b) {\n  return a + b + c;\n}", {
+    create(ctx) {
+      return {
+        "ReturnStatement Identifier"(node) {
+          bindings.push([node.name, ctx.sourceCode.getBinding(node)]);
+        },
+      };
+    },
+  });
+
+  assertEquals(bindings, [
+    ["a", { kind: "const", range: [6, 7] }],
+    ["b", { kind: "param", range: [24, 25] }],
+    ["c", null],
+  ]);
+});
+
 Deno.test("Plugin - Program", async (t) => {
   await testSnapshot(t, "", "Program");
 });
@@ -80,6 +80,12 @@ fn run_test(test: &CollectedTest) -> TestResult {
     deno = deno.arg("--unstable-cron");
   }
 
+  if test.name.ends_with("::serve_file_test")
+    || test.name.ends_with("::server_sent_events_test")
+  {
+    deno = deno.arg("--unstable-http");
+  }
+
   if test.name.contains("::kv_") {
     deno = deno.arg("--unstable-kv");
   }
@@ -205,6 +205,186 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { net: true } },
+  async function netTcpDialFallsBackToOtherAddresses() {
+    // `localhost` usually resolves to `::1` as well, which nothing listens on
+    using listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    const accepted = listener.accept();
+    using conn = await Deno.connect({
+      hostname: "localhost",
+      port: listener.addr.port,
+      happyEyeballsDelay: 10,
+    });
+    using _server = await accepted;
+    assertEquals(conn.remoteAddr.hostname, "127.0.0.1");
+  },
+);
+
+/** A minimal HTTP proxy which answers `CONNECT` requests, optionally only
+ * when the expected credentials are sent. */
+function startConnectProxy(credentials?: string) {
+  const listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+  const requests: string[] = [];
+  const tunnels: Promise<unknown>[] = [];
+  const reply = (conn: Deno.Conn, status: string) =>
+    conn.write(new TextEncoder().encode(`HTTP/1.1 ${status}\r\n\r\n`));
+  const served = (async () => {
+    for await (const client of listener) {
+      const buf = new Uint8Array(1024);
+      const n = await client.read(buf);
+      const head = new TextDecoder().decode(buf.subarray(0, n!));
+      requests.push(head);
+      const authority = head.split(" ")[1];
+      const authorized = credentials === undefined ||
+        head.includes(`Proxy-Authorization: Basic ${btoa(credentials)}`);
+      if (!authorized) {
+        await reply(client, "407 Proxy Authentication Required");
+        client.close();
+        continue;
+      }
+      const [hostname, port] = authority.split(":");
+      const upstream = await Deno.connect({ hostname, port: Number(port) });
+      await reply(client, "200 Connection Established");
+      tunnels.push(
+        client.readable.pipeTo(upstream.writable).catch(() => {}),
+        upstream.readable.pipeTo(client.writable).catch(() => {}),
+      );
+    }
+  })();
+  return {
+    url: `http://127.0.0.1:${listener.addr.port}`,
+    requests,
+    async close() {
+      listener.close();
+      await served;
+      await Promise.all(tunnels);
+    },
+  };
+}
+
+Deno.test(
+  { permissions: { net: true } },
+  async function netTcpConnectThroughHttpProxy() {
+    const proxy = startConnectProxy("deno:land");
+    using listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    const accepted = listener.accept();
+
+    const conn = await Deno.connect({
+      hostname: "127.0.0.1",
+      port: listener.addr.port,
+      proxy: {
+        url: proxy.url,
+        basicAuth: { username: "deno", password: "land" },
+      },
+    });
+    const server = await accepted;
+    await server.write(new Uint8Array([1, 2, 3]));
+    const buf = new Uint8Array(3);
+    assertEquals(await conn.read(buf), 3);
+    assertEquals(buf, new Uint8Array([1, 2, 3]));
+    assert(
+      proxy.requests[0].startsWith(
+        `CONNECT 127.0.0.1:${listener.addr.port} HTTP/1.1\r\n`,
+      ),
+    );
+    server.close();
+    conn.close();
+
+    await assertRejects(
+      () =>
+        Deno.connect({
+          hostname: "127.0.0.1",
+          port: listener.addr.port,
+          proxy: { url: proxy.url },
+        }),
+      Deno.errors.PermissionDenied,
+      "Proxy authentication required",
+    );
+    await assertRejects(
+      () =>
+        Deno.connect({
+          hostname: "127.0.0.1",
+          port: listener.addr.port,
+          proxy: { url: "ftp://127.0.0.1:21" },
+        }),
+      TypeError,
+      "Invalid proxy url",
+    );
+    await proxy.close();
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function netTcpConnectWithLookup() {
+    using listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    const accepted = listener.accept();
+    const lookups: string[] = [];
+    using conn = await Deno.connect({
+      hostname: "deno.invalid",
+      port: listener.addr.port,
+      lookup: (hostname) => {
+        lookups.push(hostname);
+        return Promise.resolve(["127.0.0.1"]);
+      },
+    });
+    using _server = await accepted;
+    assertEquals(lookups, ["deno.invalid"]);
+    assertEquals(conn.remoteAddr.hostname, "127.0.0.1");
+
+    await assertRejects(
+      () =>
+        Deno.connect({
+          hostname: "deno.invalid",
+          port: listener.addr.port,
+          lookup: () => [],
+        }),
+      TypeError,
+      "returned no addresses",
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: ["deno.invalid:4505"] } },
+  async function netTcpConnectDnsNeedsPermission() {
+    await assertRejects(
+      () =>
+        Deno.connect({
+          hostname: "deno.invalid",
+          port: 4505,
+          lookup: () => ["127.0.0.1"],
+        }),
+      Deno.errors.NotCapable,
+    );
+    await assertRejects(
+      () =>
+        Deno.connect({
+          hostname: "deno.invalid",
+          port: 4505,
+          nameServer: { ipAddr: "127.0.0.1" },
+        }),
+      Deno.errors.NotCapable,
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: ["127.0.0.1:4505"] } },
+  async function netTcpConnectProxyNeedsPermission() {
+    await assertRejects(
+      () =>
+        Deno.connect({
+          hostname: "127.0.0.1",
+          port: 4505,
+          proxy: { url: "http://127.0.0.1:3128" },
+        }),
+      Deno.errors.NotCapable,
+    );
+  },
+);
+
 Deno.test({ permissions: { net: true } }, async function netTcpDialListen() {
   const listener = Deno.listen({ port: listenPort });
   listener.accept().then(
@@ -309,6 +489,57 @@ Deno.test({ permissions: { net: true } }, async function netTcpSetKeepAlive() {
   conn.close();
 });
 
+Deno.test({ permissions: { net: true } }, async function netTcpSetOption() {
+  const listener = Deno.listen({ port: listenPort });
+  const accepted = listener.accept();
+  const conn = await Deno.connect({ hostname: "127.0.0.1", port: listenPort });
+
+  conn.setOption({
+    noDelay: true,
+    keepAlive: true,
+    keepAliveIdle: 30_000,
+    recvBuffer: 65536,
+    linger: 1000,
+  });
+  const options = conn.getOption();
+  assertEquals(options.noDelay, true);
+  assertEquals(options.keepAlive, true);
+  if (Deno.build.os !== "windows") {
+    assertEquals(options.keepAliveIdle, 30_000);
+  }
+  // kernels may round the buffer size, e.g. Linux doubles it
+  assert(options.recvBuffer! >= 65536);
+  assert(options.sendBuffer! > 0);
+  assertEquals(options.linger, 1000);
+
+  conn.setOption({ noDelay: false, linger: null });
+  assertEquals(conn.getOption().noDelay, false);
+  assertEquals(conn.getOption().linger, null);
+
+  (await accepted).close();
+  listener.close();
+  conn.close();
+});
+
+Deno.test(
+  { permissions: { net: true } },
+  function netUdpSetOption() {
+    const socket = Deno.listenDatagram({
+      port: listenPort,
+      transport: "udp",
+    });
+    socket.setOption({ sendBuffer: 65536 });
+    const options = socket.getOption();
+    assert(options.sendBuffer >= 65536);
+    assertEquals("noDelay" in options, false);
+    assertThrows(
+      () => socket.setOption({ noDelay: true } as Deno.SocketOptions),
+      Deno.errors.NotSupported,
+    );
+    socket.close();
+  },
+);
+
 Deno.test(
   {
     ignore: Deno.build.os === "windows",
@@ -347,6 +578,224 @@ Deno.test(
   },
 );
 
+Deno.test(
+  {
+    ignore: Deno.build.os === "windows",
+    permissions: { read: true, write: true },
+  },
+  async function netUnixPeerCredentials() {
+    const filePath = tmpUnixSocketPath();
+    using listener = Deno.listen({ path: filePath, transport: "unix" });
+    const accepted = listener.accept();
+    using conn = await Deno.connect({ path: filePath, transport: "unix" });
+    using server = await accepted;
+    const credentials = server.peerCredentials();
+    assertEquals(credentials.uid, Deno.uid());
+    assertEquals(credentials.gid, Deno.gid());
+    if (Deno.build.os === "linux") {
+      assertEquals(credentials.pid, Deno.pid);
+    }
+    assertEquals(conn.peerCredentials().uid, Deno.uid());
+  },
+);
+
+Deno.test(
+  {
+    ignore: Deno.build.os === "windows",
+    permissions: { read: true, write: true, net: true },
+  },
+  async function netUnixWriteReadWithHandles() {
+    const filePath = tmpUnixSocketPath();
+    const tempFile = Deno.makeTempFileSync();
+    Deno.writeTextFileSync(tempFile, "passed along");
+    using listener = Deno.listen({ path: filePath, transport: "unix" });
+    using tcpListener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    const accepted = listener.accept();
+    using conn = await Deno.connect({ path: filePath, transport: "unix" });
+    using server = await accepted;
+
+    {
+      using file = Deno.openSync(tempFile);
+      const nwritten = await conn.writeWithHandles(new Uint8Array([42]), [
+        file,
+        tcpListener,
+      ]);
+      assertEquals(nwritten, 1);
+    }
+
+    const buf = new Uint8Array(16);
+    const { nread, handles } = await server.readWithHandles(buf);
+    assertEquals(nread, 1);
+    assertEquals(buf[0], 42);
+    assertEquals(handles.length, 2);
+
+    // the file was closed by the sender but our copy is still open
+    const file = handles[0] as Deno.FsFile;
+    const contents = new Uint8Array(32);
+    const n = await file.read(contents);
+    assertEquals(
+      new TextDecoder().decode(contents.subarray(0, n!)),
+      "passed along",
+    );
+    file.close();
+
+    // both listeners share one socket, so the copy can accept connections
+    using received = handles[1] as Deno.TcpListener;
+    assertEquals(received.addr, tcpListener.addr);
+    const acceptedTcp = received.accept();
+    using _client = await Deno.connect(received.addr);
+    using _tcpConn = await acceptedTcp;
+
+    await assertRejects(
+      () => conn.writeWithHandles(new Uint8Array(), [tcpListener]),
+      TypeError,
+    );
+    Deno.removeSync(tempFile);
+  },
+);
+
+Deno.test(
+  { ignore: Deno.build.os === "windows", permissions: { env: true } },
+  function netSystemdWithoutActivation() {
+    assertEquals(Deno.env.get("LISTEN_FDS"), undefined);
+    assertEquals(Deno.listenSystemd(), []);
+    assertEquals(Deno.env.get("NOTIFY_SOCKET"), undefined);
+    assertEquals(Deno.notifySystemd("READY=1"), false);
+  },
+);
+
+Deno.test(
+  { ignore: Deno.build.os === "windows", permissions: { run: true } },
+  async function netSystemdUnsetsEnvironment() {
+    const output = await new Deno.Command(Deno.execPath(), {
+      args: [
+        "eval",
+        "--unstable-net",
+        "console.log(Deno.listenSystemd().length, Deno.env.get('LISTEN_FDS'))",
+      ],
+      // meant for another process
+      env: { LISTEN_PID: "1", LISTEN_FDS: "1" },
+    }).output();
+    assertEquals(
+      new TextDecoder().decode(output.stdout).trim(),
+      "0 undefined",
+    );
+  },
+);
+
+Deno.test(
+  {
+    ignore: Deno.build.os === "windows",
+    permissions: { read: true, write: true, run: true },
+  },
+  async function netNotifySystemd() {
+    const path = tmpUnixSocketPath();
+    using socket = Deno.listenDatagram({ path, transport: "unixpacket" });
+    const output = await new Deno.Command(Deno.execPath(), {
+      args: [
+        "eval",
+        "--unstable-net",
+        "console.log(Deno.notifySystemd('READY=1'))",
+      ],
+      env: { NOTIFY_SOCKET: path },
+    }).output();
+    assertEquals(new TextDecoder().decode(output.stdout).trim(), "true");
+    const [data] = await socket.receive();
+    assertEquals(new TextDecoder().decode(data), "READY=1");
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function netTcpListenAcceptOptions() {
+    using listener = Deno.listen({
+      hostname: "127.0.0.1",
+      port: listenPort,
+      tcpFastOpen: true,
+      deferAccept: 5,
+    });
+    const accepted = listener.accept();
+    using conn = await Deno.connect({
+      hostname: "127.0.0.1",
+      port: listenPort,
+    });
+    // with deferred accept, the connection is accepted once data arrives
+    await conn.write(new Uint8Array([1, 2, 3]));
+    using server = await accepted;
+    const buf = new Uint8Array(3);
+    assertEquals(await server.read(buf), 3);
+
+    assertThrows(
+      () => Deno.listen({ port: listenPort2, tcpFastOpen: -1 }),
+      TypeError,
+    );
+    assertThrows(
+      () => Deno.listen({ port: listenPort2, deferAccept: 1.5 }),
+      TypeError,
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function netTcpSetRateLimit() {
+    using listener = Deno.listen({ hostname: "127.0.0.1", port: listenPort });
+    const accepted = listener.accept();
+    using conn = await Deno.connect({
+      hostname: "127.0.0.1",
+      port: listenPort,
+    });
+    using server = await accepted;
+
+    // the first second worth of data is sent as a burst, the rest is paced
+    conn.setRateLimit({ writeBps: 16 * 1024 });
+    const start = performance.now();
+    assertEquals(await conn.write(new Uint8Array(32 * 1024)), 16 * 1024);
+    let written = 0;
+    while (written < 8 * 1024) {
+      written += await conn.write(new Uint8Array(8 * 1024 - written));
+    }
+    assert(performance.now() - start >= 400);
+
+    conn.setRateLimit({});
+    assertEquals(await conn.write(new Uint8Array(64 * 1024)), 64 * 1024);
+
+    server.setRateLimit({ readBps: 1024 });
+    const buf = new Uint8Array(4096);
+    assertEquals(await server.read(buf), 1024);
+
+    assertThrows(() => conn.setRateLimit({ readBps: 0 }), TypeError);
+    assertThrows(() => conn.setRateLimit({ writeBps: Infinity }), TypeError);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function netIcmpPingRequiresSysPermission() {
+    await assertRejects(
+      () => Deno.icmpPing("127.0.0.1"),
+      Deno.errors.NotCapable,
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: ["127.0.0.1"], sys: ["icmpPing"] } },
+  async function netIcmpPingLocalhost() {
+    let reply;
+    try {
+      reply = await Deno.icmpPing("127.0.0.1", { payloadSize: 16 });
+    } catch (err) {
+      // neither raw sockets nor unprivileged ping sockets are available
+      if (err instanceof Deno.errors.PermissionDenied) return;
+      throw err;
+    }
+    assertEquals(reply.address, "127.0.0.1");
+    assert(reply.time >= 0);
+    assertEquals(typeof reply.privileged, "boolean");
+  },
+);
+
 Deno.test(
   { permissions: { net: true } },
   async function netUdpSendReceive() {
@@ -614,6 +1063,32 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { net: true } },
+  async function netUdpSetBroadcast() {
+    const socket = Deno.listenDatagram({ port: listenPort, transport: "udp" });
+    await socket.setBroadcast(false);
+    await socket.setBroadcast(true);
+    socket.close();
+  },
+);
+
+Deno.test(
+  { permissions: { net: ["127.0.0.1"] } },
+  async function netUdpJoinMulticastNeedsGroupPermission() {
+    const socket = Deno.listenDatagram({ port: listenPort, transport: "udp" });
+    await assertRejects(
+      () => socket.joinMulticastV4("224.0.0.251", "0.0.0.0"),
+      Deno.errors.NotCapable,
+    );
+    await assertRejects(
+      () => socket.joinMulticastV4("127.0.0.1", "0.0.0.0"),
+      TypeError,
+    );
+    socket.close();
+  },
+);
+
 Deno.test(
   { permissions: { net: true } },
   async function netUdpConcurrentSendReceive() {
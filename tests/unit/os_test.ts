@@ -188,6 +188,10 @@ Deno.test({ permissions: { read: false } }, function execPath() {
   assertNotEquals(Deno.execPath(), "");
 });
 
+Deno.test(function embeddedFilesOutsideCompiledBinary() {
+  assertEquals(Deno.embeddedFiles(), []);
+});
+
 Deno.test(
   {
     ignore: Deno.build.os !== "linux",
@@ -316,6 +320,20 @@ Deno.test(function memoryUsage() {
   assert(mem.rss >= mem.heapTotal);
 });
 
+Deno.test(function memoryUsageDetailed() {
+  const mem = Deno.memoryUsage({ detailed: true });
+  assert(typeof mem.rss === "number");
+  assert(typeof mem.heapUsed === "number");
+  assertEquals(mem.resources.stdin, 1);
+  assertEquals(mem.resources.stdout, 1);
+  if (Deno.build.os === "linux" && mem.allocator !== null) {
+    assert(mem.allocator.allocated > 0);
+  }
+  using _file = Deno.openSync(import.meta.filename!);
+  const after = Deno.memoryUsage({ detailed: true });
+  assertEquals(after.resources.fsFile, (mem.resources.fsFile ?? 0) + 1);
+});
+
 Deno.test("Deno.exitCode getter and setter", () => {
   // Initial value is 0
   assertEquals(Deno.exitCode, 0);
@@ -382,3 +400,20 @@ Deno.test("Setting Deno.exitCode does not cause an immediate exit", () => {
     Deno.exitCode = 0;
   }
 });
+
+Deno.test(
+  { permissions: { sys: ["systemMemoryInfo"] } },
+  function cgroupLimits() {
+    const limits = Deno.cgroupLimits();
+    if (Deno.build.os !== "linux") {
+      assertEquals(limits, null);
+      return;
+    }
+    if (limits === null) return;
+    assert(limits.version === 1 || limits.version === 2);
+    assert(limits.cpuQuota === null || limits.cpuQuota > 0);
+    if (limits.memoryMax !== null) {
+      assert(Deno.systemMemoryInfo().total <= limits.memoryMax);
+    }
+  },
+);
@@ -212,6 +212,22 @@ Deno.test(function permissionDescriptorValidationSync() {
   }
 });
 
+Deno.test(function permissionRequestExpiresInValidation() {
+  for (const expiresIn of [-1, 1.5, NaN]) {
+    assertThrows(
+      () => Deno.permissions.requestSync({ name: "env" }, { expiresIn }),
+      TypeError,
+      "'expiresIn' must be a non-negative integer",
+    );
+  }
+  // a permission granted already is not affected
+  const status = Deno.permissions.requestSync({ name: "env" }, {
+    expiresIn: 0,
+  });
+  assertEquals(status.state, "granted");
+  assertEquals(Deno.permissions.querySync({ name: "env" }).state, "granted");
+});
+
 // Regression test for https://github.com/denoland/deno/issues/15894.
 Deno.test(async function permissionStatusObjectsNotEqual() {
   assert(
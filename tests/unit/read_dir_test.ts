@@ -119,3 +119,41 @@ Deno.test({ permissions: { read: true } }, async function readDirNotFound() {
     `readdir 'bad_dir_name'`,
   );
 });
+
+Deno.test(
+  {
+    // macOS and Windows file systems reject names that aren't valid UTF-8
+    ignore: Deno.build.os !== "linux",
+    permissions: { read: true, write: true },
+  },
+  async function readDirRawPreservesNonUtf8Names() {
+    const encoder = new TextEncoder();
+    const dir = Deno.makeTempDirSync();
+    const dirBytes = encoder.encode(dir);
+    // "caf\xe9" is latin-1, not UTF-8
+    const name = new Uint8Array([0x63, 0x61, 0x66, 0xe9]);
+    const path = new Uint8Array([...dirBytes, 0x2f, ...name]);
+    Deno.openSync(path, { write: true, createNew: true }).close();
+
+    // string-based readDir can't represent the name
+    assertEquals([...Deno.readDirSync(dir)].length, 0);
+
+    const syncEntries = [...Deno.readDirSync(dirBytes)];
+    assertEquals(syncEntries.length, 1);
+    assertEquals(syncEntries[0].name, name);
+    assert(syncEntries[0].isFile);
+
+    const asyncEntries = await Array.fromAsync(Deno.readDir(dirBytes));
+    assertEquals(asyncEntries.length, 1);
+    assertEquals(asyncEntries[0].name, name);
+
+    assert(Deno.statSync(path).isFile);
+    assert((await Deno.lstat(path)).isFile);
+    using file = await Deno.open(path, { write: true });
+    await file.write(encoder.encode("hello"));
+    assertEquals(Deno.lstatSync(path).size, 5);
+    assertEquals((await Deno.stat(path)).size, 5);
+
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
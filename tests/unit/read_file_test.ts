@@ -155,6 +155,31 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { ignore: Deno.build.os !== "linux" },
+  function readFileSyncProcFs() {
+    // procfs reports a size of 0, and /proc/self/maps is usually larger
+    // than a single read
+    const data = Deno.readFileSync("/proc/self/maps");
+    assert(data.byteLength > 0);
+    assertEquals(data[data.byteLength - 1], "\n".charCodeAt(0));
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  function readFileSyncExactSize() {
+    const filename = Deno.makeTempDirSync() + "/test_readFileSyncExactSize";
+    const data = new Uint8Array(100_003);
+    for (let i = 0; i < data.length; i++) data[i] = i % 251;
+    Deno.writeFileSync(filename, data);
+    const read = Deno.readFileSync(filename);
+    assertEquals(read.byteLength, data.byteLength);
+    assertEquals(read.buffer.byteLength, data.byteLength);
+    assertEquals(read, data);
+  },
+);
+
 Deno.test(
   { permissions: { read: true } },
   async function readFileNotFoundErrorCode() {
@@ -176,3 +201,44 @@ Deno.test(
     }
   },
 );
+
+Deno.test(
+  { permissions: { read: true } },
+  async function fileFromPathReadsLazily() {
+    const path = "tests/testdata/assets/fixture.json";
+    const expected = Deno.readFileSync(path);
+    const file = await Deno.fileFromPath(path, { type: "application/json" });
+    assert(file instanceof File);
+    assertEquals(file.name, "fixture.json");
+    assertEquals(file.type, "application/json");
+    assertEquals(file.size, expected.byteLength);
+    assertEquals(
+      file.lastModified,
+      Deno.statSync(path).mtime!.getTime(),
+    );
+    assertEquals(await file.bytes(), expected);
+    assertEquals(await file.slice(2, 10).bytes(), expected.subarray(2, 10));
+    assertEquals(
+      await new Response(file.stream()).bytes(),
+      expected,
+    );
+    assertEquals(await new Response(file).bytes(), expected);
+    const url = URL.createObjectURL(file.slice(1));
+    assertEquals(
+      await (await fetch(url)).bytes(),
+      expected.subarray(1),
+    );
+    URL.revokeObjectURL(url);
+  },
+);
+
+Deno.test(
+  { permissions: { read: true } },
+  async function fileFromPathDirectory() {
+    await assertRejects(
+      () => Deno.fileFromPath("tests/testdata/assets"),
+      TypeError,
+      "Not a file",
+    );
+  },
+);
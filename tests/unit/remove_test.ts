@@ -84,10 +84,9 @@ Deno.test(
         async () => {
           await Deno[method](path);
         },
-        Error,
+        Deno.errors.DirectoryNotEmpty,
         `remove '${path}'`,
       );
-      // TODO(ry) Is Other really the error we should get here? What would Go do?
 
       // NON-EXISTENT DIRECTORY/FILE
       await assertRejects(
@@ -289,3 +288,115 @@ if (Deno.build.os === "windows") {
     },
   );
 }
+
+Deno.test(
+  {
+    ignore: Deno.build.os !== "linux",
+    permissions: { write: true, read: true, env: true },
+  },
+  async function removeTrashMovesToFreedesktopTrash() {
+    const dataHome = Deno.makeTempDirSync();
+    const prevDataHome = Deno.env.get("XDG_DATA_HOME");
+    Deno.env.set("XDG_DATA_HOME", dataHome);
+    try {
+      for (const method of REMOVE_METHODS) {
+        const dir = Deno.makeTempDirSync();
+        const filename = dir + "/trash me.txt";
+        Deno.writeTextFileSync(filename, method);
+        await Deno[method](filename, { trash: true });
+        assertThrows(() => Deno.statSync(filename), Deno.errors.NotFound);
+      }
+
+      // the second file collides with the first and gets a suffix
+      const trashed = Deno.readTextFileSync(
+        dataHome + "/Trash/files/trash me.txt",
+      );
+      assert(trashed === "remove");
+      const info = Deno.readTextFileSync(
+        dataHome + "/Trash/info/trash me.txt.1.trashinfo",
+      );
+      assert(info.startsWith("[Trash Info]\nPath=/"));
+      assert(info.includes("/trash%20me.txt\nDeletionDate="));
+    } finally {
+      if (prevDataHome === undefined) {
+        Deno.env.delete("XDG_DATA_HOME");
+      } else {
+        Deno.env.set("XDG_DATA_HOME", prevDataHome);
+      }
+      Deno.removeSync(dataHome, { recursive: true });
+    }
+  },
+);
+
+Deno.test(
+  {
+    ignore: Deno.build.os !== "linux",
+    permissions: { write: true, read: true, env: true },
+  },
+  async function removeTrashDirectory() {
+    const dataHome = Deno.makeTempDirSync();
+    const prevDataHome = Deno.env.get("XDG_DATA_HOME");
+    Deno.env.set("XDG_DATA_HOME", dataHome);
+    try {
+      const dir = Deno.makeTempDirSync() + "/populated";
+      Deno.mkdirSync(dir);
+      Deno.writeTextFileSync(dir + "/file.txt", "hello");
+      // no `recursive` needed, the whole tree is moved
+      await Deno.remove(dir, { trash: true });
+      assertThrows(() => Deno.statSync(dir), Deno.errors.NotFound);
+      assert(
+        Deno.readTextFileSync(dataHome + "/Trash/files/populated/file.txt") ===
+          "hello",
+      );
+    } finally {
+      if (prevDataHome === undefined) {
+        Deno.env.delete("XDG_DATA_HOME");
+      } else {
+        Deno.env.set("XDG_DATA_HOME", prevDataHome);
+      }
+      Deno.removeSync(dataHome, { recursive: true });
+    }
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: ["."], env: true } },
+  async function removeTrashNoFullWritePermissions() {
+    for (const method of REMOVE_METHODS) {
+      await assertRejects(
+        async () => await Deno[method]("missing", { trash: true }),
+        Deno.errors.NotCapable,
+      );
+    }
+  },
+);
+
+Deno.test(
+  {
+    ignore: Deno.build.os === "windows",
+    permissions: { read: true, write: true },
+  },
+  async function removeTrashNoEnvPermissions() {
+    for (const method of REMOVE_METHODS) {
+      await assertRejects(
+        async () => await Deno[method]("missing", { trash: true }),
+        Deno.errors.NotCapable,
+      );
+    }
+  },
+);
+
+Deno.test(
+  { permissions: { write: true, read: true, env: true } },
+  async function removeTrashNotFound() {
+    for (const method of REMOVE_METHODS) {
+      await assertRejects(
+        async () =>
+          await Deno[method](Deno.makeTempDirSync() + "/missing", {
+            trash: true,
+          }),
+        Deno.errors.NotFound,
+      );
+    }
+  },
+);
@@ -0,0 +1,202 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+import { assertEquals } from "./test_util.ts";
+
+async function withServer(
+  handler: (req: Request) => Response | Promise<Response>,
+  fn: (origin: string) => Promise<void>,
+) {
+  const { promise, resolve } = Promise.withResolvers<number>();
+  await using _server = Deno.serve({
+    port: 0,
+    onListen: ({ port }) => resolve(port),
+    handler,
+  });
+  await fn(`http://localhost:${await promise}`);
+}
+
+function makeTempDir(): string {
+  const dir = Deno.makeTempDirSync();
+  Deno.writeTextFileSync(`${dir}/hello.txt`, "hello world");
+  Deno.writeTextFileSync(`${dir}/index.html`, "<h1>index</h1>");
+  Deno.mkdirSync(`${dir}/sub`);
+  Deno.writeTextFileSync(`${dir}/sub/index.html`, "<h1>sub</h1>");
+  return dir;
+}
+
+Deno.test(
+  { permissions: { read: true, write: true, net: true } },
+  async function serveFileFullResponse() {
+    const dir = makeTempDir();
+    await withServer(
+      (req) => Deno.serveFile(req, `${dir}/hello.txt`),
+      async (origin) => {
+        const resp = await fetch(origin);
+        assertEquals(resp.status, 200);
+        assertEquals(resp.headers.get("content-length"), "11");
+        assertEquals(
+          resp.headers.get("content-type"),
+          "text/plain; charset=UTF-8",
+        );
+        assertEquals(resp.headers.get("accept-ranges"), "bytes");
+        assertEquals(resp.headers.get("etag")?.startsWith('W/"b-'), true);
+        assertEquals(resp.headers.has("last-modified"), true);
+        assertEquals(await resp.text(), "hello world");
+
+        const head = await fetch(origin, { method: "HEAD" });
+        assertEquals(head.status, 200);
+        assertEquals(head.headers.get("content-length"), "11");
+        assertEquals(await head.text(), "");
+      },
+    );
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true, net: true } },
+  async function serveFileConditional() {
+    const dir = makeTempDir();
+    await withServer(
+      (req) => Deno.serveFile(req, `${dir}/hello.txt`),
+      async (origin) => {
+        const first = await fetch(origin);
+        await first.body?.cancel();
+        const etag = first.headers.get("etag")!;
+        const lastModified = first.headers.get("last-modified")!;
+
+        const byEtag = await fetch(origin, {
+          headers: { "if-none-match": etag },
+        });
+        assertEquals(byEtag.status, 304);
+        assertEquals(await byEtag.text(), "");
+
+        const byDate = await fetch(origin, {
+          headers: { "if-modified-since": lastModified },
+        });
+        assertEquals(byDate.status, 304);
+        await byDate.body?.cancel();
+
+        const stale = await fetch(origin, {
+          headers: { "if-none-match": '"other"' },
+        });
+        assertEquals(stale.status, 200);
+        assertEquals(await stale.text(), "hello world");
+      },
+    );
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true, net: true } },
+  async function serveFileRange() {
+    const dir = makeTempDir();
+    await withServer(
+      (req) => Deno.serveFile(req, `${dir}/hello.txt`),
+      async (origin) => {
+        const partial = await fetch(origin, {
+          headers: { range: "bytes=6-" },
+        });
+        assertEquals(partial.status, 206);
+        assertEquals(partial.headers.get("content-range"), "bytes 6-10/11");
+        assertEquals(partial.headers.get("content-length"), "5");
+        assertEquals(await partial.text(), "world");
+
+        const unsatisfiable = await fetch(origin, {
+          headers: { range: "bytes=20-" },
+        });
+        assertEquals(unsatisfiable.status, 416);
+        assertEquals(
+          unsatisfiable.headers.get("content-range"),
+          "bytes */11",
+        );
+        await unsatisfiable.body?.cancel();
+
+        // a stale If-Range gets the full file
+        const stale = await fetch(origin, {
+          headers: { range: "bytes=6-", "if-range": '"other"' },
+        });
+        assertEquals(stale.status, 200);
+        assertEquals(await stale.text(), "hello world");
+
+        // the etag is weak, so it can't validate a range
+        const weak = await fetch(origin, {
+          headers: {
+            range: "bytes=6-",
+            "if-range": partial.headers.get("etag")!,
+          },
+        });
+        assertEquals(weak.status, 200);
+        assertEquals(await weak.text(), "hello world");
+      },
+    );
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true, net: true } },
+  async function serveFileNotFound() {
+    const dir = makeTempDir();
+    await withServer(
+      (req) =>
+        Deno.serveFile(
+          req,
+          new URL(req.url).pathname === "/dir"
+            ? `${dir}/sub`
+            : `${dir}/missing.txt`,
+        ),
+      async (origin) => {
+        const missing = await fetch(origin);
+        assertEquals(missing.status, 404);
+        await missing.body?.cancel();
+
+        const directory = await fetch(`${origin}/dir`);
+        assertEquals(directory.status, 404);
+        await directory.body?.cancel();
+      },
+    );
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true, net: true } },
+  async function serveDirMapsUrlPaths() {
+    const dir = makeTempDir();
+    await withServer(
+      (req) => Deno.serveDir(req, { fsRoot: dir, urlRoot: "static" }),
+      async (origin) => {
+        const file = await fetch(`${origin}/static/hello.txt`);
+        assertEquals(await file.text(), "hello world");
+
+        const index = await fetch(`${origin}/static/`);
+        assertEquals(index.headers.get("content-type")?.includes("html"), true);
+        assertEquals(await index.text(), "<h1>index</h1>");
+
+        const subIndex = await fetch(`${origin}/static/sub`);
+        assertEquals(await subIndex.text(), "<h1>sub</h1>");
+
+        const outside = await fetch(`${origin}/hello.txt`);
+        assertEquals(outside.status, 404);
+        await outside.body?.cancel();
+
+        // encoded separators cannot climb above the root
+        const escape = await fetch(
+          `${origin}/static/sub%2f..%2f..%2f${dir.split("/").pop()}`,
+        );
+        assertEquals(escape.status, 404);
+        await escape.body?.cancel();
+
+        const post = await fetch(`${origin}/static/hello.txt`, {
+          method: "POST",
+        });
+        assertEquals(post.status, 405);
+        assertEquals(post.headers.get("allow"), "GET, HEAD");
+        await post.body?.cancel();
+      },
+    );
+    Deno.removeSync(dir, { recursive: true });
+  },
+);
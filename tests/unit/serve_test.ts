@@ -293,6 +293,231 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerShutdownGracePeriod() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    const { promise: requested, resolve: onRequest } = Promise
+      .withResolvers<void>();
+    const { promise: released, resolve: release } = Promise
+      .withResolvers<void>();
+    const server = Deno.serve({
+      port: 0,
+      onListen,
+    }, async () => {
+      onRequest();
+      await released;
+      return new Response("late");
+    });
+    const { port } = await listening;
+
+    const conn = await Deno.connect({ port });
+    await conn.write(new TextEncoder().encode("GET / HTTP/1.1\r\n\r\n"));
+    await requested;
+
+    const start = Date.now();
+    const { droppedConnections } = await server.shutdown({
+      gracePeriodMs: 100,
+    });
+    assertEquals(droppedConnections, 1);
+    assert(Date.now() - start >= 90);
+
+    conn.close();
+    release();
+    await server.finished;
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerShutdownGracePeriodDrained() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    const server = Deno.serve(
+      { port: 0, onListen },
+      () => new Response("ok"),
+    );
+    const { port } = await listening;
+    const resp = await fetch(`http://localhost:${port}/`);
+    assertEquals(await resp.text(), "ok");
+
+    const result = await server.shutdown({ gracePeriodMs: 10_000 });
+    assertEquals(result, { droppedConnections: 0 });
+    await server.finished;
+
+    await assertRejects(
+      () => server.shutdown({ gracePeriodMs: -1 }),
+      TypeError,
+      "'gracePeriodMs' must be a non-negative number",
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerLimits() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    const chunkedErrors: string[] = [];
+    await using server = Deno.serve({
+      port: 0,
+      onListen,
+      limits: { maxHeaderSize: 8192, maxBodySize: 8 },
+    }, async (req) => {
+      try {
+        return new Response(await req.text());
+      } catch (err) {
+        chunkedErrors.push((err as Error).message);
+        return new Response("failed", { status: 400 });
+      }
+    });
+    const { port } = await listening;
+    const url = `http://localhost:${port}/`;
+
+    const small = await fetch(url, { method: "POST", body: "12345678" });
+    assertEquals(await small.text(), "12345678");
+
+    const large = await fetch(url, { method: "POST", body: "123456789" });
+    assertEquals(large.status, 413);
+    await large.body?.cancel();
+
+    const stream = ReadableStream.from([
+      new TextEncoder().encode("12345"),
+      new TextEncoder().encode("67890"),
+    ]);
+    const chunked = await fetch(url, { method: "POST", body: stream });
+    assertEquals(chunked.status, 400);
+    await chunked.body?.cancel();
+    assertEquals(chunkedErrors.length, 1);
+    assertStringIncludes(chunkedErrors[0], "larger than the limit");
+
+    const headers = await fetch(url, {
+      headers: { "x-large": "a".repeat(16 * 1024) },
+    });
+    assertEquals(headers.status, 431);
+    await headers.body?.cancel();
+
+    assertThrows(
+      () =>
+        Deno.serve({
+          port: 0,
+          limits: { maxBodySize: -1 },
+        }, () => new Response()),
+      TypeError,
+      "'limits.maxBodySize' must be a non-negative integer",
+    );
+    assertThrows(
+      () =>
+        Deno.serve({
+          port: 0,
+          limits: { maxHeaderSize: 1024 },
+        }, () => new Response()),
+      RangeError,
+      "'limits.maxHeaderSize' must be at least 8192",
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerBodyReadTimeout() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    const { promise: failed, resolve: onFailed } = Promise
+      .withResolvers<string>();
+    await using _server = Deno.serve({
+      port: 0,
+      onListen,
+      limits: { bodyReadTimeoutMs: 100 },
+    }, async (req) => {
+      try {
+        await req.text();
+        return new Response("read");
+      } catch (err) {
+        onFailed((err as Error).message);
+        return new Response("timed out", { status: 408 });
+      }
+    });
+    const { port } = await listening;
+
+    // the client sends half of the body and then stalls
+    const conn = await Deno.connect({ port });
+    await conn.write(
+      new TextEncoder().encode(
+        "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 10\r\n\r\n12345",
+      ),
+    );
+    assertStringIncludes(await failed, "Timed out reading the request body");
+    const response = new Uint8Array(1024);
+    const nread = await conn.read(response);
+    assertStringIncludes(
+      new TextDecoder().decode(response.subarray(0, nread!)),
+      "HTTP/1.1 408",
+    );
+    conn.close();
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerMetrics() {
+    const { promise: listening, resolve: onListen } = Promise
+      .withResolvers<Deno.NetAddr>();
+    const server = Deno.serve({
+      port: 0,
+      onListen,
+      metrics: { path: "/metrics" },
+    }, async (req) => {
+      const body = await req.text();
+      return new Response(body, { status: body === "" ? 404 : 200 });
+    });
+    const { port } = await listening;
+    const url = `http://localhost:${port}`;
+
+    const ok = await fetch(url, { method: "POST", body: "hello" });
+    assertEquals(await ok.text(), "hello");
+    const missing = await fetch(url);
+    assertEquals(missing.status, 404);
+    await missing.body?.cancel();
+
+    const snapshot = server.metrics()!;
+    assertEquals(snapshot.requests, 2);
+    assertEquals(snapshot.responses["2xx"], 1);
+    assertEquals(snapshot.responses["4xx"], 1);
+    assertEquals(snapshot.requestBodyBytes, 5);
+    assertEquals(snapshot.responseBodyBytes, 5);
+    assertEquals(snapshot.activeConnections, 1);
+    assertEquals(snapshot.requestDurationBuckets.at(-1)!.le, 10);
+
+    const text = await fetch(`${url}/metrics`);
+    assertEquals(
+      text.headers.get("content-type"),
+      "text/plain; version=0.0.4; charset=utf-8",
+    );
+    const exposition = await text.text();
+    assertStringIncludes(
+      exposition,
+      'deno_serve_responses_total{status_class="2xx"} 1',
+    );
+    assertStringIncludes(
+      exposition,
+      'deno_serve_request_duration_seconds_bucket{le="+Inf"} 2',
+    );
+    // the metrics endpoint itself is not counted
+    assertEquals(server.metrics()!.requests, 2);
+
+    await server.shutdown();
+    assertEquals(server.metrics(), null);
+
+    await using plain = Deno.serve(
+      { port: 0, onListen() {} },
+      () => new Response(),
+    );
+    assertEquals(plain.metrics(), null);
+  },
+);
+
 // Ensure that resources don't leak during a graceful shutdown
 Deno.test(
   { permissions: { net: true, write: true, read: true } },
@@ -371,6 +596,22 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { net: true, write: true, read: true } },
+  async function httpServerFileBodyContentLength() {
+    await using _server = await makeServer(async (_req) => {
+      const file = await makeTempFile(1024 * 1024);
+      // only what is left after the cursor is sent
+      await file.seek(1024, Deno.SeekMode.Start);
+      return new Response(file.readable);
+    });
+
+    const resp = await fetch(`http://localhost:${servePort}`);
+    assertEquals(resp.headers.get("content-length"), String(1023 * 1024));
+    assertEquals((await resp.bytes()).byteLength, 1023 * 1024);
+  },
+);
+
 Deno.test(
   { permissions: { read: true, run: true } },
   async function httpServerUnref() {
@@ -2744,15 +2985,15 @@ const compressionTestCases = [
     out: {},
     expect: null,
   },
-  // This technically would be compressible if not for the size, however the size_hint is not implemented
-  // for FileResource and we don't currently peek ahead on resources.
-  // {
-  //   name: "EmptyAcceptGzip2",
-  //   length: 0,
-  //   in: { "Accept-Encoding": "gzip" },
-  //   out: { "Content-Type": "text/plain" },
-  //   expect: null,
-  // },
+  // This would be compressible if not for the size, which is known from the
+  // FileResource size hint.
+  {
+    name: "EmptyAcceptGzip2",
+    length: 0,
+    in: { "Accept-Encoding": "gzip" },
+    out: { "Content-Type": "text/plain" },
+    expect: null,
+  },
   { name: "Incompressible", length: 1024, in: {}, out: {}, expect: null },
   {
     name: "IncompressibleAcceptGzip",
@@ -4070,6 +4311,26 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { net: true } },
+  async function httpServerOnExistingListener() {
+    const listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    const { promise, resolve } = Promise.withResolvers<Deno.NetAddr>();
+    const server = Deno.serve(
+      {
+        listener,
+        onListen: resolve,
+      },
+      () => new Response("hello from an existing listener"),
+    );
+
+    assertEquals(await promise, listener.addr);
+    const resp = await fetch(`http://127.0.0.1:${listener.addr.port}/`);
+    assertEquals(await resp.text(), "hello from an existing listener");
+    await server.shutdown();
+  },
+);
+
 Deno.test(
   {
     ignore: Deno.build.os !== "linux",
@@ -0,0 +1,94 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+import { assertEquals, assertThrows } from "./test_util.ts";
+
+async function withServer(
+  handler: (req: Request) => Response | Promise<Response>,
+  fn: (origin: string) => Promise<void>,
+) {
+  const { promise, resolve } = Promise.withResolvers<number>();
+  await using _server = Deno.serve({
+    port: 0,
+    onListen: ({ port }) => resolve(port),
+    handler,
+  });
+  await fn(`http://localhost:${await promise}`);
+}
+
+Deno.test(
+  { permissions: { net: true } },
+  async function serverSentEventsSendsEvents() {
+    await withServer(
+      (req) => {
+        const events = Deno.serverSentEvents(req, {
+          keepAliveInterval: false,
+        });
+        events.send({ comment: "hello" });
+        events.send({ event: "greeting", id: "1", data: "a\nb" });
+        events.send({ retry: 1000, data: events.lastEventId ?? "" });
+        events.close();
+        assertEquals(events.isClosed, true);
+        return events.response;
+      },
+      async (origin) => {
+        const resp = await fetch(origin, {
+          headers: { "last-event-id": "41" },
+        });
+        assertEquals(resp.headers.get("content-type"), "text/event-stream");
+        assertEquals(resp.headers.get("cache-control"), "no-cache");
+        assertEquals(
+          await resp.text(),
+          ": hello\n\n" +
+            "event: greeting\nid: 1\ndata: a\ndata: b\n\n" +
+            "retry: 1000\ndata: 41\n\n",
+        );
+      },
+    );
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function serverSentEventsFlushesImmediately() {
+    const { promise: closed, resolve } = Promise.withResolvers<void>();
+    await withServer(
+      (req) => {
+        const events = Deno.serverSentEvents(req, { keepAliveInterval: 10 });
+        events.send({ data: "first" });
+        events.closed.then(resolve);
+        return events.response;
+      },
+      async (origin) => {
+        const resp = await fetch(origin);
+        const reader = resp.body!.pipeThrough(new TextDecoderStream())
+          .getReader();
+        let text = "";
+        while (!text.includes(":keep-alive\n\n")) {
+          const { value } = await reader.read();
+          text += value;
+        }
+        assertEquals(text.startsWith("data: first\n\n"), true);
+        await reader.cancel();
+        await closed;
+      },
+    );
+  },
+);
+
+Deno.test(function serverSentEventsValidatesEvents() {
+  const events = Deno.serverSentEvents(new Request("http://localhost"), {
+    keepAliveInterval: false,
+  });
+  assertThrows(() => events.send({ event: "a\nb" }), TypeError);
+  assertThrows(() => events.send({ id: "1\r" }), TypeError);
+  assertThrows(() => events.send({ retry: -1 }), TypeError);
+  events.close();
+  assertThrows(() => events.send({ data: "late" }), TypeError);
+  assertThrows(
+    () =>
+      Deno.serverSentEvents(new Request("http://localhost"), {
+        keepAliveInterval: 0,
+      }),
+    TypeError,
+  );
+});
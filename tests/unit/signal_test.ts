@@ -327,3 +327,60 @@ Deno.test(
     );
   },
 );
+
+Deno.test(
+  {
+    ignore: Deno.build.os !== "linux",
+    permissions: { run: true },
+  },
+  async function signalListenerInfoTest() {
+    const infos: Deno.SignalInfo[] = [];
+    const listener = (info: Deno.SignalInfo) => {
+      infos.push(info);
+    };
+    Deno.addSignalListener("SIGUSR1", listener);
+    Deno.kill(Deno.pid, "SIGUSR1");
+    while (infos.length < 1) {
+      await delay(20);
+    }
+    Deno.removeSignalListener("SIGUSR1", listener);
+    assertEquals(infos[0].signal, "SIGUSR1");
+    assertEquals(infos[0].pid, Deno.pid);
+    assertEquals(infos[0].code, 0);
+    assertEquals(infos[0].value, null);
+  },
+);
+
+Deno.test(
+  {
+    ignore: Deno.build.os !== "linux",
+    permissions: { run: true },
+  },
+  async function realtimeSignalValueTest() {
+    const infos: Deno.SignalInfo[] = [];
+    const listener = (info: Deno.SignalInfo) => {
+      infos.push(info);
+    };
+    Deno.addSignalListener("SIGRTMIN+2", listener);
+    Deno.kill(Deno.pid, "SIGRTMIN+2", { value: 42 });
+    while (infos.length < 1) {
+      await delay(20);
+    }
+    Deno.removeSignalListener("SIGRTMIN+2", listener);
+    assertEquals(infos[0].signal, "SIGRTMIN+2");
+    assertEquals(infos[0].pid, Deno.pid);
+    assertEquals(infos[0].code, -1);
+    assertEquals(infos[0].value, 42);
+
+    assertThrows(
+      () => Deno.kill(Deno.pid, "SIGRTMIN+2", { value: 1.5 }),
+      TypeError,
+      "Signal value must be a safe integer",
+    );
+    assertThrows(
+      () => Deno.addSignalListener("SIGRTMIN+1000", () => {}),
+      TypeError,
+      "Invalid signal: SIGRTMIN+1000",
+    );
+  },
+);
@@ -487,6 +487,7 @@ Deno.test(async function compressionStreamWritableMayBeAborted() {
     new CompressionStream("gzip").writable.getWriter().abort(),
     new CompressionStream("deflate").writable.getWriter().abort(),
     new CompressionStream("deflate-raw").writable.getWriter().abort(),
+    new CompressionStream("zstd").writable.getWriter().abort(),
   ]);
 });
 
@@ -511,6 +512,7 @@ Deno.test(async function decompressionStreamReadableMayBeCancelled() {
     new DecompressionStream("gzip").readable.getReader().cancel(),
     new DecompressionStream("deflate").readable.getReader().cancel(),
     new DecompressionStream("deflate-raw").readable.getReader().cancel(),
+    new DecompressionStream("zstd").readable.getReader().cancel(),
   ]);
 });
 
@@ -539,6 +541,40 @@ Deno.test(async function decompressionStreamInvalidGzipStillReported() {
   );
 });
 
+Deno.test(async function zstdStreamRoundTrip() {
+  const input = new TextEncoder().encode("hello zstd ".repeat(1000));
+  const compressed = await new Response(
+    ReadableStream.from([input]).pipeThrough(new CompressionStream("zstd")),
+  ).bytes();
+  assertEquals(compressed.length < input.length, true);
+  // zstd frame magic number
+  assertEquals(
+    compressed.subarray(0, 4),
+    new Uint8Array([0x28, 0xb5, 0x2f, 0xfd]),
+  );
+
+  const decompressed = await new Response(
+    ReadableStream.from([compressed.subarray(0, 10), compressed.subarray(10)])
+      .pipeThrough(new DecompressionStream("zstd")),
+  ).bytes();
+  assertEquals(decompressed, input);
+});
+
+Deno.test(async function decompressionStreamTruncatedZstdReported() {
+  const compressed = await new Response(
+    ReadableStream.from([new TextEncoder().encode("hello zstd")])
+      .pipeThrough(new CompressionStream("zstd")),
+  ).bytes();
+  await assertRejects(
+    () =>
+      new Response(
+        ReadableStream.from([compressed.subarray(0, compressed.length - 2)])
+          .pipeThrough(new DecompressionStream("zstd")),
+      ).bytes(),
+    TypeError,
+  );
+});
+
 Deno.test(function readableStreamFromWithStringThrows() {
   assertThrows(
     // @ts-expect-error: primitives are not acceptable
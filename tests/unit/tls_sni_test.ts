@@ -1,5 +1,5 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
-import { assertEquals, assertRejects } from "./test_util.ts";
+import { assertEquals, assertRejects, assertThrows } from "./test_util.ts";
 // @ts-expect-error TypeScript (as of 3.7) does not support indexing namespaces by symbol
 const { resolverSymbol, serverNameSymbol } = Deno[Deno.internal];
 
@@ -57,3 +57,68 @@ Deno.test(
     listener.close();
   },
 );
+
+Deno.test(
+  { permissions: { net: true, read: true } },
+  async function listenTlsResolveCertificate() {
+    const serverNames: string[] = [];
+    const listener = Deno.listenTls({
+      hostname: "localhost",
+      port: 0,
+      resolveCertificate: (serverName) => {
+        serverNames.push(serverName);
+        return { cert, key };
+      },
+    });
+
+    const handshake = async (server: string) => {
+      const conn = await Deno.connectTls({
+        hostname: "localhost",
+        // @ts-ignore Trust me
+        [serverNameSymbol]: server,
+        port: listener.addr.port,
+      });
+      const serverConn = await listener.accept();
+      await Promise.all([conn.handshake(), serverConn.handshake()]);
+      conn.close();
+      serverConn.close();
+    };
+
+    await handshake("a.localhost");
+    await handshake("a.localhost");
+    await handshake("b.localhost");
+    assertEquals(serverNames, ["a.localhost", "b.localhost"]);
+
+    listener.clearCertificateCache();
+    await handshake("a.localhost");
+    assertEquals(serverNames, ["a.localhost", "b.localhost", "a.localhost"]);
+    listener.close();
+  },
+);
+
+Deno.test(
+  { permissions: { net: true, read: true } },
+  function listenTlsResolveCertificateInvalid() {
+    assertThrows(
+      () =>
+        Deno.listenTls({
+          port: 0,
+          // @ts-expect-error not a function
+          resolveCertificate: "localhost",
+        }),
+      TypeError,
+      "`resolveCertificate` must be a function",
+    );
+    const options = {
+      port: 0,
+      cert,
+      key,
+      resolveCertificate: () => ({ cert, key }),
+    };
+    assertThrows(
+      () => Deno.listenTls(options),
+      TypeError,
+      "`resolveCertificate` cannot be combined with `cert` and `key`",
+    );
+  },
+);
@@ -462,6 +462,46 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { read: true, net: true } },
+  async function tlsHandshakePeerCertificates() {
+    const [serverConn, clientConn] = await tlsAlpn(false);
+    const [serverHS, clientHS] = await Promise.all([
+      serverConn.handshake(),
+      clientConn.handshake(),
+    ]);
+    assertEquals(clientHS.peerCertificates, [cert]);
+    assertEquals(serverHS.peerCertificates, []);
+
+    serverConn.close();
+    clientConn.close();
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, net: true } },
+  async function startTlsWithClientCertificate() {
+    const { listener, port, hostname } = listenTls();
+    const acceptPromise = listener.accept();
+    const client = await Deno.connect({ hostname, port });
+    const [serverConn, clientConn] = await Promise.all([
+      acceptPromise,
+      Deno.startTls(client, { hostname, caCerts, cert, key }),
+    ]);
+    await Promise.all([serverConn.handshake(), clientConn.handshake()]);
+
+    await assertRejects(
+      () => Deno.startTls(client, { hostname, cert }),
+      TypeError,
+      "`key` must be specified as well for `Deno.startTls`",
+    );
+
+    serverConn.close();
+    clientConn.close();
+    listener.close();
+  },
+);
+
 Deno.test(
   { permissions: { read: true, net: true } },
   async function tlsServerStreamHalfCloseSendOneByte() {
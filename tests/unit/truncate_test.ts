@@ -73,6 +73,33 @@ Deno.test(
   },
 );
 
+Deno.test(
+  { permissions: { read: true, write: true } },
+  function truncateSyncPastFourGiB() {
+    const filename = Deno.makeTempDirSync() + "/test_truncateSyncPastFourGiB";
+    Deno.writeFileSync(filename, new Uint8Array(0));
+    // Sparse, so these do not allocate on disk.
+    Deno.truncateSync(filename, 2 ** 32 + 1);
+    assertEquals(Deno.statSync(filename).size, 2 ** 32 + 1);
+    Deno.truncateSync(filename, 5n * 2n ** 30n);
+    assertEquals(Deno.statSync(filename).size, 5 * 2 ** 30);
+    Deno.truncateSync(filename, -1n);
+    assertEquals(Deno.statSync(filename).size, 0);
+    Deno.removeSync(filename);
+  },
+);
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function truncatePastFourGiB() {
+    const filename = Deno.makeTempDirSync() + "/test_truncatePastFourGiB";
+    await Deno.writeFile(filename, new Uint8Array(0));
+    await Deno.truncate(filename, 2n ** 32n + 1n);
+    assertEquals((await Deno.stat(filename)).size, 2 ** 32 + 1);
+    await Deno.remove(filename);
+  },
+);
+
 Deno.test({ permissions: { write: false } }, function truncateSyncPerm() {
   assertThrows(() => {
     Deno.truncateSync("/test_truncateSyncPermission.txt");
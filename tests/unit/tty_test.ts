@@ -2,7 +2,7 @@
 
 // deno-lint-ignore-file no-deprecated-deno-api
 
-import { assert } from "./test_util.ts";
+import { assert, assertEquals, assertThrows } from "./test_util.ts";
 
 // Note tests for Deno.stdin.setRaw is in integration tests.
 
@@ -15,6 +15,30 @@ Deno.test(function consoleSize() {
   assert(typeof result.rows !== "undefined");
 });
 
+Deno.test(async function consoleSizeChangesReturn() {
+  if (!Deno.stdout.isTerminal()) {
+    return;
+  }
+  const changes = Deno.consoleSizeChanges();
+  const next = changes.next();
+  await changes.return!();
+  assertEquals(await next, { value: undefined, done: true });
+  assertEquals(await changes.next(), { value: undefined, done: true });
+});
+
+Deno.test(function setRawInvalidControlChars() {
+  assertThrows(
+    () => Deno.stdin.setRaw(true, { min: 256 }),
+    RangeError,
+    'The "min" option must be an integer between 0 and 255',
+  );
+  assertThrows(
+    () => Deno.stdin.setRaw(true, { time: 0.5 }),
+    RangeError,
+    'The "time" option must be an integer between 0 and 255',
+  );
+});
+
 Deno.test(function isattyDoesntRaiseOnBadRid() {
   // Absurdly large rid.
   // @ts-ignore `Deno.isatty()` was soft-removed in Deno 2.
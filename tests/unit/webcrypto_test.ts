@@ -2162,3 +2162,34 @@ Deno.test(async function x25519ExportJwk() {
   assert(jwk.d);
   assert(jwk.x);
 });
+
+Deno.test(async function digestReadableStream() {
+  const data = new TextEncoder().encode("hello world");
+  const expected = await crypto.subtle.digest("SHA-256", data);
+  const stream = ReadableStream.from([data.subarray(0, 5), data.subarray(5)]);
+  const actual = await crypto.subtle.digest("SHA-256", stream);
+  assertEquals(new Uint8Array(actual), new Uint8Array(expected));
+
+  await assertRejects(
+    () =>
+      crypto.subtle.digest(
+        "SHA-256",
+        ReadableStream.from(["not a buffer"]) as ReadableStream<BufferSource>,
+      ),
+    TypeError,
+  );
+});
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function digestFsFile() {
+    const data = new Uint8Array(200_000).map((_, i) => i % 251);
+    const path = await Deno.makeTempFile();
+    await Deno.writeFile(path, data);
+    const expected = await crypto.subtle.digest("SHA-512", data);
+    using file = await Deno.open(path);
+    const actual = await crypto.subtle.digest("SHA-512", file);
+    assertEquals(new Uint8Array(actual), new Uint8Array(expected));
+    await Deno.remove(path);
+  },
+);
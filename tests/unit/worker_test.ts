@@ -883,3 +883,158 @@ Deno.test({
     w.terminate();
   },
 });
+
+async function exceedWorkerLimit(
+  limit: string,
+  limits: Record<string, number>,
+): Promise<string> {
+  const worker = new Worker(
+    resolveWorker("limits_worker.js"),
+    { type: "module", deno: { limits } },
+  );
+  const { promise, resolve } = Promise.withResolvers<string>();
+  worker.onerror = (e) => {
+    e.preventDefault();
+    resolve(e.message);
+  };
+  worker.postMessage(limit);
+  const message = await promise;
+  worker.terminate();
+  return message;
+}
+
+Deno.test("Worker heap limit", async function () {
+  assertMatch(
+    await exceedWorkerLimit("heap", { heapMb: 32 }),
+    /Worker exceeded its heap limit of 32 MB/,
+  );
+});
+
+Deno.test({
+  name: "Worker CPU time limit",
+  ignore: Deno.build.os !== "linux",
+  fn: async function () {
+    assertMatch(
+      await exceedWorkerLimit("cpu", { cpuMs: 100 }),
+      /Worker exceeded its CPU time limit of 100 ms/,
+    );
+  },
+});
+
+Deno.test(
+  { permissions: { read: true } },
+  async function workerOpenResourcesLimit() {
+    assertMatch(
+      await exceedWorkerLimit("fds", { maxOpenFds: 4 }),
+      /Worker exceeded its limit of 4 open resources/,
+    );
+  },
+);
+
+Deno.test("Worker limits must be positive integers", function () {
+  assertThrows(
+    () =>
+      new Worker(resolveWorker("limits_worker.js"), {
+        type: "module",
+        deno: { limits: { heapMb: -1 } },
+      }),
+    TypeError,
+    'Worker limit "heapMb" must be a positive integer, received -1',
+  );
+});
+
+Deno.test(
+  { permissions: { read: true, write: true } },
+  async function transferFileReadableStreamToWorker() {
+    const path = await Deno.makeTempFile();
+    await Deno.writeTextFile(path, "hello from a file");
+    const file = await Deno.open(path);
+    const worker = new Worker(resolveWorker("stream_resource_worker.ts"), {
+      type: "module",
+    });
+    const { promise, resolve } = Promise.withResolvers<string>();
+    worker.onmessage = (e) => resolve(e.data);
+    const readable = file.readable;
+    worker.postMessage({ readable }, [readable]);
+    assertEquals(await promise, "hello from a file");
+    // the worker owns the file now
+    assert(readable.locked);
+    assertThrows(
+      () => file.readSync(new Uint8Array(1)),
+      Deno.errors.BadResource,
+    );
+    worker.terminate();
+    await Deno.remove(path);
+  },
+);
+
+Deno.test(
+  { permissions: { net: true, read: true } },
+  async function transferConnStreamsToWorker() {
+    using listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    const client = await Deno.connect(listener.addr);
+    const conn = await listener.accept();
+    const worker = new Worker(resolveWorker("stream_resource_worker.ts"), {
+      type: "module",
+    });
+    const { promise, resolve } = Promise.withResolvers<string>();
+    worker.onmessage = (e) => resolve(e.data);
+    const { readable, writable } = conn;
+    worker.postMessage({ readable, writable }, [readable, writable]);
+
+    await client.write(new TextEncoder().encode("ping"));
+    const buf = new Uint8Array(4);
+    let nread = 0;
+    while (nread < buf.length) {
+      nread += (await client.read(buf.subarray(nread)))!;
+    }
+    assertEquals(new TextDecoder().decode(buf), "ping");
+    client.closeWrite();
+    assertEquals(await promise, "echoed");
+    client.close();
+    worker.terminate();
+  },
+);
+
+Deno.test(
+  { permissions: { net: true } },
+  async function transferConnStreamInUseThrows() {
+    using listener = Deno.listen({ hostname: "127.0.0.1", port: 0 });
+    using client = await Deno.connect(listener.addr);
+    using conn = await listener.accept();
+    const buf = new Uint8Array(4);
+    const pendingRead = conn.read(buf);
+    const { port1, port2 } = new MessageChannel();
+    const readable = conn.readable;
+    assertThrows(
+      () => port1.postMessage(readable, [readable]),
+      DOMException,
+      "Cannot transfer a stream whose resource is in use",
+    );
+    // the connection is kept
+    await client.write(new TextEncoder().encode("ping"));
+    assertEquals(await pendingRead, 4);
+    assertEquals(new TextDecoder().decode(buf), "ping");
+    port1.close();
+    port2.close();
+  },
+);
+
+Deno.test(
+  { permissions: { read: true } },
+  async function transferDisturbedResourceStreamThrows() {
+    using file = await Deno.open(import.meta.filename!);
+    const readable = file.readable;
+    const reader = readable.getReader();
+    await reader.read();
+    reader.releaseLock();
+    const { port1, port2 } = new MessageChannel();
+    assertThrows(
+      () => port1.postMessage(readable, [readable]),
+      DOMException,
+      "Cannot transfer a disturbed ReadableStream",
+    );
+    port1.close();
+    port2.close();
+  },
+);
@@ -1,7 +1,13 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
-import { assert, assertEquals, fail } from "@std/assert";
+import {
+  assert,
+  assertEquals,
+  assertRejects,
+  assertThrows,
+  fail,
+} from "@std/assert";
 import { assertCallbackErrorUncaught } from "../_test_utils.ts";
-import { Dir as DirOrig, type Dirent } from "node:fs";
+import { Dir as DirOrig, type Dirent, opendirSync } from "node:fs";
 
 // deno-lint-ignore no-explicit-any
 const Dir = DirOrig as any;
@@ -203,3 +209,45 @@ Deno.test(
     });
   },
 );
+
+Deno.test({
+  name: "[std/node/fs] Dir throws ERR_DIR_CLOSED once closed",
+  async fn() {
+    const testDir: string = Deno.makeTempDirSync();
+    try {
+      const dir = new Dir(testDir);
+      dir.closeSync();
+      assertThrows(() => dir.readSync(), Error, "Directory handle was closed");
+      assertThrows(() => dir.closeSync(), Error, "Directory handle was closed");
+      await assertRejects(
+        () => dir.read(),
+        Error,
+        "Directory handle was closed",
+      );
+      await assertRejects(
+        () => dir.close(),
+        Error,
+        "Directory handle was closed",
+      );
+    } finally {
+      Deno.removeSync(testDir);
+    }
+  },
+});
+
+Deno.test({
+  name: "[std/node/fs] Dir entries have parentPath and Dir is disposable",
+  fn() {
+    const testDir: string = Deno.makeTempDirSync();
+    Deno.writeTextFileSync(testDir + "/foo.txt", "");
+    try {
+      using dir = opendirSync(testDir);
+      const dirent = dir.readSync();
+      assertEquals(dirent?.name, "foo.txt");
+      assertEquals(dirent?.parentPath, testDir);
+      assert(dirent?.isFile());
+    } finally {
+      Deno.removeSync(testDir, { recursive: true });
+    }
+  },
+});
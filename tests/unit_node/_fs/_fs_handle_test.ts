@@ -157,6 +157,38 @@ Deno.test(
   },
 );
 
+Deno.test(
+  "[node/fs filehandle.readv] Read file into array of buffers",
+  async function () {
+    const fileHandle = await fs.open(testData);
+
+    const buffer1 = Buffer.alloc(6);
+    const buffer2 = Buffer.alloc(10);
+    const res = await fileHandle.readv([buffer1, buffer2]);
+    await fileHandle.close();
+
+    assertEquals(res.bytesRead, 11);
+    assertEquals(res.buffers[0], buffer1);
+    assertEquals(res.buffers[1], buffer2);
+    assertEquals(decoder.decode(buffer1), "hello ");
+    assertEquals(decoder.decode(buffer2.subarray(0, 5)), "world");
+  },
+);
+
+Deno.test(
+  "[node/fs filehandle.readv] Read array of buffers from position",
+  async function () {
+    const fileHandle = await fs.open(testData);
+
+    const buffer = Buffer.alloc(5);
+    const res = await fileHandle.readv([buffer], 6);
+    await fileHandle.close();
+
+    assertEquals(res.bytesRead, 5);
+    assertEquals(decoder.decode(buffer), "world");
+  },
+);
+
 Deno.test(
   "[node/fs filehandle.truncate] Truncate file with length",
   async function () {
@@ -352,6 +384,42 @@ Deno.test(
   },
 );
 
+Deno.test(
+  "[node/fs filehandle.createReadStream] Honors start, end and highWaterMark",
+  async function () {
+    const fileHandle = await fs.open(testData);
+    const stream = fileHandle.createReadStream({
+      start: 2,
+      end: 8,
+      highWaterMark: 2,
+    });
+
+    const chunks: string[] = [];
+    stream.on("data", (data) => chunks.push(decoder.decode(data as Buffer)));
+    await new Promise<void>((resolve) => stream.on("close", resolve));
+
+    assertEquals(chunks, ["ll", "o ", "wo", "r"]);
+    // the stream closed the handle along with the fd
+    assertEquals(fileHandle.fd, -1);
+  },
+);
+
+Deno.test(
+  "[node/fs filehandle.createReadStream] Keeps handle open without autoClose",
+  async function () {
+    const fileHandle = await fs.open(testData);
+    const stream = fileHandle.createReadStream({ autoClose: false });
+
+    let data = "";
+    stream.on("data", (chunk) => data += decoder.decode(chunk as Buffer));
+    await new Promise<void>((resolve) => stream.on("end", resolve));
+
+    assertEquals(data, "hello world");
+    assert(fileHandle.fd !== -1);
+    await fileHandle.close();
+  },
+);
+
 Deno.test(
   "[node/fs filehandle.createWriteStream] Create a write stream",
   async function () {
@@ -253,6 +253,25 @@ Deno.test({
   },
 });
 
+Deno.test({
+  name: "[std/node/fs] fs.readSync() accepts a bigint position past 4GiB",
+  fn() {
+    const file = Deno.makeTempFileSync();
+    const position = 2n ** 32n + 3n;
+    using f = Deno.openSync(file, { write: true });
+    f.seekSync(position, Deno.SeekMode.Start);
+    f.writeSync(new TextEncoder().encode("deno"));
+
+    const fd = openSync(file, "r");
+    const buf = Buffer.alloc(4);
+    const bytesRead = readSync(fd, buf, 0, buf.byteLength, position);
+    assertStrictEquals(bytesRead, 4);
+    assertEquals(buf.toString(), "deno");
+    closeSync(fd);
+    Deno.removeSync(file);
+  },
+});
+
 Deno.test({
   name: "[std/node/fs] Read fs.readSync(fd, buffer[, options]) signature",
   fn() {
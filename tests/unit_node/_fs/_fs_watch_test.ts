@@ -3,6 +3,7 @@ import { unwatchFile, watch, watchFile } from "node:fs";
 import { watch as watchPromise } from "node:fs/promises";
 import { assert, assertEquals } from "@std/assert";
 import { spy } from "@std/testing/mock";
+import { join } from "node:path";
 
 function wait(time: number) {
   return new Promise((resolve) => {
@@ -107,3 +108,29 @@ Deno.test({
     );
   },
 });
+
+Deno.test({
+  name: "[node/fs] watch recursive reports paths relative to the root",
+  async fn() {
+    const root = Deno.makeTempDirSync();
+    Deno.mkdirSync(`${root}/sub`);
+    const result: Array<[string, string | null]> = [];
+    const watcher = watch(
+      root,
+      { recursive: true },
+      (eventType, filename) => result.push([eventType, filename]),
+    );
+    await wait(100);
+    Deno.writeTextFileSync(`${root}/sub/file.txt`, "something");
+    await wait(200);
+    watcher.close();
+    await wait(100);
+    Deno.removeSync(root, { recursive: true });
+
+    const filename = join("sub", "file.txt");
+    assert(
+      result.some(([_, name]) => name === filename),
+      `expected an event for ${filename}, got ${JSON.stringify(result)}`,
+    );
+  },
+});
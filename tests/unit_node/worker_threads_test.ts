@@ -975,3 +975,86 @@ Deno.test({
     await worker.terminate();
   },
 });
+
+Deno.test({
+  name: "[node/worker_threads] Atomics.wait on a SharedArrayBuffer",
+  async fn() {
+    const sab = new SharedArrayBuffer(Int32Array.BYTES_PER_ELEMENT);
+    const int32 = new Int32Array(sab);
+    const worker = new workerThreads.Worker(
+      `
+      import { parentPort, workerData } from "node:worker_threads";
+      const int32 = new Int32Array(workerData);
+      parentPort.postMessage("waiting");
+      const result = Atomics.wait(int32, 0, 0, 5000);
+      parentPort.postMessage([result, Atomics.load(int32, 0)]);
+      `,
+      { eval: true, workerData: sab },
+    );
+    assertEquals((await once(worker, "message"))[0], "waiting");
+    Atomics.store(int32, 0, 42);
+    Atomics.notify(int32, 0);
+    assertEquals((await once(worker, "message"))[0], ["ok", 42]);
+    await worker.terminate();
+  },
+});
+
+Deno.test({
+  name: "[node/worker_threads] MessagePort transferred to the parent",
+  async fn() {
+    const worker = new workerThreads.Worker(
+      `
+      import { MessageChannel, parentPort } from "node:worker_threads";
+      const { port1, port2 } = new MessageChannel();
+      parentPort.postMessage({ ports: new Map([["port", port2]]) }, [port2]);
+      port1.postMessage("hello through the port");
+      `,
+      { eval: true },
+    );
+    const [{ ports }] = await once(worker, "message");
+    const port = ports.get("port");
+    const [message] = await once(port, "message");
+    assertEquals(message, "hello through the port");
+    port.close();
+    await worker.terminate();
+  },
+});
+
+Deno.test("[node/worker_threads] markAsUntransferable", () => {
+  const buffer = new ArrayBuffer(8);
+  assertEquals(workerThreads.isMarkedAsUntransferable(buffer), false);
+  workerThreads.markAsUntransferable(buffer);
+  assertEquals(workerThreads.isMarkedAsUntransferable(buffer), true);
+  const { port1, port2 } = new workerThreads.MessageChannel();
+  assertThrows(
+    () => port1.postMessage(buffer, [buffer]),
+    DOMException,
+    "Value not transferable",
+  );
+  assertEquals(buffer.byteLength, 8);
+  port1.close();
+  port2.close();
+});
+
+Deno.test({
+  name: "[node/worker_threads] resourceLimits are enforced",
+  async fn() {
+    const worker = new workerThreads.Worker(
+      `
+      import { parentPort, resourceLimits } from "node:worker_threads";
+      parentPort.postMessage(resourceLimits.maxOldGenerationSizeMb);
+      const retained = [];
+      while (true) {
+        retained.push(new Array(1024 * 1024).fill({}));
+      }
+      `,
+      { eval: true, resourceLimits: { maxOldGenerationSizeMb: 32 } },
+    );
+    assertEquals(worker.resourceLimits.maxOldGenerationSizeMb, 32);
+    const exit = once(worker, "exit");
+    assertEquals((await once(worker, "message"))[0], 32);
+    const [error] = await once(worker, "error");
+    assertEquals(error.code, "ERR_WORKER_OUT_OF_MEMORY");
+    assertEquals((await exit)[0], 1);
+  },
+});